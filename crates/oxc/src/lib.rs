@@ -1,6 +1,11 @@
 //! # The JavaScript Oxidation Compiler
 //!
 //! <https://github.com/oxc-project/oxc>
+//!
+//! The modules re-export the oxc crates, which change with every release.
+//! See [`stable`] for an API which is kept stable across releases.
+
+pub mod stable;
 
 pub mod allocator {
     #[doc(inline)]
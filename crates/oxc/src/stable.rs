@@ -0,0 +1,241 @@
+//! A curated API which is kept stable across releases, for checking, printing and minifying
+//! JavaScript and TypeScript without depending on the AST.
+//!
+//! The other modules of this crate re-export the oxc crates as they are, and change with the AST
+//! in every release. This module only takes and returns source text and owned values: the kind
+//! of the source and the diagnostics are wrapped in [`SourceKind`] and [`Diagnostic`], and the
+//! options are built with methods, so their internals can change without breaking the callers.
+//!
+//! The linter is not part of it, as it is not published yet.
+//!
+//! ```ignore
+//! use oxc::stable::{check_syntax, SourceKind};
+//!
+//! let diagnostics = check_syntax("let a = ;", SourceKind::javascript());
+//! assert_eq!(diagnostics[0].ranges(), [8..9]);
+//! ```
+
+use std::{
+    fmt::{self, Display},
+    ops::Range,
+    path::Path,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_diagnostics::{Error, Severity};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+/// The language of a source text, and whether it is a module or a script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceKind(SourceType);
+
+impl Default for SourceKind {
+    /// A JavaScript module
+    fn default() -> Self {
+        Self::javascript()
+    }
+}
+
+impl SourceKind {
+    /// A JavaScript module, with JSX
+    pub fn javascript() -> Self {
+        Self(SourceType::default().with_module(true).with_jsx(true))
+    }
+
+    /// A TypeScript module, without JSX
+    pub fn typescript() -> Self {
+        Self(SourceType::default().with_module(true).with_typescript(true))
+    }
+
+    /// The kind of the files with the extension of `path`, e.g. `.tsx` or `.d.ts`,
+    /// `None` when it is not a JavaScript or TypeScript extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        SourceType::from_path(path).ok().map(Self)
+    }
+
+    #[must_use]
+    pub fn with_jsx(self, yes: bool) -> Self {
+        Self(self.0.with_jsx(yes))
+    }
+
+    /// A module when `yes`, a script otherwise
+    #[must_use]
+    pub fn with_module(self, yes: bool) -> Self {
+        Self(self.0.with_module(yes))
+    }
+
+    pub fn is_typescript(self) -> bool {
+        self.0.is_typescript()
+    }
+
+    pub fn is_module(self) -> bool {
+        self.0.is_module()
+    }
+}
+
+/// An error or a warning about a source text
+#[derive(Debug)]
+pub struct Diagnostic(Error);
+
+impl Diagnostic {
+    pub fn message(&self) -> String {
+        self.0.to_string()
+    }
+
+    pub fn help(&self) -> Option<String> {
+        self.0.help().map(|help| help.to_string())
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self.0.severity(), None | Some(Severity::Error))
+    }
+
+    /// The byte ranges of the source text the diagnostic is about, the first one is the main one
+    pub fn ranges(&self) -> Vec<Range<usize>> {
+        self.0.labels().map_or_else(Vec::new, |labels| {
+            labels.map(|label| label.offset()..label.offset() + label.len()).collect()
+        })
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+fn diagnostics(errors: Vec<Error>) -> Vec<Diagnostic> {
+    errors.into_iter().map(Diagnostic).collect()
+}
+
+/// The AST of `source_text`, or its syntax errors
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    kind: SourceKind,
+) -> Result<Program<'a>, Vec<Diagnostic>> {
+    let ret = Parser::new(allocator, source_text, kind.0).parse();
+    if ret.errors.is_empty() {
+        Ok(ret.program)
+    } else {
+        Err(diagnostics(ret.errors))
+    }
+}
+
+/// The syntax errors of `source_text`, empty when it is valid
+pub fn check_syntax(source_text: &str, kind: SourceKind) -> Vec<Diagnostic> {
+    let allocator = Allocator::default();
+    parse(&allocator, source_text, kind).err().unwrap_or_default()
+}
+
+/// The syntax errors of `source_text`, and the errors found once its scopes and symbols are
+/// known, e.g. the redeclarations, empty when it is valid
+#[cfg(feature = "semantic")]
+pub fn check(source_text: &str, kind: SourceKind) -> Vec<Diagnostic> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, kind.0).parse();
+    if !ret.errors.is_empty() {
+        return diagnostics(ret.errors);
+    }
+    let program = allocator.alloc(ret.program);
+    let ret = oxc_semantic::SemanticBuilder::new(source_text, kind.0)
+        .with_trivias(ret.trivias)
+        .with_check_syntax_error(true)
+        .build(program);
+    diagnostics(ret.errors)
+}
+
+/// See [`print`]
+#[cfg(feature = "codegen")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PrintOptions {
+    minify_whitespace: bool,
+}
+
+#[cfg(feature = "codegen")]
+impl PrintOptions {
+    /// Print without the whitespace which is not needed
+    #[must_use]
+    pub fn with_minified_whitespace(mut self, yes: bool) -> Self {
+        self.minify_whitespace = yes;
+        self
+    }
+}
+
+/// `source_text` printed again, without its comments, or its syntax errors.
+/// The types are kept for TypeScript.
+#[cfg(feature = "codegen")]
+pub fn print(
+    source_text: &str,
+    kind: SourceKind,
+    options: PrintOptions,
+) -> Result<String, Vec<Diagnostic>> {
+    let allocator = Allocator::default();
+    let program = parse(&allocator, source_text, kind)?;
+    Ok(print_program(&program, source_text, kind, options))
+}
+
+#[cfg(feature = "codegen")]
+fn print_program(
+    program: &Program,
+    source_text: &str,
+    kind: SourceKind,
+    options: PrintOptions,
+) -> String {
+    use oxc_codegen::{Codegen, CodegenOptions};
+
+    let codegen_options = CodegenOptions { enable_typescript: kind.is_typescript() };
+    if options.minify_whitespace {
+        Codegen::<true>::new(source_text.len(), codegen_options).build(program)
+    } else {
+        Codegen::<false>::new(source_text.len(), codegen_options).build(program)
+    }
+}
+
+/// See [`minify`]
+#[cfg(all(feature = "minifier", feature = "codegen"))]
+#[derive(Debug, Clone, Copy)]
+pub struct MinifyOptions {
+    compress: bool,
+}
+
+#[cfg(all(feature = "minifier", feature = "codegen"))]
+impl Default for MinifyOptions {
+    fn default() -> Self {
+        Self { compress: true }
+    }
+}
+
+#[cfg(all(feature = "minifier", feature = "codegen"))]
+impl MinifyOptions {
+    /// Rewrite the code to shorter code doing the same, e.g. fold the constants, on by default.
+    /// Only the whitespace is removed otherwise.
+    #[must_use]
+    pub fn with_compress(mut self, yes: bool) -> Self {
+        self.compress = yes;
+        self
+    }
+}
+
+/// `source_text` minified, or its syntax errors
+#[cfg(all(feature = "minifier", feature = "codegen"))]
+pub fn minify(
+    source_text: &str,
+    kind: SourceKind,
+    options: MinifyOptions,
+) -> Result<String, Vec<Diagnostic>> {
+    use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
+
+    let allocator = Allocator::default();
+    let program = allocator.alloc(parse(&allocator, source_text, kind)?);
+    if options.compress {
+        let options = MinifierOptions { mangle: false, compress: CompressOptions::default() };
+        Minifier::new(options).build(&allocator, program);
+    }
+    let print_options = PrintOptions::default().with_minified_whitespace(true);
+    Ok(print_program(program, source_text, kind, print_options))
+}
@@ -0,0 +1,62 @@
+use std::path::Path;
+
+use oxc::stable::{check_syntax, SourceKind};
+
+#[test]
+fn source_kind() {
+    assert!(SourceKind::from_path(Path::new("a.tsx")).is_some_and(SourceKind::is_typescript));
+    assert!(SourceKind::from_path(Path::new("a.cjs")).is_some_and(|kind| !kind.is_typescript()));
+    assert!(!SourceKind::javascript().with_module(false).is_module());
+    assert!(SourceKind::from_path(Path::new("a.css")).is_none());
+}
+
+#[test]
+fn syntax() {
+    assert!(check_syntax("let a = <a />;", SourceKind::javascript()).is_empty());
+    let diagnostics = check_syntax("let a: number = 1;", SourceKind::javascript());
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].is_error());
+    assert!(check_syntax("let a: number = 1;", SourceKind::typescript()).is_empty());
+
+    let diagnostics = check_syntax("let a = ;", SourceKind::default());
+    assert_eq!(diagnostics[0].ranges()[0].start, 8);
+}
+
+#[cfg(feature = "semantic")]
+#[test]
+fn semantic() {
+    use oxc::stable::check;
+
+    assert!(check("let a = 1; a;", SourceKind::default()).is_empty());
+    let diagnostics = check("let a = 1; let a = 2;", SourceKind::default());
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message().contains("`a`"), "{}", diagnostics[0]);
+}
+
+#[cfg(feature = "codegen")]
+#[test]
+fn print() {
+    use oxc::stable::{print, PrintOptions};
+
+    let options = PrintOptions::default();
+    assert_eq!(print("let  a = 1", SourceKind::default(), options).unwrap(), "let a = 1;\n");
+    let options = options.with_minified_whitespace(true);
+    assert_eq!(print("let  a = 1", SourceKind::default(), options).unwrap(), "let a=1;");
+    let kind = SourceKind::typescript();
+    assert_eq!(
+        print("let a: number = 1", kind, PrintOptions::default()).unwrap(),
+        "let a: number = 1;\n"
+    );
+    assert!(print("let a = ;", SourceKind::default(), options).is_err());
+}
+
+#[cfg(all(feature = "minifier", feature = "codegen"))]
+#[test]
+fn minify() {
+    use oxc::stable::{minify, MinifyOptions};
+
+    let minified = minify("x = 1 + 2;", SourceKind::default(), MinifyOptions::default()).unwrap();
+    assert_eq!(minified, "x=3;");
+    let options = MinifyOptions::default().with_compress(false);
+    assert_eq!(minify("x = 1 + 2;", SourceKind::default(), options).unwrap(), "x=1+2;");
+}
@@ -10,6 +10,13 @@ pub struct Allocator {
     bump: Bump,
 }
 
+impl Allocator {
+    /// Free everything allocated so far, keeping the largest chunk of memory to allocate into again.
+    pub fn reset(&mut self) {
+        self.bump.reset();
+    }
+}
+
 impl From<Bump> for Allocator {
     fn from(bump: Bump) -> Self {
         Self { bump }
@@ -40,4 +47,13 @@ mod test {
             _ = allocator.deref();
         }
     }
+
+    #[test]
+    fn reset() {
+        let mut allocator = Allocator::default();
+        let ptr: *const u64 = allocator.alloc(0);
+        allocator.reset();
+        // The memory is allocated into again
+        assert_eq!(allocator.alloc(0u64) as *const u64, ptr);
+    }
 }
@@ -2,6 +2,7 @@
 
 use oxc_allocator::{Box, Vec};
 use oxc_span::{Atom, Span};
+use oxc_syntax::identifier::{is_irregular_whitespace, is_line_terminator};
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
@@ -254,3 +255,43 @@ pub struct JSXText<'a> {
     pub span: Span,
     pub value: Atom<'a>,
 }
+
+impl<'a> JSXText<'a> {
+    /// The lines of the text as rendered, before decoding entities.
+    ///
+    /// JSX trims whitespace at the end and beginning of lines, except that the start/end
+    /// of a tag is considered a start/end of a line only if that line is on the same line
+    /// as the tag. Lines of only whitespace are removed, and the rendered text joins the
+    /// remaining lines with a space.
+    ///
+    /// <https://github.com/microsoft/TypeScript/blob/f0374ce2a9c465e27a15b7fa4a347e2bd9079450/src/compiler/transformers/jsx.ts#L557-L608>
+    pub fn trimmed_lines(&self) -> std::vec::Vec<&str> {
+        let text = self.value.as_str();
+        let mut lines = vec![];
+        let mut first_non_whitespace: Option<usize> = Some(0);
+        let mut last_non_whitespace: Option<usize> = None;
+        for (i, c) in text.char_indices() {
+            if is_line_terminator(c) {
+                if let (Some(first), Some(last)) = (first_non_whitespace, last_non_whitespace) {
+                    lines.push(&text[first..=last]);
+                }
+                first_non_whitespace = None;
+            } else if !matches!(c, ' ' | '\t') && !is_irregular_whitespace(c) {
+                last_non_whitespace = Some(i + c.len_utf8() - 1);
+                if first_non_whitespace.is_none() {
+                    first_non_whitespace.replace(i);
+                }
+            }
+        }
+        if let Some(first) = first_non_whitespace {
+            lines.push(&text[first..]);
+        }
+        lines
+    }
+
+    /// Whether the text is not rendered, e.g. a line break and the indentation between
+    /// two elements
+    pub fn is_insignificant(&self) -> bool {
+        self.trimmed_lines().is_empty()
+    }
+}
@@ -961,6 +961,32 @@ pub enum ModifierKind {
     Override,
 }
 
+impl ModifierKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Abstract => "abstract",
+            Self::Accessor => "accessor",
+            Self::Async => "async",
+            Self::Const => "const",
+            Self::Declare => "declare",
+            Self::Default => "default",
+            Self::Export => "export",
+            Self::In => "in",
+            Self::Public => "public",
+            Self::Private => "private",
+            Self::Protected => "protected",
+            Self::Readonly => "readonly",
+            Self::Static => "static",
+            Self::Out => "out",
+            Self::Override => "override",
+        }
+    }
+
+    pub fn is_accessibility(self) -> bool {
+        matches!(self, Self::Public | Self::Private | Self::Protected)
+    }
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 #[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
@@ -988,6 +1014,10 @@ impl<'a> Modifiers<'a> {
         self.0.is_none()
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &Modifier> + '_ {
+        self.0.iter().flat_map(|modifiers| modifiers.iter())
+    }
+
     pub fn contains(&self, target: ModifierKind) -> bool {
         self.0
             .as_ref()
@@ -175,6 +175,191 @@ pub enum AstKind<'a> {
     TSPropertySignature(&'a TSPropertySignature<'a>),
 }
 
+/// Declares [`AstType`] with its variants, and its number of variants as `AstType::COUNT`.
+macro_rules! ast_type {
+    ($($variant:ident,)*) => {
+        /// The type of an [`AstKind`] without the node it refers to,
+        /// `AstType as usize` can index lookup tables of node kinds.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum AstType {
+            $($variant,)*
+        }
+
+        impl AstType {
+            /// The number of node types
+            pub const COUNT: usize = [$(Self::$variant),*].len();
+        }
+    };
+}
+
+ast_type! {
+    Program,
+    Directive,
+    Hashbang,
+
+    BlockStatement,
+    BreakStatement,
+    ContinueStatement,
+    DebuggerStatement,
+    DoWhileStatement,
+    EmptyStatement,
+    ExpressionStatement,
+    ForInStatement,
+    ForOfStatement,
+    ForStatement,
+    ForStatementInit,
+    IfStatement,
+    LabeledStatement,
+    ReturnStatement,
+    SwitchStatement,
+    ThrowStatement,
+    TryStatement,
+    WhileStatement,
+    WithStatement,
+
+    SwitchCase,
+    CatchClause,
+    FinallyClause,
+
+    VariableDeclaration,
+    VariableDeclarator,
+
+    UsingDeclaration,
+
+    IdentifierName,
+    IdentifierReference,
+    BindingIdentifier,
+    LabelIdentifier,
+    PrivateIdentifier,
+
+    NumericLiteral,
+    StringLiteral,
+    BooleanLiteral,
+    NullLiteral,
+    BigintLiteral,
+    RegExpLiteral,
+    TemplateLiteral,
+
+    MetaProperty,
+    Super,
+
+    ArrayExpression,
+    ArrowFunctionExpression,
+    AssignmentExpression,
+    AwaitExpression,
+    BinaryExpression,
+    CallExpression,
+    ChainExpression,
+    ConditionalExpression,
+    LogicalExpression,
+    MemberExpression,
+    NewExpression,
+    ObjectExpression,
+    ParenthesizedExpression,
+    SequenceExpression,
+    TaggedTemplateExpression,
+    ThisExpression,
+    UnaryExpression,
+    UpdateExpression,
+    YieldExpression,
+    ImportExpression,
+    PrivateInExpression,
+
+    ObjectProperty,
+    PropertyKey,
+    Argument,
+    AssignmentTarget,
+    SimpleAssignmentTarget,
+    AssignmentTargetWithDefault,
+    ArrayExpressionElement,
+    Elision,
+    ExpressionArrayElement,
+    SpreadElement,
+    BindingRestElement,
+
+    Function,
+    FunctionBody,
+    FormalParameters,
+    FormalParameter,
+
+    Class,
+    ClassBody,
+    ClassHeritage,
+    StaticBlock,
+    PropertyDefinition,
+    MethodDefinition,
+
+    ArrayPattern,
+    ObjectPattern,
+    AssignmentPattern,
+
+    Decorator,
+
+    ModuleDeclaration,
+    ImportDeclaration,
+    ImportSpecifier,
+    ImportDefaultSpecifier,
+    ImportNamespaceSpecifier,
+    ExportDefaultDeclaration,
+    ExportNamedDeclaration,
+    ExportAllDeclaration,
+
+    // JSX
+    JSXElement,
+    JSXFragment,
+    JSXOpeningElement,
+    JSXClosingElement,
+    JSXElementName,
+    JSXExpressionContainer,
+    JSXAttributeItem,
+    JSXSpreadAttribute,
+    JSXText,
+    JSXIdentifier,
+    JSXMemberExpression,
+    JSXMemberExpressionObject,
+    JSXNamespacedName,
+
+    // TypeScript
+    TSModuleBlock,
+
+    TSAnyKeyword,
+    TSIntersectionType,
+    TSLiteralType,
+    TSMethodSignature,
+    TSNullKeyword,
+    TSTypeLiteral,
+    TSTypeReference,
+    TSUnionType,
+    TSVoidKeyword,
+
+    TSIndexedAccessType,
+
+    TSAsExpression,
+    TSSatisfiesExpression,
+    TSNonNullExpression,
+    TSInstantiationExpression,
+
+    TSEnumDeclaration,
+    TSEnumMember,
+
+    TSImportEqualsDeclaration,
+    TSTypeName,
+    TSExternalModuleReference,
+    TSQualifiedName,
+
+    TSInterfaceDeclaration,
+    TSModuleDeclaration,
+    TSTypeAliasDeclaration,
+    TSTypeAnnotation,
+    TSTypeQuery,
+    TSTypeAssertion,
+    TSTypeParameter,
+    TSTypeParameterDeclaration,
+    TSTypeParameterInstantiation,
+
+    TSPropertySignature,
+}
+
 // SAFETY:
 // The AST is part of the bump allocator,
 // it is our responsibility to never simultaneously mutate across threads.
@@ -185,6 +370,151 @@ unsafe impl<'a> Send for AstKind<'a> {}
 unsafe impl<'a> Sync for AstKind<'a> {}
 
 impl<'a> AstKind<'a> {
+    pub fn ty(&self) -> AstType {
+        match self {
+            Self::Program(_) => AstType::Program,
+            Self::Directive(_) => AstType::Directive,
+            Self::Hashbang(_) => AstType::Hashbang,
+            Self::BlockStatement(_) => AstType::BlockStatement,
+            Self::BreakStatement(_) => AstType::BreakStatement,
+            Self::ContinueStatement(_) => AstType::ContinueStatement,
+            Self::DebuggerStatement(_) => AstType::DebuggerStatement,
+            Self::DoWhileStatement(_) => AstType::DoWhileStatement,
+            Self::EmptyStatement(_) => AstType::EmptyStatement,
+            Self::ExpressionStatement(_) => AstType::ExpressionStatement,
+            Self::ForInStatement(_) => AstType::ForInStatement,
+            Self::ForOfStatement(_) => AstType::ForOfStatement,
+            Self::ForStatement(_) => AstType::ForStatement,
+            Self::ForStatementInit(_) => AstType::ForStatementInit,
+            Self::IfStatement(_) => AstType::IfStatement,
+            Self::LabeledStatement(_) => AstType::LabeledStatement,
+            Self::ReturnStatement(_) => AstType::ReturnStatement,
+            Self::SwitchStatement(_) => AstType::SwitchStatement,
+            Self::ThrowStatement(_) => AstType::ThrowStatement,
+            Self::TryStatement(_) => AstType::TryStatement,
+            Self::WhileStatement(_) => AstType::WhileStatement,
+            Self::WithStatement(_) => AstType::WithStatement,
+            Self::SwitchCase(_) => AstType::SwitchCase,
+            Self::CatchClause(_) => AstType::CatchClause,
+            Self::FinallyClause(_) => AstType::FinallyClause,
+            Self::VariableDeclaration(_) => AstType::VariableDeclaration,
+            Self::VariableDeclarator(_) => AstType::VariableDeclarator,
+            Self::UsingDeclaration(_) => AstType::UsingDeclaration,
+            Self::IdentifierName(_) => AstType::IdentifierName,
+            Self::IdentifierReference(_) => AstType::IdentifierReference,
+            Self::BindingIdentifier(_) => AstType::BindingIdentifier,
+            Self::LabelIdentifier(_) => AstType::LabelIdentifier,
+            Self::PrivateIdentifier(_) => AstType::PrivateIdentifier,
+            Self::NumericLiteral(_) => AstType::NumericLiteral,
+            Self::StringLiteral(_) => AstType::StringLiteral,
+            Self::BooleanLiteral(_) => AstType::BooleanLiteral,
+            Self::NullLiteral(_) => AstType::NullLiteral,
+            Self::BigintLiteral(_) => AstType::BigintLiteral,
+            Self::RegExpLiteral(_) => AstType::RegExpLiteral,
+            Self::TemplateLiteral(_) => AstType::TemplateLiteral,
+            Self::MetaProperty(_) => AstType::MetaProperty,
+            Self::Super(_) => AstType::Super,
+            Self::ArrayExpression(_) => AstType::ArrayExpression,
+            Self::ArrowFunctionExpression(_) => AstType::ArrowFunctionExpression,
+            Self::AssignmentExpression(_) => AstType::AssignmentExpression,
+            Self::AwaitExpression(_) => AstType::AwaitExpression,
+            Self::BinaryExpression(_) => AstType::BinaryExpression,
+            Self::CallExpression(_) => AstType::CallExpression,
+            Self::ChainExpression(_) => AstType::ChainExpression,
+            Self::ConditionalExpression(_) => AstType::ConditionalExpression,
+            Self::LogicalExpression(_) => AstType::LogicalExpression,
+            Self::MemberExpression(_) => AstType::MemberExpression,
+            Self::NewExpression(_) => AstType::NewExpression,
+            Self::ObjectExpression(_) => AstType::ObjectExpression,
+            Self::ParenthesizedExpression(_) => AstType::ParenthesizedExpression,
+            Self::SequenceExpression(_) => AstType::SequenceExpression,
+            Self::TaggedTemplateExpression(_) => AstType::TaggedTemplateExpression,
+            Self::ThisExpression(_) => AstType::ThisExpression,
+            Self::UnaryExpression(_) => AstType::UnaryExpression,
+            Self::UpdateExpression(_) => AstType::UpdateExpression,
+            Self::YieldExpression(_) => AstType::YieldExpression,
+            Self::ImportExpression(_) => AstType::ImportExpression,
+            Self::PrivateInExpression(_) => AstType::PrivateInExpression,
+            Self::ObjectProperty(_) => AstType::ObjectProperty,
+            Self::PropertyKey(_) => AstType::PropertyKey,
+            Self::Argument(_) => AstType::Argument,
+            Self::AssignmentTarget(_) => AstType::AssignmentTarget,
+            Self::SimpleAssignmentTarget(_) => AstType::SimpleAssignmentTarget,
+            Self::AssignmentTargetWithDefault(_) => AstType::AssignmentTargetWithDefault,
+            Self::ArrayExpressionElement(_) => AstType::ArrayExpressionElement,
+            Self::Elision(_) => AstType::Elision,
+            Self::ExpressionArrayElement(_) => AstType::ExpressionArrayElement,
+            Self::SpreadElement(_) => AstType::SpreadElement,
+            Self::BindingRestElement(_) => AstType::BindingRestElement,
+            Self::Function(_) => AstType::Function,
+            Self::FunctionBody(_) => AstType::FunctionBody,
+            Self::FormalParameters(_) => AstType::FormalParameters,
+            Self::FormalParameter(_) => AstType::FormalParameter,
+            Self::Class(_) => AstType::Class,
+            Self::ClassBody(_) => AstType::ClassBody,
+            Self::ClassHeritage(_) => AstType::ClassHeritage,
+            Self::StaticBlock(_) => AstType::StaticBlock,
+            Self::PropertyDefinition(_) => AstType::PropertyDefinition,
+            Self::MethodDefinition(_) => AstType::MethodDefinition,
+            Self::ArrayPattern(_) => AstType::ArrayPattern,
+            Self::ObjectPattern(_) => AstType::ObjectPattern,
+            Self::AssignmentPattern(_) => AstType::AssignmentPattern,
+            Self::Decorator(_) => AstType::Decorator,
+            Self::ModuleDeclaration(_) => AstType::ModuleDeclaration,
+            Self::ImportDeclaration(_) => AstType::ImportDeclaration,
+            Self::ImportSpecifier(_) => AstType::ImportSpecifier,
+            Self::ImportDefaultSpecifier(_) => AstType::ImportDefaultSpecifier,
+            Self::ImportNamespaceSpecifier(_) => AstType::ImportNamespaceSpecifier,
+            Self::ExportDefaultDeclaration(_) => AstType::ExportDefaultDeclaration,
+            Self::ExportNamedDeclaration(_) => AstType::ExportNamedDeclaration,
+            Self::ExportAllDeclaration(_) => AstType::ExportAllDeclaration,
+            Self::JSXElement(_) => AstType::JSXElement,
+            Self::JSXFragment(_) => AstType::JSXFragment,
+            Self::JSXOpeningElement(_) => AstType::JSXOpeningElement,
+            Self::JSXClosingElement(_) => AstType::JSXClosingElement,
+            Self::JSXElementName(_) => AstType::JSXElementName,
+            Self::JSXExpressionContainer(_) => AstType::JSXExpressionContainer,
+            Self::JSXAttributeItem(_) => AstType::JSXAttributeItem,
+            Self::JSXSpreadAttribute(_) => AstType::JSXSpreadAttribute,
+            Self::JSXText(_) => AstType::JSXText,
+            Self::JSXIdentifier(_) => AstType::JSXIdentifier,
+            Self::JSXMemberExpression(_) => AstType::JSXMemberExpression,
+            Self::JSXMemberExpressionObject(_) => AstType::JSXMemberExpressionObject,
+            Self::JSXNamespacedName(_) => AstType::JSXNamespacedName,
+            Self::TSModuleBlock(_) => AstType::TSModuleBlock,
+            Self::TSAnyKeyword(_) => AstType::TSAnyKeyword,
+            Self::TSIntersectionType(_) => AstType::TSIntersectionType,
+            Self::TSLiteralType(_) => AstType::TSLiteralType,
+            Self::TSMethodSignature(_) => AstType::TSMethodSignature,
+            Self::TSNullKeyword(_) => AstType::TSNullKeyword,
+            Self::TSTypeLiteral(_) => AstType::TSTypeLiteral,
+            Self::TSTypeReference(_) => AstType::TSTypeReference,
+            Self::TSUnionType(_) => AstType::TSUnionType,
+            Self::TSVoidKeyword(_) => AstType::TSVoidKeyword,
+            Self::TSIndexedAccessType(_) => AstType::TSIndexedAccessType,
+            Self::TSAsExpression(_) => AstType::TSAsExpression,
+            Self::TSSatisfiesExpression(_) => AstType::TSSatisfiesExpression,
+            Self::TSNonNullExpression(_) => AstType::TSNonNullExpression,
+            Self::TSInstantiationExpression(_) => AstType::TSInstantiationExpression,
+            Self::TSEnumDeclaration(_) => AstType::TSEnumDeclaration,
+            Self::TSEnumMember(_) => AstType::TSEnumMember,
+            Self::TSImportEqualsDeclaration(_) => AstType::TSImportEqualsDeclaration,
+            Self::TSTypeName(_) => AstType::TSTypeName,
+            Self::TSExternalModuleReference(_) => AstType::TSExternalModuleReference,
+            Self::TSQualifiedName(_) => AstType::TSQualifiedName,
+            Self::TSInterfaceDeclaration(_) => AstType::TSInterfaceDeclaration,
+            Self::TSModuleDeclaration(_) => AstType::TSModuleDeclaration,
+            Self::TSTypeAliasDeclaration(_) => AstType::TSTypeAliasDeclaration,
+            Self::TSTypeAnnotation(_) => AstType::TSTypeAnnotation,
+            Self::TSTypeQuery(_) => AstType::TSTypeQuery,
+            Self::TSTypeAssertion(_) => AstType::TSTypeAssertion,
+            Self::TSTypeParameter(_) => AstType::TSTypeParameter,
+            Self::TSTypeParameterDeclaration(_) => AstType::TSTypeParameterDeclaration,
+            Self::TSTypeParameterInstantiation(_) => AstType::TSTypeParameterInstantiation,
+            Self::TSPropertySignature(_) => AstType::TSPropertySignature,
+        }
+    }
+
     #[rustfmt::skip]
     pub fn is_statement(self) -> bool {
         self.is_iteration_statement()
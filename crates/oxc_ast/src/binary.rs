@@ -0,0 +1,302 @@
+//! A compact binary encoding of the ESTree AST, for passing trees to rule plugins running out of
+//! process or in WASM. The encoding follows the shape of ESTree, not the structs of this crate, so
+//! it only changes when `ESTREE_BINARY_VERSION` is bumped.
+//!
+//! Layout, with integers as unsigned LEB128 varints:
+//!
+//! ```text
+//! magic     b"OXAST"
+//! version   u8
+//! strings   count, then the length and UTF-8 bytes of each string
+//! root      value
+//! ```
+//!
+//! A value starts with its tag: `0` null, `1` false, `2` true, `3` integer (zigzag varint),
+//! `4` float (little endian `f64`), `5` string (index into the strings), `6` array (length, then the
+//! values), `7` object (length, then the string index of each key followed by its value, sorted by
+//! key).
+//! The node types, property names and names of identifiers are each stored once in the strings.
+
+use std::{collections::HashMap, fmt};
+
+use serde_json::{Map, Number, Value};
+
+use crate::ast::Program;
+
+/// The version of the encoding, bumped on any change of the layout or of the ESTree shape.
+/// Also checked by the decoder of the JavaScript plugins, in `oxc_linter/src/js_plugin/runtime.js`.
+pub const ESTREE_BINARY_VERSION: u8 = 1;
+
+const MAGIC: &[u8] = b"OXAST";
+
+const NULL: u8 = 0;
+const FALSE: u8 = 1;
+const TRUE: u8 = 2;
+const INTEGER: u8 = 3;
+const FLOAT: u8 = 4;
+const STRING: u8 = 5;
+const ARRAY: u8 = 6;
+const OBJECT: u8 = 7;
+
+/// Errors decoding a binary AST
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EstreeBinaryError {
+    /// Not an AST encoded by `Program::to_estree_binary`
+    InvalidMagic,
+    /// Encoded with another version of the encoding
+    UnsupportedVersion(u8),
+    /// Truncated or corrupted
+    Invalid,
+}
+
+impl fmt::Display for EstreeBinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "not a binary AST"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported binary AST version {version}, expected {ESTREE_BINARY_VERSION}"
+            ),
+            Self::Invalid => write!(f, "invalid binary AST"),
+        }
+    }
+}
+
+impl std::error::Error for EstreeBinaryError {}
+
+impl<'a> Program<'a> {
+    /// Serialize to the ESTree shaped tree of `Program::to_estree_json`, in the binary encoding of
+    /// `crate::binary`. Decoded by `decode_estree_binary`.
+    /// `source_text` must be the source text of this program.
+    pub fn to_estree_binary(&self, source_text: &str) -> Vec<u8> {
+        encode_estree_binary(&self.to_estree_value(source_text))
+    }
+}
+
+/// Encode an ESTree shaped JSON value, see `Program::to_estree_binary`.
+pub fn encode_estree_binary(value: &Value) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+    encoder.encode(value);
+    let mut bytes = MAGIC.to_vec();
+    bytes.push(ESTREE_BINARY_VERSION);
+    write_varint(&mut bytes, encoder.strings.len() as u64);
+    for string in &encoder.strings {
+        write_varint(&mut bytes, string.len() as u64);
+        bytes.extend_from_slice(string.as_bytes());
+    }
+    bytes.extend_from_slice(&encoder.values);
+    bytes
+}
+
+/// The JSON value of an AST encoded by `Program::to_estree_binary`.
+///
+/// # Errors
+///
+/// Returns `Err` if `bytes` are not a binary AST of `ESTREE_BINARY_VERSION`.
+pub fn decode_estree_binary(bytes: &[u8]) -> Result<Value, EstreeBinaryError> {
+    let bytes = bytes.strip_prefix(MAGIC).ok_or(EstreeBinaryError::InvalidMagic)?;
+    let (&version, bytes) = bytes.split_first().ok_or(EstreeBinaryError::InvalidMagic)?;
+    if version != ESTREE_BINARY_VERSION {
+        return Err(EstreeBinaryError::UnsupportedVersion(version));
+    }
+    let mut decoder = Decoder { bytes, position: 0, strings: vec![] };
+    let count = decoder.read_varint()?;
+    for _ in 0..count {
+        let len =
+            usize::try_from(decoder.read_varint()?).map_err(|_| EstreeBinaryError::Invalid)?;
+        let string = std::str::from_utf8(decoder.read_bytes(len)?)
+            .map_err(|_| EstreeBinaryError::Invalid)?;
+        decoder.strings.push(string);
+    }
+    let value = decoder.decode(0)?;
+    if decoder.position != decoder.bytes.len() {
+        return Err(EstreeBinaryError::Invalid);
+    }
+    Ok(value)
+}
+
+#[derive(Default)]
+struct Encoder<'v> {
+    strings: Vec<&'v str>,
+    indices: HashMap<&'v str, u64>,
+    values: Vec<u8>,
+}
+
+impl<'v> Encoder<'v> {
+    #[allow(clippy::cast_sign_loss)]
+    fn encode(&mut self, value: &'v Value) {
+        match value {
+            Value::Null => self.values.push(NULL),
+            Value::Bool(false) => self.values.push(FALSE),
+            Value::Bool(true) => self.values.push(TRUE),
+            Value::Number(number) => match number.as_i64() {
+                Some(integer) => {
+                    self.values.push(INTEGER);
+                    write_varint(&mut self.values, ((integer << 1) ^ (integer >> 63)) as u64);
+                }
+                None => {
+                    self.values.push(FLOAT);
+                    let float = number.as_f64().unwrap_or(f64::NAN);
+                    self.values.extend_from_slice(&float.to_le_bytes());
+                }
+            },
+            Value::String(string) => {
+                self.values.push(STRING);
+                self.string(string);
+            }
+            Value::Array(array) => {
+                self.values.push(ARRAY);
+                write_varint(&mut self.values, array.len() as u64);
+                array.iter().for_each(|value| self.encode(value));
+            }
+            Value::Object(object) => {
+                self.values.push(OBJECT);
+                write_varint(&mut self.values, object.len() as u64);
+                // Sorted, as `serde_json::Map` keeps the insertion order with its `preserve_order`
+                // feature, which any crate of the build can enable
+                let mut entries = object.iter().collect::<Vec<_>>();
+                entries.sort_unstable_by_key(|(key, _)| *key);
+                for (key, value) in entries {
+                    self.string(key);
+                    self.encode(value);
+                }
+            }
+        }
+    }
+
+    fn string(&mut self, string: &'v str) {
+        let next = self.strings.len() as u64;
+        let index = *self.indices.entry(string).or_insert(next);
+        if index == next {
+            self.strings.push(string);
+        }
+        write_varint(&mut self.values, index);
+    }
+}
+
+struct Decoder<'b> {
+    bytes: &'b [u8],
+    position: usize,
+    strings: Vec<&'b str>,
+}
+
+impl<'b> Decoder<'b> {
+    /// Deeper trees are not produced by the parser, whose nesting is limited by the stack.
+    const MAX_DEPTH: usize = 10_000;
+
+    fn decode(&mut self, depth: usize) -> Result<Value, EstreeBinaryError> {
+        if depth > Self::MAX_DEPTH {
+            return Err(EstreeBinaryError::Invalid);
+        }
+        Ok(match self.read_bytes(1)?[0] {
+            NULL => Value::Null,
+            FALSE => Value::Bool(false),
+            TRUE => Value::Bool(true),
+            INTEGER => {
+                let zigzag = self.read_varint()?;
+                #[allow(clippy::cast_possible_wrap)]
+                let integer = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+                Value::from(integer)
+            }
+            FLOAT => {
+                let bytes =
+                    self.read_bytes(8)?.try_into().map_err(|_| EstreeBinaryError::Invalid)?;
+                Number::from_f64(f64::from_le_bytes(bytes)).map_or(Value::Null, Value::Number)
+            }
+            STRING => Value::from(self.read_string()?),
+            ARRAY => {
+                let len = self.read_varint()?;
+                let values = (0..len).map(|_| self.decode(depth + 1));
+                Value::Array(values.collect::<Result<_, _>>()?)
+            }
+            OBJECT => {
+                let len = self.read_varint()?;
+                let mut object = Map::new();
+                for _ in 0..len {
+                    let key = self.read_string()?.to_string();
+                    object.insert(key, self.decode(depth + 1)?);
+                }
+                Value::Object(object)
+            }
+            _ => return Err(EstreeBinaryError::Invalid),
+        })
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'b [u8], EstreeBinaryError> {
+        let end = self.position.checked_add(len).ok_or(EstreeBinaryError::Invalid)?;
+        let bytes = self.bytes.get(self.position..end).ok_or(EstreeBinaryError::Invalid)?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_varint(&mut self) -> Result<u64, EstreeBinaryError> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_bytes(1)?[0];
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(EstreeBinaryError::Invalid)
+    }
+
+    fn read_string(&mut self) -> Result<&'b str, EstreeBinaryError> {
+        let index = usize::try_from(self.read_varint()?).map_err(|_| EstreeBinaryError::Invalid)?;
+        self.strings.get(index).copied().ok_or(EstreeBinaryError::Invalid)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{decode_estree_binary, encode_estree_binary, EstreeBinaryError};
+
+    #[test]
+    fn round_trip() {
+        let value = json!({
+            "type": "Program",
+            "body": [
+                { "type": "Identifier", "name": "a", "start": 0, "end": 1 },
+                { "type": "Identifier", "name": "é", "start": -1, "end": u64::from(u32::MAX) },
+            ],
+            "value": 1.5,
+            "bigint": null,
+            "flags": [true, false],
+        });
+        let bytes = encode_estree_binary(&value);
+        assert_eq!(decode_estree_binary(&bytes), Ok(value));
+        // "type" and "Identifier" are stored once
+        assert_eq!(bytes.windows(10).filter(|window| *window == b"Identifier").count(), 1);
+    }
+
+    #[test]
+    fn invalid() {
+        let bytes = encode_estree_binary(&json!({ "type": "Program" }));
+        assert_eq!(decode_estree_binary(b"{}"), Err(EstreeBinaryError::InvalidMagic));
+        let mut other_version = bytes.clone();
+        other_version[5] += 1;
+        assert_eq!(
+            decode_estree_binary(&other_version),
+            Err(EstreeBinaryError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            decode_estree_binary(&bytes[..bytes.len() - 1]),
+            Err(EstreeBinaryError::Invalid)
+        );
+        assert_eq!(
+            decode_estree_binary(&[&bytes[..], &[0]].concat()),
+            Err(EstreeBinaryError::Invalid)
+        );
+    }
+}
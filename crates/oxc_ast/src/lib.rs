@@ -7,8 +7,12 @@
 //! * `AssignmentExpression`.`left` `Pattern` is replaced with `AssignmentTarget`
 //!
 //! ## Cargo Features
-//! * `"serde"` enables support for serde serialization
+//! * `"serde"` enables support for serde serialization,
+//!   and for [ESTree](https://github.com/estree/estree) JSON with `Program::to_estree_json`,
+//!   or in the versioned binary encoding of `Program::to_estree_binary` for rule plugins
 
+#[cfg(feature = "serde")]
+mod binary;
 #[cfg(feature = "serde")]
 mod serialize;
 
@@ -26,12 +30,20 @@ pub use num_bigint::BigUint;
 
 pub use crate::{
     ast_builder::AstBuilder,
-    ast_kind::AstKind,
+    ast_kind::{AstKind, AstType},
     trivia::{Comment, CommentKind, Trivias, TriviasMap},
     visit::Visit,
     visit_mut::VisitMut,
 };
 
+#[cfg(feature = "serde")]
+pub use crate::{
+    binary::{
+        decode_estree_binary, encode_estree_binary, EstreeBinaryError, ESTREE_BINARY_VERSION,
+    },
+    serialize::SpanConverter,
+};
+
 // After experimenting with two types of boxed enum variants:
 //   1.
 //   ```
@@ -1,4 +1,5 @@
 use serde::{ser::Serializer, Serialize};
+use serde_json::{Map, Value};
 
 use crate::ast::{Program, RegExpFlags};
 
@@ -24,6 +25,276 @@ impl<'a> Program<'a> {
         self.serialize(&mut ser).unwrap();
         String::from_utf8(ser.into_inner()).unwrap()
     }
+
+    /// Serialize to [ESTree](https://github.com/estree/estree) shaped JSON for JavaScript tools,
+    /// with the TypeScript nodes named as in [typescript-estree](https://typescript-eslint.io/packages/typescript-estree/).
+    ///
+    /// The offsets of `start`, `end` and `range` are in UTF-16 code units as in JavaScript,
+    /// and `loc` has 1-based lines and 0-based columns.
+    /// `source_text` must be the source text of this program.
+    ///
+    /// # Panics
+    pub fn to_estree_json(&self, source_text: &str) -> String {
+        let value = self.to_estree_value(source_text);
+        let buf = std::vec::Vec::new();
+        let mut ser = serde_json::Serializer::with_formatter(buf, crate::serialize::EcmaFormatter);
+        value.serialize(&mut ser).unwrap();
+        String::from_utf8(ser.into_inner()).unwrap()
+    }
+
+    /// The JSON value of `to_estree_json`.
+    ///
+    /// # Panics
+    pub fn to_estree_value(&self, source_text: &str) -> Value {
+        let value = serde_json::to_value(self).unwrap();
+        EstreeConverter { spans: SpanConverter::new(source_text) }.convert(value)
+    }
+}
+
+/// Converts the UTF-8 offsets of spans to the lines, columns and offsets used by JavaScript tools,
+/// counted in UTF-16 code units.
+pub struct SpanConverter<'a> {
+    source_text: &'a str,
+    /// The UTF-8 and UTF-16 offsets of the start of each line
+    lines: Vec<(usize, usize)>,
+}
+
+impl<'a> SpanConverter<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        let mut lines = vec![(0, 0)];
+        let mut utf16_offset = 0;
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            utf16_offset += c.len_utf16();
+            let is_line_end = match c {
+                '\r' => !matches!(chars.peek(), Some((_, '\n'))),
+                '\n' | '\u{2028}' | '\u{2029}' => true,
+                _ => false,
+            };
+            if is_line_end {
+                lines.push((offset + c.len_utf8(), utf16_offset));
+            }
+        }
+        Self { source_text, lines }
+    }
+
+    /// The 1-based line and the 0-based column of `offset`.
+    pub fn line_column(&self, offset: u32) -> (usize, usize) {
+        let (line, column, _) = self.locate(offset);
+        (line, column)
+    }
+
+    /// The offset in UTF-16 code units of `offset`.
+    pub fn utf16_offset(&self, offset: u32) -> usize {
+        self.locate(offset).2
+    }
+
+    fn locate(&self, offset: u32) -> (usize, usize, usize) {
+        let offset = (offset as usize).min(self.source_text.len());
+        let line = self.lines.partition_point(|(start, _)| *start <= offset) - 1;
+        let (line_start, line_start_utf16) = self.lines[line];
+        let column = self
+            .source_text
+            .get(line_start..offset)
+            .map_or(offset - line_start, |text| text.encode_utf16().count());
+        (line + 1, column, line_start_utf16 + column)
+    }
+}
+
+/// Rewrites the serialized oxc AST into ESTree, see `Program::to_estree_json`.
+struct EstreeConverter<'a> {
+    spans: SpanConverter<'a>,
+}
+
+impl<'a> EstreeConverter<'a> {
+    fn convert(&self, value: Value) -> Value {
+        match value {
+            Value::Array(values) => {
+                Value::Array(values.into_iter().map(|value| self.convert(value)).collect())
+            }
+            Value::Object(object) => self.convert_object(object),
+            value => value,
+        }
+    }
+
+    fn convert_object(&self, object: Map<String, Value>) -> Value {
+        let mut node = object
+            .into_iter()
+            .map(|(key, value)| (key, self.convert(value)))
+            .collect::<Map<String, Value>>();
+        let Some(Value::String(ty)) = node.get("type").cloned() else {
+            return Value::Object(node);
+        };
+        let (start, end) = match (node.get("start"), node.get("end")) {
+            (Some(Value::Number(start)), Some(Value::Number(end))) => {
+                let to_u32 = |n: &serde_json::Number| {
+                    n.as_u64().and_then(|n| u32::try_from(n).ok()).unwrap_or_default()
+                };
+                (to_u32(start), to_u32(end))
+            }
+            _ => (0, 0),
+        };
+
+        match ty.as_str() {
+            "BindingIdentifier" | "IdentifierReference" | "IdentifierName" | "LabelIdentifier" => {
+                set_type(&mut node, "Identifier");
+                for key in ["referenceId", "referenceFlag", "symbolId"] {
+                    node.remove(key);
+                }
+            }
+            "StaticMemberExpression" => {
+                set_type(&mut node, "MemberExpression");
+                node.insert("computed".into(), Value::Bool(false));
+            }
+            "ComputedMemberExpression" => {
+                set_type(&mut node, "MemberExpression");
+                rename(&mut node, "expression", "property");
+                node.insert("computed".into(), Value::Bool(true));
+            }
+            "PrivateFieldExpression" => {
+                set_type(&mut node, "MemberExpression");
+                rename(&mut node, "field", "property");
+                node.insert("computed".into(), Value::Bool(false));
+            }
+            "BooleanLiteral" | "NumericLiteral" | "StringLiteral" => {
+                self.convert_literal(&mut node, start, end);
+            }
+            "NullLiteral" => {
+                self.convert_literal(&mut node, start, end);
+                node.insert("value".into(), Value::Null);
+            }
+            "BigintLiteral" => {
+                self.convert_literal(&mut node, start, end);
+                let raw = node.get("raw").and_then(Value::as_str).unwrap_or_default();
+                let bigint = raw.trim_end_matches('n').replace('_', "");
+                node.insert("bigint".into(), Value::String(bigint));
+                node.insert("value".into(), Value::Null);
+            }
+            "RegExpLiteral" => {
+                // The `RegExp` value cannot be represented in JSON
+                self.convert_literal(&mut node, start, end);
+                node.insert("value".into(), Value::Null);
+            }
+            "Directive" => set_type(&mut node, "ExpressionStatement"),
+            "Program" => {
+                let is_module = node
+                    .get("sourceType")
+                    .and_then(|source_type| source_type.get("moduleKind"))
+                    .is_some_and(|kind| kind == "module");
+                let source_type = if is_module { "module" } else { "script" };
+                node.insert("sourceType".into(), Value::String(source_type.into()));
+                node.remove("hashbang");
+                prepend_directives(&mut node, "body");
+            }
+            "FunctionBody" => {
+                set_type(&mut node, "BlockStatement");
+                rename(&mut node, "statements", "body");
+                prepend_directives(&mut node, "body");
+            }
+            "BindingPattern" => {
+                // The pattern itself, with the type annotation of TypeScript
+                let Some(Value::Object(mut pattern)) = node.remove("kind") else {
+                    return Value::Null;
+                };
+                match node.remove("typeAnnotation") {
+                    Some(Value::Null) | None => {}
+                    Some(type_annotation) => {
+                        pattern.insert("typeAnnotation".into(), type_annotation);
+                    }
+                }
+                if node.get("optional") == Some(&Value::Bool(true)) {
+                    pattern.insert("optional".into(), Value::Bool(true));
+                }
+                return Value::Object(pattern);
+            }
+            "FormalParameter" => {
+                let pattern = node.remove("pattern").unwrap_or_default();
+                let accessibility = node.remove("accessibility").unwrap_or_default();
+                let readonly = node.remove("readonly").unwrap_or_default();
+                if accessibility.is_null() && readonly != Value::Bool(true) {
+                    return pattern;
+                }
+                node.clear();
+                node.insert("type".into(), Value::String("TSParameterProperty".into()));
+                node.insert("start".into(), start.into());
+                node.insert("end".into(), end.into());
+                node.insert("accessibility".into(), accessibility);
+                node.insert("readonly".into(), readonly);
+                node.insert("parameter".into(), pattern);
+            }
+            "FormalParameters" => {
+                // A list of patterns
+                let mut params = match node.remove("items") {
+                    Some(Value::Array(items)) => items,
+                    _ => vec![],
+                };
+                params.extend(node.remove("rest").filter(|rest| !rest.is_null()));
+                return Value::Array(params);
+            }
+            "BindingRestElement" => set_type(&mut node, "RestElement"),
+            "ArrowFunctionExpression" if node.get("expression") == Some(&Value::Bool(true)) => {
+                // The body is the expression, not a block with an expression statement
+                let expression = node
+                    .get_mut("body")
+                    .and_then(|body| body.get_mut("body"))
+                    .and_then(|statements| statements.get_mut(0))
+                    .and_then(|statement| statement.get_mut("expression"))
+                    .map(Value::take);
+                if let Some(expression) = expression {
+                    node.insert("body".into(), expression);
+                }
+            }
+            _ => {}
+        }
+
+        if node.contains_key("start") {
+            self.add_location(&mut node, start, end);
+        }
+        Value::Object(node)
+    }
+
+    fn convert_literal(&self, node: &mut Map<String, Value>, start: u32, end: u32) {
+        set_type(node, "Literal");
+        let raw = self.spans.source_text.get(start as usize..end as usize).unwrap_or_default();
+        node.insert("raw".into(), Value::String(raw.into()));
+    }
+
+    fn add_location(&self, node: &mut Map<String, Value>, start: u32, end: u32) {
+        let position = |offset| {
+            let (line, column) = self.spans.line_column(offset);
+            serde_json::json!({ "line": line, "column": column })
+        };
+        let (utf16_start, utf16_end) =
+            (self.spans.utf16_offset(start), self.spans.utf16_offset(end));
+        node.insert("start".into(), utf16_start.into());
+        node.insert("end".into(), utf16_end.into());
+        node.insert("range".into(), serde_json::json!([utf16_start, utf16_end]));
+        node.insert(
+            "loc".into(),
+            serde_json::json!({ "start": position(start), "end": position(end) }),
+        );
+    }
+}
+
+fn set_type(node: &mut Map<String, Value>, ty: &str) {
+    node.insert("type".into(), Value::String(ty.into()));
+}
+
+fn rename(node: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(value) = node.remove(from) {
+        node.insert(to.into(), value);
+    }
+}
+
+/// ESTree has the directives as the first statements of `key`.
+fn prepend_directives(node: &mut Map<String, Value>, key: &str) {
+    let Some(Value::Array(mut statements)) = node.remove("directives") else {
+        return;
+    };
+    if let Some(Value::Array(body)) = node.remove(key) {
+        statements.extend(body);
+    }
+    node.insert(key.into(), Value::Array(statements));
 }
 
 impl Serialize for RegExpFlags {
@@ -1,13 +1,23 @@
 //! Trivias such as comments
 
-use oxc_span::Span;
-use std::collections::BTreeMap;
+use oxc_span::{LineEndings, Span};
+use std::{collections::BTreeMap, sync::OnceLock};
 
 /// A vec of trivias from the lexer, tupled by (span.start, span.end).
 #[derive(Debug, Default)]
 pub struct Trivias {
     pub comments: Vec<(u32, u32, CommentKind)>,
     pub irregular_whitespaces: Vec<Span>,
+    /// Computed on request, see `line_endings`
+    line_endings: OnceLock<LineEndings>,
+}
+
+impl Trivias {
+    /// The line breaks of `source_text`, the source text of the trivias, for computing lines and
+    /// columns. Found on the first call, as most consumers do not need them.
+    pub fn line_endings(&self, source_text: &str) -> &LineEndings {
+        self.line_endings.get_or_init(|| LineEndings::new(source_text))
+    }
 }
 
 /// Trivias such as comments
@@ -19,6 +29,7 @@ pub struct TriviasMap {
     /// Keyed by span.start
     comments: BTreeMap<u32, Comment>,
     irregular_whitespaces: Vec<Span>,
+    line_endings: OnceLock<LineEndings>,
 }
 
 impl From<Trivias> for TriviasMap {
@@ -26,6 +37,7 @@ impl From<Trivias> for TriviasMap {
         Self {
             comments: trivias.comments.iter().map(|t| (t.0, Comment::new(t.1, t.2))).collect(),
             irregular_whitespaces: trivias.irregular_whitespaces,
+            line_endings: trivias.line_endings,
         }
     }
 }
@@ -102,4 +114,9 @@ impl TriviasMap {
     pub fn irregular_whitespaces(&self) -> &Vec<Span> {
         &self.irregular_whitespaces
     }
+
+    /// The line breaks of `source_text`, see `Trivias::line_endings`
+    pub fn line_endings(&self, source_text: &str) -> &LineEndings {
+        self.line_endings.get_or_init(|| LineEndings::new(source_text))
+    }
 }
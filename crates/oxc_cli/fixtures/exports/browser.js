@@ -0,0 +1,3 @@
+import main from "browser-main";
+import map from "browser-map";
+import fs from "fs";
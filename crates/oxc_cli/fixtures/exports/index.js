@@ -0,0 +1,3 @@
+import pkg from "pkg";
+import { utils } from "pkg/utils";
+import internal from "#internal/internal";
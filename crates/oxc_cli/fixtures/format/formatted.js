@@ -0,0 +1 @@
+let a = 1;
\ No newline at end of file
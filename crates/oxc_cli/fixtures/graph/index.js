@@ -0,0 +1,5 @@
+import fs from 'fs';
+import { a } from './a';
+import { c } from './c.js';
+
+console.log(fs, a, c);
@@ -0,0 +1,3 @@
+import foo from "./foo.js";
+import native from "rn-pkg";
+import web from "web-pkg";
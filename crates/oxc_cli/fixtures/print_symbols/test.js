@@ -0,0 +1,8 @@
+const foo = 1;
+
+function bar(baz) {
+  foo + baz++;
+  return baz;
+}
+bar(foo, console);
+{ let qux; }
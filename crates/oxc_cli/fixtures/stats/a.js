@@ -0,0 +1,4 @@
+const double = (n) => n * 2;
+const name = user?.name ?? "anonymous";
+const city = user?.address.city;
+module.exports = { double, name, city, fs: require("fs") };
@@ -0,0 +1,8 @@
+interface User {
+  name: string;
+}
+export class Store {
+  #users: User[] = [];
+  async load(): Promise<void> {}
+  find = (name: string) => this.#users.find((user) => user.name === name);
+}
@@ -0,0 +1,5 @@
+/* eslint-disable no-console, no-debugger */
+console.log("a");
+// eslint-disable-next-line no-console
+console.log("b");
+debugger; // eslint-disable-line
@@ -0,0 +1,4 @@
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function log(value: any) {
+  console.log(value); // eslint-disable-line no-console
+}
@@ -0,0 +1,2 @@
+fetch();
+void fetch();
@@ -0,0 +1,212 @@
+//! `oxc_cli ast`: the tree of the AST nodes of a file or of a snippet, with their kinds and spans,
+//! or the list of the nodes matching a selector, for writing rules and `no-restricted-syntax`
+//! selectors.
+
+use std::{fmt::Write, fs, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_linter::selector::Selector;
+use oxc_parser::Parser;
+use oxc_semantic::{AstNode, AstNodeId, Semantic, SemanticBuilder};
+use oxc_span::{GetSpan, SourceType, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{command::AstOptions, result::CliRunResult, Runner};
+
+pub struct AstRunner {
+    options: AstOptions,
+}
+
+impl Runner for AstRunner {
+    type Options = AstOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let AstOptions { selector, code, ext, path } = self.options;
+
+        let selector = match selector.as_deref().map(Selector::parse).transpose() {
+            Ok(selector) => selector,
+            Err(err) => {
+                return CliRunResult::InvalidOptions { message: format!("Invalid selector: {err}") }
+            }
+        };
+        let (source_text, path) = match (code, path) {
+            (Some(code), None) => (code, Path::new("code").with_extension(ext)),
+            (None, Some(path)) => match fs::read_to_string(&path) {
+                Ok(source_text) => (source_text, path),
+                Err(_) => return CliRunResult::PathNotFound { paths: vec![path] },
+            },
+            _ => {
+                let message = "Expected either a PATH or `--code`".to_string();
+                return CliRunResult::InvalidOptions { message };
+            }
+        };
+        let Ok(source_type) = SourceType::from_path(&path) else {
+            let message = format!("{path:?} is not a JavaScript or TypeScript file");
+            return CliRunResult::InvalidOptions { message };
+        };
+
+        match ast_tree(&source_text, source_type, selector.as_ref()) {
+            Ok(output) => {
+                print!("{output}");
+                CliRunResult::None
+            }
+            Err(message) => CliRunResult::InvalidOptions { message },
+        }
+    }
+}
+
+/// The tree of the AST nodes of `source_text`, one node per line written as
+/// `Kind line:column start..end`, followed by the source text of the leaves.
+/// With a `selector`, only the matching nodes are listed, followed by their source text.
+pub fn ast_tree(
+    source_text: &str,
+    source_type: SourceType,
+    selector: Option<&Selector>,
+) -> Result<String, String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if let Some(error) = ret.errors.first() {
+        return Err(format!("The code has syntax errors: {error}"));
+    }
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source_text, source_type)
+        .with_trivias(ret.trivias)
+        .build(program)
+        .semantic;
+
+    let mut printer = AstPrinter::new(&semantic, source_text);
+    match selector {
+        Some(selector) => {
+            let nodes = semantic.nodes();
+            for node in nodes.iter().filter(|node| selector.matches(node, nodes)) {
+                printer.print_node(node, true);
+            }
+        }
+        None => {
+            if let Some(root) = semantic.nodes().iter().next() {
+                printer.print_node(root, false);
+                printer.print_children(root.id(), "");
+            }
+        }
+    }
+    Ok(printer.output)
+}
+
+struct AstPrinter<'p, 'a> {
+    semantic: &'p Semantic<'a>,
+    source_text: &'p str,
+    children: FxHashMap<AstNodeId, Vec<AstNodeId>>,
+    /// The offsets of the lines of the source text
+    line_starts: Vec<usize>,
+    output: String,
+}
+
+impl<'p, 'a> AstPrinter<'p, 'a> {
+    /// Longer source texts are cut
+    const MAX_TEXT_LEN: usize = 60;
+
+    fn new(semantic: &'p Semantic<'a>, source_text: &'p str) -> Self {
+        let nodes = semantic.nodes();
+        let mut children = FxHashMap::<AstNodeId, Vec<AstNodeId>>::default();
+        for node in nodes.iter() {
+            if let Some(parent_id) = nodes.parent_id(node.id()) {
+                children.entry(parent_id).or_default().push(node.id());
+            }
+        }
+        // In source order, as some nodes are visited out of it, e.g. the arguments of calls
+        for node_ids in children.values_mut() {
+            node_ids.sort_by_key(|node_id| nodes.kind(*node_id).span().start);
+        }
+        let line_starts =
+            std::iter::once(0).chain(source_text.match_indices('\n').map(|(i, _)| i + 1)).collect();
+        Self { semantic, source_text, children, line_starts, output: String::new() }
+    }
+
+    fn print_children(&mut self, node_id: AstNodeId, prefix: &str) {
+        let children = self.children.get(&node_id).cloned().unwrap_or_default();
+        for (i, child_id) in children.iter().enumerate() {
+            let is_last = i + 1 == children.len();
+            self.output.push_str(prefix);
+            self.output.push_str(if is_last { "└─ " } else { "├─ " });
+            let node = self.semantic.nodes().get_node(*child_id);
+            let is_leaf = !self.children.contains_key(child_id);
+            self.print_node(node, is_leaf);
+            let prefix = format!("{prefix}{}", if is_last { "   " } else { "│  " });
+            self.print_children(*child_id, &prefix);
+        }
+    }
+
+    fn print_node(&mut self, node: &AstNode<'a>, with_text: bool) {
+        let kind = node.kind();
+        let span = kind.span();
+        let position = self.position(span);
+        _ = write!(self.output, "{:?} {position} {}..{}", kind.ty(), span.start, span.end);
+        if with_text {
+            _ = write!(self.output, " {}", self.text(span));
+        }
+        self.output.push('\n');
+    }
+
+    /// The source text of `span` on one line, between backticks
+    fn text(&self, span: Span) -> String {
+        let text = span.source_text(self.source_text).split_whitespace().collect::<Vec<_>>();
+        let text = text.join(" ");
+        match text.char_indices().nth(Self::MAX_TEXT_LEN) {
+            Some((end, _)) => format!("`{}…`", &text[..end]),
+            None => format!("`{text}`"),
+        }
+    }
+
+    /// The line and column of the start of `span`, starting at 1
+    fn position(&self, span: Span) -> String {
+        let offset = span.start as usize;
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        format!("{line}:{}", offset - self.line_starts[line - 1] + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_linter::selector::Selector;
+    use oxc_span::SourceType;
+
+    use super::ast_tree;
+
+    #[test]
+    fn tree() {
+        let tree = ast_tree("foo(1);", SourceType::default(), None).unwrap();
+        let expected = "\
+Program 1:1 0..7
+└─ ExpressionStatement 1:1 0..7
+   └─ CallExpression 1:1 0..6
+      ├─ IdentifierReference 1:1 0..3 `foo`
+      └─ Argument 1:5 4..5
+         └─ NumericLiteral 1:5 4..5 `1`
+";
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn selector() {
+        let source_text = "foo(1);\nbar(\n  'a',\n);";
+        let selector = Selector::parse("CallExpression > Literal").unwrap();
+        let tree = ast_tree(source_text, SourceType::default(), Some(&selector)).unwrap();
+        assert_eq!(tree, "NumericLiteral 1:5 4..5 `1`\nStringLiteral 3:3 15..18 `'a'`\n");
+
+        let selector = Selector::parse("CallExpression").unwrap();
+        let tree = ast_tree(source_text, SourceType::default(), Some(&selector)).unwrap();
+        assert_eq!(
+            tree,
+            "CallExpression 1:1 0..6 `foo(1)`\nCallExpression 2:1 8..21 `bar( 'a', )`\n"
+        );
+    }
+
+    #[test]
+    fn syntax_error() {
+        assert!(ast_tree("foo(", SourceType::default(), None).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct AstOptions {
+    /// Print only the nodes matching the esquery SELECTOR, e.g. `CallExpression[callee.name=foo]`,
+    /// see the `no-restricted-syntax` rule for the supported selectors
+    #[bpaf(argument("SELECTOR"))]
+    pub selector: Option<String>,
+
+    /// The code to print the AST of, instead of a file
+    #[bpaf(argument("CODE"))]
+    pub code: Option<String>,
+
+    /// The extension of the language of `--code`, e.g. `ts` or `tsx`, `jsx` by default
+    #[bpaf(argument("EXT"), fallback("jsx".into()))]
+    pub ext: String,
+
+    /// The file to print the AST of
+    #[bpaf(positional("PATH"))]
+    pub path: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod ast_options {
+    use std::path::PathBuf;
+
+    use bpaf::Parser;
+
+    use super::{ast_options, AstOptions};
+
+    fn get_ast_options(args: &[&str]) -> AstOptions {
+        ast_options().to_options().run_inner(args).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let options = get_ast_options(&["src/index.js"]);
+        assert_eq!(options.path, Some(PathBuf::from("src/index.js")));
+        assert!(options.selector.is_none());
+        assert!(options.code.is_none());
+        assert_eq!(options.ext, "jsx");
+    }
+
+    #[test]
+    fn code() {
+        let options = get_ast_options(&["--code", "let a: number = 1", "--ext", "ts"]);
+        assert_eq!(options.code.as_deref(), Some("let a: number = 1"));
+        assert_eq!(options.ext, "ts");
+        assert!(options.path.is_none());
+    }
+
+    #[test]
+    fn selector() {
+        let options = get_ast_options(&["--selector", "CallExpression > Literal", "a.js"]);
+        assert_eq!(options.selector.as_deref(), Some("CallExpression > Literal"));
+    }
+}
@@ -29,6 +29,14 @@ pub struct FormatOptions {
     #[bpaf(external)]
     pub ignore_options: IgnoreOptions,
 
+    /// Write the formatted code back to the files
+    #[bpaf(switch)]
+    pub write: bool,
+
+    /// List the files which are not formatted, exit with an error code if there are any
+    #[bpaf(switch)]
+    pub check: bool,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many)]
     pub paths: Vec<PathBuf>,
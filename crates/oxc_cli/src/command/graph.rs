@@ -0,0 +1,92 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::ignore::{ignore_options, IgnoreOptions};
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct GraphOptions {
+    /// Output the graph as JSON instead of DOT
+    // last flag is the default
+    #[bpaf(long("json"), flag(GraphFormat::Json, GraphFormat::Dot))]
+    pub format: GraphFormat,
+
+    /// TypeScript `tsconfig.json` path for reading path alias and project references,
+    /// defaults to the `tsconfig.json` of the current working directory
+    #[bpaf(argument("PATH"))]
+    pub tsconfig: Option<PathBuf>,
+
+    /// Custom condition of package.json `exports` and `imports`,
+    /// in addition to `import`, `require`, `module`, `node` and `types`.
+    /// `browser` also enables the package.json `browser` field
+    #[bpaf(long("condition"), argument("NAME"), many)]
+    pub conditions: Vec<String>,
+
+    /// Resolve imports to the location of symlinks instead of their real path
+    #[bpaf(switch)]
+    pub preserve_symlinks: bool,
+
+    /// Print the source files of the current working directory which are not reachable
+    /// from any of the entries, instead of the graph
+    #[bpaf(switch)]
+    pub unused: bool,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    /// The modules to start from
+    #[bpaf(positional("ENTRY"), some("At least one entry module is required"))]
+    pub entries: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GraphFormat {
+    Dot,
+    Json,
+}
+
+#[cfg(test)]
+mod graph_options {
+    use std::path::PathBuf;
+
+    use bpaf::Parser;
+
+    use super::{graph_options, GraphFormat, GraphOptions};
+
+    fn get_graph_options(arg: &str) -> GraphOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        graph_options().to_options().run_inner(args.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let options = get_graph_options("src/index.js");
+        assert_eq!(options.entries, [PathBuf::from("src/index.js")]);
+        assert!(!options.unused);
+        assert_eq!(options.format, GraphFormat::Dot);
+        assert!(options.tsconfig.is_none());
+        assert!(options.conditions.is_empty());
+    }
+
+    #[test]
+    fn json() {
+        let options = get_graph_options("--json src/index.js");
+        assert_eq!(options.format, GraphFormat::Json);
+    }
+
+    #[test]
+    fn unused() {
+        let options = get_graph_options("--unused src/index.js src/worker.js");
+        assert!(options.unused);
+        assert_eq!(
+            options.entries,
+            [PathBuf::from("src/index.js"), PathBuf::from("src/worker.js")]
+        );
+    }
+
+    #[test]
+    fn conditions() {
+        let options = get_graph_options("--condition custom --condition worker src/index.js");
+        assert_eq!(options.conditions, ["custom", "worker"]);
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
 use oxc_linter::AllowWarnDeny;
@@ -29,6 +29,11 @@ pub struct LintOptions {
     #[bpaf(external(lint_filter), map(LintFilter::into_tuple), many)]
     pub filter: Vec<(AllowWarnDeny, String)>,
 
+    /// Include the nursery rules, which are still under development, in `-D nursery` and `-D all`.
+    /// Without it, they are only enabled by name
+    #[bpaf(switch, hide_usage)]
+    pub nursery: bool,
+
     #[bpaf(external)]
     pub enable_plugins: EnablePlugins,
 
@@ -44,23 +49,135 @@ pub struct LintOptions {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub git_options: GitOptions,
+
     /// list all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Print metrics of the files found in PATH as JSON instead of linting them:
+    /// the number of files, lines, functions and classes, the share of TypeScript,
+    /// and how many files use each syntax feature
+    #[bpaf(switch, hide_usage)]
+    pub stats: bool,
+
+    /// Print the `eslint-disable` comments of the files found in PATH as JSON instead of linting them,
+    /// counted by rule for all files and for each file
+    #[bpaf(switch, hide_usage)]
+    pub suppressions: bool,
+
+    /// Print the configuration resolved for the given file as JSON, then exit
+    #[bpaf(long("print-config"), argument("PATH"), hide_usage)]
+    pub print_config: Option<PathBuf>,
+
+    /// Print the scopes, symbols and references of the given file as a tree, then exit.
+    /// For debugging unexpected `no-unused-vars` or `no-undef` diagnostics
+    #[bpaf(long("print-symbols"), argument("PATH"), hide_usage)]
+    pub print_symbols: Option<PathBuf>,
+
+    /// Keep running and answer JSON-RPC lint requests on a Unix domain socket only accessible to
+    /// the current user, instead of linting PATH. Not supported on Windows
+    #[bpaf(switch, hide_usage)]
+    pub daemon: bool,
+
+    /// The socket `--daemon` listens on, created with the permissions 0600.
+    /// Defaults to `.oxlint_daemon/daemon.sock`, in a directory with the permissions 0700
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub daemon_socket: Option<PathBuf>,
+
+    /// Keep running and lint the files again when they change, only their diagnostics are reported.
+    /// Implies `--cache`
+    #[bpaf(switch, hide_usage)]
+    pub watch: bool,
+
+    /// Store the diagnostics of each file in `.oxlint_cache`, and report the unchanged files from it
+    /// instead of linting them again. Not used with `--fix` nor with the import plugin
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Report `eslint-disable` comments which did not suppress any diagnostic
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
+
+    /// Print the time spent in parsing, semantic analysis and linting,
+    /// and in the slowest rules with the number of diagnostics they reported
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Print the arena memory used by all files and by the largest ones, and the peak resident memory
+    #[bpaf(switch, hide_usage)]
+    pub memory: bool,
+
+    /// Lint the files larger than BYTES one at a time and free their memory right after,
+    /// to bound the memory used by large files. Not used with the import plugin
+    #[bpaf(argument("BYTES"), hide_usage)]
+    pub low_memory_threshold: Option<usize>,
+
+    /// Skip the files larger than BYTES, with an informational diagnostic instead of linting them
+    #[bpaf(argument("BYTES"), hide_usage)]
+    pub max_file_size: Option<usize>,
+
+    /// Skip the files with more than COUNT AST nodes, with an informational diagnostic
+    #[bpaf(argument("COUNT"), hide_usage)]
+    pub max_node_count: Option<usize>,
+
+    /// Skip the files with AST nodes nested deeper than DEPTH, with an informational diagnostic
+    #[bpaf(argument("DEPTH"), hide_usage)]
+    pub max_nesting_depth: Option<usize>,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
     /// ESLint configuration file (experimental)
     ///
-    /// * only `.json` extension is supported
+    /// * `.json` files, with comments and trailing commas, and `.json5` files are supported
+    /// * without it, `.oxlintrc.json` or `.eslintrc.json` files are looked up from each linted file upward
+    ///   and merged, a rule configured with only a severity in a nested file keeps its options
     #[bpaf(long, short, argument("PATH"))]
     pub config: Option<PathBuf>,
 
-    /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin
+    /// Load the plugin libraries and the JavaScript plugins listed in the `plugins` of the
+    /// configuration files. They run arbitrary code, so only pass it for trusted configurations
+    #[bpaf(switch, hide_usage)]
+    pub allow_plugins: bool,
+
+    /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin,
+    /// defaults to the `tsconfig.json` of the current working directory.
+    /// In the root of a npm, yarn or pnpm workspace, each package defaults to its own `tsconfig.json`
     #[bpaf(argument("PATH"))]
     pub tsconfig: Option<PathBuf>,
 
+    /// JSON file with the types of expressions, e.g. dumped with the TypeScript compiler API,
+    /// for type-aware rules such as `no-floating-promises`
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub type_info: Option<PathBuf>,
+
+    /// Custom condition of package.json `exports` and `imports` for the import plugin,
+    /// in addition to `import`, `require`, `module`, `node` and `types`.
+    /// `browser` also enables the package.json `browser` field
+    #[bpaf(long("condition"), argument("NAME"), many, hide_usage)]
+    pub conditions: Vec<String>,
+
+    /// Resolve imports to the location of symlinks instead of their real path
+    #[bpaf(switch, hide_usage)]
+    pub preserve_symlinks: bool,
+
+    /// Also lint the `js`, `jsx`, `ts` and `tsx` fenced code blocks of Markdown and MDX files
+    #[bpaf(switch, hide_usage)]
+    pub markdown: bool,
+
+    /// Also lint JSON, JSONC and JSON5 files for syntax errors and duplicate keys,
+    /// and `package.json`, `tsconfig.json` and configuration files for values of the wrong type
+    #[bpaf(switch, hide_usage)]
+    pub json: bool,
+
+    /// Memory map the files instead of reading them, which saves a copy of each file on large repositories.
+    /// Not used with `--fix`. The files must not change while oxlint runs: a file truncated by another
+    /// process crashes oxlint, and a file modified by another process is undefined behavior
+    #[bpaf(switch, hide_usage)]
+    pub mmap: bool,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
@@ -90,7 +207,7 @@ const PATHS_ERROR_MESSAGE: &str = "PATH cannot start with \"..\"";
 ///  * suspicious  - code that is most likely wrong or useless
 ///  * pedantic    - lints which are rather strict or have occasional false positives
 ///  * style       - code that should be written in a more idiomatic way
-///  * nursery     - new lints that are still under development
+///  * nursery     - new lints that are still under development, requires "--nursery"
 ///  * restriction - lints which prevent the use of language and library features
 ///  * all         - all the categories listed above
 #[derive(Debug, Clone, Bpaf)]
@@ -122,6 +239,22 @@ pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Print the fixes as a unified diff instead of writing them to the files
+    #[bpaf(switch, hide_usage)]
+    pub fix_dry_run: bool,
+
+    /// Print the fixed code of a single file instead of writing it, nothing else is printed
+    #[bpaf(switch, hide_usage)]
+    pub fix_to_stdout: bool,
+
+    /// Also apply the fixes which may change the behavior of the code, implies `--fix`
+    #[bpaf(switch, hide_usage)]
+    pub fix_suggestions: bool,
+
+    /// Also apply the fixes which may break the code, implies `--fix-suggestions`
+    #[bpaf(switch, hide_usage)]
+    pub fix_dangerously: bool,
 }
 
 /// Handle Warnings
@@ -144,16 +277,113 @@ pub struct WarningOptions {
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json)
-    // last flag is the default
-    #[bpaf(long, short, flag(OutputFormat::Json, OutputFormat::Default))]
+    /// Use a specific output format (default, json, sarif, github).
+    /// Repeat it to use several reporters at once, each one followed by `:stderr` or `:PATH`
+    /// to write it to stderr or to a file instead, e.g. `-f default:stderr -f sarif:oxlint.sarif`
+    #[bpaf(long, short, argument("FORMAT"), many)]
+    pub format: Vec<Reporter>,
+
+    /// Write the output of the formats which are not followed by a destination to FILE
+    #[bpaf(argument("FILE"), hide_usage)]
+    pub output_file: Option<PathBuf>,
+
+    /// Write a summary of the run to FILE as JSON: the version, the hash of the configuration,
+    /// the duration and the numbers of files, rules, warnings and errors
+    #[bpaf(argument("FILE"), hide_usage)]
+    pub metadata_file: Option<PathBuf>,
+}
+
+impl OutputOptions {
+    /// The reporters to use, the default format to stdout or to `--output-file` without `--format`
+    pub fn reporters(&self) -> Vec<Reporter> {
+        let default_output =
+            self.output_file.clone().map_or(ReportOutput::Stdout, ReportOutput::File);
+        if self.format.is_empty() {
+            return vec![Reporter { format: OutputFormat::Default, output: default_output }];
+        }
+        self.format
+            .iter()
+            .map(|reporter| match reporter.output {
+                ReportOutput::Default => {
+                    Reporter { output: default_output.clone(), ..reporter.clone() }
+                }
+                _ => reporter.clone(),
+            })
+            .collect()
+    }
+}
+
+/// An output format and its destination, `FORMAT[:stdout|:stderr|:PATH]`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Reporter {
     pub format: OutputFormat,
+    pub output: ReportOutput,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ReportOutput {
+    /// `--output-file` if given, stdout otherwise
+    Default,
+    Stdout,
+    Stderr,
+    File(PathBuf),
+}
+
+impl FromStr for Reporter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (format, output) = match s.split_once(':') {
+            Some((format, "stdout")) => (format, ReportOutput::Stdout),
+            Some((format, "stderr")) => (format, ReportOutput::Stderr),
+            Some((_, "")) => return Err(format!("'{s}' is missing a destination after ':'")),
+            Some((format, path)) => (format, ReportOutput::File(PathBuf::from(path))),
+            None => (s, ReportOutput::Default),
+        };
+        Ok(Self { format: format.parse()?, output })
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum OutputFormat {
     Default,
+    /// The diagnostics with their rule, severity, file, position, message and help
     Json,
+    /// SARIF 2.1.0 for code scanning tools
+    Sarif,
+    /// Workflow commands annotating GitHub pull requests
+    Github,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "github" => Ok(Self::Github),
+            _ => Err(format!("'{s}' is not a known format, use default, json, sarif or github")),
+        }
+    }
+}
+
+/// Git
+#[derive(Debug, Clone, Bpaf)]
+pub struct GitOptions {
+    /// Only lint the files changed since REF, e.g. `main` or `HEAD~1`,
+    /// including the uncommitted and untracked files
+    #[bpaf(argument("REF"), hide_usage)]
+    pub changed: Option<String>,
+
+    /// Only lint the files staged for the next commit
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
+
+    /// With `--changed` or `--staged`, only report the diagnostics on the added or modified lines
+    #[bpaf(switch, hide_usage)]
+    pub changed_lines: bool,
 }
 
 /// Enable Plugins
@@ -176,9 +406,21 @@ pub struct EnablePlugins {
     #[bpaf(switch, hide_usage)]
     pub nextjs_plugin: bool,
 
+    /// Enable the promise plugin and detect promise usage problems
+    #[bpaf(switch, hide_usage)]
+    pub promise_plugin: bool,
+
     /// Enable the React performance plugin and detect rendering performance problems
     #[bpaf(switch, hide_usage)]
     pub react_perf_plugin: bool,
+
+    /// Enable the security plugin and detect injection sinks
+    #[bpaf(switch, hide_usage)]
+    pub security_plugin: bool,
+
+    /// Enable the JSDoc plugin and detect JSDoc problems
+    #[bpaf(switch, hide_usage)]
+    pub jsdoc_plugin: bool,
 }
 
 #[cfg(test)]
@@ -216,7 +458,7 @@ mod lint_options {
 
     use oxc_linter::AllowWarnDeny;
 
-    use super::{lint_command, LintOptions, OutputFormat};
+    use super::{lint_command, LintOptions, OutputFormat, ReportOutput, Reporter};
 
     fn get_lint_options(arg: &str) -> LintOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -229,7 +471,10 @@ mod lint_options {
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix_options.fix);
         assert!(!options.list_rules);
-        assert_eq!(options.output_options.format, OutputFormat::Default);
+        assert_eq!(
+            options.output_options.reporters(),
+            [Reporter { format: OutputFormat::Default, output: ReportOutput::Stdout }]
+        );
     }
 
     #[test]
@@ -260,6 +505,35 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(!options.fix_options.fix);
+        assert!(options.fix_options.fix_dry_run);
+        assert!(!options.fix_options.fix_to_stdout);
+    }
+
+    #[test]
+    fn fix_to_stdout() {
+        let options = get_lint_options("--fix-to-stdout test.js");
+        assert!(options.fix_options.fix_to_stdout);
+    }
+
+    #[test]
+    fn fix_suggestions() {
+        let options = get_lint_options("--fix-suggestions test.js");
+        assert!(!options.fix_options.fix);
+        assert!(options.fix_options.fix_suggestions);
+        assert!(!options.fix_options.fix_dangerously);
+    }
+
+    #[test]
+    fn fix_dangerously() {
+        let options = get_lint_options("--fix-dry-run --fix-dangerously test.js");
+        assert!(options.fix_options.fix_dry_run);
+        assert!(options.fix_options.fix_dangerously);
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -277,8 +551,87 @@ mod lint_options {
 
     #[test]
     fn format() {
-        let options = get_lint_options("-f json");
-        assert_eq!(options.output_options.format, OutputFormat::Json);
+        let format = |arg: &str| {
+            let reporters = get_lint_options(arg).output_options.reporters();
+            reporters.into_iter().map(|reporter| reporter.format).collect::<Vec<_>>()
+        };
+        assert_eq!(format("-f json"), [OutputFormat::Json]);
+        assert_eq!(format("--format sarif ."), [OutputFormat::Sarif]);
+        assert_eq!(get_lint_options("--format sarif .").paths, [PathBuf::from(".")]);
+        assert_eq!(format("--format=github"), [OutputFormat::Github]);
+        assert!(lint_command().run_inner(&["--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn multiple_reporters() {
+        let options = get_lint_options("-f default:stderr -f sarif:out/oxlint.sarif -f json");
+        assert_eq!(
+            options.output_options.reporters(),
+            [
+                Reporter { format: OutputFormat::Default, output: ReportOutput::Stderr },
+                Reporter {
+                    format: OutputFormat::Sarif,
+                    output: ReportOutput::File(PathBuf::from("out/oxlint.sarif"))
+                },
+                Reporter { format: OutputFormat::Json, output: ReportOutput::Stdout },
+            ]
+        );
+        assert!(lint_command().run_inner(&["--format", "json:"]).is_err());
+        assert!(lint_command().run_inner(&["--format", "xml:stderr"]).is_err());
+    }
+
+    #[test]
+    fn output_file() {
+        let options = get_lint_options("--output-file oxlint.json -f json -f github:stdout");
+        assert_eq!(
+            options.output_options.reporters(),
+            [
+                Reporter {
+                    format: OutputFormat::Json,
+                    output: ReportOutput::File(PathBuf::from("oxlint.json"))
+                },
+                Reporter { format: OutputFormat::Github, output: ReportOutput::Stdout },
+            ]
+        );
+        let options = get_lint_options("--output-file report.txt");
+        assert_eq!(
+            options.output_options.reporters(),
+            [Reporter {
+                format: OutputFormat::Default,
+                output: ReportOutput::File(PathBuf::from("report.txt"))
+            }]
+        );
+    }
+
+    #[test]
+    fn metadata_file() {
+        let options = get_lint_options("--metadata-file out/oxlint-run.json .");
+        assert_eq!(
+            options.output_options.metadata_file,
+            Some(PathBuf::from("out/oxlint-run.json"))
+        );
+    }
+
+    #[test]
+    fn git() {
+        let options = get_lint_options(".");
+        assert!(options.git_options.changed.is_none());
+        assert!(!options.git_options.staged);
+        let options = get_lint_options("--changed main --changed-lines .");
+        assert_eq!(options.git_options.changed.as_deref(), Some("main"));
+        assert!(options.git_options.changed_lines);
+        let options = get_lint_options("--staged");
+        assert!(options.git_options.staged);
+    }
+
+    #[test]
+    fn watch() {
+        let options = get_lint_options(".");
+        assert!(!options.watch);
+        assert!(!options.cache);
+        let options = get_lint_options("--watch --cache src");
+        assert!(options.watch);
+        assert!(options.cache);
     }
 
     #[test]
@@ -286,4 +639,72 @@ mod lint_options {
         let options = get_lint_options("--rules");
         assert!(options.list_rules);
     }
+
+    #[test]
+    fn stats() {
+        let options = get_lint_options("--stats src");
+        assert!(options.stats);
+        assert_eq!(options.paths, [PathBuf::from("src")]);
+    }
+
+    #[test]
+    fn suppressions() {
+        let options = get_lint_options("--suppressions src");
+        assert!(options.suppressions);
+        assert_eq!(options.paths, [PathBuf::from("src")]);
+    }
+
+    #[test]
+    fn print_config() {
+        let options = get_lint_options("--print-config src/index.js");
+        assert_eq!(options.print_config, Some(PathBuf::from("src/index.js")));
+        assert!(options.paths.is_empty());
+    }
+
+    #[test]
+    fn print_symbols() {
+        let options = get_lint_options("--print-symbols src/index.js");
+        assert_eq!(options.print_symbols, Some(PathBuf::from("src/index.js")));
+        assert!(options.paths.is_empty());
+    }
+
+    #[test]
+    fn nursery() {
+        let options = get_lint_options(".");
+        assert!(!options.nursery);
+        let options = get_lint_options("--nursery -D nursery .");
+        assert!(options.nursery);
+    }
+
+    #[test]
+    fn timing() {
+        let options = get_lint_options(".");
+        assert!(!options.timing);
+        let options = get_lint_options("--timing .");
+        assert!(options.timing);
+    }
+
+    #[test]
+    fn memory() {
+        let options = get_lint_options(".");
+        assert!(!options.memory);
+        assert!(options.low_memory_threshold.is_none());
+        let options = get_lint_options("--memory --low-memory-threshold 1000000 .");
+        assert!(options.memory);
+        assert_eq!(options.low_memory_threshold, Some(1_000_000));
+    }
+
+    #[test]
+    fn input_limits() {
+        let options = get_lint_options(".");
+        assert!(options.max_file_size.is_none());
+        assert!(options.max_node_count.is_none());
+        assert!(options.max_nesting_depth.is_none());
+        let options = get_lint_options(
+            "--max-file-size 1000000 --max-node-count 50000 --max-nesting-depth 500 .",
+        );
+        assert_eq!(options.max_file_size, Some(1_000_000));
+        assert_eq!(options.max_node_count, Some(50_000));
+        assert_eq!(options.max_nesting_depth, Some(500));
+    }
 }
@@ -1,18 +1,30 @@
+mod ast;
 mod format;
+mod graph;
 mod ignore;
 mod lint;
+mod reduce;
 
 use bpaf::Bpaf;
 
 pub use self::{
+    ast::AstOptions,
     format::{format_command, FormatOptions},
+    graph::{GraphFormat, GraphOptions},
     ignore::IgnoreOptions,
-    lint::{lint_command, LintOptions, OutputFormat, OutputOptions, WarningOptions},
+    lint::{
+        lint_command, GitOptions, LintOptions, OutputFormat, OutputOptions, ReportOutput, Reporter,
+        WarningOptions,
+    },
+    reduce::ReduceOptions,
 };
 
-use self::{format::format_options, lint::lint_options};
+use self::{
+    ast::ast_options, format::format_options, graph::graph_options, lint::lint_options,
+    reduce::reduce_options,
+};
 
-const VERSION: &str = match option_env!("OXC_VERSION") {
+pub(crate) const VERSION: &str = match option_env!("OXC_VERSION") {
     Some(v) => v,
     None => "dev",
 };
@@ -27,6 +39,18 @@ pub enum CliCommand {
     /// Format this repository
     #[bpaf(command)]
     Format(#[bpaf(external(format_options))] FormatOptions),
+
+    /// Print the import graph of modules, and report circular dependencies or unused files
+    #[bpaf(command)]
+    Graph(#[bpaf(external(graph_options))] GraphOptions),
+
+    /// Shrink a file to a minimal snippet which still crashes or still reports a diagnostic, for bug reports
+    #[bpaf(command)]
+    Reduce(#[bpaf(external(reduce_options))] ReduceOptions),
+
+    /// Print the AST of a file or of a snippet, or only the nodes matching a selector, for rule authors
+    #[bpaf(command)]
+    Ast(#[bpaf(external(ast_options))] AstOptions),
 }
 
 impl CliCommand {
@@ -38,6 +62,7 @@ impl CliCommand {
             Self::Format(options) => {
                 Self::set_rayon_threads(options.misc_options.threads);
             }
+            Self::Graph(_) | Self::Reduce(_) | Self::Ast(_) => {}
         }
     }
 
@@ -51,9 +76,15 @@ impl CliCommand {
 /// Miscellaneous
 #[derive(Debug, Clone, Bpaf)]
 pub struct MiscOptions {
-    /// Number of threads to use. Set to 1 for using only 1 CPU core
+    /// Number of threads to use, e.g. to limit the CPU cores used in CI. Set to 1 for using only 1 CPU core.
+    /// The output of the linter is the same with any number of threads
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
+
+    /// Print debug logs to stderr, e.g. why a file was skipped and the time spent in each phase.
+    /// `OXC_LOG` takes precedence, e.g. `OXC_LOG=oxc_linter=trace`, and `OXC_LOG_FORMAT=json` prints JSON lines
+    #[bpaf(switch, hide_usage)]
+    pub verbose: bool,
 }
 
 #[cfg(test)]
@@ -77,4 +108,10 @@ mod misc_options {
         let options = get_misc_options("--threads 4 .");
         assert_eq!(options.threads, Some(4));
     }
+
+    #[test]
+    fn verbose() {
+        assert!(!get_misc_options(".").verbose);
+        assert!(get_misc_options("--verbose .").verbose);
+    }
 }
@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct ReduceOptions {
+    /// Keep the code reporting a diagnostic whose message contains TEXT, with all the rules enabled,
+    /// instead of the code crashing the parser, the semantic analysis or the linter
+    #[bpaf(argument("TEXT"))]
+    pub diagnostic: Option<String>,
+
+    /// Write the reduced code to PATH instead of printing it
+    #[bpaf(long, short, argument("PATH"))]
+    pub output: Option<PathBuf>,
+
+    /// The file to reduce
+    #[bpaf(positional("PATH"))]
+    pub path: PathBuf,
+}
+
+#[cfg(test)]
+mod reduce_options {
+    use std::path::PathBuf;
+
+    use bpaf::Parser;
+
+    use super::{reduce_options, ReduceOptions};
+
+    fn get_reduce_options(arg: &str) -> ReduceOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        reduce_options().to_options().run_inner(args.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let options = get_reduce_options("crash.js");
+        assert_eq!(options.path, PathBuf::from("crash.js"));
+        assert!(options.diagnostic.is_none());
+        assert!(options.output.is_none());
+    }
+
+    #[test]
+    fn diagnostic() {
+        let options = get_reduce_options("--diagnostic no-debugger -o reduced.js big.js");
+        assert_eq!(options.diagnostic.as_deref(), Some("no-debugger"));
+        assert_eq!(options.output, Some(PathBuf::from("reduced.js")));
+        assert_eq!(options.path, PathBuf::from("big.js"));
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{fs, path::Path};
 
 use oxc_allocator::Allocator;
 use oxc_parser::Parser;
@@ -25,7 +25,7 @@ impl Runner for FormatRunner {
     }
 
     fn run(self) -> CliRunResult {
-        let FormatOptions { paths, ignore_options, .. } = &self.options;
+        let FormatOptions { paths, ignore_options, write, check, .. } = &self.options;
 
         if paths.is_empty() {
             return CliRunResult::InvalidOptions { message: "No paths are provided.".to_string() };
@@ -35,24 +35,89 @@ impl Runner for FormatRunner {
 
         let paths = Walk::new(paths, ignore_options).paths();
 
-        paths.par_iter().for_each(|path| {
-            Self::format(path);
-        });
+        let statuses = paths
+            .par_iter()
+            .map(|path| {
+                let status = Self::format(path, *write);
+                if *check && status == FormatStatus::Changed {
+                    println!("{}", path.display());
+                }
+                status
+            })
+            .collect::<Vec<_>>();
 
+        let count = |status| statuses.iter().filter(|s| **s == status).count();
         CliRunResult::FormatResult(FormatResult {
             duration: now.elapsed(),
             number_of_files: paths.len(),
+            number_of_unformatted_files: count(FormatStatus::Changed),
+            number_of_errors: count(FormatStatus::Failed),
+            check: *check,
         })
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatStatus {
+    Unchanged,
+    Changed,
+    Failed,
+}
+
 impl FormatRunner {
-    fn format(path: &Path) {
-        let source_text = std::fs::read_to_string(path).unwrap();
+    fn format(path: &Path, write: bool) -> FormatStatus {
+        let Ok(source_text) = fs::read_to_string(path) else {
+            eprintln!("Failed to read {}", path.display());
+            return FormatStatus::Failed;
+        };
+        let Ok(source_type) = SourceType::from_path(path) else {
+            return FormatStatus::Failed;
+        };
         let allocator = Allocator::default();
-        let source_type = SourceType::from_path(path).unwrap();
         let ret = Parser::new(&allocator, &source_text, source_type).preserve_parens(false).parse();
-        let _ = Prettier::new(&allocator, &source_text, ret.trivias, PrettierOptions::default())
-            .build(&ret.program);
+        if !ret.errors.is_empty() {
+            eprintln!("Failed to format {} because of syntax errors", path.display());
+            return FormatStatus::Failed;
+        }
+        let formatted =
+            Prettier::new(&allocator, &source_text, ret.trivias, PrettierOptions::default())
+                .build(&ret.program);
+        if formatted == source_text {
+            return FormatStatus::Unchanged;
+        }
+        if write && fs::write(path, formatted).is_err() {
+            eprintln!("Failed to write {}", path.display());
+            return FormatStatus::Failed;
+        }
+        FormatStatus::Changed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FormatRunner;
+    use crate::{format_command, result::FormatResult, CliRunResult, Runner};
+
+    fn test(args: &[&str]) -> FormatResult {
+        let options = format_command().run_inner(args).unwrap().format_options;
+        match FormatRunner::new(options).run() {
+            CliRunResult::FormatResult(result) => result,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn check() {
+        let result = test(&["--check", "fixtures/format"]);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_unformatted_files, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn check_formatted() {
+        let result = test(&["--check", "fixtures/format/formatted.js"]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_unformatted_files, 0);
     }
 }
@@ -1,66 +1,192 @@
-use std::path::Path;
-
-use git2::Repository;
-use miette::Diagnostic;
-use oxc_diagnostics::{thiserror::Error, Error};
-
-#[derive(Debug, Error, Diagnostic)]
-#[error("No repository found")]
-#[diagnostic(severity(warning), help("Ensure target path(s) belong to a Git repository"))]
-struct NoRepositoryFound;
-
-#[derive(Debug, Error, Diagnostic)]
-#[error("Multiple repositories found")]
-#[diagnostic(severity(warning), help("Ensure all paths belong to a single repository"))]
-struct MultipleRepositoriesFound;
-
-#[derive(Debug, Error, Diagnostic)]
-#[error("Uncommitted changes")]
-#[diagnostic(severity(warning), help("Commit any changes before linting"))]
-struct UncommittedChanges;
-
-pub struct Git<'a> {
-    // paths: &'a Vec<Box<Path>>,
-    repos: Vec<Repository>,
+//! The files and lines changed in the Git repository of the current directory,
+//! for `--changed` and `--staged`.
+
+use std::{
+    ops::Range,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use rustc_hash::FxHashMap;
+
+/// What the files are compared to.
+#[derive(Debug, Clone, Copy)]
+pub enum GitDiff<'a> {
+    /// The working tree, including untracked files, compared to a commit, branch or tag
+    Since(&'a str),
+    /// The index compared to `HEAD`
+    Staged,
 }
 
-impl<'a> Git<'a> {
-    pub fn new() -> Self {
-        // let repos: Vec<Repository> =
-        // paths.iter().filter_map(|path| Repository::discover(path).ok()).collect();
-        Self { repos }
-    }
+#[derive(Debug, Default)]
+pub struct ChangedFiles {
+    /// The added or modified lines of each file, 1-based, keyed by canonicalized path.
+    /// `None` for untracked files, all their lines are new.
+    files: FxHashMap<PathBuf, Option<Vec<Range<usize>>>>,
+}
 
-    pub fn verify(&'a self) -> Result<&'a Repository, Error> {
-        if self.repos.is_empty() {
-            return Err(NoRepositoryFound.into());
+impl ChangedFiles {
+    /// # Errors
+    ///
+    /// Returns the error output of Git when it fails, e.g. outside of a repository or with an unknown ref,
+    /// and an error for a ref starting with `-`.
+    pub fn new(cwd: &Path, diff: GitDiff) -> Result<Self, String> {
+        // It would be taken as an option of `git diff`
+        if let GitDiff::Since(reference) = diff {
+            if reference.starts_with('-') {
+                return Err(format!("Invalid Git ref: {reference}"));
+            }
+        }
+        let root = PathBuf::from(git(cwd, &["rev-parse", "--show-toplevel"])?.trim_end());
+        // Canonicalized as the paths are looked up, Git prints `C:/repo` on Windows
+        let root = root
+            .canonicalize()
+            .map_err(|err| format!("Failed to read {}: {err}", root.display()))?;
+        let mut args = vec![
+            "-c",
+            "core.quotePath=off",
+            "diff",
+            "--no-color",
+            "--no-ext-diff",
+            "--unified=0",
+            "--diff-filter=ACMR",
+        ];
+        match diff {
+            GitDiff::Since(reference) => args.extend([reference, "--"]),
+            GitDiff::Staged => args.push("--cached"),
+        }
+        let mut changed_files = Self::parse_diff(&root, &git(&root, &args)?);
+        if matches!(diff, GitDiff::Since(_)) {
+            let untracked = git(&root, &["ls-files", "--others", "--exclude-standard", "-z"])?;
+            let untracked = untracked.split('\0').filter(|path| !path.is_empty());
+            changed_files.files.extend(untracked.map(|path| (root.join(path), None)));
         }
-        match self.is_same_repo() {
-            Ok(repo) => {
-                for path in self.paths {
-                    if Self::is_uncommitted(repo, path) {
-                        return Err(UncommittedChanges.into());
+        Ok(changed_files)
+    }
+
+    /// The changed lines of each file in the output of `git diff --unified=0`,
+    /// whose paths are relative to `root`.
+    fn parse_diff(root: &Path, diff: &str) -> Self {
+        let mut files = FxHashMap::default();
+        let mut current = None;
+        let mut previous_line = "";
+        for line in diff.lines() {
+            if let Some(path) =
+                line.strip_prefix("+++ ").filter(|_| previous_line.starts_with("--- "))
+            {
+                // `+++ /dev/null` for deleted files
+                current = path.trim_matches('"').strip_prefix("b/").map(|path| root.join(path));
+                if let Some(path) = &current {
+                    files.insert(path.clone(), Some(vec![]));
+                }
+            } else if let Some(hunk) = line.strip_prefix("@@ ") {
+                if let (Some(path), Some(lines)) = (&current, parse_hunk(hunk)) {
+                    if let Some(Some(ranges)) = files.get_mut(path) {
+                        ranges.push(lines);
                     }
                 }
-                Ok(repo)
             }
-            err => err,
+            previous_line = line;
         }
+        Self { files }
     }
 
-    /// Given a list of repositories, verify they're all the same repository.
-    fn is_same_repo(&self) -> Result<&Repository, Error> {
-        assert!(!self.repos.is_empty());
-        let first_repo = self.repos.first().unwrap();
-        for repo in &self.repos[1..] {
-            if repo.path() != first_repo.path() {
-                return Err(MultipleRepositoriesFound.into());
-            }
+    /// Whether the file at `path`, relative to the current directory or absolute, changed.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.lines(path).is_some()
+    }
+
+    /// Whether the `line`, 1-based, of the file at `path` was added or modified.
+    pub fn contains_line(&self, path: &Path, line: usize) -> bool {
+        match self.lines(path) {
+            Some(None) => true,
+            Some(Some(ranges)) => ranges.iter().any(|lines| lines.contains(&line)),
+            None => false,
         }
-        Ok(first_repo)
     }
 
-    fn is_uncommitted(repo: &Repository, path: &Path) -> bool {
-        repo.status_file(path).map_or(true, |status| !matches!(status, git2::Status::CURRENT))
+    fn lines(&self, path: &Path) -> Option<&Option<Vec<Range<usize>>>> {
+        self.files.get(path).or_else(|| self.files.get(&path.canonicalize().ok()?))
+    }
+}
+
+/// The new lines of a hunk header without its leading `@@ `, e.g. `-1,2 +1,3 @@ fn main()`.
+/// `None` when the hunk only removes lines.
+fn parse_hunk(hunk: &str) -> Option<Range<usize>> {
+    let new_lines = hunk.split(' ').nth(1)?.strip_prefix('+')?;
+    let (start, count) = match new_lines.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (new_lines.parse::<usize>().ok()?, 1),
+    };
+    (count > 0).then_some(start..start + count)
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|err| format!("Failed to run git: {err}"))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim_end().to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{parse_hunk, ChangedFiles, GitDiff};
+
+    #[test]
+    fn hunk() {
+        assert_eq!(parse_hunk("-1,2 +1,3 @@ fn main()"), Some(1..4));
+        assert_eq!(parse_hunk("-5 +6 @@"), Some(6..7));
+        assert_eq!(parse_hunk("-5,2 +4,0 @@"), None);
+    }
+
+    #[test]
+    fn diff() {
+        let diff = "\
+diff --git a/src/a.js b/src/a.js
+index 1111111..2222222 100644
+--- a/src/a.js
++++ b/src/a.js
+@@ -2 +2,2 @@ function a() {
+-  debugger;
++  // removed
++  return 1;
+@@ -10,3 +11,0 @@
+-a;
+-b;
+-c;
+diff --git a/b.js b/b.js
+new file mode 100644
+index 0000000..3333333
+--- /dev/null
++++ b/b.js
+@@ -0,0 +1 @@
++--- not a header
+";
+        let root = Path::new("/repo");
+        let changed_files = ChangedFiles::parse_diff(root, diff);
+        assert_eq!(changed_files.files.len(), 2);
+
+        let a = root.join("src/a.js");
+        assert!(changed_files.contains(&a));
+        assert!(!changed_files.contains_line(&a, 1));
+        assert!(changed_files.contains_line(&a, 2));
+        assert!(changed_files.contains_line(&a, 3));
+        assert!(!changed_files.contains_line(&a, 11));
+        assert!(changed_files.contains_line(&root.join("b.js"), 1));
+        assert!(!changed_files.contains(&root.join("c.js")));
+    }
+
+    #[test]
+    fn option_like_ref() {
+        let err = ChangedFiles::new(Path::new("."), GitDiff::Since("--output=a.txt")).unwrap_err();
+        assert_eq!(err, "Invalid Git ref: --output=a.txt");
     }
 }
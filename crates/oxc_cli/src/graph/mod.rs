@@ -0,0 +1,448 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::resolve_options;
+use oxc_parser::Parser;
+use oxc_resolver::{Resolver, TsconfigOptions, TsconfigReferences};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    command::{GraphFormat, GraphOptions},
+    result::{CliRunResult, GraphResult},
+    walk::Walk,
+    Runner,
+};
+
+pub struct GraphRunner {
+    options: GraphOptions,
+}
+
+impl Runner for GraphRunner {
+    type Options = GraphOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let GraphOptions {
+            format,
+            tsconfig,
+            conditions,
+            preserve_symlinks,
+            unused,
+            ignore_options,
+            entries,
+        } = self.options;
+
+        let mut canonicalized = vec![];
+        for entry in entries {
+            let Ok(path) = entry.canonicalize() else {
+                return CliRunResult::PathNotFound { paths: vec![entry] };
+            };
+            canonicalized.push(path);
+        }
+        if let Some(tsconfig) = &tsconfig {
+            if !tsconfig.is_file() {
+                let message = format!("Tsconfig {tsconfig:?} is not a file");
+                return CliRunResult::InvalidOptions { message };
+            }
+        }
+
+        let cwd = env::current_dir().and_then(|cwd| cwd.canonicalize()).unwrap_or_default();
+        let tsconfig = tsconfig.or_else(|| {
+            let path = cwd.join("tsconfig.json");
+            path.is_file().then_some(path)
+        });
+
+        let now = std::time::Instant::now();
+        let graph = ModuleGraph::build(
+            canonicalized,
+            &Self::get_resolver(tsconfig, &conditions, preserve_symlinks),
+        );
+
+        if unused {
+            let files = Walk::new(&[cwd.clone()], &ignore_options).paths();
+            let unused = graph.unused(&files);
+            let display = unused
+                .iter()
+                .map(|path| path.strip_prefix(&cwd).unwrap_or(path).display().to_string())
+                .collect::<Vec<_>>();
+            match format {
+                GraphFormat::Dot => display.iter().for_each(|path| println!("{path}")),
+                GraphFormat::Json => {
+                    let json = serde_json::json!({ "unused": display });
+                    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+                }
+            }
+            return CliRunResult::GraphResult(GraphResult {
+                duration: now.elapsed(),
+                number_of_modules: graph.modules.len(),
+                number_of_cycles: 0,
+                number_of_unused: Some(unused.len()),
+            });
+        }
+
+        let cycles = graph.cycles();
+        match format {
+            GraphFormat::Dot => println!("{}", graph.to_dot(&cwd, &cycles)),
+            GraphFormat::Json => println!("{}", graph.to_json(&cwd, &cycles)),
+        }
+        for cycle in &cycles {
+            eprintln!("Circular dependency: {}", graph.display_chain(&cwd, cycle));
+        }
+
+        CliRunResult::GraphResult(GraphResult {
+            duration: now.elapsed(),
+            number_of_modules: graph.modules.len(),
+            number_of_cycles: cycles.len(),
+            number_of_unused: None,
+        })
+    }
+}
+
+impl GraphRunner {
+    /// Same resolution as the import plugin of the linter.
+    fn get_resolver(
+        tsconfig: Option<PathBuf>,
+        conditions: &[String],
+        preserve_symlinks: bool,
+    ) -> Resolver {
+        let tsconfig = tsconfig.map(|path| TsconfigOptions {
+            config_file: path,
+            references: TsconfigReferences::Auto,
+        });
+        Resolver::new(resolve_options(tsconfig, conditions, preserve_symlinks))
+    }
+}
+
+/// Modules reachable from the entry modules, keyed by their canonicalized path.
+///
+/// Dependencies inside `node_modules` and files which cannot be parsed are leaves of the graph.
+struct ModuleGraph {
+    /// The entries come first.
+    modules: Vec<PathBuf>,
+    entries: usize,
+    /// Indices into `modules` of the dependencies of each module, in source order.
+    dependencies: Vec<Vec<usize>>,
+}
+
+impl ModuleGraph {
+    fn build(entries: Vec<PathBuf>, resolver: &Resolver) -> Self {
+        let mut graph = Self { modules: vec![], entries: 0, dependencies: vec![] };
+        let mut indices = FxHashMap::default();
+        for entry in entries {
+            indices.entry(entry.clone()).or_insert_with(|| {
+                graph.modules.push(entry);
+                graph.dependencies.push(vec![]);
+                graph.modules.len() - 1
+            });
+        }
+        graph.entries = graph.modules.len();
+
+        // Breadth first, so modules are numbered by their distance from the entries.
+        let mut next = 0;
+        while next < graph.modules.len() {
+            let mut dependencies = vec![];
+            for path in Self::resolve_dependencies(&graph.modules[next], resolver) {
+                let index = *indices.entry(path.clone()).or_insert_with(|| {
+                    graph.modules.push(path);
+                    graph.dependencies.push(vec![]);
+                    graph.modules.len() - 1
+                });
+                if !dependencies.contains(&index) {
+                    dependencies.push(index);
+                }
+            }
+            graph.dependencies[next] = dependencies;
+            next += 1;
+        }
+        graph
+    }
+
+    fn resolve_dependencies(path: &Path, resolver: &Resolver) -> Vec<PathBuf> {
+        if path.components().any(|component| component.as_os_str() == "node_modules") {
+            return vec![];
+        }
+        let Ok(source_type) = SourceType::from_path(path) else { return vec![] };
+        let Ok(source_text) = fs::read_to_string(path) else {
+            eprintln!("Failed to read {}", path.display());
+            return vec![];
+        };
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            eprintln!("Failed to parse {}, its imports are not included", path.display());
+            return vec![];
+        }
+        let module_record = SemanticBuilder::new(&source_text, source_type)
+            .build_module_record(path.to_path_buf(), &ret.program)
+            .module_record();
+
+        let dir = path.parent().unwrap_or(path);
+        // Modules which cannot be resolved, such as node builtins, are left out.
+        module_record
+            .requested_modules
+            .keys()
+            .filter_map(|specifier| resolver.resolve(dir, specifier).ok())
+            .map(|resolution| resolution.full_path())
+            .collect()
+    }
+
+    /// Circular dependency chains, each starting and ending with the same module.
+    /// One chain is reported for every import closing a cycle, found by a depth first search from the entries.
+    fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut cycles = vec![];
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![];
+        for entry in 0..self.entries {
+            if !visited.contains(&entry) {
+                self.visit(entry, &mut visited, &mut stack, &mut cycles);
+            }
+        }
+        cycles
+    }
+
+    /// The `files` which are not modules of the graph, i.e. not reachable from any entry.
+    /// Files inside `node_modules` are left out.
+    fn unused(&self, files: &[Box<Path>]) -> Vec<PathBuf> {
+        let modules = self.modules.iter().collect::<FxHashSet<_>>();
+        let mut unused = files
+            .iter()
+            .filter(|path| {
+                !path.components().any(|component| component.as_os_str() == "node_modules")
+            })
+            .filter_map(|path| path.canonicalize().ok())
+            .filter(|path| !modules.contains(path))
+            .collect::<Vec<_>>();
+        unused.sort_unstable();
+        unused
+    }
+
+    fn visit(
+        &self,
+        module: usize,
+        visited: &mut FxHashSet<usize>,
+        stack: &mut Vec<usize>,
+        cycles: &mut Vec<Vec<usize>>,
+    ) {
+        visited.insert(module);
+        stack.push(module);
+        for &dependency in &self.dependencies[module] {
+            if let Some(start) = stack.iter().position(|m| *m == dependency) {
+                let mut cycle = stack[start..].to_vec();
+                cycle.push(dependency);
+                cycles.push(cycle);
+            } else if !visited.contains(&dependency) {
+                self.visit(dependency, visited, stack, cycles);
+            }
+        }
+        stack.pop();
+    }
+
+    fn display_path(&self, cwd: &Path, module: usize) -> String {
+        let path = &self.modules[module];
+        path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+    }
+
+    fn display_chain(&self, cwd: &Path, chain: &[usize]) -> String {
+        chain.iter().map(|module| self.display_path(cwd, *module)).collect::<Vec<_>>().join(" -> ")
+    }
+
+    /// Edges closing a cycle are colored red.
+    fn to_dot(&self, cwd: &Path, cycles: &[Vec<usize>]) -> String {
+        let cycle_edges = cycles
+            .iter()
+            .flat_map(|cycle| cycle.windows(2).map(|edge| (edge[0], edge[1])))
+            .collect::<FxHashSet<_>>();
+        let mut dot = String::from("digraph {\n");
+        for module in 0..self.modules.len() {
+            dot.push_str(&format!("  {:?}\n", self.display_path(cwd, module)));
+        }
+        for (module, dependencies) in self.dependencies.iter().enumerate() {
+            for &dependency in dependencies {
+                let attributes =
+                    if cycle_edges.contains(&(module, dependency)) { " [color=red]" } else { "" };
+                dot.push_str(&format!(
+                    "  {:?} -> {:?}{attributes}\n",
+                    self.display_path(cwd, module),
+                    self.display_path(cwd, dependency)
+                ));
+            }
+        }
+        dot.push('}');
+        dot
+    }
+
+    fn to_json(&self, cwd: &Path, cycles: &[Vec<usize>]) -> String {
+        let modules = self
+            .dependencies
+            .iter()
+            .enumerate()
+            .map(|(module, dependencies)| {
+                serde_json::json!({
+                    "path": self.display_path(cwd, module),
+                    "dependencies": dependencies
+                        .iter()
+                        .map(|dependency| self.display_path(cwd, *dependency))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let cycles = cycles
+            .iter()
+            .map(|cycle| cycle.iter().map(|module| self.display_path(cwd, *module)).collect())
+            .collect::<Vec<Vec<_>>>();
+        let json = serde_json::json!({ "modules": modules, "cycles": cycles });
+        serde_json::to_string_pretty(&json).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{GraphRunner, ModuleGraph};
+    use crate::{command::IgnoreOptions, CliRunResult, GraphFormat, GraphOptions, Runner};
+
+    fn graph(entry: &str) -> (ModuleGraph, PathBuf) {
+        let entry = Path::new(entry).canonicalize().unwrap();
+        let graph = ModuleGraph::build(vec![entry], &GraphRunner::get_resolver(None, &[], false));
+        (graph, Path::new("fixtures/graph").canonicalize().unwrap())
+    }
+
+    fn dependencies(entry: &str, conditions: &[String]) -> Vec<String> {
+        let entry = Path::new(entry).canonicalize().unwrap();
+        let graph =
+            ModuleGraph::build(vec![entry], &GraphRunner::get_resolver(None, conditions, false));
+        let cwd = Path::new("fixtures/exports").canonicalize().unwrap();
+        graph.dependencies[0].iter().map(|module| graph.display_path(&cwd, *module)).collect()
+    }
+
+    #[test]
+    fn cycles() {
+        let (graph, cwd) = graph("fixtures/graph/index.js");
+        assert_eq!(graph.modules.len(), 4);
+        let cycles = graph.cycles();
+        let cycles =
+            cycles.iter().map(|cycle| graph.display_chain(&cwd, cycle)).collect::<Vec<_>>();
+        assert_eq!(cycles, ["a.js -> b.ts -> a.js"]);
+    }
+
+    #[test]
+    fn no_cycles() {
+        let (graph, _) = graph("fixtures/graph/c.js");
+        assert_eq!(graph.modules.len(), 1);
+        assert!(graph.cycles().is_empty());
+    }
+
+    #[test]
+    fn exports_and_imports() {
+        assert_eq!(
+            dependencies("fixtures/exports/index.js", &[]),
+            ["node_modules/pkg/esm.js", "node_modules/pkg/lib/utils.js", "src/internal.js"]
+        );
+
+        let custom = dependencies("fixtures/exports/index.js", &["custom".to_string()]);
+        assert_eq!(custom[0], "node_modules/pkg/custom.js");
+    }
+
+    #[test]
+    fn browser_field() {
+        assert_eq!(
+            dependencies("fixtures/exports/browser.js", &[]),
+            ["node_modules/browser-main/main.js", "node_modules/browser-map/node.js"]
+        );
+        // `fs` is ignored with `false`
+        assert_eq!(
+            dependencies("fixtures/exports/browser.js", &["browser".to_string()]),
+            ["node_modules/browser-main/browser.js", "node_modules/browser-map/browser.js"]
+        );
+    }
+
+    #[test]
+    fn pnpm_symlinks() {
+        let cwd = Path::new("fixtures/pnpm").canonicalize().unwrap();
+        let resolver = GraphRunner::get_resolver(None, &[], false);
+        let a = resolver.resolve(&cwd, "a").unwrap().full_path();
+        assert_eq!(a, cwd.join("node_modules/.pnpm/a@1.0.0/node_modules/a/index.js"));
+        // The dependencies of `a` are next to its real path.
+        let b = resolver.resolve(a.parent().unwrap(), "b").unwrap().full_path();
+        assert_eq!(b, cwd.join("node_modules/.pnpm/b@1.0.0/node_modules/b/index.js"));
+
+        let resolver = GraphRunner::get_resolver(None, &[], true);
+        let a = resolver.resolve(&cwd, "a").unwrap().full_path();
+        assert_eq!(a, cwd.join("node_modules/a/index.js"));
+    }
+
+    #[test]
+    fn unused() {
+        let (graph, cwd) = graph("fixtures/graph/a.js");
+        let files = ["index.js", "a.js", "b.ts", "c.js", "unused.js"]
+            .map(|file| cwd.join(file).into_boxed_path());
+        let unused = graph.unused(&files);
+        assert_eq!(unused, [cwd.join("c.js"), cwd.join("index.js"), cwd.join("unused.js")]);
+
+        let entries = ["index.js", "unused.js"].map(|file| cwd.join(file)).to_vec();
+        let graph = ModuleGraph::build(entries, &GraphRunner::get_resolver(None, &[], false));
+        assert_eq!(graph.entries, 2);
+        assert!(graph.unused(&files).is_empty());
+        assert_eq!(graph.cycles().len(), 1);
+    }
+
+    #[test]
+    fn dot() {
+        let (graph, cwd) = graph("fixtures/graph/index.js");
+        let dot = graph.to_dot(&cwd, &graph.cycles());
+        let expected = r#"digraph {
+  "index.js"
+  "a.js"
+  "c.js"
+  "b.ts"
+  "index.js" -> "a.js"
+  "index.js" -> "c.js"
+  "a.js" -> "b.ts" [color=red]
+  "b.ts" -> "a.js" [color=red]
+}"#;
+        assert_eq!(dot, expected);
+    }
+
+    #[test]
+    fn json() {
+        let (graph, cwd) = graph("fixtures/graph/index.js");
+        let json: serde_json::Value =
+            serde_json::from_str(&graph.to_json(&cwd, &graph.cycles())).unwrap();
+        assert_eq!(json["modules"][0]["dependencies"], serde_json::json!(["a.js", "c.js"]));
+        assert_eq!(json["cycles"], serde_json::json!([["a.js", "b.ts", "a.js"]]));
+    }
+
+    #[test]
+    fn run() {
+        let options = GraphOptions {
+            format: GraphFormat::Json,
+            tsconfig: None,
+            conditions: vec![],
+            preserve_symlinks: false,
+            unused: false,
+            ignore_options: IgnoreOptions {
+                ignore_path: ".eslintignore".into(),
+                ignore_pattern: vec![],
+                no_ignore: false,
+            },
+            entries: vec![PathBuf::from("fixtures/graph/index.js")],
+        };
+        match GraphRunner::new(options).run() {
+            CliRunResult::GraphResult(result) => {
+                assert_eq!(result.number_of_modules, 4);
+                assert_eq!(result.number_of_cycles, 1);
+                assert!(result.number_of_unused.is_none());
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+}
@@ -1,14 +1,21 @@
+mod ast;
 mod command;
 mod format;
+mod git;
+mod graph;
 mod lint;
+mod reduce;
 mod result;
 mod runner;
 mod walk;
 
 pub use crate::{
+    ast::AstRunner,
     command::*,
     format::FormatRunner,
+    graph::GraphRunner,
     lint::LintRunner,
-    result::{CliRunResult, LintResult},
+    reduce::ReduceRunner,
+    result::{CliRunResult, GraphResult, LintResult, ReduceResult},
     runner::Runner,
 };
@@ -0,0 +1,421 @@
+//! The persistent cache of `--cache` and `--watch`, stored in `.oxlint_cache` of the current directory.
+//!
+//! The diagnostics of each file are stored along with the hash of its content and of the nested
+//! configuration files applying to it, so unchanged files are reported without being parsed.
+//! The whole cache is keyed by the version of oxlint, the linter options and the configuration
+//! and type information files given on the command line, and starts over when they change.
+//!
+//! Not used with `--fix`, which rewrites the files, nor with the import plugin,
+//! whose diagnostics depend on other files.
+
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    io, mem,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use oxc_diagnostics::{
+    miette::{LabeledSpan, MietteDiagnostic, Severity},
+    DiagnosticReceiver, DiagnosticSender, DiagnosticService, DiagnosticTuple, Error,
+};
+use oxc_linter::{encoding::FileEncoding, LintOptions, Linter};
+use rayon::iter::{Either, IntoParallelIterator, ParallelIterator};
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+
+use crate::command::VERSION;
+
+pub const CACHE_DIR: &str = ".oxlint_cache";
+const CACHE_FILE: &str = "cache.json";
+/// Changed when the fields hashed by `LintCache::key` change
+const KEY_VERSION: u32 = 1;
+
+pub struct LintCache {
+    cwd: PathBuf,
+    file: Mutex<CacheFile>,
+    /// The hashes of the files being linted, until their diagnostics are stored by `record`.
+    pending: Mutex<FxHashMap<PathBuf, u64>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// See `LintCache::key`
+    key: u64,
+    /// Keyed by the path relative to the current directory
+    files: FxHashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    /// See `LintCache::hash_file`
+    hash: u64,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedDiagnostic {
+    /// Including the `plugin(rule-name): ` prefix
+    message: String,
+    severity: Option<CachedSeverity>,
+    help: Option<String>,
+    labels: Vec<CachedLabel>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum CachedSeverity {
+    Error,
+    Warning,
+    Advice,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedLabel {
+    label: Option<String>,
+    offset: usize,
+    length: usize,
+}
+
+impl LintCache {
+    /// Read the cache of `cwd`, an empty cache if it does not exist or cannot be read.
+    pub fn load(cwd: &Path) -> Self {
+        let file = fs::read_to_string(cwd.join(CACHE_DIR).join(CACHE_FILE))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self { cwd: cwd.to_path_buf(), file: Mutex::new(file), pending: Mutex::default() }
+    }
+
+    /// Split `paths` into the diagnostics of the unchanged files, read from the cache,
+    /// and the files to lint, whose diagnostics are stored by `record`.
+    pub fn partition(
+        &self,
+        linter: &Linter,
+        paths: Vec<Box<Path>>,
+    ) -> (Vec<DiagnosticTuple>, Vec<Box<Path>>) {
+        {
+            let key = Self::key(linter);
+            let mut file = self.file.lock().unwrap();
+            if file.key != key {
+                *file = CacheFile { key, files: FxHashMap::default() };
+            }
+        }
+
+        let (cached, paths): (Vec<Option<DiagnosticTuple>>, Vec<Box<Path>>) =
+            paths.into_par_iter().partition_map(|path| self.lookup(linter, path));
+        (cached.into_iter().flatten().collect(), paths)
+    }
+
+    /// `Left` with the cached diagnostics of the file at `path` if it did not change,
+    /// `None` when it has no diagnostics, `Right` with `path` when it has to be linted.
+    fn lookup(
+        &self,
+        linter: &Linter,
+        path: Box<Path>,
+    ) -> Either<Option<DiagnosticTuple>, Box<Path>> {
+        // Decoded as by the linter, for the offsets of the diagnostics to match the linted text
+        let source_text =
+            fs::read(&path).and_then(|bytes| FileEncoding::detect(&bytes).decode(bytes));
+        let Ok(source_text) = source_text else {
+            return Either::Right(path);
+        };
+        let hash = self.hash_file(linter, &path, &source_text);
+        let relative_path = path.strip_prefix(&self.cwd).unwrap_or(&path);
+
+        let file = self.file.lock().unwrap();
+        match file.files.get(relative_path) {
+            Some(entry) if entry.hash == hash => {
+                if entry.diagnostics.is_empty() {
+                    return Either::Left(None);
+                }
+                let errors = entry.diagnostics.iter().cloned().map(CachedDiagnostic::into_error);
+                let errors = errors.collect();
+                Either::Left(Some(DiagnosticService::wrap_diagnostics(
+                    relative_path,
+                    &source_text,
+                    errors,
+                )))
+            }
+            _ => {
+                drop(file);
+                self.pending.lock().unwrap().insert(relative_path.to_path_buf(), hash);
+                Either::Right(path)
+            }
+        }
+    }
+
+    /// Forward the diagnostics of the linted files from `rx` to `tx_error` until `None`,
+    /// then store them along with the hashes of the files.
+    pub fn record(&self, rx: &DiagnosticReceiver, tx_error: &DiagnosticSender) {
+        let mut diagnostics = FxHashMap::<PathBuf, Vec<CachedDiagnostic>>::default();
+        while let Ok(Some((path, errors))) = rx.recv() {
+            // The sections of a file handled by a partial loader are sent separately
+            diagnostics
+                .entry(path.clone())
+                .or_default()
+                .extend(errors.iter().map(CachedDiagnostic::new));
+            tx_error.send(Some((path, errors))).unwrap();
+        }
+
+        let pending = mem::take(&mut *self.pending.lock().unwrap());
+        let mut file = self.file.lock().unwrap();
+        for (path, hash) in pending {
+            let diagnostics = diagnostics.remove(&path).unwrap_or_default();
+            file.files.insert(path, CacheEntry { hash, diagnostics });
+        }
+        drop(file);
+        tx_error.send(None).unwrap();
+    }
+
+    /// Write the cache, without the files which no longer exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `.oxlint_cache` cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let dir = self.cwd.join(CACHE_DIR);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(".gitignore"), "*\n")?;
+        let mut file = self.file.lock().unwrap();
+        file.files.retain(|path, _| self.cwd.join(path).is_file());
+        let json = serde_json::to_string(&*file).map_err(io::Error::from)?;
+        fs::write(dir.join(CACHE_FILE), json)
+    }
+
+    /// The hash of the linter version and options, and of the content of the configuration
+    /// and type information files passed on the command line, also reported by `--metadata-file`.
+    pub(super) fn key(linter: &Linter) -> u64 {
+        // Destructured so the options added later have to be considered here
+        let LintOptions {
+            filter,
+            nursery,
+            config_path,
+            nested_config,
+            // The cache is not used with the fixes
+            fix: _,
+            fix_kind: _,
+            fix_output: _,
+            timing: _,
+            report_unused_directives,
+            type_info_path,
+            allow_plugins,
+            import_plugin,
+            jest_plugin,
+            jsx_a11y_plugin,
+            nextjs_plugin,
+            promise_plugin,
+            react_perf_plugin,
+            security_plugin,
+            jsdoc_plugin,
+            env,
+        } = linter.options();
+
+        let mut hasher = FxHasher::default();
+        KEY_VERSION.hash(&mut hasher);
+        VERSION.hash(&mut hasher);
+        for (severity, name) in filter {
+            (severity.as_eslint_str(), name).hash(&mut hasher);
+        }
+        let mut env = env.iter().collect::<Vec<_>>();
+        env.sort_unstable();
+        env.hash(&mut hasher);
+        [
+            *nursery,
+            *nested_config,
+            *report_unused_directives,
+            *allow_plugins,
+            *import_plugin,
+            *jest_plugin,
+            *jsx_a11y_plugin,
+            *nextjs_plugin,
+            *promise_plugin,
+            *react_perf_plugin,
+            *security_plugin,
+            *jsdoc_plugin,
+        ]
+        .hash(&mut hasher);
+        for path in [config_path, type_info_path] {
+            path.hash(&mut hasher);
+            path.as_ref().and_then(|path| fs::read(path).ok()).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// The hash of the file content and of the nested configuration files applying to it.
+    /// The configuration files they extend are not hashed.
+    fn hash_file(&self, linter: &Linter, path: &Path, source_text: &str) -> u64 {
+        let mut hasher = FxHasher::default();
+        source_text.hash(&mut hasher);
+        for config_path in linter.options().nested_config_paths(&self.cwd.join(path)) {
+            fs::read(config_path).ok().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+impl CachedDiagnostic {
+    fn new(error: &Error) -> Self {
+        let severity = error.severity().map(|severity| match severity {
+            Severity::Error => CachedSeverity::Error,
+            Severity::Warning => CachedSeverity::Warning,
+            Severity::Advice => CachedSeverity::Advice,
+        });
+        let labels = error.labels().map_or(vec![], |labels| {
+            labels
+                .map(|label| CachedLabel {
+                    label: label.label().map(ToString::to_string),
+                    offset: label.offset(),
+                    length: label.len(),
+                })
+                .collect()
+        });
+        Self {
+            message: error.to_string(),
+            severity,
+            help: error.help().map(|help| help.to_string()),
+            labels,
+        }
+    }
+
+    fn into_error(self) -> Error {
+        let labels = self
+            .labels
+            .into_iter()
+            .map(|label| LabeledSpan::new(label.label, label.offset, label.length));
+        let mut diagnostic = MietteDiagnostic::new(self.message).with_labels(labels);
+        if let Some(severity) = self.severity {
+            diagnostic = diagnostic.with_severity(match severity {
+                CachedSeverity::Error => Severity::Error,
+                CachedSeverity::Warning => Severity::Warning,
+                CachedSeverity::Advice => Severity::Advice,
+            });
+        }
+        if let Some(help) = self.help {
+            diagnostic = diagnostic.with_help(help);
+        }
+        Error::new(diagnostic)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path, sync::mpsc};
+
+    use oxc_diagnostics::{
+        miette::{LabeledSpan, MietteDiagnostic},
+        DiagnosticService,
+    };
+    use oxc_linter::{LintOptions, Linter};
+
+    use super::{CachedDiagnostic, LintCache, CACHE_DIR};
+
+    #[test]
+    fn partition_and_record() {
+        let dir = env::temp_dir().join(format!("oxc_cli_cache_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+        fs::write(dir.join("a.js"), "debugger;").unwrap();
+        fs::write(dir.join("b.js"), "let b = 1;").unwrap();
+        let paths = || vec![dir.join("a.js").into_boxed_path(), dir.join("b.js").into_boxed_path()];
+        let linter = Linter::from_options(LintOptions::default()).unwrap();
+
+        // Nothing is cached yet
+        let cache = LintCache::load(&dir);
+        let (cached, to_lint) = cache.partition(&linter, paths());
+        assert!(cached.is_empty());
+        assert_eq!(to_lint.len(), 2);
+
+        let error =
+            oxc_diagnostics::Error::msg("eslint(no-debugger): `debugger` statement is not allowed");
+        let (path, errors) =
+            DiagnosticService::wrap_diagnostics(Path::new("a.js"), "debugger;", vec![error]);
+        let (tx, rx) = mpsc::sync_channel(4);
+        let (tx_error, rx_error) = mpsc::sync_channel(4);
+        tx.send(Some((path, errors))).unwrap();
+        tx.send(None).unwrap();
+        cache.record(&rx, &tx_error);
+        assert_eq!(rx_error.iter().map_while(|diagnostics| diagnostics).count(), 1);
+        cache.save().unwrap();
+
+        // Both files are unchanged, only `a.js` has diagnostics
+        let cache = LintCache::load(&dir);
+        let (cached, to_lint) = cache.partition(&linter, paths());
+        assert!(to_lint.is_empty());
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].0, Path::new("a.js"));
+        assert_eq!(
+            cached[0].1.iter().map(CachedDiagnostic::new).map(|d| d.message).collect::<Vec<_>>(),
+            ["eslint(no-debugger): `debugger` statement is not allowed"]
+        );
+
+        fs::write(dir.join("b.js"), "let b = 2;").unwrap();
+        let (_, to_lint) = cache.partition(&linter, paths());
+        assert_eq!(to_lint, [dir.join("b.js").into_boxed_path()]);
+
+        // Other options use another cache
+        let linter = Linter::from_options(LintOptions::default().with_jest_plugin(true)).unwrap();
+        let (_, to_lint) = cache.partition(&linter, paths());
+        assert_eq!(to_lint.len(), 2);
+
+        assert!(dir.join(CACHE_DIR).join(".gitignore").is_file());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn encoded_files() {
+        let dir = env::temp_dir().join(format!("oxc_cli_cache_encoding_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+        // With a UTF-8 byte order mark, and in UTF-16 LE
+        fs::write(dir.join("a.js"), b"\xEF\xBB\xBFdebugger;").unwrap();
+        let utf16 =
+            "\u{FEFF}debugger;".encode_utf16().flat_map(u16::to_le_bytes).collect::<Vec<_>>();
+        fs::write(dir.join("b.js"), utf16).unwrap();
+        let paths = || vec![dir.join("a.js").into_boxed_path(), dir.join("b.js").into_boxed_path()];
+        let linter = Linter::from_options(LintOptions::default()).unwrap();
+
+        let cache = LintCache::load(&dir);
+        let (_, to_lint) = cache.partition(&linter, paths());
+        assert_eq!(to_lint.len(), 2);
+        let (tx, rx) = mpsc::sync_channel(4);
+        let (tx_error, rx_error) = mpsc::sync_channel(4);
+        for path in ["a.js", "b.js"] {
+            // Reported at the offsets of the text without the byte order mark
+            let error = oxc_diagnostics::Error::new(
+                MietteDiagnostic::new("debugger").with_label(LabeledSpan::at(0..8, "here")),
+            );
+            let diagnostics =
+                DiagnosticService::wrap_diagnostics(Path::new(path), "debugger;", vec![error]);
+            tx.send(Some(diagnostics)).unwrap();
+        }
+        tx.send(None).unwrap();
+        cache.record(&rx, &tx_error);
+        assert_eq!(rx_error.iter().map_while(|diagnostics| diagnostics).count(), 2);
+
+        let (cached, to_lint) = cache.partition(&linter, paths());
+        assert!(to_lint.is_empty());
+        assert_eq!(cached.len(), 2);
+        for (_, errors) in &cached {
+            let label = errors[0].labels().unwrap().next().unwrap();
+            let span = errors[0].source_code().unwrap().read_span(label.inner(), 0, 0).unwrap();
+            assert_eq!(span.data(), b"debugger");
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn key() {
+        let key = |options: LintOptions| LintCache::key(&Linter::from_options(options).unwrap());
+        let default = key(LintOptions::default());
+        assert_eq!(default, key(LintOptions::default().with_timing(true)));
+        assert_ne!(default, key(LintOptions::default().with_nursery(true)));
+        assert_ne!(default, key(LintOptions::default().with_env(vec!["browser".to_string()])));
+        // The order of the environments does not matter
+        assert_eq!(
+            key(LintOptions::default().with_env(vec!["browser".to_string(), "node".to_string()])),
+            key(LintOptions::default().with_env(vec!["node".to_string(), "browser".to_string()]))
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! `oxlint --daemon`: keep the linter in memory and answer lint requests over a Unix domain socket.
+//!
+//! The socket is only accessible to the user running the daemon, as the requests read any file
+//! the user can read: it is created in the `.oxlint_daemon` directory of the working directory,
+//! with the permissions `0700`, or at the path given to `--daemon-socket` with the permissions `0600`.
+//!
+//! The protocol is JSON-RPC 2.0 with one message per line:
+//!
+//! ```text
+//! --> {"jsonrpc": "2.0", "id": 1, "method": "lint", "params": {"path": "/project/src/a.js"}}
+//! <-- {"jsonrpc": "2.0", "id": 1, "result": {"diagnostics": [{"message": "...", "severity": "warning", "help": null, "start": 0, "end": 9}]}}
+//! --> {"jsonrpc": "2.0", "id": 2, "method": "shutdown"}
+//! ```
+//!
+//! `params.path` must be absolute. `params.source` can be given to lint unsaved content instead
+//! of the file on disk. Files are linted as by `oxlint`, without fixing them.
+//! The results of the last `MAX_CACHED_RESULTS` paths are cached with the hash of their content,
+//! so unchanged files are not linted again, unless the import plugin is enabled.
+//! A `lint` request still running when another one arrives for the same path is cancelled,
+//! and answered with the error code `-32800`.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs::{self, DirBuilder, Permissions},
+    hash::{Hash, Hasher},
+    io::{self, BufRead, BufReader, Write},
+    os::unix::{
+        fs::{DirBuilderExt, PermissionsExt},
+        net::{UnixListener, UnixStream},
+    },
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex, PoisonError,
+    },
+    thread,
+};
+
+use oxc_diagnostics::{
+    miette::{LabeledSpan, Severity},
+    CancellationToken, Error,
+};
+use oxc_linter::{encoding::FileEncoding, LintService};
+use rustc_hash::FxHashMap;
+use serde_json::{json, Value};
+
+/// The directory of the default socket, in the working directory
+pub const SOCKET_DIR: &str = ".oxlint_daemon";
+
+const MAX_CACHED_RESULTS: usize = 1000;
+
+/// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const REQUEST_CANCELLED: i64 = -32800;
+
+pub struct Daemon {
+    listener: UnixListener,
+    socket_path: PathBuf,
+    service: LintService,
+    /// Held while linting with the import plugin, see `LintService::run_source_text`
+    module_graph_lock: Mutex<()>,
+    cache: Mutex<ResultCache>,
+    /// Token of the `lint` request of each path in progress
+    lint_tokens: Mutex<FxHashMap<PathBuf, CancellationToken>>,
+    shutdown: AtomicBool,
+}
+
+impl Daemon {
+    /// `.oxlint_daemon/daemon.sock` in `cwd`, creating the directory with the permissions `0700`.
+    ///
+    /// # Errors
+    ///
+    /// * The directory cannot be created, or is owned by another user
+    pub fn default_socket_path(cwd: &Path) -> io::Result<PathBuf> {
+        let dir = cwd.join(SOCKET_DIR);
+        DirBuilder::new().recursive(true).mode(0o700).create(&dir)?;
+        // Only the owner can change the permissions of an existing directory
+        fs::set_permissions(&dir, Permissions::from_mode(0o700))?;
+        Ok(dir.join("daemon.sock"))
+    }
+
+    /// Listen on `socket_path`, replacing the socket of a daemon which did not shut down.
+    ///
+    /// # Errors
+    ///
+    /// * The socket cannot be bound, or its permissions cannot be set
+    pub fn bind(socket_path: &Path, service: LintService) -> io::Result<Self> {
+        if socket_path.exists() && UnixStream::connect(socket_path).is_err() {
+            fs::remove_file(socket_path)?;
+        }
+        let listener = UnixListener::bind(socket_path)?;
+        fs::set_permissions(socket_path, Permissions::from_mode(0o600))?;
+        Ok(Self {
+            listener,
+            socket_path: socket_path.to_path_buf(),
+            service,
+            module_graph_lock: Mutex::default(),
+            cache: Mutex::default(),
+            lint_tokens: Mutex::default(),
+            shutdown: AtomicBool::new(false),
+        })
+    }
+
+    /// Serve connections, each of them on its own thread, until a `shutdown` request.
+    /// Connections still open at that point are not waited for.
+    pub fn serve(self) {
+        let daemon = Arc::new(self);
+        for stream in daemon.listener.incoming() {
+            if daemon.shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+            let Ok(stream) = stream else { continue };
+            let daemon = Arc::clone(&daemon);
+            thread::spawn(move || daemon.handle_connection(stream));
+        }
+        let _ = fs::remove_file(&daemon.socket_path);
+    }
+
+    fn handle_connection(&self, stream: UnixStream) {
+        let Ok(mut writer) = stream.try_clone() else { return };
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else { break };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (response, shutdown) = self.handle_message(&line);
+            if writeln!(writer, "{response}").is_err() {
+                break;
+            }
+            if shutdown {
+                self.shutdown.store(true, Ordering::SeqCst);
+                // Wake up the accept loop so it sees the flag.
+                let _ = UnixStream::connect(&self.socket_path);
+                break;
+            }
+        }
+    }
+
+    /// Returns the response and whether the daemon should shut down.
+    fn handle_message(&self, message: &str) -> (Value, bool) {
+        let request = match serde_json::from_str::<Value>(message) {
+            Ok(request) => request,
+            Err(err) => {
+                return (error_response(&Value::Null, PARSE_ERROR, &err.to_string()), false)
+            }
+        };
+        let id = request.get("id").cloned().unwrap_or_default();
+        match request.get("method").and_then(Value::as_str) {
+            Some("lint") => {
+                let params = request.get("params").cloned().unwrap_or_default();
+                let response = match self.lint(&params) {
+                    Ok(Some(result)) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Ok(None) => error_response(&id, REQUEST_CANCELLED, "Request cancelled"),
+                    Err(message) => error_response(&id, INVALID_PARAMS, &message),
+                };
+                (response, false)
+            }
+            Some("shutdown") => (json!({ "jsonrpc": "2.0", "id": id, "result": null }), true),
+            _ => (error_response(&id, METHOD_NOT_FOUND, "Method not found"), false),
+        }
+    }
+
+    /// `None` if the request was cancelled by a newer request for the same path.
+    fn lint(&self, params: &Value) -> Result<Option<Value>, String> {
+        let path = params
+            .get("path")
+            .and_then(Value::as_str)
+            .map(PathBuf::from)
+            .ok_or_else(|| "`path` is required".to_string())?;
+        // Relative paths would be resolved against the working directory of the daemon
+        if !path.is_absolute() {
+            return Err(format!("`path` must be absolute: {}", path.display()));
+        }
+        let source_text = match params.get("source").and_then(Value::as_str) {
+            Some(source_text) => source_text.to_string(),
+            None => fs::read(&path)
+                .and_then(|bytes| FileEncoding::detect(&bytes).decode(bytes))
+                .map_err(|err| format!("Failed to read {}: {err}", path.display()))?,
+        };
+
+        // The diagnostics of the import plugin depend on other files
+        let cached = !self.service.linter().options().import_plugin;
+        let mut hasher = DefaultHasher::new();
+        source_text.hash(&mut hasher);
+        let hash = hasher.finish();
+        if cached {
+            if let Some(result) = self.cache.lock().unwrap().get(&path, hash) {
+                return Ok(Some(result));
+            }
+        }
+
+        let token = CancellationToken::new();
+        {
+            let mut lint_tokens = self.lint_tokens.lock().unwrap();
+            if let Some(previous) = lint_tokens.insert(path.clone(), token.clone()) {
+                previous.cancel();
+            }
+        }
+        let errors = self.lint_source(&path, source_text, &token);
+        {
+            // Otherwise a newer request replaced the token, and cancelled this one while holding the lock
+            let mut lint_tokens = self.lint_tokens.lock().unwrap();
+            if !token.is_cancelled() {
+                lint_tokens.remove(&path);
+            }
+        }
+        if token.is_cancelled() {
+            return Ok(None);
+        }
+        let diagnostics = errors.iter().map(diagnostic_to_json).collect::<Vec<_>>();
+        let result = json!({ "diagnostics": diagnostics });
+        if cached {
+            self.cache.lock().unwrap().insert(path, hash, result.clone());
+        }
+        Ok(Some(result))
+    }
+
+    fn lint_source(
+        &self,
+        path: &Path,
+        source_text: String,
+        token: &CancellationToken,
+    ) -> Vec<Error> {
+        let _module_graph_guard = self
+            .service
+            .linter()
+            .options()
+            .import_plugin
+            .then(|| self.module_graph_lock.lock().unwrap_or_else(PoisonError::into_inner));
+        let (tx_error, rx_error) = mpsc::sync_channel(16);
+        // Received while linting, as the diagnostics of each script of a file are sent separately
+        thread::scope(|scope| {
+            scope.spawn(move || self.service.run_source_text(path, source_text, token, &tx_error));
+            rx_error.iter().flatten().flat_map(|(_, errors)| errors).collect()
+        })
+    }
+}
+
+/// The results of the last `MAX_CACHED_RESULTS` linted paths
+#[derive(Default)]
+struct ResultCache {
+    results: FxHashMap<PathBuf, CachedResult>,
+    /// Incremented on each use, to evict the least recently used result
+    clock: u64,
+}
+
+struct CachedResult {
+    /// Hash of the linted source text
+    hash: u64,
+    result: Value,
+    last_used: u64,
+}
+
+impl ResultCache {
+    fn get(&mut self, path: &Path, hash: u64) -> Option<Value> {
+        self.clock += 1;
+        let cached = self.results.get_mut(path).filter(|cached| cached.hash == hash)?;
+        cached.last_used = self.clock;
+        Some(cached.result.clone())
+    }
+
+    fn insert(&mut self, path: PathBuf, hash: u64, result: Value) {
+        self.clock += 1;
+        if self.results.len() >= MAX_CACHED_RESULTS && !self.results.contains_key(&path) {
+            let oldest = self
+                .results
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(path, _)| path.clone());
+            if let Some(oldest) = oldest {
+                self.results.remove(&oldest);
+            }
+        }
+        self.results.insert(path, CachedResult { hash, result, last_used: self.clock });
+    }
+}
+
+fn error_response(id: &Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn diagnostic_to_json(error: &Error) -> Value {
+    let labels = error.labels().map_or(vec![], Iterator::collect);
+    let start = labels.iter().map(LabeledSpan::offset).min().unwrap_or(0);
+    let end = labels.iter().map(|label| label.offset() + label.len()).max().unwrap_or(0);
+    let severity = match error.severity() {
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Advice) => "advice",
+        Some(Severity::Error) | None => "error",
+    };
+    json!({
+        "message": error.to_string(),
+        "severity": severity,
+        "help": error.help().map(|help| help.to_string()),
+        "start": start,
+        "end": end,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Write},
+        os::unix::{fs::PermissionsExt, net::UnixStream},
+        path::{Path, PathBuf},
+        thread,
+    };
+
+    use oxc_linter::{LintOptions, LintService, LintServiceOptions, Linter};
+    use serde_json::{json, Value};
+
+    use super::{Daemon, ResultCache, MAX_CACHED_RESULTS};
+
+    fn request(stream: &mut UnixStream, request: &Value) -> Value {
+        writeln!(stream, "{request}").unwrap();
+        let mut line = String::new();
+        BufReader::new(stream.try_clone().unwrap()).read_line(&mut line).unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+
+    #[test]
+    fn lint_and_shutdown() {
+        let dir = env::temp_dir().join(format!("oxlint_daemon_test_{}", std::process::id()));
+        let socket_path = Daemon::default_socket_path(&dir).unwrap();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(socket_path.parent().unwrap()), 0o700);
+
+        let linter = Linter::from_options(LintOptions::default()).unwrap();
+        let options = LintServiceOptions::new(dir.clone().into_boxed_path(), vec![]);
+        let daemon = Daemon::bind(&socket_path, LintService::new(linter, options)).unwrap();
+        assert_eq!(mode(&socket_path), 0o600);
+        let server = thread::spawn(move || daemon.serve());
+
+        let mut stream = UnixStream::connect(&socket_path).unwrap();
+        let lint = json!({
+            "jsonrpc": "2.0", "id": 1, "method": "lint",
+            "params": { "path": dir.join("test.js"), "source": "debugger;" }
+        });
+        let response = request(&mut stream, &lint);
+        assert_eq!(response["id"], 1);
+        let diagnostics = &response["result"]["diagnostics"];
+        assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+        assert_eq!(diagnostics[0]["severity"], "warning");
+        assert_eq!(diagnostics[0]["end"], 9);
+
+        // Served from the cache
+        assert_eq!(request(&mut stream, &lint)["result"], response["result"]);
+
+        let relative = json!({
+            "jsonrpc": "2.0", "id": 2, "method": "lint",
+            "params": { "path": "test.js", "source": "debugger;" }
+        });
+        assert_eq!(request(&mut stream, &relative)["error"]["code"], -32602);
+
+        let response = request(&mut stream, &json!({ "jsonrpc": "2.0", "id": 3, "method": "foo" }));
+        assert_eq!(response["error"]["code"], -32601);
+
+        let shutdown = json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" });
+        assert_eq!(request(&mut stream, &shutdown)["id"], 4);
+        server.join().unwrap();
+        assert!(!socket_path.exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn cache_is_bounded() {
+        let mut cache = ResultCache::default();
+        for i in 0..=MAX_CACHED_RESULTS {
+            cache.insert(PathBuf::from(format!("/{i}.js")), 0, json!(i));
+            // Keep the first result in use
+            assert!(cache.get(&PathBuf::from("/0.js"), 0).is_some());
+        }
+        assert_eq!(cache.results.len(), MAX_CACHED_RESULTS);
+        assert!(cache.get(&PathBuf::from("/1.js"), 0).is_none());
+        assert!(cache.get(&PathBuf::from(format!("/{MAX_CACHED_RESULTS}.js")), 0).is_some());
+        // A changed source text is not served
+        assert!(cache.get(&PathBuf::from("/0.js"), 1).is_none());
+    }
+}
@@ -11,10 +11,10 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 use oxc_cli::{CliRunResult, LintRunner, Runner};
 
 fn main() -> CliRunResult {
-    init_tracing();
     init_miette();
 
     let command = oxc_cli::lint_command().run();
+    init_tracing(command.lint_options.misc_options.verbose);
     command.handle_threads();
     LintRunner::new(command.lint_options).run()
 }
@@ -24,7 +24,20 @@ fn init_miette() {
     miette::set_hook(Box::new(|_| Box::new(miette::MietteHandlerOpts::new().build()))).unwrap();
 }
 
-fn init_tracing() {
-    use tracing_subscriber::{fmt, prelude::*, registry, EnvFilter};
-    registry().with(fmt::layer()).with(EnvFilter::from_env("OXC_LOG")).init();
+/// Logs are written to stderr, so they do not mix with the diagnostics written to stdout.
+fn init_tracing(verbose: bool) {
+    use tracing_subscriber::{fmt, fmt::format::FmtSpan, prelude::*, registry, EnvFilter};
+
+    let filter = match EnvFilter::try_from_env("OXC_LOG") {
+        Ok(filter) => filter,
+        Err(_) if verbose => EnvFilter::new("debug"),
+        Err(_) => EnvFilter::new("error"),
+    };
+    let json = std::env::var("OXC_LOG_FORMAT").is_ok_and(|format| format == "json");
+    // The duration of the file and phase spans is logged when they close
+    let json_layer = json
+        .then(|| fmt::layer().json().with_span_events(FmtSpan::CLOSE).with_writer(std::io::stderr));
+    let human_layer =
+        (!json).then(|| fmt::layer().with_span_events(FmtSpan::CLOSE).with_writer(std::io::stderr));
+    registry().with(filter).with(json_layer).with(human_layer).init();
 }
@@ -0,0 +1,91 @@
+//! The summary of a run written by `--metadata-file`, for build systems to archive and compare
+//! runs over time. It is only written to the given file, nothing is sent anywhere.
+
+use std::{fs, io, path::Path, time::SystemTime};
+
+use oxc_linter::Linter;
+use serde::Serialize;
+
+use super::cache::LintCache;
+use crate::{command::VERSION, LintResult};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunMetadata {
+    pub tool: &'static str,
+    pub version: &'static str,
+    /// The hash of the version, the options and the configuration files given on the command line,
+    /// as hexadecimal. Runs with the same hash lint the same files the same way.
+    pub config_hash: String,
+    /// Seconds since the Unix epoch
+    pub started_at: u64,
+    pub duration_ms: u128,
+    pub number_of_files: usize,
+    pub number_of_rules: usize,
+    pub number_of_warnings: usize,
+    pub number_of_errors: usize,
+    pub exit_code: u8,
+}
+
+impl RunMetadata {
+    pub fn new(linter: &Linter, started_at: SystemTime, result: &LintResult) -> Self {
+        let started_at = started_at.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+        Self {
+            tool: "oxlint",
+            version: VERSION,
+            config_hash: format!("{:016x}", LintCache::key(linter)),
+            started_at: started_at.as_secs(),
+            duration_ms: result.duration.as_millis(),
+            number_of_files: result.number_of_files,
+            number_of_rules: result.number_of_rules,
+            number_of_warnings: result.number_of_warnings,
+            number_of_errors: result.number_of_errors,
+            exit_code: result.exit_code(),
+        }
+    }
+
+    /// Write the metadata to `path` as JSON, creating its missing parent directories
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime};
+
+    use oxc_linter::Linter;
+
+    use super::RunMetadata;
+    use crate::LintResult;
+
+    #[test]
+    fn json() {
+        let linter = Linter::builder().build().unwrap();
+        let result = LintResult {
+            duration: Duration::from_millis(1500),
+            number_of_files: 3,
+            number_of_warnings: 2,
+            ..LintResult::default()
+        };
+        let started_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let metadata = RunMetadata::new(&linter, started_at, &result);
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(json["tool"], "oxlint");
+        assert_eq!(json["startedAt"], 1_700_000_000);
+        assert_eq!(json["durationMs"], 1500);
+        assert_eq!(json["numberOfFiles"], 3);
+        assert_eq!(json["numberOfWarnings"], 2);
+        assert_eq!(json["exitCode"], 0);
+        assert_eq!(json["configHash"].as_str().unwrap().len(), 16);
+        // The same linter gives the same hash
+        assert_eq!(
+            RunMetadata::new(&linter, started_at, &result).config_hash,
+            metadata.config_hash
+        );
+    }
+}
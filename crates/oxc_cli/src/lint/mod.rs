@@ -1,18 +1,61 @@
+mod cache;
+#[cfg(unix)]
+mod daemon;
+mod metadata;
+mod stats;
+mod suppressions;
+mod symbols;
+
 use ignore::gitignore::Gitignore;
-use std::{env, io::BufWriter, time::Instant, vec::Vec};
+use std::{
+    env, fs,
+    io::{self, BufWriter},
+    path::{Path, PathBuf},
+    process::Termination,
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant, SystemTime},
+    vec::Vec,
+};
 
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use notify::{RecursiveMode, Watcher};
+use oxc_diagnostics::{
+    DiagnosticReporter, DiagnosticService, Error, GraphicalReportHandler, GraphicalTheme,
+};
 use oxc_linter::{
-    partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, LintServiceOptions, Linter,
+    json::JSON_EXT,
+    partial_loader::{MarkdownProcessor, Processor, LINT_PARTIAL_LOADER_EXT, MARKDOWN_EXT},
+    FixKind, FixOutput, InputLimits, LintOptions, LintService, LintServiceOptions, Linter,
+    Workspace,
 };
 use oxc_span::VALID_EXTENSIONS;
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use rustc_hash::FxHashMap;
+use tracing::{debug, trace};
 
+use self::{
+    cache::{LintCache, CACHE_DIR},
+    metadata::RunMetadata,
+    stats::CodeStats,
+    suppressions::SuppressionStats,
+    symbols::symbols_tree,
+};
 use crate::{
-    command::{LintOptions as CliLintOptions, OutputFormat, OutputOptions, WarningOptions},
+    command::{
+        GitOptions, IgnoreOptions, LintOptions as CliLintOptions, OutputFormat, OutputOptions,
+        ReportOutput, Reporter, WarningOptions,
+    },
+    git::{ChangedFiles, GitDiff},
     walk::{Extensions, Walk},
     CliRunResult, LintResult, Runner,
 };
 
+#[cfg(unix)]
+use self::daemon::Daemon;
+
+/// How long `--watch` waits for more changes before linting again.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
+
 pub struct LintRunner {
     options: CliLintOptions,
 }
@@ -31,20 +74,51 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
-        let CliLintOptions {
-            paths,
-            filter,
-            warning_options,
-            ignore_options,
-            fix_options,
-            enable_plugins,
-            config,
-            tsconfig,
-            output_options,
-            ..
-        } = self.options;
+        if let Some(path) = &self.options.print_config {
+            return Self::print_config(path, &self.get_lint_options());
+        }
+
+        if let Some(path) = &self.options.print_symbols {
+            return Self::print_symbols(path);
+        }
+
+        if self.options.daemon {
+            return self.run_daemon();
+        }
+
+        let lint_options = self.get_lint_options();
+        let CliLintOptions { paths, warning_options, ignore_options, git_options, .. } =
+            &self.options;
 
-        let mut paths = paths;
+        if self.options.fix_options.fix_to_stdout && !(paths.len() == 1 && paths[0].is_file()) {
+            return CliRunResult::InvalidOptions {
+                message: "--fix-to-stdout requires a single file path.".to_string(),
+            };
+        }
+
+        // The fixes written to the files would be linted and written again
+        if self.options.watch && lint_options.fix {
+            return CliRunResult::InvalidOptions {
+                message: "--watch cannot be used with --fix, --fix-dry-run or --fix-to-stdout."
+                    .to_string(),
+            };
+        }
+
+        let cwd = match env::current_dir() {
+            Ok(cwd) => oxc_linter::paths::normalize(&cwd),
+            Err(err) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to get current working directory: {err}"),
+                }
+            }
+        };
+
+        let changed_files = match Self::changed_files(git_options) {
+            Ok(changed_files) => changed_files.map(Arc::new),
+            Err(message) => return CliRunResult::InvalidOptions { message },
+        };
+
+        let mut paths = paths.clone();
         let provided_path_count = paths.len();
         let now = Instant::now();
 
@@ -55,7 +129,13 @@ impl Runner for LintRunner {
         // pre-filter the paths.
         if !paths.is_empty() && !ignore_options.no_ignore {
             let (ignore, _err) = Gitignore::new(&ignore_options.ignore_path);
-            paths.retain(|p| if p.is_dir() { true } else { !ignore.matched(p, false).is_ignore() });
+            paths.retain(|p| {
+                let ignored = !p.is_dir() && ignore.matched(p, false).is_ignore();
+                if ignored {
+                    debug!(path = %p.display(), "skipped: matched by {:?}", ignore_options.ignore_path);
+                }
+                !ignored
+            });
         }
 
         if paths.is_empty() {
@@ -69,66 +149,164 @@ impl Runner for LintRunner {
                 });
             }
 
-            if let Ok(cwd) = env::current_dir() {
-                paths.push(cwd);
-            } else {
-                return CliRunResult::InvalidOptions {
-                    message: "Failed to get current working directory.".to_string(),
-                };
-            }
+            paths.push(cwd.clone());
         }
 
+        if self.options.stats {
+            return Self::print_stats(&paths, ignore_options);
+        }
+
+        if self.options.suppressions {
+            return Self::print_suppressions(&paths, ignore_options);
+        }
+
+        // The fixes rewrite the files, and the diagnostics of the import plugin depend on other files
+        let cache = ((self.options.cache || self.options.watch)
+            && !lint_options.fix
+            && !lint_options.import_plugin)
+            .then(|| Arc::new(LintCache::load(&cwd)));
+
+        let result =
+            match self.lint(&paths, &cwd, now, changed_files.as_ref(), cache.as_ref(), true) {
+                Ok(result) => result,
+                Err(result) => return result,
+            };
+
+        // Keep stdout to the fixed code.
+        if self.options.fix_options.fix_to_stdout {
+            return CliRunResult::None;
+        }
+
+        if self.options.watch {
+            CliRunResult::LintResult(result).report();
+            return self.watch(&paths, &cwd, changed_files.as_ref(), cache.as_ref());
+        }
+
+        CliRunResult::LintResult(result)
+    }
+}
+
+impl LintRunner {
+    /// Lint the files found in `paths`, relative to `cwd`. With `cache`, the diagnostics of the
+    /// unchanged files are read from it, and only reported when `report_cached` is set.
+    fn lint(
+        &self,
+        paths: &[PathBuf],
+        cwd: &Path,
+        now: Instant,
+        changed_files: Option<&Arc<ChangedFiles>>,
+        cache: Option<&Arc<LintCache>>,
+        report_cached: bool,
+    ) -> Result<LintResult, CliRunResult> {
+        let CliLintOptions {
+            warning_options,
+            ignore_options,
+            json,
+            output_options,
+            git_options,
+            ..
+        } = &self.options;
+
+        // Created for each run, so `--watch` picks up the changes of the configuration files
+        let linter = Linter::from_options(self.get_lint_options())
+            .map_err(|diagnostic| Self::config_error(&diagnostic))?;
+        for warning in linter.config_warnings() {
+            eprintln!("{}", Self::render_diagnostic(warning));
+        }
+
+        let processors = self.processors();
         let extensions = VALID_EXTENSIONS
             .iter()
             .chain(LINT_PARTIAL_LOADER_EXT.iter())
             .copied()
-            .collect::<Vec<&'static str>>();
+            .chain(linter.extensions())
+            .chain(processors.keys().map(String::as_str))
+            .chain(JSON_EXT.iter().copied().filter(|_| *json))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
 
-        let paths =
-            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
+        let mut paths =
+            Walk::new(paths, ignore_options).with_extensions(Extensions(extensions)).paths();
+        if let Some(changed_files) = changed_files {
+            paths.retain(|path| {
+                let changed = changed_files.contains(path);
+                if !changed {
+                    trace!(path = %path.display(), "skipped: not changed");
+                }
+                changed
+            });
+        }
 
         let number_of_files = paths.len();
-
-        let cwd = std::env::current_dir().unwrap().into_boxed_path();
-        let lint_options = LintOptions::default()
-            .with_filter(filter)
-            .with_config_path(config)
-            .with_fix(fix_options.fix)
-            .with_import_plugin(enable_plugins.import_plugin)
-            .with_jest_plugin(enable_plugins.jest_plugin)
-            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
-            .with_nextjs_plugin(enable_plugins.nextjs_plugin)
-            .with_react_perf_plugin(enable_plugins.react_perf_plugin);
-
-        let linter = match Linter::from_options(lint_options) {
-            Ok(lint_service) => lint_service,
-            Err(diagnostic) => {
-                let handler = GraphicalReportHandler::new();
-                let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
-                eprintln!("{err}");
-                return CliRunResult::InvalidOptions {
-                    message: "Failed to parse configuration file.".to_string(),
-                };
-            }
+        let (cached, paths) = match cache {
+            Some(cache) => cache.partition(&linter, paths),
+            None => (vec![], paths),
         };
+        debug!(
+            "linting {} of {number_of_files} files with {} rules",
+            paths.len(),
+            linter.number_of_rules()
+        );
 
-        let options = LintServiceOptions { cwd, paths, tsconfig };
+        let options = self.service_options(cwd.into(), paths, processors);
         let lint_service = LintService::new(linter, options);
-        let mut diagnostic_service =
-            Self::get_diagnostic_service(&warning_options, &output_options);
+        let mut diagnostic_service = Self::get_diagnostic_service(warning_options, output_options)?;
+        // Reported as soon as the files sorted before them are linted
+        let mut expected_files = lint_service.report_paths();
+        if report_cached {
+            expected_files.extend(cached.iter().map(|(path, _)| path.clone()));
+        }
+        diagnostic_service = diagnostic_service.with_expected_files(expected_files);
+        if let Some(changed_files) = changed_files.filter(|_| git_options.changed_lines) {
+            let changed_files = Arc::clone(changed_files);
+            diagnostic_service = diagnostic_service
+                .with_line_filter(move |path, line| changed_files.contains_line(path, line));
+        }
 
-        // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
+        // Spawn linting in another thread so diagnostics are received by diagnostic_service.run while files are linted.
         rayon::spawn({
             let tx_error = diagnostic_service.sender().clone();
             let lint_service = lint_service.clone();
+            let cache = cache.cloned();
             move || {
-                lint_service.run(&tx_error);
+                if report_cached {
+                    for (path, diagnostics) in cached {
+                        tx_error.send(Some((path.clone(), diagnostics))).unwrap();
+                        tx_error.send(Some((path, vec![]))).unwrap();
+                    }
+                }
+                let Some(cache) = cache else {
+                    lint_service.run(&tx_error);
+                    return;
+                };
+                // The diagnostics of the linted files are stored on their way to diagnostic_service.
+                let (tx_linted, rx_linted) = mpsc::sync_channel(64);
+                let recorder = thread::spawn(move || cache.record(&rx_linted, &tx_error));
+                lint_service.run(&tx_linted);
+                recorder.join().unwrap();
             }
         });
         diagnostic_service.run();
 
-        CliRunResult::LintResult(LintResult {
+        if let Some(cache) = cache {
+            if let Err(err) = cache.save() {
+                eprintln!("Failed to write {CACHE_DIR}: {err}");
+            }
+        }
+
+        if !self.options.fix_options.fix_to_stdout {
+            if let Some(timings) = lint_service.linter().timings() {
+                let mut stdout = BufWriter::new(std::io::stdout());
+                timings.print(&mut stdout).unwrap();
+            }
+
+            if let Some(memory_usage) = lint_service.memory_usage() {
+                let mut stdout = BufWriter::new(std::io::stdout());
+                memory_usage.print(&mut stdout).unwrap();
+            }
+        }
+
+        let result = LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
             number_of_files,
@@ -136,31 +314,383 @@ impl Runner for LintRunner {
             number_of_errors: diagnostic_service.errors_count(),
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
             deny_warnings: warning_options.deny_warnings,
-        })
+        };
+
+        if let Some(path) = &output_options.metadata_file {
+            let started_at = SystemTime::now() - result.duration;
+            let metadata = RunMetadata::new(lint_service.linter(), started_at, &result);
+            if let Err(err) = metadata.write(path) {
+                eprintln!("Failed to write {}: {err}", path.display());
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Lint again whenever files change in `paths`, until the process is interrupted.
+    /// With the cache, only the diagnostics of the changed files are reported.
+    fn watch(
+        &self,
+        paths: &[PathBuf],
+        cwd: &Path,
+        changed_files: Option<&Arc<ChangedFiles>>,
+        cache: Option<&Arc<LintCache>>,
+    ) -> CliRunResult {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = match notify::recommended_watcher(tx) {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to watch files: {err}"),
+                }
+            }
+        };
+        for path in paths {
+            if let Err(err) = watcher.watch(path, RecursiveMode::Recursive) {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to watch {}: {err}", path.display()),
+                };
+            }
+        }
+
+        // Writing the reports must not trigger another lint
+        let output_files = self
+            .options
+            .output_options
+            .reporters()
+            .into_iter()
+            .filter_map(|reporter| match reporter.output {
+                ReportOutput::File(path) => Some(path),
+                _ => None,
+            })
+            .chain(self.options.output_options.metadata_file.clone())
+            .map(|path| oxc_linter::paths::absolute(&path, cwd))
+            .collect::<Vec<_>>();
+
+        println!("Watching for changes...");
+        while let Ok(event) = rx.recv() {
+            // Editors and formatters write files in several steps, wait for the events to settle.
+            let mut events = vec![event];
+            while let Ok(event) = rx.recv_timeout(WATCH_DEBOUNCE) {
+                events.push(event);
+            }
+            let changed = events
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|event| !event.kind.is_access())
+                .flat_map(|event| event.paths)
+                .filter(|path| {
+                    !path.components().any(|component| component.as_os_str() == CACHE_DIR)
+                })
+                .filter(|path| !output_files.contains(path))
+                .collect::<Vec<_>>();
+            if changed.is_empty() {
+                continue;
+            }
+            debug!("changed: {changed:?}");
+
+            match self.lint(paths, cwd, Instant::now(), changed_files, cache, false) {
+                Ok(result) => CliRunResult::LintResult(result).report(),
+                Err(result) => result.report(),
+            };
+            println!("Watching for changes...");
+        }
+        CliRunResult::None
+    }
+
+    fn get_lint_options(&self) -> LintOptions {
+        let CliLintOptions {
+            filter,
+            nursery,
+            fix_options,
+            enable_plugins,
+            config,
+            allow_plugins,
+            timing,
+            report_unused_disable_directives,
+            type_info,
+            ..
+        } = &self.options;
+        LintOptions::default()
+            .with_filter(filter.clone())
+            .with_nursery(*nursery)
+            .with_config_path(config.clone())
+            .with_nested_config(config.is_none())
+            .with_fix(
+                fix_options.fix
+                    || fix_options.fix_dry_run
+                    || fix_options.fix_to_stdout
+                    || fix_options.fix_suggestions
+                    || fix_options.fix_dangerously,
+            )
+            .with_fix_kind(if fix_options.fix_dangerously {
+                FixKind::Dangerous
+            } else if fix_options.fix_suggestions {
+                FixKind::Suggestion
+            } else {
+                FixKind::Safe
+            })
+            .with_fix_output(if fix_options.fix_to_stdout {
+                FixOutput::Stdout
+            } else if fix_options.fix_dry_run {
+                FixOutput::Diff
+            } else {
+                FixOutput::Write
+            })
+            .with_timing(*timing)
+            .with_report_unused_directives(*report_unused_disable_directives)
+            .with_type_info_path(type_info.clone())
+            .with_allow_plugins(*allow_plugins)
+            .with_import_plugin(enable_plugins.import_plugin)
+            .with_jest_plugin(enable_plugins.jest_plugin)
+            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
+            .with_nextjs_plugin(enable_plugins.nextjs_plugin)
+            .with_promise_plugin(enable_plugins.promise_plugin)
+            .with_react_perf_plugin(enable_plugins.react_perf_plugin)
+            .with_security_plugin(enable_plugins.security_plugin)
+            .with_jsdoc_plugin(enable_plugins.jsdoc_plugin)
+    }
+
+    /// The processors enabled by the options, keyed by file extension
+    fn processors(&self) -> FxHashMap<String, Arc<dyn Processor>> {
+        let mut processors: FxHashMap<String, Arc<dyn Processor>> = FxHashMap::default();
+        if self.options.markdown {
+            let processor: Arc<dyn Processor> = Arc::new(MarkdownProcessor);
+            for ext in MARKDOWN_EXT {
+                processors.insert((*ext).to_string(), Arc::clone(&processor));
+            }
+        }
+        processors
+    }
+
+    fn service_options(
+        &self,
+        cwd: Box<Path>,
+        paths: Vec<Box<Path>>,
+        processors: FxHashMap<String, Arc<dyn Processor>>,
+    ) -> LintServiceOptions {
+        let CliLintOptions {
+            tsconfig,
+            conditions,
+            preserve_symlinks,
+            mmap,
+            memory,
+            low_memory_threshold,
+            max_file_size,
+            max_node_count,
+            max_nesting_depth,
+            ..
+        } = &self.options;
+        // Linting from the root of a monorepo lints each package with its own `tsconfig.json`
+        let workspace = Workspace::discover(&cwd);
+        LintServiceOptions {
+            cwd,
+            paths,
+            tsconfig: tsconfig.clone(),
+            conditions: conditions.clone(),
+            preserve_symlinks: *preserve_symlinks,
+            mmap: *mmap,
+            memory_usage: *memory,
+            low_memory_threshold: *low_memory_threshold,
+            limits: InputLimits {
+                max_file_size: *max_file_size,
+                max_node_count: *max_node_count,
+                max_nesting_depth: *max_nesting_depth,
+            },
+            processors,
+            workspace,
+        }
+    }
+
+    /// The files to lint with `--changed` or `--staged`, `None` to lint all files.
+    fn changed_files(git_options: &GitOptions) -> Result<Option<ChangedFiles>, String> {
+        let diff = match (&git_options.changed, git_options.staged) {
+            (Some(_), true) => {
+                return Err("--changed and --staged cannot be used together.".to_string());
+            }
+            (Some(reference), false) => GitDiff::Since(reference),
+            (None, true) => GitDiff::Staged,
+            (None, false) if git_options.changed_lines => {
+                return Err("--changed-lines requires --changed or --staged.".to_string());
+            }
+            (None, false) => return Ok(None),
+        };
+        let cwd = env::current_dir().map_err(|err| err.to_string())?;
+        ChangedFiles::new(&cwd, diff).map(Some)
+    }
+
+    #[cfg(unix)]
+    fn run_daemon(&self) -> CliRunResult {
+        let linter = match Linter::from_options(self.get_lint_options()) {
+            Ok(linter) => linter,
+            Err(diagnostic) => return Self::config_error(&diagnostic),
+        };
+        let cwd = match env::current_dir() {
+            Ok(cwd) => oxc_linter::paths::normalize(&cwd).into_boxed_path(),
+            Err(err) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to get current working directory: {err}"),
+                }
+            }
+        };
+        let socket_path = match &self.options.daemon_socket {
+            Some(socket_path) => socket_path.clone(),
+            None => match Daemon::default_socket_path(&cwd) {
+                Ok(socket_path) => socket_path,
+                Err(err) => {
+                    return CliRunResult::InvalidOptions {
+                        message: format!("Failed to create {}: {err}", daemon::SOCKET_DIR),
+                    }
+                }
+            },
+        };
+        let options = self.service_options(cwd, vec![], self.processors());
+        match Daemon::bind(&socket_path, LintService::new(linter, options)) {
+            Ok(daemon) => {
+                println!("Listening on {}", socket_path.display());
+                daemon.serve();
+                CliRunResult::None
+            }
+            Err(err) => CliRunResult::InvalidOptions {
+                message: format!("Failed to listen on {}: {err}", socket_path.display()),
+            },
+        }
+    }
+
+    #[cfg(not(unix))]
+    #[allow(clippy::unused_self)]
+    fn run_daemon(&self) -> CliRunResult {
+        CliRunResult::InvalidOptions {
+            message:
+                "--daemon listens on a Unix domain socket, which is not supported on this platform."
+                    .to_string(),
+        }
+    }
+
+    fn print_config(path: &Path, lint_options: &LintOptions) -> CliRunResult {
+        if !path.exists() {
+            return CliRunResult::PathNotFound { paths: vec![path.to_path_buf()] };
+        }
+        match lint_options.resolved_config(path) {
+            Ok(config) => {
+                println!("{}", serde_json::to_string_pretty(&config).unwrap());
+                CliRunResult::None
+            }
+            Err(diagnostic) => Self::config_error(&diagnostic),
+        }
+    }
+
+    fn print_symbols(path: &Path) -> CliRunResult {
+        if !path.exists() {
+            return CliRunResult::PathNotFound { paths: vec![path.to_path_buf()] };
+        }
+        match symbols_tree(path) {
+            Ok(tree) => {
+                print!("{tree}");
+                CliRunResult::None
+            }
+            Err(message) => CliRunResult::InvalidOptions { message },
+        }
+    }
+
+    /// The files are found like for linting, without the JSON and markdown files.
+    fn script_paths(paths: &[PathBuf], ignore_options: &IgnoreOptions) -> Vec<Box<Path>> {
+        let extensions = VALID_EXTENSIONS
+            .iter()
+            .chain(LINT_PARTIAL_LOADER_EXT.iter())
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        Walk::new(paths, ignore_options).with_extensions(Extensions(extensions)).paths()
+    }
+
+    fn print_stats(paths: &[PathBuf], ignore_options: &IgnoreOptions) -> CliRunResult {
+        let stats = Self::script_paths(paths, ignore_options)
+            .par_iter()
+            .map(|path| CodeStats::of_file(path))
+            .reduce(CodeStats::default, CodeStats::merge);
+        println!("{}", serde_json::to_string_pretty(&stats.to_json()).unwrap());
+        CliRunResult::None
+    }
+
+    fn print_suppressions(paths: &[PathBuf], ignore_options: &IgnoreOptions) -> CliRunResult {
+        let stats = Self::script_paths(paths, ignore_options)
+            .par_iter()
+            .map(|path| SuppressionStats::of_file(path))
+            .reduce(SuppressionStats::default, SuppressionStats::merge);
+        println!("{}", serde_json::to_string_pretty(&stats.to_json()).unwrap());
+        CliRunResult::None
+    }
+
+    /// Diagnostics of the configuration, which are printed to stderr outside of the reporter
+    fn render_diagnostic(diagnostic: &Error) -> String {
+        let handler = GraphicalReportHandler::new();
+        let mut output = String::new();
+        handler.render_report(&mut output, diagnostic.as_ref()).unwrap();
+        output
+    }
+
+    fn config_error(diagnostic: &Error) -> CliRunResult {
+        eprintln!("{}", Self::render_diagnostic(diagnostic));
+        CliRunResult::InvalidOptions { message: "Failed to parse configuration file.".to_string() }
     }
-}
 
-impl LintRunner {
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
-    ) -> DiagnosticService {
+    ) -> Result<DiagnosticService, CliRunResult> {
+        // The output does not depend on the number of threads nor on the order files are linted in
         let mut diagnostic_service = DiagnosticService::default()
             .with_quiet(warning_options.quiet)
-            .with_max_warnings(warning_options.max_warnings);
+            .with_max_warnings(warning_options.max_warnings)
+            .with_stable_order(true);
 
-        match output_options.format {
-            OutputFormat::Default => {}
-            OutputFormat::Json => diagnostic_service.set_json_reporter(),
+        let mut output_files = vec![];
+        let mut reporters = vec![];
+        for Reporter { format, output } in output_options.reporters() {
+            let reporter = match format {
+                OutputFormat::Default => DiagnosticReporter::new_graphical(),
+                OutputFormat::Json => DiagnosticReporter::new_json(),
+                OutputFormat::Sarif => DiagnosticReporter::new_sarif(),
+                OutputFormat::Github => DiagnosticReporter::new_github(),
+            };
+            let reporter = match output {
+                ReportOutput::Default | ReportOutput::Stdout => reporter,
+                ReportOutput::Stderr => reporter.with_writer(io::stderr()),
+                ReportOutput::File(path) => {
+                    if output_files.contains(&path) {
+                        let message =
+                            format!("{} is the output of several formats.", path.display());
+                        return Err(CliRunResult::InvalidOptions { message });
+                    }
+                    let file = Self::create_output_file(&path).map_err(|err| {
+                        let message = format!("Failed to create {}: {err}", path.display());
+                        CliRunResult::InvalidOptions { message }
+                    })?;
+                    output_files.push(path);
+                    let handler =
+                        GraphicalReportHandler::new().with_theme(GraphicalTheme::unicode_nocolor());
+                    reporter.with_writer(file).with_handler(handler)
+                }
+            };
+            reporters.push(reporter);
         }
+        diagnostic_service.set_reporters(reporters);
+
+        Ok(diagnostic_service)
+    }
 
-        diagnostic_service
+    /// Create the file at `path` and its missing parent directories, as ESLint does
+    fn create_output_file(path: &Path) -> io::Result<fs::File> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::File::create(path)
     }
 }
 
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
-    use super::LintRunner;
+    use super::{FixKind, LintRunner};
     use crate::{lint_command, CliRunResult, LintResult, Runner};
 
     fn test(args: &[&str]) -> LintResult {
@@ -291,14 +821,26 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn nursery() {
+        let args = &["-A", "all", "-D", "nursery", "fixtures/no_undef/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &["-A", "all", "-D", "nursery", "--nursery", "fixtures/no_undef/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn eslintrc_no_env() {
         let args =
             &["-c", "fixtures/eslintrc_env/eslintrc_no_env.json", "fixtures/eslintrc_env/test.js"];
         let result = test(args);
         assert_eq!(result.number_of_files, 1);
-        assert_eq!(result.number_of_warnings, 1);
-        assert_eq!(result.number_of_errors, 0);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
     }
 
     #[test]
@@ -340,8 +882,8 @@ mod test {
         ];
         let result = test(args);
         assert_eq!(result.number_of_files, 1);
-        assert_eq!(result.number_of_warnings, 1);
-        assert_eq!(result.number_of_errors, 0);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
     }
 
     #[test]
@@ -363,8 +905,8 @@ mod test {
         ];
         let result = test(args);
         assert_eq!(result.number_of_files, 1);
-        assert_eq!(result.number_of_warnings, 1);
-        assert_eq!(result.number_of_errors, 0);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
     }
 
     #[test]
@@ -394,6 +936,325 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn nested_config() {
+        let args = &["fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn oxlintrc_overrides() {
+        let args = &["fixtures/overrides"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let args = &["fixtures/disable_directives"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &["--report-unused-disable-directives", "fixtures/disable_directives"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn nested_config_ignored_with_config() {
+        let args = &["-c", "fixtures/eslintrc_off/eslintrc.json", "fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn print_config() {
+        let args = &["--print-config", "fixtures/linter/debugger.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::None));
+
+        let args = &["--print-config", "fixtures/does_not_exist.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::PathNotFound { .. }));
+    }
+
+    #[test]
+    fn print_symbols() {
+        let args = &["--print-symbols", "fixtures/print_symbols/test.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::None));
+
+        let dir =
+            std::env::temp_dir().join(format!("oxc_cli_print_symbols_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let invalid = dir.join("invalid.js");
+        std::fs::write(&invalid, "const = ;\n").unwrap();
+        let args = ["--print-symbols", invalid.to_str().unwrap()];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::InvalidOptions { .. }));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let path = "fixtures/linter/debugger.js";
+        let source_text = std::fs::read_to_string(path).unwrap();
+        let result = test(&["--fix-dry-run", "--fix-suggestions", path]);
+        assert_eq!(result.number_of_files, 1);
+        // Fixed diagnostics are not reported, the fixed code is linted again and is an empty file.
+        // The file is left untouched.
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(std::fs::read_to_string(path).unwrap(), source_text);
+    }
+
+    #[test]
+    fn fix_kind() {
+        let fix_kind = |args: &[&str]| {
+            let options = lint_command().run_inner(args).unwrap().lint_options;
+            let lint_options = LintRunner::new(options).get_lint_options();
+            (lint_options.fix, lint_options.fix_kind)
+        };
+        assert_eq!(fix_kind(&["test.js"]), (false, FixKind::Safe));
+        assert_eq!(fix_kind(&["--fix", "test.js"]), (true, FixKind::Safe));
+        assert_eq!(fix_kind(&["--fix-suggestions", "test.js"]), (true, FixKind::Suggestion));
+        assert_eq!(
+            fix_kind(&["--fix-dry-run", "--fix-dangerously", "test.js"]),
+            (true, FixKind::Dangerous)
+        );
+    }
+
+    #[test]
+    fn fix_to_stdout_requires_single_file() {
+        let args = &["--fix-to-stdout", "fixtures/linter"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn watch_with_fix() {
+        let args = &["--watch", "--fix", "fixtures/linter"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn timing() {
+        let args = &["--timing", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn memory() {
+        let args = &["--memory", "--low-memory-threshold", "0", "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn custom_extension() {
+        let args = &["-c", "fixtures/extensions/eslintrc.json", "fixtures/extensions"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn mmap() {
+        let args = &["--mmap", "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+
+        // Files which are not valid UTF-8 are read instead, and fail the same way
+        let dir = std::env::temp_dir().join(format!("oxc_cli_mmap_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("latin1.js"), b"const caf\xE9 = 1;\n").unwrap();
+        let dir_arg = dir.to_str().unwrap();
+        for args in [&[dir_arg][..], &["--mmap", dir_arg]] {
+            let result = test(args);
+            assert_eq!(result.number_of_files, 1);
+            assert_eq!(result.number_of_errors, 1);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn output_files() {
+        let dir = std::env::temp_dir().join(format!("oxc_cli_output_{}", std::process::id()));
+        let sarif = dir.join("reports/oxlint.sarif");
+        let json = dir.join("oxlint.json");
+        let sarif_format = format!("sarif:{}", sarif.display());
+        let json_format = format!("json:{}", json.display());
+        let args = &[
+            "-D",
+            "no-debugger",
+            "-f",
+            &sarif_format,
+            "-f",
+            &json_format,
+            "fixtures/linter/debugger.js",
+        ];
+        // Without `--quiet`, which would leave the warning out of the reports
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let CliRunResult::LintResult(result) = LintRunner::new(options).run() else {
+            panic!("expected a lint result")
+        };
+        assert_eq!(result.number_of_warnings, 1);
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(sarif).unwrap()).unwrap();
+        assert_eq!(sarif["runs"][0]["results"].as_array().unwrap().len(), 1);
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(json).unwrap()).unwrap();
+        assert_eq!(json[0]["rule"], "eslint/no-debugger");
+
+        let args = ["-f", &json_format, "-f", &json_format, "fixtures/linter/debugger.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn import_resolver_settings() {
+        let args = &[
+            "--import-plugin",
+            "-A",
+            "all",
+            "-c",
+            "fixtures/import_resolver/eslintrc.json",
+            "fixtures/import_resolver/index.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &[
+            "--import-plugin",
+            "-A",
+            "all",
+            "-c",
+            "fixtures/import_resolver/eslintrc_without_settings.json",
+            "fixtures/import_resolver/index.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_errors, 3);
+    }
+
+    #[test]
+    fn type_info() {
+        let args = &["-A", "all", "-D", "no-floating-promises", "fixtures/type_info"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &[
+            "-A",
+            "all",
+            "-D",
+            "no-floating-promises",
+            "--type-info",
+            "fixtures/type_info/types.json",
+            "fixtures/type_info",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn git_options() {
+        for args in [&["--changed", "HEAD", "--staged"][..], &["--changed-lines"]] {
+            let options = lint_command().run_inner(args).unwrap().lint_options;
+            let result = LintRunner::new(options).run();
+            assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+        }
+
+        // The fixtures are committed
+        let args = &["--changed", "HEAD", "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+    }
+
+    #[test]
+    fn lint_html_file() {
+        let args = &["fixtures/html/debugger.html"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_markdown_file() {
+        let args = &["fixtures/markdown/debugger.md"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+
+        let args = &["--markdown", "fixtures/markdown"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_json_file() {
+        let args = &["fixtures/json"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+
+        let args = &["--json", "fixtures/json"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn input_limits() {
+        let args = &["--max-file-size", "1000", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+
+        // Skipped files are reported with an informational diagnostic, which is not counted
+        for limit in ["--max-file-size", "--max-node-count", "--max-nesting-depth"] {
+            let args = &[limit, "0", "fixtures/linter/debugger.js"];
+            let result = test(args);
+            assert_eq!(result.number_of_files, 1);
+            assert_eq!(result.number_of_warnings, 0);
+            assert_eq!(result.number_of_errors, 0);
+        }
+
+        // The parser stops at the nesting depth instead of overflowing the stack
+        let dir = std::env::temp_dir().join(format!("oxc_cli_limits_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let nested = format!("{}x{};\n", "(".repeat(100_000), ")".repeat(100_000));
+        std::fs::write(dir.join("nested.js"), nested).unwrap();
+        let args = &["--max-nesting-depth", "100", dir.to_str().unwrap()];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 0);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn lint_svelte_file() {
         let args = &["fixtures/svelte/debugger.svelte"];
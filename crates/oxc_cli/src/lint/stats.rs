@@ -0,0 +1,275 @@
+//! `--stats`: metrics of the code base aggregated from the parsed files, for tracking the adoption
+//! of TypeScript and of syntax features over time.
+
+use std::{collections::BTreeMap, ffi::OsStr, fs, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::PropertyKey, AstKind};
+use oxc_linter::partial_loader::{JavaScriptSource, PartialLoader};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    syntax_feature::SyntaxFeature,
+};
+use serde_json::json;
+
+/// The metrics of a set of files, merged from the metrics of each file.
+#[derive(Debug, Default)]
+pub struct CodeStats {
+    files: usize,
+    /// Files which cannot be read or have syntax errors. They are left out of the other metrics.
+    files_with_errors: usize,
+    lines: usize,
+    /// Including methods, getters, setters and arrow functions
+    functions: usize,
+    classes: usize,
+    /// Keyed by `javascript` and `typescript`
+    languages: BTreeMap<&'static str, LanguageStats>,
+    /// See `CodeStats::features_of`
+    features: BTreeMap<SyntaxFeature, FeatureStats>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct LanguageStats {
+    files: usize,
+    lines: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct FeatureStats {
+    /// Files using the feature at least once
+    files: usize,
+    /// Occurrences of the feature in all files
+    count: usize,
+}
+
+impl CodeStats {
+    /// The metrics of the file at `path`. Components such as `.vue` files count their scripts.
+    pub fn of_file(path: &Path) -> Self {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let Ok(source_text) = fs::read_to_string(path) else {
+            return Self { files: 1, files_with_errors: 1, ..Self::default() };
+        };
+        let sources = match PartialLoader::parse(ext, &source_text) {
+            Some(sources) => sources,
+            None => match SourceType::from_path(path) {
+                Ok(source_type) => vec![JavaScriptSource::new(&source_text, source_type, 0)],
+                Err(_) => vec![],
+            },
+        };
+
+        let mut stats = Self { files: 1, ..Self::default() };
+        let mut features = BTreeMap::<SyntaxFeature, usize>::new();
+        for source in &sources {
+            if !stats.add_source(source, &mut features) {
+                return Self { files: 1, files_with_errors: 1, ..Self::default() };
+            }
+        }
+
+        stats.lines = source_text.lines().count();
+        let language = if sources.iter().any(|source| source.source_type.is_typescript()) {
+            "typescript"
+        } else {
+            "javascript"
+        };
+        stats.languages.insert(language, LanguageStats { files: 1, lines: stats.lines });
+        stats.features = features
+            .into_iter()
+            .map(|(feature, count)| (feature, FeatureStats { files: 1, count }))
+            .collect();
+        stats
+    }
+
+    /// Returns `false` when the source has syntax errors.
+    fn add_source(
+        &mut self,
+        source: &JavaScriptSource,
+        features: &mut BTreeMap<SyntaxFeature, usize>,
+    ) -> bool {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source.source_text, source.source_type).parse();
+        if !ret.errors.is_empty() {
+            return false;
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source.source_text, source.source_type)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => self.functions += 1,
+                AstKind::Class(_) => self.classes += 1,
+                _ => {}
+            }
+            Self::features_of(node.kind(), |feature| *features.entry(feature).or_default() += 1);
+        }
+        true
+    }
+
+    /// Calls `add` with the syntax features used by a node, usually one of them
+    fn features_of<F: FnMut(SyntaxFeature)>(kind: AstKind, mut add: F) {
+        let feature = match kind {
+            AstKind::ArrowFunctionExpression(arrow) => {
+                if arrow.r#async {
+                    add(SyntaxFeature::AsyncFunctions);
+                }
+                SyntaxFeature::ArrowFunctions
+            }
+            AstKind::Function(func) => match (func.r#async, func.generator) {
+                (true, true) => SyntaxFeature::AsyncGenerators,
+                (true, false) => SyntaxFeature::AsyncFunctions,
+                (false, true) => SyntaxFeature::Generators,
+                (false, false) => return,
+            },
+            AstKind::AwaitExpression(_) => SyntaxFeature::Await,
+            AstKind::Class(_) => SyntaxFeature::Classes,
+            AstKind::PropertyKey(PropertyKey::PrivateIdentifier(_)) => {
+                SyntaxFeature::PrivateMembers
+            }
+            AstKind::StaticBlock(_) => SyntaxFeature::StaticBlocks,
+            AstKind::Decorator(_) => SyntaxFeature::Decorators,
+            AstKind::ChainExpression(_) => SyntaxFeature::OptionalChaining,
+            AstKind::LogicalExpression(expr) if expr.operator == LogicalOperator::Coalesce => {
+                SyntaxFeature::NullishCoalescing
+            }
+            AstKind::AssignmentExpression(expr) if expr.operator.is_logical() => {
+                SyntaxFeature::LogicalAssignment
+            }
+            AstKind::AssignmentExpression(expr)
+                if expr.operator == AssignmentOperator::Exponential =>
+            {
+                SyntaxFeature::Exponentiation
+            }
+            AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::Exponential => {
+                SyntaxFeature::Exponentiation
+            }
+            AstKind::SpreadElement(_) | AstKind::BindingRestElement(_) => {
+                SyntaxFeature::SpreadAndRest
+            }
+            AstKind::ArrayPattern(_) | AstKind::ObjectPattern(_) => SyntaxFeature::Destructuring,
+            AstKind::TemplateLiteral(_) => SyntaxFeature::TemplateLiterals,
+            AstKind::TaggedTemplateExpression(_) => SyntaxFeature::TaggedTemplates,
+            AstKind::BigintLiteral(_) => SyntaxFeature::Bigint,
+            AstKind::ImportExpression(_) => SyntaxFeature::DynamicImport,
+            AstKind::MetaProperty(meta) if meta.meta.name == "import" => SyntaxFeature::ImportMeta,
+            AstKind::ImportDeclaration(_)
+            | AstKind::ExportNamedDeclaration(_)
+            | AstKind::ExportDefaultDeclaration(_)
+            | AstKind::ExportAllDeclaration(_) => SyntaxFeature::EsModules,
+            AstKind::CallExpression(call) if call.callee.is_specific_id("require") => {
+                SyntaxFeature::CommonjsRequire
+            }
+            AstKind::UsingDeclaration(_) => SyntaxFeature::UsingDeclarations,
+            AstKind::JSXElement(_) | AstKind::JSXFragment(_) => SyntaxFeature::Jsx,
+            AstKind::TSEnumDeclaration(_) => SyntaxFeature::TsEnums,
+            AstKind::TSModuleDeclaration(_) => SyntaxFeature::TsNamespaces,
+            AstKind::TSInterfaceDeclaration(_) => SyntaxFeature::TsInterfaces,
+            AstKind::TSTypeAliasDeclaration(_) => SyntaxFeature::TsTypeAliases,
+            AstKind::TSTypeParameterDeclaration(_) => SyntaxFeature::TsGenerics,
+            AstKind::TSAsExpression(_) | AstKind::TSTypeAssertion(_) => {
+                SyntaxFeature::TsTypeAssertions
+            }
+            AstKind::TSSatisfiesExpression(_) => SyntaxFeature::TsSatisfies,
+            AstKind::TSNonNullExpression(_) => SyntaxFeature::TsNonNullAssertions,
+            AstKind::TSAnyKeyword(_) => SyntaxFeature::TsAny,
+            _ => return,
+        };
+        add(feature);
+    }
+
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        self.files += other.files;
+        self.files_with_errors += other.files_with_errors;
+        self.lines += other.lines;
+        self.functions += other.functions;
+        self.classes += other.classes;
+        for (language, stats) in other.languages {
+            let entry = self.languages.entry(language).or_default();
+            entry.files += stats.files;
+            entry.lines += stats.lines;
+        }
+        for (feature, stats) in other.features {
+            let entry = self.features.entry(feature).or_default();
+            entry.files += stats.files;
+            entry.count += stats.count;
+        }
+        self
+    }
+
+    /// The share of each language is the ratio of its lines to the lines of all files.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_json(&self) -> serde_json::Value {
+        let languages = self
+            .languages
+            .iter()
+            .map(|(language, stats)| {
+                let share = if self.lines == 0 {
+                    0.0
+                } else {
+                    (stats.lines as f64 / self.lines as f64 * 1000.0).round() / 1000.0
+                };
+                let json = json!({ "files": stats.files, "lines": stats.lines, "share": share });
+                ((*language).to_string(), json)
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let features = self
+            .features
+            .iter()
+            .map(|(feature, stats)| {
+                (feature.name().to_string(), json!({ "files": stats.files, "count": stats.count }))
+            })
+            .collect::<serde_json::Map<_, _>>();
+        json!({
+            "files": self.files,
+            "filesWithErrors": self.files_with_errors,
+            "lines": self.lines,
+            "functions": self.functions,
+            "classes": self.classes,
+            "languages": languages,
+            "features": features,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::CodeStats;
+
+    #[test]
+    fn stats() {
+        // Not a fixture, as `oxlint` without arguments would report its syntax error
+        let dir = std::env::temp_dir().join(format!("oxc_cli_stats_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let invalid = dir.join("invalid.js");
+        std::fs::write(&invalid, "const = ;\n").unwrap();
+        let stats = [Path::new("fixtures/stats/a.js"), Path::new("fixtures/stats/b.ts"), &invalid]
+            .into_iter()
+            .map(CodeStats::of_file)
+            .fold(CodeStats::default(), CodeStats::merge);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let json = stats.to_json();
+        assert_eq!(json["files"], 3);
+        assert_eq!(json["filesWithErrors"], 1);
+        assert_eq!(json["lines"], 12);
+        assert_eq!(json["functions"], 4);
+        assert_eq!(json["classes"], 1);
+        assert_eq!(json["languages"]["javascript"]["files"], 1);
+        assert_eq!(json["languages"]["typescript"]["lines"], 8);
+        assert_eq!(json["features"]["arrow-functions"], json!({ "files": 2, "count": 3 }));
+        assert_eq!(json["features"]["optional-chaining"], json!({ "files": 1, "count": 2 }));
+        assert_eq!(json["features"]["commonjs-require"]["count"], 1);
+        assert_eq!(json["features"]["async-functions"]["files"], 1);
+        assert_eq!(json["features"]["private-members"]["count"], 1);
+        assert_eq!(json["features"]["ts-interfaces"]["count"], 1);
+        assert!(json["features"]["generators"].is_null());
+    }
+}
@@ -0,0 +1,145 @@
+//! `--suppressions`: the `eslint-disable` directives of the code base counted by rule and by file,
+//! for tracking and burning down the suppressed diagnostics over time.
+
+use std::{collections::BTreeMap, ffi::OsStr, fs, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_ast::TriviasMap;
+use oxc_linter::{
+    disable_comments,
+    partial_loader::{JavaScriptSource, PartialLoader},
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use serde_json::json;
+
+/// The name counting the directives which disable all rules
+const ALL_RULES: &str = "all";
+
+/// The directives of a set of files, merged from the directives of each file.
+#[derive(Debug, Default)]
+pub struct SuppressionStats {
+    /// Files which cannot be read or have syntax errors. Their directives are not counted.
+    files_with_errors: usize,
+    /// The directives of each file, for the files with at least one directive
+    files: BTreeMap<String, FileSuppressions>,
+}
+
+#[derive(Debug, Default)]
+struct FileSuppressions {
+    /// Number of directive comments
+    directives: usize,
+    /// Number of directives disabling each rule, keyed by the name written in the comments
+    rules: BTreeMap<String, usize>,
+}
+
+impl SuppressionStats {
+    /// The directives of the file at `path`. Components such as `.vue` files count the directives of
+    /// their scripts.
+    pub fn of_file(path: &Path) -> Self {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let Ok(source_text) = fs::read_to_string(path) else {
+            return Self { files_with_errors: 1, ..Self::default() };
+        };
+        let sources = match PartialLoader::parse(ext, &source_text) {
+            Some(sources) => sources,
+            None => match SourceType::from_path(path) {
+                Ok(source_type) => vec![JavaScriptSource::new(&source_text, source_type, 0)],
+                Err(_) => vec![],
+            },
+        };
+
+        let mut file = FileSuppressions::default();
+        for source in &sources {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source.source_text, source.source_type).parse();
+            if !ret.errors.is_empty() {
+                return Self { files_with_errors: 1, ..Self::default() };
+            }
+            let trivias = TriviasMap::from(ret.trivias);
+            for comment in disable_comments(source.source_text, &trivias) {
+                file.directives += 1;
+                if comment.rules.is_empty() {
+                    *file.rules.entry(ALL_RULES.to_string()).or_default() += 1;
+                }
+                for rule in comment.rules {
+                    *file.rules.entry(rule.to_string()).or_default() += 1;
+                }
+            }
+        }
+
+        let mut stats = Self::default();
+        if file.directives > 0 {
+            stats.files.insert(path.to_string_lossy().replace('\\', "/"), file);
+        }
+        stats
+    }
+
+    pub fn merge(mut self, other: Self) -> Self {
+        self.files_with_errors += other.files_with_errors;
+        self.files.extend(other.files);
+        self
+    }
+
+    /// The directives counted by rule for all files, then for each file.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut rules = BTreeMap::<&str, usize>::new();
+        for file in self.files.values() {
+            for (rule, count) in &file.rules {
+                *rules.entry(rule).or_default() += count;
+            }
+        }
+        let files = self
+            .files
+            .iter()
+            .map(|(path, file)| {
+                (path.clone(), json!({ "directives": file.directives, "rules": file.rules }))
+            })
+            .collect::<serde_json::Map<_, _>>();
+        json!({
+            "directives": self.files.values().map(|file| file.directives).sum::<usize>(),
+            "filesWithErrors": self.files_with_errors,
+            "rules": rules,
+            "files": files,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::SuppressionStats;
+
+    #[test]
+    fn suppressions() {
+        let stats = [
+            "fixtures/suppressions/a.js",
+            "fixtures/suppressions/b.ts",
+            "fixtures/stats/a.js",
+            "fixtures/stats/invalid.js",
+        ]
+        .into_iter()
+        .map(|path| SuppressionStats::of_file(Path::new(path)))
+        .fold(SuppressionStats::default(), SuppressionStats::merge);
+        let json = stats.to_json();
+        assert_eq!(json["directives"], 5);
+        assert_eq!(json["filesWithErrors"], 1);
+        assert_eq!(
+            json["rules"],
+            json!({
+                "@typescript-eslint/no-explicit-any": 1,
+                "all": 1,
+                "no-console": 3,
+                "no-debugger": 1
+            })
+        );
+        assert_eq!(
+            json["files"]["fixtures/suppressions/a.js"],
+            json!({ "directives": 3, "rules": { "all": 1, "no-console": 2, "no-debugger": 1 } })
+        );
+        assert!(json["files"]["fixtures/stats/a.js"].is_null());
+    }
+}
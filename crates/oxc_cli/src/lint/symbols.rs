@@ -0,0 +1,229 @@
+//! `--print-symbols`: the scopes, symbols and references of a file as a tree, for debugging
+//! unexpected results of rules such as `no-unused-vars` and `no-undef`.
+
+use std::{ffi::OsStr, fmt::Write, fs, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_linter::partial_loader::{JavaScriptSource, PartialLoader};
+use oxc_parser::Parser;
+use oxc_semantic::{Reference, ScopeId, Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{CompactString, GetSpan, SourceType, Span};
+use rustc_hash::FxHashMap;
+
+/// The tree of the scopes of the file at `path`, with the symbols declared in each scope and their
+/// references. The references which are not resolved to any symbol are listed in the top scope.
+/// Components such as `.vue` files have a tree for each of their scripts.
+pub fn symbols_tree(path: &Path) -> Result<String, String> {
+    let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+    let source_text = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+    let sources = match PartialLoader::parse(ext, &source_text) {
+        Some(sources) => sources,
+        None => {
+            let source_type = SourceType::from_path(path).map_err(|_| {
+                format!("{} is not a JavaScript or TypeScript file.", path.display())
+            })?;
+            vec![JavaScriptSource::new(&source_text, source_type, 0)]
+        }
+    };
+
+    let line_starts = std::iter::once(0)
+        .chain(source_text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect::<Vec<_>>();
+    let mut output = String::new();
+    for source in &sources {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source.source_text, source.source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(format!("{} has syntax errors, lint it to report them.", path.display()));
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source.source_text, source.source_type)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+        TreePrinter::new(&semantic, &line_starts, source.start, &mut output).print();
+    }
+    Ok(output)
+}
+
+struct TreePrinter<'p, 'a> {
+    semantic: &'p Semantic<'a>,
+    /// The span of the node creating each scope, which is the first node of the scope
+    scope_spans: FxHashMap<ScopeId, Span>,
+    /// The offsets of the lines of the file
+    line_starts: &'p [usize],
+    /// The offset of the source in the file
+    start: usize,
+    output: &'p mut String,
+}
+
+/// The children of a scope in the tree
+enum Entry<'p> {
+    Scope(ScopeId),
+    Symbol(SymbolId),
+    Unresolved(&'p CompactString, Vec<&'p Reference>),
+}
+
+impl<'p, 'a> TreePrinter<'p, 'a> {
+    fn new(
+        semantic: &'p Semantic<'a>,
+        line_starts: &'p [usize],
+        start: usize,
+        output: &'p mut String,
+    ) -> Self {
+        let mut scope_spans = FxHashMap::default();
+        for node in semantic.nodes().iter() {
+            scope_spans.entry(node.scope_id()).or_insert_with(|| node.kind().span());
+        }
+        Self { semantic, scope_spans, line_starts, start, output }
+    }
+
+    fn print(&mut self) {
+        let root_scope_id = self.semantic.scopes().root_scope_id();
+        self.print_scope(root_scope_id);
+        self.print_children(&self.scope_entries(root_scope_id), "");
+    }
+
+    fn scope_entries(&self, scope_id: ScopeId) -> Vec<Entry<'p>> {
+        let semantic = self.semantic;
+        let scopes = semantic.scopes();
+        let mut entries = scopes
+            .get_bindings(scope_id)
+            .values()
+            .map(|symbol_id| Entry::Symbol(*symbol_id))
+            .chain(scopes.get_child_ids(scope_id).iter().map(|child_id| Entry::Scope(*child_id)))
+            .collect::<Vec<_>>();
+        if scope_id == scopes.root_scope_id() {
+            let symbols = semantic.symbols();
+            let mut unresolved = scopes
+                .root_unresolved_references()
+                .iter()
+                .map(|(name, reference_ids)| {
+                    let references = reference_ids
+                        .iter()
+                        .map(|reference_id| symbols.get_reference(*reference_id))
+                        .collect::<Vec<_>>();
+                    (name, references)
+                })
+                .collect::<Vec<_>>();
+            unresolved.sort_unstable_by_key(|(_, references)| {
+                references.first().map(|reference| reference.span().start)
+            });
+            entries.extend(
+                unresolved
+                    .into_iter()
+                    .map(|(name, references)| Entry::Unresolved(name, references)),
+            );
+        }
+        entries
+    }
+
+    fn print_children(&mut self, entries: &[Entry<'p>], prefix: &str) {
+        let semantic = self.semantic;
+        for (i, entry) in entries.iter().enumerate() {
+            let is_last = i + 1 == entries.len();
+            self.output.push_str(prefix);
+            self.output.push_str(if is_last { "└─ " } else { "├─ " });
+            let prefix = format!("{prefix}{}", if is_last { "   " } else { "│  " });
+            match entry {
+                Entry::Scope(scope_id) => {
+                    self.print_scope(*scope_id);
+                    self.print_children(&self.scope_entries(*scope_id), &prefix);
+                }
+                Entry::Symbol(symbol_id) => {
+                    let symbols = semantic.symbols();
+                    let flags = symbols.get_flag(*symbol_id);
+                    let flags = flags.iter_names().map(|(name, _)| name).collect::<Vec<_>>();
+                    let position = self.position(symbols.get_span(*symbol_id));
+                    let name = symbols.get_name(*symbol_id);
+                    _ = writeln!(self.output, "symbol `{name}` ({}) {position}", flags.join(" | "));
+                    let references =
+                        symbols.get_resolved_references(*symbol_id).collect::<Vec<_>>();
+                    self.print_references(&references, &prefix);
+                }
+                Entry::Unresolved(name, references) => {
+                    _ = writeln!(self.output, "unresolved `{name}`");
+                    self.print_references(references, &prefix);
+                }
+            }
+        }
+    }
+
+    fn print_scope(&mut self, scope_id: ScopeId) {
+        let flags = self.semantic.scopes().get_flags(scope_id);
+        let flags = flags.iter_names().map(|(name, _)| name).collect::<Vec<_>>();
+        self.output.push_str("scope");
+        if !flags.is_empty() {
+            _ = write!(self.output, " ({})", flags.join(" | "));
+        }
+        if let Some(span) = self.scope_spans.get(&scope_id) {
+            _ = write!(self.output, " {}", self.position(*span));
+        }
+        self.output.push('\n');
+    }
+
+    fn print_references(&mut self, references: &[&Reference], prefix: &str) {
+        let mut references = references.to_vec();
+        references.sort_unstable_by_key(|reference| reference.span().start);
+        for (i, reference) in references.iter().enumerate() {
+            let is_last = i + 1 == references.len();
+            let kind = match (reference.is_type(), reference.is_read(), reference.is_write()) {
+                (true, _, _) => "type",
+                (false, true, true) => "read write",
+                (false, false, true) => "write",
+                _ => "read",
+            };
+            let position = self.position(reference.span());
+            let branch = if is_last { "└─ " } else { "├─ " };
+            _ = writeln!(self.output, "{prefix}{branch}{kind} {position}");
+        }
+    }
+
+    /// The line and column of the start of `span` in the file, starting at 1
+    fn position(&self, span: Span) -> String {
+        let offset = self.start + span.start as usize;
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        format!("{line}:{}", offset - self.line_starts[line - 1] + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::symbols_tree;
+
+    #[test]
+    fn symbols() {
+        let tree = symbols_tree(Path::new("fixtures/print_symbols/test.js")).unwrap();
+        let expected = "\
+scope (StrictMode | Top) 1:1
+├─ symbol `foo` (BlockScopedVariable | ConstVariable) 1:7
+│  ├─ read 4:3
+│  └─ read 7:5
+├─ symbol `bar` (BlockScopedVariable | Function) 3:10
+│  └─ read 7:1
+├─ scope (StrictMode | Function) 3:1
+│  └─ symbol `baz` (FunctionScopedVariable) 3:14
+│     ├─ read write 4:9
+│     └─ read 5:10
+├─ scope (StrictMode) 8:1
+│  └─ symbol `qux` (BlockScopedVariable) 8:7
+└─ unresolved `console`
+   └─ read 7:10
+";
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(symbols_tree(Path::new("fixtures/print_symbols/does_not_exist.js")).is_err());
+
+        let dir = std::env::temp_dir().join(format!("oxc_cli_symbols_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("invalid.js"), "const = ;\n").unwrap();
+        assert!(symbols_tree(&dir.join("invalid.js")).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -8,7 +8,10 @@ static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use oxc_cli::{CliCommand, CliRunResult, FormatRunner, LintRunner, Runner};
+use oxc_cli::{
+    AstRunner, CliCommand, CliRunResult, FormatRunner, GraphRunner, LintRunner, ReduceRunner,
+    Runner,
+};
 
 fn main() -> CliRunResult {
     let options = oxc_cli::cli_command().fallback_to_usage().run();
@@ -16,5 +19,8 @@ fn main() -> CliRunResult {
     match options {
         CliCommand::Lint(options) => LintRunner::new(options).run(),
         CliCommand::Format(options) => FormatRunner::new(options).run(),
+        CliCommand::Graph(options) => GraphRunner::new(options).run(),
+        CliCommand::Reduce(options) => ReduceRunner::new(options).run(),
+        CliCommand::Ast(options) => AstRunner::new(options).run(),
     }
 }
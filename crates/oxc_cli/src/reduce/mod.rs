@@ -0,0 +1,116 @@
+mod reducer;
+
+use std::{fs, panic, path::Path, rc::Rc};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{AllowWarnDeny, LintContext, LintOptions, Linter};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use crate::{
+    command::ReduceOptions,
+    result::{CliRunResult, ReduceResult},
+    Runner,
+};
+
+use self::reducer::Reducer;
+
+pub struct ReduceRunner {
+    options: ReduceOptions,
+}
+
+impl Runner for ReduceRunner {
+    type Options = ReduceOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let ReduceOptions { diagnostic, output, path } = self.options;
+
+        let Ok(source_text) = fs::read_to_string(&path) else {
+            return CliRunResult::PathNotFound { paths: vec![path] };
+        };
+        let Ok(source_type) = SourceType::from_path(&path) else {
+            let message = format!("{path:?} is not a JavaScript or TypeScript file");
+            return CliRunResult::InvalidOptions { message };
+        };
+        let options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Deny, "all".into())])
+            .with_jest_plugin(true)
+            .with_jsx_a11y_plugin(true)
+            .with_nextjs_plugin(true)
+            .with_promise_plugin(true)
+            .with_react_perf_plugin(true)
+            .with_security_plugin(true)
+            .with_jsdoc_plugin(true);
+        let linter = match Linter::from_options(options) {
+            Ok(linter) => linter,
+            Err(err) => return CliRunResult::InvalidOptions { message: err.to_string() },
+        };
+
+        let now = std::time::Instant::now();
+        // The panics are expected while reducing a crash, do not print them
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let mut reducer = Reducer::new(source_type, |source_text: &str| {
+            let messages = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                lint(&linter, &path, source_text, source_type)
+            }));
+            match (&diagnostic, messages) {
+                (None, messages) => messages.is_err(),
+                (Some(text), Ok(messages)) => messages.iter().any(|m| m.contains(text.as_str())),
+                (Some(_), Err(_)) => false,
+            }
+        });
+        let reduced = reducer.test(&source_text).then(|| reducer.reduce(&source_text));
+        let number_of_tests = reducer.tests();
+        panic::set_hook(hook);
+
+        let Some(reduced) = reduced else {
+            let message = match &diagnostic {
+                Some(text) => format!("{path:?} does not report a diagnostic containing {text:?}"),
+                None => format!("{path:?} does not crash"),
+            };
+            return CliRunResult::InvalidOptions { message };
+        };
+
+        match &output {
+            Some(output) => {
+                if let Err(err) = fs::write(output, &reduced) {
+                    let message = format!("Failed to write {output:?}: {err}");
+                    return CliRunResult::InvalidOptions { message };
+                }
+            }
+            None => print!("{reduced}"),
+        }
+
+        CliRunResult::ReduceResult(ReduceResult {
+            duration: now.elapsed(),
+            original_size: source_text.len(),
+            reduced_size: reduced.len(),
+            number_of_tests,
+        })
+    }
+}
+
+/// The messages of the diagnostics reported for `source_text`, including the syntax errors.
+fn lint(linter: &Linter, path: &Path, source_text: &str, source_type: SourceType) -> Vec<String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return ret.errors.iter().map(ToString::to_string).collect();
+    }
+    let program = allocator.alloc(ret.program);
+    let semantic_ret = SemanticBuilder::new(source_text, source_type)
+        .with_trivias(ret.trivias)
+        .with_check_syntax_error(true)
+        .build(program);
+    if !semantic_ret.errors.is_empty() {
+        return semantic_ret.errors.iter().map(ToString::to_string).collect();
+    }
+    let lint_ctx = LintContext::new(path.into(), &Rc::new(semantic_ret.semantic));
+    linter.run(lint_ctx).into_iter().map(|message| message.error.to_string()).collect()
+}
@@ -0,0 +1,349 @@
+//! Delta debugging over the syntax tree, see `Reducer`.
+
+use std::panic;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Statement, AstKind, Visit};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+/// Shrinks source code while a predicate still holds for it, e.g. that it crashes the linter.
+///
+/// The statements of each statement list, then the lines of the file, are removed with the
+/// ddmin algorithm, which tries to remove large chunks first. Then the nodes are simplified one at
+/// a time: expressions are replaced by one of their operands, function bodies are emptied,
+/// and the items of arguments, parameters, arrays, objects and classes are removed.
+/// The code is parsed again after each successful step, until nothing can be removed.
+pub struct Reducer<F: FnMut(&str) -> bool> {
+    source_type: SourceType,
+    predicate: F,
+    /// The number of times the predicate was run
+    tests: usize,
+}
+
+/// Replaces `start..end` of the source text with `replacement`.
+#[derive(Debug)]
+struct Edit {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+#[derive(Debug, Default)]
+struct Candidates {
+    /// The spans of the statements of each statement list, outer lists first
+    statement_lists: Vec<Vec<Span>>,
+    edits: Vec<Edit>,
+}
+
+impl<F: FnMut(&str) -> bool> Reducer<F> {
+    pub fn new(source_type: SourceType, predicate: F) -> Self {
+        Self { source_type, predicate, tests: 0 }
+    }
+
+    pub fn tests(&self) -> usize {
+        self.tests
+    }
+
+    pub fn test(&mut self, source_text: &str) -> bool {
+        self.tests += 1;
+        (self.predicate)(source_text)
+    }
+
+    /// The smallest code found for which the predicate holds,
+    /// which must hold for `source_text` in the first place.
+    pub fn reduce(&mut self, source_text: &str) -> String {
+        let mut source_text = source_text.to_string();
+        loop {
+            let len = source_text.len();
+            source_text = self.remove_statements(source_text);
+            source_text = self.simplify_nodes(source_text);
+            if source_text.len() == len {
+                return source_text;
+            }
+        }
+    }
+
+    fn remove_statements(&mut self, mut source_text: String) -> String {
+        'reduce: loop {
+            let candidates = self.candidates(&source_text).unwrap_or_default();
+            let lists = candidates.statement_lists.into_iter().chain([lines(&source_text)]);
+            for units in lists {
+                if let Some(reduced) = self.ddmin(&source_text, units) {
+                    source_text = reduced;
+                    continue 'reduce;
+                }
+            }
+            return source_text;
+        }
+    }
+
+    fn simplify_nodes(&mut self, mut source_text: String) -> String {
+        'reduce: loop {
+            let mut edits = self.candidates(&source_text).unwrap_or_default().edits;
+            // The largest reductions first
+            edits.sort_by_key(|edit| {
+                edit.replacement.len() as isize - (edit.end - edit.start) as isize
+            });
+            for edit in edits {
+                let reduced = edit.apply(&source_text);
+                if reduced.len() < source_text.len() && self.test(&reduced) {
+                    source_text = reduced;
+                    continue 'reduce;
+                }
+            }
+            return source_text;
+        }
+    }
+
+    /// Remove as many of the non-overlapping `units` as possible from `source_text`,
+    /// `None` if none of them can be removed.
+    fn ddmin(&mut self, source_text: &str, mut units: Vec<Span>) -> Option<String> {
+        units.retain(|span| span.size() != 0);
+        units.sort_unstable_by_key(|span| span.start);
+        let mut kept = (0..units.len()).collect::<Vec<_>>();
+        let mut reduced = None;
+        let mut chunks = 2;
+        while !kept.is_empty() {
+            let chunk_size = kept.len().div_ceil(chunks);
+            let mut removed = false;
+            for chunk_start in (0..kept.len()).step_by(chunk_size) {
+                let chunk_end = (chunk_start + chunk_size).min(kept.len());
+                let candidate = kept[..chunk_start].iter().chain(&kept[chunk_end..]).copied();
+                let candidate = candidate.collect::<Vec<_>>();
+                let text = remove_units(source_text, &units, &candidate);
+                if self.test(&text) {
+                    kept = candidate;
+                    reduced = Some(text);
+                    chunks = (chunks - 1).max(2);
+                    removed = true;
+                    break;
+                }
+            }
+            if !removed {
+                if chunks >= kept.len() {
+                    break;
+                }
+                chunks = (chunks * 2).min(kept.len());
+            }
+        }
+        reduced
+    }
+
+    /// `None` if the parser crashes.
+    fn candidates(&self, source_text: &str) -> Option<Candidates> {
+        let source_type = self.source_type;
+        panic::catch_unwind(|| {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let program = allocator.alloc(ret.program);
+            let mut collector = Collector { source_text, candidates: Candidates::default() };
+            collector.visit_program(program);
+            collector.candidates
+        })
+        .ok()
+    }
+}
+
+impl Edit {
+    fn apply(&self, source_text: &str) -> String {
+        format!("{}{}{}", &source_text[..self.start], self.replacement, &source_text[self.end..])
+    }
+}
+
+/// `source_text` with only the `units` at the indices `kept`, which are sorted.
+fn remove_units(source_text: &str, units: &[Span], kept: &[usize]) -> String {
+    let mut text = String::with_capacity(source_text.len());
+    let mut end = 0;
+    let mut kept = kept.iter().peekable();
+    for (index, unit) in units.iter().enumerate() {
+        if kept.peek() == Some(&&index) {
+            kept.next();
+            continue;
+        }
+        text.push_str(&source_text[end..unit.start as usize]);
+        end = unit.end as usize;
+    }
+    text.push_str(&source_text[end..]);
+    text
+}
+
+/// The spans of the lines of `source_text`, with their line break.
+fn lines(source_text: &str) -> Vec<Span> {
+    let mut start = 0;
+    source_text
+        .split_inclusive('\n')
+        .map(|line| {
+            let span = Span::new(start, start + line.len() as u32);
+            start = span.end;
+            span
+        })
+        .collect()
+}
+
+struct Collector<'s> {
+    source_text: &'s str,
+    candidates: Candidates,
+}
+
+impl<'s> Collector<'s> {
+    fn replace(&mut self, span: Span, replacement: &str) {
+        let (start, end) = (span.start as usize, span.end as usize);
+        if self.source_text[start..end] != *replacement {
+            let replacement = replacement.to_string();
+            self.candidates.edits.push(Edit { start, end, replacement });
+        }
+    }
+
+    /// Replace the node at `span` with its child at `child`.
+    fn replace_with(&mut self, span: Span, child: Span) {
+        let replacement = &self.source_text[child.start as usize..child.end as usize];
+        self.replace(span, replacement);
+    }
+
+    /// Remove an item of a comma separated list, with the comma and the whitespace after it.
+    fn remove_item(&mut self, span: Span) {
+        let rest = &self.source_text[span.end as usize..];
+        let trimmed = rest.trim_start();
+        let end = match trimmed.strip_prefix(',') {
+            Some(after_comma) => self.source_text.len() - after_comma.trim_start().len(),
+            None => span.end as usize,
+        };
+        self.candidates.edits.push(Edit {
+            start: span.start as usize,
+            end,
+            replacement: String::new(),
+        });
+    }
+}
+
+impl<'a, 's> Visit<'a> for Collector<'s> {
+    fn visit_statements(&mut self, stmts: &oxc_allocator::Vec<'a, Statement<'a>>) {
+        if !stmts.is_empty() {
+            self.candidates.statement_lists.push(stmts.iter().map(GetSpan::span).collect());
+        }
+        for stmt in stmts {
+            self.visit_statement(stmt);
+        }
+    }
+
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::FunctionBody(body) if !body.is_empty() => self.replace(body.span, "{}"),
+            AstKind::BinaryExpression(expr) => {
+                self.replace_with(expr.span, expr.left.span());
+                self.replace_with(expr.span, expr.right.span());
+            }
+            AstKind::LogicalExpression(expr) => {
+                self.replace_with(expr.span, expr.left.span());
+                self.replace_with(expr.span, expr.right.span());
+            }
+            AstKind::ConditionalExpression(expr) => {
+                self.replace_with(expr.span, expr.test.span());
+                self.replace_with(expr.span, expr.consequent.span());
+                self.replace_with(expr.span, expr.alternate.span());
+            }
+            AstKind::ParenthesizedExpression(expr) => {
+                self.replace_with(expr.span, expr.expression.span());
+            }
+            AstKind::SequenceExpression(expr) => {
+                for expression in &expr.expressions {
+                    self.replace_with(expr.span, expression.span());
+                }
+            }
+            AstKind::CallExpression(expr) => {
+                self.replace_with(expr.span, expr.callee.span());
+                for argument in &expr.arguments {
+                    self.remove_item(argument.span());
+                }
+            }
+            AstKind::NewExpression(expr) => {
+                self.replace_with(expr.span, expr.callee.span());
+                for argument in &expr.arguments {
+                    self.remove_item(argument.span());
+                }
+            }
+            AstKind::ArrayExpression(expr) => {
+                for element in &expr.elements {
+                    self.remove_item(element.span());
+                }
+            }
+            AstKind::ObjectExpression(expr) => {
+                for property in &expr.properties {
+                    self.remove_item(property.span());
+                }
+            }
+            AstKind::FormalParameters(params) => {
+                for param in &params.items {
+                    self.remove_item(param.span);
+                }
+            }
+            AstKind::VariableDeclaration(decl) if decl.declarations.len() > 1 => {
+                for declarator in &decl.declarations {
+                    self.remove_item(declarator.span);
+                }
+            }
+            AstKind::ClassBody(body) => {
+                for element in &body.body {
+                    self.replace(element.span(), "");
+                }
+            }
+            AstKind::IfStatement(stmt) => {
+                self.replace_with(stmt.span, stmt.consequent.span());
+                if stmt.alternate.is_some() {
+                    let end = Span::new(stmt.consequent.span().end, stmt.span.end);
+                    self.replace(end, "");
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::{SourceType, Span};
+
+    use super::{remove_units, Reducer};
+
+    fn parses(source_text: &str) -> bool {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        ret.errors.is_empty()
+    }
+
+    #[test]
+    fn ddmin_units() {
+        let units = [Span::new(0, 2), Span::new(2, 4), Span::new(4, 6)];
+        assert_eq!(remove_units("a;b;c;", &units, &[0, 2]), "a;c;");
+        assert_eq!(remove_units("a;b;c;", &units, &[]), "");
+    }
+
+    #[test]
+    fn reduce() {
+        let source_text = "\
+function f(a, b) {
+  if (a) {
+    foo(a + b, [1, 2]);
+  } else {
+    bar();
+  }
+  debugger;
+  return a * b;
+}
+const x = { a: 1, b: [1, 2, 3] };
+";
+        let mut reducer = Reducer::new(SourceType::default(), |source_text: &str| {
+            source_text.contains("debugger") && parses(source_text)
+        });
+        let reduced = reducer.reduce(source_text);
+        assert!(reduced.contains("debugger"));
+        assert!(parses(&reduced));
+        for removed in ["foo", "bar", "return", "const", "a, b"] {
+            assert!(!reduced.contains(removed), "{removed} is left in {reduced}");
+        }
+        assert!(reducer.tests() > 0);
+    }
+}
@@ -11,6 +11,8 @@ pub enum CliRunResult {
     PathNotFound { paths: Vec<PathBuf> },
     LintResult(LintResult),
     FormatResult(FormatResult),
+    GraphResult(GraphResult),
+    ReduceResult(ReduceResult),
     TypeCheckResult { duration: Duration, number_of_diagnostics: usize },
 }
 
@@ -25,10 +27,45 @@ pub struct LintResult {
     pub deny_warnings: bool,
 }
 
+impl LintResult {
+    /// 1 with errors, or with warnings and `--deny-warnings` or more than `--max-warnings`
+    pub fn exit_code(&self) -> u8 {
+        u8::from(
+            self.max_warnings_exceeded
+                || (self.number_of_warnings > 0 && self.deny_warnings)
+                || self.number_of_errors > 0,
+        )
+    }
+}
+
 #[derive(Debug)]
 pub struct FormatResult {
     pub duration: Duration,
     pub number_of_files: usize,
+    /// Files whose formatted code differs from the original, only reported by `--check`
+    pub number_of_unformatted_files: usize,
+    /// Files which could not be read or have syntax errors
+    pub number_of_errors: usize,
+    pub check: bool,
+}
+
+#[derive(Debug)]
+pub struct GraphResult {
+    pub duration: Duration,
+    pub number_of_modules: usize,
+    pub number_of_cycles: usize,
+    /// `Some` with `--unused`, the cycles are not searched for then.
+    pub number_of_unused: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct ReduceResult {
+    pub duration: Duration,
+    /// Sizes of the source text in bytes
+    pub original_size: usize,
+    pub reduced_size: usize,
+    /// The number of times the candidates were parsed and linted
+    pub number_of_tests: usize,
 }
 
 impl Termination for CliRunResult {
@@ -43,15 +80,16 @@ impl Termination for CliRunResult {
                 println!("Path {paths:?} does not exist.");
                 ExitCode::from(1)
             }
-            Self::LintResult(LintResult {
-                duration,
-                number_of_rules,
-                number_of_files,
-                number_of_warnings,
-                number_of_errors,
-                max_warnings_exceeded,
-                deny_warnings,
-            }) => {
+            Self::LintResult(result) => {
+                let LintResult {
+                    duration,
+                    number_of_rules,
+                    number_of_files,
+                    number_of_warnings,
+                    number_of_errors,
+                    max_warnings_exceeded,
+                    deny_warnings: _,
+                } = result;
                 let threads = rayon::current_num_threads();
                 let number_of_diagnostics = number_of_warnings + number_of_errors;
 
@@ -76,17 +114,63 @@ impl Termination for CliRunResult {
                     if number_of_errors == 1 { "" } else { "s" }
                 );
 
-                let exit_code =
-                    u8::from((number_of_warnings > 0 && deny_warnings) || number_of_errors > 0);
-                ExitCode::from(exit_code)
+                ExitCode::from(result.exit_code())
             }
-            Self::FormatResult(FormatResult { duration, number_of_files }) => {
+            Self::FormatResult(FormatResult {
+                duration,
+                number_of_files,
+                number_of_unformatted_files,
+                number_of_errors,
+                check,
+            }) => {
                 let threads = rayon::current_num_threads();
                 let time = Self::get_execution_time(&duration);
                 let s = if number_of_files == 1 { "" } else { "s" };
                 println!(
                     "Finished in {time} on {number_of_files} file{s} using {threads} threads."
                 );
+                if check {
+                    let s = if number_of_unformatted_files == 1 { "" } else { "s" };
+                    println!("Found {number_of_unformatted_files} unformatted file{s}.");
+                }
+                if number_of_errors > 0 {
+                    let s = if number_of_errors == 1 { "" } else { "s" };
+                    println!("Failed to format {number_of_errors} file{s}.");
+                }
+                let exit_code =
+                    u8::from((check && number_of_unformatted_files > 0) || number_of_errors > 0);
+                ExitCode::from(exit_code)
+            }
+            Self::GraphResult(GraphResult {
+                duration,
+                number_of_modules,
+                number_of_cycles,
+                number_of_unused,
+            }) => {
+                // The graph itself is printed to stdout.
+                let time = Self::get_execution_time(&duration);
+                let s = if number_of_modules == 1 { "" } else { "s" };
+                eprintln!("Finished in {time} on {number_of_modules} module{s}.");
+                if let Some(number_of_unused) = number_of_unused {
+                    let s = if number_of_unused == 1 { "" } else { "s" };
+                    eprintln!("Found {number_of_unused} unused file{s}.");
+                    return ExitCode::from(u8::from(number_of_unused > 0));
+                }
+                let s = if number_of_cycles == 1 { "y" } else { "ies" };
+                eprintln!("Found {number_of_cycles} circular dependenc{s}.");
+                ExitCode::from(u8::from(number_of_cycles > 0))
+            }
+            Self::ReduceResult(ReduceResult {
+                duration,
+                original_size,
+                reduced_size,
+                number_of_tests,
+            }) => {
+                // The reduced code is printed to stdout.
+                let time = Self::get_execution_time(&duration);
+                let s = if number_of_tests == 1 { "" } else { "s" };
+                eprintln!("Finished in {time} after {number_of_tests} test{s}.");
+                eprintln!("Reduced {original_size} bytes to {reduced_size} bytes.");
                 ExitCode::from(0)
             }
             Self::TypeCheckResult { duration, number_of_diagnostics } => {
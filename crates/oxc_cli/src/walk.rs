@@ -9,11 +9,11 @@ use oxc_span::VALID_EXTENSIONS;
 use crate::IgnoreOptions;
 
 #[derive(Clone)]
-pub struct Extensions(pub Vec<&'static str>);
+pub struct Extensions(pub Vec<String>);
 
 impl Default for Extensions {
     fn default() -> Self {
-        Self(VALID_EXTENSIONS.to_vec())
+        Self(VALID_EXTENSIONS.iter().map(ToString::to_string).collect())
     }
 }
 
@@ -73,6 +73,8 @@ impl Walk {
     pub fn new(paths: &[PathBuf], options: &IgnoreOptions) -> Self {
         assert!(!paths.is_empty(), "At least one path must be provided to Walk::new");
 
+        // `ignore` does not match the ignore files and patterns of paths with a verbatim prefix
+        let paths = paths.iter().map(|path| oxc_linter::paths::normalize(path)).collect::<Vec<_>>();
         let mut inner = ignore::WalkBuilder::new(
             paths
                 .iter()
@@ -128,11 +130,12 @@ impl Walk {
         }
         let Some(file_name) = dir_entry.path().file_name() else { return false };
         if [".min.", "-min.", "_min."].iter().any(|e| file_name.to_string_lossy().contains(e)) {
+            tracing::debug!(path = %dir_entry.path().display(), "skipped: minified file");
             return false;
         }
         let Some(extension) = dir_entry.path().extension() else { return false };
         let extension = extension.to_string_lossy();
-        extensions.0.contains(&extension.as_ref())
+        extensions.0.iter().any(|e| *e == extension)
     }
 }
 
@@ -155,7 +158,7 @@ mod test {
         };
 
         let mut paths = Walk::new(&fixtures, &ignore_options)
-            .with_extensions(Extensions(["js", "vue"].to_vec()))
+            .with_extensions(Extensions(vec!["js".to_string(), "vue".to_string()]))
             .paths()
             .into_iter()
             .map(|path| path.strip_prefix(&fixture).unwrap().to_string_lossy().to_string())
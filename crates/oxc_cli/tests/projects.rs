@@ -0,0 +1,55 @@
+//! Runs the `oxlint` binary in each project of `tests/projects`, with the arguments listed one per
+//! line in the `args` file of the project if there is one, and snapshots its output and exit code.
+//!
+//! The projects cover what the unit tests of `LintRunner` do not: the discovery of the
+//! configuration files, the ignore files and the mix of JavaScript and TypeScript files.
+//! The diagnostics are printed with `--format github`, one per line, and the duration and the
+//! numbers of rules and threads are left out of the summary.
+
+#![cfg(not(target_os = "windows"))]
+
+use std::{env, fs, process::Command};
+
+#[test]
+fn test_projects() {
+    let projects_dir = env::current_dir().unwrap().join("tests/projects");
+    let mut projects = fs::read_dir(&projects_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    projects.sort_unstable();
+
+    for project in projects {
+        let name = project.file_name().unwrap().to_string_lossy().to_string();
+        let args = fs::read_to_string(project.join("args")).unwrap_or_default();
+        let args = args.lines().filter(|arg| !arg.is_empty()).collect::<Vec<_>>();
+
+        let output = Command::new(env!("CARGO_BIN_EXE_oxlint"))
+            .current_dir(&project)
+            .args(["--format", "github"])
+            .args(&args)
+            .env("NO_COLOR", "1")
+            .env_remove("OXC_LOG")
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        let mut snapshot = format!("$ oxlint {}", args.join(" ")).trim_end().to_string();
+        snapshot.push('\n');
+        for line in stdout.lines() {
+            snapshot.push_str(&redact(line));
+            snapshot.push('\n');
+        }
+        let exit_code = output.status.code().unwrap_or(-1);
+        snapshot.push_str(&format!("exit code: {exit_code}\n"));
+        insta::assert_snapshot!(name, snapshot);
+    }
+}
+
+/// Keep only the number of files of `Finished in 1ms on 2 files with 90 rules using 8 threads.`
+fn redact(line: &str) -> String {
+    let Some(rest) = line.strip_prefix("Finished in ") else { return line.to_string() };
+    let files = rest.split(" on ").nth(1).and_then(|rest| rest.split(" with ").next());
+    format!("Finished on {}.", files.unwrap_or_default())
+}
@@ -0,0 +1 @@
+declare const DEBUG: boolean;
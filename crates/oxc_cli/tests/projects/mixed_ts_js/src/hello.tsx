@@ -0,0 +1,8 @@
+interface Props {
+  name: string;
+}
+
+export function Hello(props: Props) {
+  debugger;
+  return <div>{props.name}</div>;
+}
@@ -110,7 +110,13 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ExpressionStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
         p.print_indent();
         p.start_of_stmt = p.code_len();
-        p.print_expression(&self.expression);
+        // A string literal alone would be printed as a directive, e.g. `'use strict'`
+        p.wrap(
+            matches!(self.expression.get_inner_expression(), Expression::StringLiteral(_)),
+            |p| {
+                p.print_expression(&self.expression);
+            },
+        );
         if self.expression.is_specific_id("let") {
             p.print_semicolon();
         } else {
@@ -1532,7 +1538,8 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for YieldExpression<'a> {
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for UpdateExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         let operator = self.operator.as_str().as_bytes();
-        p.wrap(precedence > self.precedence(), |p| {
+        // `(a++)[b]`, member objects are printed with `Precedence::Postfix`
+        p.wrap(precedence >= Precedence::Postfix, |p| {
             if self.prefix {
                 p.print_space_before_operator(self.operator.into());
                 p.print_str(operator);
@@ -1573,7 +1580,10 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for BinaryExpression<'a> {
         let wrap_in = self.operator == BinaryOperator::In && !ctx.has_in();
         let wrap = precedence >= self.precedence() || wrap_in;
         p.wrap(wrap, |p| {
-            let left_precedence = if self.precedence().is_right_associative() {
+            // `(a && b) | c`, logical expressions only wrap below their own precedence
+            let left_is_logical =
+                matches!(self.left.get_inner_expression(), Expression::LogicalExpression(_));
+            let left_precedence = if self.precedence().is_right_associative() || left_is_logical {
                 self.precedence()
             } else {
                 self.operator.lower_precedence()
@@ -1639,7 +1649,10 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ConditionalExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         let wrap = precedence > self.precedence();
         p.wrap(wrap, |p| {
-            self.test.gen_expr(p, self.precedence(), ctx);
+            // `(a ? b : c) ? d : e`, conditional expressions are right associative
+            let wrap_test =
+                matches!(self.test.get_inner_expression(), Expression::ConditionalExpression(_));
+            p.wrap(wrap_test, |p| self.test.gen_expr(p, self.precedence(), ctx));
             p.print_soft_space();
             p.print(b'?');
             p.print_soft_space();
@@ -345,19 +345,12 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         ctx: Context,
         print_semicolon_first: bool,
     ) {
-        if let Some(directives) = directives {
-            if directives.is_empty() {
-                if let Some(Statement::ExpressionStatement(s)) = statements.first() {
-                    if matches!(s.expression.get_inner_expression(), Expression::StringLiteral(_)) {
-                        self.print_semicolon();
-                    }
-                }
-            } else {
-                for directive in directives {
-                    directive.gen(self, ctx);
-                }
-                self.print_soft_newline();
+        // A string literal statement is wrapped in parentheses so it is not read as a directive
+        if let Some(directives) = directives.filter(|directives| !directives.is_empty()) {
+            for directive in directives {
+                directive.gen(self, ctx);
             }
+            self.print_soft_newline();
         }
         for stmt in statements {
             if let Statement::Declaration(decl) = stmt {
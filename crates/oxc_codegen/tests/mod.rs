@@ -1,3 +1,5 @@
+mod property;
+
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::Parser;
@@ -26,6 +28,23 @@ fn test_ts(source_text: &str, expected: &str, is_typescript_definition: bool) {
     assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
 }
 
+/// Print `source_text`, then parse and print the printed code again, which must parse without
+/// errors and be printed the same, both with the readable and the compact whitespace.
+fn test_round_trip(source_text: &str) {
+    fn print<const MINIFY: bool>(source_text: &str, source_type: SourceType) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "failed to parse {source_text:?}: {:?}", ret.errors);
+        Codegen::<MINIFY>::new(source_text.len(), CodegenOptions::default()).build(&ret.program)
+    }
+
+    let source_type = SourceType::default().with_module(true);
+    let printed = print::<false>(source_text, source_type);
+    assert_eq!(printed, print::<false>(&printed, source_type), "for source {source_text}");
+    let minified = print::<true>(source_text, source_type);
+    assert_eq!(minified, print::<true>(&minified, source_type), "for source {source_text}");
+}
+
 #[test]
 fn string() {
     test("let x = ''", "let x = '';\n");
@@ -123,6 +142,86 @@ fn module_decl() {
     test("export * from './foo.js' with {}", "export * from './foo.js' with {\n};\n");
 }
 
+#[test]
+fn round_trip() {
+    // Operator precedence
+    for source_text in [
+        "(a + b) * c",
+        "a - -b",
+        "a + +b",
+        "a - --b",
+        "-(-a)",
+        "+(+a)",
+        "!(a && b)",
+        "(a, b) ? c : d",
+        "a ? (b, c) : d",
+        "(a = b) ? c : d",
+        "(a || b) ?? c",
+        "a ?? (b && c)",
+        "(-a) ** b",
+        "(await a) ** b",
+        "(a ** b) ** c",
+        "a ** (b ** c)",
+        "(a => a)()",
+        "(function () {})()",
+        "(class {}).name",
+        "({}).toString()",
+        "({ a } = b)",
+        "(1).toString()",
+        "1.5.toFixed()",
+        "new (a())()",
+        "new (a.b())()",
+        "(a?.b)()",
+        "(a?.b).c",
+        "!(a in b)",
+        "for ((a in b);;);",
+        "for (var a = (b in c); ;);",
+        "typeof (() => {})",
+        "async () => ({})",
+        "x = function* () { yield (a, b) }",
+        "(a++)[b]",
+        "(a ? b : c) ? d : e",
+        "(a && b) | c",
+        "function f() { ('a'); }",
+    ] {
+        test_round_trip(source_text);
+    }
+
+    // Automatic semicolon insertion
+    for source_text in [
+        "a\n++b",
+        "a\n(b)",
+        "a\n[b]",
+        "a\n`b`",
+        "let a = b\n/c/g.exec(d)",
+        "function f() { return (\na\n) }",
+        "function* f() { yield\na }",
+        "a: while (true) { continue\na }",
+        "throw a\n+b",
+        "if (a) b\nelse c",
+        "do a; while (b) c",
+        "class A { a\n['b']() {} }",
+        "class A { get\na() {} }",
+    ] {
+        test_round_trip(source_text);
+    }
+
+    // String quoting and escaping
+    for source_text in [
+        "'\"'",
+        "\"'\"",
+        "'\\'\"'",
+        "'\\n\\r\\t'",
+        "'\\u2028\\u2029'",
+        "'</script>'",
+        "`${a}\\${b}`",
+        "a = /[/]\\//g",
+        "'\\u{1F600}'",
+    ] {
+        test_round_trip(source_text);
+    }
+}
+
 #[test]
 fn new_expr() {
     test("new (foo()).bar();", "new (foo()).bar();\n");
@@ -0,0 +1,296 @@
+//! Property tests of the printer on generated programs.
+//!
+//! The programs are generated as fully parenthesized source text, so they are valid by
+//! construction, and parsed without keeping the parentheses: the printer has to add back the
+//! ones the precedence and the start of the statements require.
+//! The ASTs are compared with their hashes, which leave out the spans.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use proptest::prelude::*;
+
+const IDENTIFIERS: [&str; 4] = ["a", "b", "c", "x"];
+
+const BINARY_OPERATORS: [&str; 22] = [
+    "+",
+    "-",
+    "*",
+    "/",
+    "%",
+    "**",
+    "==",
+    "!=",
+    "===",
+    "!==",
+    "<",
+    "<=",
+    ">",
+    ">=",
+    "<<",
+    ">>",
+    ">>>",
+    "&",
+    "|",
+    "^",
+    "in",
+    "instanceof",
+];
+
+const LOGICAL_OPERATORS: [&str; 3] = ["&&", "||", "??"];
+
+const UNARY_OPERATORS: [&str; 7] = ["!", "-", "+", "~", "typeof", "void", "delete"];
+
+const ASSIGNMENT_OPERATORS: [&str; 5] = ["=", "+=", "-=", "**=", "??="];
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Identifier(&'static str),
+    /// Below 1000, which are printed the same when minified
+    Number(u32),
+    String(String),
+    Boolean(bool),
+    Null,
+    Unary(&'static str, Box<Expr>),
+    Update(&'static str, bool, &'static str),
+    Binary(Box<Expr>, &'static str, Box<Expr>),
+    Conditional(Box<Expr>, Box<Expr>, Box<Expr>),
+    Assignment(&'static str, &'static str, Box<Expr>),
+    Call(Box<Expr>, Vec<Expr>),
+    New(&'static str, Vec<Expr>),
+    Member(Box<Expr>, &'static str),
+    Computed(Box<Expr>, Box<Expr>),
+    Array(Vec<Expr>),
+    Object(Vec<(&'static str, Expr)>),
+    Arrow(&'static str, Box<Expr>),
+    Function(&'static str, Box<Expr>),
+    Sequence(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone)]
+enum Stmt {
+    Var(&'static str, Expr),
+    Expression(Expr),
+    If(Expr, Vec<Stmt>, Vec<Stmt>),
+    Function(&'static str, Vec<Stmt>, Expr),
+}
+
+#[derive(Debug, Clone)]
+struct Program(Vec<Stmt>);
+
+fn identifier() -> impl Strategy<Value = &'static str> {
+    prop::sample::select(&IDENTIFIERS[..])
+}
+
+fn expr() -> impl Strategy<Value = Expr> {
+    let leaf = prop_oneof![
+        identifier().prop_map(Expr::Identifier),
+        (0u32..1000).prop_map(Expr::Number),
+        "[a-z ]{0,6}".prop_map(Expr::String),
+        any::<bool>().prop_map(Expr::Boolean),
+        Just(Expr::Null),
+        (prop::sample::select(&["++", "--"][..]), any::<bool>(), identifier())
+            .prop_map(|(operator, prefix, name)| Expr::Update(operator, prefix, name)),
+    ];
+    leaf.prop_recursive(4, 48, 3, |inner| {
+        let operands = prop::collection::vec(inner.clone(), 0..3);
+        prop_oneof![
+            (prop::sample::select(&UNARY_OPERATORS[..]), inner.clone())
+                .prop_map(|(operator, argument)| Expr::Unary(operator, Box::new(argument))),
+            (
+                inner.clone(),
+                prop::sample::select([&BINARY_OPERATORS[..], &LOGICAL_OPERATORS[..]].concat()),
+                inner.clone()
+            )
+                // `a && (b && c)` is printed as `a && b && c`, which has the same result
+                .prop_filter("regrouped logical expression", |(_, operator, right)| {
+                    !LOGICAL_OPERATORS.contains(operator)
+                        || !matches!(right, Expr::Binary(_, right_operator, _) if right_operator == operator)
+                })
+                .prop_map(|(left, operator, right)| Expr::Binary(
+                    Box::new(left),
+                    operator,
+                    Box::new(right)
+                )),
+            (inner.clone(), inner.clone(), inner.clone()).prop_map(
+                |(test, consequent, alternate)| {
+                    Expr::Conditional(Box::new(test), Box::new(consequent), Box::new(alternate))
+                }
+            ),
+            (identifier(), prop::sample::select(&ASSIGNMENT_OPERATORS[..]), inner.clone())
+                .prop_map(|(name, operator, value)| Expr::Assignment(
+                    name,
+                    operator,
+                    Box::new(value)
+                )),
+            (inner.clone(), operands.clone())
+                .prop_map(|(callee, arguments)| Expr::Call(Box::new(callee), arguments)),
+            (identifier(), operands.clone())
+                .prop_map(|(callee, arguments)| Expr::New(callee, arguments)),
+            (inner.clone(), identifier())
+                .prop_map(|(object, property)| Expr::Member(Box::new(object), property)),
+            (inner.clone(), inner.clone()).prop_map(|(object, property)| Expr::Computed(
+                Box::new(object),
+                Box::new(property)
+            )),
+            operands.prop_map(Expr::Array),
+            prop::collection::vec((identifier(), inner.clone()), 0..3).prop_map(Expr::Object),
+            (identifier(), inner.clone())
+                .prop_map(|(param, body)| Expr::Arrow(param, Box::new(body))),
+            (identifier(), inner.clone())
+                .prop_map(|(param, body)| Expr::Function(param, Box::new(body))),
+            (inner.clone(), inner)
+                .prop_map(|(left, right)| Expr::Sequence(Box::new(left), Box::new(right))),
+        ]
+    })
+}
+
+fn stmt() -> impl Strategy<Value = Stmt> {
+    let leaf = prop_oneof![
+        (identifier(), expr()).prop_map(|(name, init)| Stmt::Var(name, init)),
+        expr().prop_map(Stmt::Expression),
+    ];
+    leaf.prop_recursive(2, 16, 3, |inner| {
+        let body = prop::collection::vec(inner, 0..3);
+        prop_oneof![
+            (expr(), body.clone(), body.clone())
+                .prop_map(|(test, consequent, alternate)| Stmt::If(test, consequent, alternate)),
+            (identifier(), body, expr())
+                .prop_map(|(name, body, value)| Stmt::Function(name, body, value)),
+        ]
+    })
+}
+
+fn program() -> impl Strategy<Value = Program> {
+    prop::collection::vec(stmt(), 1..4).prop_map(Program)
+}
+
+fn list<T: Display>(f: &mut fmt::Formatter<'_>, items: &[T]) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(name) => write!(f, "{name}"),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "'{value}'"),
+            Self::Boolean(value) => write!(f, "{value}"),
+            Self::Null => write!(f, "null"),
+            Self::Unary(operator, argument) => write!(f, "({operator} ({argument}))"),
+            Self::Update(operator, true, name) => write!(f, "({operator}{name})"),
+            Self::Update(operator, false, name) => write!(f, "({name}{operator})"),
+            Self::Binary(left, operator, right) => write!(f, "(({left}) {operator} ({right}))"),
+            Self::Conditional(test, consequent, alternate) => {
+                write!(f, "(({test}) ? ({consequent}) : ({alternate}))")
+            }
+            Self::Assignment(name, operator, value) => write!(f, "({name} {operator} ({value}))"),
+            Self::Call(callee, arguments) => {
+                write!(f, "(({callee})(")?;
+                list(f, &arguments.iter().map(|arg| format!("({arg})")).collect::<Vec<_>>())?;
+                write!(f, "))")
+            }
+            Self::New(callee, arguments) => {
+                write!(f, "(new {callee}(")?;
+                list(f, &arguments.iter().map(|arg| format!("({arg})")).collect::<Vec<_>>())?;
+                write!(f, "))")
+            }
+            Self::Member(object, property) => write!(f, "(({object}).{property})"),
+            Self::Computed(object, property) => write!(f, "(({object})[{property}])"),
+            Self::Array(elements) => {
+                write!(f, "[")?;
+                list(f, &elements.iter().map(|e| format!("({e})")).collect::<Vec<_>>())?;
+                write!(f, "]")
+            }
+            Self::Object(properties) => {
+                write!(f, "({{")?;
+                let properties = properties.iter().map(|(key, value)| format!("{key}: ({value})"));
+                list(f, &properties.collect::<Vec<_>>())?;
+                write!(f, "}})")
+            }
+            Self::Arrow(param, body) => write!(f, "(({param}) => ({body}))"),
+            Self::Function(param, body) => write!(f, "(function ({param}) {{ return ({body}); }})"),
+            Self::Sequence(left, right) => write!(f, "(({left}), ({right}))"),
+        }
+    }
+}
+
+impl Display for Stmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Var(name, init) => write!(f, "var {name} = ({init});"),
+            Self::Expression(expression) => write!(f, "({expression});"),
+            Self::If(test, consequent, alternate) => {
+                write!(f, "if ({test}) {{ ")?;
+                consequent.iter().try_for_each(|stmt| write!(f, "{stmt} "))?;
+                write!(f, "}} else {{ ")?;
+                alternate.iter().try_for_each(|stmt| write!(f, "{stmt} "))?;
+                write!(f, "}}")
+            }
+            Self::Function(name, body, value) => {
+                write!(f, "function {name}() {{ ")?;
+                body.iter().try_for_each(|stmt| write!(f, "{stmt} "))?;
+                write!(f, "return ({value}); }}")
+            }
+        }
+    }
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|stmt| writeln!(f, "{stmt}"))
+    }
+}
+
+/// The hash of the AST of `source_text`, without the spans, and `source_text` printed.
+fn parse_and_print<const MINIFY: bool>(source_text: &str) -> Result<(u64, String), String> {
+    let allocator = Allocator::default();
+    // A script, where the functions and variables of the same name can be declared several times
+    let source_type = SourceType::default();
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    if !ret.errors.is_empty() {
+        return Err(format!("failed to parse {source_text:?}: {:?}", ret.errors));
+    }
+    let mut hasher = DefaultHasher::new();
+    ret.program.hash(&mut hasher);
+    let printed =
+        Codegen::<MINIFY>::new(source_text.len(), CodegenOptions::default()).build(&ret.program);
+    Ok((hasher.finish(), printed))
+}
+
+/// The printed code has the AST of the source, and is printed the same
+fn check_round_trip<const MINIFY: bool>(source_text: &str) -> Result<(), TestCaseError> {
+    let (hash, printed) = parse_and_print::<MINIFY>(source_text).map_err(TestCaseError::fail)?;
+    let (reparsed_hash, reprinted) =
+        parse_and_print::<MINIFY>(&printed).map_err(TestCaseError::fail)?;
+    prop_assert_eq!(hash, reparsed_hash, "the AST changed, printed as {}", printed);
+    prop_assert_eq!(&printed, &reprinted);
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn round_trip(program in program()) {
+        check_round_trip::<false>(&program.to_string())?;
+    }
+
+    #[test]
+    fn round_trip_minified(program in program()) {
+        check_round_trip::<true>(&program.to_string())?;
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Flag shared between the caller and the parser, the semantic builder and the linter,
+/// to stop the work on a file whose result is no longer needed, e.g. after it was edited again.
+///
+/// Cancelled work returns a `CancelledError`, or no diagnostics from the linter.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
@@ -177,10 +177,11 @@ impl GraphicalReportHandler {
             );
             write!(header, "{}", link)?;
             writeln!(f, "{}", header)?;
-        } else if let Some(code) = diagnostic.code() {
+        } else if let (Some(code), Some(url)) = (diagnostic.code(), diagnostic.url()) {
+            // Without a URL, the code is left out: the code of a lint diagnostic is its rule,
+            // which its message already starts with, see `with_rule`.
             write!(header, "{}", code.style(severity_style),)?;
-            if self.links == LinkStyle::Text && diagnostic.url().is_some() {
-                let url = diagnostic.url().unwrap(); // safe
+            if self.links == LinkStyle::Text {
                 write!(header, " ({})", url.style(self.theme.styles.link))?;
             }
             writeln!(f, "{}", header)?;
@@ -1,14 +1,25 @@
 //! Diagnostics Wrapper
 //! Exports `thiserror` and `miette`
 
+mod cancellation;
 mod graphic_reporter;
 mod graphical_theme;
 mod reporter;
+mod rule;
 mod service;
+mod severity;
+mod structured;
 
 use std::path::PathBuf;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::cancellation::CancellationToken;
+pub use crate::reporter::{DiagnosticReporter, ReportWriter};
+pub use crate::rule::with_rule;
+pub use crate::service::{
+    DiagnosticReceiver, DiagnosticSender, DiagnosticService, DiagnosticTuple,
+};
+pub use crate::severity::with_severity;
+pub use crate::structured::{StructuredDiagnostic, StructuredSeverity, StructuredSpan};
 pub use graphic_reporter::{GraphicalReportHandler, GraphicalTheme};
 pub use miette;
 pub use thiserror;
@@ -27,7 +38,29 @@ use thiserror::Error;
 #[diagnostic(help("{0:?} seems like a minified file"))]
 pub struct MinifiedFileError(pub PathBuf);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("File {0:?} is not linted because {1}")]
+#[diagnostic(
+    severity(advice),
+    help("Generated files such as bundles are better ignored, or the limit can be raised")
+)]
+pub struct InputLimitExceededError(pub PathBuf, pub String);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Failed to open file {0:?} with error \"{1}\"")]
 #[diagnostic(help("Failed to open file {0:?} with error \"{1}\""))]
 pub struct FailedToOpenFileError(pub PathBuf, pub std::io::Error);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cancelled")]
+pub struct CancelledError;
+
+/// The source is nested deeper than the limit given to the parser.
+#[derive(Debug, Error, Diagnostic)]
+#[error("Nesting deeper than {0} is not supported")]
+pub struct NestingTooDeepError(pub usize, #[label] pub miette::SourceSpan);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Internal error while processing {0:?}: {1}")]
+#[diagnostic(help("This is a bug, please report it with a minimal reproduction. {2}"))]
+pub struct InternalError(pub PathBuf, pub String, pub String);
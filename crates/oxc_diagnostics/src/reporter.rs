@@ -1,51 +1,97 @@
-use std::io::{BufWriter, Stdout, Write};
+use std::{
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use serde_json::json;
 
 use crate::{
-    miette::{Error, JSONReportHandler},
+    miette::Error,
+    structured::{StructuredDiagnostic, StructuredSeverity},
     GraphicalReportHandler,
 };
 
-#[allow(clippy::large_enum_variant)] // Lerge size is fine because this is a singleton
-#[derive(Debug)]
+/// The destination of a reporter: stdout by default, or stderr or a file.
+/// stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
+/// See `https://github.com/rust-lang/rust/issues/60673`.
+pub type ReportWriter = BufWriter<Box<dyn Write>>;
+
+#[allow(clippy::large_enum_variant)] // Large size is fine because there are only a few reporters
 #[non_exhaustive]
 pub enum DiagnosticReporter {
-    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
-    // See `https://github.com/rust-lang/rust/issues/60673`.
-    Graphical { handler: GraphicalReportHandler, writer: BufWriter<Stdout> },
-    Json { diagnostics: Vec<Error> },
+    Graphical {
+        handler: GraphicalReportHandler,
+        writer: ReportWriter,
+    },
+    /// An array of `StructuredDiagnostic`
+    Json {
+        diagnostics: Vec<StructuredDiagnostic>,
+        writer: ReportWriter,
+    },
+    /// A SARIF 2.1.0 log, e.g. for GitHub code scanning and SonarQube
+    Sarif {
+        diagnostics: Vec<StructuredDiagnostic>,
+        writer: ReportWriter,
+    },
+    /// Workflow commands of GitHub Actions, which annotate the lines of pull requests
+    Github {
+        writer: ReportWriter,
+    },
 }
 
 impl DiagnosticReporter {
     pub fn new_graphical() -> Self {
-        Self::Graphical {
-            handler: GraphicalReportHandler::new(),
-            writer: BufWriter::new(std::io::stdout()),
-        }
+        Self::Graphical { handler: GraphicalReportHandler::new(), writer: stdout() }
     }
 
     pub fn new_json() -> Self {
-        Self::Json { diagnostics: vec![] }
+        Self::Json { diagnostics: vec![], writer: stdout() }
+    }
+
+    pub fn new_sarif() -> Self {
+        Self::Sarif { diagnostics: vec![], writer: stdout() }
+    }
+
+    pub fn new_github() -> Self {
+        Self::Github { writer: stdout() }
+    }
+
+    /// Write to `writer` instead of stdout
+    #[must_use]
+    pub fn with_writer(mut self, writer: impl Write + 'static) -> Self {
+        match &mut self {
+            Self::Graphical { writer: w, .. }
+            | Self::Json { writer: w, .. }
+            | Self::Sarif { writer: w, .. }
+            | Self::Github { writer: w } => *w = BufWriter::new(Box::new(writer)),
+        }
+        self
+    }
+
+    /// Render the graphical diagnostics with `handler`, e.g. without colors for a file
+    #[must_use]
+    pub fn with_handler(mut self, handler: GraphicalReportHandler) -> Self {
+        if let Self::Graphical { handler: h, .. } = &mut self {
+            *h = handler;
+        }
+        self
     }
 
     pub fn finish(&mut self) {
         match self {
-            Self::Graphical { writer, .. } => {
+            Self::Graphical { writer, .. } | Self::Github { writer } => {
                 writer.flush().unwrap();
             }
             // NOTE: this output does not conform to eslint json format yet
             // https://eslint.org/docs/latest/use/formatters/#json
-            Self::Json { diagnostics } => {
-                let handler = JSONReportHandler::new();
-                let messages = diagnostics
-                    .drain(..)
-                    .map(|error| {
-                        let mut output = String::from("\t");
-                        handler.render_report(&mut output, error.as_ref()).unwrap();
-                        output
-                    })
-                    .collect::<Vec<_>>()
-                    .join(",\n");
-                println!("[\n{messages}\n]");
+            Self::Json { diagnostics, writer } => {
+                writeln!(writer, "{}", serde_json::to_string_pretty(diagnostics).unwrap()).unwrap();
+                writer.flush().unwrap();
+            }
+            Self::Sarif { diagnostics, writer } => {
+                let log = serde_json::to_string_pretty(&sarif_log(diagnostics)).unwrap();
+                writeln!(writer, "{log}").unwrap();
+                writer.flush().unwrap();
             }
         }
     }
@@ -55,21 +101,167 @@ impl DiagnosticReporter {
             Self::Graphical { writer, .. } => {
                 writer.write_all(s).unwrap();
             }
-            Self::Json { .. } => {}
+            Self::Json { .. } | Self::Sarif { .. } | Self::Github { .. } => {}
         }
     }
 
-    pub fn render_error(&mut self, error: Error) -> Option<String> {
+    pub fn render_error(&mut self, path: &Path, error: &Error) -> Option<String> {
         match self {
             Self::Graphical { handler, .. } => {
                 let mut output = String::new();
                 handler.render_report(&mut output, error.as_ref()).unwrap();
                 Some(output)
             }
-            Self::Json { diagnostics } => {
-                diagnostics.push(error);
+            Self::Json { diagnostics, .. } | Self::Sarif { diagnostics, .. } => {
+                diagnostics.push(StructuredDiagnostic::new(path, error));
+                None
+            }
+            Self::Github { writer } => {
+                let diagnostic = StructuredDiagnostic::new(path, error);
+                writeln!(writer, "{}", github_annotation(&diagnostic)).unwrap();
                 None
             }
         }
     }
 }
+
+fn stdout() -> ReportWriter {
+    BufWriter::new(Box::new(io::stdout()))
+}
+
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+fn sarif_log(diagnostics: &[StructuredDiagnostic]) -> serde_json::Value {
+    let mut rules = diagnostics.iter().filter_map(|d| d.rule.as_deref()).collect::<Vec<_>>();
+    rules.sort_unstable();
+    rules.dedup();
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let level = match diagnostic.severity {
+                StructuredSeverity::Error => "error",
+                StructuredSeverity::Warning => "warning",
+                StructuredSeverity::Advice => "note",
+            };
+            let text = match &diagnostic.help {
+                Some(help) => format!("{}\nhelp: {help}", diagnostic.message),
+                None => diagnostic.message.clone(),
+            };
+            let uri = diagnostic.filename.to_string_lossy().replace('\\', "/");
+            let mut physical_location = json!({ "artifactLocation": { "uri": uri } });
+            if let Some(span) = &diagnostic.span {
+                physical_location["region"] = json!({
+                    "startLine": span.line,
+                    "startColumn": span.column,
+                    "endLine": span.end_line,
+                    "endColumn": span.end_column,
+                    "byteOffset": span.offset,
+                    "byteLength": span.length,
+                });
+            }
+            let mut result = json!({
+                "level": level,
+                "message": { "text": text },
+                "locations": [{ "physicalLocation": physical_location }],
+            });
+            if let Some(rule) = &diagnostic.rule {
+                result["ruleId"] = json!(rule);
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "oxlint",
+                    "informationUri": "https://github.com/oxc-project/oxc",
+                    "rules": rules.iter().map(|rule| json!({ "id": rule })).collect::<Vec<_>>(),
+                }
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }]
+    })
+}
+
+/// <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message>
+fn github_annotation(diagnostic: &StructuredDiagnostic) -> String {
+    let command = match diagnostic.severity {
+        StructuredSeverity::Error => "error",
+        StructuredSeverity::Warning => "warning",
+        StructuredSeverity::Advice => "notice",
+    };
+    let mut properties =
+        vec![format!("file={}", escape_property(&diagnostic.filename.to_string_lossy()))];
+    if let Some(span) = &diagnostic.span {
+        properties.push(format!("line={}", span.line));
+        properties.push(format!("endLine={}", span.end_line));
+        properties.push(format!("col={}", span.column));
+        properties.push(format!("endColumn={}", span.end_column));
+    }
+    if let Some(rule) = &diagnostic.rule {
+        properties.push(format!("title={}", escape_property(rule)));
+    }
+    let message = match &diagnostic.help {
+        Some(help) => format!("{}\nhelp: {help}", diagnostic.message),
+        None => diagnostic.message.clone(),
+    };
+    format!("::{command} {}::{}", properties.join(","), escape_data(&message))
+}
+
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{github_annotation, sarif_log};
+    use crate::structured::{StructuredDiagnostic, StructuredSeverity, StructuredSpan};
+
+    fn diagnostic() -> StructuredDiagnostic {
+        StructuredDiagnostic {
+            rule: Some("eslint/no-debugger".to_string()),
+            severity: StructuredSeverity::Warning,
+            filename: "src/a,b.js".into(),
+            message: "`debugger` statement is not allowed".to_string(),
+            help: Some("Delete this code.".to_string()),
+            span: Some(StructuredSpan {
+                offset: 7,
+                length: 9,
+                line: 2,
+                column: 1,
+                end_line: 2,
+                end_column: 10,
+            }),
+        }
+    }
+
+    #[test]
+    fn github() {
+        assert_eq!(
+            github_annotation(&diagnostic()),
+            "::warning file=src/a%2Cb.js,line=2,endLine=2,col=1,endColumn=10,title=eslint/no-debugger::`debugger` statement is not allowed%0Ahelp: Delete this code."
+        );
+    }
+
+    #[test]
+    fn sarif() {
+        let log = sarif_log(&[diagnostic()]);
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "eslint/no-debugger");
+        assert_eq!(run["columnKind"], "unicodeCodePoints");
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "eslint/no-debugger");
+        assert_eq!(result["level"], "warning");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/a,b.js");
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["endColumn"], 10);
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::{self, Display};
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+use crate::{Error, Severity};
+
+/// Report `diagnostic` as reported by a lint rule, whose `plugin/rule-name` is its code,
+/// e.g. `eslint/no-debugger` for the rule ids of the JSON, SARIF and GitHub reports.
+pub fn with_rule(diagnostic: Error, plugin_name: &'static str, rule_name: &'static str) -> Error {
+    Error::new(RuleDiagnostic { diagnostic, plugin_name, rule_name })
+}
+
+#[derive(Debug)]
+struct RuleDiagnostic {
+    diagnostic: Error,
+    plugin_name: &'static str,
+    rule_name: &'static str,
+}
+
+impl Display for RuleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for RuleDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for RuleDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(format!("{}/{}", self.plugin_name, self.rule_name)))
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.diagnostic.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
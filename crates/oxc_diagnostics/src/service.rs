@@ -1,19 +1,30 @@
 use std::{
     cell::Cell,
+    fmt::{self, Display},
     path::{Path, PathBuf},
     sync::{mpsc, Arc},
 };
 
 use crate::{
-    miette::NamedSource, reporter::DiagnosticReporter, Error, MinifiedFileError, Severity,
+    miette::{Diagnostic, LabeledSpan, NamedSource, SourceCode},
+    reporter::DiagnosticReporter,
+    Error, MinifiedFileError, Severity,
 };
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
-pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
+pub type DiagnosticSender = mpsc::SyncSender<Option<DiagnosticTuple>>;
 pub type DiagnosticReceiver = mpsc::Receiver<Option<DiagnosticTuple>>;
 
+/// Number of files whose diagnostics can wait for the reporter.
+/// Senders block beyond that, so memory stays bounded when printing is slower than linting.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Called with the path of a file and a 1-based line number.
+type LineFilter = Box<dyn Fn(&Path, usize) -> bool>;
+
 pub struct DiagnosticService {
-    reporter: DiagnosticReporter,
+    /// Each diagnostic is rendered by all the reporters, e.g. graphical to stderr and SARIF to a file
+    reporters: Vec<DiagnosticReporter>,
 
     /// Disable reporting on warnings, only errors are reported
     quiet: bool,
@@ -22,6 +33,15 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Only report the diagnostics on the lines it accepts, see `with_line_filter`
+    line_filter: Option<LineFilter>,
+
+    /// Report the files in the order of their paths, see `with_stable_order`
+    stable_order: bool,
+
+    /// The files reported as soon as they are complete, see `with_expected_files`
+    expected_files: Vec<PathBuf>,
+
     /// Total number of warnings received
     warnings_count: Cell<usize>,
 
@@ -34,11 +54,14 @@ pub struct DiagnosticService {
 
 impl Default for DiagnosticService {
     fn default() -> Self {
-        let (sender, receiver) = mpsc::channel();
+        let (sender, receiver) = mpsc::sync_channel(CHANNEL_CAPACITY);
         Self {
-            reporter: DiagnosticReporter::new_graphical(),
+            reporters: vec![DiagnosticReporter::new_graphical()],
             quiet: false,
             max_warnings: None,
+            line_filter: None,
+            stable_order: false,
+            expected_files: vec![],
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
             sender,
@@ -49,7 +72,20 @@ impl Default for DiagnosticService {
 
 impl DiagnosticService {
     pub fn set_json_reporter(&mut self) {
-        self.reporter = DiagnosticReporter::new_json();
+        self.reporters = vec![DiagnosticReporter::new_json()];
+    }
+
+    pub fn set_sarif_reporter(&mut self) {
+        self.reporters = vec![DiagnosticReporter::new_sarif()];
+    }
+
+    pub fn set_github_reporter(&mut self) {
+        self.reporters = vec![DiagnosticReporter::new_github()];
+    }
+
+    /// Replace the reporters, the diagnostics are reported by each of them
+    pub fn set_reporters(&mut self, reporters: Vec<DiagnosticReporter>) {
+        self.reporters = reporters;
     }
 
     #[must_use]
@@ -64,6 +100,34 @@ impl DiagnosticService {
         self
     }
 
+    /// Only report and count the diagnostics whose first label is on a line accepted by `filter`,
+    /// e.g. the lines changed in a pull request. Diagnostics without labels are always reported.
+    #[must_use]
+    pub fn with_line_filter(mut self, filter: impl Fn(&Path, usize) -> bool + 'static) -> Self {
+        self.line_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Report the files sorted by path, so the output is the same whatever order the files are
+    /// linted in by the threads. The diagnostics of a file keep the order they are received in.
+    ///
+    /// The diagnostics of the files given to `with_expected_files` are reported as soon as the files
+    /// before them are complete, the others are buffered and reported once all files are linted.
+    #[must_use]
+    pub fn with_stable_order(mut self, yes: bool) -> Self {
+        self.stable_order = yes;
+        self
+    }
+
+    /// The files whose diagnostics are reported while the others are linted with `with_stable_order`.
+    /// Each of them must be marked as complete by sending an empty list of diagnostics for its path,
+    /// once all its diagnostics are sent.
+    #[must_use]
+    pub fn with_expected_files(mut self, paths: Vec<PathBuf>) -> Self {
+        self.expected_files = paths;
+        self
+    }
+
     pub fn sender(&self) -> &DiagnosticSender {
         &self.sender
     }
@@ -93,36 +157,62 @@ impl DiagnosticService {
         (path.to_path_buf(), diagnostics)
     }
 
+    /// Wraps the diagnostics of a section of the file which starts at `start`,
+    /// e.g. the `<script>` of a Vue component, to report them at their positions in the file.
+    pub fn wrap_diagnostics_at(
+        path: &Path,
+        source_text: &str,
+        start: usize,
+        diagnostics: Vec<Error>,
+    ) -> (PathBuf, Vec<Error>) {
+        let diagnostics = if start == 0 {
+            diagnostics
+        } else {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| Error::new(OffsetDiagnostic { diagnostic, start }))
+                .collect()
+        };
+        Self::wrap_diagnostics(path, source_text, diagnostics)
+    }
+
     /// # Panics
     ///
     /// * When the writer fails to write
     pub fn run(&mut self) {
-        while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
-            let mut output = String::new();
-            for diagnostic in diagnostics {
-                let severity = diagnostic.severity();
-                let is_warning = severity == Some(Severity::Warning);
-                let is_error = severity.is_none() || severity == Some(Severity::Error);
-                if is_warning || is_error {
-                    if is_warning {
-                        let warnings_count = self.warnings_count() + 1;
-                        self.warnings_count.set(warnings_count);
-                    }
-                    if is_error {
-                        let errors_count = self.errors_count() + 1;
-                        self.errors_count.set(errors_count);
-                    }
-                    // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
-                    // Note that it does not disable ALL diagnostics, only Warning diagnostics
-                    else if self.quiet {
-                        continue;
-                    }
+        if self.stable_order {
+            let mut order = StableOrder::new(std::mem::take(&mut self.expected_files));
+            while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
+                for (path, diagnostics) in order.push(path, diagnostics) {
+                    self.report(&path, diagnostics);
                 }
+            }
+            for (path, diagnostics) in order.finish() {
+                self.report(&path, diagnostics);
+            }
+        } else {
+            while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
+                self.report(&path, diagnostics);
+            }
+        }
+
+        for reporter in &mut self.reporters {
+            reporter.finish();
+        }
+    }
 
-                if let Some(mut err_str) = self.reporter.render_error(diagnostic) {
+    fn report(&mut self, path: &Path, diagnostics: Vec<Error>) {
+        let diagnostics = diagnostics
+            .into_iter()
+            .filter(|diagnostic| self.count(path, diagnostic))
+            .collect::<Vec<_>>();
+        for reporter in &mut self.reporters {
+            let mut output = String::new();
+            for diagnostic in &diagnostics {
+                if let Some(mut err_str) = reporter.render_error(path, diagnostic) {
                     // Skip large output and print only once
                     if err_str.lines().any(|line| line.len() >= 400) {
-                        let minified_diagnostic = Error::new(MinifiedFileError(path.clone()));
+                        let minified_diagnostic = Error::new(MinifiedFileError(path.to_path_buf()));
                         err_str = format!("{minified_diagnostic:?}");
                         output = err_str;
                         break;
@@ -130,9 +220,267 @@ impl DiagnosticService {
                     output.push_str(&err_str);
                 }
             }
-            self.reporter.render_diagnostics(output.as_bytes());
+            reporter.render_diagnostics(output.as_bytes());
+        }
+    }
+
+    /// Whether `diagnostic` is reported, counting it when it is a warning or an error
+    fn count(&self, path: &Path, diagnostic: &Error) -> bool {
+        if !self.is_on_accepted_line(path, diagnostic) {
+            return false;
+        }
+        let severity = diagnostic.severity();
+        let is_warning = severity == Some(Severity::Warning);
+        let is_error = severity.is_none() || severity == Some(Severity::Error);
+        if is_warning {
+            let warnings_count = self.warnings_count() + 1;
+            self.warnings_count.set(warnings_count);
+        }
+        if is_error {
+            let errors_count = self.errors_count() + 1;
+            self.errors_count.set(errors_count);
+        }
+        // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
+        // Note that it does not disable ALL diagnostics, only Warning diagnostics
+        !(is_warning && self.quiet)
+    }
+
+    fn is_on_accepted_line(&self, path: &Path, diagnostic: &Error) -> bool {
+        let Some(line_filter) = &self.line_filter else { return true };
+        let line = diagnostic.labels().and_then(|mut labels| labels.next()).and_then(|label| {
+            let source_code = diagnostic.source_code()?;
+            let contents = source_code.read_span(label.inner(), 0, 0).ok()?;
+            Some(contents.line() + 1)
+        });
+        line.map_or(true, |line| line_filter(path, line))
+    }
+}
+
+/// The diagnostics of the files in the order they are reported, see `DiagnosticService::with_stable_order`.
+struct StableOrder {
+    /// The expected files sorted by path, with the parts of their diagnostics received so far,
+    /// and whether they are complete.
+    files: Vec<(PathBuf, Vec<Vec<Error>>, bool)>,
+    /// The number of `files` reported
+    reported: usize,
+    /// The diagnostics of the other files
+    others: Vec<DiagnosticTuple>,
+}
+
+impl StableOrder {
+    fn new(mut expected_files: Vec<PathBuf>) -> Self {
+        expected_files.sort_unstable();
+        expected_files.dedup();
+        let files = expected_files.into_iter().map(|path| (path, vec![], false)).collect();
+        Self { files, reported: 0, others: vec![] }
+    }
+
+    /// Receives the diagnostics of `path`, returns the diagnostics which can be reported now.
+    fn push(&mut self, path: PathBuf, diagnostics: Vec<Error>) -> Vec<DiagnosticTuple> {
+        match self.files.binary_search_by(|(p, _, _)| p.cmp(&path)) {
+            Ok(i) if i >= self.reported => {
+                if diagnostics.is_empty() {
+                    self.files[i].2 = true;
+                } else {
+                    self.files[i].1.push(diagnostics);
+                }
+            }
+            _ => {
+                if !diagnostics.is_empty() {
+                    self.others.push((path, diagnostics));
+                }
+                return vec![];
+            }
+        }
+        let complete = self.files[self.reported..].iter().take_while(|(_, _, complete)| *complete);
+        let count = complete.count();
+        self.take(count)
+    }
+
+    /// The diagnostics of the files not complete yet, then of the other files.
+    fn finish(mut self) -> Vec<DiagnosticTuple> {
+        let mut diagnostics = self.take(self.files.len() - self.reported);
+        // Stable, for the files whose diagnostics are sent in parts
+        self.others.sort_by(|(a, _), (b, _)| a.cmp(b));
+        diagnostics.append(&mut self.others);
+        diagnostics
+    }
+
+    /// The diagnostics of the next `count` files, which are then reported.
+    fn take(&mut self, count: usize) -> Vec<DiagnosticTuple> {
+        let files = &mut self.files[self.reported..self.reported + count];
+        self.reported += count;
+        files
+            .iter_mut()
+            .flat_map(|(path, parts, _)| {
+                std::mem::take(parts).into_iter().map(|diagnostics| (path.clone(), diagnostics))
+            })
+            .collect()
+    }
+}
+
+/// A diagnostic whose labels are moved by `start` bytes.
+#[derive(Debug)]
+struct OffsetDiagnostic {
+    diagnostic: Error,
+    start: usize,
+}
+
+impl Display for OffsetDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl std::error::Error for OffsetDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for OffsetDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        None
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let labels = self.diagnostic.labels()?.map(|label| {
+            LabeledSpan::new(
+                label.label().map(ToString::to_string),
+                label.offset() + self.start,
+                label.len(),
+            )
+        });
+        Some(Box::new(labels))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, io, path::PathBuf, rc::Rc};
+
+    use super::StableOrder;
+    use crate::{
+        miette::{miette, LabeledSpan},
+        DiagnosticReporter, DiagnosticService, DiagnosticTuple,
+    };
+
+    /// A writer whose output is read after the reporter is dropped
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn stable_order() {
+        // The line filter is called with the files in the order they are reported
+        let reported = Rc::new(RefCell::new(vec![]));
+        let mut service = DiagnosticService::default().with_stable_order(true).with_line_filter({
+            let reported = Rc::clone(&reported);
+            move |path, _| {
+                reported.borrow_mut().push(path.to_path_buf());
+                true
+            }
+        });
+        service.set_json_reporter();
+
+        let sender = service.sender().clone();
+        for path in ["b.js", "a.js", "c.js", "a.js"] {
+            let diagnostic = miette!(labels = vec![LabeledSpan::at(0..8, "here")], "{path}");
+            let diagnostics =
+                DiagnosticService::wrap_diagnostics(path.as_ref(), "debugger;", vec![diagnostic]);
+            sender.send(Some(diagnostics)).unwrap();
         }
+        sender.send(None).unwrap();
+        service.run();
+
+        let expected = ["a.js", "a.js", "b.js", "c.js"].map(PathBuf::from);
+        assert_eq!(*reported.borrow(), expected);
+        assert_eq!(service.errors_count(), 4);
+    }
+
+    #[test]
+    fn stable_order_streams() {
+        let diagnostic = |message: &str| vec![miette!("{message}")];
+        let messages = |diagnostics: Vec<DiagnosticTuple>| {
+            diagnostics
+                .into_iter()
+                .flat_map(|(path, errors)| {
+                    errors.into_iter().map(move |error| format!("{}: {error}", path.display()))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut order = StableOrder::new(vec!["b.js".into(), "a.js".into()]);
+        assert!(order.push("b.js".into(), diagnostic("b")).is_empty());
+        assert!(order.push("a.js".into(), diagnostic("a1")).is_empty());
+        assert!(order.push("a.js".into(), diagnostic("a2")).is_empty());
+        // Reported once complete, before the others are linted
+        assert_eq!(messages(order.push("a.js".into(), vec![])), ["a.js: a1", "a.js: a2"]);
+        assert!(order.push(".oxlintrc.json".into(), diagnostic("config")).is_empty());
+        assert_eq!(messages(order.push("b.js".into(), vec![])), ["b.js: b"]);
+        assert_eq!(messages(order.finish()), [".oxlintrc.json: config"]);
+    }
+
+    #[test]
+    fn multiple_reporters() {
+        let github = SharedBuffer::default();
+        let json = SharedBuffer::default();
+        let mut service = DiagnosticService::default();
+        service.set_reporters(vec![
+            DiagnosticReporter::new_github().with_writer(github.clone()),
+            DiagnosticReporter::new_json().with_writer(json.clone()),
+        ]);
+
+        let sender = service.sender().clone();
+        let diagnostic = miette!(labels = vec![LabeledSpan::at(0..8, "here")], "debugger");
+        let diagnostics =
+            DiagnosticService::wrap_diagnostics("a.js".as_ref(), "debugger;", vec![diagnostic]);
+        sender.send(Some(diagnostics)).unwrap();
+        sender.send(None).unwrap();
+        service.run();
 
-        self.reporter.finish();
+        assert_eq!(service.errors_count(), 1);
+        assert!(github.text().starts_with("::error file=a.js,line=1"));
+        let json: serde_json::Value = serde_json::from_str(&json.text()).unwrap();
+        assert_eq!(json[0]["filename"], "a.js");
     }
 }
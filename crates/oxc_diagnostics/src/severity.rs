@@ -0,0 +1,65 @@
+use std::fmt::{self, Display};
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+use crate::{Error, Severity};
+
+/// Report `diagnostic` with another severity, e.g. the one a lint rule is configured with.
+pub fn with_severity(diagnostic: Error, severity: Severity) -> Error {
+    if diagnostic.severity() == Some(severity) {
+        return diagnostic;
+    }
+    Error::new(SeverityDiagnostic { diagnostic, severity })
+}
+
+#[derive(Debug)]
+struct SeverityDiagnostic {
+    diagnostic: Error,
+    severity: Severity,
+}
+
+impl Display for SeverityDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for SeverityDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for SeverityDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.diagnostic.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
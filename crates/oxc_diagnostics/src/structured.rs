@@ -0,0 +1,171 @@
+//! A representation of diagnostics for machine-readable output, decoupled from the report handlers.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::{
+    miette::{Diagnostic, SourceCode},
+    Error, Severity,
+};
+
+/// A diagnostic with its rule, file, message and position resolved,
+/// serialized by the JSON reporter and converted by the SARIF and GitHub reporters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredDiagnostic {
+    /// The rule reporting the diagnostic as `plugin/rule-name`, e.g. `eslint/no-debugger`,
+    /// `None` for parse and semantic errors. See `with_rule`.
+    pub rule: Option<String>,
+    pub severity: StructuredSeverity,
+    pub filename: PathBuf,
+    /// The message without the `plugin(rule-name): ` prefix
+    pub message: String,
+    pub help: Option<String>,
+    /// The position of the first label, `None` for diagnostics of the whole file.
+    pub span: Option<StructuredSpan>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StructuredSeverity {
+    Error,
+    Warning,
+    Advice,
+}
+
+/// A range of the file, lines and columns are 1-based as in the graphical report.
+/// Columns count characters, as expected by SARIF and GitHub, while offsets count bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredSpan {
+    /// Byte offset of the start
+    pub offset: usize,
+    /// Length in bytes
+    pub length: usize,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl StructuredDiagnostic {
+    pub fn new(path: &Path, error: &Error) -> Self {
+        let rule = error.code().map(|code| code.to_string());
+        let mut message = error.to_string();
+        if let Some(prefix_len) = rule.as_deref().and_then(|rule| rule_prefix_len(rule, &message)) {
+            message.drain(..prefix_len);
+        }
+        let severity = match error.severity() {
+            Some(Severity::Warning) => StructuredSeverity::Warning,
+            Some(Severity::Advice) => StructuredSeverity::Advice,
+            Some(Severity::Error) | None => StructuredSeverity::Error,
+        };
+        Self {
+            rule,
+            severity,
+            filename: path.to_path_buf(),
+            message,
+            help: error.help().map(|help| help.to_string()),
+            span: StructuredSpan::new(error.as_ref()),
+        }
+    }
+}
+
+impl StructuredSpan {
+    fn new(diagnostic: &dyn Diagnostic) -> Option<Self> {
+        let label = diagnostic.labels()?.next()?;
+        let source_code = diagnostic.source_code()?;
+        let (line, column) = line_and_column(source_code, label.offset())?;
+        let (end_line, end_column) = line_and_column(source_code, label.offset() + label.len())?;
+        Some(Self {
+            offset: label.offset(),
+            length: label.len(),
+            line,
+            column,
+            end_line,
+            end_column,
+        })
+    }
+}
+
+/// The 1-based line and character column of the byte `offset`.
+fn line_and_column(source_code: &dyn SourceCode, offset: usize) -> Option<(usize, usize)> {
+    let contents = source_code.read_span(&(offset, 0).into(), 0, 0).ok()?;
+    // The column of miette counts bytes
+    let line_start = offset - contents.column();
+    let line = source_code.read_span(&(line_start, contents.column()).into(), 0, 0).ok()?;
+    // An empty span is read as its first character
+    let column = String::from_utf8_lossy(line.data().get(..contents.column())?).chars().count();
+    Some((contents.line() + 1, column + 1))
+}
+
+/// The length of the `plugin(rule-name): ` prefix of the message of a rule,
+/// whose plugin is written in the message as e.g. `eslint-plugin-import` for `import`.
+fn rule_prefix_len(rule: &str, message: &str) -> Option<usize> {
+    let (_, rule_name) = rule.rsplit_once('/')?;
+    let (plugin, _) = message.split_once(&format!("({rule_name}): "))?;
+    (!plugin.is_empty() && !plugin.contains(char::is_whitespace))
+        .then(|| plugin.len() + rule_name.len() + 4)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use crate::{
+        miette::{self, Diagnostic, NamedSource},
+        thiserror::Error,
+        with_rule,
+    };
+
+    use super::{rule_prefix_len, StructuredDiagnostic, StructuredSeverity, StructuredSpan};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint(no-debugger): `debugger` statement is not allowed")]
+    #[diagnostic(severity(warning), help("Delete this code."))]
+    struct NoDebugger(#[label] miette::SourceSpan);
+
+    #[test]
+    fn new() {
+        let source = Arc::new(NamedSource::new("test.js", "let a;\nébc; debugger;\n".to_string()));
+        let error = miette::Error::new(NoDebugger((13, 9).into()));
+        let error = with_rule(error, "eslint", "no-debugger").with_source_code(source);
+        let diagnostic = StructuredDiagnostic::new(Path::new("test.js"), &error);
+        assert_eq!(
+            diagnostic,
+            StructuredDiagnostic {
+                rule: Some("eslint/no-debugger".to_string()),
+                severity: StructuredSeverity::Warning,
+                filename: "test.js".into(),
+                message: "`debugger` statement is not allowed".to_string(),
+                help: Some("Delete this code.".to_string()),
+                span: Some(StructuredSpan {
+                    offset: 13,
+                    length: 9,
+                    line: 2,
+                    column: 6,
+                    end_line: 2,
+                    end_column: 15,
+                }),
+            }
+        );
+
+        // Not reported by a rule
+        let error = miette::Error::new(NoDebugger((13, 9).into()));
+        let diagnostic = StructuredDiagnostic::new(Path::new("test.js"), &error);
+        assert_eq!(diagnostic.rule, None);
+        assert_eq!(diagnostic.message, "eslint(no-debugger): `debugger` statement is not allowed");
+    }
+
+    #[test]
+    fn rule_prefix() {
+        assert_eq!(rule_prefix_len("eslint/no-debugger", "eslint(no-debugger): a"), Some(21));
+        assert_eq!(
+            rule_prefix_len("import/no-unresolved", "eslint-plugin-import(no-unresolved): b"),
+            Some(37)
+        );
+        assert_eq!(rule_prefix_len("eslint/no-debugger", "Unexpected `debugger`"), None);
+        assert_eq!(rule_prefix_len("eslint/no-debugger", "A (no-debugger): c"), None);
+    }
+}
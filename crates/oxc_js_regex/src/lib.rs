@@ -1,5 +1,6 @@
 pub mod ast;
 mod lexer;
+pub mod literal;
 pub mod parser;
 pub mod validator;
 pub mod visitor;
@@ -0,0 +1,124 @@
+//! Patterns which match a fixed string, such as `^foo` or `\.js$`, for replacing regular
+//! expressions by string methods.
+
+use crate::validator::{validate_pattern, PatternMode, Validator};
+
+/// The string matched by a pattern, and the anchors of the pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteralPattern {
+    pub text: String,
+    /// The pattern starts with `^`
+    pub start_anchor: bool,
+    /// The pattern ends with `$`
+    pub end_anchor: bool,
+}
+
+/// Returns the string matched by `pattern` when it only consists of characters and escaped
+/// characters, apart from a leading `^` and a trailing `$`.
+///
+/// Surrogate pairs written as escapes, like `\uD83D\uDE00`, are read as one character, and
+/// patterns with lone surrogates are not literal.
+/// The flags are not taken into account, callers have to check that `i` and `m` are not set.
+pub fn literal_pattern(pattern: &str, mode: PatternMode) -> Option<LiteralPattern> {
+    validate_pattern(pattern, mode).ok()?;
+    let mut validator = Validator::new(pattern, mode);
+    let start_anchor = validator.peek() == Some('^');
+    if start_anchor {
+        validator.pos += 1;
+    }
+    let mut end_anchor = false;
+    let mut code_points = vec![];
+    while let Some(c) = validator.peek() {
+        validator.pos += 1;
+        let value = match c {
+            '$' if validator.pos == validator.chars.len() => {
+                end_anchor = true;
+                break;
+            }
+            '^' | '$' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' => {
+                return None
+            }
+            '\\' => {
+                let escaped = validator.peek()?;
+                validator.pos += 1;
+                match escaped {
+                    // Assertions, character sets and backreferences
+                    'b' | 'B' | 'd' | 'D' | 's' | 'S' | 'w' | 'W' | 'p' | 'P' | 'k' | '1'..='9' => {
+                        return None
+                    }
+                    _ => validator.character_escape(escaped, false).ok()?,
+                }
+            }
+            _ => u32::from(c),
+        };
+        if matches!(validator.peek(), Some('*' | '+' | '?' | '{')) {
+            return None;
+        }
+        code_points.push(value);
+    }
+
+    let mut text = String::with_capacity(code_points.len());
+    let mut code_points = code_points.into_iter().peekable();
+    while let Some(value) = code_points.next() {
+        let value = match code_points.peek() {
+            Some(&low) if (0xd800..0xdc00).contains(&value) && (0xdc00..0xe000).contains(&low) => {
+                code_points.next();
+                0x10000 + ((value - 0xd800) << 10) + (low - 0xdc00)
+            }
+            _ => value,
+        };
+        text.push(char::from_u32(value)?);
+    }
+    Some(LiteralPattern { text, start_anchor, end_anchor })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{literal_pattern, LiteralPattern};
+    use crate::validator::PatternMode;
+
+    fn literal(text: &str, start_anchor: bool, end_anchor: bool) -> LiteralPattern {
+        LiteralPattern { text: text.to_string(), start_anchor, end_anchor }
+    }
+
+    #[test]
+    fn literal_patterns() {
+        for (pattern, mode, expected) in [
+            ("foo", PatternMode::Legacy, literal("foo", false, false)),
+            ("^foo", PatternMode::Legacy, literal("foo", true, false)),
+            ("\\.js$", PatternMode::Legacy, literal(".js", false, true)),
+            ("^foo$", PatternMode::Legacy, literal("foo", true, true)),
+            ("\\^a\\$", PatternMode::Legacy, literal("^a$", false, false)),
+            ("^\\/\\x41\\u0042\\t", PatternMode::Legacy, literal("/AB\t", true, false)),
+            ("^\\u{1F600}", PatternMode::Unicode, literal("😀", true, false)),
+            ("^\\uD83D\\uDE00", PatternMode::Legacy, literal("😀", true, false)),
+            ("^你好", PatternMode::UnicodeSets, literal("你好", true, false)),
+            ("^\\c", PatternMode::Legacy, literal("\\c", true, false)),
+        ] {
+            assert_eq!(literal_pattern(pattern, mode), Some(expected), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn not_literal_patterns() {
+        for (pattern, mode) in [
+            ("^foo+", PatternMode::Legacy),
+            ("^fo{2}", PatternMode::Legacy),
+            ("^a|b", PatternMode::Legacy),
+            ("^[a]", PatternMode::Legacy),
+            ("^(a)", PatternMode::Legacy),
+            ("^a.", PatternMode::Legacy),
+            ("a^", PatternMode::Legacy),
+            ("$a", PatternMode::Legacy),
+            ("\\w$", PatternMode::Legacy),
+            ("\\bfoo", PatternMode::Legacy),
+            ("(a)\\1", PatternMode::Legacy),
+            ("^\\uD83D", PatternMode::Legacy),
+            ("^😀+", PatternMode::Legacy),
+            ("^\\u{1F600}", PatternMode::Legacy),
+            ("^\\a", PatternMode::Unicode),
+        ] {
+            assert_eq!(literal_pattern(pattern, mode), None, "{pattern}");
+        }
+    }
+}
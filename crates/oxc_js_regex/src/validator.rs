@@ -1 +1,546 @@
+//! Syntax validation of regular expression patterns.
+//!
+//! Follows the [`Pattern` grammar](https://tc39.es/ecma262/#sec-patterns), with the
+//! [Annex B](https://tc39.es/ecma262/#sec-regular-expressions-patterns) extensions when
+//! neither the `u` nor the `v` flag is set. Error messages use the wording of `regexpp`.
 
+/// How the pattern is interpreted, depending on the `u` and `v` flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternMode {
+    /// No `u` or `v` flag, Annex B syntax is allowed
+    Legacy,
+    /// The `u` flag
+    Unicode,
+    /// The `v` flag
+    UnicodeSets,
+}
+
+/// Validates `pattern`, the source between the slashes of a regular expression literal.
+///
+/// # Errors
+///
+/// Returns the reason when `pattern` is not a valid pattern in `mode`.
+pub fn validate_pattern(pattern: &str, mode: PatternMode) -> Result<(), &'static str> {
+    let mut validator = Validator::new(pattern, mode);
+    validator.scan_groups();
+    validator.pattern()
+}
+
+pub(crate) struct Validator {
+    pub(crate) chars: Vec<char>,
+    pub(crate) pos: usize,
+    unicode: bool,
+    unicode_sets: bool,
+    group_count: usize,
+    group_names: Vec<String>,
+    seen_group_names: Vec<String>,
+}
+
+impl Validator {
+    pub(crate) fn new(pattern: &str, mode: PatternMode) -> Self {
+        Self {
+            chars: pattern.chars().collect(),
+            pos: 0,
+            unicode: mode != PatternMode::Legacy,
+            unicode_sets: mode == PatternMode::UnicodeSets,
+            group_count: 0,
+            group_names: vec![],
+            seen_group_names: vec![],
+        }
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        s.chars().enumerate().all(|(i, c)| self.peek_at(i) == Some(c))
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Backreferences may refer to groups which come later in the pattern,
+    /// so the groups are counted before validating.
+    fn scan_groups(&mut self) {
+        let mut class_depth = 0;
+        let mut i = 0;
+        while let Some(&c) = self.chars.get(i) {
+            match c {
+                '\\' => i += 1,
+                '[' if class_depth == 0 || self.unicode_sets => class_depth += 1,
+                ']' if class_depth > 0 => class_depth -= 1,
+                '(' if class_depth == 0 => match (self.chars.get(i + 1), self.chars.get(i + 2)) {
+                    (Some('?'), Some('<')) if !matches!(self.chars.get(i + 3), Some('=' | '!')) => {
+                        self.group_count += 1;
+                        let name = self.chars[i + 3..].iter().take_while(|c| **c != '>').collect();
+                        self.group_names.push(name);
+                    }
+                    (Some('?'), _) => {}
+                    _ => self.group_count += 1,
+                },
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    fn pattern(&mut self) -> Result<(), &'static str> {
+        self.disjunction()?;
+        match self.peek() {
+            None => Ok(()),
+            Some(')') => Err("Unmatched ')'"),
+            Some(_) => Err("Lone quantifier brackets"),
+        }
+    }
+
+    fn disjunction(&mut self) -> Result<(), &'static str> {
+        loop {
+            while !matches!(self.peek(), None | Some('|' | ')')) {
+                self.term()?;
+            }
+            if !self.eat('|') {
+                return Ok(());
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<(), &'static str> {
+        let quantifiable = match self.peek() {
+            Some('^' | '$') => {
+                self.pos += 1;
+                false
+            }
+            Some('\\') if matches!(self.peek_at(1), Some('b' | 'B')) => {
+                self.pos += 2;
+                false
+            }
+            Some('(') if self.starts_with("(?=") || self.starts_with("(?!") => {
+                self.pos += 3;
+                self.group_end()?;
+                // Annex B allows quantified lookaheads
+                !self.unicode
+            }
+            Some('(') if self.starts_with("(?<=") || self.starts_with("(?<!") => {
+                self.pos += 4;
+                self.group_end()?;
+                false
+            }
+            Some('(') => {
+                if self.starts_with("(?:") {
+                    self.pos += 3;
+                } else if self.starts_with("(?<") {
+                    self.pos += 3;
+                    self.group_name()?;
+                } else if self.starts_with("(?") {
+                    return Err("Invalid group");
+                } else {
+                    self.pos += 1;
+                }
+                self.group_end()?;
+                true
+            }
+            Some('*' | '+' | '?') => return Err("Nothing to repeat"),
+            Some('{') => {
+                if self.brace_quantifier().is_some() {
+                    return Err("Nothing to repeat");
+                }
+                if self.unicode {
+                    return Err("Lone quantifier brackets");
+                }
+                self.pos += 1;
+                true
+            }
+            Some(']' | '}') if self.unicode => return Err("Lone quantifier brackets"),
+            Some('[') => {
+                self.class()?;
+                true
+            }
+            Some('\\') => {
+                self.atom_escape()?;
+                true
+            }
+            _ => {
+                self.pos += 1;
+                true
+            }
+        };
+        if quantifiable {
+            self.quantifier()?;
+        }
+        Ok(())
+    }
+
+    fn group_end(&mut self) -> Result<(), &'static str> {
+        self.disjunction()?;
+        if self.eat(')') {
+            Ok(())
+        } else {
+            Err("Unterminated group")
+        }
+    }
+
+    /// `name>` of `(?<name>`
+    fn group_name(&mut self) -> Result<(), &'static str> {
+        let name = self.identifier().ok_or("Invalid capture group name")?;
+        if self.seen_group_names.contains(&name) {
+            return Err("Duplicate capture group name");
+        }
+        self.seen_group_names.push(name);
+        Ok(())
+    }
+
+    fn identifier(&mut self) -> Option<String> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            let valid = if self.pos == start {
+                c.is_alphabetic() || c == '$' || c == '_'
+            } else {
+                c.is_alphanumeric() || c == '$' || c == '_' || c == '\u{200c}' || c == '\u{200d}'
+            };
+            if !valid {
+                break;
+            }
+            self.pos += 1;
+        }
+        let name = self.chars[start..self.pos].iter().collect::<String>();
+        (!name.is_empty() && self.eat('>')).then_some(name)
+    }
+
+    /// The `min`, `max` and length of `{min,max}` at the current position
+    fn brace_quantifier(&self) -> Option<(u64, u64, usize)> {
+        let mut i = self.pos;
+        if self.chars.get(i) != Some(&'{') {
+            return None;
+        }
+        i += 1;
+        let read_number = |i: &mut usize| {
+            let start = *i;
+            let mut value: u64 = 0;
+            while let Some(digit) = self.chars.get(*i).and_then(|c| c.to_digit(10)) {
+                value = value.saturating_mul(10).saturating_add(u64::from(digit));
+                *i += 1;
+            }
+            (*i > start).then_some(value)
+        };
+        let min = read_number(&mut i)?;
+        let max = if self.chars.get(i) == Some(&',') {
+            i += 1;
+            read_number(&mut i).unwrap_or(u64::MAX)
+        } else {
+            min
+        };
+        (self.chars.get(i) == Some(&'}')).then_some((min, max, i + 1 - self.pos))
+    }
+
+    fn quantifier(&mut self) -> Result<(), &'static str> {
+        match self.peek() {
+            Some('*' | '+' | '?') => self.pos += 1,
+            Some('{') => match self.brace_quantifier() {
+                Some((min, max, _)) if max < min => {
+                    return Err("numbers out of order in {} quantifier")
+                }
+                Some((_, _, len)) => self.pos += len,
+                None if self.unicode => return Err("Incomplete quantifier"),
+                // A literal `{` in Annex B
+                None => return Ok(()),
+            },
+            _ => return Ok(()),
+        }
+        // Lazy quantifier
+        self.eat('?');
+        Ok(())
+    }
+
+    fn atom_escape(&mut self) -> Result<(), &'static str> {
+        self.pos += 1;
+        let Some(c) = self.peek() else { return Err("\\ at end of pattern") };
+        self.pos += 1;
+        match c {
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => Ok(()),
+            'p' | 'P' if self.unicode => self.property(),
+            'k' if self.unicode || !self.group_names.is_empty() => {
+                if !self.eat('<') {
+                    return Err("Invalid named reference");
+                }
+                let name = self.identifier().ok_or("Invalid named reference")?;
+                if self.group_names.contains(&name) {
+                    Ok(())
+                } else {
+                    Err("Invalid named capture referenced")
+                }
+            }
+            '1'..='9' => {
+                let start = self.pos - 1;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                let reference = self.chars[start..self.pos]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<usize>()
+                    .unwrap_or(usize::MAX);
+                // Annex B reads references to missing groups as legacy octal escapes
+                if self.unicode && reference > self.group_count {
+                    return Err("Invalid escape");
+                }
+                Ok(())
+            }
+            _ => self.character_escape(c, false).map(|_| ()),
+        }
+    }
+
+    /// The value of the escape `\c`, where `c` has already been consumed
+    pub(crate) fn character_escape(&mut self, c: char, in_class: bool) -> Result<u32, &'static str> {
+        let value = match c {
+            'f' => 0x0c,
+            'n' => 0x0a,
+            'r' => 0x0d,
+            't' => 0x09,
+            'v' => 0x0b,
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.pos += 1;
+                    u32::from(letter) % 32
+                }
+                // Annex B allows `[\c0]` and `[\c_]`
+                Some(letter)
+                    if in_class && !self.unicode && (letter.is_ascii_digit() || letter == '_') =>
+                {
+                    self.pos += 1;
+                    u32::from(letter) % 32
+                }
+                _ if self.unicode => return Err("Invalid unicode escape"),
+                // `\c` is read as `\` followed by `c`
+                _ => {
+                    self.pos -= 1;
+                    u32::from('\\')
+                }
+            },
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            '0'..='7' if !self.unicode => {
+                // Legacy octal escape, at most `\377`
+                let mut value = c.to_digit(8).unwrap_or_default();
+                while let Some(digit) = self.peek().and_then(|c| c.to_digit(8)) {
+                    if value * 8 + digit > 0o377 {
+                        break;
+                    }
+                    value = value * 8 + digit;
+                    self.pos += 1;
+                }
+                value
+            }
+            'x' => match self.hex_digits(2) {
+                Some(value) => value,
+                None if self.unicode => return Err("Invalid escape"),
+                None => u32::from('x'),
+            },
+            'u' => match self.unicode_escape() {
+                Some(value) => value,
+                None if self.unicode => return Err("Invalid unicode escape"),
+                None => u32::from('u'),
+            },
+            '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+            | '/' => u32::from(c),
+            '-' if in_class => u32::from(c),
+            _ if self.unicode => {
+                return Err(if in_class { "Invalid class escape" } else { "Invalid escape" })
+            }
+            _ => u32::from(c),
+        };
+        Ok(value)
+    }
+
+    fn hex_digits(&mut self, len: usize) -> Option<u32> {
+        let digits = (0..len).map(|i| self.peek_at(i)?.to_digit(16)).collect::<Option<Vec<_>>>()?;
+        self.pos += len;
+        Some(digits.into_iter().fold(0, |value, digit| value * 16 + digit))
+    }
+
+    /// `XXXX` or, in unicode mode, `{X...}` after `\u`
+    fn unicode_escape(&mut self) -> Option<u32> {
+        if self.unicode && self.peek() == Some('{') {
+            let start = self.pos;
+            self.pos += 1;
+            let mut value: u32 = 0;
+            let mut len = 0;
+            while let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) {
+                value = value.saturating_mul(16).saturating_add(digit);
+                self.pos += 1;
+                len += 1;
+            }
+            if len > 0 && value <= 0x0010_ffff && self.eat('}') {
+                return Some(value);
+            }
+            self.pos = start;
+            return None;
+        }
+        self.hex_digits(4)
+    }
+
+    /// `{Name}` or `{Name=Value}` after `\p` or `\P`
+    fn property(&mut self) -> Result<(), &'static str> {
+        if !self.eat('{') {
+            return Err("Invalid property name");
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '=') {
+            self.pos += 1;
+        }
+        if self.pos == start || !self.eat('}') {
+            return Err("Invalid property name");
+        }
+        Ok(())
+    }
+
+    fn class(&mut self) -> Result<(), &'static str> {
+        if self.unicode_sets {
+            return self.class_set();
+        }
+        self.pos += 1;
+        self.eat('^');
+        loop {
+            match self.peek() {
+                None => return Err("Unterminated character class"),
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ => {}
+            }
+            let from = self.class_atom()?;
+            if self.peek() == Some('-') && !matches!(self.peek_at(1), None | Some(']')) {
+                self.pos += 1;
+                let to = self.class_atom()?;
+                match (from, to) {
+                    (Some(from), Some(to)) if from > to => {
+                        return Err("Range out of order in character class")
+                    }
+                    (Some(_), Some(_)) => {}
+                    // Annex B reads `[\d-a]` as `\d`, `-` and `a`
+                    _ if self.unicode => return Err("Invalid character class"),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// The value of the character, or `None` for character sets like `\d`
+    fn class_atom(&mut self) -> Result<Option<u32>, &'static str> {
+        let Some(c) = self.peek() else { return Err("Unterminated character class") };
+        self.pos += 1;
+        if c != '\\' {
+            return Ok(Some(u32::from(c)));
+        }
+        let Some(c) = self.peek() else { return Err("\\ at end of pattern") };
+        self.pos += 1;
+        match c {
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => Ok(None),
+            'p' | 'P' if self.unicode => self.property().map(|()| None),
+            'b' => Ok(Some(0x08)),
+            // `\8` and `\9` are identity escapes in Annex B
+            '1'..='9' if self.unicode => Err("Invalid class escape"),
+            _ => self.character_escape(c, true).map(Some),
+        }
+    }
+
+    /// Class set expressions of the `v` flag, which allow nested classes, `&&`, `--` and `\q{...}`
+    fn class_set(&mut self) -> Result<(), &'static str> {
+        self.pos += 1;
+        self.eat('^');
+        loop {
+            match self.peek() {
+                None => return Err("Unterminated character class"),
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Some('[') => self.class_set()?,
+                Some('\\') if self.peek_at(1) == Some('q') => {
+                    self.pos += 2;
+                    if !self.eat('{') {
+                        return Err("Invalid escape");
+                    }
+                    loop {
+                        match self.peek() {
+                            None => return Err("Unterminated class string disjunction"),
+                            Some('}') => break,
+                            Some('\\') => self.pos += 2,
+                            Some(_) => self.pos += 1,
+                        }
+                    }
+                    self.pos += 1;
+                }
+                Some(_) => {
+                    self.class_atom()?;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{validate_pattern, PatternMode};
+
+    #[test]
+    fn valid() {
+        for pattern in [
+            "",
+            "a|b",
+            "(a)(?:b)(?<c>d)\\k<c>\\1",
+            "^a*b+?c{1,2}d{3,}$",
+            "[a-z\\d_-]",
+            "(?=a)(?!b)(?<=c)(?<!d)",
+            "\\u{1F600}\\p{Letter}\\x41\\u0041\\cA",
+        ] {
+            assert_eq!(validate_pattern(pattern, PatternMode::Unicode), Ok(()), "{pattern}");
+        }
+        // Annex B
+        for pattern in ["a{", "}", "]", "\\c", "\\8", "[\\d-a]", "(?=a)*", "\\k", "\\u{1}"] {
+            assert_eq!(validate_pattern(pattern, PatternMode::Legacy), Ok(()), "{pattern}");
+        }
+        assert_eq!(validate_pattern("[[a-z]--[aeiou]]", PatternMode::UnicodeSets), Ok(()));
+        assert_eq!(validate_pattern("[\\q{abc|d}&&\\w]", PatternMode::UnicodeSets), Ok(()));
+    }
+
+    #[test]
+    fn invalid() {
+        for (pattern, mode, message) in [
+            ("(", PatternMode::Legacy, "Unterminated group"),
+            (")", PatternMode::Legacy, "Unmatched ')'"),
+            ("[", PatternMode::Legacy, "Unterminated character class"),
+            ("*", PatternMode::Legacy, "Nothing to repeat"),
+            ("a**", PatternMode::Legacy, "Nothing to repeat"),
+            ("^*", PatternMode::Legacy, "Nothing to repeat"),
+            ("a{2,1}", PatternMode::Legacy, "numbers out of order in {} quantifier"),
+            ("[z-a]", PatternMode::Legacy, "Range out of order in character class"),
+            ("\\", PatternMode::Legacy, "\\ at end of pattern"),
+            ("(?a)", PatternMode::Legacy, "Invalid group"),
+            ("(?<a>)(?<a>)", PatternMode::Legacy, "Duplicate capture group name"),
+            ("(?<a>)\\k<b>", PatternMode::Legacy, "Invalid named capture referenced"),
+            ("(?<1>)", PatternMode::Legacy, "Invalid capture group name"),
+            ("a{", PatternMode::Unicode, "Incomplete quantifier"),
+            ("}", PatternMode::Unicode, "Lone quantifier brackets"),
+            ("\\a", PatternMode::Unicode, "Invalid escape"),
+            ("\\1", PatternMode::Unicode, "Invalid escape"),
+            ("\\u{110000}", PatternMode::Unicode, "Invalid unicode escape"),
+            ("[\\d-a]", PatternMode::Unicode, "Invalid character class"),
+            ("(?=a)*", PatternMode::Unicode, "Nothing to repeat"),
+            ("\\p", PatternMode::Unicode, "Invalid property name"),
+            ("[a", PatternMode::UnicodeSets, "Unterminated character class"),
+        ] {
+            assert_eq!(validate_pattern(pattern, mode), Err(message), "{pattern}");
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+use oxc_linter::FixKind;
+
+use crate::linter::DiagnosticReport;
+
+/// Fixes all auto-fixable problems, e.g. with `"editor.codeActionsOnSave": { "source.fixAll.oxc": true }`
+pub const SOURCE_FIX_ALL_OXC: CodeActionKind = CodeActionKind::new("source.fixAll.oxc");
+
+/// The code actions for the diagnostics overlapping `range`, in order:
+/// * "Fix this `rule` problem" for each fixable diagnostic
+/// * "Fix all `rule` problems" when the rule has several safely fixable diagnostics in the file
+/// * "Fix all auto-fixable problems" when the file has several safely fixable diagnostics
+///
+/// Fixes which may change the behavior of the code are only offered for their own diagnostic.
+/// `only` filters the kinds of the actions, as requested by the client.
+pub fn code_actions(
+    uri: &Url,
+    reports: &[DiagnosticReport],
+    range: Range,
+    only: Option<&[CodeActionKind]>,
+) -> Vec<CodeActionOrCommand> {
+    let wants = |kind: &CodeActionKind| {
+        only.map_or(true, |only| only.iter().any(|only| kind.as_str().starts_with(only.as_str())))
+    };
+    let fixable = reports.iter().filter(|r| r.fixed_content.is_some()).collect::<Vec<_>>();
+    let auto_fixable = fixable
+        .iter()
+        .copied()
+        .filter(|r| r.fixed_content.as_ref().is_some_and(|fix| fix.kind == FixKind::Safe))
+        .collect::<Vec<_>>();
+    let mut actions = vec![];
+
+    // Source actions are only requested explicitly, e.g. on save
+    if only.is_some() && wants(&SOURCE_FIX_ALL_OXC) {
+        if !auto_fixable.is_empty() {
+            actions.push(fix_all_action(
+                uri,
+                "Fix all auto-fixable problems",
+                SOURCE_FIX_ALL_OXC,
+                &auto_fixable,
+            ));
+        }
+        return actions.into_iter().map(CodeActionOrCommand::CodeAction).collect();
+    }
+    if !wants(&CodeActionKind::QUICKFIX) {
+        return vec![];
+    }
+
+    let in_range =
+        fixable.iter().filter(|r| overlaps(&r.diagnostic.range, &range)).collect::<Vec<_>>();
+    let mut rules = vec![];
+    for report in &in_range {
+        let rule = rule_of(report);
+        let fixed_content = report.fixed_content.clone().unwrap();
+        actions.push(CodeAction {
+            title: rule.map_or_else(
+                || "Fix this problem".into(),
+                |rule| format!("Fix this {rule} problem"),
+            ),
+            kind: Some(CodeActionKind::QUICKFIX),
+            is_preferred: Some(fixed_content.kind == FixKind::Safe),
+            edit: Some(workspace_edit(
+                uri,
+                vec![TextEdit { range: fixed_content.range, new_text: fixed_content.code }],
+            )),
+            diagnostics: Some(vec![report.diagnostic.clone()]),
+            ..CodeAction::default()
+        });
+        if let Some(rule) = rule {
+            if !rules.contains(&rule) {
+                rules.push(rule);
+            }
+        }
+    }
+    for rule in rules {
+        let same_rule =
+            auto_fixable.iter().copied().filter(|r| rule_of(r) == Some(rule)).collect::<Vec<_>>();
+        if same_rule.len() > 1 {
+            actions.push(fix_all_action(
+                uri,
+                &format!("Fix all {rule} problems"),
+                CodeActionKind::QUICKFIX,
+                &same_rule,
+            ));
+        }
+    }
+    if !in_range.is_empty() && auto_fixable.len() > 1 {
+        actions.push(fix_all_action(
+            uri,
+            "Fix all auto-fixable problems",
+            CodeActionKind::QUICKFIX,
+            &auto_fixable,
+        ));
+    }
+
+    actions.into_iter().map(CodeActionOrCommand::CodeAction).collect()
+}
+
+/// The rule of a diagnostic, the prefix of its message, e.g. `eslint(no-debugger)`
+fn rule_of(report: &DiagnosticReport) -> Option<&str> {
+    report.diagnostic.message.split_once(':').map(|(rule, _)| rule)
+}
+
+/// An action applying the fix of every report, skipping fixes which overlap an earlier one
+/// as the linter does when fixing a file
+fn fix_all_action(
+    uri: &Url,
+    title: &str,
+    kind: CodeActionKind,
+    reports: &[&DiagnosticReport],
+) -> CodeAction {
+    let mut fixes = reports.iter().filter_map(|r| r.fixed_content.as_ref()).collect::<Vec<_>>();
+    fixes.sort_by_key(|fix| (fix.range.start, fix.range.end));
+    let mut edits: Vec<TextEdit> = vec![];
+    for fix in fixes {
+        if let Some(last) = edits.last() {
+            let duplicate = last.range == fix.range && last.new_text == fix.code;
+            if duplicate || fix.range.start < last.range.end {
+                continue;
+            }
+        }
+        edits.push(TextEdit { range: fix.range, new_text: fix.code.clone() });
+    }
+    CodeAction {
+        title: title.into(),
+        kind: Some(kind),
+        edit: Some(workspace_edit(uri, edits)),
+        ..CodeAction::default()
+    }
+}
+
+fn workspace_edit(uri: &Url, edits: Vec<TextEdit>) -> WorkspaceEdit {
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(uri.clone(), edits)])),
+        ..WorkspaceEdit::default()
+    }
+}
+
+fn overlaps(a: &Range, b: &Range) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
@@ -0,0 +1,151 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Position, TextDocumentContentChangeEvent};
+
+use crate::linter::DiagnosticReport;
+
+/// An opened document, which may not be saved to disk yet.
+#[derive(Debug)]
+pub struct Document {
+    text: Rope,
+    version: i32,
+    /// The diagnostics of the current version, `None` until it is linted.
+    reports: Option<Vec<DiagnosticReport>>,
+}
+
+impl Document {
+    pub fn new(text: &str, version: i32) -> Self {
+        Self { text: Rope::from_str(text), version, reports: None }
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    pub fn text(&self) -> String {
+        self.text.to_string()
+    }
+
+    /// The diagnostics of the current version, when it was linted already.
+    pub fn reports(&self) -> Option<&[DiagnosticReport]> {
+        self.reports.as_deref()
+    }
+
+    /// Keeps the diagnostics of `version`, unless the document changed since it was linted.
+    pub fn set_reports(&mut self, version: i32, reports: Vec<DiagnosticReport>) {
+        if version == self.version {
+            self.reports = Some(reports);
+        }
+    }
+
+    /// Applies the changes of `version` in order, returning `false` for a stale version
+    /// which is not applied.
+    pub fn apply_changes(
+        &mut self,
+        changes: &[TextDocumentContentChangeEvent],
+        version: i32,
+    ) -> bool {
+        if version <= self.version {
+            return false;
+        }
+        for change in changes {
+            match change.range {
+                Some(range) => {
+                    let start = self.position_to_char(range.start);
+                    let end = self.position_to_char(range.end).max(start);
+                    self.text.remove(start..end);
+                    self.text.insert(start, &change.text);
+                }
+                None => self.text = Rope::from_str(&change.text),
+            }
+        }
+        self.version = version;
+        self.reports = None;
+        true
+    }
+
+    /// Positions are in UTF-16 code units, clamped to the end of their line, or of the
+    /// document for a line past the last one.
+    fn position_to_char(&self, position: Position) -> usize {
+        let line = position.line as usize;
+        if line >= self.text.len_lines() {
+            return self.text.len_chars();
+        }
+        let line_start = self.text.line_to_char(line);
+        let line_text = self.text.line(line);
+        let mut line_len = line_text.len_chars();
+        while line_len > 0 && matches!(line_text.char(line_len - 1), '\n' | '\r') {
+            line_len -= 1;
+        }
+        let line_end = line_start + line_len;
+        let line_start_utf16 = self.text.char_to_utf16_cu(line_start);
+        let utf16 = line_start_utf16 + position.character as usize;
+        let utf16 = utf16.min(self.text.char_to_utf16_cu(line_end));
+        self.text.utf16_cu_to_char(utf16)
+    }
+}
+
+/// The position of a byte offset, with the column in UTF-16 code units as in LSP.
+/// An offset past the end of the text is at its end.
+#[allow(clippy::cast_possible_truncation)]
+pub fn offset_to_position(rope: &Rope, offset: u32) -> Position {
+    let offset = (offset as usize).min(rope.len_bytes());
+    let line = rope.byte_to_line(offset);
+    let line_start = rope.char_to_utf16_cu(rope.line_to_char(line));
+    let column = rope.char_to_utf16_cu(rope.byte_to_char(offset)) - line_start;
+    Position::new(line as u32, column as u32)
+}
+
+/// The byte offset of a position with the column in UTF-16 code units,
+/// `None` when it is out of the text
+#[allow(clippy::cast_possible_truncation)]
+pub fn position_to_offset(rope: &Rope, position: Position) -> Option<u32> {
+    let line_start = rope.try_line_to_char(position.line as usize).ok()?;
+    let utf16 = rope.char_to_utf16_cu(line_start) + position.character as usize;
+    let offset = rope.try_char_to_byte(rope.try_utf16_cu_to_char(utf16).ok()?).ok()?;
+    Some(offset as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use ropey::Rope;
+    use tower_lsp::lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+
+    use super::{offset_to_position, position_to_offset, Document};
+
+    #[test]
+    fn utf16_positions() {
+        // `😀` is 4 bytes and 2 UTF-16 code units, `é` is 2 bytes and 1 code unit
+        let rope = Rope::from_str("a😀b\né c");
+        let positions = [(0, (0, 0)), (1, (0, 1)), (5, (0, 3)), (7, (1, 0)), (10, (1, 2))];
+        for (offset, (line, character)) in positions {
+            let position = Position::new(line, character);
+            assert_eq!(offset_to_position(&rope, offset), position, "{offset}");
+            assert_eq!(position_to_offset(&rope, position), Some(offset), "{offset}");
+        }
+        assert_eq!(offset_to_position(&rope, 100), Position::new(1, 3));
+        assert_eq!(position_to_offset(&rope, Position::new(3, 0)), None);
+    }
+
+    #[test]
+    fn apply_changes() {
+        let change =
+            |range: Option<((u32, u32), (u32, u32))>, text: &str| TextDocumentContentChangeEvent {
+                range: range.map(|((l1, c1), (l2, c2))| {
+                    Range::new(Position::new(l1, c1), Position::new(l2, c2))
+                }),
+                range_length: None,
+                text: text.to_string(),
+            };
+        let mut document = Document::new("let a = '😀';\nfoo();\n", 1);
+        // After the emoji, in UTF-16 code units
+        assert!(document.apply_changes(&[change(Some(((0, 11), (0, 11))), "!")], 2));
+        assert_eq!(document.text(), "let a = '😀!';\nfoo();\n");
+        // Clamped to the end of the line
+        assert!(document.apply_changes(&[change(Some(((1, 3), (1, 99))), ";")], 3));
+        assert_eq!(document.text(), "let a = '😀!';\nfoo;\n");
+        // Stale
+        assert!(!document.apply_changes(&[change(None, "")], 3));
+        assert!(document.apply_changes(&[change(None, "bar();")], 4));
+        assert_eq!((document.text().as_str(), document.version()), ("bar();", 4));
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::SourceType;
+use ropey::Rope;
+use tower_lsp::lsp_types::{Position, Range, TextEdit};
+
+use crate::document::offset_to_position;
+
+/// Format the whole document, returning no edits when it has syntax errors or is already formatted.
+pub fn format(path: &Path, source_text: &str) -> Option<Vec<TextEdit>> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    if !ret.errors.is_empty() {
+        return None;
+    }
+    let formatted = Prettier::new(&allocator, source_text, ret.trivias, PrettierOptions::default())
+        .build(&ret.program);
+    if formatted == source_text {
+        return Some(vec![]);
+    }
+    Some(vec![TextEdit::new(Range::new(Position::new(0, 0), end_position(source_text)), formatted)])
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn end_position(source_text: &str) -> Position {
+    offset_to_position(&Rope::from_str(source_text), source_text.len() as u32)
+}
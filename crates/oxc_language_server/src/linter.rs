@@ -1,5 +1,6 @@
 use log::debug;
 use std::{
+    cell::OnceCell,
     fs,
     path::{Path, PathBuf},
     rc::Rc,
@@ -8,13 +9,13 @@ use std::{
 
 use miette::NamedSource;
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{miette, Error, Severity};
+use oxc_diagnostics::{miette, CancellationToken, Error, Severity};
 use oxc_linter::{
     partial_loader::{
-        AstroPartialLoader, JavaScriptSource, SveltePartialLoader, VuePartialLoader,
-        LINT_PARTIAL_LOADER_EXT,
+        AstroPartialLoader, HtmlPartialLoader, JavaScriptSource, SveltePartialLoader,
+        VuePartialLoader, LINT_PARTIAL_LOADER_EXT,
     },
-    LintContext, Linter,
+    FixKind, LintContext, Linter,
 };
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -24,6 +25,8 @@ use tower_lsp::lsp_types::{
     self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, Url,
 };
 
+use crate::document;
+
 #[derive(Debug)]
 struct ErrorWithPosition {
     pub start_pos: Position,
@@ -43,7 +46,7 @@ struct LabeledSpanWithPosition {
 impl ErrorWithPosition {
     pub fn new(
         error: Error,
-        text: &str,
+        line_index: &LineIndex,
         fixed_content: Option<FixedContent>,
         start: usize,
     ) -> Self {
@@ -51,13 +54,12 @@ impl ErrorWithPosition {
         let labels_with_pos: Vec<LabeledSpanWithPosition> = labels
             .iter()
             .map(|labeled_span| LabeledSpanWithPosition {
-                start_pos: offset_to_position(labeled_span.offset() + start, text)
+                start_pos: line_index
+                    .offset_to_position(labeled_span.offset() + start)
+                    .unwrap_or_default(),
+                end_pos: line_index
+                    .offset_to_position(labeled_span.offset() + start + labeled_span.len())
                     .unwrap_or_default(),
-                end_pos: offset_to_position(
-                    labeled_span.offset() + start + labeled_span.len(),
-                    text,
-                )
-                .unwrap_or_default(),
                 message: labeled_span.label().map(ToString::to_string),
             })
             .collect();
@@ -151,6 +153,8 @@ struct ErrorReport {
 pub struct FixedContent {
     pub code: String,
     pub range: Range,
+    /// Only safe fixes are applied by the actions fixing several problems at once
+    pub kind: FixKind,
 }
 
 #[derive(Debug)]
@@ -163,13 +167,19 @@ impl IsolatedLintHandler {
         Self { linter }
     }
 
+    /// `None` if the extension is not linted, or if `token` was cancelled.
     pub fn run_single(
         &self,
         path: &Path,
         content: Option<String>,
+        token: &CancellationToken,
     ) -> Option<Vec<DiagnosticReport>> {
         if Self::is_wanted_ext(path) {
-            Some(Self::lint_path(&self.linter, path, content).map_or(vec![], |(p, errors)| {
+            let result = Self::lint_path(&self.linter, path, content, token);
+            if token.is_cancelled() {
+                return None;
+            }
+            Some(result.map_or(vec![], |(p, errors)| {
                 let mut diagnostics: Vec<DiagnosticReport> =
                     errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect();
                 // a diagnostics connected from related_info to original diagnostic
@@ -248,6 +258,7 @@ impl IsolatedLintHandler {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "html" => Some(HtmlPartialLoader::new(source_text).parse()),
             _ => None,
         }
     }
@@ -256,6 +267,7 @@ impl IsolatedLintHandler {
         linter: &Linter,
         path: &Path,
         source_text: Option<String>,
+        token: &CancellationToken,
     ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
         let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
         let (source_type, original_source_text) =
@@ -267,12 +279,15 @@ impl IsolatedLintHandler {
 
         debug!("lint {path:?}");
         let mut diagnostics = vec![];
+        let line_index = LineIndex::new(&original_source_text);
         for source in javascript_sources {
             let JavaScriptSource { source_text: javascript_source_text, source_type, start } =
                 source;
+            let javascript_line_index = LineIndex::new(javascript_source_text);
             let allocator = Allocator::default();
             let ret = Parser::new(&allocator, javascript_source_text, source_type)
                 .allow_return_outside_function(true)
+                .with_cancellation_token(token.clone())
                 .parse();
 
             if !ret.errors.is_empty() {
@@ -281,13 +296,14 @@ impl IsolatedLintHandler {
                     .into_iter()
                     .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
                     .collect();
-                return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
+                return Some(Self::wrap_diagnostics(path, &line_index, reports, start));
             };
 
             let program = allocator.alloc(ret.program);
             let semantic_ret = SemanticBuilder::new(javascript_source_text, source_type)
                 .with_trivias(ret.trivias)
                 .with_check_syntax_error(true)
+                .with_cancellation_token(token.clone())
                 .build(program);
 
             if !semantic_ret.errors.is_empty() {
@@ -296,13 +312,14 @@ impl IsolatedLintHandler {
                     .into_iter()
                     .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
                     .collect();
-                return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
+                return Some(Self::wrap_diagnostics(path, &line_index, reports, start));
             };
 
             let lint_ctx = LintContext::new(
                 path.to_path_buf().into_boxed_path(),
                 &Rc::new(semantic_ret.semantic),
-            );
+            )
+            .with_cancellation_token(token.clone());
 
             let result = linter.run(lint_ctx);
 
@@ -311,17 +328,14 @@ impl IsolatedLintHandler {
                 .map(|msg| {
                     let fixed_content = msg.fix.map(|f| FixedContent {
                         code: f.content.to_string(),
+                        kind: f.kind,
                         range: Range {
-                            start: offset_to_position(
-                                f.span.start as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                            end: offset_to_position(
-                                f.span.end as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
+                            start: javascript_line_index
+                                .offset_to_position(f.span.start as usize + start)
+                                .unwrap_or_default(),
+                            end: javascript_line_index
+                                .offset_to_position(f.span.end as usize + start)
+                                .unwrap_or_default(),
                         },
                     });
 
@@ -329,7 +343,7 @@ impl IsolatedLintHandler {
                 })
                 .collect::<Vec<ErrorReport>>();
             let (_, errors_with_position) =
-                Self::wrap_diagnostics(path, &original_source_text, reports, start);
+                Self::wrap_diagnostics(path, &line_index, reports, start);
             diagnostics.extend(errors_with_position);
         }
 
@@ -338,17 +352,21 @@ impl IsolatedLintHandler {
 
     fn wrap_diagnostics(
         path: &Path,
-        source_text: &str,
+        line_index: &LineIndex,
         reports: Vec<ErrorReport>,
         start: usize,
     ) -> (PathBuf, Vec<ErrorWithPosition>) {
-        let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
+        if reports.is_empty() {
+            return (path.to_path_buf(), vec![]);
+        }
+        let source =
+            Arc::new(NamedSource::new(path.to_string_lossy(), line_index.source_text.to_owned()));
         let diagnostics = reports
             .into_iter()
             .map(|report| {
                 ErrorWithPosition::new(
                     report.error.with_source_code(Arc::clone(&source)),
-                    source_text,
+                    line_index,
                     report.fixed_content,
                     start,
                 )
@@ -366,25 +384,34 @@ fn get_valid_extensions() -> Vec<&'static str> {
         .collect::<Vec<&'static str>>()
 }
 
-#[allow(clippy::cast_possible_truncation)]
-fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
-    let rope = Rope::from_str(source_text);
-    let line = rope.try_byte_to_line(offset).ok()?;
-    let first_char_of_line = rope.try_line_to_char(line).ok()?;
-    // Original offset is byte, but Rope uses char offset
-    let offset = rope.try_byte_to_char(offset).ok()?;
-    let column = offset - first_char_of_line;
-    Some(Position::new(line as u32, column as u32))
+/// Converts byte offsets of a source text to LSP positions.
+/// The index is built on the first conversion, most files do not have any diagnostics.
+struct LineIndex<'a> {
+    source_text: &'a str,
+    rope: OnceCell<Rope>,
 }
 
-#[derive(Debug)]
+impl<'a> LineIndex<'a> {
+    fn new(source_text: &'a str) -> Self {
+        Self { source_text, rope: OnceCell::new() }
+    }
+
+    /// See `document::offset_to_position`, `None` for an offset past the end of the text
+    #[allow(clippy::cast_possible_truncation)]
+    fn offset_to_position(&self, offset: usize) -> Option<Position> {
+        let rope = self.rope.get_or_init(|| Rope::from_str(self.source_text));
+        (offset <= rope.len_bytes()).then(|| document::offset_to_position(rope, offset as u32))
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ServerLinter {
     linter: Arc<Linter>,
 }
 
 impl ServerLinter {
     pub fn new() -> Self {
-        let linter = Linter::default().with_fix(true);
+        let linter = Linter::default().with_fix(true).with_fix_kind(FixKind::Dangerous);
         Self { linter: Arc::new(linter) }
     }
 
@@ -392,9 +419,17 @@ impl ServerLinter {
         Self { linter: Arc::new(linter) }
     }
 
-    pub fn run_single(&self, uri: &Url, content: Option<String>) -> Option<Vec<DiagnosticReport>> {
-        IsolatedLintHandler::new(Arc::clone(&self.linter))
-            .run_single(&uri.to_file_path().unwrap(), content)
+    pub fn run_single(
+        &self,
+        uri: &Url,
+        content: Option<String>,
+        token: &CancellationToken,
+    ) -> Option<Vec<DiagnosticReport>> {
+        IsolatedLintHandler::new(Arc::clone(&self.linter)).run_single(
+            &uri.to_file_path().unwrap(),
+            content,
+            token,
+        )
     }
 }
 
@@ -1,40 +1,87 @@
+mod code_actions;
+mod document;
+mod formatter;
 mod linter;
 mod options;
+mod outline;
+mod rename;
+mod semantic_tokens;
 
+use crate::code_actions::{code_actions, SOURCE_FIX_ALL_OXC};
+use crate::document::Document;
 use crate::linter::{DiagnosticReport, ServerLinter};
 use globset::Glob;
 use ignore::gitignore::Gitignore;
 use log::{debug, error, info};
-use oxc_linter::{LintOptions, Linter};
+use oxc_diagnostics::CancellationToken;
+use oxc_linter::{FixKind, LintOptions, Linter};
+use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_span::VALID_EXTENSIONS;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use dashmap::DashMap;
 use futures::future::join_all;
 use tokio::sync::{Mutex, OnceCell, RwLock, SetError};
 use tower_lsp::jsonrpc::{Error, ErrorCode, Result};
 use tower_lsp::lsp_types::{
-    CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
-    CodeActionProviderCapability, CodeActionResponse, ConfigurationItem, Diagnostic,
-    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-    DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
-    InitializedParams, OneOf, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-    TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
-    WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+    CodeActionKind, CodeActionOptions, CodeActionParams, CodeActionProviderCapability,
+    CodeActionResponse, ConfigurationItem, Diagnostic, DiagnosticOptions,
+    DiagnosticServerCapabilities, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesParams, DidChangeWatchedFilesRegistrationOptions,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportResult,
+    DocumentFormattingParams, DocumentSymbolParams, DocumentSymbolResponse, FileSystemWatcher,
+    FoldingRange, FoldingRangeParams, FoldingRangeProviderCapability, FullDocumentDiagnosticReport,
+    GlobPattern, InitializeParams, InitializeResult, InitializedParams, OneOf,
+    PrepareRenameResponse, Registration, RelatedFullDocumentDiagnosticReport, RenameOptions,
+    RenameParams, SemanticTokens, SemanticTokensFullOptions, SemanticTokensOptions,
+    SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensRangeResult,
+    SemanticTokensResult, SemanticTokensServerCapabilities, ServerCapabilities, ServerInfo,
+    TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit,
+    Unregistration, Url, WorkDoneProgressOptions, WorkspaceDiagnosticParams,
+    WorkspaceDiagnosticReport, WorkspaceDiagnosticReportResult, WorkspaceDocumentDiagnosticReport,
+    WorkspaceEdit, WorkspaceFoldersServerCapabilities, WorkspaceFullDocumentDiagnosticReport,
+    WorkspaceServerCapabilities,
 };
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 
+/// How long to wait for more changes while typing before linting a document
+const LINT_DEBOUNCE: Duration = Duration::from_millis(150);
+
+const CONFIG_WATCHER_ID: &str = "oxc-config-watcher";
+
+/// Ignore files which are watched along with the config file
+const IGNORE_FILES: [&str; 2] = [".eslintignore", ".gitignore"];
+
 #[derive(Debug)]
 struct Backend {
     client: Client,
     root_uri: OnceCell<Option<Url>>,
     server_linter: RwLock<ServerLinter>,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
+    documents: DashMap<String, Document>,
+    /// Token of the last lint run of each document, cancelled when the document is linted again.
+    lint_tokens: DashMap<String, CancellationToken>,
+    /// Token of the last workspace diagnostics, cancelled when they are pulled again.
+    workspace_diagnostic_token: Mutex<CancellationToken>,
     options: Mutex<Options>,
     gitignore_glob: Mutex<Option<Gitignore>>,
+    client_support: OnceCell<ClientSupport>,
+}
+
+/// What the client supports, from its capabilities sent with `initialize`
+#[derive(Debug, Default, Clone, Copy)]
+struct ClientSupport {
+    /// The client pulls the diagnostics, which are not published to it then
+    pull_diagnostics: bool,
+    /// The client watches the files of the watchers registered by the server
+    dynamic_watchers: bool,
 }
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, PartialOrd, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
@@ -49,11 +96,19 @@ struct Options {
     run: Run,
     enable: bool,
     config_path: String,
+    /// Load the plugins of the configuration file, see `LintOptions::allow_plugins`.
+    #[serde(default)]
+    allow_plugins: bool,
 }
 
 impl Default for Options {
     fn default() -> Self {
-        Self { enable: true, run: Run::default(), config_path: ".eslintrc".into() }
+        Self {
+            enable: true,
+            run: Run::default(),
+            config_path: ".eslintrc".into(),
+            allow_plugins: false,
+        }
     }
 }
 
@@ -87,9 +142,22 @@ enum SyntheticRunLevel {
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let capabilities = &params.capabilities;
+        let client_support = ClientSupport {
+            pull_diagnostics: capabilities
+                .text_document
+                .as_ref()
+                .is_some_and(|text_document| text_document.diagnostic.is_some()),
+            dynamic_watchers: capabilities
+                .workspace
+                .as_ref()
+                .and_then(|workspace| workspace.did_change_watched_files.as_ref())
+                .and_then(|watched_files| watched_files.dynamic_registration)
+                .unwrap_or(false),
+        };
+        let _ = self.client_support.set(client_support);
         self.init(params.root_uri)?;
         self.init_ignore_glob().await;
-        self.init_linter_config().await;
         let options = params.initialization_options.and_then(|mut value| {
             let settings = value.get_mut("settings")?.take();
             serde_json::from_value::<Options>(settings).ok()
@@ -100,12 +168,14 @@ impl LanguageServer for Backend {
             info!("language server version: {:?}", env!("CARGO_PKG_VERSION"));
             *self.options.lock().await = value;
         }
+        // The config path comes from the options, so the linter can only be created afterwards.
+        self.init_linter_config().await;
         Ok(InitializeResult {
             server_info: Some(ServerInfo { name: "oxc".into(), version: None }),
             offset_encoding: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 workspace: Some(WorkspaceServerCapabilities {
                     workspace_folders: Some(WorkspaceFoldersServerCapabilities {
@@ -116,13 +186,44 @@ impl LanguageServer for Backend {
                 }),
                 code_action_provider: Some(CodeActionProviderCapability::Options(
                     CodeActionOptions {
-                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX, SOURCE_FIX_ALL_OXC]),
                         work_done_progress_options: WorkDoneProgressOptions {
                             work_done_progress: None,
                         },
                         resolve_provider: None,
                     },
                 )),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions {
+                        work_done_progress: None,
+                    },
+                })),
+                semantic_tokens_provider: Some(
+                    SemanticTokensServerCapabilities::SemanticTokensOptions(
+                        SemanticTokensOptions {
+                            legend: semantic_tokens::legend(),
+                            full: Some(SemanticTokensFullOptions::Bool(true)),
+                            range: Some(true),
+                            work_done_progress_options: WorkDoneProgressOptions {
+                                work_done_progress: None,
+                            },
+                        },
+                    ),
+                ),
+                diagnostic_provider: Some(DiagnosticServerCapabilities::Options(
+                    DiagnosticOptions {
+                        identifier: Some("oxc".into()),
+                        inter_file_dependencies: false,
+                        workspace_diagnostics: true,
+                        work_done_progress_options: WorkDoneProgressOptions {
+                            work_done_progress: None,
+                        },
+                    },
+                )),
                 ..ServerCapabilities::default()
             },
         })
@@ -152,7 +253,9 @@ impl LanguageServer for Backend {
             };
 
         debug!("{:?}", &changed_options.get_lint_level());
-        if changed_options.get_lint_level() == SyntheticRunLevel::Disable {
+        let disabled = changed_options.get_lint_level() == SyntheticRunLevel::Disable;
+        let pull_diagnostics = self.client_support().pull_diagnostics;
+        if disabled && !pull_diagnostics {
             // clear all exists diagnostics when linter is disabled
             let opened_files = self.diagnostics_report_map.iter().map(|k| k.key().to_string());
             let cleared_diagnostics = opened_files
@@ -170,11 +273,52 @@ impl LanguageServer for Backend {
                 .collect::<Vec<_>>();
             self.publish_all_diagnostics(&cleared_diagnostics).await;
         }
+
+        let config_path_changed =
+            self.options.lock().await.config_path != changed_options.config_path;
+        let allow_plugins_changed =
+            self.options.lock().await.allow_plugins != changed_options.allow_plugins;
         *self.options.lock().await = changed_options;
+        // The diagnostics are pulled again, empty now
+        if disabled && pull_diagnostics {
+            self.refresh_pulled_diagnostics().await;
+        }
+
+        if config_path_changed {
+            self.unregister_config_watcher().await;
+            self.register_config_watcher().await;
+        }
+        if config_path_changed || allow_plugins_changed {
+            self.init_linter_config().await;
+            self.refresh_diagnostics().await;
+        }
     }
 
     async fn initialized(&self, _params: InitializedParams) {
         debug!("oxc initialized.");
+        self.register_config_watcher().await;
+    }
+
+    /// Reload the linter or the ignore files when they are created, changed or deleted,
+    /// and re-publish the diagnostics of all opened files with the new rules.
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let (ignore_changes, config_changes): (Vec<_>, Vec<_>) =
+            params.changes.iter().partition(|change| {
+                change
+                    .uri
+                    .path_segments()
+                    .and_then(Iterator::last)
+                    .is_some_and(|name| IGNORE_FILES.contains(&name))
+            });
+        if !ignore_changes.is_empty() {
+            debug!("oxc ignore file changed");
+            self.init_ignore_glob().await;
+        }
+        if !config_changes.is_empty() {
+            debug!("oxc config file changed");
+            self.init_linter_config().await;
+        }
+        self.refresh_diagnostics().await;
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -192,12 +336,26 @@ impl LanguageServer for Backend {
         if self.is_ignored(&uri).await {
             return;
         }
-        self.handle_file_update(uri, None, None).await;
+        // Saving what was just typed, the diagnostics of this version are published already
+        if self.documents.get(&uri.to_string()).is_some_and(|document| document.reports().is_some())
+        {
+            return;
+        }
+        self.handle_file_update(uri, false).await;
     }
 
     /// When the document changed, it may not be written to disk, so we should
     /// get the file context from the language client
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let applied = self.documents.get_mut(&params.text_document.uri.to_string()).is_some_and(
+            |mut document| {
+                document.apply_changes(&params.content_changes, params.text_document.version)
+            },
+        );
+        if !applied {
+            debug!("ignored change of {}: not opened or stale", params.text_document.uri);
+            return;
+        }
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -207,16 +365,15 @@ impl LanguageServer for Backend {
         if self.is_ignored(uri).await {
             return;
         }
-        let content = params.content_changes.first().map(|c| c.text.clone());
-        self.handle_file_update(
-            params.text_document.uri,
-            content,
-            Some(params.text_document.version),
-        )
-        .await;
+        // Typing sends a change per keystroke, so only the last one of a burst is linted
+        self.handle_file_update(params.text_document.uri, true).await;
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        self.documents.insert(
+            params.text_document.uri.to_string(),
+            Document::new(&params.text_document.text, params.text_document.version),
+        );
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -224,54 +381,210 @@ impl LanguageServer for Backend {
         if self.is_ignored(&params.text_document.uri).await {
             return;
         }
-        self.handle_file_update(params.text_document.uri, None, Some(params.text_document.version))
-            .await;
+        self.handle_file_update(params.text_document.uri, false).await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri.to_string();
         self.diagnostics_report_map.remove(&uri);
+        self.documents.remove(&uri);
+        if let Some((_, token)) = self.lint_tokens.remove(&uri) {
+            token.cancel();
+        }
     }
 
-    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
-        let uri = params.text_document.uri;
+    /// Format the whole document with the prettier-compatible formatter (`textDocument/formatting`).
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let Some((path, source_text)) = self.source_text(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(formatter::format(&path, &source_text))
+    }
 
-        if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
-                .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
-            {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
-                        || "Fix this problem".into(),
-                        |s| format!("Fix this {s} problem"),
-                    );
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let Some((path, source_text)) = self.source_text(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(outline::document_symbols(&path, &source_text).map(DocumentSymbolResponse::Nested))
+    }
 
-                let fixed_content = report.fixed_content.clone().unwrap();
-
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
-                    title,
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
-                        ..WorkspaceEdit::default()
-                    }),
-                    disabled: None,
-                    data: None,
-                    diagnostics: None,
-                    command: None,
-                })]));
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let Some((path, source_text)) = self.source_text(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(outline::folding_ranges(&path, &source_text))
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<Option<SemanticTokensResult>> {
+        let Some((path, source_text)) = self.source_text(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(semantic_tokens::semantic_tokens(&path, &source_text, None)
+            .map(|data| SemanticTokensResult::Tokens(SemanticTokens { result_id: None, data })))
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> Result<Option<SemanticTokensRangeResult>> {
+        let Some((path, source_text)) = self.source_text(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(semantic_tokens::semantic_tokens(&path, &source_text, Some(params.range)).map(|data| {
+            SemanticTokensRangeResult::Tokens(SemanticTokens { result_id: None, data })
+        }))
+    }
+
+    /// Pull diagnostics (`textDocument/diagnostic`).
+    /// Reuses the reports from the last lint run, or lints the file from disk when there are none.
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
+        let uri = params.text_document.uri;
+        let disabled = self.options.lock().await.get_lint_level() == SyntheticRunLevel::Disable;
+        let cached = if disabled {
+            Some(vec![])
+        } else {
+            self.diagnostics_report_map
+                .get(&uri.to_string())
+                .map(|reports| reports.iter().map(|r| r.diagnostic.clone()).collect::<Vec<_>>())
+        };
+        let items = match cached {
+            Some(items) => items,
+            None if self.is_ignored(&uri).await => vec![],
+            None => {
+                let reports = self.server_linter.read().await.run_single(
+                    &uri,
+                    None,
+                    &CancellationToken::new(),
+                );
+                let items = reports
+                    .as_ref()
+                    .map_or(vec![], |r| r.iter().map(|r| r.diagnostic.clone()).collect());
+                if let Some(reports) = reports {
+                    self.diagnostics_report_map.insert(uri.to_string(), reports);
+                }
+                items
             }
+        };
+
+        Ok(DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+            RelatedFullDocumentDiagnosticReport {
+                related_documents: None,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: None,
+                    items,
+                },
+            },
+        )))
+    }
+
+    /// Pull diagnostics of the whole workspace (`workspace/diagnostic`).
+    /// Opened documents are reported from their last lint run, all other files are linted from disk
+    /// in parallel. A new pull cancels the previous one, which then returns `RequestCancelled`.
+    async fn workspace_diagnostic(
+        &self,
+        _params: WorkspaceDiagnosticParams,
+    ) -> Result<WorkspaceDiagnosticReportResult> {
+        let Some(root_path) =
+            self.root_uri.get().and_then(Option::as_ref).and_then(|uri| uri.to_file_path().ok())
+        else {
+            return Ok(WorkspaceDiagnosticReport::default().into());
+        };
+        if self.options.lock().await.get_lint_level() == SyntheticRunLevel::Disable {
+            return Ok(WorkspaceDiagnosticReport::default().into());
         }
+        let token = CancellationToken::new();
+        std::mem::replace(&mut *self.workspace_diagnostic_token.lock().await, token.clone())
+            .cancel();
+
+        let mut items = self
+            .documents
+            .iter()
+            .filter_map(|document| {
+                let uri = Url::from_str(document.key()).ok()?;
+                let reports = self.diagnostics_report_map.get(document.key())?;
+                let diagnostics = reports.iter().map(|r| r.diagnostic.clone()).collect();
+                Some(workspace_document_report(uri, Some(document.version()), diagnostics))
+            })
+            .collect::<Vec<_>>();
+
+        let opened = self.documents.iter().map(|d| d.key().clone()).collect::<HashSet<_>>();
+        let server_linter = self.server_linter.read().await.clone();
+        let lint_token = token.clone();
+        let linted = tokio::task::spawn_blocking(move || {
+            let uris = workspace_files(&root_path)
+                .filter_map(|path| Url::from_file_path(path).ok())
+                .filter(|uri| !opened.contains(uri.as_str()))
+                .collect::<Vec<_>>();
+            uris.into_par_iter()
+                .filter_map(|uri| {
+                    if lint_token.is_cancelled() {
+                        return None;
+                    }
+                    let reports = server_linter.run_single(&uri, None, &lint_token)?;
+                    let diagnostics = reports.into_iter().map(|r| r.diagnostic).collect();
+                    Some(workspace_document_report(uri, None, diagnostics))
+                })
+                .collect::<Vec<_>>()
+        })
+        .await
+        .unwrap_or_default();
+        if token.is_cancelled() {
+            return Err(Error::request_cancelled());
+        }
+        items.extend(linted);
+
+        Ok(WorkspaceDiagnosticReport { items }.into())
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let Some((path, source_text)) = self.source_text(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(rename::prepare_rename(&path, &source_text, params.position)
+            .map(PrepareRenameResponse::Range))
+    }
 
-        Ok(None)
+    /// Renames a declaration and its references in the document. When the name it is exported
+    /// under changes too, the imports of it in the other files of the workspace are updated.
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = params.text_document_position.text_document.uri;
+        let Some((path, source_text)) = self.source_text(&uri) else { return Ok(None) };
+        let renamed = rename::rename(
+            &path,
+            &source_text,
+            params.text_document_position.position,
+            &params.new_name,
+        )
+        .map_err(Error::invalid_params)?;
+        let Some(renamed) = renamed else { return Ok(None) };
+
+        let mut changes = HashMap::new();
+        if renamed.export_renamed {
+            changes = self.importer_edits(path, renamed.old_name, params.new_name).await;
+        }
+        changes.insert(uri, renamed.edits);
+        Ok(Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        let Some(reports) = self.diagnostics_report_map.get(&uri.to_string()) else {
+            return Ok(None);
+        };
+        let actions = code_actions(&uri, &reports, params.range, params.context.only.as_deref());
+        Ok((!actions.is_empty()).then_some(actions))
     }
 }
 
@@ -289,6 +602,74 @@ impl Backend {
         Ok(())
     }
 
+    /// The opened content of the document, or the file on disk when it is not opened.
+    fn source_text(&self, uri: &Url) -> Option<(PathBuf, String)> {
+        let path = uri.to_file_path().ok()?;
+        let source_text = match self.documents.get(&uri.to_string()) {
+            Some(document) => document.text(),
+            None => std::fs::read_to_string(&path).ok()?,
+        };
+        Some((path, source_text))
+    }
+
+    /// The edits of the files in the workspace importing the export `old_name` of `exporter`.
+    async fn importer_edits(
+        &self,
+        exporter: PathBuf,
+        old_name: String,
+        new_name: String,
+    ) -> HashMap<Url, Vec<TextEdit>> {
+        let Some(root_path) =
+            self.root_uri.get().and_then(Option::as_ref).and_then(|uri| uri.to_file_path().ok())
+        else {
+            return HashMap::new();
+        };
+        let mut opened = self
+            .documents
+            .iter()
+            .filter_map(|document| {
+                let path = Url::from_str(document.key()).ok()?.to_file_path().ok()?;
+                Some((path, document.text()))
+            })
+            .collect::<HashMap<_, _>>();
+        tokio::task::spawn_blocking(move || {
+            let resolver = Resolver::new(ResolveOptions {
+                extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+                ..ResolveOptions::default()
+            });
+            // The resolver returns canonical paths
+            let exporter = exporter.canonicalize().unwrap_or(exporter);
+            workspace_files(&root_path)
+                .filter(|path| {
+                    path.extension().is_some_and(|ext| {
+                        VALID_EXTENSIONS.contains(&ext.to_string_lossy().as_ref())
+                    })
+                })
+                .filter_map(|path| {
+                    let source_text = match opened.remove(&path) {
+                        Some(text) => text,
+                        None => std::fs::read_to_string(&path).ok()?,
+                    };
+                    if !source_text.contains(old_name.as_str()) {
+                        return None;
+                    }
+                    let edits = rename::importer_edits(
+                        &resolver,
+                        &path,
+                        &source_text,
+                        &exporter,
+                        &old_name,
+                        &new_name,
+                    );
+                    let uri = Url::from_file_path(&path).ok()?;
+                    (!edits.is_empty()).then_some((uri, edits))
+                })
+                .collect()
+        })
+        .await
+        .unwrap_or_default()
+    }
+
     async fn init_ignore_glob(&self) {
         let uri = self
             .root_uri
@@ -338,33 +719,157 @@ impl Backend {
             return;
         };
         let mut config_path = None;
-        let config = root_path.join(self.options.lock().await.get_config_path().unwrap());
-        if config.exists() {
-            config_path = Some(config);
+        let allow_plugins = self.options.lock().await.allow_plugins;
+        if let Some(path) = self.options.lock().await.get_config_path() {
+            let config = root_path.join(path);
+            if config.exists() {
+                config_path = Some(config);
+            }
+        }
+        let server_linter = match config_path {
+            Some(config_path) => {
+                match Linter::from_options(
+                    LintOptions::default()
+                        .with_fix(true)
+                        .with_fix_kind(FixKind::Dangerous)
+                        .with_config_path(Some(config_path))
+                        .with_allow_plugins(allow_plugins),
+                ) {
+                    Ok(linter) => ServerLinter::new_with_linter(linter),
+                    Err(err) => {
+                        // Keep the previous linter so a half-written config does not wipe out all diagnostics.
+                        error!("Failed to load configuration: {err:?}");
+                        return;
+                    }
+                }
+            }
+            None => ServerLinter::new(),
+        };
+        *self.server_linter.write().await = server_linter;
+    }
+
+    /// Watches the config file and the ignore files, see [LanguageServer::did_change_watched_files].
+    /// Re-registered when the config path changes.
+    async fn register_config_watcher(&self) {
+        if !self.client_support().dynamic_watchers {
+            debug!("the client does not support registering file watchers");
+            return;
         }
-        if let Some(config_path) = config_path {
-            let mut linter = self.server_linter.write().await;
-            *linter = ServerLinter::new_with_linter(
-                Linter::from_options(
-                    LintOptions::default().with_fix(true).with_config_path(Some(config_path)),
-                )
-                .expect("should have initialized linter with new options"),
-            );
+        let config_path = self.options.lock().await.get_config_path();
+        let watchers = config_path
+            .map(|path| format!("**/{}", path.display()))
+            .into_iter()
+            .chain(IGNORE_FILES.iter().map(|name| format!("**/{name}")))
+            .map(|pattern| FileSystemWatcher {
+                glob_pattern: GlobPattern::String(pattern),
+                kind: None,
+            })
+            .collect();
+        let register_options = DidChangeWatchedFilesRegistrationOptions { watchers };
+        let registration = Registration {
+            id: CONFIG_WATCHER_ID.into(),
+            method: "workspace/didChangeWatchedFiles".into(),
+            register_options: serde_json::to_value(register_options).ok(),
+        };
+        if let Err(err) = self.client.register_capability(vec![registration]).await {
+            error!("Failed to register the config file watcher: {err:?}");
         }
     }
 
-    async fn handle_file_update(&self, uri: Url, content: Option<String>, version: Option<i32>) {
-        if let Some(Some(_root_uri)) = self.root_uri.get() {
-            if let Some(diagnostics) = self.server_linter.read().await.run_single(&uri, content) {
-                self.client
-                    .publish_diagnostics(
-                        uri.clone(),
-                        diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
-                        version,
-                    )
-                    .await;
+    async fn unregister_config_watcher(&self) {
+        if !self.client_support().dynamic_watchers {
+            return;
+        }
+        let unregistration = Unregistration {
+            id: CONFIG_WATCHER_ID.into(),
+            method: "workspace/didChangeWatchedFiles".into(),
+        };
+        if let Err(err) = self.client.unregister_capability(vec![unregistration]).await {
+            debug!("Failed to unregister the config file watcher: {err:?}");
+        }
+    }
+
+    /// Re-publishes the diagnostics of all opened files, and asks the client to pull
+    /// the workspace diagnostics again.
+    async fn refresh_diagnostics(&self) {
+        self.revalidate_open_files().await;
+        self.refresh_pulled_diagnostics().await;
+    }
+
+    /// Asks the client to pull the diagnostics of the documents and of the workspace again.
+    async fn refresh_pulled_diagnostics(&self) {
+        // Errors when the client does not support refreshing pulled diagnostics
+        if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+            debug!("Failed to refresh workspace diagnostics: {err:?}");
+        }
+    }
+
+    fn client_support(&self) -> ClientSupport {
+        self.client_support.get().copied().unwrap_or_default()
+    }
+
+    async fn revalidate_open_files(&self) {
+        let uris = self
+            .diagnostics_report_map
+            .iter()
+            .filter_map(|entry| Url::from_str(entry.key()).ok())
+            .collect::<Vec<_>>();
+        for uri in uris {
+            self.handle_file_update(uri, false).await;
+        }
+    }
 
+    /// Lints the opened content of the document, or the file on disk when it is not opened.
+    /// With `debounce`, waits for [LINT_DEBOUNCE] first, and does not lint when the document
+    /// is updated again in the meantime.
+    async fn handle_file_update(&self, uri: Url, debounce: bool) {
+        if let Some(Some(_root_uri)) = self.root_uri.get() {
+            let token = CancellationToken::new();
+            if let Some(previous) = self.lint_tokens.insert(uri.to_string(), token.clone()) {
+                previous.cancel();
+            }
+            if debounce {
+                tokio::time::sleep(LINT_DEBOUNCE).await;
+                if token.is_cancelled() {
+                    return;
+                }
+            }
+            let (content, version) = self
+                .documents
+                .get(&uri.to_string())
+                .map(|document| (document.text(), document.version()))
+                .unzip();
+            // Linted on a blocking thread, so that the next change can cancel it.
+            let server_linter = self.server_linter.read().await.clone();
+            let lint_uri = uri.clone();
+            let diagnostics = tokio::task::spawn_blocking(move || {
+                server_linter.run_single(&lint_uri, content, &token)
+            })
+            .await
+            .ok()
+            .flatten();
+            if let Some(diagnostics) = diagnostics {
+                let pull_diagnostics = self.client_support().pull_diagnostics;
+                if !pull_diagnostics {
+                    self.client
+                        .publish_diagnostics(
+                            uri.clone(),
+                            diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                            version,
+                        )
+                        .await;
+                }
+
+                if let (Some(version), Some(mut document)) =
+                    (version, self.documents.get_mut(&uri.to_string()))
+                {
+                    document.set_reports(version, diagnostics.clone());
+                }
                 self.diagnostics_report_map.insert(uri.to_string(), diagnostics);
+                // Pulled by the client before they were linted, they are pulled again
+                if pull_diagnostics {
+                    self.refresh_pulled_diagnostics().await;
+                }
             }
         }
     }
@@ -390,6 +895,31 @@ impl Backend {
     }
 }
 
+/// The files in the workspace, without the ignored ones
+fn workspace_files(root_path: &Path) -> impl Iterator<Item = PathBuf> {
+    ignore::WalkBuilder::new(root_path)
+        .add_custom_ignore_filename(".eslintignore")
+        .require_git(false)
+        .git_global(false)
+        .build()
+        .flatten()
+        .filter(|entry| entry.file_type().is_some_and(|ty| !ty.is_dir()))
+        .map(ignore::DirEntry::into_path)
+}
+
+fn workspace_document_report(
+    uri: Url,
+    version: Option<i32>,
+    items: Vec<Diagnostic>,
+) -> WorkspaceDocumentDiagnosticReport {
+    WorkspaceFullDocumentDiagnosticReport {
+        uri,
+        version: version.map(i64::from),
+        full_document_diagnostic_report: FullDocumentDiagnosticReport { result_id: None, items },
+    }
+    .into()
+}
+
 #[tokio::main]
 async fn main() {
     env_logger::init();
@@ -405,8 +935,12 @@ async fn main() {
         root_uri: OnceCell::new(),
         server_linter: RwLock::new(server_linter),
         diagnostics_report_map,
+        documents: DashMap::new(),
+        lint_tokens: DashMap::new(),
+        workspace_diagnostic_token: Mutex::new(CancellationToken::new()),
         options: Mutex::new(Options::default()),
         gitignore_glob: Mutex::new(None),
+        client_support: OnceCell::new(),
     })
     .finish();
 
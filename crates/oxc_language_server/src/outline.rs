@@ -0,0 +1,335 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{
+        BindingPatternKind, ExportDefaultDeclarationKind, Expression, MethodDefinitionKind,
+        ModuleDeclaration, PropertyKey, Statement, TSEnumMemberName, TSModuleDeclarationName,
+        VariableDeclarationKind,
+    },
+    AstKind, AstType, CommentKind, Trivias, Visit,
+};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use ropey::Rope;
+use tower_lsp::lsp_types::{DocumentSymbol, FoldingRange, FoldingRangeKind, Range, SymbolKind};
+
+use crate::document::offset_to_position;
+
+/// The declarations of the document, e.g. functions, classes and their members, nested as
+/// they are in the source. Local variables inside of functions are left out.
+pub fn document_symbols(path: &Path, source_text: &str) -> Option<Vec<DocumentSymbol>> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut collector = SymbolCollector::new(source_text);
+    collector.visit_program(&ret.program);
+    Some(collector.symbols)
+}
+
+/// Foldable blocks, multi line comments, groups of imports and `// #region` markers.
+pub fn folding_ranges(path: &Path, source_text: &str) -> Option<Vec<FoldingRange>> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut collector = FoldingCollector::new(source_text);
+    collector.visit_program(&ret.program);
+    collector.add_imports(&ret.program.body);
+    collector.add_comments(&ret.trivias);
+    let mut ranges = collector.ranges;
+    ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    ranges.dedup_by_key(|range| (range.start_line, range.end_line));
+    Some(ranges)
+}
+
+struct SymbolCollector<'s> {
+    source_text: &'s str,
+    rope: Rope,
+    symbols: Vec<DocumentSymbol>,
+    /// Symbols whose node is being visited, with the node to close them on leaving it
+    open: Vec<(AstType, Span, DocumentSymbol)>,
+    /// Number of enclosing functions
+    function_depth: usize,
+}
+
+impl<'s> SymbolCollector<'s> {
+    fn new(source_text: &'s str) -> Self {
+        Self {
+            source_text,
+            rope: Rope::from_str(source_text),
+            symbols: vec![],
+            open: vec![],
+            function_depth: 0,
+        }
+    }
+
+    /// The name, kind, and the span of the name of the symbol declared by `kind`
+    fn declaration(&self, kind: AstKind) -> Option<(String, SymbolKind, Span)> {
+        let declaration = match kind {
+            AstKind::Function(func) if func.is_declaration() => match &func.id {
+                Some(id) => (id.name.to_string(), SymbolKind::FUNCTION, id.span),
+                // `export default function() {}`
+                None => ("default".into(), SymbolKind::FUNCTION, func.span),
+            },
+            AstKind::Class(class) if class.is_declaration() => match &class.id {
+                Some(id) => (id.name.to_string(), SymbolKind::CLASS, id.span),
+                None => ("default".into(), SymbolKind::CLASS, class.span),
+            },
+            AstKind::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::Expression(_) => {
+                    ("default".into(), SymbolKind::VARIABLE, decl.span)
+                }
+                _ => return None,
+            },
+            AstKind::VariableDeclarator(decl) => {
+                let BindingPatternKind::BindingIdentifier(id) = &decl.id.kind else {
+                    return None;
+                };
+                let kind = match decl.init.as_ref().map(Expression::without_parenthesized) {
+                    Some(
+                        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_),
+                    ) => SymbolKind::FUNCTION,
+                    Some(Expression::ClassExpression(_)) => SymbolKind::CLASS,
+                    _ if self.function_depth > 0 => return None,
+                    _ if decl.kind == VariableDeclarationKind::Const => SymbolKind::CONSTANT,
+                    _ => SymbolKind::VARIABLE,
+                };
+                (id.name.to_string(), kind, id.span)
+            }
+            AstKind::MethodDefinition(def) => {
+                let kind = match def.kind {
+                    MethodDefinitionKind::Constructor => SymbolKind::CONSTRUCTOR,
+                    MethodDefinitionKind::Get | MethodDefinitionKind::Set => SymbolKind::PROPERTY,
+                    MethodDefinitionKind::Method => SymbolKind::METHOD,
+                };
+                (self.property_key_name(&def.key), kind, def.key.span())
+            }
+            AstKind::PropertyDefinition(def) => {
+                (self.property_key_name(&def.key), SymbolKind::PROPERTY, def.key.span())
+            }
+            AstKind::TSInterfaceDeclaration(decl) => {
+                (decl.id.name.to_string(), SymbolKind::INTERFACE, decl.id.span)
+            }
+            // Only the members of interfaces and object types, not of every type annotation
+            AstKind::TSPropertySignature(sig) if self.in_type_declaration() => {
+                (self.property_key_name(&sig.key), SymbolKind::PROPERTY, sig.key.span())
+            }
+            AstKind::TSMethodSignature(sig) if self.in_type_declaration() => {
+                (self.property_key_name(&sig.key), SymbolKind::METHOD, sig.key.span())
+            }
+            AstKind::TSTypeAliasDeclaration(decl) => {
+                (decl.id.name.to_string(), SymbolKind::TYPE_PARAMETER, decl.id.span)
+            }
+            AstKind::TSEnumDeclaration(decl) => {
+                (decl.id.name.to_string(), SymbolKind::ENUM, decl.id.span)
+            }
+            AstKind::TSEnumMember(member) => {
+                let name = match &member.id {
+                    TSEnumMemberName::Identifier(ident) => ident.name.to_string(),
+                    TSEnumMemberName::StringLiteral(lit) => lit.value.to_string(),
+                    TSEnumMemberName::ComputedPropertyName(expr) => {
+                        expr.span().source_text(self.source_text).to_string()
+                    }
+                    TSEnumMemberName::NumericLiteral(lit) => lit.raw.to_string(),
+                };
+                (name, SymbolKind::ENUM_MEMBER, member.span)
+            }
+            AstKind::TSModuleDeclaration(decl) => {
+                let kind = match &decl.id {
+                    TSModuleDeclarationName::Identifier(_) => SymbolKind::NAMESPACE,
+                    TSModuleDeclarationName::StringLiteral(_) => SymbolKind::MODULE,
+                };
+                (decl.id.name().to_string(), kind, decl.id.span())
+            }
+            _ => return None,
+        };
+        Some(declaration)
+    }
+
+    fn in_type_declaration(&self) -> bool {
+        self.open.last().is_some_and(|(ty, _, _)| {
+            matches!(ty, AstType::TSInterfaceDeclaration | AstType::TSTypeAliasDeclaration)
+        })
+    }
+
+    fn property_key_name(&self, key: &PropertyKey) -> String {
+        match key {
+            PropertyKey::PrivateIdentifier(ident) => format!("#{}", ident.name),
+            _ => key.static_name().map_or_else(
+                || format!("[{}]", key.span().source_text(self.source_text)),
+                |name| name.to_string(),
+            ),
+        }
+    }
+
+    fn range(&self, span: Span) -> Range {
+        Range::new(
+            offset_to_position(&self.rope, span.start),
+            offset_to_position(&self.rope, span.end),
+        )
+    }
+}
+
+impl<'a, 's> Visit<'a> for SymbolCollector<'s> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if let Some((name, symbol_kind, selection_span)) = self.declaration(kind) {
+            let span = kind.span();
+            #[allow(deprecated)]
+            let symbol = DocumentSymbol {
+                name,
+                detail: None,
+                kind: symbol_kind,
+                tags: None,
+                deprecated: None,
+                range: self.range(span),
+                selection_range: self.range(selection_span),
+                children: None,
+            };
+            self.open.push((kind.ty(), span, symbol));
+        }
+        if matches!(kind, AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+            self.function_depth += 1;
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+            self.function_depth -= 1;
+        }
+        let is_open =
+            self.open.last().is_some_and(|(ty, span, _)| *ty == kind.ty() && *span == kind.span());
+        if !is_open {
+            return;
+        }
+        let Some((_, _, symbol)) = self.open.pop() else { return };
+        match self.open.last_mut() {
+            Some((_, _, parent)) => parent.children.get_or_insert_with(Vec::new).push(symbol),
+            None => self.symbols.push(symbol),
+        }
+    }
+}
+
+struct FoldingCollector {
+    rope: Rope,
+    ranges: Vec<FoldingRange>,
+}
+
+impl FoldingCollector {
+    fn new(source_text: &str) -> Self {
+        Self { rope: Rope::from_str(source_text), ranges: vec![] }
+    }
+
+    fn line(&self, offset: u32) -> u32 {
+        offset_to_position(&self.rope, offset).line
+    }
+
+    /// Folds the lines of a bracketed span, keeping the line of the closing bracket visible
+    fn add_block(&mut self, span: Span) {
+        let start_line = self.line(span.start);
+        let end_line = self.line(span.end).saturating_sub(1);
+        self.add(start_line, end_line, None);
+    }
+
+    fn add(&mut self, start_line: u32, end_line: u32, kind: Option<FoldingRangeKind>) {
+        if end_line <= start_line {
+            return;
+        }
+        self.ranges.push(FoldingRange {
+            start_line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind,
+            collapsed_text: None,
+        });
+    }
+
+    /// Consecutive import declarations fold into one range
+    fn add_imports(&mut self, statements: &[Statement]) {
+        let mut group: Option<Span> = None;
+        for stmt in statements {
+            let is_import = matches!(
+                stmt,
+                Statement::ModuleDeclaration(decl)
+                    if matches!(**decl, ModuleDeclaration::ImportDeclaration(_))
+            );
+            if is_import {
+                let span = stmt.span();
+                group = Some(group.map_or(span, |group| Span::new(group.start, span.end)));
+            } else if let Some(span) = group.take() {
+                self.add(
+                    self.line(span.start),
+                    self.line(span.end),
+                    Some(FoldingRangeKind::Imports),
+                );
+            }
+        }
+        if let Some(span) = group {
+            self.add(self.line(span.start), self.line(span.end), Some(FoldingRangeKind::Imports));
+        }
+    }
+
+    /// Multi line comments, consecutive line comments, and regions between
+    /// `// #region` and `// #endregion`
+    fn add_comments(&mut self, trivias: &Trivias) {
+        let mut regions = vec![];
+        let mut line_comments: Option<(u32, u32)> = None;
+        for &(start, end, kind) in &trivias.comments {
+            let (start_line, end_line) = (self.line(start), self.line(end));
+            if kind == CommentKind::MultiLine {
+                self.add(start_line, end_line, Some(FoldingRangeKind::Comment));
+                continue;
+            }
+            let text = self.rope.byte_slice(start as usize..end as usize).to_string();
+            let text = text.trim_start();
+            if text.starts_with("#region") {
+                regions.push(start_line);
+                continue;
+            }
+            if text.starts_with("#endregion") {
+                if let Some(region_start) = regions.pop() {
+                    self.add(region_start, end_line, Some(FoldingRangeKind::Region));
+                }
+                continue;
+            }
+            match line_comments {
+                Some((first, last)) if last + 1 == start_line => {
+                    line_comments = Some((first, start_line));
+                }
+                _ => {
+                    if let Some((first, last)) = line_comments {
+                        self.add(first, last, Some(FoldingRangeKind::Comment));
+                    }
+                    line_comments = Some((start_line, start_line));
+                }
+            }
+        }
+        if let Some((first, last)) = line_comments {
+            self.add(first, last, Some(FoldingRangeKind::Comment));
+        }
+    }
+}
+
+impl<'a> Visit<'a> for FoldingCollector {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = match kind {
+            AstKind::BlockStatement(block) | AstKind::FinallyClause(block) => block.span,
+            AstKind::FunctionBody(body) => body.span,
+            AstKind::ClassBody(body) => body.span,
+            AstKind::StaticBlock(block) => block.span,
+            AstKind::SwitchStatement(stmt) => stmt.span,
+            AstKind::ObjectExpression(expr) => expr.span,
+            AstKind::ArrayExpression(expr) => expr.span,
+            AstKind::ObjectPattern(pattern) => pattern.span,
+            AstKind::TemplateLiteral(lit) => lit.span,
+            AstKind::JSXElement(elem) => elem.span,
+            AstKind::JSXFragment(fragment) => fragment.span,
+            AstKind::TSModuleBlock(block) => block.span,
+            AstKind::TSInterfaceDeclaration(decl) => decl.body.span,
+            AstKind::TSTypeLiteral(lit) => lit.span,
+            AstKind::TSEnumDeclaration(decl) => decl.span,
+            _ => return,
+        };
+        self.add_block(span);
+    }
+}
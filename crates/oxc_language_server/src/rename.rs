@@ -0,0 +1,181 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_linter::rename::rename_symbol;
+use oxc_parser::Parser;
+use oxc_resolver::Resolver;
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{SourceType, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ExportLocalName, ImportImportName,
+};
+use ropey::Rope;
+use tower_lsp::lsp_types::{Position, Range, TextEdit};
+
+use crate::document::{offset_to_position, position_to_offset};
+
+pub struct Rename {
+    pub edits: Vec<TextEdit>,
+    pub old_name: String,
+    /// The symbol is exported under its own name by its declaration, e.g. `export function foo() {}`,
+    /// so the export is renamed as well and the importers of the file need to be updated.
+    pub export_renamed: bool,
+}
+
+/// The range of the identifier at `position`, when it refers to a declaration of the file.
+pub fn prepare_rename(path: &Path, source_text: &str, position: Position) -> Option<Range> {
+    let rope = Rope::from_str(source_text);
+    let offset = position_to_offset(&rope, position)?;
+    with_semantic(path, source_text, |semantic| {
+        let (_, span) = symbol_at(semantic, offset)?;
+        Some(Range::new(offset_to_position(&rope, span.start), offset_to_position(&rope, span.end)))
+    })?
+}
+
+/// Renames the declaration at `position` and all of its references in the file.
+///
+/// Errors when `new_name` is not a valid name, or would conflict with another declaration.
+/// See [`rename_symbol`].
+pub fn rename(
+    path: &Path,
+    source_text: &str,
+    position: Position,
+    new_name: &str,
+) -> Result<Option<Rename>, String> {
+    let rope = Rope::from_str(source_text);
+    let Some(offset) = position_to_offset(&rope, position) else { return Ok(None) };
+    let renamed = with_semantic(path, source_text, |semantic| {
+        let Some((symbol_id, _)) = symbol_at(semantic, offset) else { return Ok(None) };
+        let fixes = rename_symbol(semantic, symbol_id, new_name).map_err(|e| e.to_string())?;
+
+        let symbols = semantic.symbols();
+        let old_name = symbols.get_name(symbol_id).to_string();
+        let symbol_span = symbols.get_span(symbol_id);
+        let export_renamed = symbols.get_scope_id(symbol_id) == semantic.scopes().root_scope_id()
+            && semantic.module_record().local_export_entries.iter().any(|entry| {
+                let (ExportLocalName::Name(local), ExportExportName::Name(exported)) =
+                    (&entry.local_name, &entry.export_name)
+                else {
+                    return false;
+                };
+                local.span() == symbol_span && exported.name().as_str() == old_name
+            });
+
+        let edits = fixes.into_iter().map(|fix| (fix.span, fix.content.into_owned())).collect();
+        Ok(Some(Rename { edits: text_edits(&rope, edits), old_name, export_renamed }))
+    });
+    renamed.unwrap_or(Ok(None))
+}
+
+/// The edits of the file at `path` for the export `old_name` of `export_path` being renamed
+/// to `new_name`.
+/// Imports and re-exports keep their local or export name.
+pub fn importer_edits(
+    resolver: &Resolver,
+    path: &Path,
+    source_text: &str,
+    export_path: &Path,
+    old_name: &str,
+    new_name: &str,
+) -> Vec<TextEdit> {
+    let (Ok(source_type), Some(dir)) = (SourceType::from_path(path), path.parent()) else {
+        return vec![];
+    };
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let module_record = SemanticBuilder::new(source_text, source_type)
+        .build_module_record(path.to_path_buf(), &program)
+        .module_record();
+    let resolves_to_export_path = |specifier: &str| {
+        resolver.resolve(dir, specifier).is_ok_and(|resolution| resolution.path() == export_path)
+    };
+
+    let mut edits = vec![];
+    for entry in &module_record.import_entries {
+        let ImportImportName::Name(imported) = &entry.import_name else { continue };
+        if imported.name().as_str() != old_name
+            || !resolves_to_export_path(entry.module_request.name())
+        {
+            continue;
+        }
+        let text = if entry.local_name.span() == imported.span() {
+            format!("{new_name} as {old_name}")
+        } else {
+            new_name.to_string()
+        };
+        edits.push((imported.span(), text));
+    }
+    for entry in &module_record.indirect_export_entries {
+        let (Some(request), ExportImportName::Name(imported)) =
+            (&entry.module_request, &entry.import_name)
+        else {
+            continue;
+        };
+        if imported.name().as_str() != old_name || !resolves_to_export_path(request.name()) {
+            continue;
+        }
+        let text = match &entry.export_name {
+            ExportExportName::Name(exported) if exported.span() == imported.span() => {
+                format!("{new_name} as {old_name}")
+            }
+            _ => new_name.to_string(),
+        };
+        edits.push((imported.span(), text));
+    }
+    text_edits(&Rope::from_str(source_text), edits)
+}
+
+fn with_semantic<T>(path: &Path, source_text: &str, f: impl FnOnce(&Semantic) -> T) -> Option<T> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if ret.panicked {
+        return None;
+    }
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source_text, source_type)
+        .build_module_record(path.to_path_buf(), program)
+        .build(program)
+        .semantic;
+    Some(f(&semantic))
+}
+
+/// The declared symbol at `offset`, and the span of the identifier referring to it there
+fn symbol_at(semantic: &Semantic, offset: u32) -> Option<(SymbolId, Span)> {
+    let symbols = semantic.symbols();
+    let contains = |span: Span| span.start <= offset && offset <= span.end;
+    if let Some((symbol_id, span)) =
+        symbols.spans.iter_enumerated().find(|(_, span)| contains(**span))
+    {
+        return Some((symbol_id, *span));
+    }
+    if let Some(reference) = symbols.references.iter().find(|reference| contains(reference.span()))
+    {
+        // Globals are not declared in the file
+        return reference.symbol_id().map(|symbol_id| (symbol_id, reference.span()));
+    }
+    // The local name of `export { foo }` is not a reference
+    semantic.module_record().local_export_entries.iter().find_map(|entry| {
+        let ExportLocalName::Name(local) = &entry.local_name else { return None };
+        if !contains(local.span()) {
+            return None;
+        }
+        let symbol_id = semantic.scopes().get_root_binding(local.name())?;
+        Some((symbol_id, local.span()))
+    })
+}
+
+fn text_edits(rope: &Rope, mut edits: Vec<(Span, String)>) -> Vec<TextEdit> {
+    edits.sort_by_key(|(span, _)| span.start);
+    edits.dedup_by_key(|(span, _)| *span);
+    edits
+        .into_iter()
+        .map(|(span, text)| {
+            let range = Range::new(
+                offset_to_position(rope, span.start),
+                offset_to_position(rope, span.end),
+            );
+            TextEdit::new(range, text)
+        })
+        .collect()
+}
@@ -0,0 +1,160 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolFlags, SymbolId, SymbolTable};
+use oxc_span::{SourceType, Span};
+use ropey::Rope;
+use tower_lsp::lsp_types::{
+    Range, SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokensLegend,
+};
+
+use crate::document::{offset_to_position, position_to_offset};
+
+const TOKEN_TYPES: [SemanticTokenType; 10] = [
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::TYPE_PARAMETER,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+];
+
+/// Bits of the modifiers are their indexes in [TOKEN_MODIFIERS]
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 3] = [
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    // Not a standard modifier, bindings of `import` declarations
+    SemanticTokenModifier::new("import"),
+];
+
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+const IMPORT: u32 = 1 << 2;
+
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Tokens of the identifiers which resolve to a declaration of the file, classified by the
+/// kind of the declared symbol. Only the ones in `range` are returned when it is set.
+pub fn semantic_tokens(
+    path: &Path,
+    source_text: &str,
+    range: Option<Range>,
+) -> Option<Vec<SemanticToken>> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if ret.panicked {
+        return None;
+    }
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+    let symbols = semantic.symbols();
+
+    let mut tokens = vec![];
+    for symbol_id in symbols.iter() {
+        let bits = modifiers(symbols.get_flag(symbol_id));
+        let ty = token_type(&semantic, symbol_id, false);
+        tokens.push((name_span(source_text, symbols, symbol_id), ty, bits | DECLARATION));
+        for reference in symbols.get_resolved_references(symbol_id) {
+            let ty = token_type(&semantic, symbol_id, reference.is_type());
+            tokens.push((reference.span(), ty, bits));
+        }
+    }
+    tokens.sort_unstable_by_key(|(span, _, _)| span.start);
+    tokens.dedup_by_key(|(span, _, _)| *span);
+
+    let rope = Rope::from_str(source_text);
+    let range = range.and_then(|range| {
+        Some(Span::new(
+            position_to_offset(&rope, range.start)?,
+            position_to_offset(&rope, range.end)?,
+        ))
+    });
+    let mut previous = (0, 0);
+    let encoded = tokens
+        .into_iter()
+        .filter(|(span, _, _)| {
+            range.map_or(true, |range| range.start <= span.start && span.end <= range.end)
+        })
+        .map(|(span, token_type, modifiers)| {
+            let start = offset_to_position(&rope, span.start);
+            let end = offset_to_position(&rope, span.end);
+            let delta_line = start.line - previous.0;
+            let delta_start =
+                if delta_line == 0 { start.character - previous.1 } else { start.character };
+            previous = (start.line, start.character);
+            SemanticToken {
+                delta_line,
+                delta_start,
+                length: end.character.saturating_sub(start.character),
+                token_type,
+                token_modifiers_bitset: modifiers,
+            }
+        })
+        .collect();
+    Some(encoded)
+}
+
+/// Some symbols, e.g. of namespaces, span their whole declaration
+fn name_span(source_text: &str, symbols: &SymbolTable, symbol_id: SymbolId) -> Span {
+    let span = symbols.get_span(symbol_id);
+    let name = symbols.get_name(symbol_id);
+    let text = span.source_text(source_text);
+    if text == name {
+        return span;
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    text.find(name).map_or(span, |start| {
+        let start = span.start + start as u32;
+        Span::new(start, start + name.len() as u32)
+    })
+}
+
+fn token_type(semantic: &Semantic, symbol_id: SymbolId, is_type_reference: bool) -> u32 {
+    let symbols = semantic.symbols();
+    let flags = symbols.get_flag(symbol_id);
+    let token_type = match semantic.nodes().kind(symbols.get_declaration(symbol_id)) {
+        AstKind::ImportNamespaceSpecifier(_) => SemanticTokenType::NAMESPACE,
+        // The declaration is in another file, so imports are classified by how they are used
+        AstKind::ImportSpecifier(_) | AstKind::ImportDefaultSpecifier(_) if is_type_reference => {
+            SemanticTokenType::TYPE
+        }
+        AstKind::FormalParameter(_) => SemanticTokenType::PARAMETER,
+        AstKind::Function(_) => SemanticTokenType::FUNCTION,
+        _ if flags.contains(SymbolFlags::Class) => SemanticTokenType::CLASS,
+        _ if flags.intersects(SymbolFlags::Enum) => SemanticTokenType::ENUM,
+        _ if flags.contains(SymbolFlags::Interface) => SemanticTokenType::INTERFACE,
+        _ if flags.contains(SymbolFlags::TypeAlias) => SemanticTokenType::TYPE,
+        _ if flags.contains(SymbolFlags::TypeParameter) => SemanticTokenType::TYPE_PARAMETER,
+        _ if flags.contains(SymbolFlags::EnumMember) => SemanticTokenType::ENUM_MEMBER,
+        _ if flags.intersects(SymbolFlags::NameSpaceModule | SymbolFlags::ValueModule) => {
+            SemanticTokenType::NAMESPACE
+        }
+        _ => SemanticTokenType::VARIABLE,
+    };
+    #[allow(clippy::cast_possible_truncation)]
+    let index = TOKEN_TYPES.iter().position(|ty| *ty == token_type).unwrap_or_default() as u32;
+    index
+}
+
+fn modifiers(flags: SymbolFlags) -> u32 {
+    let mut modifiers = 0;
+    if flags.contains(SymbolFlags::ConstVariable) {
+        modifiers |= READONLY;
+    }
+    if flags.contains(SymbolFlags::ImportBinding) {
+        modifiers |= IMPORT;
+    }
+    modifiers
+}
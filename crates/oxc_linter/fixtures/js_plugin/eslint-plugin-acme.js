@@ -0,0 +1,22 @@
+module.exports = {
+  rules: {
+    'no-foo': {
+      meta: {
+        type: 'problem',
+        docs: { description: 'Disallow an identifier' },
+        messages: { unexpected: 'Unexpected {{name}} in {{statement}}.' },
+      },
+      create(context) {
+        const name = context.options[0];
+        return {
+          Identifier(node) {
+            if (node.name === name) {
+              const statement = context.sourceCode.getText(node.parent.parent);
+              context.report({ node, messageId: 'unexpected', data: { name, statement } });
+            }
+          },
+        };
+      },
+    },
+  },
+};
@@ -0,0 +1,64 @@
+use oxc_linter::{
+    native_plugin::{NativeContext, NativeRule},
+    rule::RuleCategory,
+};
+use oxc_span::Span;
+use serde_json::Value;
+
+/// Reports the identifiers named as the option, `foo` by default
+struct NoFoo;
+
+impl NativeRule for NoFoo {
+    fn name(&self) -> &'static str {
+        "no-foo"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Restriction
+    }
+
+    fn documentation(&self) -> Option<&'static str> {
+        Some("Disallow an identifier")
+    }
+
+    fn run(&self, ctx: &NativeContext<'_>) {
+        let name = ctx.options()[0].as_str().unwrap_or("foo");
+        visit(ctx.ast(), &mut |node| {
+            if node["type"] == "Identifier" && node["name"] == name {
+                let offset = |key: &str| node[key].as_u64().and_then(|n| u32::try_from(n).ok());
+                let span = Span::new(offset("start").unwrap_or(0), offset("end").unwrap_or(0));
+                ctx.report_with_help(format!("Unexpected {name}."), "Rename it", span);
+            }
+        });
+    }
+}
+
+/// Always panics, which is reported instead of unwinding into the linter
+struct Panics;
+
+impl NativeRule for Panics {
+    fn name(&self) -> &'static str {
+        "panics"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Restriction
+    }
+
+    fn run(&self, _ctx: &NativeContext<'_>) {
+        panic!("panics");
+    }
+}
+
+fn visit(value: &Value, f: &mut dyn FnMut(&Value)) {
+    match value {
+        Value::Object(object) => {
+            f(value);
+            object.values().for_each(|value| visit(value, f));
+        }
+        Value::Array(values) => values.iter().for_each(|value| visit(value, f)),
+        _ => {}
+    }
+}
+
+oxc_linter::declare_plugin!("acme", [NoFoo, Panics]);
@@ -1,10 +1,14 @@
 use std::hash::{Hash, Hasher};
 
-use oxc_ast::AstKind;
-use oxc_semantic::AstNode;
+use oxc_ast::{AstKind, Comment};
+use oxc_semantic::{AstNode, AstNodeId};
 use oxc_span::{GetSpan, Span};
-use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
-use rustc_hash::FxHasher;
+use oxc_syntax::{
+    identifier::is_line_terminator,
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    precedence::{GetPrecedence, Precedence},
+};
+use rustc_hash::{FxHashMap, FxHasher};
 
 pub fn calculate_hash<T: Hash>(t: &T) -> u64 {
     let mut hasher = FxHasher::default();
@@ -405,3 +409,256 @@ pub fn is_global_require_call(call_expr: &CallExpression, ctx: &LintContext) ->
         false
     }
 }
+
+/// The name of the global `parseInt` function called by `call_expr`, either `parseInt`
+/// or `Number.parseInt`.
+pub fn get_global_parse_int_name(
+    call_expr: &CallExpression,
+    ctx: &LintContext,
+) -> Option<&'static str> {
+    match call_expr.callee.without_parenthesized() {
+        Expression::Identifier(ident) if ident.name == "parseInt" => {
+            is_global_reference(ident, ctx).then_some("parseInt")
+        }
+        Expression::MemberExpression(member)
+            if member.static_property_name() == Some("parseInt") =>
+        {
+            match member.object().without_parenthesized() {
+                Expression::Identifier(ident) if ident.name == "Number" => {
+                    is_global_reference(ident, ctx).then_some("Number.parseInt")
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether `call_expr` calls `object.method` of a global object, like `Math.pow`, rather than
+/// a method of a variable shadowing it
+pub fn is_global_method_call(
+    call_expr: &CallExpression,
+    object: &str,
+    method: &str,
+    ctx: &LintContext,
+) -> bool {
+    let Expression::MemberExpression(member) = call_expr.callee.without_parenthesized() else {
+        return false;
+    };
+    if member.static_property_name() != Some(method) {
+        return false;
+    }
+    match member.object().without_parenthesized() {
+        Expression::Identifier(ident) => ident.name == object && is_global_reference(ident, ctx),
+        _ => false,
+    }
+}
+
+/// The precedence of an expression, `Precedence::Grouping` for primary expressions
+pub fn get_precedence(expr: &Expression) -> Precedence {
+    match expr {
+        Expression::SequenceExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ConditionalExpression(_)
+        | Expression::LogicalExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::UnaryExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::AwaitExpression(_) => expr.precedence(),
+        Expression::TSAsExpression(_) | Expression::TSSatisfiesExpression(_) => {
+            Precedence::Relational
+        }
+        _ => Precedence::Grouping,
+    }
+}
+
+/// The source text of `expr`, wrapped in parentheses if it binds looser than `precedence`
+pub fn get_parenthesized_text(
+    expr: &Expression,
+    precedence: Precedence,
+    ctx: &LintContext,
+) -> String {
+    let text = expr.span().source_text(ctx.source_text());
+    if get_precedence(expr) < precedence {
+        format!("({text})")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Describe a `Function` or `ArrowFunctionExpression` node like ESLint's `getFunctionNameWithKind`,
+/// capitalized for starting a diagnostic message, e.g. `Function 'foo'`, `Async arrow function`
+/// or `Static method 'bar'`.
+pub fn get_function_name_with_kind<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> String {
+    let description = function_name_with_kind(node, ctx);
+    let mut chars = description.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+/// Like `get_function_name_with_kind`, but not capitalized, e.g. `method 'foo'`.
+pub fn function_name_with_kind<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> String {
+    let parent = ctx.nodes().parent_kind(node.id());
+    let mut tokens = vec![];
+
+    let class_member = match parent {
+        Some(AstKind::MethodDefinition(def)) => Some((def.r#static, &def.key)),
+        Some(AstKind::PropertyDefinition(def)) => Some((def.r#static, &def.key)),
+        _ => None,
+    };
+    if let Some((is_static, key)) = class_member {
+        if is_static {
+            tokens.push("static".to_string());
+        }
+        if matches!(key, PropertyKey::PrivateIdentifier(_)) {
+            tokens.push("private".to_string());
+        }
+    }
+
+    let (is_async, is_generator) = match node.kind() {
+        AstKind::Function(func) => (func.r#async, func.generator),
+        AstKind::ArrowFunctionExpression(arrow) => (arrow.r#async, false),
+        _ => (false, false),
+    };
+    if is_async {
+        tokens.push("async".to_string());
+    }
+    if is_generator {
+        tokens.push("generator".to_string());
+    }
+
+    let (kind, key) = match (node.kind(), parent) {
+        (_, Some(AstKind::PropertyDefinition(def))) => ("method", Some(&def.key)),
+        (AstKind::ArrowFunctionExpression(_), _) => ("arrow function", None),
+        (_, Some(AstKind::MethodDefinition(def))) => match def.kind {
+            MethodDefinitionKind::Constructor => ("constructor", None),
+            MethodDefinitionKind::Method => ("method", Some(&def.key)),
+            MethodDefinitionKind::Get => ("getter", Some(&def.key)),
+            MethodDefinitionKind::Set => ("setter", Some(&def.key)),
+        },
+        (_, Some(AstKind::ObjectProperty(prop))) => match prop.kind {
+            PropertyKind::Get => ("getter", Some(&prop.key)),
+            PropertyKind::Set => ("setter", Some(&prop.key)),
+            PropertyKind::Init => ("method", Some(&prop.key)),
+        },
+        _ => ("function", None),
+    };
+    tokens.push(kind.to_string());
+
+    match key {
+        Some(PropertyKey::PrivateIdentifier(ident)) => tokens.push(format!("#{}", ident.name)),
+        Some(key) => {
+            if let Some(name) = key.static_name() {
+                tokens.push(format!("'{name}'"));
+            }
+        }
+        None => {
+            if let AstKind::Function(Function { id: Some(id), .. }) = node.kind() {
+                tokens.push(format!("'{}'", id.name));
+            }
+        }
+    }
+
+    tokens.join(" ")
+}
+
+/// The span of a `Function` or `ArrowFunctionExpression` node up to the end of its parameters,
+/// starting at the method or accessor it is the value of, e.g. `function foo(a, b)` or `get bar()`.
+/// For reporting a whole function on a single line.
+pub fn get_function_head_span(node: &AstNode, ctx: &LintContext) -> Span {
+    let (span, params) = match node.kind() {
+        AstKind::Function(func) => (func.span, &func.params),
+        AstKind::ArrowFunctionExpression(arrow) => (arrow.span, &arrow.params),
+        kind => return kind.span(),
+    };
+    let start = match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::MethodDefinition(def)) => def.span.start,
+        Some(AstKind::ObjectProperty(prop)) if prop.method || prop.kind != PropertyKind::Init => {
+            prop.span.start
+        }
+        _ => span.start,
+    };
+    Span::new(start, params.span.end)
+}
+
+/// The span of a comment including its `//` or `/* */`, from the span of its content
+/// as stored in the trivias.
+pub fn get_comment_span(content: Span, comment: Comment) -> Span {
+    let end = if comment.is_multi_line() { content.end + 2 } else { content.end };
+    Span::new(content.start - 2, end)
+}
+
+/// Whether the content of a comment is a directive for the linter,
+/// e.g. `eslint-disable-next-line` or `global foo`.
+pub fn is_directive_comment(comment: Comment, content: &str) -> bool {
+    let content = content.trim_start();
+    content.starts_with("eslint-")
+        || content.starts_with("oxlint-")
+        || (comment.is_multi_line()
+            && (content.starts_with("eslint ") || content.starts_with("global ")))
+}
+
+/// The source text on the line of `span` before it, and on the line of its end after it,
+/// e.g. `foo();` and an empty string for the comment of `foo(); // bar`.
+pub fn get_line_text_around(span: Span, source_text: &str) -> (&str, &str) {
+    let before = &source_text[..span.start as usize];
+    let after = &source_text[span.end as usize..];
+    (
+        before.rsplit(is_line_terminator).next().unwrap_or(before),
+        after.split(is_line_terminator).next().unwrap_or(after),
+    )
+}
+
+/// The keywords of a function which refer to the function itself, and which arrow functions
+/// take from their enclosing function instead
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FunctionContextUsage {
+    pub uses_this: bool,
+    pub uses_super: bool,
+    pub uses_arguments: bool,
+    pub uses_new_target: bool,
+}
+
+/// The usage of `this`, `super`, `arguments` and `new.target` by the functions which use any of
+/// them, including in nested arrow functions, keyed by the id of the `Function` node.
+pub fn get_function_context_usages(
+    ctx: &LintContext,
+) -> FxHashMap<AstNodeId, FunctionContextUsage> {
+    let mut usages = FxHashMap::<AstNodeId, FunctionContextUsage>::default();
+    for node in ctx.nodes().iter() {
+        let mark: fn(&mut FunctionContextUsage) = match node.kind() {
+            AstKind::ThisExpression(_) => |usage| usage.uses_this = true,
+            AstKind::Super(_) => |usage| usage.uses_super = true,
+            AstKind::IdentifierReference(ident)
+                if ident.name == "arguments" && is_global_reference(ident, ctx) =>
+            {
+                |usage| usage.uses_arguments = true
+            }
+            AstKind::MetaProperty(meta)
+                if meta.meta.name == "new" && meta.property.name == "target" =>
+            {
+                |usage| usage.uses_new_target = true
+            }
+            _ => continue,
+        };
+        // Class fields and static blocks have their own `this`, unlike computed keys
+        let mut in_key = false;
+        for id in ctx.nodes().ancestors(node.id()).skip(1) {
+            match ctx.nodes().kind(id) {
+                AstKind::PropertyKey(_) => in_key = true,
+                AstKind::Function(_) => {
+                    mark(usages.entry(id).or_default());
+                    break;
+                }
+                AstKind::StaticBlock(_) => break,
+                AstKind::PropertyDefinition(_) if !in_key => break,
+                AstKind::PropertyDefinition(_)
+                | AstKind::MethodDefinition(_)
+                | AstKind::ObjectProperty(_) => in_key = false,
+                _ => {}
+            }
+        }
+    }
+    usages
+}
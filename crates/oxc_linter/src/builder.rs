@@ -0,0 +1,93 @@
+//! Building a [`Linter`] from Rust code, for tools embedding the linter such as bundlers and dev
+//! servers, without going through the command line:
+//!
+//! ```ignore
+//! let linter = Linter::builder()
+//!     .with_config(serde_json::json!({ "rules": { "no-debugger": "error" } }))
+//!     .build()?;
+//! for message in linter.lint_source(Path::new("index.js"), "debugger;") {
+//!     println!("{:?}", message.error);
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use oxc_diagnostics::Report;
+
+use crate::{LintOptions, Linter, RuleEnum, RulePlugin, TypeInfo};
+
+/// The options of a [`Linter`], see [`Linter::builder`].
+#[derive(Debug, Default)]
+pub struct LinterBuilder {
+    options: LintOptions,
+    /// The content of a configuration file, instead of `LintOptions::config_path`
+    config: Option<serde_json::Value>,
+    /// The rules to run instead of the rules enabled by the options and the configuration
+    rules: Option<Vec<RuleEnum>>,
+    plugins: Vec<Arc<dyn RulePlugin>>,
+    type_info: Option<Arc<dyn TypeInfo>>,
+}
+
+impl LinterBuilder {
+    /// The options of the command line, e.g. the enabled plugins and the `-A` and `-D` filters.
+    #[must_use]
+    pub fn with_options(mut self, options: LintOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// The content of an `.eslintrc.json` file, e.g. `{ "rules": { "no-debugger": "error" } }`.
+    #[must_use]
+    pub fn with_config(mut self, config: serde_json::Value) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Run exactly `rules`, ignoring the rules enabled by the options and the configuration.
+    #[must_use]
+    pub fn with_rules(mut self, rules: Vec<RuleEnum>) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    /// See `Linter::with_plugin`, the rules of `plugin` are enabled by the configuration.
+    #[must_use]
+    pub fn with_plugin(mut self, plugin: Arc<dyn RulePlugin>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// See `Linter::with_type_info`.
+    #[must_use]
+    pub fn with_type_info(mut self, type_info: Arc<dyn TypeInfo>) -> Self {
+        self.type_info = Some(type_info);
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix(mut self, yes: bool) -> Self {
+        self.options.fix = yes;
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Err` if there are any errors loading the configuration, the type information or
+    /// the plugin libraries listed in the configuration.
+    pub fn build(self) -> Result<Linter, Report> {
+        let mut linter = Linter::from_options(self.options)?;
+        for plugin in self.plugins {
+            linter = linter.with_plugin(plugin);
+        }
+        if let Some(config) = self.config {
+            linter = linter.with_config_json(config)?;
+        }
+        if let Some(rules) = self.rules {
+            linter = linter.with_rules(rules);
+        }
+        if let Some(type_info) = self.type_info {
+            linter = linter.with_type_info(type_info);
+        }
+        Ok(linter)
+    }
+}
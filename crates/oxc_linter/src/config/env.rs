@@ -1,12 +1,12 @@
 use rustc_hash::FxHashMap;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Environment
 /// https://eslint.org/docs/latest/use/configure/language-options#using-configuration-files
 ///
 /// TS type is `Record<string, boolean>`
 /// https://github.com/eslint/eslint/blob/ce838adc3b673e52a151f36da0eedf5876977514/lib/shared/types.js#L40
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ESLintEnv(FxHashMap<String, bool>);
 
 impl ESLintEnv {
@@ -1,6 +1,6 @@
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
-    thiserror::Error,
+    thiserror::{self, Error},
     Report,
 };
 use std::path::PathBuf;
@@ -41,6 +41,26 @@ pub struct FailedToParseAllowWarnDenyFromNumberError(pub String);
 pub struct FailedToParseAllowWarnDenyFromJsonValueError(pub String);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("Failed to parse jsonc file {0:?}")]
+#[error("Failed to resolve {0:?} in the `extends` of config {1:?}")]
+#[diagnostic(help("`extends` supports relative paths to json configuration files and the bundled `oxc:recommended`, `eslint:recommended` and `typescript:strict` configurations"))]
+pub struct FailedToResolveExtendsError(pub String, pub PathBuf);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Config {0:?} extends itself")]
+#[diagnostic()]
+pub struct CircularExtendsError(pub PathBuf);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Rule {0:?} of the configuration is not supported: {1}")]
+#[diagnostic(severity(warning))]
+pub struct UnsupportedRuleError(pub String, pub &'static str);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Rule {0:?} of the configuration is also configured as {1:?} with a different value")]
+#[diagnostic(severity(warning), help("Configure the rule once, the most severe value is used"))]
+pub struct ConflictingRuleError(pub String, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid configuration of rule {0:?}: {1}")]
 #[diagnostic()]
-pub struct FailedToParseJsonc(pub PathBuf);
+pub struct InvalidRuleConfigError(pub String, pub String);
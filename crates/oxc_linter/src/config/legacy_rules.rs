@@ -0,0 +1,171 @@
+//! Rules which were deprecated, renamed or removed by ESLint and its plugins.
+//!
+//! Old configuration files still enable them by their former names. The renamed rules are replaced
+//! by the rules of oxlint implementing them, and the others are reported as not supported instead
+//! of being ignored like unknown rules.
+
+use serde_json::Value;
+
+use self::LegacyRule::{NotSupported, Renamed};
+use super::{errors::UnsupportedRuleError, rules::parse_rule_value};
+
+#[derive(Debug, Clone, Copy)]
+pub enum LegacyRule {
+    /// Replaced by the rule with this key.
+    /// The options are dropped unless the replacement accepts the same ones.
+    Renamed { to: &'static str, keep_options: bool },
+    /// Why the rule is not supported
+    NotSupported(&'static str),
+}
+
+const fn renamed(to: &'static str) -> LegacyRule {
+    Renamed { to, keep_options: true }
+}
+
+const fn replaced(to: &'static str) -> LegacyRule {
+    Renamed { to, keep_options: false }
+}
+
+const REMOVED: LegacyRule = NotSupported("it was removed without a replacement");
+const FORMATTING: LegacyRule = NotSupported("formatting is left to formatters");
+
+/// Keyed by the rule names of ESLint configurations
+const LEGACY_RULES: &[(&str, LegacyRule)] = &[
+    ("no-arrow-condition", replaced("no-constant-condition")),
+    ("no-catch-shadow", replaced("no-shadow")),
+    ("no-comma-dangle", FORMATTING),
+    ("no-empty-class", renamed("no-empty-character-class")),
+    ("no-empty-label", NotSupported("it was replaced by `no-labels`, which is not supported yet")),
+    ("no-extra-strict", NotSupported("it was replaced by `strict`, which is not supported yet")),
+    ("global-strict", NotSupported("it was replaced by `strict`, which is not supported yet")),
+    ("no-native-reassign", renamed("no-global-assign")),
+    ("no-negated-in-lhs", renamed("no-unsafe-negation")),
+    ("no-process-exit", replaced("unicorn/no-process-exit")),
+    ("no-spaced-func", FORMATTING),
+    ("prefer-reflect", REMOVED),
+    ("require-jsdoc", NotSupported("it was removed in favor of eslint-plugin-jsdoc")),
+    ("valid-jsdoc", NotSupported("it was removed in favor of eslint-plugin-jsdoc")),
+    ("@typescript-eslint/ban-ts-ignore", replaced("@typescript-eslint/ban-ts-comment")),
+    ("@typescript-eslint/camelcase", replaced("@typescript-eslint/naming-convention")),
+    ("@typescript-eslint/class-name-casing", replaced("@typescript-eslint/naming-convention")),
+    ("@typescript-eslint/generic-type-naming", replaced("@typescript-eslint/naming-convention")),
+    ("@typescript-eslint/interface-name-prefix", replaced("@typescript-eslint/naming-convention")),
+    ("@typescript-eslint/member-naming", replaced("@typescript-eslint/naming-convention")),
+    ("@typescript-eslint/no-duplicate-imports", replaced("import/no-duplicates")),
+    (
+        "@typescript-eslint/no-parameter-properties",
+        NotSupported(
+            "it was replaced by `@typescript-eslint/parameter-properties`, which is not supported yet",
+        ),
+    ),
+    ("@typescript-eslint/no-type-alias", REMOVED),
+    (
+        "@typescript-eslint/no-untyped-public-signature",
+        NotSupported(
+            "it was replaced by `@typescript-eslint/explicit-module-boundary-types`, which is not supported yet",
+        ),
+    ),
+    (
+        "import/imports-first",
+        NotSupported("it was renamed to `import/first`, which is not supported yet"),
+    ),
+    (
+        "jest/lowercase-name",
+        NotSupported("it was renamed to `jest/prefer-lowercase-title`, which is not supported yet"),
+    ),
+    ("jest/no-expect-resolves", REMOVED),
+    (
+        "jest/no-if",
+        NotSupported("it was replaced by `jest/no-conditional-in-test`, which is not supported yet"),
+    ),
+    ("jest/no-truthy-falsy", REMOVED),
+    ("jest/prefer-inline-snapshots", REMOVED),
+    (
+        "jest/prefer-to-be-null",
+        NotSupported("it was replaced by `jest/prefer-to-be`, which is not supported yet"),
+    ),
+    (
+        "jest/prefer-to-be-undefined",
+        NotSupported("it was replaced by `jest/prefer-to-be`, which is not supported yet"),
+    ),
+    ("jest/valid-describe", replaced("jest/valid-describe-callback")),
+    ("jsx-a11y/accessible-emoji", REMOVED),
+    ("jsx-a11y/no-onchange", REMOVED),
+    ("react/jsx-space-before-closing", FORMATTING),
+    ("unicorn/no-array-instanceof", renamed("unicorn/no-instanceof-array")),
+    ("unicorn/no-reduce", renamed("unicorn/no-array-reduce")),
+    ("unicorn/no-unsafe-regex", REMOVED),
+    ("unicorn/prefer-dataset", renamed("unicorn/prefer-dom-node-dataset")),
+    ("unicorn/prefer-flat-map", renamed("unicorn/prefer-array-flat-map")),
+    ("unicorn/prefer-node-append", renamed("unicorn/prefer-dom-node-append")),
+    ("unicorn/prefer-node-remove", renamed("unicorn/prefer-dom-node-remove")),
+    ("unicorn/prefer-replace-all", renamed("unicorn/prefer-string-replace-all")),
+    ("unicorn/prefer-starts-ends-with", renamed("unicorn/prefer-string-starts-ends-with")),
+    ("unicorn/prefer-text-content", renamed("unicorn/prefer-dom-node-text-content")),
+    ("unicorn/prefer-trim-start-end", renamed("unicorn/prefer-string-trim-start-end")),
+];
+
+pub fn find(key: &str) -> Option<LegacyRule> {
+    LEGACY_RULES.iter().find(|(name, _)| *name == key).map(|(_, rule)| *rule)
+}
+
+/// Replaces the legacy rules of a `rules` object by the rules replacing them, and removes the ones
+/// which are not supported, returning the enabled ones as warnings.
+/// A replacement configured in the same object takes precedence over the legacy rule.
+pub fn migrate(rules: &mut serde_json::Map<String, Value>) -> Vec<UnsupportedRuleError> {
+    if !rules.keys().any(|key| find(key).is_some()) {
+        return vec![];
+    }
+    let mut warnings = vec![];
+    let mut migrated = serde_json::Map::with_capacity(rules.len());
+    for (key, value) in std::mem::take(rules) {
+        match find(&key) {
+            None => {
+                migrated.insert(key, value);
+            }
+            // The replacement is configured already. When it is configured later instead,
+            // it overwrites the value inserted here.
+            Some(Renamed { to, .. }) if migrated.contains_key(to) => {}
+            Some(Renamed { to, keep_options }) => {
+                let value = match value {
+                    Value::Array(mut values) if !keep_options => {
+                        values.truncate(1);
+                        Value::Array(values)
+                    }
+                    value => value,
+                };
+                migrated.insert(to.to_string(), value);
+            }
+            Some(NotSupported(reason)) => {
+                let enabled =
+                    parse_rule_value(&value).map_or(true, |(severity, _)| severity.is_warn_deny());
+                if enabled {
+                    warnings.push(UnsupportedRuleError(key, reason));
+                }
+            }
+        }
+    }
+    *rules = migrated;
+    warnings
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LegacyRule, LEGACY_RULES};
+    use crate::{config::rules::parse_rule_key, rules::RULES};
+
+    fn exists(key: &str) -> bool {
+        let (plugin_name, rule_name) = parse_rule_key(key);
+        RULES.iter().any(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+    }
+
+    #[test]
+    fn legacy_rules() {
+        for (key, rule) in LEGACY_RULES {
+            assert!(!exists(key), "{key} is implemented, it should not be replaced");
+            if let LegacyRule::Renamed { to, .. } = rule {
+                assert!(exists(to), "{key} is replaced by {to}, which is not implemented");
+            }
+        }
+    }
+}
@@ -1,21 +1,35 @@
 mod env;
 pub mod errors;
+mod legacy_rules;
+mod overrides;
+mod presets;
 mod rules;
 mod settings;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use oxc_diagnostics::{Error, FailedToOpenFileError, Report};
-use rustc_hash::FxHashSet;
+use oxc_span::VALID_EXTENSIONS;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Deserialize;
 
-use crate::{rules::RuleEnum, AllowWarnDeny};
+use crate::{
+    json::{parse_json, JsonDialect, JsonParseError},
+    rules::RuleEnum,
+    AllowWarnDeny, RULES,
+};
 
 use self::errors::{
-    FailedToParseConfigError, FailedToParseConfigJsonError, FailedToParseConfigPropertyError,
-    FailedToParseJsonc,
+    CircularExtendsError, ConflictingRuleError, FailedToParseConfigError,
+    FailedToParseConfigJsonError, FailedToParseConfigPropertyError, FailedToResolveExtendsError,
+    InvalidRuleConfigError, UnsupportedRuleError,
+};
+pub use self::{
+    env::ESLintEnv,
+    overrides::ESLintOverride,
+    rules::{ESLintRule, ESLintRules},
+    settings::ESLintSettings,
 };
-pub use self::{env::ESLintEnv, rules::ESLintRules, settings::ESLintSettings};
 
 /// ESLint Config
 /// <https://eslint.org/docs/latest/use/configure/configuration-files-new#configuration-objects>
@@ -27,27 +41,154 @@ pub struct ESLintConfig {
     settings: ESLintSettings,
     #[serde(default)]
     env: ESLintEnv,
+    /// Custom file extensions mapped to the extension whose language they contain,
+    /// e.g. `{ "es6": "js" }` lints `.es6` files as JavaScript.
+    #[serde(default)]
+    extensions: FxHashMap<String, String>,
+    /// Rules for the files matching some globs, taking precedence over `rules` in order.
+    #[serde(default)]
+    overrides: Vec<ESLintOverride>,
+    /// ESLint plugin names, and paths to plugin libraries (see `crate::plugin`).
+    #[serde(default)]
+    plugins: Vec<String>,
+    /// The enabled rules which are not supported, see `legacy_rules`.
+    #[serde(skip)]
+    unsupported_rules: Vec<UnsupportedRuleError>,
+    /// The rules configured more than once with different values, see `dedupe_rules`.
+    #[serde(skip)]
+    conflicting_rules: Vec<ConflictingRuleError>,
 }
 
+/// Configuration file names looked up in every directory during discovery, in order of precedence.
+pub const CONFIG_FILE_NAMES: [&str; 2] = [".oxlintrc.json", ".eslintrc.json"];
+
 impl ESLintConfig {
     pub fn from_file(path: &Path) -> Result<Self, Report> {
-        let mut string = std::fs::read_to_string(path).map_err(|e| {
+        let json = Self::load_json(path, &mut vec![])?;
+        Self::from_json(json)
+    }
+
+    /// Find the configuration files from `dir` upward,
+    /// stopping at the first one containing `"root": true` (see `CONFIG_FILE_NAMES`).
+    ///
+    /// Returns the paths ordered from the outermost to the innermost configuration file.
+    pub fn discover(dir: &Path) -> Vec<PathBuf> {
+        let mut paths = vec![];
+        for dir in dir.ancestors() {
+            let Some(path) =
+                CONFIG_FILE_NAMES.iter().map(|name| dir.join(name)).find(|path| path.is_file())
+            else {
+                continue;
+            };
+            let is_root = Self::read_json(&path).is_ok_and(|json| {
+                json.get("root").and_then(serde_json::Value::as_bool) == Some(true)
+            });
+            paths.push(path);
+            if is_root {
+                break;
+            }
+        }
+        paths.reverse();
+        paths
+    }
+
+    /// Read and merge the configuration files, configurations later in the list take precedence.
+    pub fn from_files(paths: &[PathBuf]) -> Result<Self, Report> {
+        let mut json = serde_json::Value::Object(serde_json::Map::default());
+        for path in paths {
+            merge_json(&mut json, Self::load_json(path, &mut vec![])?);
+        }
+        Self::from_json(json)
+    }
+
+    /// Parse an in-memory configuration, `extends` entries are resolved against the current directory.
+    pub fn from_value(json: serde_json::Value) -> Result<Self, Report> {
+        let json = Self::resolve_extends(json, Path::new(""), &mut vec![])?;
+        Self::from_json(json)
+    }
+
+    /// Read a configuration file and resolve its `extends`.
+    fn load_json(path: &Path, stack: &mut Vec<PathBuf>) -> Result<serde_json::Value, Report> {
+        let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if stack.contains(&canonical_path) {
+            return Err(FailedToParseConfigError(vec![Error::new(CircularExtendsError(
+                path.to_path_buf(),
+            ))])
+            .into());
+        }
+
+        let mut json = Self::read_json(path)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        set_overrides_base_path(&mut json, dir);
+        set_plugins_base_path(&mut json, dir);
+        stack.push(canonical_path);
+        let json = Self::resolve_extends(json, path, stack)?;
+        stack.pop();
+        Ok(json)
+    }
+
+    /// Merge the configurations listed in the `extends` of `json`, which was read from `path`.
+    ///
+    /// The extended configurations are merged in the order they are listed,
+    /// with the configuration itself taking precedence over all of them.
+    /// Entries other than relative paths and bundled configurations (e.g. npm packages) are ignored.
+    fn resolve_extends(
+        mut json: serde_json::Value,
+        path: &Path,
+        stack: &mut Vec<PathBuf>,
+    ) -> Result<serde_json::Value, Report> {
+        let extends = match json.as_object_mut().and_then(|json| json.remove("extends")) {
+            Some(serde_json::Value::String(name)) => vec![name],
+            Some(serde_json::Value::Array(names)) => names
+                .into_iter()
+                .filter_map(|name| name.as_str().map(ToString::to_string))
+                .collect(),
+            _ => vec![],
+        };
+
+        let mut base = serde_json::Value::Object(serde_json::Map::default());
+        for name in extends {
+            let resolve_error = || {
+                FailedToParseConfigError(vec![Error::new(FailedToResolveExtendsError(
+                    name.clone(),
+                    path.to_path_buf(),
+                ))])
+            };
+            if presets::is_preset_name(&name) {
+                merge_json(&mut base, presets::get(&name).ok_or_else(resolve_error)?);
+            } else if name.starts_with('.') || Path::new(&name).is_absolute() {
+                let extended_path = path.parent().unwrap_or_else(|| Path::new("")).join(&name);
+                if !extended_path.is_file() {
+                    return Err(resolve_error().into());
+                }
+                merge_json(&mut base, Self::load_json(&extended_path, stack)?);
+            }
+        }
+
+        merge_json(&mut base, json);
+        Ok(base)
+    }
+
+    fn read_json(path: &Path) -> Result<serde_json::Value, Report> {
+        let string = std::fs::read_to_string(path).map_err(|e| {
             FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(path.to_path_buf(), e))])
         })?;
 
-        // jsonc support
-        json_strip_comments::strip(&mut string)
-            .map_err(|_| FailedToParseJsonc(path.to_path_buf()))?;
-
-        let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
+        // Comments and trailing commas are allowed in all configuration files
+        let dialect = JsonDialect::from_path(path)
+            .map_or(JsonDialect::Jsonc, |dialect| dialect.max(JsonDialect::Jsonc));
+        let json = parse_json(&string, dialect).map_err(|err| {
             let guess = mime_guess::from_path(path);
             let err = match guess.first() {
                 // syntax error
-                Some(mime) if mime.subtype() == "json" => err.to_string(),
+                Some(mime) if mime.subtype() == "json" || dialect == JsonDialect::Json5 => {
+                    Self::syntax_error_message(&string, &err)
+                }
                 Some(_) => "only json configuration is supported".to_string(),
                 None => {
                     format!(
-                        "{err}, if the configuration is not a json file, please use json instead."
+                        "{}, if the configuration is not a json file, please use json instead.",
+                        Self::syntax_error_message(&string, &err)
                     )
                 }
             };
@@ -57,17 +198,176 @@ impl ESLintConfig {
             ))])
         })?;
 
-        let config = Self::deserialize(&json).map_err(|err| {
+        Ok(json.to_serde())
+    }
+
+    /// The message of `error` with its location, e.g. `Unexpected end of input at line 3 column 1`
+    fn syntax_error_message(source_text: &str, error: &JsonParseError) -> String {
+        let before = &source_text[..error.span.start as usize];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+        format!("{} at line {line} column {column}", error.message)
+    }
+
+    fn from_json(mut json: serde_json::Value) -> Result<Self, Report> {
+        let unsupported_rules = Self::migrate_legacy_rules(&mut json);
+        let conflicting_rules = Self::dedupe_rules(&mut json);
+        let mut config = Self::deserialize(&json).map_err(|err| {
             FailedToParseConfigError(vec![Error::new(FailedToParseConfigPropertyError(
                 err.to_string(),
             ))])
         })?;
 
+        if let Some((extension, language)) = config
+            .extensions
+            .iter()
+            .find(|(_, language)| !VALID_EXTENSIONS.contains(&language.as_str()))
+        {
+            let message = format!(
+                "extension {extension:?} is mapped to {language:?}, expected one of {}",
+                VALID_EXTENSIONS.join(", ")
+            );
+            return Err(FailedToParseConfigError(vec![Error::new(
+                FailedToParseConfigPropertyError(message),
+            )])
+            .into());
+        }
+
+        let invalid_rules = config.invalid_rules();
+        if !invalid_rules.is_empty() {
+            return Err(FailedToParseConfigError(invalid_rules).into());
+        }
+
+        config.unsupported_rules = unsupported_rules;
+        config.conflicting_rules = conflicting_rules;
         Ok(config)
     }
 
-    pub fn properties(self) -> (ESLintSettings, ESLintEnv) {
-        (self.settings, self.env)
+    /// The errors of the rules of `rules` and of the `rules` of `overrides` with a configuration
+    /// their rule rejects, see `Rule::validate_configuration`.
+    fn invalid_rules(&self) -> Vec<Error> {
+        let overrides = self.overrides.iter().map(|r#override| &r#override.rules);
+        std::iter::once(&self.rules)
+            .chain(overrides)
+            .flat_map(|rules| rules.iter())
+            .filter_map(|rule_config| {
+                let value = rule_config.config.as_ref()?;
+                let rule = RULES.iter().find(|rule| {
+                    rule.name() == rule_config.rule_name
+                        && rule.plugin_name() == rule_config.plugin_name
+                })?;
+                let message = rule.validate_json(value).err()?;
+                let name = format!("{}/{}", rule_config.plugin_name, rule_config.rule_name);
+                Some(Error::new(InvalidRuleConfigError(name, message)))
+            })
+            .collect()
+    }
+
+    /// Replaces the rules renamed by ESLint and its plugins in `rules` and in the `rules` of `overrides`,
+    /// returning the enabled rules which are not supported.
+    fn migrate_legacy_rules(json: &mut serde_json::Value) -> Vec<UnsupportedRuleError> {
+        let mut unsupported_rules = vec![];
+        if let Some(rules) = json.get_mut("rules").and_then(serde_json::Value::as_object_mut) {
+            unsupported_rules.extend(legacy_rules::migrate(rules));
+        }
+        let overrides = json.get_mut("overrides").and_then(serde_json::Value::as_array_mut);
+        for r#override in overrides.into_iter().flatten() {
+            if let Some(rules) =
+                r#override.get_mut("rules").and_then(serde_json::Value::as_object_mut)
+            {
+                unsupported_rules.extend(legacy_rules::migrate(rules));
+            }
+        }
+        unsupported_rules
+    }
+
+    /// Removes the rules configured more than once in the same `rules`, see `dedupe_rules`.
+    fn dedupe_rules(json: &mut serde_json::Value) -> Vec<ConflictingRuleError> {
+        let mut warnings = vec![];
+        if let Some(rules) = json.get_mut("rules").and_then(serde_json::Value::as_object_mut) {
+            warnings.extend(dedupe_rules(rules));
+        }
+        let overrides = json.get_mut("overrides").and_then(serde_json::Value::as_array_mut);
+        for r#override in overrides.into_iter().flatten() {
+            if let Some(rules) =
+                r#override.get_mut("rules").and_then(serde_json::Value::as_object_mut)
+            {
+                warnings.extend(dedupe_rules(rules));
+            }
+        }
+        warnings
+    }
+
+    pub fn properties(&self) -> (ESLintSettings, ESLintEnv) {
+        (self.settings.clone(), self.env.clone())
+    }
+
+    pub fn extensions(&self) -> &FxHashMap<String, String> {
+        &self.extensions
+    }
+
+    pub fn rules(&self) -> &ESLintRules {
+        &self.rules
+    }
+
+    /// Takes the warnings for the enabled rules which are not supported and for the rules configured
+    /// more than once, to report them once.
+    pub fn take_warnings(&mut self) -> Vec<Error> {
+        let unsupported_rules = std::mem::take(&mut self.unsupported_rules).into_iter();
+        let conflicting_rules = std::mem::take(&mut self.conflicting_rules).into_iter();
+        unsupported_rules.map(Error::new).chain(conflicting_rules.map(Error::new)).collect()
+    }
+
+    /// The plugin libraries and the JavaScript plugins of `plugins`, other entries name ESLint
+    /// plugins which are built in.
+    pub fn plugin_paths(&self) -> impl Iterator<Item = &Path> {
+        self.plugins.iter().map(Path::new).filter(|path| is_plugin_library(path))
+    }
+
+    pub fn has_overrides(&self) -> bool {
+        !self.overrides.is_empty()
+    }
+
+    /// The indices of the `overrides` applying to the file at `path`.
+    pub fn matching_overrides(&self, path: &Path) -> Vec<usize> {
+        self.overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, r#override)| r#override.is_match(path))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// This configuration with the rules of the `overrides` at `indices` applied in order,
+    /// and without any `overrides` left.
+    pub fn with_overrides(&self, indices: &[usize]) -> Self {
+        let mut rules = self.rules.clone();
+        for &i in indices {
+            rules.extend_from(&self.overrides[i].rules);
+        }
+        Self {
+            rules,
+            settings: self.settings.clone(),
+            env: self.env.clone(),
+            extensions: self.extensions.clone(),
+            overrides: vec![],
+            plugins: self.plugins.clone(),
+            unsupported_rules: vec![],
+            conflicting_rules: vec![],
+        }
+    }
+
+    /// Find the configured entry for a rule, falling back to a rule with the same name from another plugin
+    /// (see the overlapping rule names handling in `override_rules`).
+    pub fn find_rule(&self, plugin_name: &str, rule_name: &str) -> Option<&ESLintRule> {
+        let exact =
+            self.rules.iter().find(|r| r.rule_name == rule_name && r.plugin_name == plugin_name);
+        exact.filter(|r| r.severity.is_warn_deny()).or_else(|| {
+            self.rules
+                .iter()
+                .find(|r| r.rule_name == rule_name && r.severity.is_warn_deny())
+                .or(exact)
+        })
     }
 
     #[allow(clippy::option_if_let_else)]
@@ -137,11 +437,227 @@ impl ESLintConfig {
     }
 }
 
+/// Deep merge `overlay` into `base`: objects are merged key by key, `overrides` and `plugins` are
+/// concatenated, `rules` are merged with `merge_rules`, everything else is replaced.
+fn merge_json(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match (base.get_mut(&key), value) {
+                    (
+                        Some(serde_json::Value::Array(base_values)),
+                        serde_json::Value::Array(values),
+                    ) if key == "overrides" || key == "plugins" => base_values.extend(values),
+                    (
+                        Some(serde_json::Value::Object(base_rules)),
+                        serde_json::Value::Object(rules),
+                    ) if key == "rules" => merge_rules(base_rules, rules),
+                    (Some(base_value), value) => merge_json(base_value, value),
+                    (None, value) => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Merge the `rules` of a configuration into the `rules` of the configurations it takes precedence
+/// over, like ESLint does for cascading configurations:
+/// * a rule replaces the same rule written with another plugin prefix, e.g. `typescript/no-loss-of-precision`
+///   replaces `@typescript-eslint/no-loss-of-precision`
+/// * a rule configured with only a severity keeps its options, so nested configurations can change
+///   the severity of a rule without repeating them
+fn merge_rules(
+    base: &mut serde_json::Map<String, serde_json::Value>,
+    overlay: serde_json::Map<String, serde_json::Value>,
+) {
+    for (key, value) in overlay {
+        let rule = rules::parse_rule_key(&key);
+        let base_key =
+            base.keys().find(|base_key| rules::parse_rule_key(base_key) == rule).cloned();
+        let base_value = base_key.and_then(|base_key| base.remove(&base_key));
+        let options =
+            base_value.as_ref().and_then(serde_json::Value::as_array).and_then(|v| v.get(1..));
+        let value = match (&value, options) {
+            (serde_json::Value::String(_) | serde_json::Value::Number(_), Some(options))
+                if !options.is_empty() =>
+            {
+                std::iter::once(value.clone()).chain(options.iter().cloned()).collect()
+            }
+            (serde_json::Value::Array(severity), Some(options))
+                if severity.len() == 1 && !options.is_empty() =>
+            {
+                severity.iter().chain(options).cloned().collect()
+            }
+            _ => value,
+        };
+        base.insert(key, value);
+    }
+}
+
+/// Remove the rules configured more than once in `rules` with different plugin prefixes, keeping
+/// the most severe one, and warn about the ones configured with different values.
+fn dedupe_rules(
+    rules: &mut serde_json::Map<String, serde_json::Value>,
+) -> Vec<ConflictingRuleError> {
+    let mut warnings = vec![];
+    let mut seen: Vec<((String, String), String)> = vec![];
+    let keys = rules.keys().cloned().collect::<Vec<_>>();
+    for key in keys {
+        let rule = rules::parse_rule_key(&key);
+        let Some(i) = seen.iter().position(|(seen_rule, _)| *seen_rule == rule) else {
+            seen.push((rule, key));
+            continue;
+        };
+        let kept_key = &mut seen[i].1;
+        let kept = rules::parse_rule_value(&rules[kept_key.as_str()]).ok();
+        let other = rules::parse_rule_value(&rules[key.as_str()]).ok();
+        if kept != other {
+            warnings.push(ConflictingRuleError(kept_key.clone(), key.clone()));
+        }
+        let rank = |value: &Option<(AllowWarnDeny, Option<serde_json::Value>)>| {
+            value.as_ref().map(|(severity, _)| *severity as u8)
+        };
+        if rank(&other) > rank(&kept) {
+            rules.remove(kept_key.as_str());
+            *kept_key = key;
+        } else {
+            rules.remove(&key);
+        }
+    }
+    warnings
+}
+
+/// Make the globs of the `overrides` of a configuration file relative to its directory `dir`.
+fn set_overrides_base_path(json: &mut serde_json::Value, dir: &Path) {
+    let Some(overrides) = json.get_mut("overrides").and_then(serde_json::Value::as_array_mut)
+    else {
+        return;
+    };
+    for r#override in overrides.iter_mut().filter_map(serde_json::Value::as_object_mut) {
+        let base_path = serde_json::Value::from(dir.to_string_lossy().into_owned());
+        r#override.insert("basePath".to_string(), base_path);
+    }
+}
+
+/// Make the plugin libraries of a configuration file relative to its directory `dir`.
+fn set_plugins_base_path(json: &mut serde_json::Value, dir: &Path) {
+    let Some(plugins) = json.get_mut("plugins").and_then(serde_json::Value::as_array_mut) else {
+        return;
+    };
+    for plugin in plugins {
+        if let Some(path) = plugin.as_str().map(Path::new).filter(|path| is_plugin_library(path)) {
+            *plugin = serde_json::Value::from(dir.join(path).to_string_lossy().into_owned());
+        }
+    }
+}
+
+fn is_plugin_library(path: &Path) -> bool {
+    path.extension()
+        .and_then(std::ffi::OsStr::to_str)
+        .is_some_and(|extension| matches!(extension, "so" | "dylib" | "dll"))
+        || crate::plugin::is_js_plugin(path)
+}
+
 #[cfg(test)]
 mod test {
-    use super::ESLintConfig;
+    use super::{merge_json, ESLintConfig};
     use serde::Deserialize;
-    use std::env;
+    use std::{env, path::Path};
+
+    #[test]
+    fn test_discover() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/nested_config");
+        let paths = ESLintConfig::discover(&fixture_path.join("packages/a/src"));
+        assert_eq!(
+            paths,
+            vec![
+                fixture_path.join(".eslintrc.json"),
+                fixture_path.join("packages/a/.eslintrc.json")
+            ]
+        );
+
+        // `root: true` stops the lookup
+        let paths = ESLintConfig::discover(&fixture_path.join("packages/b"));
+        assert_eq!(paths, vec![fixture_path.join("packages/b/.eslintrc.json")]);
+    }
+
+    #[test]
+    fn test_from_files() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/nested_config");
+        let paths = ESLintConfig::discover(&fixture_path.join("packages/a"));
+        let config = ESLintConfig::from_files(&paths).unwrap();
+        let no_console = config.rules.iter().find(|r| r.rule_name == "no-console").unwrap();
+        assert!(no_console.severity.is_allow());
+        let no_debugger = config.rules.iter().find(|r| r.rule_name == "no-debugger").unwrap();
+        assert!(no_debugger.severity.is_warn_deny());
+        assert_eq!(config.env.iter().filter(|env| *env == "browser" || *env == "node").count(), 2);
+    }
+
+    #[test]
+    fn test_extends() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends");
+        let config = ESLintConfig::from_file(&fixture_path.join(".eslintrc.json")).unwrap();
+        let severity = |name: &str| {
+            config.rules.iter().find(|r| r.rule_name == name).map(|r| r.severity.as_eslint_str())
+        };
+        // from `eslint:recommended`
+        assert_eq!(severity("no-undef"), Some("error"));
+        // `shared/strict.json` takes precedence over `eslint:recommended`
+        assert_eq!(severity("no-empty"), Some("off"));
+        // `base.json` takes precedence over `shared/strict.json`
+        assert_eq!(severity("eqeqeq"), Some("warn"));
+        assert_eq!(severity("no-console"), Some("error"));
+        // the config itself takes precedence over everything it extends
+        assert_eq!(severity("no-debugger"), Some("off"));
+
+        for invalid in ["circular.json", "unknown_preset.json", "missing_file.json"] {
+            assert!(ESLintConfig::from_file(&fixture_path.join("invalid").join(invalid)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_overrides() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/overrides");
+        let paths = ESLintConfig::discover(&fixture_path.join("src"));
+        assert_eq!(paths, vec![fixture_path.join(".oxlintrc.json")]);
+
+        let config = ESLintConfig::from_files(&paths).unwrap();
+        let matching_overrides = |path: &str| config.matching_overrides(&fixture_path.join(path));
+        assert_eq!(matching_overrides("index.js"), Vec::<usize>::new());
+        assert_eq!(matching_overrides("index.test.js"), [0]);
+        assert_eq!(matching_overrides("src/index.js"), [1]);
+        assert_eq!(matching_overrides("src/index.test.js"), [0, 1]);
+        assert_eq!(matching_overrides("src/vendor/index.js"), Vec::<usize>::new());
+
+        let severity = |indices: &[usize], name: &str| {
+            let config = config.with_overrides(indices);
+            config.find_rule("eslint", name).map(|r| r.severity.as_eslint_str())
+        };
+        assert_eq!(severity(&[], "no-debugger"), Some("warn"));
+        assert_eq!(severity(&[0], "no-debugger"), Some("off"));
+        assert_eq!(severity(&[1], "no-debugger"), Some("error"));
+        // later overrides take precedence
+        assert_eq!(severity(&[1, 0], "no-debugger"), Some("off"));
+        assert_eq!(severity(&[0], "eqeqeq"), None);
+        assert_eq!(severity(&[1], "eqeqeq"), Some("error"));
+
+        let config = ESLintConfig::from_json(serde_json::json!({
+            "overrides": [{ "rules": { "no-debugger": "off" } }]
+        }));
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn test_presets() {
+        let config = ESLintConfig::deserialize(super::presets::get("oxc:recommended").unwrap());
+        assert!(config.unwrap().rules.iter().any(|r| r.rule_name == "no-debugger"));
+        let config = ESLintConfig::deserialize(super::presets::get("typescript:strict").unwrap());
+        assert!(config.unwrap().rules.iter().all(|r| r.plugin_name == "typescript"));
+        assert!(super::presets::get("oxc:unknown").is_none());
+    }
 
     #[test]
     fn test_from_file() {
@@ -150,6 +666,16 @@ mod test {
         assert!(!config.rules.is_empty());
     }
 
+    #[test]
+    fn test_from_file_jsonc_and_json5() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures");
+        for name in ["eslint_config_jsonc.json", "eslint_config.json5"] {
+            let config = ESLintConfig::from_file(&fixture_path.join(name)).unwrap();
+            let no_debugger = config.rules.iter().find(|r| r.rule_name == "no-debugger").unwrap();
+            assert!(no_debugger.severity.is_warn_deny());
+        }
+    }
+
     #[test]
     fn test_deserialize() {
         let config = ESLintConfig::deserialize(&serde_json::json!({
@@ -181,9 +707,183 @@ mod test {
         }));
         assert!(config.is_ok());
 
-        let ESLintConfig { rules, settings, env } = config.unwrap();
+        let ESLintConfig {
+            rules,
+            settings,
+            env,
+            extensions,
+            overrides,
+            plugins,
+            unsupported_rules,
+            conflicting_rules,
+        } = config.unwrap();
         assert!(!rules.is_empty());
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(env.iter().count(), 1);
+        assert!(extensions.is_empty());
+        assert!(overrides.is_empty());
+        assert!(plugins.is_empty());
+        assert!(unsupported_rules.is_empty());
+        assert!(conflicting_rules.is_empty());
+    }
+
+    #[test]
+    fn test_plugin_paths() {
+        let config = ESLintConfig::from_json(serde_json::json!({
+            "plugins": ["react", "./libacme_plugin.so", "/plugins/acme.dylib"]
+        }));
+        let config = config.unwrap();
+        let paths = config.plugin_paths().collect::<Vec<_>>();
+        assert_eq!(paths, [Path::new("./libacme_plugin.so"), Path::new("/plugins/acme.dylib")]);
+    }
+
+    #[test]
+    fn test_legacy_rules() {
+        let mut config = ESLintConfig::from_json(serde_json::json!({
+            "rules": {
+                "no-native-reassign": ["error", { "exceptions": ["Object"] }],
+                "no-catch-shadow": "warn",
+                "@typescript-eslint/camelcase": ["error", { "properties": "never" }],
+                "valid-jsdoc": "error",
+                "require-jsdoc": "off",
+            },
+            "overrides": [{
+                "files": ["*.test.js"],
+                "rules": { "jest/valid-describe": "error", "jest/valid-describe-callback": "off" }
+            }]
+        }))
+        .unwrap();
+
+        let rule = config.find_rule("eslint", "no-global-assign").unwrap();
+        assert_eq!(rule.config, Some(serde_json::json!([{ "exceptions": ["Object"] }])));
+        let rule = config.find_rule("eslint", "no-shadow").unwrap();
+        assert_eq!(rule.severity.as_eslint_str(), "warn");
+        // The options of the replaced rules do not apply to the new ones
+        assert!(config.find_rule("typescript", "naming-convention").unwrap().config.is_none());
+        assert!(config.find_rule("eslint", "no-catch-shadow").is_none());
+
+        let warnings = config.take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].to_string().contains("\"valid-jsdoc\""));
+        assert!(config.take_warnings().is_empty());
+
+        // The replacement configured in the same `rules` takes precedence
+        let config = config.with_overrides(&[0]);
+        let rule = config.find_rule("jest", "valid-describe-callback").unwrap();
+        assert!(rule.severity.is_allow());
+    }
+
+    #[test]
+    fn test_cascading_rules() {
+        let mut json = serde_json::json!({
+            "rules": {
+                "eqeqeq": ["error", "always", { "null": "ignore" }],
+                "@typescript-eslint/no-explicit-any": ["error", { "fixToUnknown": true }],
+                "no-console": ["error", { "allow": ["warn"] }],
+            },
+            "overrides": [{ "files": ["*.test.js"], "rules": { "eqeqeq": "off" } }]
+        });
+        merge_json(
+            &mut json,
+            serde_json::json!({
+                "rules": {
+                    "eqeqeq": "warn",
+                    "typescript/no-explicit-any": ["off"],
+                    "no-console": ["warn", { "allow": ["error"] }],
+                }
+            }),
+        );
+        let config = ESLintConfig::from_json(json).unwrap();
+
+        // Only the severity is changed
+        let rule = config.find_rule("eslint", "eqeqeq").unwrap();
+        assert_eq!(rule.severity.as_eslint_str(), "warn");
+        assert_eq!(rule.config, Some(serde_json::json!(["always", { "null": "ignore" }])));
+        // The same rule with another plugin prefix is replaced
+        let rules = config.rules.iter().filter(|r| r.rule_name == "no-explicit-any");
+        let rules = rules.collect::<Vec<_>>();
+        assert_eq!(rules.len(), 1);
+        assert!(rules[0].severity.is_allow());
+        // The options are replaced
+        let rule = config.find_rule("eslint", "no-console").unwrap();
+        assert_eq!(rule.config, Some(serde_json::json!([{ "allow": ["error"] }])));
+
+        // The overrides changing only the severity keep the options as well
+        let config = config.with_overrides(&[0]);
+        let rule = config.find_rule("eslint", "eqeqeq").unwrap();
+        assert!(rule.severity.is_allow());
+        assert_eq!(rule.config, Some(serde_json::json!(["always", { "null": "ignore" }])));
+    }
+
+    #[test]
+    fn test_conflicting_rules() {
+        let mut config = ESLintConfig::from_json(serde_json::json!({
+            "rules": {
+                "@typescript-eslint/no-explicit-any": "warn",
+                "typescript/no-explicit-any": "error",
+                "@typescript-eslint/prefer-as-const": "warn",
+                "typescript/prefer-as-const": 1,
+            }
+        }))
+        .unwrap();
+
+        // The most severe one is used
+        let rules = config.rules.iter().filter(|r| r.rule_name == "no-explicit-any");
+        let rules = rules.collect::<Vec<_>>();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].severity.as_eslint_str(), "error");
+        assert_eq!(config.rules.iter().filter(|r| r.rule_name == "prefer-as-const").count(), 1);
+
+        // Only the rules configured with different values are reported
+        let warnings = config.take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].to_string().contains("\"typescript/no-explicit-any\""));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let errors = |options: serde_json::Value| {
+            let json = serde_json::json!({
+                "rules": { "@typescript-eslint/naming-convention": ["error", options] },
+                "overrides": [{
+                    "files": "*.ts",
+                    "rules": { "no-console": ["error", { "allow": ["warn"] }] }
+                }]
+            });
+            let Err(error) = ESLintConfig::from_json(json) else { return vec![] };
+            error.related().unwrap().map(ToString::to_string).collect::<Vec<_>>()
+        };
+
+        let options = serde_json::json!({ "selector": "variable", "modifiers": ["cnst"] });
+        let errors_of_modifiers = errors(options);
+        assert_eq!(errors_of_modifiers.len(), 1);
+        assert!(errors_of_modifiers[0].starts_with(
+            "Invalid configuration of rule \"typescript/naming-convention\": unknown modifier \"cnst\""
+        ));
+
+        let options = serde_json::json!({ "selector": "variable", "filter": "^(a" });
+        assert!(errors(options)[0].contains("invalid regex \"^(a\""));
+        let options = serde_json::json!({ "selector": "variable", "custom": { "regex": "[" } });
+        assert!(errors(options)[0].contains("invalid regex \"[\""));
+
+        let options = serde_json::json!({ "selector": "variable", "modifiers": ["const"] });
+        assert!(errors(options).is_empty());
+
+        let json = serde_json::json!({
+            "rules": { "no-restricted-syntax": ["error", "WithStatement", { "selector": "A ~ B" }] }
+        });
+        let error = ESLintConfig::from_json(json).unwrap_err();
+        let errors = error.related().unwrap().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("invalid selector \"A ~ B\""));
+    }
+
+    #[test]
+    fn test_extensions() {
+        let config = ESLintConfig::from_json(serde_json::json!({ "extensions": { "es6": "js" } }));
+        assert_eq!(config.unwrap().extensions().get("es6").map(String::as_str), Some("js"));
+
+        let config = ESLintConfig::from_json(serde_json::json!({ "extensions": { "es6": "es5" } }));
+        assert!(config.is_err());
     }
 }
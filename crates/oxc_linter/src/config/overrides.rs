@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use serde::Deserialize;
+
+use super::rules::ESLintRules;
+
+/// An entry of the `overrides` field from ESLint config
+/// <https://eslint.org/docs/latest/use/configure/configuration-files#how-do-overrides-work>
+///
+/// Globs without a slash match the file name in any directory,
+/// others are relative to `base_path`, like in `.gitignore` files.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "ESLintOverrideJson")]
+pub struct ESLintOverride {
+    files: Gitignore,
+    excluded_files: Gitignore,
+    pub rules: ESLintRules,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ESLintOverrideJson {
+    files: Globs,
+    #[serde(default)]
+    excluded_files: Globs,
+    /// The directory of the configuration file declaring the override, set when loading the file.
+    #[serde(default)]
+    base_path: PathBuf,
+    #[serde(default)]
+    rules: ESLintRules,
+}
+
+/// A glob or a list of globs
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Globs {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Default for Globs {
+    fn default() -> Self {
+        Self::Many(vec![])
+    }
+}
+
+impl Globs {
+    fn build(&self, base_path: &Path) -> Result<Gitignore, String> {
+        let globs: &[String] = match self {
+            Self::One(glob) => std::slice::from_ref(glob),
+            Self::Many(globs) => globs,
+        };
+        let mut builder = GitignoreBuilder::new(base_path);
+        for glob in globs {
+            builder.add_line(None, glob).map_err(|err| err.to_string())?;
+        }
+        builder.build().map_err(|err| err.to_string())
+    }
+}
+
+impl TryFrom<ESLintOverrideJson> for ESLintOverride {
+    type Error = String;
+
+    fn try_from(json: ESLintOverrideJson) -> Result<Self, Self::Error> {
+        Ok(Self {
+            files: json.files.build(&json.base_path)?,
+            excluded_files: json.excluded_files.build(&json.base_path)?,
+            rules: json.rules,
+        })
+    }
+}
+
+impl ESLintOverride {
+    pub fn is_match(&self, path: &Path) -> bool {
+        self.files.matched(path, false).is_ignore()
+            && !self.excluded_files.matched(path, false).is_ignore()
+    }
+}
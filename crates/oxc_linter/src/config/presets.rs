@@ -0,0 +1,122 @@
+//! Shareable configurations bundled with the linter, usable from `extends`.
+
+use serde_json::{Map, Value};
+
+use crate::{rules::RULES, RuleCategory};
+
+/// <https://github.com/eslint/eslint/blob/main/packages/js/src/configs/eslint-recommended.js>
+const ESLINT_RECOMMENDED: &[&str] = &[
+    "constructor-super",
+    "for-direction",
+    "getter-return",
+    "no-async-promise-executor",
+    "no-case-declarations",
+    "no-class-assign",
+    "no-compare-neg-zero",
+    "no-cond-assign",
+    "no-const-assign",
+    "no-constant-condition",
+    "no-control-regex",
+    "no-debugger",
+    "no-delete-var",
+    "no-dupe-args",
+    "no-dupe-class-members",
+    "no-dupe-else-if",
+    "no-dupe-keys",
+    "no-duplicate-case",
+    "no-empty",
+    "no-empty-character-class",
+    "no-empty-pattern",
+    "no-ex-assign",
+    "no-extra-boolean-cast",
+    "no-fallthrough",
+    "no-func-assign",
+    "no-global-assign",
+    "no-import-assign",
+    "no-inner-declarations",
+    "no-invalid-regexp",
+    "no-irregular-whitespace",
+    "no-loss-of-precision",
+    "no-misleading-character-class",
+    "no-new-symbol",
+    "no-nonoctal-decimal-escape",
+    "no-obj-calls",
+    "no-octal",
+    "no-prototype-builtins",
+    "no-redeclare",
+    "no-regex-spaces",
+    "no-self-assign",
+    "no-setter-return",
+    "no-shadow-restricted-names",
+    "no-sparse-arrays",
+    "no-this-before-super",
+    "no-undef",
+    "no-unexpected-multiline",
+    "no-unreachable",
+    "no-unsafe-finally",
+    "no-unsafe-negation",
+    "no-unsafe-optional-chaining",
+    "no-unused-labels",
+    "no-unused-vars",
+    "no-useless-backreference",
+    "no-useless-catch",
+    "no-useless-escape",
+    "no-with",
+    "require-yield",
+    "use-isnan",
+    "valid-typeof",
+];
+
+/// <https://github.com/typescript-eslint/typescript-eslint/blob/main/packages/eslint-plugin/src/configs/strict.ts>
+const TYPESCRIPT_STRICT: &[&str] = &[
+    "ban-ts-comment",
+    "ban-types",
+    "no-array-constructor",
+    "no-duplicate-enum-values",
+    "no-dynamic-delete",
+    "no-explicit-any",
+    "no-extra-non-null-assertion",
+    "no-extraneous-class",
+    "no-invalid-void-type",
+    "no-loss-of-precision",
+    "no-misused-new",
+    "no-namespace",
+    "no-non-null-asserted-nullish-coalescing",
+    "no-non-null-asserted-optional-chain",
+    "no-non-null-assertion",
+    "no-this-alias",
+    "no-unnecessary-type-constraint",
+    "no-unsafe-declaration-merging",
+    "no-unused-vars",
+    "no-useless-constructor",
+    "no-var-requires",
+    "prefer-as-const",
+    "prefer-literal-enum-member",
+    "prefer-ts-expect-error",
+    "triple-slash-reference",
+    "unified-signatures",
+];
+
+/// Get the bundled configuration named `name`, e.g. `oxc:recommended`.
+pub fn get(name: &str) -> Option<Value> {
+    let rules: Vec<String> = match name {
+        // The rules enabled by default, i.e. the correctness category.
+        "oxc:recommended" => RULES
+            .iter()
+            .filter(|rule| rule.category() == RuleCategory::Correctness)
+            .map(|rule| format!("{}/{}", rule.plugin_name(), rule.name()))
+            .collect(),
+        "eslint:recommended" => ESLINT_RECOMMENDED.iter().map(|name| (*name).to_string()).collect(),
+        "typescript:strict" => {
+            TYPESCRIPT_STRICT.iter().map(|name| format!("@typescript-eslint/{name}")).collect()
+        }
+        _ => return None,
+    };
+    let rules = rules.into_iter().map(|key| (key, Value::from("error"))).collect::<Map<_, _>>();
+    Some(serde_json::json!({ "rules": rules }))
+}
+
+/// Whether `name` refers to a bundled configuration rather than a configuration file or package.
+pub fn is_preset_name(name: &str) -> bool {
+    ["oxc:", "eslint:", "typescript:"].iter().any(|prefix| name.starts_with(prefix))
+}
@@ -60,7 +60,7 @@ impl<'de> Deserialize<'de> for ESLintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub(super) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return ("eslint".to_string(), name.to_string());
     };
@@ -77,7 +77,7 @@ fn parse_rule_key(name: &str) -> (String, String) {
     (oxlint_plugin_name.to_string(), rule_name.to_string())
 }
 
-fn parse_rule_value(
+pub(super) fn parse_rule_value(
     value: &serde_json::Value,
 ) -> Result<(AllowWarnDeny, Option<serde_json::Value>), Error> {
     match value {
@@ -116,6 +116,25 @@ fn parse_rule_value(
     }
 }
 
+impl ESLintRules {
+    /// Add the entries of `other`, replacing the entries for the same rules.
+    /// The entries with only a severity keep the options of the replaced entries.
+    pub fn extend_from(&mut self, other: &Self) {
+        for rule in &other.0 {
+            let mut rule = rule.clone();
+            if let Some(i) = self
+                .0
+                .iter()
+                .position(|r| r.plugin_name == rule.plugin_name && r.rule_name == rule.rule_name)
+            {
+                let replaced = self.0.remove(i);
+                rule.config = rule.config.or(replaced.config);
+            }
+            self.0.push(rule);
+        }
+    }
+}
+
 impl Deref for ESLintRules {
     type Target = Vec<ESLintRule>;
 
@@ -0,0 +1,49 @@
+use oxc_resolver::ResolveOptions;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// `settings["import/resolver"]`, configuring how the import plugin resolves modules.
+///
+/// ```json
+/// {
+///   "settings": {
+///     "import/resolver": {
+///       "extensionAlias": { ".js": [".ts", ".js"] },
+///       "mainFields": ["react-native", "browser", "main"],
+///       "modules": ["node_modules", "/path/to/shared"]
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ESLintSettingsImportResolver {
+    /// Extensions to try instead of the extension of the specifier, e.g. `.ts` for `./foo.js`
+    #[serde(default)]
+    extension_alias: FxHashMap<String, Vec<String>>,
+    /// package.json fields of the entry point, replacing `main`
+    #[serde(default)]
+    main_fields: Vec<String>,
+    /// Directories to look up packages in, replacing `node_modules`
+    #[serde(default)]
+    modules: Vec<String>,
+}
+
+impl ESLintSettingsImportResolver {
+    pub fn apply(&self, mut options: ResolveOptions) -> ResolveOptions {
+        let mut extension_alias = self
+            .extension_alias
+            .iter()
+            .map(|(extension, aliases)| (extension.clone(), aliases.clone()))
+            .collect::<Vec<_>>();
+        extension_alias.sort_unstable();
+        options.extension_alias.extend(extension_alias);
+        if !self.main_fields.is_empty() {
+            options.main_fields = self.main_fields.clone();
+        }
+        if !self.modules.is_empty() {
+            options.modules = self.modules.clone();
+        }
+        options
+    }
+}
@@ -1,8 +1,8 @@
 use rustc_hash::FxHashMap;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// https://github.com/jsx-eslint/eslint-plugin-jsx-a11y#configurations
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct ESLintSettingsJSXA11y {
     #[serde(rename = "polymorphicPropName")]
     pub polymorphic_prop_name: Option<String>,
@@ -1,6 +1,10 @@
-use self::{jsx_a11y::ESLintSettingsJSXA11y, next::ESLintSettingsNext, react::ESLintSettingsReact};
-use serde::Deserialize;
+use self::{
+    import::ESLintSettingsImportResolver, jsx_a11y::ESLintSettingsJSXA11y,
+    next::ESLintSettingsNext, react::ESLintSettingsReact,
+};
+use serde::{Deserialize, Serialize};
 
+mod import;
 mod jsx_a11y;
 mod next;
 mod react;
@@ -11,7 +15,7 @@ mod react;
 /// TS type is `Object`
 /// https://github.com/eslint/eslint/blob/ce838adc3b673e52a151f36da0eedf5876977514/lib/shared/types.js#L53
 /// But each plugin extends this with their own properties.
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct ESLintSettings {
     #[serde(default)]
     #[serde(rename = "jsx-a11y")]
@@ -20,6 +24,20 @@ pub struct ESLintSettings {
     pub next: ESLintSettingsNext,
     #[serde(default)]
     pub react: ESLintSettingsReact,
+    #[serde(default)]
+    #[serde(rename = "import/resolver")]
+    pub import_resolver: ESLintSettingsImportResolver,
+    /// The other settings, e.g. of the rules of plugins, see `get`
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ESLintSettings {
+    /// The value of the settings without a typed field, e.g. `settings.get("acme")` for
+    /// `"settings": { "acme": { "strict": true } }`.
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.other.get(key)
+    }
 }
 
 #[cfg(test)]
@@ -72,6 +90,56 @@ mod test {
             Some(vec!["to".to_string(), "href".to_string()])
         );
         assert_eq!(settings.react.get_link_component_attrs("Noop"), None);
+        assert_eq!(settings.react.pragma(), "React");
+        assert_eq!(settings.react.version(), None);
+    }
+
+    #[test]
+    fn test_parse_react_version_and_other_settings() {
+        let settings = ESLintSettings::deserialize(&serde_json::json!({
+            "react": { "pragma": "h", "version": "16.8" },
+            "acme": { "strict": true },
+            "import/extensions": [".js"]
+        }))
+        .unwrap();
+        assert_eq!(settings.react.pragma(), "h");
+        assert_eq!(settings.react.version(), Some((16, 8, 0)));
+        assert_eq!(settings.get("acme"), Some(&serde_json::json!({ "strict": true })));
+        assert_eq!(settings.get("import/extensions"), Some(&serde_json::json!([".js"])));
+        assert_eq!(settings.get("react"), None);
+
+        let version = |version: &str| {
+            let settings = ESLintSettings::deserialize(
+                &serde_json::json!({ "react": { "version": version } }),
+            )
+            .unwrap();
+            settings.react.version()
+        };
+        assert_eq!(version("18.2.0"), Some((18, 2, 0)));
+        assert_eq!(version("17"), Some((17, 0, 0)));
+        assert_eq!(version("detect"), None);
+    }
+
+    #[test]
+    fn test_parse_import_resolver_settings() {
+        let settings = ESLintSettings::deserialize(&serde_json::json!({
+            "import/resolver": {
+                "extensionAlias": { ".js": [".ts", ".js"] },
+                "mainFields": ["react-native", "main"],
+                "modules": ["node_modules", "web_modules"]
+            }
+        }))
+        .unwrap();
+
+        let options = settings.import_resolver.apply(oxc_resolver::ResolveOptions::default());
+        assert_eq!(options.extension_alias, [(".js".into(), vec![".ts".into(), ".js".into()])]);
+        assert_eq!(options.main_fields, ["react-native", "main"]);
+        assert_eq!(options.modules, ["node_modules", "web_modules"]);
+
+        let options = ESLintSettings::default()
+            .import_resolver
+            .apply(oxc_resolver::ResolveOptions::default());
+        assert_eq!(options.main_fields, ["main"]);
     }
 
     #[test]
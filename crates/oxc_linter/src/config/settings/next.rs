@@ -1,7 +1,7 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// https://nextjs.org/docs/pages/building-your-application/configuring/eslint#eslint-plugin
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct ESLintSettingsNext {
     #[serde(default)]
     #[serde(rename = "rootDir")]
@@ -19,7 +19,7 @@ impl ESLintSettingsNext {
 
 // Deserialize helper types
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(untagged)]
 enum OneOrMany<T> {
     One(T),
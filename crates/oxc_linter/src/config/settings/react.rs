@@ -1,7 +1,7 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// https://github.com/jsx-eslint/eslint-plugin-react#configuration-legacy-eslintrc-
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct ESLintSettingsReact {
     #[serde(default)]
     #[serde(rename = "formComponents")]
@@ -9,10 +9,29 @@ pub struct ESLintSettingsReact {
     #[serde(default)]
     #[serde(rename = "linkComponents")]
     link_components: Vec<CustomComponent>,
+    /// The name of the variable JSX is compiled to calls of, `React` by default
+    pragma: Option<String>,
+    /// The React version of the project, e.g. `16.8`, or `detect`
+    version: Option<String>,
     // TODO: More properties should be added
 }
 
 impl ESLintSettingsReact {
+    pub fn pragma(&self) -> &str {
+        self.pragma.as_deref().unwrap_or("React")
+    }
+
+    /// The major, minor and patch versions of `version`, with the missing ones at `0`.
+    /// `None` for `detect` and invalid versions, which are the latest version as in
+    /// eslint-plugin-react.
+    pub fn version(&self) -> Option<(u32, u32, u32)> {
+        let mut parts = self.version.as_deref()?.split('.').map(str::parse::<u32>);
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().transpose().ok()?.unwrap_or(0);
+        let patch = parts.next().transpose().ok()?.unwrap_or(0);
+        Some((major, minor, patch))
+    }
+
     pub fn get_form_component_attrs(&self, name: &str) -> Option<Vec<String>> {
         get_component_attrs_by_name(&self.form_components, name)
     }
@@ -24,7 +43,7 @@ impl ESLintSettingsReact {
 
 // Deserialize helper types
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 enum CustomComponent {
     NameOnly(String),
@@ -1,14 +1,29 @@
-use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
+use std::{
+    any::{Any, TypeId},
+    cell::{RefCell, RefMut},
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+};
 
+use oxc_ast::ast::Expression;
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::Error;
-use oxc_semantic::{AstNodes, JSDocFinder, ScopeTree, Semantic, SymbolTable};
-use oxc_span::SourceType;
+use oxc_diagnostics::{with_rule, with_severity, CancellationToken, Error, Severity};
+use oxc_parser::Token;
+use oxc_semantic::{
+    AstNodes, ControlFlowGraph, JSDocFinder, ModuleRecord, ScopeTree, Semantic, SymbolId,
+    SymbolTable,
+};
+use oxc_span::{SourceType, Span};
+use phf::Map;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message},
+    fixer::{Fix, FixKind, Message},
+    globals::{SVELTE_GLOBALS, VUE_GLOBALS},
     javascript_globals::GLOBALS,
+    type_info::{ExpressionType, TypeInfo},
     ESLintEnv, ESLintSettings,
 };
 
@@ -22,13 +37,37 @@ pub struct LintContext<'a> {
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
+    /// The most dangerous kind of fixes to apply, other fixes are only reported.
+    fix_kind: FixKind,
+
+    /// The plugin of the current rule, e.g. `eslint`
+    current_plugin_name: &'static str,
+
     current_rule_name: &'static str,
 
+    /// The severity the current rule is configured with, `None` to keep the severity of its diagnostics.
+    current_rule_severity: Option<Severity>,
+
     file_path: Box<Path>,
 
     settings: Arc<ESLintSettings>,
 
     env: Arc<ESLintEnv>,
+
+    /// `None` when no type information is available, type-aware rules do nothing then.
+    type_info: Option<Arc<dyn TypeInfo>>,
+
+    cancellation_token: Option<CancellationToken>,
+
+    /// The tokens of the file in source order, see `LintContext::with_tokens`.
+    tokens: Vec<Token>,
+
+    /// The state of each rule for the file, see `LintContext::rule_state`.
+    /// Keyed by the plugin name, empty for the rules of this crate, the rule name and the type.
+    rule_states: RefCell<FxHashMap<(&'static str, &'static str, TypeId), Box<dyn Any>>>,
+
+    /// The symbols marked as used by rules, see `LintContext::mark_used`.
+    used_symbols: RefCell<FxHashSet<SymbolId>>,
 }
 
 impl<'a> LintContext<'a> {
@@ -40,10 +79,18 @@ impl<'a> LintContext<'a> {
             diagnostics: RefCell::new(vec![]),
             disable_directives,
             fix: false,
+            fix_kind: FixKind::default(),
+            current_plugin_name: "",
             current_rule_name: "",
+            current_rule_severity: None,
             file_path,
             settings: Arc::new(ESLintSettings::default()),
             env: Arc::new(ESLintEnv::default()),
+            type_info: None,
+            cancellation_token: None,
+            tokens: vec![],
+            rule_states: RefCell::default(),
+            used_symbols: RefCell::default(),
         }
     }
 
@@ -53,6 +100,12 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, kind: FixKind) -> Self {
+        self.fix_kind = kind;
+        self
+    }
+
     #[must_use]
     pub fn with_settings(mut self, settings: &Arc<ESLintSettings>) -> Self {
         self.settings = Arc::clone(settings);
@@ -65,6 +118,31 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_type_info(mut self, type_info: Option<&Arc<dyn TypeInfo>>) -> Self {
+        self.type_info = type_info.cloned();
+        self
+    }
+
+    /// The tokens of the file, collected with `Parser::collect_tokens`.
+    /// Without them, the token queries find no token.
+    #[must_use]
+    pub fn with_tokens(mut self, tokens: Vec<Token>) -> Self {
+        self.tokens = tokens;
+        self
+    }
+
+    /// Stop running rules when `token` is cancelled, no diagnostics are returned then.
+    #[must_use]
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token.as_ref().is_some_and(CancellationToken::is_cancelled)
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -93,45 +171,202 @@ impl<'a> LintContext<'a> {
         &self.env
     }
 
+    pub fn has_type_info(&self) -> bool {
+        self.type_info.is_some()
+    }
+
+    /// The type of `expr`, `None` when no type information is available or the type is unknown.
+    pub fn expression_type(&self, expr: &Expression<'_>) -> Option<ExpressionType> {
+        self.type_info.as_ref()?.expression_type(&self.file_path, expr)
+    }
+
     pub fn env_contains_var(&self, var: &str) -> bool {
-        for env in self.env.iter() {
-            let env = GLOBALS.get(env).unwrap_or(&GLOBALS["builtin"]);
-            if env.get(var).is_some() {
-                return true;
-            }
-        }
+        self.env_var_writable(var).is_some()
+    }
 
-        false
+    /// Whether the global `var` of the enabled environments can be assigned to,
+    /// `None` when none of them defines it.
+    pub fn env_var_writable(&self, var: &str) -> Option<bool> {
+        self.env
+            .iter()
+            .find_map(|env| {
+                let env = GLOBALS.get(env).unwrap_or(&GLOBALS["builtin"]);
+                env.get(var).copied()
+            })
+            .or_else(|| self.framework_globals()?.get(var).copied())
+    }
+
+    /// Globals defined by the compiler of the component file, e.g. `defineProps` in Vue.
+    fn framework_globals(&self) -> Option<&'static Map<&'static str, bool>> {
+        match self.file_path.extension()?.to_str()? {
+            "vue" => Some(&VUE_GLOBALS),
+            "svelte" => Some(&SVELTE_GLOBALS),
+            _ => None,
+        }
     }
 
     #[inline]
-    pub fn with_rule_name(&mut self, name: &'static str) {
+    pub fn with_rule_name(&mut self, plugin_name: &'static str, name: &'static str) {
+        self.current_plugin_name = plugin_name;
         self.current_rule_name = name;
     }
 
+    #[inline]
+    pub fn with_rule_severity(&mut self, severity: Option<Severity>) {
+        self.current_rule_severity = severity;
+    }
+
+    /* Tokens */
+
+    /// The tokens of the file in source order, without comments.
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// The last token ending before `span`, like `sourceCode.getTokenBefore` in ESLint.
+    pub fn get_token_before(&self, span: Span) -> Option<Token> {
+        let index = self.tokens.partition_point(|token| token.end <= span.start);
+        index.checked_sub(1).map(|index| self.tokens[index])
+    }
+
+    /// The first token starting after `span`, like `sourceCode.getTokenAfter` in ESLint.
+    pub fn get_token_after(&self, span: Span) -> Option<Token> {
+        let index = self.tokens.partition_point(|token| token.start < span.end);
+        self.tokens.get(index).copied()
+    }
+
+    /// The first token inside of `span`, like `sourceCode.getFirstToken` in ESLint.
+    pub fn first_token_of(&self, span: Span) -> Option<Token> {
+        let index = self.tokens.partition_point(|token| token.start < span.start);
+        self.tokens.get(index).copied().filter(|token| token.end <= span.end)
+    }
+
+    /// The last token inside of `span`, like `sourceCode.getLastToken` in ESLint.
+    pub fn last_token_of(&self, span: Span) -> Option<Token> {
+        let index = self.tokens.partition_point(|token| token.end <= span.end);
+        index
+            .checked_sub(1)
+            .map(|index| self.tokens[index])
+            .filter(|token| token.start >= span.start)
+    }
+
+    /// The source text between the end of `left` and the start of `right`, including comments,
+    /// e.g. ` = ` between `a` and `b` of `a = b`.
+    pub fn text_between(&self, left: Span, right: Span) -> &'a str {
+        Span::new(left.end, right.start).source_text(self.source_text())
+    }
+
     /* Diagnostics */
 
+    /// The state of the current rule for this file, `T::default()` the first time.
+    /// Lets a rule collect what it finds in `run` and report it in `run_on_program_exit`.
+    ///
+    /// # Panics
+    /// When the state is already borrowed.
+    pub fn rule_state<T: Default + 'static>(&self) -> RefMut<'_, T> {
+        self.rule_state_of("", self.current_rule_name)
+    }
+
+    pub(crate) fn rule_state_of<T: Default + 'static>(
+        &self,
+        plugin_name: &'static str,
+        rule_name: &'static str,
+    ) -> RefMut<'_, T> {
+        RefMut::map(self.rule_states.borrow_mut(), |states| {
+            states
+                .entry((plugin_name, rule_name, TypeId::of::<T>()))
+                .or_insert_with(|| Box::<T>::default())
+                .downcast_mut::<T>()
+                .unwrap()
+        })
+    }
+
+    /// Mark a symbol as used without a reference to it, e.g. `React` by the JSX of
+    /// `react/jsx-uses-react`, so that `no-unused-vars` does not report it as in ESLint.
+    /// Must be called from `Rule::run_once`, as the symbols are checked before the nodes are visited.
+    pub fn mark_used(&self, symbol_id: SymbolId) {
+        self.used_symbols.borrow_mut().insert(symbol_id);
+    }
+
+    /// Whether a rule marked the symbol as used, see `mark_used`.
+    pub fn is_marked_used(&self, symbol_id: SymbolId) -> bool {
+        self.used_symbols.borrow().contains(&symbol_id)
+    }
+
     pub fn into_message(self) -> Vec<Message<'a>> {
         self.diagnostics.into_inner()
     }
 
-    fn add_diagnostic(&self, message: Message<'a>) {
+    pub(crate) fn current_rule_name(&self) -> &'static str {
+        self.current_rule_name
+    }
+
+    pub(crate) fn number_of_diagnostics(&self) -> usize {
+        self.diagnostics.borrow().len()
+    }
+
+    fn add_diagnostic(&self, mut message: Message<'a>) {
         if !self.disable_directives.contains(self.current_rule_name, message.start()) {
+            if let Some(severity) = self.current_rule_severity {
+                message.error = with_severity(message.error, severity);
+            }
+            if !self.current_rule_name.is_empty() {
+                message.error =
+                    with_rule(message.error, self.current_plugin_name, self.current_rule_name);
+            }
             self.diagnostics.borrow_mut().push(message);
         }
     }
 
+    /// Report the `eslint-disable` directives which did not suppress any diagnostic,
+    /// must be called after running all the rules.
+    pub fn report_unused_disable_directives(&self) {
+        let unused = self.disable_directives.unused_directives();
+        self.diagnostics
+            .borrow_mut()
+            .extend(unused.into_iter().map(|diagnostic| Message::new(diagnostic.into(), None)));
+    }
+
     pub fn diagnostic<T: Into<Error>>(&self, diagnostic: T) {
         self.add_diagnostic(Message::new(diagnostic.into(), None));
     }
 
+    /// Report `diagnostic` with a fix preserving the behavior of the code.
     pub fn diagnostic_with_fix<T, F>(&self, diagnostic: T, fix: F)
     where
         T: Into<Error>,
         F: FnOnce() -> Fix<'a>,
     {
-        if self.fix {
-            self.add_diagnostic(Message::new(diagnostic.into(), Some(fix())));
+        self.diagnostic_with_fix_of_kind(diagnostic, FixKind::Safe, fix);
+    }
+
+    /// Report `diagnostic` with a fix which may change the behavior of the code,
+    /// only applied with `FixKind::Suggestion` or above.
+    pub fn diagnostic_with_suggestion<T, F>(&self, diagnostic: T, fix: F)
+    where
+        T: Into<Error>,
+        F: FnOnce() -> Fix<'a>,
+    {
+        self.diagnostic_with_fix_of_kind(diagnostic, FixKind::Suggestion, fix);
+    }
+
+    /// Report `diagnostic` with a fix which may break the code,
+    /// only applied with `FixKind::Dangerous`.
+    pub fn diagnostic_with_dangerous_fix<T, F>(&self, diagnostic: T, fix: F)
+    where
+        T: Into<Error>,
+        F: FnOnce() -> Fix<'a>,
+    {
+        self.diagnostic_with_fix_of_kind(diagnostic, FixKind::Dangerous, fix);
+    }
+
+    fn diagnostic_with_fix_of_kind<T, F>(&self, diagnostic: T, kind: FixKind, fix: F)
+    where
+        T: Into<Error>,
+        F: FnOnce() -> Fix<'a>,
+    {
+        if self.fix && kind <= self.fix_kind {
+            self.add_diagnostic(Message::new(diagnostic.into(), Some(fix().with_kind(kind))));
         } else {
             self.diagnostic(diagnostic);
         }
@@ -149,6 +384,14 @@ impl<'a> LintContext<'a> {
         self.semantic().symbols()
     }
 
+    pub fn cfg(&self) -> &ControlFlowGraph {
+        self.semantic().cfg()
+    }
+
+    pub fn module_record(&self) -> &ModuleRecord {
+        self.semantic().module_record()
+    }
+
     #[allow(clippy::unused_self)]
     pub fn codegen(&self) -> Codegen<false> {
         Codegen::<false>::new(0, CodegenOptions::default())
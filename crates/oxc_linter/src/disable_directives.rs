@@ -1,9 +1,20 @@
+use std::cell::RefCell;
+
 use oxc_ast::TriviasMap;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unused eslint-disable directive (no problems were reported{0}).")]
+#[diagnostic(severity(warning))]
+pub struct UnusedDisableDirectiveDiagnostic(String, #[label] pub Span);
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 enum DisabledRule<'a> {
     All,
     Single(&'a str),
@@ -17,9 +28,14 @@ pub struct DisableRuleComment<'a> {
     pub rules: Vec<&'a str>,
 }
 
+/// A rule disabled by a comment, identified by the span of the comment
+type Directive<'a> = (DisabledRule<'a>, Span);
+
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
+    intervals: Lapper<u32, Directive<'a>>,
+    /// Directives which suppressed at least one diagnostic
+    used: RefCell<FxHashSet<Directive<'a>>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -28,13 +44,52 @@ pub struct DisableDirectives<'a> {
 
 impl<'a> DisableDirectives<'a> {
     pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
-                // Our rule name currently does not contain the prefix.
-                // For example, this will match `@typescript-eslint/no-var-requires` given
-                // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+        let mut contains = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let (rule, _) = interval.val;
+            // Our rule name currently does not contain the prefix.
+            // For example, this will match `@typescript-eslint/no-var-requires` given
+            // our rule_name is `no-var-requires`.
+            if rule == DisabledRule::All
+                || matches!(rule, DisabledRule::Single(name) if name.contains(rule_name))
+            {
+                self.used.borrow_mut().insert(interval.val);
+                contains = true;
+            }
+        }
+        contains
+    }
+
+    /// Diagnostics for the directives which have not suppressed anything so far,
+    /// in the order of the comments.
+    pub fn unused_directives(&self) -> Vec<UnusedDisableDirectiveDiagnostic> {
+        let used = self.used.borrow();
+        let mut unused = self
+            .intervals
+            .iter()
+            .map(|interval| interval.val)
+            .filter(|directive| !used.contains(directive))
+            .collect::<Vec<_>>();
+        unused.sort_unstable_by_key(|(rule, span)| {
+            (
+                span.start,
+                match rule {
+                    DisabledRule::All => "",
+                    DisabledRule::Single(name) => *name,
+                },
+            )
+        });
+        unused.dedup();
+        unused
+            .into_iter()
+            .map(|(rule, span)| {
+                let from = match rule {
+                    DisabledRule::All => String::new(),
+                    DisabledRule::Single(name) => format!(" from '{name}'"),
+                };
+                UnusedDisableDirectiveDiagnostic(from, span)
+            })
+            .collect()
     }
 
     pub fn disable_all_comments(&self) -> &Vec<Span> {
@@ -46,15 +101,32 @@ impl<'a> DisableDirectives<'a> {
     }
 }
 
+/// The `eslint-disable`, `eslint-disable-line` and `eslint-disable-next-line` comments of a source,
+/// in the order of the comments. The comments disabling all rules have no rules.
+pub fn disable_comments<'a>(
+    source_text: &'a str,
+    trivias: &TriviasMap,
+) -> Vec<DisableRuleComment<'a>> {
+    let directives = DisableDirectivesBuilder::new(source_text, trivias).build();
+    let mut comments = directives
+        .disable_all_comments
+        .into_iter()
+        .map(|span| DisableRuleComment { span, rules: vec![] })
+        .chain(directives.disable_rule_comments)
+        .collect::<Vec<_>>();
+    comments.sort_unstable_by_key(|comment| comment.span.start);
+    comments
+}
+
 pub struct DisableDirectivesBuilder<'a, 'b> {
     source_text: &'a str,
     trivias: &'b TriviasMap,
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    intervals: Lapper<u32, Directive<'a>>,
+    /// Comment of the pending `eslint-disable`
+    disable_all_start: Option<Span>,
+    /// Comments of the pending `eslint-disable rule_name`
+    disable_start_map: FxHashMap<&'a str, Span>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -78,13 +150,14 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         self.build_impl();
         DisableDirectives {
             intervals: self.intervals,
+            used: RefCell::default(),
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
         }
     }
 
-    fn add_interval(&mut self, start: u32, stop: u32, val: DisabledRule<'a>) {
-        self.intervals.insert(Interval { start, stop, val });
+    fn add_interval(&mut self, start: u32, stop: u32, rule: DisabledRule<'a>, comment: Span) {
+        self.intervals.insert(Interval { start, stop, val: (rule, comment) });
     }
 
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
@@ -102,7 +175,7 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                 // `eslint-disable`
                 if text.trim().is_empty() {
                     if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
+                        self.disable_all_start = Some(span);
                     }
                     self.disable_all_comments.push(span);
                     continue;
@@ -116,13 +189,18 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        self.add_interval(span.end, stop, DisabledRule::All, span);
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
+                            self.add_interval(
+                                span.end,
+                                stop,
+                                DisabledRule::Single(rule_name),
+                                span,
+                            );
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -141,13 +219,13 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        self.add_interval(start, stop, DisabledRule::All, span);
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
+                            self.add_interval(start, stop, DisabledRule::Single(rule_name), span);
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -158,7 +236,7 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                 // `eslint-disable rule-name1, rule-name2`
                 let mut rules = vec![];
                 Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.entry(rule_name).or_insert(span.end);
+                    self.disable_start_map.entry(rule_name).or_insert(span);
                     rules.push(rule_name);
                 });
                 self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -169,14 +247,15 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
             if let Some(text) = text.strip_prefix("eslint-enable") {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some(comment) = self.disable_all_start.take() {
+                        self.add_interval(comment.end, span.start, DisabledRule::All, comment);
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                        if let Some(comment) = self.disable_start_map.remove(rule_name) {
+                            let rule = DisabledRule::Single(rule_name);
+                            self.add_interval(comment.end, span.start, rule, comment);
                         }
                     });
                 }
@@ -185,14 +264,14 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some(comment) = self.disable_all_start {
+            self.add_interval(comment.end, source_len, DisabledRule::All, comment);
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (rule_name, comment) in disable_start_map {
+            self.add_interval(comment.end, source_len, DisabledRule::Single(rule_name), comment);
         }
     }
 
@@ -0,0 +1,146 @@
+//! Files are linted as UTF-8 without a byte order mark. Files with a byte order mark or encoded in
+//! UTF-16, such as files created on Windows, are transcoded when read, and fixed code is written
+//! back in the encoding of the file.
+
+use std::io;
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+const UTF16_LE_BOM: &[u8] = b"\xFF\xFE";
+const UTF16_BE_BOM: &[u8] = b"\xFE\xFF";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    #[default]
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+}
+
+/// The encoding of a file, and whether the file starts with a byte order mark
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FileEncoding {
+    pub encoding: Encoding,
+    pub bom: bool,
+}
+
+impl FileEncoding {
+    /// Detected from the byte order mark. Without it, UTF-16 is detected from the zero byte of an
+    /// ASCII character at the start of the file, which JavaScript files do not have in UTF-8.
+    pub fn detect(bytes: &[u8]) -> Self {
+        let (encoding, bom) = if bytes.starts_with(UTF8_BOM) {
+            (Encoding::Utf8, true)
+        } else if bytes.starts_with(UTF16_LE_BOM) {
+            (Encoding::Utf16Le, true)
+        } else if bytes.starts_with(UTF16_BE_BOM) {
+            (Encoding::Utf16Be, true)
+        } else {
+            match bytes {
+                [first, 0, ..] if *first != 0 => (Encoding::Utf16Le, false),
+                [0, second, ..] if *second != 0 => (Encoding::Utf16Be, false),
+                _ => (Encoding::Utf8, false),
+            }
+        };
+        Self { encoding, bom }
+    }
+
+    fn bom(self) -> &'static [u8] {
+        match (self.encoding, self.bom) {
+            (_, false) => &[],
+            (Encoding::Utf8, true) => UTF8_BOM,
+            (Encoding::Utf16Le, true) => UTF16_LE_BOM,
+            (Encoding::Utf16Be, true) => UTF16_BE_BOM,
+        }
+    }
+
+    /// Length of the byte order mark in bytes, `0` without it
+    pub fn bom_len(self) -> usize {
+        self.bom().len()
+    }
+
+    /// The text of the file without the byte order mark.
+    ///
+    /// # Errors
+    ///
+    /// When `bytes` are not valid in the encoding, with the same kind of error as
+    /// `fs::read_to_string`.
+    pub fn decode(self, mut bytes: Vec<u8>) -> io::Result<String> {
+        let invalid = |encoding| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("stream did not contain valid {encoding}"),
+            )
+        };
+        let from_bytes: fn([u8; 2]) -> u16 = match self.encoding {
+            Encoding::Utf8 => {
+                bytes.drain(..self.bom_len());
+                return String::from_utf8(bytes).map_err(|_| invalid("UTF-8"));
+            }
+            Encoding::Utf16Le => u16::from_le_bytes,
+            Encoding::Utf16Be => u16::from_be_bytes,
+        };
+        // `None` for an odd byte at the end of the file
+        let units = bytes[self.bom_len()..]
+            .chunks(2)
+            .map(|unit| Some(from_bytes(unit.try_into().ok()?)))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| invalid("UTF-16"))?;
+        String::from_utf16(&units).map_err(|_| invalid("UTF-16"))
+    }
+
+    /// `text` encoded in the encoding of the file, with its byte order mark
+    pub fn encode(self, text: &str) -> Vec<u8> {
+        let mut bytes = self.bom().to_vec();
+        match self.encoding {
+            Encoding::Utf8 => bytes.extend_from_slice(text.as_bytes()),
+            Encoding::Utf16Le => bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes)),
+            Encoding::Utf16Be => bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes)),
+        }
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Encoding, FileEncoding};
+
+    #[test]
+    fn detect() {
+        let detect = |bytes: &[u8]| {
+            let encoding = FileEncoding::detect(bytes);
+            (encoding.encoding, encoding.bom)
+        };
+        assert_eq!(detect(b"let a;"), (Encoding::Utf8, false));
+        assert_eq!(detect(b""), (Encoding::Utf8, false));
+        assert_eq!(detect(b"\xEF\xBB\xBFlet a;"), (Encoding::Utf8, true));
+        assert_eq!(detect(b"\xFF\xFEl\0e\0t\0"), (Encoding::Utf16Le, true));
+        assert_eq!(detect(b"\xFE\xFF\0l\0e\0t"), (Encoding::Utf16Be, true));
+        assert_eq!(detect(b"l\0e\0t\0"), (Encoding::Utf16Le, false));
+        assert_eq!(detect(b"\0l\0e\0t"), (Encoding::Utf16Be, false));
+    }
+
+    #[test]
+    fn round_trip() {
+        for bytes in [
+            &b"let a = '\xC3\xA9';"[..],
+            b"\xEF\xBB\xBFlet a;",
+            b"\xFF\xFEl\0e\0t\0 \0a\0;\0",
+            b"\xFE\xFF\0l\0e\0t\0 \0a\0;",
+            b"l\0e\0t\0 \0a\0;\0",
+            b"\xFF\xFE=\xD8\0\xDE",
+        ] {
+            let encoding = FileEncoding::detect(bytes);
+            let text = encoding.decode(bytes.to_vec()).unwrap();
+            assert!(!text.starts_with('\u{feff}'));
+            assert_eq!(encoding.encode(&text), bytes);
+        }
+        assert_eq!(FileEncoding::detect(b"\xFF\xFE").decode(b"\xFF\xFE".to_vec()).unwrap(), "");
+    }
+
+    #[test]
+    fn invalid() {
+        for bytes in [&b"\xFF\xFEl\0e"[..], b"\xFF\xFE\0\xD8", b"\xEF\xBB\xBF\xFF"] {
+            let err = FileEncoding::detect(bytes).decode(bytes.to_vec()).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+    }
+}
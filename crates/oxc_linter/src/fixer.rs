@@ -1,21 +1,66 @@
 use std::borrow::Cow;
 
 use oxc_diagnostics::Error;
-use oxc_span::Span;
+use oxc_span::{LineEnding, Span};
+
+/// Whether applying a fix preserves the behavior of the code.
+/// Only safe fixes are applied by default, the other kinds have to be opted into.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FixKind {
+    /// Preserves the behavior of the code
+    #[default]
+    Safe,
+    /// May change the behavior of the code, e.g. removing a `debugger` statement
+    Suggestion,
+    /// May break the code, e.g. replacing `any` with `unknown` can introduce type errors
+    Dangerous,
+}
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
     pub span: Span,
+    pub kind: FixKind,
 }
 
 impl<'a> Fix<'a> {
     pub const fn delete(span: Span) -> Self {
-        Self { content: Cow::Borrowed(""), span }
+        Self { content: Cow::Borrowed(""), span, kind: FixKind::Safe }
     }
 
     pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
-        Self { content: content.into(), span }
+        Self { content: content.into(), span, kind: FixKind::Safe }
+    }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: FixKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// The fix without borrowing from the code, to keep it after the code is dropped.
+    pub fn into_owned(self) -> Fix<'static> {
+        Fix { content: Cow::Owned(self.content.into_owned()), span: self.span, kind: self.kind }
+    }
+
+    /// Combine the edits of `source_text` by `fixes` into one fix, replacing the text from the
+    /// start of the first edit to the end of the last one, for changes which have to be applied
+    /// together, e.g. at a declaration and at its references.
+    /// Returns `None` when there are no fixes or when they overlap.
+    pub fn merge(mut fixes: Vec<Self>, source_text: &str) -> Option<Self> {
+        fixes.sort_by_key(|fix| fix.span);
+        let start = fixes.first()?.span.start;
+        let mut content = String::new();
+        let mut end = start;
+        for fix in &fixes {
+            if fix.span.start < end {
+                return None;
+            }
+            content.push_str(&source_text[end as usize..fix.span.start as usize]);
+            content.push_str(&fix.content);
+            end = fix.span.end;
+        }
+        Some(Self::new(content, Span::new(start, end)))
     }
 }
 
@@ -55,18 +100,32 @@ impl<'a> Message<'a> {
     pub fn end(&self) -> u32 {
         self.end
     }
+
+    /// See `Fix::into_owned`.
+    pub fn into_owned(self) -> Message<'static> {
+        let Self { error, start, end, fix, fixed } = self;
+        Message { error, start, end, fix: fix.map(Fix::into_owned), fixed }
+    }
 }
 
 /// The fixer of the code.
-/// Note that our parser has handled the BOM, so we don't need to port the BOM test cases from `ESLint`.
+/// Note that the BOM is stripped when files are read and kept when fixed code is written back, see
+/// `encoding`, so we don't need to port the BOM test cases from `ESLint`.
+///
+/// The code outside of the fixes is left byte-identical. The content of the fixes is adapted to the
+/// code around them: their line breaks are the line breaks of the code, their lines are indented
+/// like the line they start on, and the presence of the final newline is kept.
 pub struct Fixer<'a> {
     source_text: &'a str,
     messages: Vec<Message<'a>>,
+    /// The line ending of the first line of the code, `\n` without line endings
+    line_ending: &'static str,
 }
 
 impl<'a> Fixer<'a> {
     pub fn new(source_text: &'a str, messages: Vec<Message<'a>>) -> Self {
-        Self { source_text, messages }
+        let line_ending = LineEnding::detect(source_text).unwrap_or_default().as_str();
+        Self { source_text, messages, line_ending }
     }
 
     /// # Panics
@@ -80,36 +139,93 @@ impl<'a> Fixer<'a> {
             };
         }
 
-        self.messages.sort_by_key(|m| m.fix.as_ref().unwrap_or(&Fix::default()).span);
+        // Of overlapping fixes, the one with the smallest span applies, then the one with the smallest content,
+        // so the same fixes apply whatever the order of the rules. The others are left to the next pass.
+        self.messages.sort_by(|a, b| match (&a.fix, &b.fix) {
+            (Some(a), Some(b)) => a.span.cmp(&b.span).then_with(|| a.content.cmp(&b.content)),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        });
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: i64 = -1;
-        self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
-            let start = span.start;
-            let end = span.end;
+        let mut last_fix: Option<Fix> = None;
+        let line_ending = self.line_ending;
+        for m in self.messages.iter_mut() {
+            let Some(fix) = &m.fix else {
+                continue;
+            };
+            let start = fix.span.start;
+            let end = fix.span.end;
             if start > end {
-                return;
+                continue;
             }
             if i64::from(start) <= last_pos {
-                return;
+                // The same fix reported more than once, e.g. by two rules, is applied once for all of them.
+                m.fixed = last_fix.as_ref() == Some(fix);
+                continue;
             }
 
             m.fixed = true;
             fixed = true;
             let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
             output.push_str(&source_text[offset..start as usize]);
-            output.push_str(content);
+            let indentation = Self::indentation(source_text, start);
+            output.push_str(&Self::format_content(&fix.content, line_ending, indentation));
             last_pos = i64::from(end);
-        });
+            last_fix = Some(fix.clone());
+        }
 
         let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
         output.push_str(&source_text[offset..]);
 
+        // A fix at the end of the code may add or remove the final newline
+        if source_text.ends_with('\n') {
+            if !output.is_empty() && !output.ends_with('\n') {
+                output.push_str(line_ending);
+            }
+        } else if let Some(trimmed) = output.strip_suffix('\n') {
+            output.truncate(trimmed.strip_suffix('\r').unwrap_or(trimmed).len());
+        }
+
         let mut messages = self.messages.into_iter().filter(|m| !m.fixed).collect::<Vec<_>>();
         messages.sort_by_key(|m| (m.start, m.end));
         return FixResult { fixed, fixed_code: Cow::Owned(output), messages };
     }
+
+    /// The indentation of the line containing `offset`
+    fn indentation(source_text: &str, offset: u32) -> &str {
+        let line_start = source_text[..offset as usize].rfind('\n').map_or(0, |i| i + 1);
+        let line = &source_text[line_start..];
+        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+    }
+
+    /// The content of a fix with the line breaks of the code. When its lines after the first one
+    /// are not indented at all, they are indented like the line the fix starts on.
+    fn format_content<'c>(content: &'c str, line_ending: &str, indentation: &str) -> Cow<'c, str> {
+        if !content.contains('\n') {
+            return Cow::Borrowed(content);
+        }
+        // The line breaks of template literals are part of their value
+        let indent = !indentation.is_empty()
+            && !content.contains('`')
+            && content.lines().skip(1).all(|line| !line.starts_with(char::is_whitespace));
+        let lines = content.split('\n').collect::<Vec<_>>();
+        let mut output = String::with_capacity(content.len());
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                output.push_str(line_ending);
+                if indent && !line.is_empty() {
+                    output.push_str(indentation);
+                }
+            }
+            if i + 1 < lines.len() {
+                output.push_str(line.strip_suffix('\r').unwrap_or(line));
+            } else {
+                output.push_str(line);
+            }
+        }
+        Cow::Owned(output)
+    }
 }
 
 #[cfg(test)]
@@ -123,38 +239,44 @@ mod test {
     };
     use oxc_span::Span;
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{Fix, FixKind, FixResult, Fixer, Message};
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("End")]
     struct InsertAtEnd;
-    const INSERT_AT_END: Fix = Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end") };
+    const INSERT_AT_END: Fix =
+        Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Start")]
     struct InsertAtStart;
-    const INSERT_AT_START: Fix = Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start") };
+    const INSERT_AT_START: Fix =
+        Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Multiply")]
     struct InsertAtMiddle;
-    const INSERT_AT_MIDDLE: Fix = Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *") };
+    const INSERT_AT_MIDDLE: Fix =
+        Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("foo")]
     struct ReplaceId;
-    const REPLACE_ID: Fix = Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo") };
+    const REPLACE_ID: Fix =
+        Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo"), kind: FixKind::Safe };
     #[derive(Debug, Error, Diagnostic)]
     #[error("let")]
     struct ReplaceVar;
-    const REPLACE_VAR: Fix = Fix { span: Span::new(0, 3), content: Cow::Borrowed("let") };
+    const REPLACE_VAR: Fix =
+        Fix { span: Span::new(0, 3), content: Cow::Borrowed("let"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("5")]
     struct ReplaceNum;
-    const REPLACE_NUM: Fix = Fix { span: Span::new(13, 14), content: Cow::Borrowed("5") };
+    const REPLACE_NUM: Fix =
+        Fix { span: Span::new(13, 14), content: Cow::Borrowed("5"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("removestart")]
@@ -174,7 +296,8 @@ mod test {
     #[derive(Debug, Error, Diagnostic)]
     #[error("reversed range")]
     struct ReverseRange;
-    const REVERSE_RANGE: Fix = Fix { span: Span::new(3, 0), content: Cow::Borrowed(" ") };
+    const REVERSE_RANGE: Fix =
+        Fix { span: Span::new(3, 0), content: Cow::Borrowed(" "), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("nofix")]
@@ -240,6 +363,18 @@ mod test {
         assert_eq!(result.messages.len(), 0);
     }
 
+    #[test]
+    fn merge_fixes() {
+        let fix = Fix::merge(vec![REPLACE_NUM, REPLACE_VAR, REPLACE_ID], TEST_CODE).unwrap();
+        assert_eq!(fix.span, Span::new(0, 14));
+        assert_eq!(fix.content, "let foo = 5");
+        let result = get_fix_result(vec![create_message(ReplaceVar, Some(fix))]);
+        assert_eq!(result.fixed_code, "let foo = 5 * 7;");
+
+        assert_eq!(Fix::merge(vec![REMOVE_MIDDLE, REPLACE_ID], TEST_CODE), None);
+        assert_eq!(Fix::merge(vec![], TEST_CODE), None);
+    }
+
     #[test]
     fn ignore_reverse_range() {
         let result = get_fix_result(vec![create_message(ReverseRange, Some(REVERSE_RANGE))]);
@@ -373,6 +508,40 @@ mod test {
         assert_eq!(result1.fixed_code, result2.fixed_code);
     }
 
+    #[test]
+    fn apply_same_fix_when_spans_are_equal_regardless_of_order() {
+        let replace_with_bar =
+            Fix { span: REPLACE_ID.span, content: Cow::Borrowed("bar"), kind: FixKind::Safe };
+        let result1 = get_fix_result(vec![
+            create_message(ReplaceId, Some(REPLACE_ID)),
+            create_message(NoFix(Span::default()), Some(replace_with_bar.clone())),
+        ]);
+        let result2 = get_fix_result(vec![
+            create_message(NoFix(Span::default()), Some(replace_with_bar)),
+            create_message(ReplaceId, Some(REPLACE_ID)),
+        ]);
+        assert_eq!(result1.fixed_code, TEST_CODE.replace("answer", "bar"));
+        assert_eq!(result1.fixed_code, result2.fixed_code);
+        assert_eq!(result1.messages.len(), 1);
+        assert_eq!(result1.messages[0].error.to_string(), "foo");
+    }
+
+    #[test]
+    fn apply_identical_fixes_once() {
+        let result = get_fix_result(vec![
+            create_message(ReplaceId, Some(REPLACE_ID)),
+            create_message(InsertAtEnd, Some(INSERT_AT_END)),
+            create_message(ReplaceId, Some(REPLACE_ID)),
+            create_message(InsertAtEnd, Some(INSERT_AT_END)),
+        ]);
+        assert_eq!(
+            result.fixed_code,
+            TEST_CODE.replace("answer", "foo") + INSERT_AT_END.content.as_ref()
+        );
+        assert_eq!(result.messages.len(), 0);
+        assert!(result.fixed);
+    }
+
     #[test]
     fn should_not_apply_fix_with_one_no_fix() {
         let result = get_fix_result(vec![create_message(NoFix(Span::default()), None)]);
@@ -382,6 +551,99 @@ mod test {
         assert!(!result.fixed);
     }
 
+    #[test]
+    fn keep_line_endings() {
+        let source_text = "var a;\r\nvar b;\r\n";
+        let fix = Fix::new("let b;\nlet c;", Span::new(8, 14));
+        let result =
+            Fixer::new(source_text, vec![create_message(NoFix(Span::default()), Some(fix))]).fix();
+        assert_eq!(result.fixed_code, "var a;\r\nlet b;\r\nlet c;\r\n");
+
+        let source_text = "var a;\nvar b;\n";
+        let fix = Fix::new("let b;\r\nlet c;", Span::new(7, 13));
+        let result =
+            Fixer::new(source_text, vec![create_message(NoFix(Span::default()), Some(fix))]).fix();
+        assert_eq!(result.fixed_code, "var a;\nlet b;\nlet c;\n");
+    }
+
+    #[test]
+    fn keep_indentation() {
+        let source_text = "if (a) {\n  foo();\n}\n";
+        let fix = |content| {
+            let fix = Fix::new(content, Span::new(11, 17));
+            Fixer::new(source_text, vec![create_message(NoFix(Span::default()), Some(fix))]).fix()
+        };
+        assert_eq!(fix("bar();\nbaz();").fixed_code, "if (a) {\n  bar();\n  baz();\n}\n");
+        assert_eq!(fix("{\n\nbar();\n}").fixed_code, "if (a) {\n  {\n\n  bar();\n  }\n}\n");
+        // Lines which are indented already are left as is
+        assert_eq!(fix("{\n    bar();\n  }").fixed_code, "if (a) {\n  {\n    bar();\n  }\n}\n");
+        assert_eq!(fix("`a\nb`").fixed_code, "if (a) {\n  `a\nb`\n}\n");
+    }
+
+    #[test]
+    fn keep_final_newline() {
+        let fix = |source_text, fix| {
+            Fixer::new(source_text, vec![create_message(NoFix(Span::default()), Some(fix))]).fix()
+        };
+        assert_eq!(fix("a;\nb;\n", Fix::delete(Span::new(2, 6))).fixed_code, "a;\n");
+        assert_eq!(fix("a;\r\nb;\r\n", Fix::delete(Span::new(2, 8))).fixed_code, "a;\r\n");
+        assert_eq!(fix("a;", Fix::new("\nb;\n", Span::new(2, 2))).fixed_code, "a;\nb;");
+        assert_eq!(fix("a;\n", Fix::delete(Span::new(0, 3))).fixed_code, "");
+    }
+
+    /// A xorshift generator, for reproducible random inputs
+    struct Random(u64);
+
+    impl Random {
+        fn below(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            usize::try_from(self.0 % u64::try_from(bound).unwrap()).unwrap()
+        }
+    }
+
+    #[test]
+    fn keep_code_outside_of_fixes() {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+        for _ in 0..1000 {
+            let line_ending = ["\n", "\r\n"][random.below(2)];
+            let mut source_text = String::new();
+            for _ in 0..=random.below(6) {
+                source_text.push_str(&" ".repeat(random.below(4)));
+                source_text.push_str(&"a = `b`;".repeat(random.below(3)));
+                source_text.push_str(line_ending);
+            }
+            if random.below(2) == 0 {
+                source_text.truncate(source_text.len() - line_ending.len());
+            }
+
+            // Fixes with code between them, which leave the last character as is
+            let mut messages = vec![];
+            let mut expected = String::new();
+            let mut position = random.below(3);
+            while position + 1 < source_text.len() {
+                let end = (position + random.below(4)).min(source_text.len() - 1);
+                let content = ["", "c", "d;", "e()"][random.below(4)];
+                let span = Span::new(u32::try_from(position).unwrap(), u32::try_from(end).unwrap());
+                messages
+                    .push(create_message(NoFix(Span::default()), Some(Fix::new(content, span))));
+                let last_end = messages
+                    .len()
+                    .checked_sub(2)
+                    .map_or(0, |i| messages[i].fix.as_ref().unwrap().span.end as usize);
+                expected.push_str(&source_text[last_end..position]);
+                expected.push_str(content);
+                position = end + 1 + random.below(3);
+            }
+            let last_end = messages.last().map_or(0, |m| m.fix.as_ref().unwrap().span.end as usize);
+            expected.push_str(&source_text[last_end..]);
+
+            let result = Fixer::new(&source_text, messages).fix();
+            assert_eq!(result.fixed_code, expected, "{source_text:?}");
+        }
+    }
+
     #[test]
     fn sort_no_fix_messages_correctly() {
         let result = get_fix_result(vec![
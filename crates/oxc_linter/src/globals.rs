@@ -15,6 +15,33 @@ pub const GLOBAL_OBJECT_NAMES: phf::Set<&'static str> = phf_set! {
     "window",
 };
 
+/// Compiler macros of Vue `<script setup>`, which are available without being imported.
+/// Reference: <https://vuejs.org/api/sfc-script-setup.html>
+pub static VUE_GLOBALS: Map<&'static str, bool> = phf_map! {
+    "defineProps" => false,
+    "defineEmits" => false,
+    "defineExpose" => false,
+    "defineOptions" => false,
+    "defineSlots" => false,
+    "defineModel" => false,
+    "withDefaults" => false,
+};
+
+/// Component variables and runes of Svelte, which are available without being imported.
+/// Reference: <https://svelte.dev/docs/svelte-components>
+pub static SVELTE_GLOBALS: Map<&'static str, bool> = phf_map! {
+    "$$props" => false,
+    "$$restProps" => false,
+    "$$slots" => false,
+    "$state" => false,
+    "$derived" => false,
+    "$effect" => false,
+    "$props" => false,
+    "$bindable" => false,
+    "$inspect" => false,
+    "$host" => false,
+};
+
 /// set of valid ARIA properties from the WAI-ARIA 1.1 specifications.
 /// Reference: <https://www.w3.org/TR/wai-aria/#state_prop_def>
 pub const VALID_ARIA_PROPS: phf::Set<&'static str> = phf_set! {
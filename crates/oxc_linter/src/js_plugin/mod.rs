@@ -0,0 +1,306 @@
+//! ESLint plugins written in JavaScript, listed in the `plugins` of the configuration file as
+//! CommonJS files such as `./eslint-plugin-acme.js`, for teams migrating their custom ESLint rules.
+//!
+//! The rules run in an embedded JavaScript runtime, one per thread, on the ESTree AST of
+//! `Program::to_estree_json`, passed in the binary encoding of `Program::to_estree_binary` and
+//! decoded once per file for all of them. Their listeners are called with the nodes of their type,
+//! on entering and on `:exit`, other selectors and `require` are not supported. Fixes are not
+//! applied.
+//! Native rules are much faster, JavaScript rules are meant for the rules without a native port.
+
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use boa_engine::{object::builtins::JsUint8Array, Context, JsString, JsValue, Source};
+use oxc_ast::{ast::Program, AstKind};
+use oxc_diagnostics::Error;
+use oxc_span::Span;
+use serde::Deserialize;
+
+use crate::{
+    plugin::{utf8_offset, FailedToLoadPluginError, PluginContext, PluginRule, RulePlugin},
+    rule::RuleCategory,
+};
+
+const RUNTIME: &str = include_str!("runtime.js");
+
+/// The iterations of a loop after which a rule throws, instead of hanging the linter
+const LOOP_ITERATION_LIMIT: u64 = 100_000_000;
+
+/// The depth of the calls after which a rule throws, instead of overflowing the stack.
+/// Walking the AST recurses once per level.
+const RECURSION_LIMIT: usize = 1024;
+
+thread_local! {
+    /// The runtime of the current thread, with the plugins loaded in it
+    static JS_RUNTIME: RefCell<Option<JsRuntime>> = RefCell::new(None);
+}
+
+struct JsRuntime {
+    context: Context<'static>,
+    /// The ids of the plugins loaded in `context`
+    loaded: Vec<u32>,
+    /// The path and the source text of the file whose AST is parsed in `context`
+    file: Option<(PathBuf, String)>,
+}
+
+impl JsRuntime {
+    fn new() -> Result<Self, String> {
+        let mut context = Context::default();
+        let limits = context.runtime_limits_mut();
+        limits.set_loop_iteration_limit(LOOP_ITERATION_LIMIT);
+        limits.set_recursion_limit(RECURSION_LIMIT);
+        context.eval(Source::from_bytes(RUNTIME)).map_err(|e| e.to_string())?;
+        Ok(Self { context, loaded: vec![], file: None })
+    }
+
+    /// Parse the AST of `program` in `context` for the rules linting the file at `path`,
+    /// unless it is already.
+    fn set_file(
+        &mut self,
+        path: &Path,
+        source_text: &str,
+        program: &Program,
+    ) -> Result<(), String> {
+        if self
+            .file
+            .as_ref()
+            .is_some_and(|(file_path, file_text)| file_path == path && file_text == source_text)
+        {
+            return Ok(());
+        }
+        // Not parsed for another file if this one fails
+        self.file = None;
+        let ast = program.to_estree_binary(source_text);
+        let ast = JsUint8Array::from_iter(ast, &mut self.context).map_err(|e| e.to_string())?;
+        let filename = path.to_string_lossy();
+        self.call("__oxcSetFile", &[ast.into(), js_string(source_text), js_string(&filename)])?;
+        self.file = Some((path.to_path_buf(), source_text.to_string()));
+        Ok(())
+    }
+
+    /// Call the global function `name` of `runtime.js`, which returns a string.
+    fn call(&mut self, name: &str, args: &[JsValue]) -> Result<String, String> {
+        let function = self.context.eval(Source::from_bytes(name)).map_err(|e| e.to_string())?;
+        let function = function.as_callable().ok_or_else(|| format!("{name} is not a function"))?;
+        let result = function
+            .call(&JsValue::undefined(), args, &mut self.context)
+            .map_err(|e| e.to_string())?;
+        match result.as_string() {
+            Some(string) => string.to_std_string().map_err(|e| e.to_string()),
+            None => Ok(String::new()),
+        }
+    }
+
+    /// Run `f` with the runtime of the current thread, created on first use.
+    fn with<T>(f: impl FnOnce(&mut Self) -> Result<T, String>) -> Result<T, String> {
+        JS_RUNTIME.with(|runtime| {
+            let mut runtime = runtime.borrow_mut();
+            if runtime.is_none() {
+                *runtime = Some(Self::new()?);
+            }
+            f(runtime.as_mut().unwrap())
+        })
+    }
+}
+
+fn js_string(string: &str) -> JsValue {
+    JsValue::from(JsString::from(string))
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginDescription {
+    name: Option<String>,
+    rules: Vec<RuleDescription>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RuleDescription {
+    name: String,
+    r#type: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Report {
+    message: String,
+    start: usize,
+    end: usize,
+}
+
+/// A plugin loaded from a JavaScript file
+#[derive(Debug)]
+pub struct JsPlugin {
+    name: &'static str,
+    rules: Vec<Arc<dyn PluginRule>>,
+}
+
+impl RulePlugin for JsPlugin {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn rules(&self) -> Vec<Arc<dyn PluginRule>> {
+        self.rules.clone()
+    }
+}
+
+/// The code of a plugin, evaluated in the runtime of each thread running its rules
+#[derive(Debug)]
+struct JsPluginSource {
+    id: u32,
+    path: PathBuf,
+    code: String,
+}
+
+#[derive(Debug)]
+struct JsRule {
+    source: Arc<JsPluginSource>,
+    name: &'static str,
+    category: RuleCategory,
+    documentation: Option<&'static str>,
+}
+
+/// Load the JavaScript plugin at `path`. Like plugin libraries, a plugin is loaded once per
+/// process, and the names of the plugin and of its rules are leaked.
+///
+/// # Errors
+///
+/// Returns `Err` if the file cannot be read or throws when evaluated.
+pub(crate) fn load_js_plugin(path: &Path, id: u32) -> Result<Arc<dyn RulePlugin>, Error> {
+    let error = |message: String| Error::new(FailedToLoadPluginError(path.to_path_buf(), message));
+    let code = fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+    let filename = path.to_string_lossy();
+    let description = JsRuntime::with(|runtime| {
+        runtime.call("__oxcDescribePlugin", &[js_string(&code), js_string(&filename)])
+    })
+    .map_err(error)?;
+    let description: PluginDescription =
+        serde_json::from_str(&description).map_err(|e| error(e.to_string()))?;
+
+    let name = description.name.unwrap_or_else(|| {
+        path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default()
+    });
+    let name = name.trim_start_matches("eslint-plugin-").to_string();
+    let source = Arc::new(JsPluginSource { id, path: path.to_path_buf(), code });
+    let rules = description
+        .rules
+        .into_iter()
+        .map(|rule| {
+            let category = match rule.r#type.as_deref() {
+                Some("problem") => RuleCategory::Correctness,
+                Some("layout") => RuleCategory::Style,
+                _ => RuleCategory::Restriction,
+            };
+            Arc::new(JsRule {
+                source: Arc::clone(&source),
+                name: leak(rule.name),
+                category,
+                documentation: rule.description.map(leak),
+            }) as Arc<dyn PluginRule>
+        })
+        .collect();
+    Ok(Arc::new(JsPlugin { name: leak(name), rules }))
+}
+
+fn leak(string: String) -> &'static str {
+    Box::leak(string.into_boxed_str())
+}
+
+impl PluginRule for JsRule {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn category(&self) -> RuleCategory {
+        self.category
+    }
+
+    fn documentation(&self) -> Option<&'static str> {
+        self.documentation
+    }
+
+    fn run_once(&self, ctx: &PluginContext<'_, '_>) {
+        let source_text = ctx.source_text();
+        let Some(AstKind::Program(program)) = ctx.nodes().iter().next().map(|node| node.kind())
+        else {
+            return;
+        };
+        let options = serde_json::to_string(ctx.options()).unwrap_or_default();
+        let result = JsRuntime::with(|runtime| {
+            if !runtime.loaded.contains(&self.source.id) {
+                let path = self.source.path.to_string_lossy();
+                let id = JsValue::from(self.source.id);
+                let code = js_string(&self.source.code);
+                runtime.call("__oxcLoadPlugin", &[id, code, js_string(&path)])?;
+                runtime.loaded.push(self.source.id);
+            }
+            runtime.set_file(ctx.file_path(), source_text, program)?;
+            let args = [JsValue::from(self.source.id), js_string(self.name), js_string(&options)];
+            runtime.call("__oxcRunRule", &args)
+        });
+        let reports = result.and_then(|reports| {
+            serde_json::from_str::<Vec<Report>>(&reports).map_err(|e| e.to_string())
+        });
+        match reports {
+            Ok(reports) => {
+                for report in reports {
+                    let start = utf8_offset(source_text, report.start);
+                    let end = utf8_offset(source_text, report.end);
+                    ctx.report(report.message, Span::new(start, end));
+                }
+            }
+            Err(error) => ctx.report(format!("The rule threw an error: {error}"), Span::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::load_js_plugin;
+    use crate::{LintContext, Linter};
+
+    #[test]
+    fn js_plugin() {
+        let path = Path::new("fixtures/js_plugin/eslint-plugin-acme.js");
+        let plugin = load_js_plugin(path, u32::MAX).unwrap();
+        assert_eq!(plugin.name(), "acme");
+        assert_eq!(plugin.rules()[0].name(), "no-foo");
+
+        let source_text = "let é = foo; foo.bar;";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            Rc::new(SemanticBuilder::new(source_text, source_type).build(program).semantic);
+        let linter = Linter::default()
+            .with_plugin(Arc::clone(&plugin))
+            .with_config_json(serde_json::json!({ "rules": { "acme/no-foo": ["error", "foo"] } }))
+            .unwrap();
+        let messages = linter.run(LintContext::new(Path::new("test.js").into(), &semantic));
+        let messages = messages
+            .iter()
+            .filter(|message| message.error.to_string().starts_with("acme"))
+            .map(|message| (message.error.to_string(), message.start()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                ("acme(no-foo): Unexpected foo in let é = foo;.".to_string(), 9),
+                ("acme(no-foo): Unexpected foo in foo.bar;.".to_string(), 14),
+            ]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+// The ESLint API of the rules of JavaScript plugins, evaluated once in the runtime of each thread.
+// Rust calls `__oxcLoadPlugin` with the code of a plugin, `__oxcSetFile` with the ESTree AST of each
+// linted file in the binary encoding of `Program::to_estree_binary`, then `__oxcRunRule` for each rule. Reports are returned as JSON, with the offsets in
+// UTF-16 code units.
+(function (global) {
+  const plugins = new Map();
+
+  // The file linted by `__oxcRunRule`, its AST is parsed once for all the rules
+  let file = null;
+
+  const SKIPPED_KEYS = new Set(['parent', 'loc', 'range']);
+
+  function evaluate(code, filename) {
+    const module = { exports: {} };
+    const require = (specifier) => {
+      throw new Error(`require('${specifier}') is not supported in ${filename}`);
+    };
+    new Function('module', 'exports', 'require', code)(module, module.exports, require);
+    const plugin = module.exports;
+    return plugin && plugin.default ? plugin.default : plugin;
+  }
+
+  // The name and the rules of the plugin, without loading it in this runtime.
+  global.__oxcDescribePlugin = function (code, filename) {
+    const plugin = evaluate(code, filename);
+    const rules = Object.entries(plugin.rules || {}).map(([name, rule]) => ({
+      name,
+      type: (rule.meta && rule.meta.type) || null,
+      description: (rule.meta && rule.meta.docs && rule.meta.docs.description) || null,
+    }));
+    return JSON.stringify({ name: (plugin.meta && plugin.meta.name) || null, rules });
+  };
+
+  global.__oxcLoadPlugin = function (id, code, filename) {
+    if (!plugins.has(id)) {
+      plugins.set(id, evaluate(code, filename));
+    }
+  };
+
+  // See `oxc_ast::ESTREE_BINARY_VERSION`
+  const ESTREE_BINARY_VERSION = 1;
+
+  // Decodes a `Uint8Array` encoded by `Program::to_estree_binary`, see `oxc_ast::binary` for the
+  // layout. Varints are decoded with arithmetic, as the bitwise operators truncate to 32 bits.
+  function decodeAst(bytes) {
+    let position = 0;
+    const byte = () => {
+      if (position >= bytes.length) throw new Error('Invalid binary AST');
+      return bytes[position++];
+    };
+    const varint = () => {
+      let value = 0;
+      for (let scale = 1; ; scale *= 128) {
+        const b = byte();
+        value += (b & 0x7f) * scale;
+        if (b < 0x80) return value;
+      }
+    };
+    const utf8 = (end) => {
+      let string = '';
+      while (position < end) {
+        let c = byte();
+        if (c >= 0xf0) {
+          c = ((c & 0x07) << 18) | ((byte() & 0x3f) << 12) | ((byte() & 0x3f) << 6) | (byte() & 0x3f);
+        } else if (c >= 0xe0) {
+          c = ((c & 0x0f) << 12) | ((byte() & 0x3f) << 6) | (byte() & 0x3f);
+        } else if (c >= 0xc0) {
+          c = ((c & 0x1f) << 6) | (byte() & 0x3f);
+        }
+        string += String.fromCodePoint(c);
+      }
+      return string;
+    };
+
+    const magic = 'OXAST';
+    for (let i = 0; i < magic.length; i++) {
+      if (byte() !== magic.charCodeAt(i)) throw new Error('Not a binary AST');
+    }
+    const version = byte();
+    if (version !== ESTREE_BINARY_VERSION) {
+      throw new Error(`Unsupported binary AST version ${version}`);
+    }
+    const strings = [];
+    for (let count = varint(); count > 0; count--) {
+      const length = varint();
+      strings.push(utf8(position + length));
+    }
+    const string = () => strings[varint()];
+    const value = () => {
+      switch (byte()) {
+        case 0:
+          return null;
+        case 1:
+          return false;
+        case 2:
+          return true;
+        case 3: {
+          const zigzag = varint();
+          return zigzag % 2 === 0 ? zigzag / 2 : -(zigzag + 1) / 2;
+        }
+        case 4: {
+          const view = new DataView(bytes.buffer, bytes.byteOffset + position, 8);
+          position += 8;
+          return view.getFloat64(0, true);
+        }
+        case 5:
+          return string();
+        case 6: {
+          const array = [];
+          for (let length = varint(); length > 0; length--) array.push(value());
+          return array;
+        }
+        case 7: {
+          const object = {};
+          for (let length = varint(); length > 0; length--) {
+            const key = string();
+            object[key] = value();
+          }
+          return object;
+        }
+        default:
+          throw new Error('Invalid binary AST');
+      }
+    };
+    return value();
+  }
+
+  function isNode(value) {
+    return value !== null && typeof value === 'object' && typeof value.type === 'string';
+  }
+
+  function walk(node, parent, enter, exit) {
+    node.parent = parent;
+    enter(node);
+    for (const key of Object.keys(node)) {
+      if (SKIPPED_KEYS.has(key)) continue;
+      const value = node[key];
+      if (Array.isArray(value)) {
+        for (const child of value) {
+          if (isNode(child)) walk(child, node, enter, exit);
+        }
+      } else if (isNode(value)) {
+        walk(value, node, enter, exit);
+      }
+    }
+    exit(node);
+  }
+
+  function createSourceCode(text, ast) {
+    return {
+      text,
+      ast,
+      getText(node) {
+        return node ? text.slice(node.range[0], node.range[1]) : text;
+      },
+      getAllComments() {
+        return ast.comments || [];
+      },
+    };
+  }
+
+  function message(rule, descriptor) {
+    let text = descriptor.message;
+    if (descriptor.messageId !== undefined) {
+      text = rule.meta && rule.meta.messages && rule.meta.messages[descriptor.messageId];
+      if (text === undefined) {
+        throw new Error(`Unknown messageId '${descriptor.messageId}'`);
+      }
+    }
+    const data = descriptor.data || {};
+    return String(text).replace(/\{\{\s*([^{}]+?)\s*\}\}/g, (placeholder, name) =>
+      name in data ? String(data[name]) : placeholder,
+    );
+  }
+
+  global.__oxcSetFile = function (astBytes, text, filename) {
+    file = null;
+    const ast = decodeAst(astBytes);
+    file = { ast, filename, sourceCode: createSourceCode(text, ast) };
+  };
+
+  global.__oxcRunRule = function (id, ruleName, optionsJson) {
+    const rule = plugins.get(id).rules[ruleName];
+    const { ast, filename, sourceCode } = file;
+    const options = JSON.parse(optionsJson);
+    const reports = [];
+    const context = {
+      id: ruleName,
+      options: Array.isArray(options) ? options : options === null ? [] : [options],
+      filename,
+      physicalFilename: filename,
+      sourceCode,
+      getFilename: () => filename,
+      getSourceCode: () => sourceCode,
+      report(descriptor) {
+        const range = descriptor.node ? descriptor.node.range : [0, 0];
+        reports.push({ message: message(rule, descriptor), start: range[0], end: range[1] });
+      },
+    };
+    const create = typeof rule === 'function' ? rule : rule.create;
+    const listeners = create(context) || {};
+    const call = (name, node) => {
+      const listener = listeners[name];
+      if (typeof listener === 'function') listener(node);
+    };
+    walk(
+      ast,
+      null,
+      (node) => call(node.type, node),
+      (node) => call(`${node.type}:exit`, node),
+    );
+    return JSON.stringify(reports);
+  };
+})(globalThis);
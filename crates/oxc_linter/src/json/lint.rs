@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use oxc_diagnostics::{with_rule, with_severity, Error, Severity};
+
+use super::{parse_json, JsonDialect};
+use crate::{
+    rule::RuleMeta,
+    rules::{NoDuplicateKeys, Schema},
+};
+
+/// Lints the JSON file at `path` with the rules of the `json` plugin: duplicate keys, and values
+/// of the wrong type in `package.json`, `tsconfig.json`, `jsconfig.json` and lint configuration
+/// files. Syntax errors are always reported.
+/// The dialect is chosen by `JsonDialect::from_path`, defaulting to JSON.
+///
+/// `severity` is the configured severity of an enabled rule, see `Linter::lint_json`,
+/// `None` for a disabled rule.
+pub fn lint_json<F>(path: &Path, source_text: &str, severity: F) -> Vec<Error>
+where
+    F: Fn(&str) -> Option<Option<Severity>>,
+{
+    let dialect = JsonDialect::from_path(path).unwrap_or(JsonDialect::Json);
+    let value = match parse_json(source_text, dialect) {
+        Ok(value) => value,
+        Err(error) => return vec![Error::new(error)],
+    };
+    let mut errors = vec![];
+    let mut run = |rule_name: &'static str, check: &dyn Fn(&mut Vec<Error>)| {
+        let Some(rule_severity) = severity(rule_name) else { return };
+        let mut rule_errors = vec![];
+        check(&mut rule_errors);
+        errors.extend(rule_errors.into_iter().map(|error| {
+            let error = match rule_severity {
+                Some(rule_severity) => with_severity(error, rule_severity),
+                None => error,
+            };
+            with_rule(error, "json", rule_name)
+        }));
+    };
+    run(NoDuplicateKeys::NAME, &|errors| NoDuplicateKeys::check(&value, errors));
+    run(Schema::NAME, &|errors| Schema::check(path, &value, errors));
+    errors
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::Severity;
+
+    use super::lint_json;
+
+    fn messages(file_name: &str, source_text: &str) -> Vec<String> {
+        let errors = lint_json(Path::new(file_name), source_text, |_| Some(None));
+        errors.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        assert_eq!(
+            messages("data.json", r#"{ "a": 1, "b": { "c": 1, "c": 2 }, "a": 3 }"#),
+            vec![
+                "json(no-duplicate-keys): Duplicate key `c`",
+                "json(no-duplicate-keys): Duplicate key `a`"
+            ]
+        );
+        assert!(messages("data.json", r#"[{ "a": 1 }, { "a": 2 }]"#).is_empty());
+    }
+
+    #[test]
+    fn syntax_errors() {
+        assert_eq!(
+            messages("data.json", "{ \"a\": 1, }"),
+            vec!["Trailing commas are not allowed in JSON"]
+        );
+        assert!(
+            messages("tsconfig.json", "{\n  // comment\n  \"include\": [\"src\"],\n}").is_empty()
+        );
+        assert!(messages("data.json5", "{ a: 'b' }").is_empty());
+    }
+
+    #[test]
+    fn schemas() {
+        assert_eq!(
+            messages(
+                "package.json",
+                r#"{ "name": 1, "type": "esm", "dependencies": { "a": "1", "b": true }, "bin": { "c": 2 } }"#
+            ),
+            vec![
+                "json(schema): `name` should be a string, but is a number",
+                r#"json(schema): `type` should be one of "module", "commonjs", but is a string"#,
+                "json(schema): `dependencies.b` should be a string, but is a boolean",
+                "json(schema): `bin.c` should be a string, but is a number",
+            ]
+        );
+        assert_eq!(
+            messages(
+                "tsconfig.base.json",
+                r#"{ "compilerOptions": { "strict": "true" }, "references": [{ "path": 1 }] }"#
+            ),
+            vec![
+                "json(schema): `compilerOptions.strict` should be a boolean, but is a string",
+                "json(schema): `references[0].path` should be a string, but is a number",
+            ]
+        );
+        assert_eq!(
+            messages(
+                ".oxlintrc.json",
+                r#"{ "rules": { "eqeqeq": "warn", "no-debugger": 2, "no-console": "on" } }"#
+            ),
+            vec![
+                r#"json(schema): `rules.no-console` should be one of "off", "allow", "warn", "error", "deny", "0", "1", "2" or an array, but is a string"#,
+            ]
+        );
+        assert!(messages("data.json", r#"{ "name": 1 }"#).is_empty());
+    }
+
+    #[test]
+    fn severities() {
+        let source_text = r#"{ "name": 1, "name": "a" }"#;
+        let severity = |rule_name: &str| (rule_name == "schema").then_some(Some(Severity::Error));
+        let errors = lint_json(Path::new("package.json"), source_text, severity);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].to_string(),
+            "json(schema): `name` should be a string, but is a number"
+        );
+        assert_eq!(errors[0].severity(), Some(Severity::Error));
+    }
+}
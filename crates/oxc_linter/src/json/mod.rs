@@ -0,0 +1,140 @@
+//! JSON, JSONC and JSON5 files, parsed into values which keep the spans of their source text,
+//! for linting `package.json`, `tsconfig.json` and configuration files.
+
+mod lint;
+mod parser;
+
+use std::path::Path;
+
+use oxc_span::Span;
+
+pub use self::{
+    lint::lint_json,
+    parser::{parse_json, JsonParseError},
+};
+
+/// The file extensions linted as JSON
+pub const JSON_EXT: &[&str] = &["json", "jsonc", "json5"];
+
+/// `.json` files which are read with comments and trailing commas by their tools
+const JSONC_FILE_NAMES: [&str; 3] = [".eslintrc.json", ".oxlintrc.json", ".babelrc.json"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum JsonDialect {
+    /// Strict JSON
+    Json,
+    /// JSON with comments and trailing commas, as read by TypeScript and VS Code
+    Jsonc,
+    /// <https://spec.json5.org>, which also has unquoted keys, single quoted strings,
+    /// hexadecimal numbers, `Infinity` and `NaN`
+    Json5,
+}
+
+impl JsonDialect {
+    /// The dialect of the file at `path`, `None` if it is not a JSON file.
+    /// `tsconfig.json` and `jsconfig.json`, including their variants such as `tsconfig.base.json`,
+    /// and configuration files of tools which accept comments are JSONC.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?;
+        if JSONC_FILE_NAMES.contains(&name) {
+            return Some(Self::Jsonc);
+        }
+        match path.extension()?.to_str()? {
+            "json5" => Some(Self::Json5),
+            "jsonc" => Some(Self::Jsonc),
+            "json" if name.starts_with("tsconfig") || name.starts_with("jsconfig") => {
+                Some(Self::Jsonc)
+            }
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    fn allows_comments(self) -> bool {
+        self >= Self::Jsonc
+    }
+
+    fn allows_trailing_commas(self) -> bool {
+        self >= Self::Jsonc
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonValue {
+    pub span: Span,
+    pub kind: JsonValueKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValueKind {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// The members in source order, including the duplicated keys
+    Object(Vec<JsonMember>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonMember {
+    pub key: String,
+    pub key_span: Span,
+    pub value: JsonValue,
+}
+
+impl JsonValue {
+    /// The value of `key` in an object, the last one when the key is duplicated like `JSON.parse`
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match &self.kind {
+            JsonValueKind::Object(members) => {
+                members.iter().rev().find(|member| member.key == key).map(|member| &member.value)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.kind {
+            JsonValueKind::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// The name of the type of the value, for diagnostics
+    pub fn type_name(&self) -> &'static str {
+        match &self.kind {
+            JsonValueKind::Null => "null",
+            JsonValueKind::Bool(_) => "a boolean",
+            JsonValueKind::Number(_) => "a number",
+            JsonValueKind::String(_) => "a string",
+            JsonValueKind::Array(_) => "an array",
+            JsonValueKind::Object(_) => "an object",
+        }
+    }
+
+    /// The value without its spans.
+    /// Integers stay integers, and `Infinity` and `NaN` become `null` like in `JSON.stringify`.
+    pub fn to_serde(&self) -> serde_json::Value {
+        match &self.kind {
+            JsonValueKind::Null => serde_json::Value::Null,
+            JsonValueKind::Bool(value) => serde_json::Value::Bool(*value),
+            #[allow(clippy::cast_possible_truncation)]
+            JsonValueKind::Number(value) if value.fract() == 0.0 && value.abs() < 2f64.powi(53) => {
+                serde_json::Value::from(*value as i64)
+            }
+            JsonValueKind::Number(value) => serde_json::Number::from_f64(*value)
+                .map_or(serde_json::Value::Null, serde_json::Value::Number),
+            JsonValueKind::String(value) => serde_json::Value::String(value.clone()),
+            JsonValueKind::Array(values) => {
+                serde_json::Value::Array(values.iter().map(Self::to_serde).collect())
+            }
+            JsonValueKind::Object(members) => serde_json::Value::Object(
+                members
+                    .iter()
+                    .map(|member| (member.key.clone(), member.value.to_serde()))
+                    .collect(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,492 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_span::Span;
+use oxc_syntax::identifier::{is_identifier_part, is_identifier_start};
+
+use super::{JsonDialect, JsonMember, JsonValue, JsonValueKind};
+
+/// The deepest nesting of objects and arrays, the parser and the visitors of the values are
+/// recursive and would overflow the stack on deeper inputs.
+pub const MAX_NESTING_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
+#[error("{message}")]
+#[diagnostic()]
+pub struct JsonParseError {
+    pub message: String,
+    #[label]
+    pub span: Span,
+}
+
+/// Parses `source_text` as `dialect`. A leading byte order mark is skipped.
+///
+/// # Errors
+///
+/// Returns `Err` at the first syntax error.
+pub fn parse_json(source_text: &str, dialect: JsonDialect) -> Result<JsonValue, JsonParseError> {
+    let mut parser = JsonParser { source_text, dialect, pos: 0, depth: 0 };
+    if source_text.starts_with('\u{feff}') {
+        parser.pos = '\u{feff}'.len_utf8();
+    }
+    parser.skip_trivia()?;
+    let value = parser.parse_value()?;
+    parser.skip_trivia()?;
+    if parser.peek().is_some() {
+        return Err(parser.unexpected());
+    }
+    Ok(value)
+}
+
+struct JsonParser<'a> {
+    source_text: &'a str,
+    dialect: JsonDialect,
+    /// Byte offset of the next character
+    pos: usize,
+    /// The number of objects and arrays the next value is in
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn is_json5(&self) -> bool {
+        self.dialect == JsonDialect::Json5
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source_text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.pos += c.len_utf8();
+        }
+        eaten
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(start as u32, end as u32)
+    }
+
+    fn error(&self, message: impl Into<String>, start: usize, end: usize) -> JsonParseError {
+        JsonParseError { message: message.into(), span: self.span(start, end) }
+    }
+
+    /// The error at the next character, or at the end of the input
+    fn unexpected(&self) -> JsonParseError {
+        match self.peek() {
+            Some(c) => {
+                self.error(format!("Unexpected character `{c}`"), self.pos, self.pos + c.len_utf8())
+            }
+            None => self.error("Unexpected end of input", self.pos, self.pos),
+        }
+    }
+
+    /// Skips whitespace and comments
+    fn skip_trivia(&mut self) -> Result<(), JsonParseError> {
+        loop {
+            match self.peek() {
+                Some(' ' | '\t' | '\n' | '\r') => self.pos += 1,
+                Some(c) if self.is_json5() && (c.is_whitespace() || c == '\u{feff}') => {
+                    self.pos += c.len_utf8();
+                }
+                Some('/') => {
+                    let start = self.pos;
+                    let rest = &self.source_text[start..];
+                    let end = if rest.starts_with("//") {
+                        rest.find(['\n', '\r']).map_or(self.source_text.len(), |end| start + end)
+                    } else if rest.starts_with("/*") {
+                        match rest[2..].find("*/") {
+                            Some(end) => start + 2 + end + 2,
+                            None => {
+                                let end = self.source_text.len();
+                                return Err(self.error("Unterminated comment", start, end));
+                            }
+                        }
+                    } else {
+                        return Err(self.unexpected());
+                    };
+                    if !self.dialect.allows_comments() {
+                        return Err(self.error("Comments are not allowed in JSON", start, end));
+                    }
+                    self.pos = end;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, JsonParseError> {
+        let start = self.pos;
+        let json5 = self.is_json5();
+        let kind = match self.peek() {
+            Some(c @ ('{' | '[')) => {
+                if self.depth == MAX_NESTING_DEPTH {
+                    let message =
+                        format!("Nesting deeper than {MAX_NESTING_DEPTH} is not supported");
+                    return Err(self.error(message, start, start + 1));
+                }
+                self.depth += 1;
+                let kind = if c == '{' { self.parse_object() } else { self.parse_array() };
+                self.depth -= 1;
+                kind?
+            }
+            Some('"') => JsonValueKind::String(self.parse_string()?),
+            Some('\'') if json5 => JsonValueKind::String(self.parse_string()?),
+            Some(c)
+                if c == '-'
+                    || c.is_ascii_digit()
+                    || (json5 && matches!(c, '+' | '.' | 'I' | 'N')) =>
+            {
+                JsonValueKind::Number(self.parse_number()?)
+            }
+            Some(c) if c.is_ascii_alphabetic() => self.parse_literal()?,
+            _ => return Err(self.unexpected()),
+        };
+        Ok(JsonValue { span: self.span(start, self.pos), kind })
+    }
+
+    fn parse_literal(&mut self) -> Result<JsonValueKind, JsonParseError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric()) {
+            self.pos += 1;
+        }
+        match &self.source_text[start..self.pos] {
+            "null" => Ok(JsonValueKind::Null),
+            "true" => Ok(JsonValueKind::Bool(true)),
+            "false" => Ok(JsonValueKind::Bool(false)),
+            word => Err(self.error(format!("Unexpected `{word}`"), start, self.pos)),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValueKind, JsonParseError> {
+        self.pos += 1; // `{`
+        let mut members = vec![];
+        loop {
+            self.skip_trivia()?;
+            if self.eat('}') {
+                return Ok(JsonValueKind::Object(members));
+            }
+            let key_start = self.pos;
+            let key = match self.peek() {
+                Some('"') => self.parse_string()?,
+                Some('\'') if self.is_json5() => self.parse_string()?,
+                Some(c) if self.is_json5() && is_identifier_start(c) => self.parse_identifier(),
+                _ => return Err(self.unexpected()),
+            };
+            let key_span = self.span(key_start, self.pos);
+            self.skip_trivia()?;
+            if !self.eat(':') {
+                return Err(self.unexpected());
+            }
+            self.skip_trivia()?;
+            let value = self.parse_value()?;
+            members.push(JsonMember { key, key_span, value });
+            self.skip_trivia()?;
+            if self.eat('}') {
+                return Ok(JsonValueKind::Object(members));
+            }
+            self.parse_comma('}')?;
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValueKind, JsonParseError> {
+        self.pos += 1; // `[`
+        let mut values = vec![];
+        loop {
+            self.skip_trivia()?;
+            if self.eat(']') {
+                return Ok(JsonValueKind::Array(values));
+            }
+            values.push(self.parse_value()?);
+            self.skip_trivia()?;
+            if self.eat(']') {
+                return Ok(JsonValueKind::Array(values));
+            }
+            self.parse_comma(']')?;
+        }
+    }
+
+    /// The comma between two elements, which can only be followed by `close` in JSONC and JSON5
+    fn parse_comma(&mut self, close: char) -> Result<(), JsonParseError> {
+        let comma = self.pos;
+        if !self.eat(',') {
+            return Err(self.unexpected());
+        }
+        self.skip_trivia()?;
+        if self.peek() == Some(close) && !self.dialect.allows_trailing_commas() {
+            return Err(self.error("Trailing commas are not allowed in JSON", comma, comma + 1));
+        }
+        Ok(())
+    }
+
+    /// An unquoted key of JSON5
+    fn parse_identifier(&mut self) -> String {
+        let start = self.pos;
+        self.bump();
+        while self.peek().is_some_and(is_identifier_part) {
+            self.bump();
+        }
+        self.source_text[start..self.pos].to_string()
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonParseError> {
+        let start = self.pos;
+        let quote = self.bump();
+        let mut value = String::new();
+        loop {
+            let c_start = self.pos;
+            match self.bump() {
+                None | Some('\n' | '\r') => {
+                    return Err(self.error("Unterminated string", start, c_start));
+                }
+                Some(c) if Some(c) == quote => return Ok(value),
+                Some('\\') => self.parse_escape(&mut value)?,
+                Some(c) if c < ' ' && !self.is_json5() => {
+                    let message = "Control characters must be escaped in JSON strings";
+                    return Err(self.error(message, c_start, self.pos));
+                }
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self, value: &mut String) -> Result<(), JsonParseError> {
+        let start = self.pos - 1; // `\`
+        let json5 = self.is_json5();
+        let Some(c) = self.bump() else {
+            return Err(self.error("Unterminated string", start, self.pos));
+        };
+        let escaped = match c {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let code = self.parse_hex(4).ok_or_else(|| self.invalid_escape(start))?;
+                value.push(self.parse_surrogate_pair(code));
+                return Ok(());
+            }
+            '\'' if json5 => '\'',
+            'v' if json5 => '\u{b}',
+            '0' if json5 && !self.peek().is_some_and(|c| c.is_ascii_digit()) => '\0',
+            'x' if json5 => self
+                .parse_hex(2)
+                .and_then(char::from_u32)
+                .ok_or_else(|| self.invalid_escape(start))?,
+            // Line continuation
+            '\n' | '\u{2028}' | '\u{2029}' if json5 => return Ok(()),
+            '\r' if json5 => {
+                self.eat('\n');
+                return Ok(());
+            }
+            c if json5 && !c.is_ascii_digit() => c,
+            _ => return Err(self.invalid_escape(start)),
+        };
+        value.push(escaped);
+        Ok(())
+    }
+
+    fn invalid_escape(&self, start: usize) -> JsonParseError {
+        self.error("Invalid escape sequence", start, self.pos)
+    }
+
+    fn parse_hex(&mut self, len: usize) -> Option<u32> {
+        let digits = self.source_text.get(self.pos..self.pos + len)?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += len;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    /// The character of `\uXXXX`, combined with the low surrogate `\uXXXX` following a high one
+    fn parse_surrogate_pair(&mut self, high: u32) -> char {
+        if (0xD800..0xDC00).contains(&high) && self.source_text[self.pos..].starts_with("\\u") {
+            let pos = self.pos;
+            self.pos += 2;
+            match self.parse_hex(4) {
+                Some(low) if (0xDC00..0xE000).contains(&low) => {
+                    let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                    return char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+                }
+                _ => self.pos = pos,
+            }
+        }
+        char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn parse_number(&mut self) -> Result<f64, JsonParseError> {
+        let start = self.pos;
+        let json5 = self.is_json5();
+        let sign = match self.peek() {
+            Some('-') => -1.0,
+            Some('+') if json5 => 1.0,
+            _ => 0.0,
+        };
+        if sign != 0.0 {
+            self.pos += 1;
+        }
+        let sign = if sign < 0.0 { -1.0 } else { 1.0 };
+
+        let rest = &self.source_text[self.pos..];
+        if json5 {
+            for (word, value) in [("Infinity", f64::INFINITY), ("NaN", f64::NAN)] {
+                if rest.starts_with(word) {
+                    self.pos += word.len();
+                    return Ok(sign * value);
+                }
+            }
+            if rest.starts_with("0x") || rest.starts_with("0X") {
+                self.pos += 2;
+                let digits_start = self.pos;
+                self.skip_digits(|c| c.is_ascii_hexdigit());
+                let digits = &self.source_text[digits_start..self.pos];
+                return u64::from_str_radix(digits, 16)
+                    .map(|value| sign * value as f64)
+                    .map_err(|_| self.error("Invalid number", start, self.pos));
+            }
+        }
+
+        let digits_start = self.pos;
+        let integer_digits = self.skip_digits(|c| c.is_ascii_digit());
+        let invalid =
+            |parser: &Self| parser.error("Invalid number", start, parser.pos.max(start + 1));
+        if integer_digits > 1 && rest.starts_with('0') {
+            return Err(invalid(self));
+        }
+        if integer_digits == 0 && !(json5 && self.peek() == Some('.')) {
+            return Err(invalid(self));
+        }
+        if self.eat('.') {
+            let fraction_digits = self.skip_digits(|c| c.is_ascii_digit());
+            // `1.` and `.1` are JSON5
+            if fraction_digits == 0 && !(json5 && integer_digits > 0) {
+                return Err(invalid(self));
+            }
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.pos += 1;
+            }
+            if self.skip_digits(|c| c.is_ascii_digit()) == 0 {
+                return Err(invalid(self));
+            }
+        }
+        self.source_text[digits_start..self.pos]
+            .parse::<f64>()
+            .map(|value| sign * value)
+            .map_err(|_| invalid(self))
+    }
+
+    /// The number of digits skipped
+    fn skip_digits(&mut self, is_digit: impl Fn(char) -> bool) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(&is_digit) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::Span;
+
+    use super::{parse_json, MAX_NESTING_DEPTH};
+    use crate::json::{JsonDialect, JsonValueKind};
+
+    fn parse(source_text: &str, dialect: JsonDialect) -> Result<serde_json::Value, String> {
+        parse_json(source_text, dialect).map(|value| value.to_serde()).map_err(|e| e.message)
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            parse(r#"{ "a": [1, -2.5e1, true, false, null], "b": "\"é\n" }"#, JsonDialect::Json),
+            Ok(serde_json::json!({ "a": [1, -25, true, false, null], "b": "\"é\n" }))
+        );
+        assert_eq!(parse("\u{feff}[]", JsonDialect::Json), Ok(serde_json::json!([])));
+        assert_eq!(parse(r#""😀""#, JsonDialect::Json), Ok(serde_json::json!("😀")));
+        assert_eq!(
+            parse("[1,]", JsonDialect::Json).unwrap_err(),
+            "Trailing commas are not allowed in JSON"
+        );
+        assert_eq!(
+            parse("// a\n1", JsonDialect::Json).unwrap_err(),
+            "Comments are not allowed in JSON"
+        );
+        assert_eq!(parse("[01]", JsonDialect::Json).unwrap_err(), "Invalid number");
+        assert_eq!(parse("{'a': 1}", JsonDialect::Json).unwrap_err(), "Unexpected character `'`");
+        assert_eq!(parse("NaN", JsonDialect::Json).unwrap_err(), "Unexpected `NaN`");
+        assert_eq!(parse(r#"{"a": 1"#, JsonDialect::Json).unwrap_err(), "Unexpected end of input");
+        assert_eq!(parse(r#""a"#, JsonDialect::Json).unwrap_err(), "Unterminated string");
+        assert_eq!(parse("1 2", JsonDialect::Json).unwrap_err(), "Unexpected character `2`");
+    }
+
+    #[test]
+    fn jsonc() {
+        assert_eq!(
+            parse("{\n  // a\n  \"a\": [1, /* 2 */],\n}", JsonDialect::Jsonc),
+            Ok(serde_json::json!({ "a": [1] }))
+        );
+        assert_eq!(parse("/* a", JsonDialect::Jsonc).unwrap_err(), "Unterminated comment");
+        assert_eq!(parse("{a: 1}", JsonDialect::Jsonc).unwrap_err(), "Unexpected character `a`");
+    }
+
+    #[test]
+    fn json5() {
+        assert_eq!(
+            parse(
+                "{ unquoted: 'single', hex: 0xFF, half: .5, one: 1., plus: +1, s: 'a\\\nb\\x41' }",
+                JsonDialect::Json5
+            ),
+            Ok(serde_json::json!({
+                "unquoted": "single", "hex": 255, "half": 0.5, "one": 1, "plus": 1, "s": "abA"
+            }))
+        );
+        let value = parse_json("[Infinity, -NaN]", JsonDialect::Json5).unwrap();
+        let JsonValueKind::Array(values) = value.kind else { unreachable!() };
+        assert!(matches!(values[0].kind, JsonValueKind::Number(n) if n == f64::INFINITY));
+        assert!(matches!(values[1].kind, JsonValueKind::Number(n) if n.is_nan()));
+    }
+
+    #[test]
+    fn spans() {
+        let source_text = r#"{ "a": { "b": [1, "c"] } }"#;
+        let value = parse_json(source_text, JsonDialect::Json).unwrap();
+        assert_eq!(value.span, Span::new(0, 26));
+        let JsonValueKind::Object(members) = &value.kind else { unreachable!() };
+        assert_eq!(members[0].key_span, Span::new(2, 5));
+        let array = value.get("a").and_then(|a| a.get("b")).unwrap();
+        assert_eq!(array.span.source_text(source_text), r#"[1, "c"]"#);
+
+        let error = parse_json("[1, }", JsonDialect::Json).unwrap_err();
+        assert_eq!(error.span, Span::new(4, 5));
+    }
+
+    #[test]
+    fn nesting_depth() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&nested(MAX_NESTING_DEPTH), JsonDialect::Json).is_ok());
+        let error = parse_json(&nested(100_000), JsonDialect::Json).unwrap_err();
+        assert_eq!(error.message, "Nesting deeper than 128 is not supported");
+        assert_eq!(error.span, Span::new(128, 129));
+        let source_text = format!("{}1{}", "{\"a\":".repeat(200), "}".repeat(200));
+        assert!(parse(&source_text, JsonDialect::Json).is_err());
+    }
+}
@@ -5,35 +5,74 @@
 mod tester;
 
 mod ast_util;
+mod builder;
 mod config;
 mod context;
 mod disable_directives;
+pub mod encoding;
 mod fixer;
 mod globals;
 mod javascript_globals;
+#[cfg(feature = "js_plugins")]
+mod js_plugin;
+pub mod json;
+mod memory;
+#[cfg(feature = "estree")]
+pub mod native_plugin;
 mod options;
 pub mod partial_loader;
+pub mod paths;
+pub mod plugin;
+pub mod rename;
 pub mod rule;
 mod rules;
+pub mod selector;
 mod service;
+mod timing;
+mod type_info;
 mod utils;
+mod workspace;
 
+use dashmap::DashMap;
 use rustc_hash::FxHashMap;
-use std::{io::Write, rc::Rc, sync::Arc};
+use std::{
+    cell::Cell,
+    io::Write,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+    time::Instant,
+};
 
-use oxc_diagnostics::Report;
+use oxc_allocator::Allocator;
+use oxc_ast::AstType;
+use oxc_diagnostics::{Error, Report, Severity};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{GetSpan, SourceType, Span, UnknownExtension};
 
-use crate::{
-    config::{ESLintEnv, ESLintSettings},
-    fixer::Fix,
-    fixer::{Fixer, Message},
-    rule::RuleCategory,
-    rules::{RuleEnum, RULES},
-};
 pub use crate::{
+    builder::LinterBuilder,
     context::LintContext,
-    options::{AllowWarnDeny, LintOptions},
-    service::{LintService, LintServiceOptions},
+    disable_directives::{disable_comments, DisableRuleComment},
+    fixer::{Fix, FixKind, Message},
+    memory::MemoryUsage,
+    options::{AllowWarnDeny, FixOutput, LintOptions},
+    plugin::{PluginContext, PluginRule, RulePlugin},
+    rules::{RuleEnum, RULES},
+    service::{
+        resolve_options, InputLimits, LintService, LintServiceOptions, DEFAULT_CONDITION_NAMES,
+    },
+    timing::Timings,
+    type_info::{ExpressionType, TypeInfo, TypeInfoFile},
+    workspace::{Workspace, WorkspacePackage},
+};
+use crate::{
+    config::{ESLintConfig, ESLintEnv, ESLintSettings},
+    fixer::Fixer,
+    plugin::EnabledPluginRule,
+    rule::RuleCategory,
+    timing::RuleTiming,
 };
 use oxc_semantic::AstNode;
 
@@ -51,9 +90,30 @@ fn size_asserts() {
 #[derive(Debug)]
 pub struct Linter {
     rules: Vec<(/* rule name */ &'static str, RuleEnum)>,
+    /// The severities `rules` are configured with in the configuration file, in the same order.
+    /// `None` for the rules keeping the severity of their diagnostics, e.g. the ones only enabled by `-D`.
+    severities: Vec<Option<Severity>>,
+    /// The indices of `rules` to run on each `AstType`, see `Rule::node_types`.
+    node_rules: Vec<Vec<usize>>,
     options: LintOptions,
     settings: Arc<ESLintSettings>,
     env: Arc<ESLintEnv>,
+    /// `Some` when `LintOptions::timing` is enabled, shared with the linters created by `with_config_files`.
+    timings: Option<Arc<Timings>>,
+    /// Custom file extensions from the `extensions` of the configuration file, see `source_type`.
+    extensions: Arc<FxHashMap<String, String>>,
+    /// The configuration file when it has `overrides`, which are applied to each linted file.
+    config: Option<Arc<ESLintConfig>>,
+    /// Linters for the files matching some `overrides` of `config`, keyed by the indices of the matching overrides.
+    override_linters: DashMap<Vec<usize>, Arc<Linter>>,
+    /// Types for type-aware rules, shared with the linters created by `with_config_files`.
+    type_info: Option<Arc<dyn TypeInfo>>,
+    /// The registered plugins, see `with_plugin`.
+    plugins: Vec<Arc<dyn RulePlugin>>,
+    /// The rules of `plugins` enabled by the configuration file, run after `rules`.
+    plugin_rules: Vec<EnabledPluginRule>,
+    /// The enabled rules of the configuration file which are not supported, see `config_warnings`.
+    config_warnings: Vec<Error>,
 }
 
 impl Default for Linter {
@@ -63,18 +123,129 @@ impl Default for Linter {
 }
 
 impl Linter {
+    /// A linter configured from Rust code, see `LinterBuilder`.
+    pub fn builder() -> LinterBuilder {
+        LinterBuilder::default()
+    }
+
     /// # Errors
     ///
     /// Returns `Err` if there are any errors parsing the configuration file.
     pub fn from_options(options: LintOptions) -> Result<Self, Report> {
-        let (rules, settings, env) = options.derive_rules_and_settings_and_env()?;
-        let rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect();
-        Ok(Self { rules, options, settings: Arc::new(settings), env: Arc::new(env) })
+        let config = options.load_config()?;
+        let type_info = options.load_type_info()?;
+        let plugins = config
+            .as_ref()
+            .map(|config| plugin::load_plugins(config, options.allow_plugins))
+            .transpose()?
+            .unwrap_or_default();
+        let timings = options.timing.then(Arc::default);
+        let extensions =
+            config.as_ref().map(|config| config.extensions().clone()).unwrap_or_default();
+        let linter = Self::from_config(options, config, plugins, timings, Arc::new(extensions));
+        Ok(Self { type_info, ..linter })
+    }
+
+    fn from_config(
+        options: LintOptions,
+        mut config: Option<ESLintConfig>,
+        plugins: Vec<Arc<dyn RulePlugin>>,
+        timings: Option<Arc<Timings>>,
+        extensions: Arc<FxHashMap<String, String>>,
+    ) -> Self {
+        let (rules, settings, env) =
+            options.derive_rules_and_settings_and_env_from_config(config.as_ref());
+        let severities = rules
+            .iter()
+            .map(|rule| {
+                config.as_ref()?.find_rule(rule.plugin_name(), rule.name())?.severity.as_severity()
+            })
+            .collect();
+        let rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect::<Vec<_>>();
+        let plugin_rules = EnabledPluginRule::from_config(&plugins, config.as_ref());
+        let config_warnings = config.as_mut().map(ESLintConfig::take_warnings).unwrap_or_default();
+        Self {
+            node_rules: node_rules(&rules),
+            rules,
+            severities,
+            options,
+            settings: Arc::new(settings),
+            env: Arc::new(env),
+            timings,
+            extensions,
+            config: config.filter(ESLintConfig::has_overrides).map(Arc::new),
+            override_linters: DashMap::default(),
+            type_info: None,
+            plugins,
+            plugin_rules,
+            config_warnings,
+        }
+    }
+
+    /// Create a linter with the same options, but with the rules, settings and env derived from
+    /// the given configuration files instead of `LintOptions::config_path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if there are any errors parsing the configuration files.
+    pub fn with_config_files(&self, paths: &[PathBuf]) -> Result<Self, Report> {
+        self.with_loaded_plugins(ESLintConfig::from_files(paths)?)
+    }
+
+    /// Like `with_config_files`, but with the content of a configuration file,
+    /// for environments without a file system such as the playground.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if there are any errors parsing the configuration.
+    pub fn with_config_json(&self, json: serde_json::Value) -> Result<Self, Report> {
+        self.with_loaded_plugins(ESLintConfig::from_value(json)?)
+    }
+
+    /// `with_config`, with the plugin libraries of `config` registered as well.
+    fn with_loaded_plugins(&self, config: ESLintConfig) -> Result<Self, Report> {
+        let mut plugins = self.plugins.clone();
+        for plugin in plugin::load_plugins(&config, self.options.allow_plugins)? {
+            if !plugins.iter().any(|p| p.name() == plugin.name()) {
+                plugins.push(plugin);
+            }
+        }
+        Ok(self.with_config(config, plugins))
+    }
+
+    fn with_config(&self, config: ESLintConfig, plugins: Vec<Arc<dyn RulePlugin>>) -> Self {
+        let linter = Self::from_config(
+            self.options.clone(),
+            Some(config),
+            plugins,
+            self.timings.clone(),
+            Arc::clone(&self.extensions),
+        );
+        Self { type_info: self.type_info.clone(), ..linter }
+    }
+
+    /// The linter with the rules of the `overrides` matching `path` applied,
+    /// `None` if none of them match.
+    fn override_linter(&self, path: &Path) -> Option<Arc<Self>> {
+        let config = self.config.as_ref()?;
+        let indices = config.matching_overrides(path);
+        if indices.is_empty() {
+            return None;
+        }
+        if let Some(linter) = self.override_linters.get(&indices) {
+            return Some(Arc::clone(linter.value()));
+        }
+        let linter =
+            Arc::new(self.with_config(config.with_overrides(&indices), self.plugins.clone()));
+        self.override_linters.insert(indices, Arc::clone(&linter));
+        Some(linter)
     }
 
     #[must_use]
     pub fn with_rules(mut self, rules: Vec<RuleEnum>) -> Self {
         self.rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect();
+        self.severities = vec![None; self.rules.len()];
+        self.node_rules = node_rules(&self.rules);
         self
     }
 
@@ -90,12 +261,68 @@ impl Linter {
         self
     }
 
+    /// Provide the types of expressions to type-aware rules, instead of `LintOptions::type_info_path`.
+    #[must_use]
+    pub fn with_type_info(mut self, type_info: Arc<dyn TypeInfo>) -> Self {
+        self.type_info = Some(type_info);
+        self
+    }
+
+    /// Register a plugin without listing its library in the configuration file,
+    /// its rules are enabled by the configuration given to `with_config_files` or `with_config_json`.
+    #[must_use]
+    pub fn with_plugin(mut self, plugin: Arc<dyn RulePlugin>) -> Self {
+        self.plugins.retain(|p| p.name() != plugin.name());
+        self.plugins.push(plugin);
+        self
+    }
+
+    pub(crate) fn settings(&self) -> &ESLintSettings {
+        &self.settings
+    }
+
     pub fn options(&self) -> &LintOptions {
         &self.options
     }
 
+    /// The custom file extensions of the configuration file, without the leading dot.
+    pub fn extensions(&self) -> impl Iterator<Item = &str> {
+        self.extensions.keys().map(String::as_str)
+    }
+
+    /// Like `SourceType::from_path`, with the custom file extensions of the configuration file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the extension is neither a JavaScript or TypeScript extension nor a custom one.
+    pub fn source_type(&self, path: &Path) -> Result<SourceType, UnknownExtension> {
+        let extension = path.extension().and_then(std::ffi::OsStr::to_str);
+        match extension.and_then(|extension| self.extensions.get(extension)) {
+            Some(language) => SourceType::from_path(path.with_extension(language)),
+            None => SourceType::from_path(path),
+        }
+    }
+
+    pub fn timings(&self) -> Option<&Timings> {
+        self.timings.as_deref()
+    }
+
+    /// Warnings about the configuration file, e.g. for the rules removed from ESLint which are
+    /// still enabled. Reported once by the caller, they do not belong to a linted file.
+    pub fn config_warnings(&self) -> &[Error] {
+        &self.config_warnings
+    }
+
+    /// Whether a rule linting `path` uses the tokens, to collect them with `Parser::collect_tokens`.
+    /// See `Rule::needs_tokens`.
+    pub fn needs_tokens(&self, path: &Path) -> bool {
+        let override_linter = self.override_linter(path);
+        let linter = override_linter.as_deref().unwrap_or(self);
+        linter.rules.iter().any(|(_, rule)| rule.needs_tokens())
+    }
+
     pub fn number_of_rules(&self) -> usize {
-        self.rules.len()
+        self.rules.len() + self.plugin_rules.len()
     }
 
     #[must_use]
@@ -104,33 +331,166 @@ impl Linter {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, kind: FixKind) -> Self {
+        self.options.fix_kind = kind;
+        self
+    }
+
+    /// Lint the JSON file at `path` with the enabled rules of the `json` plugin, see `json::lint_json`.
+    pub fn lint_json(&self, path: &Path, source_text: &str) -> Vec<Error> {
+        if let Some(linter) = self.override_linter(path) {
+            return linter.lint_json(path, source_text);
+        }
+        json::lint_json(path, source_text, |name| {
+            let i = self
+                .rules
+                .iter()
+                .position(|(rule_name, rule)| *rule_name == name && rule.plugin_name() == "json")?;
+            Some(self.severities[i])
+        })
+    }
+
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
+        if let Some(linter) = self.override_linter(ctx.file_path()) {
+            return linter.run(ctx);
+        }
+
+        PANICKED_RULE.with(|rule| rule.set(None));
         let semantic = Rc::clone(ctx.semantic());
-        let mut ctx =
-            ctx.with_fix(self.options.fix).with_settings(&self.settings).with_env(&self.env);
+        let mut ctx = ctx
+            .with_fix(self.options.fix)
+            .with_fix_kind(self.options.fix_kind)
+            .with_settings(&self.settings)
+            .with_env(&self.env)
+            .with_type_info(self.type_info.as_ref());
+
+        // The timings of `plugin_rules` follow the ones of `rules`.
+        let mut timings =
+            self.timings.as_ref().map(|_| vec![RuleTiming::default(); self.number_of_rules()]);
+
+        for (i, (rule_name, rule)) in self.rules.iter().enumerate() {
+            ctx.with_rule_name(rule.plugin_name(), rule_name);
+            ctx.with_rule_severity(self.severities[i]);
+            let _span = tracing::trace_span!("rule", name = rule_name).entered();
+            timed(&mut timings, i, &ctx, None, || rule.run_once(&ctx));
+        }
 
-        for (rule_name, rule) in &self.rules {
-            ctx.with_rule_name(rule_name);
-            rule.run_once(&ctx);
+        for (i, rule) in self.plugin_rules.iter().enumerate() {
+            ctx.with_rule_name(rule.plugin_name(), rule.name());
+            ctx.with_rule_severity(rule.severity());
+            timed(&mut timings, self.rules.len() + i, &ctx, None, || rule.run_once(&ctx));
         }
 
         for symbol in semantic.symbols().iter() {
-            for (rule_name, rule) in &self.rules {
-                ctx.with_rule_name(rule_name);
-                rule.run_on_symbol(symbol, &ctx);
+            if ctx.is_cancelled() {
+                return vec![];
+            }
+            for (i, (rule_name, rule)) in self.rules.iter().enumerate() {
+                ctx.with_rule_name(rule.plugin_name(), rule_name);
+                ctx.with_rule_severity(self.severities[i]);
+                timed(&mut timings, i, &ctx, None, || rule.run_on_symbol(symbol, &ctx));
+            }
+            for (i, rule) in self.plugin_rules.iter().enumerate() {
+                ctx.with_rule_name(rule.plugin_name(), rule.name());
+                ctx.with_rule_severity(rule.severity());
+                timed(&mut timings, self.rules.len() + i, &ctx, None, || {
+                    rule.run_on_symbol(symbol, &ctx);
+                });
             }
         }
 
         for node in semantic.nodes().iter() {
-            for (rule_name, rule) in &self.rules {
-                ctx.with_rule_name(rule_name);
-                rule.run(node, &ctx);
+            if ctx.is_cancelled() {
+                return vec![];
+            }
+            let span = Some(node.kind().span());
+            for &i in &self.node_rules[node.kind().ty() as usize] {
+                let (rule_name, rule) = &self.rules[i];
+                ctx.with_rule_name(rule.plugin_name(), rule_name);
+                ctx.with_rule_severity(self.severities[i]);
+                timed(&mut timings, i, &ctx, span, || rule.run(node, &ctx));
             }
+            for (i, rule) in self.plugin_rules.iter().enumerate() {
+                ctx.with_rule_name(rule.plugin_name(), rule.name());
+                ctx.with_rule_severity(rule.severity());
+                timed(&mut timings, self.rules.len() + i, &ctx, span, || {
+                    rule.run(node, &ctx);
+                });
+            }
+        }
+
+        if ctx.is_cancelled() {
+            return vec![];
+        }
+
+        for (i, (rule_name, rule)) in self.rules.iter().enumerate() {
+            ctx.with_rule_name(rule.plugin_name(), rule_name);
+            ctx.with_rule_severity(self.severities[i]);
+            timed(&mut timings, i, &ctx, None, || rule.run_on_program_exit(&ctx));
+        }
+
+        for (i, rule) in self.plugin_rules.iter().enumerate() {
+            ctx.with_rule_name(rule.plugin_name(), rule.name());
+            ctx.with_rule_severity(rule.severity());
+            timed(&mut timings, self.rules.len() + i, &ctx, None, || {
+                rule.run_on_program_exit(&ctx)
+            });
+        }
+
+        if self.options.report_unused_directives {
+            ctx.report_unused_disable_directives();
+        }
+
+        if let (Some(total), Some(timings)) = (&self.timings, timings) {
+            total.record_rules(
+                self.rules
+                    .iter()
+                    .map(|(rule_name, rule)| (rule.plugin_name(), *rule_name))
+                    .chain(self.plugin_rules.iter().map(|rule| (rule.plugin_name(), rule.name())))
+                    .zip(timings),
+            );
         }
 
         ctx.into_message()
     }
 
+    /// Parse and lint `source_text` as the content of the file at `path`, for tools linting the
+    /// code they have in memory. The diagnostics are of the syntax errors when the code does not
+    /// parse. The fixes are not applied, see `Fixer`.
+    ///
+    /// Only JavaScript and TypeScript files are linted, the scripts of other files such as `.vue`
+    /// files are linted by `LintService`.
+    pub fn lint_source(&self, path: &Path, source_text: &str) -> Vec<Message<'static>> {
+        let source_type = match self.source_type(path) {
+            Ok(source_type) => source_type,
+            Err(UnknownExtension(message)) => return vec![Message::new(Error::msg(message), None)],
+        };
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .collect_tokens(self.needs_tokens(path))
+            .parse();
+        if !ret.errors.is_empty() {
+            return ret.errors.into_iter().map(|error| Message::new(error, None)).collect();
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic_ret = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .with_check_syntax_error(true)
+            .build(program);
+        if !semantic_ret.errors.is_empty() {
+            return semantic_ret
+                .errors
+                .into_iter()
+                .map(|error| Message::new(error, None))
+                .collect();
+        }
+        let ctx =
+            LintContext::new(path.into(), &Rc::new(semantic_ret.semantic)).with_tokens(ret.tokens);
+        self.run(ctx).into_iter().map(Message::into_owned).collect()
+    }
+
     pub fn print_rules<W: Write>(writer: &mut W) {
         let rules_by_category = RULES.iter().fold(
             FxHashMap::default(),
@@ -152,9 +512,133 @@ impl Linter {
     }
 }
 
+/// The indices of the rules to run on each node type, in the order of the rules.
+fn node_rules(rules: &[(&'static str, RuleEnum)]) -> Vec<Vec<usize>> {
+    let mut node_rules = vec![vec![]; AstType::COUNT];
+    for (i, (_, rule)) in rules.iter().enumerate() {
+        match rule.node_types() {
+            Some(types) => {
+                for ty in types {
+                    let indices = &mut node_rules[*ty as usize];
+                    if indices.last() != Some(&i) {
+                        indices.push(i);
+                    }
+                }
+            }
+            None => node_rules.iter_mut().for_each(|indices| indices.push(i)),
+        }
+    }
+    node_rules
+}
+
+thread_local! {
+    /// The rule which panicked on this thread, see `take_panicked_rule`
+    static PANICKED_RULE: Cell<Option<(&'static str, Option<Span>)>> = Cell::new(None);
+}
+
+/// The name of the rule which panicked while linting the last file on this thread, with the span
+/// of the node it was visiting, to help reproducing the panic.
+pub(crate) fn take_panicked_rule() -> Option<(&'static str, Option<Span>)> {
+    PANICKED_RULE.with(Cell::take)
+}
+
+/// Records the rule it is created for in `PANICKED_RULE` when a panic unwinds through it
+struct PanicGuard {
+    rule_name: &'static str,
+    span: Option<Span>,
+}
+
+impl Drop for PanicGuard {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            PANICKED_RULE.with(|rule| rule.set(Some((self.rule_name, self.span))));
+        }
+    }
+}
+
+/// Run `f`, adding the time it took and the diagnostics it reported to `timings[i]` when timing is enabled.
+/// `span` is of the node the rule visits, if any.
+#[inline]
+fn timed<F: FnOnce()>(
+    timings: &mut Option<Vec<RuleTiming>>,
+    i: usize,
+    ctx: &LintContext,
+    span: Option<Span>,
+    f: F,
+) {
+    let _guard = PanicGuard { rule_name: ctx.current_rule_name(), span };
+    match timings {
+        Some(timings) => {
+            let diagnostics = ctx.number_of_diagnostics();
+            let start = Instant::now();
+            f();
+            timings[i].duration += start.elapsed();
+            timings[i].diagnostics += ctx.number_of_diagnostics() - diagnostics;
+        }
+        None => f(),
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Linter;
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstType;
+    use oxc_diagnostics::{CancellationToken, Severity};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+
+    use super::{
+        FixKind, LintContext, LintOptions, Linter, PluginContext, PluginRule, RulePlugin, RULES,
+    };
+    use crate::rule::RuleCategory;
+
+    /// The plugin of the tests, with a single rule
+    #[derive(Debug)]
+    struct AcmePlugin(Arc<dyn PluginRule>);
+
+    impl AcmePlugin {
+        /// A linter with `rule` enabled as `acme/<name>`
+        fn linter(rule: impl PluginRule + 'static) -> Linter {
+            let name = format!("acme/{}", rule.name());
+            Linter::default()
+                .with_plugin(Arc::new(Self(Arc::new(rule))))
+                .with_config_json(serde_json::json!({ "rules": { name: "error" } }))
+                .unwrap()
+        }
+
+        /// The messages of the plugin rule when `linter` lints `source_text`
+        fn messages(linter: &Linter, source_text: &str) -> Vec<String> {
+            let allocator = Allocator::default();
+            let source_type = SourceType::default();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let program = allocator.alloc(ret.program);
+            let semantic = Rc::new(
+                SemanticBuilder::new(source_text, source_type)
+                    .with_trivias(ret.trivias)
+                    .build(program)
+                    .semantic,
+            );
+            let messages = linter.run(LintContext::new(Path::new("test.js").into(), &semantic));
+            messages
+                .into_iter()
+                .map(|message| message.error.to_string())
+                .filter(|message| message.starts_with("acme"))
+                .collect()
+        }
+    }
+
+    impl RulePlugin for AcmePlugin {
+        fn name(&self) -> &'static str {
+            "acme"
+        }
+
+        fn rules(&self) -> Vec<Arc<dyn PluginRule>> {
+            vec![Arc::clone(&self.0)]
+        }
+    }
 
     #[test]
     fn print_rules() {
@@ -162,4 +646,326 @@ mod test {
         Linter::print_rules(&mut writer);
         assert!(!writer.is_empty());
     }
+
+    #[test]
+    fn with_config_json() {
+        let linter = Linter::default();
+        let json =
+            serde_json::json!({ "extends": "eslint:recommended", "rules": { "no-undef": "off" } });
+        let configured = linter.with_config_json(json).unwrap();
+        assert!(configured.rules.iter().any(|(name, _)| *name == "no-dupe-keys"));
+        assert!(!configured.rules.iter().any(|(name, _)| *name == "no-undef"));
+    }
+
+    #[test]
+    fn plugins_not_allowed() {
+        let json = serde_json::json!({ "plugins": ["./libacme_plugin.so"] });
+        let error = Linter::default().with_config_json(json.clone()).unwrap_err();
+        assert!(error.to_string().starts_with("Plugin \"./libacme_plugin.so\" is not loaded"));
+
+        let linter = Linter::from_options(LintOptions::default().with_allow_plugins(true)).unwrap();
+        let error = linter.with_config_json(json).unwrap_err();
+        assert!(error.to_string().starts_with("Failed to load plugin"));
+    }
+
+    #[test]
+    fn builder() {
+        let linter = Linter::builder()
+            .with_config(serde_json::json!({ "rules": { "no-debugger": "error" } }))
+            .with_fix(true)
+            .build()
+            .unwrap();
+        let source_text = "export const a: number = 1;\ndebugger;";
+        let messages = linter.lint_source(Path::new("index.ts"), source_text);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].error.severity(), Some(Severity::Error));
+        assert_eq!((messages[0].start(), messages[0].end()), (28, 37));
+
+        let messages = linter.lint_source(Path::new("index.js"), "let a = ;");
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].fix.is_none());
+        assert_eq!(linter.lint_source(Path::new("index.md"), "").len(), 1);
+
+        let rules = RULES.iter().filter(|rule| rule.name() == "no-empty").cloned().collect();
+        let linter = Linter::builder().with_rules(rules).build().unwrap();
+        assert!(linter.lint_source(Path::new("index.js"), "debugger;").is_empty());
+        assert_eq!(linter.lint_source(Path::new("index.js"), "{}").len(), 1);
+    }
+
+    #[test]
+    fn overrides() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, "debugger;", source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            Rc::new(SemanticBuilder::new("debugger;", source_type).build(program).semantic);
+        let json = serde_json::json!({
+            "rules": { "no-debugger": "error" },
+            "overrides": [{ "files": "*.test.js", "rules": { "no-debugger": "off" } }]
+        });
+        let linter = Linter::default().with_config_json(json).unwrap();
+        let run = |path: &str| linter.run(LintContext::new(Path::new(path).into(), &semantic));
+
+        let messages = run("index.js");
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].error.severity(), Some(Severity::Error));
+        assert!(run("index.test.js").is_empty());
+    }
+
+    #[test]
+    fn fix_kind() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, "debugger;", source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            Rc::new(SemanticBuilder::new("debugger;", source_type).build(program).semantic);
+        // Removing a `debugger` statement changes the behavior of the code
+        let fix = |kind: FixKind| {
+            let options = LintOptions::default().with_fix(true).with_fix_kind(kind);
+            let linter = Linter::from_options(options).unwrap();
+            let mut messages = linter.run(LintContext::new(Path::new("test.js").into(), &semantic));
+            assert_eq!(messages.len(), 1);
+            messages.pop().unwrap().fix.map(|fix| fix.kind)
+        };
+        assert_eq!(fix(FixKind::Safe), None);
+        assert_eq!(fix(FixKind::Suggestion), Some(FixKind::Suggestion));
+        assert_eq!(fix(FixKind::Dangerous), Some(FixKind::Suggestion));
+    }
+
+    #[test]
+    fn unused_disable_directives() {
+        let source_text = "
+            // eslint-disable-next-line no-debugger, no-alert
+            debugger;
+            /* eslint-disable */
+            /* eslint-enable */
+            debugger; // eslint-disable-line
+        ";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = Rc::new(
+            SemanticBuilder::new(source_text, source_type)
+                .with_trivias(ret.trivias)
+                .build(program)
+                .semantic,
+        );
+        let options = LintOptions::default().with_report_unused_directives(true);
+        let linter = Linter::from_options(options).unwrap();
+        let messages = linter.run(LintContext::new(Path::new("test.js").into(), &semantic));
+        let messages = messages.iter().map(|message| message.error.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "Unused eslint-disable directive (no problems were reported from 'no-alert').",
+                "Unused eslint-disable directive (no problems were reported).",
+            ]
+        );
+    }
+
+    #[test]
+    fn node_rules() {
+        let rules = ["no-debugger", "no-empty", "no-unused-vars"]
+            .map(|name| RULES.iter().find(|rule| rule.name() == name).unwrap().clone());
+        let linter = Linter::default().with_rules(rules.to_vec());
+        assert_eq!(linter.node_rules[AstType::DebuggerStatement as usize], [0, 1]);
+        assert_eq!(linter.node_rules[AstType::Program as usize], [1]);
+        // `no-unused-vars` only runs on the symbols
+        assert!(linter.node_rules.iter().all(|indices| !indices.contains(&2)));
+    }
+
+    #[test]
+    fn plugin() {
+        use oxc_ast::AstKind;
+        use oxc_semantic::AstNode;
+
+        #[derive(Debug)]
+        struct NoFoo;
+
+        impl PluginRule for NoFoo {
+            fn name(&self) -> &'static str {
+                "no-foo"
+            }
+
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Restriction
+            }
+
+            #[cfg(feature = "estree")]
+            fn run_once(&self, ctx: &PluginContext<'_, '_>) {
+                let ast = oxc_ast::decode_estree_binary(&ctx.estree_binary()).unwrap();
+                assert_eq!(ast["type"], "Program");
+                assert_eq!(ast["body"].as_array().map(Vec::len), Some(3));
+            }
+
+            fn run<'a>(&self, node: &AstNode<'a>, ctx: &PluginContext<'_, 'a>) {
+                if let AstKind::IdentifierReference(ident) = node.kind() {
+                    if ident.name == "foo" {
+                        ctx.report_with_help("Unexpected foo.", "Use bar instead", ident.span);
+                    }
+                }
+            }
+        }
+
+        let source_text = "foo;\nbar;\nfoo; // eslint-disable-line acme/no-foo";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = Rc::new(
+            SemanticBuilder::new(source_text, source_type)
+                .with_trivias(ret.trivias)
+                .build(program)
+                .semantic,
+        );
+        let linter = Linter::default().with_plugin(Arc::new(AcmePlugin(Arc::new(NoFoo))));
+        let run = |json| {
+            let linter = linter.with_config_json(json).unwrap();
+            let messages = linter.run(LintContext::new(Path::new("test.js").into(), &semantic));
+            messages
+                .into_iter()
+                .filter(|message| message.error.to_string().starts_with("acme"))
+                .collect::<Vec<_>>()
+        };
+
+        // Plugin rules are disabled until configured
+        assert!(run(serde_json::json!({})).is_empty());
+
+        let messages = run(serde_json::json!({ "rules": { "acme/no-foo": "error" } }));
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].error.to_string(), "acme(no-foo): Unexpected foo.");
+        assert_eq!(messages[0].error.severity(), Some(Severity::Error));
+    }
+
+    #[test]
+    fn plugin_run_on_symbol() {
+        use oxc_semantic::SymbolId;
+
+        #[derive(Debug)]
+        struct NoUnused;
+
+        impl PluginRule for NoUnused {
+            fn name(&self) -> &'static str {
+                "no-unused"
+            }
+
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Restriction
+            }
+
+            fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &PluginContext<'_, '_>) {
+                let symbols = ctx.symbols();
+                if symbols.get_resolved_reference_ids(symbol_id).is_empty() {
+                    let name = symbols.get_name(symbol_id);
+                    ctx.report(format!("{name} is never used."), symbols.get_span(symbol_id));
+                }
+            }
+        }
+
+        let linter = AcmePlugin::linter(NoUnused);
+        assert_eq!(
+            AcmePlugin::messages(&linter, "let a = 1, b = 2; b;"),
+            ["acme(no-unused): a is never used."]
+        );
+    }
+
+    #[test]
+    fn run_on_program_exit() {
+        use oxc_ast::AstKind;
+        use oxc_semantic::AstNode;
+
+        /// Reports the `foo`s of a file once there are more than one
+        #[derive(Debug)]
+        struct MaxFoo;
+
+        impl PluginRule for MaxFoo {
+            fn name(&self) -> &'static str {
+                "max-foo"
+            }
+
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Restriction
+            }
+
+            fn run<'a>(&self, node: &AstNode<'a>, ctx: &PluginContext<'_, 'a>) {
+                if let AstKind::IdentifierReference(ident) = node.kind() {
+                    if ident.name == "foo" {
+                        ctx.rule_state::<Vec<Span>>().push(ident.span);
+                    }
+                }
+            }
+
+            fn run_on_program_exit(&self, ctx: &PluginContext<'_, '_>) {
+                let spans = ctx.rule_state::<Vec<Span>>();
+                if spans.len() > 1 {
+                    ctx.report(format!("{} foo.", spans.len()), spans[0]);
+                }
+            }
+        }
+
+        let linter = AcmePlugin::linter(MaxFoo);
+        let run = |source_text| AcmePlugin::messages(&linter, source_text);
+
+        // The state is of each file
+        assert!(run("foo; bar;").is_empty());
+        assert_eq!(run("foo; bar; foo;"), ["acme(max-foo): 2 foo."]);
+        assert!(run("foo;").is_empty());
+    }
+
+    #[test]
+    fn cancelled() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, "debugger;", source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            Rc::new(SemanticBuilder::new("debugger;", source_type).build(program).semantic);
+        let linter = Linter::default();
+        let token = CancellationToken::new();
+        let ctx = || {
+            LintContext::new(Path::new("test.js").into(), &semantic)
+                .with_cancellation_token(token.clone())
+        };
+        assert_eq!(linter.run(ctx()).len(), 1);
+        token.cancel();
+        assert!(linter.run(ctx()).is_empty());
+    }
+
+    #[test]
+    fn tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source_text = "let a = /* b */ [c];";
+        let ret = Parser::new(&allocator, source_text, source_type).collect_tokens(true).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            Rc::new(SemanticBuilder::new(source_text, source_type).build(program).semantic);
+        let ctx = LintContext::new(Path::new("test.js").into(), &semantic).with_tokens(ret.tokens);
+        let text = |token: Option<oxc_parser::Token>| {
+            token.map(|token| token.span().source_text(source_text))
+        };
+
+        let a = Span::new(4, 5);
+        let array = Span::new(16, 19);
+        assert_eq!(ctx.tokens().len(), 7);
+        assert_eq!(text(ctx.get_token_before(a)), Some("let"));
+        assert_eq!(text(ctx.get_token_after(a)), Some("="));
+        assert_eq!(text(ctx.get_token_before(array)), Some("="));
+        assert_eq!(text(ctx.first_token_of(array)), Some("["));
+        assert_eq!(text(ctx.last_token_of(array)), Some("]"));
+        assert_eq!(text(ctx.get_token_before(Span::new(0, 3))), None);
+        assert_eq!(text(ctx.get_token_after(Span::new(19, 20))), None);
+        assert_eq!(ctx.text_between(a, array), " = /* b */ ");
+    }
+
+    #[test]
+    fn needs_tokens() {
+        let path = Path::new("test.js");
+        let rule = |name| RULES.iter().find(|rule| rule.name() == name).unwrap().clone();
+        assert!(!Linter::default().with_rules(vec![rule("no-debugger")]).needs_tokens(path));
+        assert!(Linter::default().with_rules(vec![rule("object-shorthand")]).needs_tokens(path));
+    }
 }
@@ -0,0 +1,128 @@
+use std::{io::Write, path::Path, sync::Mutex};
+
+use oxc_allocator::Allocator;
+use rustc_hash::FxHashMap;
+
+/// Number of files printed by `MemoryUsage::print`.
+const NUMBER_OF_FILES_TO_PRINT: usize = 10;
+
+/// Arena memory used by each linted file.
+/// Collected when `LintServiceOptions::memory_usage` is enabled.
+#[derive(Debug, Default)]
+pub struct MemoryUsage {
+    files: Mutex<FxHashMap<Box<Path>, usize>>,
+}
+
+impl MemoryUsage {
+    /// Record the memory used in `allocator` by one source of `path`.
+    /// For files with several sources, e.g. Vue files, the largest source is kept.
+    pub(crate) fn record_file(&self, path: &Path, allocator: &Allocator) {
+        // The memory left in the current chunk is not used yet.
+        let bytes = allocator.allocated_bytes() - allocator.chunk_capacity();
+        let mut files = self.files.lock().unwrap();
+        let entry = files.entry(path.into()).or_default();
+        *entry = (*entry).max(bytes);
+    }
+
+    /// Print the total and largest arena memory, the peak resident memory of the process where it is known,
+    /// followed by the files using the most memory, formatted as markdown tables.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if writing to `writer` fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while recording memory usage.
+    pub fn print<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.print_with_peak_resident(writer, peak_resident_bytes())
+    }
+
+    fn print_with_peak_resident<W: Write>(
+        &self,
+        writer: &mut W,
+        peak_resident: Option<usize>,
+    ) -> std::io::Result<()> {
+        let files = self.files.lock().unwrap();
+        let mut rows = files
+            .iter()
+            .map(|(path, bytes)| (path.to_string_lossy().to_string(), *bytes))
+            .collect::<Vec<_>>();
+        rows.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let mut summary = vec![
+            ("All files".to_string(), rows.iter().map(|(_, bytes)| bytes).sum()),
+            ("Largest file".to_string(), rows.first().map_or(0, |(_, bytes)| *bytes)),
+        ];
+        if let Some(peak_resident) = peak_resident {
+            summary.push(("Peak resident".to_string(), peak_resident));
+        }
+        Self::print_table(writer, "Memory", summary)?;
+        writeln!(writer)?;
+
+        rows.truncate(NUMBER_OF_FILES_TO_PRINT);
+        Self::print_table(writer, "File", rows)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn print_table<W: Write>(
+        writer: &mut W,
+        header: &str,
+        rows: Vec<(String, usize)>,
+    ) -> std::io::Result<()> {
+        let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0).max(header.len());
+        writeln!(writer, "{header:<width$} | Size (KiB)")?;
+        writeln!(writer, ":{}|-----------:", "-".repeat(width))?;
+        for (name, bytes) in rows {
+            let kib = bytes as f64 / 1024.0;
+            writeln!(writer, "{name:<width$} | {kib:>10.1}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The high water mark of the resident memory of the process, only known on Linux.
+fn peak_resident_bytes() -> Option<usize> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find_map(|line| line.strip_prefix("VmHWM:"))?;
+    let kib = line.trim().strip_suffix("kB")?.trim().parse::<usize>().ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+
+    use super::MemoryUsage;
+
+    #[test]
+    fn print() {
+        let memory_usage = MemoryUsage::default();
+        let allocator = Allocator::default();
+        memory_usage.record_file(Path::new("empty.js"), &allocator);
+        memory_usage.files.lock().unwrap().insert(Path::new("large.js").into(), 3072);
+        memory_usage.files.lock().unwrap().insert(Path::new("small.js").into(), 1024);
+        let mut writer = vec![];
+        memory_usage.print_with_peak_resident(&mut writer, Some(8192)).unwrap();
+        let output = String::from_utf8(writer).unwrap();
+        let expected = "\
+Memory        | Size (KiB)
+:-------------|-----------:
+All files     |        4.0
+Largest file  |        3.0
+Peak resident |        8.0
+
+File     | Size (KiB)
+:--------|-----------:
+large.js |        3.0
+small.js |        1.0
+empty.js |        0.0
+";
+        assert_eq!(output, expected);
+    }
+}
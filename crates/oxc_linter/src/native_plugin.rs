@@ -0,0 +1,563 @@
+//! Rule plugins built as dynamic libraries, listed in the `plugins` of the configuration file:
+//!
+//! ```json
+//! {
+//!   "plugins": ["./target/release/libacme_plugin.so"],
+//!   "rules": { "acme/no-foo": "error" }
+//! }
+//! ```
+//!
+//! A library is a `cdylib` crate implementing [`NativeRule`] for its rules and exporting them with
+//! [`declare_plugin!`](crate::declare_plugin):
+//!
+//! ```ignore
+//! oxc_linter::declare_plugin!("acme", [NoFoo]);
+//! ```
+//!
+//! The library and the linter only share the C interface of this module, so that they can be built
+//! with other compilers, other versions of this crate and other allocators. The library is given
+//! the source text, the options and the AST in the versioned binary encoding of
+//! `Program::to_estree_binary`, borrowed for the call, and reports its diagnostics by calling back
+//! with borrowed strings, which are copied. Each side only frees what it allocated.
+
+use std::{
+    ffi::c_void,
+    panic::{self, AssertUnwindSafe},
+    slice, str,
+};
+
+use oxc_ast::decode_estree_binary;
+use oxc_span::Span;
+
+use crate::rule::RuleCategory;
+
+/// The version of the C interface of this module, bumped on any change of the `#[repr(C)]` types.
+/// Libraries declaring another version are not loaded.
+pub const NATIVE_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// The name of the function exported by [`declare_plugin!`](crate::declare_plugin)
+#[doc(hidden)]
+pub const NATIVE_PLUGIN_SYMBOL: &[u8] = b"oxc_linter_plugin\0";
+
+/// A UTF-8 string borrowed across the C interface
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FfiStr {
+    ptr: *const u8,
+    len: usize,
+}
+
+impl FfiStr {
+    pub fn new(s: &str) -> Self {
+        Self { ptr: s.as_ptr(), len: s.len() }
+    }
+
+    /// `None` when the string is not valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// `self` must point to `len` bytes which live for `'a`.
+    unsafe fn to_str<'a>(self) -> Option<&'a str> {
+        if self.len == 0 {
+            return Some("");
+        }
+        // SAFETY: guaranteed by the caller
+        str::from_utf8(unsafe { slice::from_raw_parts(self.ptr, self.len) }).ok()
+    }
+}
+
+/// A rule of a [`NativePluginDeclaration`], the strings live as long as the library
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug)]
+pub struct NativeRuleDeclaration {
+    pub name: FfiStr,
+    /// The lowercase name of a [`RuleCategory`], e.g. `correctness`
+    pub category: FfiStr,
+    /// Empty when the rule is not documented
+    pub documentation: FfiStr,
+}
+
+/// Called by the rules of a library for each diagnostic, `help` is empty when there is none.
+/// The span is in UTF-16 code units, like the spans of the ESTree AST.
+#[doc(hidden)]
+pub type NativeReportFn =
+    extern "C" fn(reporter: *mut c_void, message: FfiStr, help: FfiStr, start: u32, end: u32);
+
+/// The file given to a rule of a library, borrowed for the call
+#[doc(hidden)]
+#[repr(C)]
+pub struct NativeRunInput {
+    pub rule_index: usize,
+    pub source_text: FfiStr,
+    pub file_path: FfiStr,
+    /// The options of the rule as JSON
+    pub options: FfiStr,
+    pub estree_binary: *const u8,
+    pub estree_binary_len: usize,
+    /// Passed back to `report`
+    pub reporter: *mut c_void,
+    pub report: NativeReportFn,
+}
+
+/// The plugin returned by the function exported by [`declare_plugin!`](crate::declare_plugin),
+/// it lives as long as the library. The version comes first so that it can be checked before
+/// anything else is read.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug)]
+pub struct NativePluginDeclaration {
+    pub abi_version: u32,
+    pub name: FfiStr,
+    pub rules: *const NativeRuleDeclaration,
+    pub rules_len: usize,
+    pub run: extern "C" fn(input: &NativeRunInput),
+}
+
+// SAFETY: the declaration and the strings it points to are never mutated once created.
+unsafe impl Send for NativePluginDeclaration {}
+// SAFETY: see above
+unsafe impl Sync for NativePluginDeclaration {}
+
+impl NativePluginDeclaration {
+    /// The declaration of `rules`, created once by [`declare_plugin!`](crate::declare_plugin).
+    /// It is leaked, as the library is never unloaded.
+    #[doc(hidden)]
+    pub fn new(
+        name: &'static str,
+        rules: &'static [&'static dyn NativeRule],
+        run: extern "C" fn(input: &NativeRunInput),
+    ) -> Self {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                let category = rule.category().to_string().to_lowercase();
+                NativeRuleDeclaration {
+                    name: FfiStr::new(rule.name()),
+                    category: FfiStr::new(Box::leak(category.into_boxed_str())),
+                    documentation: FfiStr::new(rule.documentation().unwrap_or_default()),
+                }
+            })
+            .collect::<Vec<_>>()
+            .leak();
+        Self {
+            abi_version: NATIVE_PLUGIN_ABI_VERSION,
+            name: FfiStr::new(name),
+            rules: rules.as_ptr(),
+            rules_len: rules.len(),
+            run,
+        }
+    }
+}
+
+/// A rule of a plugin library, see the [module documentation](self).
+pub trait NativeRule: Sync {
+    fn name(&self) -> &'static str;
+
+    fn category(&self) -> RuleCategory;
+
+    fn documentation(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Run on each file, with its ESTree AST
+    fn run(&self, ctx: &NativeContext<'_>);
+}
+
+/// The file given to a [`NativeRule`], and reporting its diagnostics
+pub struct NativeContext<'a> {
+    input: &'a NativeRunInput,
+    source_text: &'a str,
+    file_path: &'a str,
+    options: serde_json::Value,
+    ast: serde_json::Value,
+}
+
+impl<'a> NativeContext<'a> {
+    pub fn source_text(&self) -> &'a str {
+        self.source_text
+    }
+
+    pub fn file_path(&self) -> &'a str {
+        self.file_path
+    }
+
+    /// The options following the severity of the rule in the configuration file,
+    /// e.g. `[{ "max": 1 }]` for `"acme/no-foo": ["error", { "max": 1 }]`.
+    pub fn options(&self) -> &serde_json::Value {
+        &self.options
+    }
+
+    /// The ESTree AST of the file, with the spans in UTF-16 code units as in JavaScript
+    pub fn ast(&self) -> &serde_json::Value {
+        &self.ast
+    }
+
+    /// Report `message` at `span`, in UTF-16 code units like the spans of [`Self::ast`]
+    pub fn report<S: AsRef<str>>(&self, message: S, span: Span) {
+        self.diagnostic(message.as_ref(), "", span);
+    }
+
+    pub fn report_with_help<S: AsRef<str>, H: AsRef<str>>(&self, message: S, help: H, span: Span) {
+        self.diagnostic(message.as_ref(), help.as_ref(), span);
+    }
+
+    fn diagnostic(&self, message: &str, help: &str, span: Span) {
+        let input = self.input;
+        (input.report)(
+            input.reporter,
+            FfiStr::new(message),
+            FfiStr::new(help),
+            span.start,
+            span.end,
+        );
+    }
+}
+
+/// Run the rule of `input` on its file, called by the function exported by
+/// [`declare_plugin!`](crate::declare_plugin). A panic of the rule is reported as a diagnostic,
+/// as it cannot unwind into the linter.
+#[doc(hidden)]
+pub fn run_native_rule(rules: &[&dyn NativeRule], input: &NativeRunInput) {
+    let report_error = |message: &str| {
+        (input.report)(input.reporter, FfiStr::new(message), FfiStr::new(""), 0, 0);
+    };
+    let Some(rule) = rules.get(input.rule_index) else {
+        return report_error("The rule does not exist.");
+    };
+    // SAFETY: the linter borrows the strings and the AST for the call, see `NativeRuleProxy`
+    let (source_text, file_path, options, estree_binary) = unsafe {
+        (
+            input.source_text.to_str(),
+            input.file_path.to_str(),
+            input.options.to_str(),
+            slice::from_raw_parts(input.estree_binary, input.estree_binary_len),
+        )
+    };
+    let (Some(source_text), Some(file_path), Some(options)) = (source_text, file_path, options)
+    else {
+        return report_error("The linter passed invalid UTF-8.");
+    };
+    let ast = match decode_estree_binary(estree_binary) {
+        Ok(ast) => ast,
+        Err(error) => return report_error(&format!("The plugin cannot read the AST: {error}.")),
+    };
+    let options = serde_json::from_str(options).unwrap_or_default();
+    let ctx = NativeContext { input, source_text, file_path, options, ast };
+    if panic::catch_unwind(AssertUnwindSafe(|| rule.run(&ctx))).is_err() {
+        report_error("The rule panicked.");
+    }
+}
+
+/// Export a plugin of `$name` with the [`NativeRule`]s `$rule` from a `cdylib` crate.
+///
+/// ```ignore
+/// oxc_linter::declare_plugin!("acme", [NoFoo, NoBar]);
+/// ```
+#[macro_export]
+macro_rules! declare_plugin {
+    ($name:expr, [$($rule:expr),* $(,)?]) => {
+        #[no_mangle]
+        pub extern "C" fn oxc_linter_plugin(
+        ) -> &'static $crate::native_plugin::NativePluginDeclaration {
+            use $crate::native_plugin::{NativePluginDeclaration, NativeRule, NativeRunInput};
+
+            static RULES: &[&dyn NativeRule] = &[$(&$rule),*];
+            static DECLARATION: ::std::sync::OnceLock<NativePluginDeclaration> =
+                ::std::sync::OnceLock::new();
+
+            extern "C" fn run(input: &NativeRunInput) {
+                $crate::native_plugin::run_native_rule(RULES, input);
+            }
+
+            DECLARATION.get_or_init(|| NativePluginDeclaration::new($name, RULES, run))
+        }
+    };
+}
+
+#[cfg(any(unix, windows))]
+pub(crate) use self::host::load_native_plugin;
+
+/// Loading the libraries, which needs a platform with dynamic libraries
+#[cfg(any(unix, windows))]
+mod host {
+    use std::{cell::RefCell, ffi::c_void, path::Path, slice, sync::Arc};
+
+    use oxc_diagnostics::Error;
+    use oxc_span::Span;
+
+    use super::{
+        FfiStr, NativePluginDeclaration, NativeRuleDeclaration, NativeRunInput,
+        NATIVE_PLUGIN_ABI_VERSION, NATIVE_PLUGIN_SYMBOL,
+    };
+    use crate::{
+        plugin::{utf8_offset, FailedToLoadPluginError, PluginContext, PluginRule, RulePlugin},
+        rule::RuleCategory,
+    };
+
+    thread_local! {
+        /// The binary AST of the last linted file, shared by the rules of the libraries
+        static ESTREE_BINARY: RefCell<(String, Vec<u8>)> = RefCell::new((String::new(), vec![]));
+    }
+
+    /// A plugin loaded from a library
+    #[derive(Debug)]
+    struct NativePlugin {
+        name: &'static str,
+        rules: Vec<Arc<dyn PluginRule>>,
+    }
+
+    impl RulePlugin for NativePlugin {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn rules(&self) -> Vec<Arc<dyn PluginRule>> {
+            self.rules.clone()
+        }
+    }
+
+    /// A rule of a library, run through its declaration
+    #[derive(Debug)]
+    struct NativeRuleProxy {
+        declaration: &'static NativePluginDeclaration,
+        index: usize,
+        name: &'static str,
+        category: RuleCategory,
+        documentation: Option<&'static str>,
+    }
+
+    /// A diagnostic reported by a library, copied out of its strings
+    struct NativeReport {
+        message: String,
+        help: Option<String>,
+        start: u32,
+        end: u32,
+    }
+
+    /// Load the library at `path`. It is never unloaded, as its code and the names of its rules
+    /// are used until the process exits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the library cannot be loaded or declares another version of the interface.
+    pub(crate) fn load_native_plugin(path: &Path) -> Result<Arc<dyn RulePlugin>, Error> {
+        let error =
+            |message: String| Error::new(FailedToLoadPluginError(path.to_path_buf(), message));
+        // SAFETY: loading a library runs its initialization routines, which the libraries built
+        // with `declare_plugin!` do not have.
+        let library =
+            unsafe { libloading::Library::new(path) }.map_err(|e| error(e.to_string()))?;
+        // SAFETY: the symbol is the `extern "C"` function exported by `declare_plugin!`, which
+        // only returns a pointer to its declaration.
+        let declaration = unsafe {
+            let symbol = library
+                .get::<extern "C" fn() -> *const NativePluginDeclaration>(NATIVE_PLUGIN_SYMBOL)
+                .map_err(|e| error(e.to_string()))?;
+            symbol()
+        };
+        // The declaration and its strings live as long as the library
+        std::mem::forget(library);
+        if declaration.is_null() {
+            return Err(error("the plugin returned no declaration".to_string()));
+        }
+        // SAFETY: the version is the first field of every version of the declaration
+        let abi_version = unsafe { declaration.cast::<u32>().read() };
+        if abi_version != NATIVE_PLUGIN_ABI_VERSION {
+            return Err(error(format!(
+                "the plugin interface version is {abi_version}, expected {NATIVE_PLUGIN_ABI_VERSION}"
+            )));
+        }
+        // SAFETY: the declaration is of this version, and is never mutated nor freed
+        let declaration: &'static NativePluginDeclaration = unsafe { &*declaration };
+        let invalid = || error("the plugin declares names which are not UTF-8".to_string());
+        // SAFETY: see above
+        let name = unsafe { declaration.name.to_str() }.ok_or_else(invalid)?;
+        let rules: &[NativeRuleDeclaration] = if declaration.rules_len == 0 {
+            &[]
+        } else {
+            // SAFETY: see above
+            unsafe { slice::from_raw_parts(declaration.rules, declaration.rules_len) }
+        };
+        let rules = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                // SAFETY: see above
+                let (name, category, documentation) = unsafe {
+                    (rule.name.to_str(), rule.category.to_str(), rule.documentation.to_str())
+                };
+                let (Some(name), Some(category), Some(documentation)) =
+                    (name, category, documentation)
+                else {
+                    return Err(invalid());
+                };
+                Ok(Arc::new(NativeRuleProxy {
+                    declaration,
+                    index,
+                    name,
+                    category: RuleCategory::from(category).unwrap_or(RuleCategory::Restriction),
+                    documentation: (!documentation.is_empty()).then_some(documentation),
+                }) as Arc<dyn PluginRule>)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Arc::new(NativePlugin { name, rules }))
+    }
+
+    impl PluginRule for NativeRuleProxy {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn category(&self) -> RuleCategory {
+            self.category
+        }
+
+        fn documentation(&self) -> Option<&'static str> {
+            self.documentation
+        }
+
+        fn run_once(&self, ctx: &PluginContext<'_, '_>) {
+            let source_text = ctx.source_text();
+            let estree_binary = ESTREE_BINARY.with(|cache| {
+                let mut cache = cache.borrow_mut();
+                if cache.0 != source_text {
+                    *cache = (source_text.to_string(), ctx.estree_binary());
+                }
+                cache.1.clone()
+            });
+            let options = serde_json::to_string(ctx.options()).unwrap_or_default();
+            let file_path = ctx.file_path().to_string_lossy();
+            let mut reports: Vec<NativeReport> = vec![];
+            let input = NativeRunInput {
+                rule_index: self.index,
+                source_text: FfiStr::new(source_text),
+                file_path: FfiStr::new(&file_path),
+                options: FfiStr::new(&options),
+                estree_binary: estree_binary.as_ptr(),
+                estree_binary_len: estree_binary.len(),
+                reporter: std::ptr::addr_of_mut!(reports).cast::<c_void>(),
+                report,
+            };
+            (self.declaration.run)(&input);
+            for report in reports {
+                let start = utf8_offset(source_text, report.start as usize);
+                let end = utf8_offset(source_text, report.end as usize);
+                match report.help {
+                    Some(help) => ctx.report_with_help(report.message, help, Span::new(start, end)),
+                    None => ctx.report(report.message, Span::new(start, end)),
+                }
+            }
+        }
+    }
+
+    /// The `NativeReportFn` given to the libraries, copying the diagnostic into the reports
+    extern "C" fn report(
+        reporter: *mut c_void,
+        message: FfiStr,
+        help: FfiStr,
+        start: u32,
+        end: u32,
+    ) {
+        // SAFETY: `reporter` is the `Vec` of `NativeRuleProxy::run_once`, which outlives the call
+        // of the library, and the strings are borrowed from the library for this call.
+        let (reports, message, help) = unsafe {
+            (&mut *reporter.cast::<Vec<NativeReport>>(), message.to_str(), help.to_str())
+        };
+        let message = message.unwrap_or("The rule reported a message which is not UTF-8.");
+        let help = help.filter(|help| !help.is_empty());
+        reports.push(NativeReport {
+            message: message.to_string(),
+            help: help.map(ToString::to_string),
+            start,
+            end,
+        });
+    }
+}
+
+#[cfg(all(test, any(unix, windows)))]
+mod test {
+    use std::{
+        env,
+        path::{Path, PathBuf},
+        process::Command,
+        rc::Rc,
+        sync::Arc,
+    };
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::load_native_plugin;
+    use crate::{LintContext, Linter};
+
+    /// Build the library of `fixtures/native_plugin`, next to the target directory of the tests
+    fn build_fixture() -> PathBuf {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/native_plugin");
+        // The test executable is in `<target>/debug/deps`
+        let target_dir =
+            env::current_exe().unwrap().ancestors().nth(3).unwrap().join("native_plugin");
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let status = Command::new(cargo)
+            .arg("build")
+            .arg("--manifest-path")
+            .arg(manifest.join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "failed to build {}", manifest.display());
+        target_dir.join("debug").join(format!(
+            "{}native_plugin_fixture{}",
+            env::consts::DLL_PREFIX,
+            env::consts::DLL_SUFFIX
+        ))
+    }
+
+    #[test]
+    fn native_plugin() {
+        let plugin = load_native_plugin(&build_fixture()).unwrap();
+        assert_eq!(plugin.name(), "acme");
+        let rules = plugin.rules();
+        assert_eq!(rules.iter().map(|rule| rule.name()).collect::<Vec<_>>(), ["no-foo", "panics"]);
+        assert_eq!(rules[0].documentation(), Some("Disallow an identifier"));
+
+        let source_text = "let é = foo; foo.bar; baz;";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            Rc::new(SemanticBuilder::new(source_text, source_type).build(program).semantic);
+        let run = |json| {
+            let linter =
+                Linter::default().with_plugin(Arc::clone(&plugin)).with_config_json(json).unwrap();
+            let messages = linter.run(LintContext::new(Path::new("test.js").into(), &semantic));
+            messages
+                .iter()
+                .filter(|message| message.error.to_string().starts_with("acme"))
+                .map(|message| (message.error.to_string(), message.start()))
+                .collect::<Vec<_>>()
+        };
+
+        // The spans are converted from UTF-16 code units
+        assert_eq!(
+            run(serde_json::json!({ "rules": { "acme/no-foo": "error" } })),
+            [
+                ("acme(no-foo): Unexpected foo.".to_string(), 9),
+                ("acme(no-foo): Unexpected foo.".to_string(), 14),
+            ]
+        );
+        assert_eq!(
+            run(serde_json::json!({ "rules": { "acme/no-foo": ["error", "baz"] } })),
+            [("acme(no-foo): Unexpected baz.".to_string(), 23)]
+        );
+        assert_eq!(
+            run(serde_json::json!({ "rules": { "acme/panics": "error" } })),
+            [("acme(panics): The rule panicked.".to_string(), 0)]
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use std::path::PathBuf;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use crate::{
     config::{
@@ -6,28 +9,49 @@ use crate::{
             FailedToParseAllowWarnDenyFromJsonValueError,
             FailedToParseAllowWarnDenyFromNumberError, FailedToParseAllowWarnDenyFromStringError,
         },
-        ESLintConfig,
+        ESLintConfig, ESLintRule,
     },
+    fixer::FixKind,
     rules::RULES,
+    type_info::{TypeInfo, TypeInfoFile},
     ESLintEnv, ESLintSettings, RuleCategory, RuleEnum,
 };
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{Error, Severity};
 use rustc_hash::FxHashSet;
 use serde_json::{Number, Value};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LintOptions {
     /// Allow / Deny rules in order. [("allow" / "deny", rule name)]
     /// Defaults to [("deny", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
+    /// Include the rules of the nursery category in the categories and `all` of `filter`.
+    /// Without it, rules still under development are only enabled by their name.
+    pub nursery: bool,
     pub config_path: Option<PathBuf>,
+    /// Look for configuration files from each linted file upward when `config_path` is not set.
+    pub nested_config: bool,
     pub fix: bool,
+    /// The most dangerous kind of fixes applied when `fix` is enabled.
+    pub fix_kind: FixKind,
+    /// What to do with the fixed code when `fix` is enabled.
+    pub fix_output: FixOutput,
     pub timing: bool,
+    /// Report `eslint-disable` comments which did not suppress any diagnostic.
+    pub report_unused_directives: bool,
+    /// A JSON file with the types of expressions for type-aware rules, see `TypeInfoFile`.
+    pub type_info_path: Option<PathBuf>,
+    /// Load the plugin libraries and the JavaScript plugins listed in the `plugins` of the
+    /// configuration files, which run arbitrary code.
+    pub allow_plugins: bool,
     pub import_plugin: bool,
     pub jest_plugin: bool,
     pub jsx_a11y_plugin: bool,
     pub nextjs_plugin: bool,
+    pub promise_plugin: bool,
     pub react_perf_plugin: bool,
+    pub security_plugin: bool,
+    pub jsdoc_plugin: bool,
     pub env: ESLintEnv,
 }
 
@@ -35,14 +59,24 @@ impl Default for LintOptions {
     fn default() -> Self {
         Self {
             filter: vec![(AllowWarnDeny::Deny, String::from("correctness"))],
+            nursery: false,
             config_path: None,
+            nested_config: false,
             fix: false,
+            fix_kind: FixKind::default(),
+            fix_output: FixOutput::default(),
             timing: false,
+            report_unused_directives: false,
+            type_info_path: None,
+            allow_plugins: false,
             import_plugin: false,
             jest_plugin: false,
             jsx_a11y_plugin: false,
             nextjs_plugin: false,
+            promise_plugin: false,
             react_perf_plugin: false,
+            security_plugin: false,
+            jsdoc_plugin: false,
             env: ESLintEnv::default(),
         }
     }
@@ -57,24 +91,66 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_nursery(mut self, yes: bool) -> Self {
+        self.nursery = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_config_path(mut self, filter: Option<PathBuf>) -> Self {
         self.config_path = filter;
         self
     }
 
+    #[must_use]
+    pub fn with_nested_config(mut self, yes: bool) -> Self {
+        self.nested_config = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_fix(mut self, yes: bool) -> Self {
         self.fix = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, kind: FixKind) -> Self {
+        self.fix_kind = kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix_output(mut self, fix_output: FixOutput) -> Self {
+        self.fix_output = fix_output;
+        self
+    }
+
     #[must_use]
     pub fn with_timing(mut self, yes: bool) -> Self {
         self.timing = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_type_info_path(mut self, path: Option<PathBuf>) -> Self {
+        self.type_info_path = path;
+        self
+    }
+
+    #[must_use]
+    pub fn with_allow_plugins(mut self, yes: bool) -> Self {
+        self.allow_plugins = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_import_plugin(mut self, yes: bool) -> Self {
         self.import_plugin = yes;
@@ -99,12 +175,30 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_promise_plugin(mut self, yes: bool) -> Self {
+        self.promise_plugin = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_react_perf_plugin(mut self, yes: bool) -> Self {
         self.react_perf_plugin = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_security_plugin(mut self, yes: bool) -> Self {
+        self.security_plugin = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_jsdoc_plugin(mut self, yes: bool) -> Self {
+        self.jsdoc_plugin = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_env(mut self, env: Vec<String>) -> Self {
         self.env = ESLintEnv::from_vec(env);
@@ -112,6 +206,17 @@ impl LintOptions {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum FixOutput {
+    /// Write the fixed code back to the files
+    #[default]
+    Write,
+    /// Print a unified diff of the fixes to stdout, leaving the files untouched
+    Diff,
+    /// Print the fixed code to stdout, leaving the files untouched
+    Stdout,
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum AllowWarnDeny {
     Allow, // Off
@@ -127,6 +232,24 @@ impl AllowWarnDeny {
     pub fn is_allow(self) -> bool {
         self == Self::Allow
     }
+
+    /// The ESLint severity name, e.g. `"error"` for `Deny`.
+    pub fn as_eslint_str(self) -> &'static str {
+        match self {
+            Self::Allow => "off",
+            Self::Warn => "warn",
+            Self::Deny => "error",
+        }
+    }
+
+    /// The severity of the diagnostics of a rule configured with this, `None` for `Allow`.
+    pub fn as_severity(self) -> Option<Severity> {
+        match self {
+            Self::Allow => None,
+            Self::Warn => Some(Severity::Warning),
+            Self::Deny => Some(Severity::Error),
+        }
+    }
 }
 
 impl TryFrom<&str> for AllowWarnDeny {
@@ -170,7 +293,10 @@ impl TryFrom<&Number> for AllowWarnDeny {
 const JEST_PLUGIN_NAME: &str = "jest";
 const JSX_A11Y_PLUGIN_NAME: &str = "jsx_a11y";
 const NEXTJS_PLUGIN_NAME: &str = "nextjs";
+const PROMISE_PLUGIN_NAME: &str = "promise";
 const REACT_PERF_PLUGIN_NAME: &str = "react_perf";
+const SECURITY_PLUGIN_NAME: &str = "security";
+const JSDOC_PLUGIN_NAME: &str = "jsdoc";
 
 impl LintOptions {
     /// # Errors
@@ -179,11 +305,33 @@ impl LintOptions {
     pub fn derive_rules_and_settings_and_env(
         &self,
     ) -> Result<(Vec<RuleEnum>, ESLintSettings, ESLintEnv), Error> {
-        let config =
-            self.config_path.as_ref().map(|path| ESLintConfig::from_file(path)).transpose()?;
+        let config = self.load_config()?;
+        Ok(self.derive_rules_and_settings_and_env_from_config(config.as_ref()))
+    }
+
+    pub(crate) fn load_config(&self) -> Result<Option<ESLintConfig>, Error> {
+        self.config_path.as_ref().map(|path| ESLintConfig::from_file(path)).transpose()
+    }
 
+    pub(crate) fn load_type_info(&self) -> Result<Option<Arc<dyn TypeInfo>>, Error> {
+        let Some(path) = &self.type_info_path else {
+            return Ok(None);
+        };
+        Ok(Some(Arc::new(TypeInfoFile::from_file(path)?)))
+    }
+
+    /// Like `derive_rules_and_settings_and_env`, but with an already loaded configuration.
+    pub(crate) fn derive_rules_and_settings_and_env_from_config(
+        &self,
+        config: Option<&ESLintConfig>,
+    ) -> (Vec<RuleEnum>, ESLintSettings, ESLintEnv) {
         let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
         let all_rules = self.get_filtered_rules();
+        let category_rules = all_rules
+            .iter()
+            .filter(|rule| self.nursery || rule.category() != RuleCategory::Nursery)
+            .cloned()
+            .collect::<Vec<_>>();
 
         for (allow_warn_deny, name_or_category) in &self.filter {
             let maybe_category = RuleCategory::from(name_or_category.as_str());
@@ -191,11 +339,14 @@ impl LintOptions {
                 AllowWarnDeny::Deny | AllowWarnDeny::Warn => {
                     match maybe_category {
                         Some(category) => rules.extend(
-                            all_rules.iter().filter(|rule| rule.category() == category).cloned(),
+                            category_rules
+                                .iter()
+                                .filter(|rule| rule.category() == category)
+                                .cloned(),
                         ),
                         None => {
                             if name_or_category == "all" {
-                                rules.extend(all_rules.iter().cloned());
+                                rules.extend(category_rules.iter().cloned());
                             } else {
                                 rules.extend(
                                     all_rules
@@ -222,7 +373,7 @@ impl LintOptions {
             }
         }
 
-        if let Some(config) = &config {
+        if let Some(config) = config {
             config.override_rules(&mut rules, &all_rules);
         }
 
@@ -231,9 +382,95 @@ impl LintOptions {
         let (settings, env) = config.map(ESLintConfig::properties).unwrap_or_default();
 
         // for stable diagnostics output ordering
-        rules.sort_unstable_by_key(RuleEnum::name);
+        rules.sort_unstable();
+
+        (rules, settings, env)
+    }
 
-        Ok((rules, settings, env))
+    /// The configuration files applying to the file at `path` when looking up nested configuration files,
+    /// ordered from the outermost to the innermost.
+    ///
+    /// Returns an empty list when `config_path` is set, or `nested_config` is off.
+    pub fn nested_config_paths(&self, path: &Path) -> Vec<PathBuf> {
+        if self.config_path.is_some() || !self.nested_config {
+            return vec![];
+        }
+        path.parent().map_or(vec![], ESLintConfig::discover)
+    }
+
+    /// Resolve the configuration used for linting `path` into an ESLint shaped json object,
+    /// with the `-A` / `-D` filters and the configuration file, including its matching `overrides`, applied.
+    ///
+    /// Rules are keyed by `plugin/rule-name`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Err` if there are any errors parsing the configuration file.
+    pub fn resolved_config(&self, path: &Path) -> Result<Value, Error> {
+        let nested_config_paths = self.nested_config_paths(path);
+        let config = if nested_config_paths.is_empty() {
+            self.load_config()?
+        } else {
+            Some(ESLintConfig::from_files(&nested_config_paths)?)
+        };
+        let config = config.map(|config| config.with_overrides(&config.matching_overrides(path)));
+        let (rules, settings, env) =
+            self.derive_rules_and_settings_and_env_from_config(config.as_ref());
+
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                let key = format!("{}/{}", rule.plugin_name(), rule.name());
+                let value = match config
+                    .as_ref()
+                    .and_then(|config| config.find_rule(rule.plugin_name(), rule.name()))
+                {
+                    Some(ESLintRule { severity, config: Some(Value::Array(options)), .. }) => {
+                        let mut value = vec![Value::from(severity.as_eslint_str())];
+                        value.extend(options.iter().cloned());
+                        Value::Array(value)
+                    }
+                    Some(ESLintRule { severity, .. }) => Value::from(severity.as_eslint_str()),
+                    None => Value::from(self.filter_severity(rule).as_eslint_str()),
+                };
+                (key, value)
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        let plugins = [
+            (self.import_plugin, "import"),
+            (self.jest_plugin, JEST_PLUGIN_NAME),
+            (self.jsx_a11y_plugin, JSX_A11Y_PLUGIN_NAME),
+            (self.nextjs_plugin, NEXTJS_PLUGIN_NAME),
+            (self.promise_plugin, PROMISE_PLUGIN_NAME),
+            (self.react_perf_plugin, REACT_PERF_PLUGIN_NAME),
+            (self.security_plugin, SECURITY_PLUGIN_NAME),
+            (self.jsdoc_plugin, JSDOC_PLUGIN_NAME),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, name)| enabled.then_some(name))
+        .collect::<Vec<_>>();
+
+        Ok(serde_json::json!({
+            "rules": rules,
+            "env": env,
+            "settings": settings,
+            "plugins": plugins,
+        }))
+    }
+
+    /// The severity of the last `-W` / `-D` filter enabling this rule.
+    fn filter_severity(&self, rule: &RuleEnum) -> AllowWarnDeny {
+        self.filter
+            .iter()
+            .rev()
+            .filter(|(allow_warn_deny, _)| allow_warn_deny.is_warn_deny())
+            .find(|(_, name_or_category)| {
+                name_or_category == "all"
+                    || name_or_category == rule.name()
+                    || RuleCategory::from(name_or_category) == Some(rule.category())
+            })
+            .map_or(AllowWarnDeny::Deny, |(allow_warn_deny, _)| *allow_warn_deny)
     }
 
     // get final filtered rules by reading `self.jest_plugin` and `self.jsx_a11y_plugin`
@@ -249,8 +486,63 @@ impl LintOptions {
         may_exclude_plugin_rules(self.jest_plugin, JEST_PLUGIN_NAME);
         may_exclude_plugin_rules(self.jsx_a11y_plugin, JSX_A11Y_PLUGIN_NAME);
         may_exclude_plugin_rules(self.nextjs_plugin, NEXTJS_PLUGIN_NAME);
+        may_exclude_plugin_rules(self.promise_plugin, PROMISE_PLUGIN_NAME);
         may_exclude_plugin_rules(self.react_perf_plugin, REACT_PERF_PLUGIN_NAME);
+        may_exclude_plugin_rules(self.security_plugin, SECURITY_PLUGIN_NAME);
+        may_exclude_plugin_rules(self.jsdoc_plugin, JSDOC_PLUGIN_NAME);
 
         rules
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use super::{AllowWarnDeny, LintOptions};
+
+    #[test]
+    fn test_resolved_config() {
+        let config_path = env::current_dir().unwrap().join("fixtures/eslint_config.json");
+        let options = LintOptions::default().with_config_path(Some(config_path));
+        let config = options.resolved_config(Path::new("test.js")).unwrap();
+        let rules = &config["rules"];
+        assert_eq!(rules["eslint/no-debugger"], "error");
+        assert_eq!(
+            rules["eslint/eqeqeq"],
+            serde_json::json!(["error", "always", { "null": "ignore" }])
+        );
+        assert_eq!(rules["typescript/ban-types"], "error");
+        assert!(rules.get("eslint/no-console").is_none());
+        assert_eq!(config["env"]["builtin"], true);
+        assert_eq!(config["plugins"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_resolved_nested_config() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/nested_config");
+        let options = LintOptions::default().with_nested_config(true);
+        let config = options.resolved_config(&fixture_path.join("packages/a/index.js")).unwrap();
+        assert!(config["rules"].get("eslint/no-console").is_none());
+        assert_eq!(config["rules"]["eslint/no-debugger"], "error");
+        assert_eq!(config["env"]["browser"], true);
+
+        let config = options.resolved_config(&fixture_path.join("packages/b/index.js")).unwrap();
+        assert!(config["rules"].get("eslint/no-debugger").is_none());
+    }
+
+    #[test]
+    fn test_nursery() {
+        let options = LintOptions::default().with_filter(vec![
+            (AllowWarnDeny::Deny, "all".to_string()),
+            (AllowWarnDeny::Deny, "no-undef".to_string()),
+        ]);
+        let (rules, _, _) = options.derive_rules_and_settings_and_env_from_config(None);
+        assert!(rules.iter().any(|rule| rule.name() == "no-undef"));
+        assert!(!rules.iter().any(|rule| rule.name() == "no-unused-vars"));
+
+        let (rules, _, _) =
+            options.with_nursery(true).derive_rules_and_settings_and_env_from_config(None);
+        assert!(rules.iter().any(|rule| rule.name() == "no-unused-vars"));
+    }
+}
@@ -2,10 +2,19 @@ use memchr::memmem::Finder;
 
 use oxc_span::{SourceType, Span};
 
-use super::{JavaScriptSource, SCRIPT_END, SCRIPT_START};
+use super::{JavaScriptSource, Processor, SCRIPT_END, SCRIPT_START};
 
 const ASTRO_SPLIT: &str = "---";
 
+/// Lints the frontmatter and the `<script>` elements of Astro components, see `PartialLoader::processor`.
+pub struct AstroProcessor;
+
+impl Processor for AstroProcessor {
+    fn preprocess<'a>(&self, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+        AstroPartialLoader::new(source_text).parse()
+    }
+}
+
 pub struct AstroPartialLoader<'a> {
     source_text: &'a str,
 }
@@ -0,0 +1,210 @@
+use memchr::memmem::Finder;
+
+use oxc_span::SourceType;
+
+use super::{JavaScriptSource, Processor, SCRIPT_END, SCRIPT_START};
+
+const COMMENT_START: &str = "<!--";
+const COMMENT_END: &str = "-->";
+
+/// Lints the inline `<script>` elements of HTML files, see `PartialLoader::processor`.
+pub struct HtmlProcessor;
+
+impl Processor for HtmlProcessor {
+    fn preprocess<'a>(&self, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+        HtmlPartialLoader::new(source_text).parse()
+    }
+}
+
+pub struct HtmlPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> HtmlPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    /// The inline <script> elements of JavaScript, classic scripts or modules by their `type`.
+    /// Data blocks such as `type="application/json"` and `type="importmap"` are skipped.
+    /// https://html.spec.whatwg.org/multipage/scripting.html#attr-script-type
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        // Tag names are case-insensitive, lowercasing ASCII keeps the offsets.
+        let lowercase_text = self.source_text.to_ascii_lowercase();
+        let script_end_finder = Finder::new(SCRIPT_END);
+        let comment_end_finder = Finder::new(COMMENT_END);
+
+        let mut results = vec![];
+        let mut pointer = 0;
+
+        while let Some(offset) = lowercase_text[pointer..].find('<') {
+            pointer += offset;
+            let rest = &lowercase_text[pointer..];
+
+            if let Some(comment) = rest.strip_prefix(COMMENT_START) {
+                let Some(offset) = comment_end_finder.find(comment.as_bytes()) else {
+                    break;
+                };
+                pointer += COMMENT_START.len() + offset + COMMENT_END.len();
+                continue;
+            }
+
+            let is_script_tag = rest.starts_with(SCRIPT_START)
+                && rest[SCRIPT_START.len()..]
+                    .starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/');
+            if !is_script_tag {
+                pointer += 1;
+                continue;
+            }
+            pointer += SCRIPT_START.len();
+
+            // find closing ">"
+            let Some(offset) = Self::find_tag_closing_angle(&self.source_text[pointer..]) else {
+                break;
+            };
+            let attributes = &self.source_text[pointer..pointer + offset];
+            pointer += offset + 1;
+            let js_start = pointer;
+
+            // find "</script>", a self-closing tag has no content and is not one in HTML
+            let Some(offset) = script_end_finder.find(lowercase_text[pointer..].as_bytes()) else {
+                break;
+            };
+            let js_end = pointer + offset;
+            pointer = js_end + SCRIPT_END.len();
+
+            if let Some(source_type) = script_source_type(attributes) {
+                let source_text = &self.source_text[js_start..js_end];
+                results.push(JavaScriptSource::new(source_text, source_type, js_start));
+            }
+        }
+        results
+    }
+
+    /// Find the `>` closing the tag, outside of quoted attribute values.
+    fn find_tag_closing_angle(text: &str) -> Option<usize> {
+        let mut quote = None;
+        for (offset, c) in text.char_indices() {
+            match (quote, c) {
+                (None, '"' | '\'') => quote = Some(c),
+                (Some(q), _) if q == c => quote = None,
+                (None, '>') => return Some(offset),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// `None` for a data block, whose `type` is neither a JavaScript MIME type nor `module`.
+fn script_source_type(attributes: &str) -> Option<SourceType> {
+    let script_type = attribute_value(attributes, "type").unwrap_or_default();
+    let script_type = script_type.trim().to_ascii_lowercase();
+    match script_type.as_str() {
+        "module" => Some(SourceType::default().with_module(true)),
+        ""
+        | "text/javascript"
+        | "application/javascript"
+        | "application/ecmascript"
+        | "application/x-ecmascript"
+        | "application/x-javascript"
+        | "text/ecmascript"
+        | "text/javascript1.0"
+        | "text/javascript1.1"
+        | "text/javascript1.2"
+        | "text/javascript1.3"
+        | "text/javascript1.4"
+        | "text/javascript1.5"
+        | "text/jscript"
+        | "text/livescript"
+        | "text/x-ecmascript"
+        | "text/x-javascript" => Some(SourceType::default()),
+        _ => None,
+    }
+}
+
+/// The value of the attribute `name` among the attributes of a tag, empty if it has none.
+fn attribute_value<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let attribute_name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+
+        let mut value = "";
+        if let Some(after_equal) = rest.strip_prefix('=') {
+            let after_equal = after_equal.trim_start();
+            let (v, after_value) = if let Some(quote @ ('"' | '\'')) = after_equal.chars().next() {
+                let inner = &after_equal[1..];
+                let end = inner.find(quote).unwrap_or(inner.len());
+                (&inner[..end], &inner[(end + 1).min(inner.len())..])
+            } else {
+                let end = after_equal
+                    .find(|c: char| c.is_ascii_whitespace())
+                    .unwrap_or(after_equal.len());
+                after_equal.split_at(end)
+            };
+            value = v;
+            rest = after_value;
+        }
+
+        if attribute_name.eq_ignore_ascii_case(name) {
+            return Some(value);
+        }
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HtmlPartialLoader, JavaScriptSource};
+
+    fn parse_html(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        HtmlPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let source_text = r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <script>var a = 1;</script>
+            <SCRIPT type="module" data-x='>'>import b from "b";</SCRIPT>
+          </head>
+        </html>
+        "#;
+
+        let sources = parse_html(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "var a = 1;");
+        assert!(!sources[0].source_type.is_module());
+        assert_eq!(sources[1].source_text, r#"import b from "b";"#);
+        assert!(sources[1].source_type.is_module());
+        for source in sources {
+            assert_eq!(
+                &source_text[source.start..][..source.source_text.len()],
+                source.source_text
+            );
+        }
+    }
+
+    #[test]
+    fn test_skip_data_blocks() {
+        let source_text = r#"
+        <script type="application/json">{ "a": 1 }</script>
+        <script type=importmap>{ "imports": {} }</script>
+        <script type="text/template"><div></div></script>
+        <!-- <script>commented()</script> -->
+        <scripts>not a script</scripts>
+        <script src="a.js" type="text/javascript"></script>
+        "#;
+
+        let sources = parse_html(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "");
+    }
+}
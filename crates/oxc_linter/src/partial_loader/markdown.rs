@@ -0,0 +1,164 @@
+use oxc_span::SourceType;
+
+use super::{JavaScriptSource, Processor};
+
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    /// The fenced code blocks whose info string is a JavaScript or TypeScript language,
+    /// a block which is not closed runs to the end of the document.
+    /// https://spec.commonmark.org/0.31.2/#fenced-code-blocks
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        let mut open_block: Option<(Fence, Option<SourceType>, usize)> = None;
+        let mut pointer = 0;
+
+        for line in self.source_text.split_inclusive('\n') {
+            let line_start = pointer;
+            pointer += line.len();
+            match &open_block {
+                None => {
+                    if let Some((fence, info)) = Fence::open(line) {
+                        open_block = Some((fence, language_source_type(info), pointer));
+                    }
+                }
+                Some((fence, source_type, js_start)) => {
+                    if fence.is_closed_by(line) {
+                        if let Some(source_type) = source_type {
+                            let source_text = &self.source_text[*js_start..line_start];
+                            results.push(JavaScriptSource::new(
+                                source_text,
+                                *source_type,
+                                *js_start,
+                            ));
+                        }
+                        open_block = None;
+                    }
+                }
+            }
+        }
+
+        if let Some((_, Some(source_type), js_start)) = open_block {
+            let source_text = &self.source_text[js_start..];
+            results.push(JavaScriptSource::new(source_text, source_type, js_start));
+        }
+        results
+    }
+}
+
+/// Lints the code blocks of Markdown and MDX files, see `LintServiceOptions::processors`.
+pub struct MarkdownProcessor;
+
+impl Processor for MarkdownProcessor {
+    fn preprocess<'a>(&self, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+        MarkdownPartialLoader::new(source_text).parse()
+    }
+}
+
+/// An opening code fence, of at least three backticks or tildes indented by up to three spaces.
+struct Fence {
+    marker: u8,
+    len: usize,
+}
+
+impl Fence {
+    fn open(line: &str) -> Option<(Self, &str)> {
+        let rest = Self::strip_indent(line)?;
+        let marker = *rest.as_bytes().first().filter(|c| matches!(c, b'`' | b'~'))?;
+        let len = rest.bytes().take_while(|c| *c == marker).count();
+        if len < 3 {
+            return None;
+        }
+        let info = rest[len..].trim();
+        // Backticks in the info string would make it inline code
+        if marker == b'`' && info.contains('`') {
+            return None;
+        }
+        Some((Self { marker, len }, info))
+    }
+
+    /// Closed by a fence of the same marker, at least as long and without info string.
+    fn is_closed_by(&self, line: &str) -> bool {
+        let Some(rest) = Self::strip_indent(line) else { return false };
+        let len = rest.bytes().take_while(|c| *c == self.marker).count();
+        len >= self.len && rest[len..].trim().is_empty()
+    }
+
+    fn strip_indent(line: &str) -> Option<&str> {
+        let rest = line.trim_start_matches(' ');
+        (line.len() - rest.len() <= 3).then_some(rest)
+    }
+}
+
+/// The first word of the info string names the language, e.g. ```` ```ts title="a.ts" ````.
+fn language_source_type(info: &str) -> Option<SourceType> {
+    let language = info.split_whitespace().next()?.to_ascii_lowercase();
+    let source_type = SourceType::default().with_module(true);
+    match language.as_str() {
+        "js" | "javascript" | "mjs" | "cjs" => Some(source_type),
+        "jsx" => Some(source_type.with_jsx(true)),
+        "ts" | "typescript" | "mts" | "cts" => Some(source_type.with_typescript(true)),
+        "tsx" => Some(source_type.with_typescript(true).with_jsx(true)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, MarkdownPartialLoader};
+
+    fn parse_markdown(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_markdown() {
+        let source_text = "# Title\n\n```js\nconsole.log(1);\n```\n\ntext\n\n```ts title=\"a.ts\"\nlet a: number = 1;\n```\n";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "console.log(1);\n");
+        assert!(!sources[0].source_type.is_typescript());
+        assert_eq!(sources[1].source_text, "let a: number = 1;\n");
+        assert!(sources[1].source_type.is_typescript());
+        for source in sources {
+            assert_eq!(
+                &source_text[source.start..][..source.source_text.len()],
+                source.source_text
+            );
+        }
+    }
+
+    #[test]
+    fn test_other_languages() {
+        let source_text =
+            "```sh\nnpm install\n```\n\n```\nplain\n```\n\n    ```js\n    indented code\n    ```\n";
+
+        assert!(parse_markdown(source_text).is_empty());
+    }
+
+    #[test]
+    fn test_nested_fences() {
+        let source_text = "~~~~md\n```js\nnot linted\n```\n~~~~\n\n````jsx\n```\n<App />\n````\n";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "```\n<App />\n");
+        assert!(sources[0].source_type.is_jsx());
+    }
+
+    #[test]
+    fn test_unclosed_fence() {
+        let source_text = "- item\n\n  ```tsx\n  const a = <div />;";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "  const a = <div />;");
+    }
+}
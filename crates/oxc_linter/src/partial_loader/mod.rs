@@ -1,15 +1,27 @@
 mod astro;
+mod html;
+mod markdown;
 mod svelte;
 mod vue;
 
+use oxc_diagnostics::Error;
 use oxc_span::SourceType;
 
-pub use self::{astro::AstroPartialLoader, svelte::SveltePartialLoader, vue::VuePartialLoader};
+pub use self::{
+    astro::{AstroPartialLoader, AstroProcessor},
+    html::{HtmlPartialLoader, HtmlProcessor},
+    markdown::{MarkdownPartialLoader, MarkdownProcessor},
+    svelte::{SveltePartialLoader, SvelteProcessor},
+    vue::{VuePartialLoader, VueProcessor},
+};
 
 const SCRIPT_START: &str = "<script";
 const SCRIPT_END: &str = "</script>";
 
-pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "astro", "svelte"];
+pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "astro", "svelte", "html"];
+
+/// Linted with `MarkdownProcessor` when enabled, code blocks in documentation are often incomplete.
+pub const MARKDOWN_EXT: &[&str] = &["md", "mdx"];
 
 #[derive(Debug, Clone, Copy)]
 pub struct JavaScriptSource<'a> {
@@ -26,17 +38,37 @@ impl<'a> JavaScriptSource<'a> {
     }
 }
 
+/// Lints the JavaScript embedded in a kind of file. The file types of `LINT_PARTIAL_LOADER_EXT`
+/// are supported by the processors of `PartialLoader::processor`, embedders can support other
+/// ones or replace them, see `LintServiceOptions::processors`.
+pub trait Processor: Send + Sync {
+    /// The JavaScript sections of the file, each one is linted as a separate virtual file.
+    fn preprocess<'a>(&self, source_text: &'a str) -> Vec<JavaScriptSource<'a>>;
+
+    /// The diagnostics to report for `source`, whose spans are relative to it,
+    /// e.g. without the ones of rules which do not apply to the format. All of them by default.
+    fn postprocess(&self, _source: &JavaScriptSource, diagnostics: Vec<Error>) -> Vec<Error> {
+        diagnostics
+    }
+}
+
 pub struct PartialLoader;
 
 impl PartialLoader {
-    /// Extract js section of specifial files.
-    /// Returns `None` if the specifial file does not have a js section.
-    pub fn parse<'a>(ext: &str, source_text: &'a str) -> Option<Vec<JavaScriptSource<'a>>> {
+    /// The built-in processor of the files with the extension `ext`, see `LINT_PARTIAL_LOADER_EXT`.
+    pub fn processor(ext: &str) -> Option<&'static dyn Processor> {
         match ext {
-            "vue" => Some(VuePartialLoader::new(source_text).parse()),
-            "astro" => Some(AstroPartialLoader::new(source_text).parse()),
-            "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "vue" => Some(&VueProcessor),
+            "astro" => Some(&AstroProcessor),
+            "svelte" => Some(&SvelteProcessor),
+            "html" => Some(&HtmlProcessor),
             _ => None,
         }
     }
+
+    /// Extract js section of specifial files.
+    /// Returns `None` if the specifial file does not have a js section.
+    pub fn parse<'a>(ext: &str, source_text: &'a str) -> Option<Vec<JavaScriptSource<'a>>> {
+        Self::processor(ext).map(|processor| processor.preprocess(source_text))
+    }
 }
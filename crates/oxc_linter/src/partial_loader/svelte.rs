@@ -2,7 +2,16 @@ use memchr::memmem::Finder;
 
 use oxc_span::SourceType;
 
-use super::{JavaScriptSource, SCRIPT_END, SCRIPT_START};
+use super::{JavaScriptSource, Processor, SCRIPT_END, SCRIPT_START};
+
+/// Lints the `<script>` blocks of Svelte components, see `PartialLoader::processor`.
+pub struct SvelteProcessor;
+
+impl Processor for SvelteProcessor {
+    fn preprocess<'a>(&self, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+        SveltePartialLoader::new(source_text).parse()
+    }
+}
 
 pub struct SveltePartialLoader<'a> {
     source_text: &'a str,
@@ -14,35 +23,92 @@ impl<'a> SveltePartialLoader<'a> {
     }
 
     pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
-        self.parse_script().map_or_else(Vec::new, |source| vec![source])
+        self.parse_scripts()
+    }
+
+    /// Each *.svelte file can contain at most
+    ///  * one instance <script> block.
+    ///  * one <script context="module"> block.
+    /// https://svelte.dev/docs/svelte-components
+    fn parse_scripts(&self) -> Vec<JavaScriptSource<'a>> {
+        let mut pointer = 0;
+        let Some(result1) = self.parse_script(&mut pointer) else { return vec![] };
+        let Some(result2) = self.parse_script(&mut pointer) else { return vec![result1] };
+        vec![result1, result2]
     }
 
-    fn parse_script(&self) -> Option<JavaScriptSource<'a>> {
+    fn parse_script(&self, pointer: &mut usize) -> Option<JavaScriptSource<'a>> {
         let script_start_finder = Finder::new(SCRIPT_START);
         let script_end_finder = Finder::new(SCRIPT_END);
 
-        let mut pointer = 0;
-
         // find opening "<script"
-        let offset = script_start_finder.find(self.source_text[pointer..].as_bytes())?;
-        pointer += offset + SCRIPT_START.len();
+        let offset = script_start_finder.find(self.source_text[*pointer..].as_bytes())?;
+        *pointer += offset + SCRIPT_START.len();
 
         // find closing ">"
-        let offset = self.source_text[pointer..].find('>')?;
+        let offset = self.source_text[*pointer..].find('>')?;
 
         // get lang="ts" attribute
-        let content = &self.source_text[pointer..pointer + offset];
+        let content = &self.source_text[*pointer..*pointer + offset];
         let is_ts = content.contains("ts");
 
-        pointer += offset + 1;
-        let js_start = pointer;
+        *pointer += offset + 1;
+        let js_start = *pointer;
 
         // find "</script>"
-        let offset = script_end_finder.find(self.source_text[pointer..].as_bytes())?;
-        let js_end = pointer + offset;
+        let offset = script_end_finder.find(self.source_text[*pointer..].as_bytes())?;
+        let js_end = *pointer + offset;
+        *pointer += offset + SCRIPT_END.len();
 
         let source_text = &self.source_text[js_start..js_end];
         let source_type = SourceType::default().with_module(true).with_typescript(is_ts);
         Some(JavaScriptSource::new(source_text, source_type, js_start))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, SveltePartialLoader};
+
+    fn parse_svelte(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        SveltePartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_svelte() {
+        let source_text = r#"
+        <script lang="ts">
+            console.log("hi");
+        </script>
+        <h1>Hello World</h1>
+        "#;
+
+        let sources = parse_svelte(source_text);
+        assert_eq!(sources.len(), 1);
+        assert!(sources[0].source_type.is_typescript());
+        assert_eq!(sources[0].source_text.trim(), r#"console.log("hi");"#);
+        assert_eq!(
+            &source_text[sources[0].start..][..sources[0].source_text.len()],
+            sources[0].source_text
+        );
+    }
+
+    #[test]
+    fn test_parse_svelte_module_script() {
+        let source_text = r#"
+        <script context="module">
+            export const prerender = true;
+        </script>
+        <script>
+            let count = 0;
+        </script>
+        <button on:click={() => count++}>{count}</button>
+        "#;
+
+        let sources = parse_svelte(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text.trim(), "export const prerender = true;");
+        assert_eq!(sources[1].source_text.trim(), "let count = 0;");
+        assert!(sources[1].start > sources[0].start);
+    }
+}
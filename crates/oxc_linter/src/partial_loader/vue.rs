@@ -2,7 +2,16 @@ use memchr::memmem::Finder;
 
 use oxc_span::SourceType;
 
-use super::{JavaScriptSource, SCRIPT_END, SCRIPT_START};
+use super::{JavaScriptSource, Processor, SCRIPT_END, SCRIPT_START};
+
+/// Lints the `<script>` blocks of Vue single-file components, see `PartialLoader::processor`.
+pub struct VueProcessor;
+
+impl Processor for VueProcessor {
+    fn preprocess<'a>(&self, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+        VuePartialLoader::new(source_text).parse()
+    }
+}
 
 pub struct VuePartialLoader<'a> {
     source_text: &'a str,
@@ -0,0 +1,140 @@
+//! Paths compared and displayed the same way on all platforms.
+//!
+//! On Windows, canonicalized paths and the paths of some tools have the verbatim prefix `\\?\`,
+//! e.g. `\\?\C:\project\index.js` for `C:\project\index.js`, and `\\?\UNC\server\share` for
+//! `\\server\share`. Paths can also be relative to the current directory of a drive (`C:index.js`)
+//! or to the root of the current drive (`\project\index.js`), and file names are case-insensitive.
+//! On other platforms, these functions only join and strip paths.
+
+use std::{
+    ffi::OsString,
+    path::{Component, Path, PathBuf, Prefix},
+};
+
+/// `path` without a verbatim prefix, and with an uppercase drive letter.
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut components = path.components();
+    let Some(Component::Prefix(prefix)) = components.clone().next() else {
+        return path.to_path_buf();
+    };
+    components.next();
+    let prefix = match prefix.kind() {
+        Prefix::VerbatimDisk(disk) | Prefix::Disk(disk) => {
+            OsString::from(format!("{}:", char::from(disk.to_ascii_uppercase())))
+        }
+        Prefix::VerbatimUNC(server, share) => {
+            let mut prefix = OsString::from(r"\\");
+            prefix.push(server);
+            prefix.push(r"\");
+            prefix.push(share);
+            prefix
+        }
+        _ => return path.to_path_buf(),
+    };
+    let mut normalized = PathBuf::from(prefix);
+    if !components.as_path().as_os_str().is_empty() {
+        normalized.push(components.as_path());
+    }
+    normalized
+}
+
+/// `path` made absolute against the current working directory `cwd`, including the paths relative
+/// to the current directory or to the root of a drive, which `Path::join` does not handle.
+/// The current directories of the other drives are not known, their root is used instead.
+pub fn absolute(path: &Path, cwd: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return normalize(path);
+    }
+    let cwd = normalize(cwd);
+    let mut components = path.components();
+    match components.next() {
+        // `C:index.js`
+        Some(Component::Prefix(prefix)) => {
+            let prefix = Path::new(prefix.as_os_str());
+            let rest = components.as_path();
+            match relative_to(&cwd, prefix) {
+                Some(_) => cwd.join(rest),
+                None => normalize(&prefix.join(Component::RootDir).join(rest)),
+            }
+        }
+        // `\project\index.js`
+        Some(Component::RootDir) => match cwd.components().next() {
+            Some(prefix @ Component::Prefix(_)) => Path::new(&prefix).join(path),
+            _ => path.to_path_buf(),
+        },
+        _ => cwd.join(path),
+    }
+}
+
+/// `path` relative to `base`, `None` if `path` is not in `base`. Verbatim prefixes are ignored,
+/// and on Windows the names are compared case-insensitively.
+pub fn relative_to(path: &Path, base: &Path) -> Option<PathBuf> {
+    let path = normalize(path);
+    let base = normalize(base);
+    let mut components = path.components();
+    for base_component in base.components() {
+        let component = components.next()?;
+        if !same_component(component, base_component) {
+            return None;
+        }
+    }
+    Some(components.as_path().to_path_buf())
+}
+
+/// The path of the file at `path` for diagnostics: relative to the current working directory `cwd`
+/// when it is inside of it, without a verbatim prefix otherwise.
+pub fn display(path: &Path, cwd: &Path) -> PathBuf {
+    relative_to(path, cwd).unwrap_or_else(|| normalize(path))
+}
+
+fn same_component(a: Component, b: Component) -> bool {
+    if cfg!(windows) {
+        a.as_os_str().to_string_lossy().to_lowercase()
+            == b.as_os_str().to_string_lossy().to_lowercase()
+    } else {
+        a == b
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{absolute, display, normalize, relative_to};
+
+    #[cfg(not(windows))]
+    #[test]
+    fn posix() {
+        let cwd = Path::new("/project");
+        assert_eq!(normalize(Path::new("/project/a.js")), PathBuf::from("/project/a.js"));
+        assert_eq!(absolute(Path::new("src/a.js"), cwd), PathBuf::from("/project/src/a.js"));
+        assert_eq!(absolute(Path::new("/other/a.js"), cwd), PathBuf::from("/other/a.js"));
+        assert_eq!(display(Path::new("/project/src/a.js"), cwd), PathBuf::from("src/a.js"));
+        assert_eq!(display(Path::new("/other/a.js"), cwd), PathBuf::from("/other/a.js"));
+        // File names are case-sensitive
+        assert_eq!(relative_to(Path::new("/Project/a.js"), cwd), None);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn windows() {
+        assert_eq!(normalize(Path::new(r"\\?\c:\project\a.js")), PathBuf::from(r"C:\project\a.js"));
+        assert_eq!(
+            normalize(Path::new(r"\\?\UNC\server\share\a.js")),
+            PathBuf::from(r"\\server\share\a.js")
+        );
+
+        let cwd = Path::new(r"\\?\C:\project");
+        assert_eq!(absolute(Path::new(r"src\a.js"), cwd), PathBuf::from(r"C:\project\src\a.js"));
+        assert_eq!(absolute(Path::new(r"c:src\a.js"), cwd), PathBuf::from(r"C:\project\src\a.js"));
+        assert_eq!(absolute(Path::new(r"D:a.js"), cwd), PathBuf::from(r"D:\a.js"));
+        assert_eq!(absolute(Path::new(r"\other\a.js"), cwd), PathBuf::from(r"C:\other\a.js"));
+
+        assert_eq!(display(Path::new(r"c:\PROJECT\src\a.js"), cwd), PathBuf::from(r"src\a.js"));
+        assert_eq!(display(Path::new(r"\\?\D:\a.js"), cwd), PathBuf::from(r"D:\a.js"));
+        assert_eq!(
+            relative_to(Path::new(r"\\server\share\src\a.js"), Path::new(r"\\?\UNC\server\share")),
+            Some(PathBuf::from(r"src\a.js"))
+        );
+    }
+}
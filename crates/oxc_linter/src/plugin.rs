@@ -0,0 +1,372 @@
+//! Rules provided by plugins outside of this crate.
+//!
+//! A plugin implements [`RulePlugin`] and is registered with `Linter::with_plugin`, which needs
+//! it to be built with this crate. The plugins built as dynamic libraries, see `native_plugin`,
+//! and the JavaScript plugins are listed in the `plugins` of the configuration file instead.
+//! Both are given the AST in its ESTree form, so they need the `estree` feature.
+//!
+//! Like ESLint plugins, the rules of a plugin only run when they are enabled in `rules`,
+//! where they are named `<plugin name>/<rule name>`.
+//!
+//! With the `js_plugins` feature, the `plugins` can also list ESLint plugins written in
+//! JavaScript, see `js_plugin`.
+//!
+//! Both run arbitrary code, so they are only loaded with `LintOptions::allow_plugins`,
+//! `--allow-plugins` on the command line.
+
+use std::{
+    cell::RefMut,
+    fmt::Debug,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+#[cfg(feature = "estree")]
+use std::sync::Mutex;
+
+#[cfg(feature = "estree")]
+use once_cell::sync::Lazy;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+    Error, Severity,
+};
+use oxc_semantic::{AstNode, AstNodes, ModuleRecord, ScopeTree, SymbolId, SymbolTable};
+use oxc_span::{SourceType, Span};
+#[cfg(feature = "estree")]
+use rustc_hash::FxHashMap;
+
+use crate::{config::ESLintConfig, context::LintContext, rule::RuleCategory};
+
+/// A set of rules under a plugin name.
+pub trait RulePlugin: Debug + Send + Sync {
+    /// The name the rules are prefixed with in the configuration file, e.g. `acme` for `acme/no-foo`.
+    fn name(&self) -> &'static str;
+
+    fn rules(&self) -> Vec<Arc<dyn PluginRule>>;
+}
+
+/// A rule of a [`RulePlugin`], the counterpart of `Rule` and `RuleMeta` for the rules of this crate.
+pub trait PluginRule: Debug + Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn category(&self) -> RuleCategory;
+
+    fn documentation(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Visit each AST Node
+    fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &PluginContext<'_, 'a>) {}
+
+    /// Visit each declared symbol, after its references are resolved.
+    fn run_on_symbol(&self, _symbol_id: SymbolId, _ctx: &PluginContext<'_, '_>) {}
+
+    /// Run only once, before visiting the nodes. Useful for inspecting scopes and trivias etc.
+    fn run_once(&self, _ctx: &PluginContext<'_, '_>) {}
+
+    /// Run only once, after visiting all the nodes. Useful for reporting on the whole file,
+    /// e.g. what `run` collected with `PluginContext::rule_state`.
+    fn run_on_program_exit(&self, _ctx: &PluginContext<'_, '_>) {}
+}
+
+/// The part of `LintContext` available to plugin rules: the semantic model of the file,
+/// the options of the rule, and reporting diagnostics without fixes.
+pub struct PluginContext<'c, 'a> {
+    ctx: &'c LintContext<'a>,
+    plugin_name: &'static str,
+    rule_name: &'static str,
+    options: &'c serde_json::Value,
+}
+
+impl<'c, 'a> PluginContext<'c, 'a> {
+    pub fn source_text(&self) -> &'a str {
+        self.ctx.source_text()
+    }
+
+    pub fn source_type(&self) -> &SourceType {
+        self.ctx.source_type()
+    }
+
+    pub fn file_path(&self) -> &Path {
+        self.ctx.file_path()
+    }
+
+    pub fn nodes(&self) -> &AstNodes<'a> {
+        self.ctx.nodes()
+    }
+
+    pub fn scopes(&self) -> &ScopeTree {
+        self.ctx.scopes()
+    }
+
+    pub fn symbols(&self) -> &SymbolTable {
+        self.ctx.symbols()
+    }
+
+    pub fn module_record(&self) -> &ModuleRecord {
+        self.ctx.module_record()
+    }
+
+    /// The ESTree AST of the file in the versioned binary encoding of `oxc_ast`, for rules running
+    /// out of process or in WASM. Unlike the AST nodes, the encoding does not change with the
+    /// version of this crate, see `oxc_ast::ESTREE_BINARY_VERSION` and `decode_estree_binary`.
+    #[cfg(feature = "estree")]
+    pub fn estree_binary(&self) -> Vec<u8> {
+        match self.nodes().iter().next().map(AstNode::kind) {
+            Some(oxc_ast::AstKind::Program(program)) => {
+                program.to_estree_binary(self.source_text())
+            }
+            _ => vec![],
+        }
+    }
+
+    /// The options following the severity of the rule in the configuration file,
+    /// e.g. `[{ "max": 1 }]` for `"acme/no-foo": ["error", { "max": 1 }]`.
+    pub fn options(&self) -> &serde_json::Value {
+        self.options
+    }
+
+    /// The value of `key` in the `settings` of the configuration file, shared by the rules,
+    /// e.g. `{ "strict": true }` for `"settings": { "acme": { "strict": true } }`.
+    pub fn settings(&self, key: &str) -> Option<&serde_json::Value> {
+        self.ctx.settings().get(key)
+    }
+
+    /// The state of the rule for this file, `T::default()` the first time.
+    /// Lets a rule collect what it finds in `run` and report it in `run_on_program_exit`.
+    pub fn rule_state<T: Default + 'static>(&self) -> RefMut<'_, T> {
+        self.ctx.rule_state_of(self.plugin_name, self.rule_name)
+    }
+
+    /// Report `message` at `span`, shown as `<plugin name>(<rule name>): <message>`.
+    pub fn report<S: Into<String>>(&self, message: S, span: Span) {
+        self.diagnostic(message.into(), None, span);
+    }
+
+    pub fn report_with_help<S: Into<String>, H: Into<String>>(
+        &self,
+        message: S,
+        help: H,
+        span: Span,
+    ) {
+        self.diagnostic(message.into(), Some(help.into()), span);
+    }
+
+    fn diagnostic(&self, message: String, help: Option<String>, span: Span) {
+        self.ctx.diagnostic(PluginDiagnostic {
+            plugin_name: self.plugin_name,
+            rule_name: self.rule_name,
+            message,
+            help,
+            span,
+        });
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("{plugin_name}({rule_name}): {message}")]
+#[diagnostic(severity(warning))]
+struct PluginDiagnostic {
+    plugin_name: &'static str,
+    rule_name: &'static str,
+    message: String,
+    #[help]
+    help: Option<String>,
+    #[label]
+    span: Span,
+}
+
+/// A plugin rule enabled by the configuration.
+#[derive(Debug)]
+pub(crate) struct EnabledPluginRule {
+    plugin_name: &'static str,
+    rule: Arc<dyn PluginRule>,
+    severity: Option<Severity>,
+    options: serde_json::Value,
+}
+
+impl EnabledPluginRule {
+    /// The rules of `plugins` enabled in the `rules` of `config`.
+    pub(crate) fn from_config(
+        plugins: &[Arc<dyn RulePlugin>],
+        config: Option<&ESLintConfig>,
+    ) -> Vec<Self> {
+        let Some(config) = config else {
+            return vec![];
+        };
+        let mut rules = vec![];
+        for plugin in plugins {
+            for rule in plugin.rules() {
+                let Some(rule_config) = config
+                    .rules()
+                    .iter()
+                    .rev()
+                    .find(|r| r.plugin_name == plugin.name() && r.rule_name == rule.name())
+                else {
+                    continue;
+                };
+                if rule_config.severity.is_allow() {
+                    continue;
+                }
+                rules.push(Self {
+                    plugin_name: plugin.name(),
+                    rule,
+                    severity: rule_config.severity.as_severity(),
+                    options: rule_config.config.clone().unwrap_or(serde_json::Value::Null),
+                });
+            }
+        }
+        rules
+    }
+
+    pub(crate) fn plugin_name(&self) -> &'static str {
+        self.plugin_name
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        self.rule.name()
+    }
+
+    pub(crate) fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    pub(crate) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        self.rule.run(node, &self.context(ctx));
+    }
+
+    pub(crate) fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext) {
+        self.rule.run_on_symbol(symbol_id, &self.context(ctx));
+    }
+
+    pub(crate) fn run_once(&self, ctx: &LintContext) {
+        self.rule.run_once(&self.context(ctx));
+    }
+
+    pub(crate) fn run_on_program_exit(&self, ctx: &LintContext) {
+        self.rule.run_on_program_exit(&self.context(ctx));
+    }
+
+    fn context<'c, 'a>(&'c self, ctx: &'c LintContext<'a>) -> PluginContext<'c, 'a> {
+        PluginContext {
+            ctx,
+            plugin_name: self.plugin_name,
+            rule_name: self.rule.name(),
+            options: &self.options,
+        }
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to load plugin {0:?} with error {1:?}")]
+#[diagnostic()]
+pub struct FailedToLoadPluginError(pub PathBuf, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Plugin {0:?} is not loaded, plugins run arbitrary code")]
+#[diagnostic(help(
+    "Allow the plugins of the configuration file with `--allow-plugins`, or with `allowPlugins` in the settings of the language server"
+))]
+pub struct PluginsNotAllowedError(pub PathBuf);
+
+/// Load the plugin libraries listed in the `plugins` of `config`, when `allow` is set,
+/// see `LintOptions::allow_plugins`.
+///
+/// # Errors
+///
+/// Returns `Err` if a plugin cannot be loaded, or if `config` lists a plugin but `allow` is not set.
+pub(crate) fn load_plugins(
+    config: &ESLintConfig,
+    allow: bool,
+) -> Result<Vec<Arc<dyn RulePlugin>>, Error> {
+    let mut plugins: Vec<Arc<dyn RulePlugin>> = vec![];
+    for path in config.plugin_paths() {
+        if !allow {
+            return Err(Error::new(PluginsNotAllowedError(path.to_path_buf())));
+        }
+        let plugin = load_plugin(path)?;
+        if !plugins.iter().any(|p| p.name() == plugin.name()) {
+            plugins.push(plugin);
+        }
+    }
+    Ok(plugins)
+}
+
+/// Whether `path` is a JavaScript plugin, see `js_plugin`.
+pub(crate) fn is_js_plugin(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "js" || extension == "cjs")
+}
+
+/// Load the plugin library or the JavaScript plugin at `path`, each plugin is loaded once per
+/// process, see `load_native_plugin` and `load_js_plugin`.
+#[cfg(feature = "estree")]
+fn load_plugin(path: &Path) -> Result<Arc<dyn RulePlugin>, Error> {
+    static LOADED_PLUGINS: Lazy<Mutex<FxHashMap<PathBuf, Arc<dyn RulePlugin>>>> =
+        Lazy::new(Mutex::default);
+
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut loaded = LOADED_PLUGINS.lock().unwrap();
+    if let Some(plugin) = loaded.get(&path) {
+        return Ok(Arc::clone(plugin));
+    }
+
+    let plugin = if is_js_plugin(&path) {
+        #[cfg(feature = "js_plugins")]
+        {
+            #[allow(clippy::cast_possible_truncation)]
+            crate::js_plugin::load_js_plugin(&path, loaded.len() as u32)?
+        }
+        #[cfg(not(feature = "js_plugins"))]
+        {
+            let message = "JavaScript plugins need the `js_plugins` feature".to_string();
+            return Err(Error::new(FailedToLoadPluginError(path, message)));
+        }
+    } else {
+        #[cfg(any(unix, windows))]
+        {
+            crate::native_plugin::load_native_plugin(&path)?
+        }
+        #[cfg(not(any(unix, windows)))]
+        {
+            let message = "plugin libraries cannot be loaded on this platform".to_string();
+            return Err(Error::new(FailedToLoadPluginError(path, message)));
+        }
+    };
+    loaded.insert(path, Arc::clone(&plugin));
+    Ok(plugin)
+}
+
+#[cfg(not(feature = "estree"))]
+fn load_plugin(path: &Path) -> Result<Arc<dyn RulePlugin>, Error> {
+    let message = "plugins need the `estree` feature".to_string();
+    Err(Error::new(FailedToLoadPluginError(path.to_path_buf(), message)))
+}
+
+/// The UTF-8 offset of the UTF-16 offset `utf16_offset` of JavaScript, see `SpanConverter`.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn utf8_offset(source_text: &str, utf16_offset: usize) -> u32 {
+    let mut utf16 = 0;
+    for (offset, c) in source_text.char_indices() {
+        if utf16 >= utf16_offset {
+            return offset as u32;
+        }
+        utf16 += c.len_utf16();
+    }
+    source_text.len() as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::utf8_offset;
+
+    #[test]
+    fn utf8_offsets() {
+        let source_text = "'é😀' + a";
+        assert_eq!(utf8_offset(source_text, 0), 0);
+        assert_eq!(utf8_offset(source_text, 2), 3);
+        assert_eq!(utf8_offset(source_text, 4), 7);
+        assert_eq!(utf8_offset(source_text, 8), 11);
+        assert_eq!(utf8_offset(source_text, 100), 12);
+    }
+}
@@ -0,0 +1,330 @@
+//! Scope-aware renaming of a declared symbol, for fixes and codemods.
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::{
+    ast::{
+        AssignmentTargetPropertyIdentifier, BindingPatternKind, BindingProperty, Expression,
+        ModuleExportName,
+    },
+    AstKind, Visit,
+};
+use oxc_diagnostics::thiserror::{self, Error};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::{CompactString, Span};
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    module_record::{ExportExportName, ExportLocalName},
+};
+
+use crate::fixer::Fix;
+
+/// Reserved words, which can not be the name of a binding in strict mode code
+const RESERVED_WORDS: [&str; 45] = [
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+];
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RenameError {
+    #[error("`{0}` is not a valid identifier")]
+    InvalidName(CompactString),
+    #[error("`{0}` is already declared in this scope")]
+    Redeclared(CompactString),
+    #[error("`{0}` is declared in an inner scope and would shadow the renamed declaration")]
+    Shadowed(CompactString),
+    #[error("`{0}` is referenced in this scope and would refer to the renamed declaration")]
+    Captured(CompactString),
+}
+
+/// Renames the declaration of `symbol_id` and all of its references to `new_name`.
+///
+/// Shorthand properties and imports, e.g. `{ foo }`, keep their key, and `export { foo }` keeps
+/// its export name, so the behavior of the code is unchanged.
+/// A declaration exporting itself, e.g. `export function foo() {}`, does change its export name.
+///
+/// Errors when `new_name` is not a valid name, or would conflict with another declaration:
+/// one in the same scope, one shadowing the declaration for some of its references,
+/// or an outer one whose references would refer to the renamed declaration instead.
+pub fn rename_symbol(
+    semantic: &Semantic,
+    symbol_id: SymbolId,
+    new_name: &str,
+) -> Result<Vec<Fix<'static>>, RenameError> {
+    if !is_identifier_name(new_name) || RESERVED_WORDS.contains(&new_name) {
+        return Err(RenameError::InvalidName(new_name.into()));
+    }
+    check_conflicts(semantic, symbol_id, new_name)?;
+
+    let symbols = semantic.symbols();
+    let old_name = symbols.get_name(symbol_id);
+    let symbol_span = symbols.get_span(symbol_id);
+    let mut shorthands = ShorthandCollector::default();
+    if let Some(AstKind::Program(program)) = semantic.nodes().iter().next().map(|node| node.kind())
+    {
+        shorthands.visit_program(program);
+    }
+    let mut fixes = std::iter::once(symbol_span)
+        .chain(
+            symbols
+                .get_resolved_reference_ids(symbol_id)
+                .iter()
+                .map(|id| symbols.get_reference(*id).span()),
+        )
+        .map(|span| {
+            let content = match shorthands.spans.get(&span) {
+                Some(Shorthand::Property) => format!("{old_name}: {new_name}"),
+                Some(Shorthand::Import) => format!("{old_name} as {new_name}"),
+                None => new_name.to_string(),
+            };
+            Fix::new(content, span)
+        })
+        .collect::<Vec<_>>();
+
+    // `export { foo }` keeps its export name, as importers refer to it
+    if symbols.get_scope_id(symbol_id) == semantic.scopes().root_scope_id() {
+        for entry in &semantic.module_record().local_export_entries {
+            let ExportLocalName::Name(local) = &entry.local_name else { continue };
+            if local.name().as_str() != old_name || local.span() == symbol_span {
+                continue;
+            }
+            let content = match &entry.export_name {
+                ExportExportName::Name(exported) if exported.span() == local.span() => {
+                    format!("{new_name} as {old_name}")
+                }
+                _ => new_name.to_string(),
+            };
+            fixes.push(Fix::new(content, local.span()));
+        }
+    }
+
+    fixes.sort_by_key(|fix| fix.span.start);
+    fixes.dedup_by_key(|fix| fix.span);
+    Ok(fixes)
+}
+
+fn check_conflicts(
+    semantic: &Semantic,
+    symbol_id: SymbolId,
+    new_name: &str,
+) -> Result<(), RenameError> {
+    let (scopes, symbols, nodes) = (semantic.scopes(), semantic.symbols(), semantic.nodes());
+    let scope_id = symbols.get_scope_id(symbol_id);
+    if scopes.get_binding(scope_id, new_name).is_some_and(|id| id != symbol_id) {
+        return Err(RenameError::Redeclared(new_name.into()));
+    }
+    for reference_id in symbols.get_resolved_reference_ids(symbol_id) {
+        let reference_scope =
+            nodes.get_node(symbols.get_reference(*reference_id).node_id()).scope_id();
+        let shadowed = scopes
+            .ancestors(reference_scope)
+            .take_while(|id| *id != scope_id)
+            .any(|id| scopes.has_binding(id, new_name));
+        if shadowed {
+            return Err(RenameError::Shadowed(new_name.into()));
+        }
+    }
+    for reference in
+        symbols.references.iter().filter(|reference| reference.name().as_str() == new_name)
+    {
+        // A reference to either a global or a declaration in an outer scope
+        let is_outer = reference.symbol_id().map_or(true, |id| {
+            scopes.ancestors(scope_id).skip(1).any(|ancestor| ancestor == symbols.get_scope_id(id))
+        });
+        let reference_scope = nodes.get_node(reference.node_id()).scope_id();
+        if is_outer && scopes.ancestors(reference_scope).any(|id| id == scope_id) {
+            return Err(RenameError::Captured(new_name.into()));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Shorthand {
+    /// `{ foo }` and `const { foo } = bar`
+    Property,
+    /// `import { foo } from 'bar'`
+    Import,
+}
+
+/// Identifiers which are names as well, and have to be split into two when renamed
+#[derive(Default)]
+struct ShorthandCollector {
+    spans: FxHashMap<Span, Shorthand>,
+}
+
+impl<'a> Visit<'a> for ShorthandCollector {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::ObjectProperty(prop) if prop.shorthand => {
+                if let Expression::Identifier(ident) = &prop.value {
+                    self.spans.insert(ident.span, Shorthand::Property);
+                }
+            }
+            AstKind::ImportSpecifier(specifier) => {
+                if let ModuleExportName::Identifier(imported) = &specifier.imported {
+                    if imported.span == specifier.local.span {
+                        self.spans.insert(specifier.local.span, Shorthand::Import);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_binding_property(&mut self, prop: &BindingProperty<'a>) {
+        if prop.shorthand {
+            let ident = match &prop.value.kind {
+                BindingPatternKind::BindingIdentifier(ident) => Some(ident),
+                // `const { foo = 1 } = bar`
+                BindingPatternKind::AssignmentPattern(pattern) => match &pattern.left.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => Some(ident),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(ident) = ident {
+                self.spans.insert(ident.span, Shorthand::Property);
+            }
+        }
+        self.visit_property_key(&prop.key);
+        self.visit_binding_pattern(&prop.value);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        ident: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.spans.insert(ident.binding.span, Shorthand::Property);
+        self.visit_identifier_reference(&ident.binding);
+        if let Some(expr) = &ident.init {
+            self.visit_expression(expr);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::{rename_symbol, RenameError};
+
+    /// The source text with the first declaration of `old_name` renamed to `new_name`
+    fn rename(source_text: &str, old_name: &str, new_name: &str) -> Result<String, RenameError> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let program = Parser::new(&allocator, source_text, source_type).parse().program;
+        let program = allocator.alloc(program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .build_module_record(PathBuf::new(), program)
+            .build(program)
+            .semantic;
+        let symbols = semantic.symbols();
+        let symbol_id = symbols.iter().find(|id| symbols.get_name(*id) == old_name).unwrap();
+        let fixes = rename_symbol(&semantic, symbol_id, new_name)?;
+
+        let mut output = String::new();
+        let mut last = 0;
+        for fix in fixes {
+            output.push_str(&source_text[last as usize..fix.span.start as usize]);
+            output.push_str(&fix.content);
+            last = fix.span.end;
+        }
+        output.push_str(&source_text[last as usize..]);
+        Ok(output)
+    }
+
+    #[test]
+    fn declaration_and_references() {
+        assert_eq!(
+            rename("let a = 1; a++; function f() { return a; }", "a", "b"),
+            Ok("let b = 1; b++; function f() { return b; }".to_string())
+        );
+    }
+
+    #[test]
+    fn shorthands() {
+        assert_eq!(
+            rename("const { a } = o; ({ a }); ({ a } = o);", "a", "b"),
+            Ok("const { a: b } = o; ({ a: b }); ({ a: b } = o);".to_string())
+        );
+        assert_eq!(
+            rename("import { a } from 'mod'; a();", "a", "b"),
+            Ok("import { a as b } from 'mod'; b();".to_string())
+        );
+        assert_eq!(
+            rename("const a = 1; export { a };", "a", "b"),
+            Ok("const b = 1; export { b as a };".to_string())
+        );
+    }
+
+    #[test]
+    fn conflicts() {
+        assert_eq!(rename("let a; a;", "a", "1a"), Err(RenameError::InvalidName("1a".into())));
+        assert_eq!(
+            rename("let a; a;", "a", "class"),
+            Err(RenameError::InvalidName("class".into()))
+        );
+        assert_eq!(rename("let a, b;", "a", "b"), Err(RenameError::Redeclared("b".into())));
+        assert_eq!(
+            rename("let a; function f() { let b; a; }", "a", "b"),
+            Err(RenameError::Shadowed("b".into()))
+        );
+        assert_eq!(
+            rename("let b; function f() { let a; b; }", "a", "b"),
+            Err(RenameError::Captured("b".into()))
+        );
+        assert_eq!(
+            rename("function f() { let a; console.log(a); }", "a", "console"),
+            Err(RenameError::Captured("console".into()))
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt;
 
+use oxc_ast::AstType;
 use oxc_semantic::SymbolId;
 
 use crate::{context::LintContext, AstNode};
@@ -10,14 +11,37 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Check the parts of the configuration which `from_configuration` would ignore, e.g. an
+    /// invalid regex, reported as an error of the configuration file.
+    fn validate_configuration(_value: &serde_json::Value) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// The types of the AST Nodes `run` is called with, `None` for all of them.
+    /// `Some(&[])` when the rule does not implement `run`, so it is not called on any node.
+    fn node_types() -> Option<&'static [AstType]> {
+        None
+    }
+
+    /// Whether the rule uses `LintContext::tokens` or the methods finding tokens.
+    /// The tokens are only collected for the files linted with such a rule.
+    fn needs_tokens() -> bool {
+        false
+    }
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
-    /// Visit each symbol
+    /// Visit each declared symbol, after its references are resolved.
+    /// Useful for rules about declarations, e.g. unused or misnamed variables.
     fn run_on_symbol(&self, _symbol_id: SymbolId, _ctx: &LintContext<'_>) {}
 
-    /// Run only once. Useful for inspecting scopes and trivias etc.
+    /// Run only once, before visiting the nodes. Useful for inspecting scopes and trivias etc.
     fn run_once(&self, _ctx: &LintContext) {}
+
+    /// Run only once, after visiting all the symbols and nodes. Useful for reporting on the whole
+    /// file, e.g. what `run` collected with `LintContext::rule_state`.
+    fn run_on_program_exit(&self, _ctx: &LintContext) {}
 }
 
 pub trait RuleMeta {
@@ -15,11 +15,13 @@ mod import {
     pub mod no_cycle;
     pub mod no_deprecated;
     pub mod no_duplicates;
+    pub mod no_extraneous_dependencies;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
     pub mod no_self_import;
     pub mod no_unresolved;
     pub mod no_unused_modules;
+    pub mod order;
 }
 
 mod deepscan {
@@ -36,12 +38,21 @@ mod deepscan {
 }
 
 mod eslint {
+    pub mod accessor_pairs;
     pub mod array_callback_return;
+    pub mod arrow_body_style;
+    pub mod capitalized_comments;
+    pub mod class_methods_use_this;
+    pub mod complexity;
     pub mod constructor_super;
     pub mod default_case_last;
+    pub mod default_param_last;
     pub mod eqeqeq;
     pub mod for_direction;
     pub mod getter_return;
+    pub mod max_depth;
+    pub mod max_lines_per_function;
+    pub mod max_params;
     pub mod no_array_constructor;
     pub mod no_async_promise_executor;
     pub mod no_bitwise;
@@ -50,6 +61,7 @@ mod eslint {
     pub mod no_class_assign;
     pub mod no_compare_neg_zero;
     pub mod no_cond_assign;
+    pub mod no_confusing_arrow;
     pub mod no_console;
     pub mod no_const_assign;
     pub mod no_constant_binary_expression;
@@ -61,21 +73,29 @@ mod eslint {
     pub mod no_dupe_else_if;
     pub mod no_dupe_keys;
     pub mod no_duplicate_case;
+    pub mod no_duplicate_imports;
     pub mod no_empty;
     pub mod no_empty_character_class;
+    pub mod no_empty_function;
     pub mod no_empty_pattern;
     pub mod no_empty_static_block;
     pub mod no_eval;
     pub mod no_ex_assign;
+    pub mod no_extend_native;
     pub mod no_extra_boolean_cast;
     pub mod no_fallthrough;
     pub mod no_func_assign;
     pub mod no_global_assign;
     pub mod no_import_assign;
+    pub mod no_inline_comments;
     pub mod no_inner_declarations;
+    pub mod no_invalid_regexp;
     pub mod no_irregular_whitespace;
+    pub mod no_lonely_if;
     pub mod no_loss_of_precision;
+    pub mod no_magic_numbers;
     pub mod no_mixed_operators;
+    pub mod no_new_native_nonconstructor;
     pub mod no_new_symbol;
     pub mod no_new_wrappers;
     pub mod no_nonoctal_decimal_escape;
@@ -83,24 +103,48 @@ mod eslint {
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
     pub mod no_regex_spaces;
+    pub mod no_restricted_globals;
+    pub mod no_restricted_imports;
+    pub mod no_restricted_syntax;
     pub mod no_return_await;
     pub mod no_self_assign;
     pub mod no_self_compare;
     pub mod no_setter_return;
+    pub mod no_shadow;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
     pub mod no_this_before_super;
     pub mod no_undef;
+    pub mod no_unmodified_loop_condition;
+    pub mod no_unneeded_ternary;
+    pub mod no_unreachable;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
+    pub mod no_unused_expressions;
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
+    pub mod no_unused_vars;
+    pub mod no_use_before_define;
     pub mod no_useless_catch;
+    pub mod no_useless_computed_key;
+    pub mod no_useless_concat;
+    pub mod no_useless_constructor;
     pub mod no_useless_escape;
+    pub mod no_useless_rename;
     pub mod no_var;
     pub mod no_void;
+    pub mod no_warning_comments;
+    pub mod object_shorthand;
+    pub mod prefer_arrow_callback;
+    pub mod prefer_destructuring;
+    pub mod prefer_exponentiation_operator;
+    pub mod prefer_numeric_literals;
+    pub mod prefer_object_spread;
+    pub mod prefer_template;
+    pub mod radix;
     pub mod require_yield;
+    pub mod sort_imports;
     pub mod use_isnan;
     pub mod valid_typeof;
 }
@@ -111,13 +155,18 @@ mod typescript {
     pub mod ban_ts_comment;
     pub mod ban_tslint_comment;
     pub mod ban_types;
+    pub mod consistent_type_definitions;
+    pub mod naming_convention;
     pub mod no_duplicate_enum_values;
     pub mod no_empty_interface;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
+    pub mod no_floating_promises;
+    pub mod no_inferrable_types;
     pub mod no_misused_new;
     pub mod no_namespace;
     pub mod no_non_null_asserted_optional_chain;
+    pub mod no_require_imports;
     pub mod no_this_alias;
     pub mod no_unnecessary_type_constraint;
     pub mod no_unsafe_declaration_merging;
@@ -125,6 +174,7 @@ mod typescript {
     pub mod prefer_as_const;
     pub mod prefer_function_type;
     pub mod prefer_ts_expect_error;
+    pub mod switch_exhaustiveness_check;
     pub mod triple_slash_reference;
 }
 
@@ -167,6 +217,7 @@ mod react {
     pub mod jsx_no_target_blank;
     pub mod jsx_no_undef;
     pub mod jsx_no_useless_fragment;
+    pub mod jsx_uses_react;
     pub mod no_children_prop;
     pub mod no_danger;
     pub mod no_direct_mutation_state;
@@ -304,6 +355,7 @@ mod oxc {
     pub mod erasing_op;
     pub mod misrefactored_assign_op;
     pub mod no_accumulating_spread;
+    pub mod no_unsupported_browser_features;
     pub mod only_used_in_recursion;
 }
 
@@ -319,6 +371,7 @@ mod nextjs {
     pub mod no_document_import_in_page;
     pub mod no_head_element;
     pub mod no_head_import_in_document;
+    pub mod no_html_link_for_pages;
     pub mod no_img_element;
     pub mod no_script_component_in_head;
     pub mod no_sync_scripts;
@@ -327,6 +380,30 @@ mod nextjs {
     pub mod no_unwanted_polyfillio;
 }
 
+mod promise {
+    pub mod catch_or_return;
+    pub mod no_nesting;
+    pub mod param_names;
+}
+
+mod security {
+    pub mod detect_child_process;
+    pub mod detect_eval_with_expression;
+    pub mod detect_unsafe_inner_html;
+}
+
+/// The rules of JSON files, see `crate::json`
+mod json {
+    pub mod no_duplicate_keys;
+    pub mod schema;
+}
+
+mod jsdoc {
+    pub mod check_param_names;
+    pub mod no_undefined_types;
+    pub mod require_param;
+}
+
 oxc_macros::declare_all_lint_rules! {
     deepscan::bad_array_method_on_arguments,
     deepscan::bad_bitwise_operator,
@@ -338,12 +415,21 @@ oxc_macros::declare_all_lint_rules! {
     deepscan::missing_throw,
     deepscan::number_arg_out_of_range,
     deepscan::uninvoked_array_callback,
+    eslint::accessor_pairs,
     eslint::array_callback_return,
+    eslint::arrow_body_style,
+    eslint::capitalized_comments,
+    eslint::class_methods_use_this,
+    eslint::complexity,
     eslint::constructor_super,
     eslint::default_case_last,
+    eslint::default_param_last,
     eslint::eqeqeq,
     eslint::for_direction,
     eslint::getter_return,
+    eslint::max_depth,
+    eslint::max_lines_per_function,
+    eslint::max_params,
     eslint::no_this_before_super,
     eslint::no_array_constructor,
     eslint::no_async_promise_executor,
@@ -353,6 +439,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_class_assign,
     eslint::no_compare_neg_zero,
     eslint::no_cond_assign,
+    eslint::no_confusing_arrow,
     eslint::no_console,
     eslint::no_const_assign,
     eslint::no_constant_binary_expression,
@@ -364,21 +451,29 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_dupe_else_if,
     eslint::no_dupe_keys,
     eslint::no_duplicate_case,
+    eslint::no_duplicate_imports,
     eslint::no_empty,
     eslint::no_empty_character_class,
+    eslint::no_empty_function,
     eslint::no_empty_pattern,
     eslint::no_empty_static_block,
     eslint::no_eval,
     eslint::no_ex_assign,
+    eslint::no_extend_native,
     eslint::no_extra_boolean_cast,
     eslint::no_fallthrough,
     eslint::no_func_assign,
     eslint::no_global_assign,
     eslint::no_import_assign,
+    eslint::no_inline_comments,
     eslint::no_inner_declarations,
+    eslint::no_invalid_regexp,
     eslint::no_irregular_whitespace,
+    eslint::no_lonely_if,
     eslint::no_loss_of_precision,
+    eslint::no_magic_numbers,
     eslint::no_mixed_operators,
+    eslint::no_new_native_nonconstructor,
     eslint::no_new_symbol,
     eslint::no_new_wrappers,
     eslint::no_nonoctal_decimal_escape,
@@ -386,23 +481,47 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_prototype_builtins,
     eslint::no_redeclare,
     eslint::no_regex_spaces,
+    eslint::no_restricted_globals,
+    eslint::no_restricted_imports,
+    eslint::no_restricted_syntax,
     eslint::no_return_await,
     eslint::no_self_assign,
     eslint::no_self_compare,
     eslint::no_setter_return,
+    eslint::no_shadow,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_undef,
+    eslint::no_unmodified_loop_condition,
+    eslint::no_unneeded_ternary,
+    eslint::no_unreachable,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
+    eslint::no_use_before_define,
+    eslint::no_unused_expressions,
     eslint::no_unused_labels,
     eslint::no_unused_private_class_members,
+    eslint::no_unused_vars,
     eslint::no_useless_catch,
+    eslint::no_useless_concat,
+    eslint::no_useless_constructor,
+    eslint::no_useless_computed_key,
     eslint::no_useless_escape,
+    eslint::no_useless_rename,
     eslint::no_var,
     eslint::no_void,
+    eslint::no_warning_comments,
+    eslint::object_shorthand,
+    eslint::prefer_arrow_callback,
+    eslint::prefer_destructuring,
+    eslint::prefer_exponentiation_operator,
+    eslint::prefer_numeric_literals,
+    eslint::prefer_object_spread,
+    eslint::prefer_template,
+    eslint::radix,
     eslint::require_yield,
+    eslint::sort_imports,
     eslint::use_isnan,
     eslint::valid_typeof,
     typescript::adjacent_overload_signatures,
@@ -410,13 +529,18 @@ oxc_macros::declare_all_lint_rules! {
     typescript::ban_ts_comment,
     typescript::ban_tslint_comment,
     typescript::ban_types,
+    typescript::consistent_type_definitions,
+    typescript::naming_convention,
     typescript::no_duplicate_enum_values,
     typescript::no_empty_interface,
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
+    typescript::no_floating_promises,
+    typescript::no_inferrable_types,
     typescript::no_misused_new,
     typescript::no_namespace,
     typescript::no_non_null_asserted_optional_chain,
+    typescript::no_require_imports,
     typescript::no_this_alias,
     typescript::no_unnecessary_type_constraint,
     typescript::no_unsafe_declaration_merging,
@@ -424,6 +548,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::prefer_as_const,
     typescript::prefer_function_type,
     typescript::prefer_ts_expect_error,
+    typescript::switch_exhaustiveness_check,
     typescript::triple_slash_reference,
     jest::expect_expect,
     jest::max_expects,
@@ -537,6 +662,7 @@ oxc_macros::declare_all_lint_rules! {
     react::jsx_no_duplicate_props,
     react::jsx_no_useless_fragment,
     react::jsx_no_undef,
+    react::jsx_uses_react,
     react::react_in_jsx_scope,
     react::no_children_prop,
     react::no_danger,
@@ -560,12 +686,14 @@ oxc_macros::declare_all_lint_rules! {
     import::no_amd,
     import::no_cycle,
     import::no_deprecated,
+    import::no_extraneous_dependencies,
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
     import::no_unresolved,
     import::no_unused_modules,
     import::no_duplicates,
+    import::order,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
@@ -598,6 +726,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::erasing_op,
     oxc::misrefactored_assign_op,
     oxc::no_accumulating_spread,
+    oxc::no_unsupported_browser_features,
     oxc::only_used_in_recursion,
     nextjs::google_font_display,
     nextjs::google_font_preconnect,
@@ -608,6 +737,7 @@ oxc_macros::declare_all_lint_rules! {
     nextjs::no_css_tags,
     nextjs::no_head_element,
     nextjs::no_head_import_in_document,
+    nextjs::no_html_link_for_pages,
     nextjs::no_img_element,
     nextjs::no_script_component_in_head,
     nextjs::no_sync_scripts,
@@ -616,4 +746,15 @@ oxc_macros::declare_all_lint_rules! {
     nextjs::no_document_import_in_page,
     nextjs::no_unwanted_polyfillio,
     nextjs::no_before_interactive_script_outside_document,
+    promise::catch_or_return,
+    promise::no_nesting,
+    promise::param_names,
+    security::detect_child_process,
+    security::detect_eval_with_expression,
+    security::detect_unsafe_inner_html,
+    jsdoc::check_param_names,
+    jsdoc::no_undefined_types,
+    jsdoc::require_param,
+    json::no_duplicate_keys,
+    json::schema,
 }
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BinaryExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -63,6 +63,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for BadBitwiseOperator {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression, AstType::AssignmentExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::BinaryExpression(bin_expr) => {
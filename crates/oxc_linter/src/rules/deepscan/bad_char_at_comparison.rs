@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -45,6 +45,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for BadCharAtComparison {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
 
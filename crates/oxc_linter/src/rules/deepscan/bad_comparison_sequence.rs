@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BinaryExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -44,6 +44,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for BadComparisonSequence {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(expr) = node.kind() else { return };
         if is_bad_comparison(expr) && has_no_bad_comparison_in_parents(node, ctx) {
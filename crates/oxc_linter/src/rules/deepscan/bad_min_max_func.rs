@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, CallExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -39,6 +39,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for BadMinMaxFunc {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
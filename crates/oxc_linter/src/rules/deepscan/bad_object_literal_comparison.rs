@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -49,6 +49,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for BadObjectLiteralComparison {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(binary_expression) = node.kind() else { return };
 
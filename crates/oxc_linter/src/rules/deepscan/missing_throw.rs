@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -35,6 +35,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for MissingThrow {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NewExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(new_expr) = node.kind() else { return };
         if new_expr.callee.is_specific_id("Error") && Self::has_missing_throw(node, ctx) {
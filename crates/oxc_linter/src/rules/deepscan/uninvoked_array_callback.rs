@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -43,6 +43,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for UninvokedArrayCallback {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NewExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(new_expr) = node.kind() else { return };
         if !new_expr.callee.is_specific_id("Array") {
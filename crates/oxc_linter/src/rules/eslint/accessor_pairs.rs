@@ -0,0 +1,325 @@
+use oxc_ast::{
+    ast::{
+        Argument, ClassElement, Expression, MethodDefinitionKind, ObjectExpression,
+        ObjectPropertyKind, PropertyKey, PropertyKind,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum AccessorPairsDiagnostic {
+    #[error("eslint(accessor-pairs): Getter is not present for {0}.")]
+    #[diagnostic(severity(warning), help("Add a getter for the property"))]
+    MissingGetter(String, #[label] Span),
+
+    #[error("eslint(accessor-pairs): Setter is not present for {0}.")]
+    #[diagnostic(severity(warning), help("Add a setter for the property"))]
+    MissingSetter(String, #[label] Span),
+
+    #[error("eslint(accessor-pairs): Getter is not present for class {0}.")]
+    #[diagnostic(severity(warning), help("Add a getter for the property"))]
+    MissingGetterInClass(String, #[label] Span),
+
+    #[error("eslint(accessor-pairs): Setter is not present for class {0}.")]
+    #[diagnostic(severity(warning), help("Add a setter for the property"))]
+    MissingSetterInClass(String, #[label] Span),
+
+    #[error("eslint(accessor-pairs): Getter is not present in property descriptor.")]
+    #[diagnostic(severity(warning), help("Add a `get` function to the property descriptor"))]
+    MissingGetterInPropertyDescriptor(#[label] Span),
+
+    #[error("eslint(accessor-pairs): Setter is not present in property descriptor.")]
+    #[diagnostic(severity(warning), help("Add a `set` function to the property descriptor"))]
+    MissingSetterInPropertyDescriptor(#[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessorPairs {
+    set_without_get: bool,
+    get_without_set: bool,
+    enforce_for_class_members: bool,
+}
+
+impl Default for AccessorPairs {
+    fn default() -> Self {
+        Self { set_without_get: true, get_without_set: false, enforce_for_class_members: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce getter and setter pairs in objects and classes
+    ///
+    /// ### Why is this bad?
+    /// A property with a setter but no getter cannot be read, which is rarely intended.
+    ///
+    /// Setters without a getter are reported by default (`setWithoutGet`),
+    /// getters without a setter with `getWithoutSet`.
+    /// Object literals, classes (unless `enforceForClassMembers` is `false`), and property descriptors
+    /// passed to `Object.defineProperty`, `Reflect.defineProperty`, `Object.defineProperties`
+    /// and `Object.create` are checked.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const o = {
+    ///   set a(value) {
+    ///     this.val = value;
+    ///   }
+    /// };
+    /// ```
+    AccessorPairs,
+    pedantic
+);
+
+impl Rule for AccessorPairs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let option = |name: &str, default: bool| {
+            config.get(name).and_then(serde_json::Value::as_bool).unwrap_or(default)
+        };
+
+        Self {
+            set_without_get: option("setWithoutGet", true),
+            get_without_set: option("getWithoutSet", false),
+            enforce_for_class_members: option("enforceForClassMembers", true),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ObjectExpression, AstType::ClassBody, AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !self.set_without_get && !self.get_without_set {
+            return;
+        }
+        match node.kind() {
+            AstKind::ObjectExpression(obj) => {
+                let accessors = obj.properties.iter().filter_map(|prop| match prop {
+                    ObjectPropertyKind::ObjectProperty(prop) => {
+                        let is_getter = match prop.kind {
+                            PropertyKind::Get => true,
+                            PropertyKind::Set => false,
+                            PropertyKind::Init => return None,
+                        };
+                        Some(Accessor::new(&prop.key, is_getter, false, ctx))
+                    }
+                    ObjectPropertyKind::SpreadProperty(_) => None,
+                });
+                self.check_accessors(&accessors.collect::<Vec<_>>(), false, ctx);
+            }
+            AstKind::ClassBody(body) if self.enforce_for_class_members => {
+                let accessors = body.body.iter().filter_map(|element| match element {
+                    ClassElement::MethodDefinition(method) => {
+                        let is_getter = match method.kind {
+                            MethodDefinitionKind::Get => true,
+                            MethodDefinitionKind::Set => false,
+                            _ => return None,
+                        };
+                        Some(Accessor::new(&method.key, is_getter, method.r#static, ctx))
+                    }
+                    _ => None,
+                });
+                self.check_accessors(&accessors.collect::<Vec<_>>(), true, ctx);
+            }
+            AstKind::CallExpression(call) => {
+                let callee = &call.callee;
+                if callee.is_specific_member_access("Object", "defineProperty")
+                    || callee.is_specific_member_access("Reflect", "defineProperty")
+                {
+                    if let Some(Argument::Expression(Expression::ObjectExpression(descriptor))) =
+                        call.arguments.get(2)
+                    {
+                        self.check_property_descriptor(descriptor, ctx);
+                    }
+                } else if callee.is_specific_member_access("Object", "defineProperties")
+                    || callee.is_specific_member_access("Object", "create")
+                {
+                    if let Some(Argument::Expression(Expression::ObjectExpression(descriptors))) =
+                        call.arguments.get(1)
+                    {
+                        for prop in &descriptors.properties {
+                            if let ObjectPropertyKind::ObjectProperty(prop) = prop {
+                                if let Expression::ObjectExpression(descriptor) = &prop.value {
+                                    self.check_property_descriptor(descriptor, ctx);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl AccessorPairs {
+    fn check_accessors(&self, accessors: &[Accessor], in_class: bool, ctx: &LintContext) {
+        for accessor in accessors {
+            let has_pair = accessors.iter().any(|other| {
+                other.is_getter != accessor.is_getter
+                    && other.is_static == accessor.is_static
+                    && other.is_private == accessor.is_private
+                    && other.name == accessor.name
+            });
+            if has_pair {
+                continue;
+            }
+            let description = accessor.description();
+            let diagnostic = match (accessor.is_getter, in_class) {
+                (false, false) if self.set_without_get => {
+                    AccessorPairsDiagnostic::MissingGetter(description, accessor.span)
+                }
+                (false, true) if self.set_without_get => {
+                    AccessorPairsDiagnostic::MissingGetterInClass(description, accessor.span)
+                }
+                (true, false) if self.get_without_set => {
+                    AccessorPairsDiagnostic::MissingSetter(description, accessor.span)
+                }
+                (true, true) if self.get_without_set => {
+                    AccessorPairsDiagnostic::MissingSetterInClass(description, accessor.span)
+                }
+                _ => continue,
+            };
+            ctx.diagnostic(diagnostic);
+        }
+    }
+
+    /// Check the `get` and `set` of a descriptor like `{ set: function(value) {} }`.
+    fn check_property_descriptor(&self, descriptor: &ObjectExpression, ctx: &LintContext) {
+        let has_key = |name: &str| {
+            descriptor.properties.iter().any(|prop| {
+                matches!(prop, ObjectPropertyKind::ObjectProperty(prop)
+                    if prop.kind == PropertyKind::Init && !prop.computed
+                        && prop.key.is_specific_static_name(name))
+            })
+        };
+        let (has_getter, has_setter) = (has_key("get"), has_key("set"));
+        if self.set_without_get && has_setter && !has_getter {
+            ctx.diagnostic(AccessorPairsDiagnostic::MissingGetterInPropertyDescriptor(
+                descriptor.span,
+            ));
+        } else if self.get_without_set && has_getter && !has_setter {
+            ctx.diagnostic(AccessorPairsDiagnostic::MissingSetterInPropertyDescriptor(
+                descriptor.span,
+            ));
+        }
+    }
+}
+
+/// A getter or a setter of an object literal or a class.
+struct Accessor {
+    /// The name of the property, or the source text of a computed key
+    name: String,
+    is_private: bool,
+    is_getter: bool,
+    is_static: bool,
+    /// Whether `name` is the name of the property, rather than a computed key
+    is_static_name: bool,
+    span: Span,
+}
+
+impl Accessor {
+    fn new(key: &PropertyKey, is_getter: bool, is_static: bool, ctx: &LintContext) -> Self {
+        let (name, is_static_name) = match key {
+            PropertyKey::PrivateIdentifier(ident) => (ident.name.to_string(), true),
+            key => match key.static_name() {
+                Some(name) => (name.to_string(), true),
+                None => (format!("[{}]", key.span().source_text(ctx.source_text())), false),
+            },
+        };
+        Self {
+            name,
+            is_private: matches!(key, PropertyKey::PrivateIdentifier(_)),
+            is_getter,
+            is_static,
+            is_static_name,
+            span: key.span(),
+        }
+    }
+
+    /// e.g. `setter 'a'`, `static getter 'b'` or `private setter #c`
+    fn description(&self) -> String {
+        let mut tokens = vec![];
+        if self.is_static {
+            tokens.push("static".to_string());
+        }
+        if self.is_private {
+            tokens.push("private".to_string());
+        }
+        tokens.push(if self.is_getter { "getter" } else { "setter" }.to_string());
+        if self.is_private {
+            tokens.push(format!("#{}", self.name));
+        } else if self.is_static_name {
+            tokens.push(format!("'{}'", self.name));
+        }
+        tokens.join(" ")
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let get_without_set = || Some(serde_json::json!([{ "getWithoutSet": true }]));
+
+    let pass = vec![
+        ("var { get: foo } = bar; ({ set: foo } = bar);", None),
+        ("var o = { get a() {} }", None),
+        ("var o = { set a(foo) {} }", Some(serde_json::json!([{ "setWithoutGet": false }]))),
+        ("var o = { get a() {}, set a(foo) {} }", None),
+        ("var o = { set a(foo) {}, get a() {} }", get_without_set()),
+        ("var o = { get a() {}, b: 1, set a(foo) {} }", get_without_set()),
+        ("var o = { get 'a'() {}, set a(foo) {} }", None),
+        ("var o = { get [a]() {}, set [a](foo) {} }", None),
+        ("var o = { get [a + b]() {}, set [a + b](foo) {} }", None),
+        ("var o = { a: 1, set: function(foo) {} }", None),
+        ("var o = { ...a, set [a](foo) {}, get [a]() {} }", None),
+        ("var o = { set 1(foo) {}, get [1]() {} }", None),
+        ("class A { get a() {} }", None),
+        ("class A { get a() {} set a(foo) {} }", None),
+        ("class A { static set a(foo) {} static get a() {} }", None),
+        ("class A { get #a() {} set #a(foo) {} }", None),
+        (
+            "class A { set a(foo) {} }",
+            Some(serde_json::json!([{ "enforceForClassMembers": false }])),
+        ),
+        ("Object.defineProperty(o, 'a', { get: function() {}, set: function(foo) {} })", None),
+        ("Object.defineProperty(o, 'a', { value: 1 })", None),
+        ("Object.defineProperties(o, { a: { get: function() {}, set: function(foo) {} } })", None),
+        ("Object.create(null, { a: { get() {}, set(foo) {} } })", None),
+        ("Reflect.defineProperty(o, 'a', { get: function() {} })", None),
+        ("foo.defineProperty(o, 'a', { set: function(foo) {} })", None),
+    ];
+
+    let fail = vec![
+        ("var o = { set a(foo) {} }", None),
+        ("var o = { get a() {} }", get_without_set()),
+        ("var o = { set a(foo) {}, get b() {} }", get_without_set()),
+        ("var o = { set [a](foo) {} }", None),
+        ("var o = { set [a](foo) {}, get [b]() {} }", None),
+        ("var o = { get a() {}, set a(foo) {}, set b(foo) {} }", None),
+        ("class A { set a(foo) {} }", None),
+        ("class A { get a() {} }", get_without_set()),
+        ("class A { static set a(foo) {} get a() {} }", None),
+        ("class A { set #a(foo) {} }", None),
+        ("class A { get a() {} set #a(foo) {} }", None),
+        ("class A { static set [a](foo) {} }", None),
+        ("Object.defineProperty(o, 'a', { set: function(foo) {} })", None),
+        ("Reflect.defineProperty(o, 'a', { get: function() {} })", get_without_set()),
+        ("Object.defineProperties(o, { a: { set: function(foo) {} } })", None),
+        ("Object.create(null, { a: { set(foo) {} } })", None),
+    ];
+
+    Tester::new(AccessorPairs::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,302 @@
+use oxc_ast::{
+    ast::{ArrowFunctionExpression, Expression, Statement},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ArrowBodyStyleDiagnostic {
+    #[error("eslint(arrow-body-style): Expected block statement surrounding arrow body.")]
+    #[diagnostic(severity(warning))]
+    ExpectedBlock(#[label] Span),
+
+    #[error("eslint(arrow-body-style): Unexpected block statement surrounding arrow body.")]
+    #[diagnostic(severity(warning))]
+    UnexpectedBlock(#[label] Span),
+
+    #[error("eslint(arrow-body-style): Unexpected block statement surrounding arrow body; parenthesize the returned value and move it immediately after the `=>`.")]
+    #[diagnostic(severity(warning))]
+    UnexpectedObjectBlock(#[label] Span),
+
+    #[error("eslint(arrow-body-style): Unexpected block statement surrounding arrow body; move the returned value immediately after the `=>`.")]
+    #[diagnostic(severity(warning))]
+    UnexpectedSingleBlock(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    #[default]
+    AsNeeded,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ArrowBodyStyle {
+    mode: Mode,
+    require_return_for_object_literal: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Require braces around arrow function bodies, or disallow them where they can be omitted
+    ///
+    /// ### Why is this bad?
+    /// `() => { return foo; }` says in more words what `() => foo` says, and mixing both
+    /// styles in a code base is inconsistent.
+    ///
+    /// ### Options
+    /// The first option is one of:
+    /// * `"as-needed"` (default): disallow braces around bodies which only return a value.
+    /// * `"always"`: require braces around all bodies.
+    /// * `"never"`: disallow braces around all bodies.
+    ///
+    /// With `"as-needed"`, the second option can be `{ "requireReturnForObjectLiteral": true }`
+    /// to require braces around bodies returning an object literal.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// let foo = () => {
+    ///     return 0;
+    /// };
+    ///
+    /// // good
+    /// let foo = () => 0;
+    /// let bar = () => {
+    ///     bar();
+    ///     return 0;
+    /// };
+    /// ```
+    ArrowBodyStyle,
+    style
+);
+
+impl Rule for ArrowBodyStyle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("always") => Mode::Always,
+            Some("never") => Mode::Never,
+            _ => Mode::AsNeeded,
+        };
+        let require_return_for_object_literal = value
+            .get(1)
+            .and_then(|config| config.get("requireReturnForObjectLiteral"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { mode, require_return_for_object_literal }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ArrowFunctionExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ArrowFunctionExpression(arrow) = node.kind() else { return };
+        if let Some(expr) = arrow.get_expression() {
+            self.check_expression_body(expr, ctx);
+        } else {
+            self.check_block_body(arrow, ctx);
+        }
+    }
+}
+
+impl ArrowBodyStyle {
+    fn check_expression_body(&self, expr: &Expression, ctx: &LintContext) {
+        let returns_object =
+            matches!(expr.without_parenthesized(), Expression::ObjectExpression(_));
+        let expects_block = match self.mode {
+            Mode::Always => true,
+            Mode::AsNeeded => self.require_return_for_object_literal && returns_object,
+            Mode::Never => false,
+        };
+        if !expects_block {
+            return;
+        }
+
+        let diagnostic = ArrowBodyStyleDiagnostic::ExpectedBlock(expr.span());
+        if ctx.semantic().trivias().has_comments_between(expr.span()) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let value = expr.without_parenthesized().span().source_text(ctx.source_text());
+            Fix::new(format!("{{ return {value}; }}"), expr.span())
+        });
+    }
+
+    fn check_block_body(&self, arrow: &ArrowFunctionExpression, ctx: &LintContext) {
+        let body = &arrow.body;
+        // Directives like `"use strict"` are statements of the body for this rule
+        let len = body.directives.len() + body.statements.len();
+        if len != 1 && self.mode != Mode::Never {
+            return;
+        }
+        let returned = match body.statements.first() {
+            Some(Statement::ReturnStatement(stmt)) if len == 1 => Some(&stmt.argument),
+            _ => None,
+        };
+        if self.mode == Mode::AsNeeded && self.require_return_for_object_literal {
+            if let Some(Some(argument)) = returned {
+                if matches!(argument.without_parenthesized(), Expression::ObjectExpression(_)) {
+                    return;
+                }
+            }
+        }
+        // `() => { return; }` has no value to move after the `=>`
+        if self.mode == Mode::Always
+            || (self.mode == Mode::AsNeeded && !matches!(returned, Some(Some(_))))
+        {
+            return;
+        }
+
+        let source_text = ctx.source_text();
+        let argument = returned.and_then(Option::as_ref);
+        let diagnostic = match argument {
+            _ if len != 1 => ArrowBodyStyleDiagnostic::UnexpectedBlock(body.span),
+            Some(argument) if argument.span().source_text(source_text).starts_with('{') => {
+                ArrowBodyStyleDiagnostic::UnexpectedObjectBlock(body.span)
+            }
+            _ => ArrowBodyStyleDiagnostic::UnexpectedSingleBlock(body.span),
+        };
+        match argument.and_then(|argument| fix_block_body(arrow, argument, ctx)) {
+            Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Replace `{ return foo; }` by `foo`
+fn fix_block_body<'a>(
+    arrow: &ArrowFunctionExpression,
+    argument: &Expression,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    let body = arrow.body.span;
+    let trivias = ctx.semantic().trivias();
+    if trivias.has_comments_between(Span::new(body.start, argument.span().start))
+        || trivias.has_comments_between(Span::new(argument.span().end, body.end))
+    {
+        return None;
+    }
+    // Without braces, the code following the arrow function could be read as part of its body,
+    // e.g. `() => { return a; }\n(b)` would become `() => a\n(b)`
+    if matches!(
+        ctx.source_text()[arrow.span.end as usize..].trim_start().chars().next(),
+        Some('(' | '[' | '`')
+    ) {
+        return None;
+    }
+
+    let text = argument.span().source_text(ctx.source_text());
+    let text = if text.starts_with('{') || matches!(argument, Expression::SequenceExpression(_)) {
+        format!("({text})")
+    } else {
+        text.to_string()
+    };
+    Some(Fix::new(text, body))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo = () => {};", None),
+        ("var foo = () => 0;", None),
+        ("var addToB = (a) => { b =  b + a };", None),
+        ("var foo = () => { /* do nothing */ };", None),
+        ("var foo = () => {\n /* do nothing */ \n};", None),
+        ("var foo = (retv, name) => {\nretv[name] = true;\nreturn retv;\n};", None),
+        ("var foo = () => ({});", None),
+        ("var foo = () => bar();", None),
+        ("var foo = () => { bar(); };", None),
+        ("var foo = () => { b = a };", None),
+        ("var foo = () => { bar: 1 };", None),
+        ("var foo = () => { return 0; };", Some(serde_json::json!(["always"]))),
+        ("var foo = () => { return bar(); };", Some(serde_json::json!(["always"]))),
+        ("var foo = () => 0;", Some(serde_json::json!(["never"]))),
+        ("var foo = () => ({ foo: 0 });", Some(serde_json::json!(["never"]))),
+        ("var foo = () => {};", Some(serde_json::json!(["as-needed"]))),
+        ("var foo = () => { return; };", Some(serde_json::json!(["as-needed"]))),
+        ("var foo = () => { bar(); return 0; };", Some(serde_json::json!(["as-needed"]))),
+        (
+            "var foo = () => { return { bar: 0 }; };",
+            Some(serde_json::json!(["as-needed", { "requireReturnForObjectLiteral": true }])),
+        ),
+        (
+            "var foo = () => { return ({ bar: 0 }); };",
+            Some(serde_json::json!(["as-needed", { "requireReturnForObjectLiteral": true }])),
+        ),
+        (
+            "var foo = () => 0;",
+            Some(serde_json::json!(["as-needed", { "requireReturnForObjectLiteral": true }])),
+        ),
+        ("var foo = () => { 'use strict'; return 0; };", None),
+    ];
+
+    let fail = vec![
+        ("var foo = () => 0;", Some(serde_json::json!(["always"]))),
+        ("var foo = () => ({});", Some(serde_json::json!(["always"]))),
+        ("var foo = () => {};", Some(serde_json::json!(["never"]))),
+        ("var foo = () => { bar(); return 0; };", Some(serde_json::json!(["never"]))),
+        ("var foo = () => { return; };", Some(serde_json::json!(["never"]))),
+        ("var foo = () => { return 0; };", Some(serde_json::json!(["as-needed"]))),
+        ("var foo = () => { return bar(); };", None),
+        ("var foo = () => { return { bar: 0 }; };", None),
+        ("var foo = () => { return ({ bar: 0 }); };", None),
+        (
+            "var foo = () => ({ bar: 0 });",
+            Some(serde_json::json!(["as-needed", { "requireReturnForObjectLiteral": true }])),
+        ),
+        ("var foo = () => { return a, b; };", None),
+        ("var foo = () => { return /* comment */ 0; };", None),
+        ("var foo = () => { return 0; }\n(bar);", None),
+    ];
+
+    let fix = vec![
+        (
+            "var foo = () => 0;",
+            "var foo = () => { return 0; };",
+            Some(serde_json::json!(["always"])),
+        ),
+        (
+            "var foo = () => ({ bar: 0 });",
+            "var foo = () => { return { bar: 0 }; };",
+            Some(serde_json::json!(["always"])),
+        ),
+        ("var foo = () => { return 0; };", "var foo = () => 0;", None),
+        ("var foo = async () => { return await bar; };", "var foo = async () => await bar;", None),
+        ("var foo = () => { return { bar: 0 }; };", "var foo = () => ({ bar: 0 });", None),
+        ("var foo = () => { return ({ bar: 0 }); };", "var foo = () => ({ bar: 0 });", None),
+        ("var foo = () => { return a, b; };", "var foo = () => (a, b);", None),
+        ("var foo = () => {\n  return bar;\n};", "var foo = () => bar;", None),
+        (
+            "var foo = () => ({ bar: 0 });",
+            "var foo = () => { return { bar: 0 }; };",
+            Some(serde_json::json!(["as-needed", { "requireReturnForObjectLiteral": true }])),
+        ),
+        (
+            "var foo = () => { bar(); return 0; };",
+            "var foo = () => { bar(); return 0; };",
+            Some(serde_json::json!(["never"])),
+        ),
+        (
+            "var foo = () => { return /* comment */ 0; };",
+            "var foo = () => { return /* comment */ 0; };",
+            None,
+        ),
+        ("foo(() => { return 0; })\n(bar);", "foo(() => 0)\n(bar);", None),
+        ("(() => { return 0; })\n(bar);", "(() => 0)\n(bar);", None),
+        ("var foo = () => { return 0; }\n(bar);", "var foo = () => { return 0; }\n(bar);", None),
+    ];
+
+    Tester::new(ArrowBodyStyle::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
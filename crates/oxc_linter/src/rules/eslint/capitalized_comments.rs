@@ -0,0 +1,336 @@
+use once_cell::sync::Lazy;
+use oxc_ast::{AstType, Comment, CommentKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{
+    ast_util::{get_comment_span, get_line_text_around},
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum CapitalizedCommentsDiagnostic {
+    #[error("eslint(capitalized-comments): Comments should not begin with a lowercase character.")]
+    #[diagnostic(severity(warning))]
+    Lowercase(#[label] Span),
+
+    #[error(
+        "eslint(capitalized-comments): Comments should not begin with an uppercase character."
+    )]
+    #[diagnostic(severity(warning))]
+    Uppercase(#[label] Span),
+}
+
+/// Comments of other tools, which are case sensitive
+static DEFAULT_IGNORE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*(?:eslint|jshint\s+|jslint\s+|istanbul\s+|globals?\s+|exported\s+|jscs)")
+        .unwrap()
+});
+
+static MAYBE_URL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*[^:/?#\s]+://[^?#]").unwrap());
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Capitalize {
+    #[default]
+    Always,
+    Never,
+}
+
+#[derive(Debug, Default, Clone)]
+struct CommentOptions {
+    ignore_pattern: Option<Regex>,
+    ignore_inline_comments: bool,
+    ignore_consecutive_comments: bool,
+}
+
+impl CommentOptions {
+    fn from_configuration(value: &serde_json::Value) -> Self {
+        let ignore_pattern = value
+            .get("ignorePattern")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|pattern| Regex::new(&format!(r"^\s*(?:{pattern})")).ok());
+        let ignore_inline_comments =
+            value.get("ignoreInlineComments").and_then(serde_json::Value::as_bool).unwrap_or(false);
+        let ignore_consecutive_comments = value
+            .get("ignoreConsecutiveComments")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { ignore_pattern, ignore_inline_comments, ignore_consecutive_comments }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CapitalizedComments(Box<CapitalizedCommentsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct CapitalizedCommentsConfig {
+    capitalize: Capitalize,
+    line: CommentOptions,
+    block: CommentOptions,
+}
+
+impl std::ops::Deref for CapitalizedComments {
+    type Target = CapitalizedCommentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce or disallow capitalization of the first letter of a comment
+    ///
+    /// ### Why is this bad?
+    /// A consistent style for comments makes a codebase easier to read.
+    ///
+    /// ### Options
+    /// The first option is `"always"` (default) or `"never"`. The second option is an object with:
+    /// * `ignorePattern`: a regular expression for words at the start of comments to ignore.
+    /// * `ignoreInlineComments`: ignore comments in the middle of code.
+    /// * `ignoreConsecutiveComments`: ignore comments directly following another comment.
+    ///
+    /// The object may also be given separately for `line` and `block` comments.
+    ///
+    /// Comments starting with a non-letter, a URL or a directive for a tool, e.g. `eslint-disable`,
+    /// are always allowed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // lowercase comment
+    /// /* lowercase block comment */
+    /// ```
+    CapitalizedComments,
+    style
+);
+
+impl Rule for CapitalizedComments {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let capitalize = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("never") => Capitalize::Never,
+            _ => Capitalize::Always,
+        };
+        let Some(config) = value.get(1) else {
+            return Self(Box::new(CapitalizedCommentsConfig {
+                capitalize,
+                ..CapitalizedCommentsConfig::default()
+            }));
+        };
+        let line = CommentOptions::from_configuration(config.get("line").unwrap_or(config));
+        let block = CommentOptions::from_configuration(config.get("block").unwrap_or(config));
+        Self(Box::new(CapitalizedCommentsConfig { capitalize, line, block }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let mut previous_comment_end = None;
+        for (comment, span) in ctx.semantic().trivias().comments_spans() {
+            let comment_span = get_comment_span(span, comment);
+            let is_consecutive = previous_comment_end.is_some_and(|end| {
+                Span::new(end, comment_span.start).source_text(source_text).trim().is_empty()
+            });
+            previous_comment_end = Some(comment_span.end);
+
+            let content = span.source_text(source_text);
+            if self.is_comment_valid(comment, content, comment_span, is_consecutive, source_text) {
+                continue;
+            }
+
+            let Some((offset, letter)) = content.char_indices().find(|(_, c)| c.is_alphabetic())
+            else {
+                continue;
+            };
+            let (diagnostic, replacement) = match self.capitalize {
+                Capitalize::Always => (
+                    CapitalizedCommentsDiagnostic::Lowercase(comment_span),
+                    letter.to_uppercase().collect::<String>(),
+                ),
+                Capitalize::Never => (
+                    CapitalizedCommentsDiagnostic::Uppercase(comment_span),
+                    letter.to_lowercase().collect::<String>(),
+                ),
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            let start = span.start + offset as u32;
+            #[allow(clippy::cast_possible_truncation)]
+            let letter_span = Span::new(start, start + letter.len_utf8() as u32);
+            ctx.diagnostic_with_fix(diagnostic, || Fix::new(replacement, letter_span));
+        }
+    }
+}
+
+impl CapitalizedComments {
+    fn is_comment_valid(
+        &self,
+        comment: Comment,
+        content: &str,
+        comment_span: Span,
+        is_consecutive: bool,
+        source_text: &str,
+    ) -> bool {
+        let options = match comment.kind() {
+            CommentKind::SingleLine => &self.line,
+            CommentKind::MultiLine => &self.block,
+        };
+
+        if DEFAULT_IGNORE_PATTERN.is_match(content) {
+            return true;
+        }
+
+        let without_asterisks = content.replace('*', "");
+        if options
+            .ignore_pattern
+            .as_ref()
+            .is_some_and(|pattern| pattern.is_match(&without_asterisks))
+        {
+            return true;
+        }
+
+        if options.ignore_inline_comments {
+            let (before, after) = get_line_text_around(comment_span, source_text);
+            if !before.trim().is_empty() && !after.trim().is_empty() {
+                return true;
+            }
+        }
+
+        if options.ignore_consecutive_comments && is_consecutive {
+            return true;
+        }
+
+        if MAYBE_URL.is_match(&without_asterisks) {
+            return true;
+        }
+
+        let Some(first) = without_asterisks.chars().find(|c| !c.is_whitespace()) else {
+            return true;
+        };
+        if !first.is_alphabetic() {
+            return true;
+        }
+
+        match self.capitalize {
+            Capitalize::Always => !first.is_lowercase(),
+            Capitalize::Never => !first.is_uppercase(),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("//Uppercase", None),
+        ("// Uppercase", None),
+        ("/*Uppercase */", None),
+        ("/* Uppercase */", None),
+        ("/*\nUppercase */", None),
+        ("/** Uppercase */", None),
+        ("/**\nUppercase */", None),
+        ("//Über", None),
+        ("//Π", None),
+        ("/* Uppercase\nsecond line need not be uppercase */", None),
+        ("// ", None),
+        ("//", None),
+        ("/**/", None),
+        ("//123", None),
+        ("// 123", None),
+        ("/*123*/", None),
+        ("/* 123 */", None),
+        ("/**123 */", None),
+        ("//@todo", None),
+        ("// @todo", None),
+        ("// 丈", None),
+        ("// jscs: enable", None),
+        ("// jscs:disable", None),
+        ("// eslint-disable-line", None),
+        ("// eslint-disable-next-line", None),
+        ("/* eslint semi:off */", None),
+        ("/* eslint-env node */", None),
+        ("/* istanbul ignore next */", None),
+        ("/* jshint asi:true */", None),
+        ("/* jscs: enable */", None),
+        ("/* global var1, var2 */", None),
+        ("/* globals var1, var2 */", None),
+        ("/* exported myVar */", None),
+        ("// https://github.com", None),
+        ("/* https://github.com */", None),
+        ("//lowercase", Some(serde_json::json!(["never"]))),
+        ("// lowercase", Some(serde_json::json!(["never"]))),
+        ("/* lowercase */", Some(serde_json::json!(["never"]))),
+        ("// matching", Some(serde_json::json!(["always", { "ignorePattern": "match" }]))),
+        ("/* matching */", Some(serde_json::json!(["always", { "ignorePattern": "match" }]))),
+        ("/** matching */", Some(serde_json::json!(["always", { "ignorePattern": "match" }]))),
+        (
+            "foo(/* ignored */ a);",
+            Some(serde_json::json!(["always", { "ignoreInlineComments": true }])),
+        ),
+        (
+            "foo(/* Ignored */ a);",
+            Some(serde_json::json!(["never", { "ignoreInlineComments": true }])),
+        ),
+        (
+            "// This comment is valid since it is capitalized,\n// and this one is valid since it follows a valid one,\n// and same with this one.",
+            Some(serde_json::json!(["always", { "ignoreConsecutiveComments": true }])),
+        ),
+        (
+            "/* This comment is valid since it is capitalized, */\n/* and this one is valid since it follows a valid one, */\n/* and same with this one. */",
+            Some(serde_json::json!(["always", { "ignoreConsecutiveComments": true }])),
+        ),
+        (
+            "// matching\n/* Uppercase */",
+            Some(serde_json::json!(["always", { "line": { "ignorePattern": "match" } }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("//lowercase", None),
+        ("// lowercase", None),
+        ("/*lowercase */", None),
+        ("/* lowercase */", None),
+        ("/** lowercase */", None),
+        ("//über", None),
+        ("//π", None),
+        ("//Uppercase", Some(serde_json::json!(["never"]))),
+        ("// Uppercase", Some(serde_json::json!(["never"]))),
+        ("/* Uppercase */", Some(serde_json::json!(["never"]))),
+        ("//Über", Some(serde_json::json!(["never"]))),
+        ("// not matching", Some(serde_json::json!(["always", { "ignorePattern": "ignored?" }]))),
+        ("foo(/* not ignored */ a);", None),
+        (
+            "foo(a, // not an inline comment\nb);",
+            Some(serde_json::json!(["always", { "ignoreInlineComments": true }])),
+        ),
+        (
+            "// This comment is valid since it is capitalized,\n\nfoo();\n// but this one is not.",
+            Some(serde_json::json!(["always", { "ignoreConsecutiveComments": true }])),
+        ),
+        (
+            "// matching\n/* matching */",
+            Some(serde_json::json!(["always", { "line": { "ignorePattern": "match" } }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("//lowercase", "//Lowercase", None),
+        ("// lowercase", "// Lowercase", None),
+        ("/** lowercase */", "/** Lowercase */", None),
+        ("/*\nlowercase */", "/*\nLowercase */", None),
+        ("//über", "//Über", None),
+        ("//Uppercase", "//uppercase", Some(serde_json::json!(["never"]))),
+        ("/* Uppercase */", "/* uppercase */", Some(serde_json::json!(["never"]))),
+    ];
+
+    Tester::new(CapitalizedComments::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
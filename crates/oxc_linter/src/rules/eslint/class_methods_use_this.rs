@@ -0,0 +1,242 @@
+use oxc_ast::{
+    ast::{MethodDefinitionKind, PropertyKey},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{ast_util::function_name_with_kind, context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(class-methods-use-this): Expected 'this' to be used by class {0}.")]
+#[diagnostic(
+    severity(warning),
+    help(
+        "Use 'this' in the method, or make it a static method or a function outside of the class"
+    )
+)]
+struct ClassMethodsUseThisDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct ClassMethodsUseThis(Box<ClassMethodsUseThisConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ClassMethodsUseThisConfig {
+    except_methods: Vec<String>,
+    enforce_for_class_fields: bool,
+}
+
+impl std::ops::Deref for ClassMethodsUseThis {
+    type Target = ClassMethodsUseThisConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for ClassMethodsUseThisConfig {
+    fn default() -> Self {
+        Self { except_methods: vec![], enforce_for_class_fields: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce that class methods utilize `this`
+    ///
+    /// ### Why is this bad?
+    /// A class method which does not use `this` does not depend on the instance,
+    /// and can be made a static method or a plain function.
+    /// `this` in arrow functions nested in the method counts as a use.
+    ///
+    /// Methods listed in `exceptMethods` are ignored (private methods as `#name`).
+    /// Class fields initialized with a function are checked as well,
+    /// unless `enforceForClassFields` is `false`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// class A {
+    ///   foo() {
+    ///     return 1;
+    ///   }
+    /// }
+    /// ```
+    ClassMethodsUseThis,
+    pedantic
+);
+
+impl Rule for ClassMethodsUseThis {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let except_methods = config
+            .get("exceptMethods")
+            .and_then(serde_json::Value::as_array)
+            .map(|methods| {
+                methods
+                    .iter()
+                    .filter_map(serde_json::Value::as_str)
+                    .map(ToString::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let enforce_for_class_fields = config
+            .get("enforceForClassFields")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(true);
+
+        Self(Box::new(ClassMethodsUseThisConfig { except_methods, enforce_for_class_fields }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let uses_this = functions_using_this(ctx);
+
+        for node in ctx.nodes().iter() {
+            // The span marked when `this` is used, the key of the class member and whether it is static
+            let (marked, key, is_static) = match (node.kind(), ctx.nodes().parent_kind(node.id())) {
+                (AstKind::Function(func), Some(AstKind::MethodDefinition(method))) => {
+                    if method.kind == MethodDefinitionKind::Constructor || func.body.is_none() {
+                        continue;
+                    }
+                    (func.span, &method.key, method.r#static)
+                }
+                (AstKind::Function(func), Some(AstKind::PropertyDefinition(prop)))
+                    if self.enforce_for_class_fields =>
+                {
+                    (func.span, &prop.key, prop.r#static)
+                }
+                (AstKind::ArrowFunctionExpression(_), Some(AstKind::PropertyDefinition(prop)))
+                    if self.enforce_for_class_fields =>
+                {
+                    (prop.span, &prop.key, prop.r#static)
+                }
+                _ => continue,
+            };
+            if is_static || uses_this.contains(&marked) || self.is_except_method(key) {
+                continue;
+            }
+            ctx.diagnostic(ClassMethodsUseThisDiagnostic(
+                function_name_with_kind(node, ctx),
+                key.span(),
+            ));
+        }
+    }
+}
+
+impl ClassMethodsUseThis {
+    fn is_except_method(&self, key: &PropertyKey) -> bool {
+        let name = match key {
+            PropertyKey::PrivateIdentifier(ident) => format!("#{}", ident.name),
+            key => match key.static_name() {
+                Some(name) => name.to_string(),
+                None => return false,
+            },
+        };
+        self.except_methods.contains(&name)
+    }
+}
+
+/// The spans of the functions, and of the class fields initialized with an arrow function,
+/// in which `this` or `super` is used, including in nested arrow functions.
+fn functions_using_this(ctx: &LintContext) -> FxHashSet<Span> {
+    let mut spans = FxHashSet::default();
+    for node in ctx.nodes().iter() {
+        if !matches!(node.kind(), AstKind::ThisExpression(_) | AstKind::Super(_)) {
+            continue;
+        }
+        // `this` in a computed key belongs to the enclosing function of the class
+        let mut in_key = false;
+        for id in ctx.nodes().ancestors(node.id()).skip(1) {
+            match ctx.nodes().kind(id) {
+                AstKind::PropertyKey(_) => in_key = true,
+                AstKind::Function(func) => {
+                    spans.insert(func.span);
+                    break;
+                }
+                AstKind::StaticBlock(_) => break,
+                AstKind::PropertyDefinition(prop) if !in_key => {
+                    spans.insert(prop.span);
+                    break;
+                }
+                AstKind::PropertyDefinition(_)
+                | AstKind::MethodDefinition(_)
+                | AstKind::ObjectProperty(_) => in_key = false,
+                _ => {}
+            }
+        }
+    }
+    spans
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("class A { constructor() {} }", None),
+        ("class A { foo() {this} }", None),
+        ("class A { foo() {this.bar = 'bar';} }", None),
+        ("class A { foo() {bar(this);} }", None),
+        ("class A extends B { foo() {super.foo();} }", None),
+        ("class A { foo() { if(true) { return this; } } }", None),
+        ("class A { static foo() {} }", None),
+        ("({ a(){} });", None),
+        ("class A { foo() { () => this; } }", None),
+        ("({ a: function () {} });", None),
+        ("class A { foo() { return () => () => this; } }", None),
+        ("class A { foo() {} }", Some(serde_json::json!([{ "exceptMethods": ["foo"] }]))),
+        ("class A { #bar() {} }", Some(serde_json::json!([{ "exceptMethods": ["#bar"] }]))),
+        ("class A { foo = function () {this} }", None),
+        ("class A { foo = () => {this} }", None),
+        ("class A { foo = () => {super.toString} }", None),
+        ("class A { static foo = function () {} }", None),
+        ("class A { static foo = () => {} }", None),
+        ("class A { #bar = () => {this} }", None),
+        (
+            "class A { foo = () => {} }",
+            Some(serde_json::json!([{ "enforceForClassFields": false }])),
+        ),
+        (
+            "class A { foo = function () {} }",
+            Some(serde_json::json!([{ "enforceForClassFields": false }])),
+        ),
+        ("class A { foo = 1; }", None),
+        ("abstract class A { abstract foo(): void; }", None),
+    ];
+
+    let fail = vec![
+        ("class A { foo() {} }", None),
+        ("class A { foo() {/**this**/} }", None),
+        ("class A { foo() {var a = function () {this};} }", None),
+        ("class A { foo() {var a = function () {var b = function(){this}};} }", None),
+        ("class A { foo() {window.this} }", None),
+        ("class A { foo() {that.this = 'this';} }", None),
+        ("class A { foo() { () => undefined; } }", None),
+        ("class A { foo() {} bar() {} }", Some(serde_json::json!([{ "exceptMethods": ["bar"] }]))),
+        ("class A { foo() {} hasOwnProperty() {} }", Some(serde_json::json!([{ "exceptMethods": ["foo"] }]))),
+        ("class A { [foo]() {} }", Some(serde_json::json!([{ "exceptMethods": ["foo"] }]))),
+        ("class A { #foo() { } foo() {} #bar() {} }", Some(serde_json::json!([{ "exceptMethods": ["#foo"] }]))),
+        ("class A { foo(){} 'bar'(){} 123(){} [`baz`](){} [a](){} [f(a)](){} get quux(){} set[a](b){} *quuux(){} }", None),
+        ("class A { foo = function() {} }", None),
+        ("class A { foo = () => {} }", None),
+        ("class A { #foo = function() {} }", None),
+        ("class A { #foo = () => {} }", None),
+        ("class A { #foo() {} }", None),
+        ("class A { get foo() {} }", None),
+        ("class A { set foo(a) {} }", None),
+        ("class A { [this.foo]() {} }", None),
+        ("class A { foo() { class B { bar = this; } } }", None),
+    ];
+
+    Tester::new(ClassMethodsUseThis::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,156 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    ast_util::{get_enclosing_function, get_function_head_span, get_function_name_with_kind},
+    context::LintContext,
+    rule::Rule,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(complexity): {0} has a complexity of {1}. Maximum allowed is {2}.")]
+#[diagnostic(severity(warning))]
+struct ComplexityDiagnostic(String, usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct Complexity {
+    max: usize,
+}
+
+impl Default for Complexity {
+    fn default() -> Self {
+        Self { max: 20 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum cyclomatic complexity allowed in a function, which is `20` by default.
+    ///
+    /// The complexity of a function is one more than the number of its branches:
+    /// `if`, `?:`, loops, `catch`, non-default `case`s, `&&`, `||`, `??` and their assignments,
+    /// default values and optional chaining. Nested functions are measured on their own.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Functions with many paths through them are hard to understand and to test.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // { "max": 2 }
+    /// function a(x) {
+    ///     if (x === 1) {
+    ///         return "one";
+    ///     } else if (x === 2) {
+    ///         return "two";
+    ///     }
+    /// }
+    /// ```
+    Complexity,
+    pedantic
+);
+
+impl Rule for Complexity {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let max = config.and_then(|config| {
+            config
+                .as_u64()
+                .or_else(|| config.get("max")?.as_u64())
+                .or_else(|| config.get("maximum")?.as_u64())
+        });
+        max.and_then(|max| usize::try_from(max).ok()).map_or_else(Self::default, |max| Self { max })
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let mut branches = FxHashMap::<AstNodeId, usize>::default();
+        for node in ctx.nodes().iter() {
+            if !is_branch(node.kind()) {
+                continue;
+            }
+            if let Some(function) = get_enclosing_function(node, ctx) {
+                *branches.entry(function.id()).or_default() += 1;
+            }
+        }
+
+        for node in ctx.nodes().iter() {
+            let is_function = match node.kind() {
+                AstKind::Function(func) => func.body.is_some(),
+                AstKind::ArrowFunctionExpression(_) => true,
+                _ => false,
+            };
+            if !is_function {
+                continue;
+            }
+            let complexity = 1 + branches.get(&node.id()).copied().unwrap_or_default();
+            if complexity > self.max {
+                ctx.diagnostic(ComplexityDiagnostic(
+                    get_function_name_with_kind(node, ctx),
+                    complexity,
+                    self.max,
+                    get_function_head_span(node, ctx),
+                ));
+            }
+        }
+    }
+}
+
+fn is_branch(kind: AstKind) -> bool {
+    match kind {
+        AstKind::IfStatement(_)
+        | AstKind::ConditionalExpression(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::CatchClause(_)
+        | AstKind::LogicalExpression(_)
+        | AstKind::AssignmentPattern(_) => true,
+        AstKind::SwitchCase(case) => case.test.is_some(),
+        AstKind::AssignmentExpression(expr) => expr.operator.is_logical(),
+        AstKind::MemberExpression(expr) => expr.optional(),
+        AstKind::CallExpression(expr) => expr.optional,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function a(x) {}", None),
+        ("function b(x) { if (x === 1) { return 1; } }", Some(json!([2]))),
+        ("function c(x) { return x ?? 1; }", Some(json!([{ "max": 2 }]))),
+        ("var d = (x) => x ? 1 : 2;", Some(json!([{ "maximum": 2 }]))),
+        ("function e(x) { switch (x) { case 1: return 1; default: return 0; } }", Some(json!([2]))),
+        ("function f(x) { if (x) {} return function () { if (x) {} }; }", Some(json!([2]))),
+        ("if (a) {} else if (b) {} else if (c) {}", Some(json!([1]))),
+    ];
+
+    let fail = vec![
+        ("function a(x) { if (x) {} else if (!x) {} }", Some(json!([2]))),
+        ("var b = (x) => x && x.y;", Some(json!([1]))),
+        ("class C { static m(x = 1) { return x?.y; } }", Some(json!([{ "max": 2 }]))),
+        (
+            "function d(x) { for (;;) { while (x) { try {} catch { x ||= 1; } } } }",
+            Some(json!([3])),
+        ),
+    ];
+
+    Tester::new(Complexity::NAME, pass, fail).test_and_snapshot();
+}
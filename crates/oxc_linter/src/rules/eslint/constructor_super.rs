@@ -1,17 +1,36 @@
+use std::collections::HashSet;
+
+use oxc_ast::{
+    ast::{Class, Expression, MethodDefinitionKind},
+    AstKind, AstType,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::{
+    petgraph::stable_graph::NodeIndex, pg::neighbors_filtered_by_edge_weight, AssignmentValue,
+    AstNodeId, BasicBlockElement, EdgeType, Register,
+};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(constructor-super): Expected to call 'super()'.")]
 #[diagnostic(severity(warning), help("Ensure 'super()' is called from constructor"))]
 struct ConstructorSuperDiagnostic(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(constructor-super): Lacked a call of 'super()' in some code paths.")]
+#[diagnostic(
+    severity(warning),
+    help("Ensure 'super()' is called in every code path of the constructor")
+)]
+struct ConstructorSuperSomePathsDiagnostic(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(constructor-super): Unexpected 'super()' because 'super' is not a constructor.")]
 #[diagnostic(severity(warning), help("Do not call 'super()' from constructor."))]
@@ -28,21 +47,200 @@ declare_oxc_lint!(
     /// Require 'super()' calls in constructors.
     ///
     /// ### Why is this bad?
-    ///
+    /// The constructor of a derived class must call `super()` before it returns,
+    /// otherwise a `ReferenceError` is thrown. The constructor of a class which
+    /// extends something other than a constructor, e.g. `null`, must not call `super()`
+    /// as that throws a `TypeError`.
     ///
     /// ### Example
     /// ```javascript
     /// class A extends B {
     ///   constructor() {}
     /// }
+    /// class C extends null {
+    ///   constructor() { super() }
+    /// }
     /// ```
     ConstructorSuper,
-    nursery // This rule should be implemented with CFG, the current implementation has a lot of
-            // false positives.
+    correctness
 );
 
+#[derive(Default, Copy, Clone, Debug)]
+enum DefinitelyCallsSuper {
+    #[default]
+    No,
+    Yes,
+    /// The path does not reach the end of the constructor, e.g. it loops back
+    Ignored,
+}
+
 impl Rule for ConstructorSuper {
-    fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let semantic = ctx.semantic();
+        let cfg = semantic.cfg();
+
+        // first pass -> find constructors and `super()` calls
+        let mut constructors = Vec::new();
+        let mut basic_blocks_with_super_called = HashSet::<NodeIndex>::new();
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::Function(func) if func.body.is_some() => {
+                    if let Some(class) = Self::constructor_class(node.id(), ctx) {
+                        constructors.push((node, class));
+                    }
+                }
+                AstKind::Super(_) => {
+                    let Some(AstKind::CallExpression(call)) = ctx.nodes().parent_kind(node.id())
+                    else {
+                        continue;
+                    };
+                    basic_blocks_with_super_called.insert(node.cfg_ix());
+                    // `super()` in an arrow function calls the `super` of the enclosing constructor
+                    let function = ctx
+                        .nodes()
+                        .ancestors(node.id())
+                        .find(|id| matches!(ctx.nodes().kind(*id), AstKind::Function(_)));
+                    let Some(class) = function.and_then(|id| Self::constructor_class(id, ctx))
+                    else {
+                        continue;
+                    };
+                    if let Some(super_class) = &class.super_class {
+                        if !is_possible_constructor(super_class) {
+                            ctx.diagnostic(SuperNotConstructorDiagnostic(
+                                call.span,
+                                super_class.span(),
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // second pass, walk the cfg of every derived constructor
+        for (node, class) in constructors {
+            if class.super_class.is_none() {
+                continue;
+            }
+            let output = neighbors_filtered_by_edge_weight(
+                &cfg.graph,
+                node.cfg_ix(),
+                &|edge| match edge {
+                    EdgeType::Normal => None,
+                    // Loops are entered once on the forward pass, and nested functions
+                    // are not evaluated as part of the constructor
+                    EdgeType::Backedge | EdgeType::NewFunction => {
+                        Some(DefinitelyCallsSuper::Ignored)
+                    }
+                },
+                &mut |basic_block_id, _| {
+                    if basic_blocks_with_super_called.contains(basic_block_id) {
+                        return (DefinitelyCallsSuper::Yes, false);
+                    }
+                    for entry in cfg.basic_block_by_index(*basic_block_id) {
+                        match entry {
+                            // Returning an object is a substitute of `super()`, `return;` is not
+                            BasicBlockElement::Assignment(Register::Return, value) => {
+                                let returns_value =
+                                    !matches!(value, AssignmentValue::ImplicitUndefined);
+                                let state = if returns_value {
+                                    DefinitelyCallsSuper::Yes
+                                } else {
+                                    DefinitelyCallsSuper::No
+                                };
+                                return (state, false);
+                            }
+                            BasicBlockElement::Throw(_) => {
+                                return (DefinitelyCallsSuper::Yes, false);
+                            }
+                            // The block after an `if` with an `else` is marked as unreachable
+                            // even when only one of the branches returns, so this is not
+                            // trusted. Paths which do return or throw already end above.
+                            BasicBlockElement::Unreachable | BasicBlockElement::Assignment(..) => {}
+                        }
+                    }
+                    (DefinitelyCallsSuper::No, true)
+                },
+            );
+
+            let calls_in_some_paths =
+                output.iter().any(|state| matches!(state, DefinitelyCallsSuper::Yes));
+            let calls_in_every_path = output.iter().all(|state| {
+                matches!(state, DefinitelyCallsSuper::Yes | DefinitelyCallsSuper::Ignored)
+            });
+            // the parent is the `MethodDefinition` for `constructor`, see `Self::constructor_class`
+            let span = ctx.nodes().parent_kind(node.id()).unwrap().span();
+            if !calls_in_some_paths {
+                ctx.diagnostic(ConstructorSuperDiagnostic(span));
+            } else if !calls_in_every_path {
+                ctx.diagnostic(ConstructorSuperSomePathsDiagnostic(span));
+            }
+        }
+    }
+}
+
+impl ConstructorSuper {
+    /// The class of the constructor when `node_id` is the `Function` of a `constructor`
+    fn constructor_class<'a>(node_id: AstNodeId, ctx: &LintContext<'a>) -> Option<&'a Class<'a>> {
+        let parent = ctx.nodes().parent_node(node_id)?;
+        let AstKind::MethodDefinition(method) = parent.kind() else { return None };
+        if method.kind != MethodDefinitionKind::Constructor {
+            return None;
+        }
+        ctx.nodes().ancestors(parent.id()).find_map(|id| match ctx.nodes().kind(id) {
+            AstKind::Class(class) => Some(class),
+            _ => None,
+        })
+    }
+}
+
+/// Whether `expr` may evaluate to a constructor, e.g. not `null` or `100`
+fn is_possible_constructor(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::ClassExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ThisExpression(_)
+        | Expression::MemberExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::ChainExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::MetaProperty(_)
+        | Expression::AwaitExpression(_)
+        | Expression::TSAsExpression(_)
+        | Expression::TSSatisfiesExpression(_)
+        | Expression::TSTypeAssertion(_)
+        | Expression::TSNonNullExpression(_)
+        | Expression::TSInstantiationExpression(_) => true,
+        Expression::Identifier(ident) => ident.name != "undefined",
+        Expression::AssignmentExpression(expr) => match expr.operator {
+            AssignmentOperator::Assign | AssignmentOperator::LogicalAnd => {
+                is_possible_constructor(&expr.right)
+            }
+            // The left-hand side is an identifier or a member expression
+            AssignmentOperator::LogicalOr | AssignmentOperator::LogicalNullish => true,
+            _ => false,
+        },
+        Expression::LogicalExpression(expr) => {
+            if expr.operator == LogicalOperator::And {
+                is_possible_constructor(&expr.right)
+            } else {
+                is_possible_constructor(&expr.left) || is_possible_constructor(&expr.right)
+            }
+        }
+        Expression::ConditionalExpression(expr) => {
+            is_possible_constructor(&expr.consequent) || is_possible_constructor(&expr.alternate)
+        }
+        Expression::SequenceExpression(expr) => {
+            expr.expressions.last().map_or(false, is_possible_constructor)
+        }
+        _ => false,
+    }
 }
 
 #[test]
@@ -50,12 +248,16 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        // non derived classes.
         ("class A { }", None),
         ("class A { constructor() { } }", None),
+        // inherit from non constructors.
+        // those are valid if we don't define the constructor.
         ("class A extends null { }", None),
-        ("class A extends B { constructor() { super(); } }", None),
+        // derived classes.
         ("class A extends B { }", None),
         ("class A extends B { constructor() { super(); } }", None),
+        ("class A extends B { constructor() { if (true) { super(); } else { super(); } } }", None),
         ("class A extends (class B {}) { constructor() { super(); } }", None),
         ("class A extends (B = C) { constructor() { super(); } }", None),
         ("class A extends (B &&= C) { constructor() { super(); } }", None),
@@ -65,14 +267,95 @@ fn test() {
         ("class A extends (B ??= 5) { constructor() { super(); } }", None),
         ("class A extends (B || C) { constructor() { super(); } }", None),
         ("class A extends (5 && B) { constructor() { super(); } }", None),
+        // A future improvement could detect the left side as statically falsy, making this invalid.
+        ("class A extends (false && B) { constructor() { super(); } }", None),
+        ("class A extends (B || 5) { constructor() { super(); } }", None),
+        ("class A extends (B ?? 5) { constructor() { super(); } }", None),
+        ("class A extends (a ? B : C) { constructor() { super(); } }", None),
+        ("class A extends (B, C) { constructor() { super(); } }", None),
+        // nested.
+        ("class A { constructor() { class B extends C { constructor() { super(); } } } }", None),
+        (
+            "class A extends B { constructor() { super(); class C extends D { constructor() { super(); } } } }",
+            None,
+        ),
+        (
+            "class A extends B { constructor() { super(); class C { constructor() { } } } }",
+            None,
+        ),
+        // multi code path.
+        ("class A extends B { constructor() { a ? super() : super(); } }", None),
+        ("class A extends B { constructor() { if (a) super(); else super(); } }", None),
+        (
+            "class A extends B { constructor() { switch (a) { case 0: super(); break; default: super(); } } }",
+            None,
+        ),
+        ("class A extends B { constructor() { try {} finally { super(); } } }", None),
+        ("class A extends B { constructor() { if (a) throw Error(); super(); } }", None),
+        // returning value is a substitute of 'super()'.
+        ("class A extends B { constructor() { if (true) return a; super(); } }", None),
+        ("class A extends null { constructor() { return a; } }", None),
+        ("class A { constructor() { return a; } }", None),
+        // https://github.com/eslint/eslint/issues/5261
+        ("class A extends B { constructor(a) { super(); for (const b of a) { this.a(); } } }", None),
+        // https://github.com/eslint/eslint/issues/5319
+        (
+            "class Foo extends Object { constructor(method) { super(); this.method = method || function() {}; } }",
+            None,
+        ),
+        // https://github.com/eslint/eslint/issues/5394
+        (
+            "class A extends Object { constructor() { super(); for (let i = 0; i < 0; i++); } }",
+            None,
+        ),
+        // https://github.com/eslint/eslint/issues/5894
+        ("class A { constructor() { return; super(); } }", None),
+        // https://github.com/eslint/eslint/issues/8848
+        (
+            "class A extends B { constructor(props) { super(props); try { let arr = []; for (let a of arr) { } } catch (err) { } } }",
+            None,
+        ),
+        // Optional chaining
+        ("class A extends obj?.prop { constructor() { super(); } }", None),
+        ("class A extends B { constructor(): void; }", None),
     ];
 
     let fail = vec![
-        // ("class A extends B { constructor() {} }", None),
-        // ("class A extends null { constructor() { super(); } }", None),
-        // ("class A extends null { constructor() { } }", None),
-        // ("class A extends 100 { constructor() { super(); } }", None),
-        // ("class A extends 'test' { constructor() { super(); } }", None),
+        // inherit from non constructors.
+        ("class A extends null { constructor() { super(); } }", None),
+        ("class A extends null { constructor() { } }", None),
+        ("class A extends 100 { constructor() { super(); } }", None),
+        ("class A extends 'test' { constructor() { super(); } }", None),
+        ("class A extends (B = 5) { constructor() { super(); } }", None),
+        ("class A extends (B && 5) { constructor() { super(); } }", None),
+        ("class A extends (B &&= 5) { constructor() { super(); } }", None),
+        ("class A extends (B += C) { constructor() { super(); } }", None),
+        ("class A extends (B -= C) { constructor() { super(); } }", None),
+        ("class A extends (B **= C) { constructor() { super(); } }", None),
+        ("class A extends (B |= C) { constructor() { super(); } }", None),
+        ("class A extends (B &= C) { constructor() { super(); } }", None),
+        ("class A extends undefined { constructor() { super(); } }", None),
+        ("class A extends null { constructor() { const f = () => super(); } }", None),
+        // derived classes.
+        ("class A extends B { constructor() { } }", None),
+        ("class A extends B { constructor() { for (var a of b) super.foo(); } }", None),
+        // nested execution scope.
+        ("class A extends B { constructor() { class C extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { var c = class extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { var c = () => super(); } }", None),
+        ("class A extends B { constructor() { class C extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { var C = class extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { super(); class C extends D { constructor() { } } } }", None),
+        ("class A extends B { constructor() { super(); var C = class extends D { constructor() { } } } }", None),
+        // lacked in some code path.
+        ("class A extends B { constructor() { if (a) super(); } }", None),
+        ("class A extends B { constructor() { if (a); else super(); } }", None),
+        ("class A extends B { constructor() { a && super(); } }", None),
+        ("class A extends B { constructor() { switch (a) { case 0: super(); } } }", None),
+        ("class A extends B { constructor() { try { super(); } catch (err) {} } }", None),
+        ("class A extends B { constructor() { try { a; } catch (err) { super(); } } }", None),
+        ("class A extends B { constructor() { if (a) return; super(); } }", None),
+        ("class A extends B { constructor() { while (a) super(); } }", None),
     ];
 
     Tester::new(ConstructorSuper::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,293 @@
+use oxc_ast::{
+    ast::{Argument, BindingPatternKind, CallExpression, FormalParameter, FormalParameters},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::get_function_context_usages, context::LintContext, fixer::Fix, rule::Rule, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(default-param-last): Default parameters should be last.")]
+#[diagnostic(
+    severity(warning),
+    help("Move the parameter after the parameters without default values.")
+)]
+struct DefaultParamLastDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct DefaultParamLast;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce default parameters to be last
+    ///
+    /// ### Why is this bad?
+    /// The default value of a parameter followed by parameters without defaults can only be
+    /// used by passing `undefined` explicitly, e.g. `createUser(undefined, "tabby")`.
+    ///
+    /// The suggested fix moves the default parameters after the others. When the function is
+    /// declared by a function declaration or a variable which are not exported, and all
+    /// references of the function are calls, the arguments of the calls are reordered too.
+    /// Arguments are evaluated in their new order, so the fix is only a suggestion.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// function f(a = 0, b) {}
+    ///
+    /// // good
+    /// function f(a, b = 0) {}
+    /// ```
+    DefaultParamLast,
+    style
+);
+
+impl Rule for DefaultParamLast {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function, AstType::ArrowFunctionExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) => &func.params,
+            AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+            _ => return,
+        };
+
+        let mut seen_plain_param = false;
+        let mut misplaced = vec![];
+        for param in params.items.iter().rev() {
+            if !has_default(param) {
+                seen_plain_param = true;
+            } else if seen_plain_param {
+                misplaced.push(param.span);
+            }
+        }
+        if misplaced.is_empty() {
+            return;
+        }
+
+        let fix = fix_params_order(node, params, ctx);
+        for span in misplaced.into_iter().rev() {
+            let diagnostic = DefaultParamLastDiagnostic(span);
+            match &fix {
+                Some(fix) => ctx.diagnostic_with_suggestion(diagnostic, || fix.clone()),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+fn has_default(param: &FormalParameter) -> bool {
+    matches!(param.pattern.kind, BindingPatternKind::AssignmentPattern(_))
+}
+
+/// Move the default parameters of the function `node` after the others, and reorder the arguments
+/// of its calls accordingly
+fn fix_params_order<'a>(
+    node: &AstNode<'a>,
+    params: &FormalParameters<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    let symbols = callee_symbols(node, ctx)?;
+    if matches!(node.kind(), AstKind::Function(_))
+        && get_function_context_usages(ctx)
+            .get(&node.id())
+            .is_some_and(|usage| usage.uses_arguments)
+    {
+        return None;
+    }
+
+    let source_text = ctx.source_text();
+    let items = &params.items;
+    let order = (0..items.len())
+        .filter(|&i| !has_default(&items[i]))
+        .chain((0..items.len()).filter(|&i| has_default(&items[i])))
+        .collect::<Vec<_>>();
+    let span = Span::new(items.first()?.span.start, items.last()?.span.end);
+    if ctx.semantic().trivias().has_comments_between(span) {
+        return None;
+    }
+    let reordered =
+        order.iter().map(|&i| items[i].span.source_text(source_text)).collect::<Vec<_>>();
+    let mut fixes = vec![Fix::new(reordered.join(", "), span)];
+
+    for symbol_id in symbols {
+        for reference in ctx.semantic().symbol_references(symbol_id) {
+            let call = reference_call(reference, ctx)?;
+            fixes.extend(reorder_arguments(call, &order, ctx)?);
+        }
+    }
+    Fix::merge(fixes, source_text)
+}
+
+/// The symbols through which the function `node` is called: the name of a function declaration,
+/// or the variable and the name of a function expression assigned to a variable
+fn callee_symbols(node: &AstNode, ctx: &LintContext) -> Option<Vec<SymbolId>> {
+    let parent = ctx.nodes().parent_node(node.id())?;
+    let is_exported = |id| {
+        matches!(
+            ctx.nodes().parent_kind(id),
+            Some(AstKind::ExportNamedDeclaration(_) | AstKind::ExportDefaultDeclaration(_))
+        )
+    };
+    match node.kind() {
+        AstKind::Function(func) if func.is_declaration() => {
+            if func.body.is_none() || is_exported(node.id()) {
+                return None;
+            }
+            Some(vec![func.id.as_ref()?.symbol_id.get()?])
+        }
+        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+            let AstKind::VariableDeclarator(declarator) = parent.kind() else { return None };
+            let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                return None;
+            };
+            if ctx
+                .nodes()
+                .parent_node(parent.id())
+                .map_or(true, |declaration| is_exported(declaration.id()))
+            {
+                return None;
+            }
+            let mut symbols = vec![ident.symbol_id.get()?];
+            if let AstKind::Function(func) = node.kind() {
+                if let Some(id) = &func.id {
+                    symbols.push(id.symbol_id.get()?);
+                }
+            }
+            Some(symbols)
+        }
+        _ => None,
+    }
+}
+
+/// The call whose callee is `reference`
+fn reference_call<'a>(
+    reference: &Reference,
+    ctx: &LintContext<'a>,
+) -> Option<&'a CallExpression<'a>> {
+    let AstKind::CallExpression(call) = ctx.nodes().parent_kind(reference.node_id())? else {
+        return None;
+    };
+    (call.callee.span() == reference.span()).then_some(call)
+}
+
+/// Reorder the arguments of `call` like the parameters, passing `undefined` for the parameters
+/// without arguments which are followed by arguments. Returns `None` when the arguments cannot
+/// be reordered, and no fix when their order does not change.
+fn reorder_arguments<'a>(
+    call: &CallExpression,
+    order: &[usize],
+    ctx: &LintContext<'a>,
+) -> Option<Option<Fix<'a>>> {
+    let (Some(first), Some(last)) = (call.arguments.first(), call.arguments.last()) else {
+        return Some(None);
+    };
+    let source_text = ctx.source_text();
+    let mut args = vec![];
+    for arg in &call.arguments {
+        let Argument::Expression(expr) = arg else { return None };
+        args.push(expr.span().source_text(source_text));
+    }
+    let span = Span::new(first.span().start, last.span().end);
+    if ctx.semantic().trivias().has_comments_between(span) {
+        return None;
+    }
+
+    let passed = order.iter().rposition(|&i| i < args.len()).map_or(0, |position| position + 1);
+    let reordered = order[..passed]
+        .iter()
+        .map(|&i| args.get(i).copied().unwrap_or("undefined"))
+        // Arguments beyond the parameters are left at the end
+        .chain(args.iter().skip(order.len()).copied())
+        .collect::<Vec<_>>();
+    if reordered == args {
+        return Some(None);
+    }
+    Some(Some(Fix::new(reordered.join(", "), span)))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function f() {}",
+        "function f(a) {}",
+        "function f(a = 5) {}",
+        "function f(a, b) {}",
+        "function f(a, b = 5) {}",
+        "function f(a, b = 5, c = 5) {}",
+        "function f(a, b = 5, ...c) {}",
+        "const f = () => {}",
+        "const f = (a) => {}",
+        "const f = (a = 5) => {}",
+        "const f = function f() {}",
+        "const f = function f(a) {}",
+        "const f = function f(a = 5) {}",
+        "class A { constructor(a, b = 1) {} }",
+    ];
+
+    let fail = vec![
+        "function f(a = 5, b) {}",
+        "function f(a = 5, b = 6, c) {}",
+        "function f(a = 5, b, c = 5) {}",
+        "function f(a = 5, b, ...c) {}",
+        "const f = (a = 5, b, ...c) => {}",
+        "const f = function f (a, b = 5, c) {}",
+        "const f = (a = 5, { b }) => {}",
+        "class A { constructor(a = 1, b) {} }",
+        "function f(a = 5, b) {} f(1, 2); f(undefined, 2); f();",
+        "export function f(a = 5, b) {} f(1, 2);",
+        "function f(a = 5, b) {} g(f);",
+        "function f(a = 5, b) { arguments; } f(1, 2);",
+    ];
+
+    let fix = vec![
+        ("function f(a = 5, b) {}", "function f(b, a = 5) {}", None),
+        ("function f(a = 5, b = 6, c) {}", "function f(c, a = 5, b = 6) {}", None),
+        ("function f(a = 5, b, c = 5) {}", "function f(b, a = 5, c = 5) {}", None),
+        ("function f(a = 5, b, ...c) {}", "function f(b, a = 5, ...c) {}", None),
+        ("const f = (a = 5, b, ...c) => {}", "const f = (b, a = 5, ...c) => {}", None),
+        ("const f = (a = 5, { b }) => {}", "const f = ({ b }, a = 5) => {}", None),
+        (
+            "function f(a = 5, b) {} f(1, 2); f(undefined, 2); f(1); f();",
+            "function f(b, a = 5) {} f(2, 1); f(2, undefined); f(undefined, 1); f();",
+            None,
+        ),
+        (
+            "const f = function g(a = 5, b) { g(1, 2); }; f(3, 4, 5);",
+            "const f = function g(b, a = 5) { g(2, 1); }; f(4, 3, 5);",
+            None,
+        ),
+        (
+            "function f(a: number = 5, b: string) {} f(1, 'b');",
+            "function f(b: string, a: number = 5) {} f('b', 1);",
+            None,
+        ),
+        ("function f(a = 5, b) {} f(...args);", "function f(a = 5, b) {} f(...args);", None),
+        ("function f(a = 5, b) {} g(f);", "function f(a = 5, b) {} g(f);", None),
+        ("function f(a = 5, b) {} f(f(1, 2), 3);", "function f(a = 5, b) {} f(f(1, 2), 3);", None),
+        ("export function f(a = 5, b) {}", "export function f(a = 5, b) {}", None),
+        ("export const f = (a = 5, b) => {}", "export const f = (a = 5, b) => {}", None),
+        (
+            "function f(a = 5, b) { arguments; } f(1, 2);",
+            "function f(a = 5, b) { arguments; } f(1, 2);",
+            None,
+        ),
+        ("foo((a = 5, b) => {})", "foo((a = 5, b) => {})", None),
+        ("class A { constructor(a = 1, b) {} }", "class A { constructor(a = 1, b) {} }", None),
+        ("function f(a = 5, /* b */ b) {}", "function f(a = 5, /* b */ b) {}", None),
+    ];
+
+    Tester::new(DefaultParamLast::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BinaryExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -58,6 +58,10 @@ impl Rule for Eqeqeq {
         }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(binary_expr) = node.kind() else { return };
         let is_null = is_null_check(binary_expr);
@@ -3,7 +3,7 @@ use oxc_ast::{
         AssignmentExpression, AssignmentTarget, Expression, IdentifierReference,
         SimpleAssignmentTarget,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -46,6 +46,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for ForDirection {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ForStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ForStatement(for_loop) = node.kind() {
             if let Some(Expression::BinaryExpression(test)) = &for_loop.test {
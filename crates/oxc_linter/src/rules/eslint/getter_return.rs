@@ -3,7 +3,7 @@ use oxc_ast::{
         ChainElement, Expression, MemberExpression, MethodDefinitionKind, ObjectProperty,
         PropertyKind,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -54,6 +54,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for GetterReturn {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function, AstType::ArrowFunctionExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::Function(func) if !func.is_typescript_syntax() => {
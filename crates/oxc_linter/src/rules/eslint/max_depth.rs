@@ -0,0 +1,146 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-depth): Blocks are nested too deeply ({0}). Maximum allowed is {1}.")]
+#[diagnostic(severity(warning))]
+struct MaxDepthDiagnostic(usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxDepth {
+    max: usize,
+}
+
+impl Default for MaxDepth {
+    fn default() -> Self {
+        Self { max: 4 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum depth that blocks can be nested, which is `4` by default.
+    ///
+    /// `if`, `switch`, `try`, `with` and loop statements are counted, except for `else if`.
+    /// The depth starts over in each function.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deeply nested code is hard to read, early returns or extracted functions flatten it.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {
+    ///     for (;;) { // Nested 1 deep
+    ///         while (true) { // Nested 2 deep
+    ///             if (true) { // Nested 3 deep
+    ///                 if (true) { // Nested 4 deep
+    ///                     if (true) { // Nested 5 deep
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    MaxDepth,
+    pedantic
+);
+
+impl Rule for MaxDepth {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let max = config.and_then(|config| {
+            config
+                .as_u64()
+                .or_else(|| config.get("max")?.as_u64())
+                .or_else(|| config.get("maximum")?.as_u64())
+        });
+        max.and_then(|max| usize::try_from(max).ok()).map_or_else(Self::default, |max| Self { max })
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::IfStatement,
+            AstType::SwitchStatement,
+            AstType::TryStatement,
+            AstType::DoWhileStatement,
+            AstType::WhileStatement,
+            AstType::WithStatement,
+            AstType::ForStatement,
+            AstType::ForInStatement,
+            AstType::ForOfStatement,
+        ])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_nested_block(node, ctx) {
+            return;
+        }
+        let depth = ctx
+            .nodes()
+            .ancestors(node.id())
+            .map(|id| ctx.nodes().get_node(id))
+            .take_while(|node| {
+                !matches!(
+                    node.kind(),
+                    AstKind::Function(_)
+                        | AstKind::ArrowFunctionExpression(_)
+                        | AstKind::StaticBlock(_)
+                )
+            })
+            .filter(|node| is_nested_block(node, ctx))
+            .count();
+        if depth > self.max {
+            ctx.diagnostic(MaxDepthDiagnostic(depth, self.max, node.kind().span()));
+        }
+    }
+}
+
+fn is_nested_block(node: &AstNode, ctx: &LintContext) -> bool {
+    match node.kind() {
+        // `else if`
+        AstKind::IfStatement(_) => {
+            !matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::IfStatement(_)))
+        }
+        AstKind::SwitchStatement(_)
+        | AstKind::TryStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::WithStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo() { if (a) { if (b) {} } }", Some(json!([2]))),
+        ("function foo() { if (a) {} else if (b) {} else if (c) { if (d) {} } }", Some(json!([2]))),
+        ("function foo() { if (a) { return () => { if (b) {} }; } }", Some(json!([1]))),
+        ("if (a) { function foo() { if (b) {} } }", Some(json!([{ "max": 1 }]))),
+        ("function foo() { if (a) { if (b) { if (c) { if (d) {} } } } }", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { for (;;) { while (true) { if (x) {} } } }", Some(json!([2]))),
+        ("if (a) { switch (b) { case 1: try {} catch {} } }", Some(json!([{ "max": 2 }]))),
+        ("function foo() { if (a) { if (b) { if (c) { if (d) { if (e) {} } } } } }", None),
+    ];
+
+    Tester::new(MaxDepth::NAME, pass, fail).test_and_snapshot();
+}
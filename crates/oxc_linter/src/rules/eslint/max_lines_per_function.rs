@@ -0,0 +1,191 @@
+use oxc_ast::{ast::PropertyKind, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::{
+        get_comment_span, get_function_head_span, get_function_name_with_kind, outermost_paren,
+    },
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-lines-per-function): {0} has too many lines ({1}). Maximum allowed is {2}.")]
+#[diagnostic(severity(warning))]
+struct MaxLinesPerFunctionDiagnostic(String, usize, usize, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct MaxLinesPerFunction(Box<MaxLinesPerFunctionConfig>);
+
+#[derive(Debug, Clone)]
+pub struct MaxLinesPerFunctionConfig {
+    max: usize,
+    skip_blank_lines: bool,
+    skip_comments: bool,
+    iifes: bool,
+}
+
+impl Default for MaxLinesPerFunctionConfig {
+    fn default() -> Self {
+        Self { max: 50, skip_blank_lines: false, skip_comments: false, iifes: false }
+    }
+}
+
+impl std::ops::Deref for MaxLinesPerFunction {
+    type Target = MaxLinesPerFunctionConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum number of lines of code in a function, which is `50` by default.
+    ///
+    /// Options:
+    /// * `max`: the maximum number of lines
+    /// * `skipBlankLines`: do not count lines made of whitespace
+    /// * `skipComments`: do not count lines containing only comments
+    /// * `IIFEs`: also check immediately invoked function expressions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Large functions tend to do a lot of things and are hard to follow,
+    /// splitting them makes the code easier to understand and to reuse.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // { "max": 2 }
+    /// function foo() {
+    ///     var x = 0;
+    /// }
+    /// ```
+    MaxLinesPerFunction,
+    pedantic
+);
+
+impl Rule for MaxLinesPerFunction {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let default = MaxLinesPerFunctionConfig::default();
+        let max = config.as_u64().or_else(|| config.get("max")?.as_u64());
+        let flag = |name: &str| config.get(name).and_then(serde_json::Value::as_bool);
+        Self(Box::new(MaxLinesPerFunctionConfig {
+            max: max.and_then(|max| usize::try_from(max).ok()).unwrap_or(default.max),
+            skip_blank_lines: flag("skipBlankLines").unwrap_or(default.skip_blank_lines),
+            skip_comments: flag("skipComments").unwrap_or(default.skip_comments),
+            iifes: flag("IIFEs").unwrap_or(default.iifes),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function, AstType::ArrowFunctionExpression])
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !self.iifes && is_iife(node, ctx) {
+            return;
+        }
+
+        // Methods and accessors include their keys.
+        let span = match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::MethodDefinition(def)) => def.span,
+            Some(AstKind::ObjectProperty(prop))
+                if prop.method || prop.kind != PropertyKind::Init =>
+            {
+                prop.span
+            }
+            _ => node.kind().span(),
+        };
+
+        let comments = ctx
+            .semantic()
+            .trivias()
+            .comments_spans()
+            .map(|(comment, content)| get_comment_span(content, comment))
+            .filter(|comment| comment.start < span.end && span.start < comment.end)
+            .collect::<Vec<_>>();
+
+        let mut count = 0;
+        let mut start = span.start;
+        for line in span.source_text(ctx.source_text()).split('\n') {
+            let line_span = Span::new(start, start + line.len() as u32);
+            start = line_span.end + 1;
+
+            let content = line.trim();
+            if content.is_empty() {
+                if !self.skip_blank_lines {
+                    count += 1;
+                }
+                continue;
+            }
+            let content_start = line_span.start + (line.len() - line.trim_start().len()) as u32;
+            let content_end = content_start + content.len() as u32;
+            let is_comment = comments
+                .iter()
+                .any(|comment| comment.start <= content_start && content_end <= comment.end);
+            if !(self.skip_comments && is_comment) {
+                count += 1;
+            }
+        }
+
+        if count > self.max {
+            ctx.diagnostic(MaxLinesPerFunctionDiagnostic(
+                get_function_name_with_kind(node, ctx),
+                count,
+                self.max,
+                get_function_head_span(node, ctx),
+            ));
+        }
+    }
+}
+
+/// Whether the function is called right where it is defined, e.g. `(function () {})()`.
+fn is_iife<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let node = outermost_paren(node, ctx);
+    matches!(
+        ctx.nodes().parent_kind(node.id()),
+        Some(AstKind::CallExpression(call)) if call.callee.span() == node.kind().span()
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function a() {}", Some(json!([1]))),
+        ("function b() {\n  return 1;\n}", Some(json!([3]))),
+        ("function c() {\n\n  return 1;\n\n}", Some(json!([{ "max": 3, "skipBlankLines": true }]))),
+        (
+            "function d() {\n  // comment\n  /* comment */\n  return 1;\n}",
+            Some(json!([{ "max": 3, "skipComments": true }])),
+        ),
+        ("(function () {\n  return 1;\n})();", Some(json!([1]))),
+        ("var e = () =>\n  1;", None),
+    ];
+
+    let fail = vec![
+        ("function a() {\n  return 1;\n}", Some(json!([2]))),
+        ("var b = () => {\n  const x = 1;\n\n  return x;\n};", Some(json!([{ "max": 4 }]))),
+        ("(function () {\n  return 1;\n})();", Some(json!([{ "max": 2, "IIFEs": true }]))),
+        (
+            "class A {\n  m() {\n    // comment\n    return 1;\n  }\n}",
+            Some(json!([{ "max": 2, "skipComments": true }])),
+        ),
+    ];
+
+    Tester::new(MaxLinesPerFunction::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,107 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::{get_function_head_span, get_function_name_with_kind},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-params): {0} has too many parameters ({1}). Maximum allowed is {2}.")]
+#[diagnostic(severity(warning))]
+struct MaxParamsDiagnostic(String, usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxParams {
+    max: usize,
+}
+
+impl Default for MaxParams {
+    fn default() -> Self {
+        Self { max: 3 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum number of parameters in function definitions, which is `3` by default.
+    /// A rest parameter counts as one, the TypeScript `this` parameter is not counted.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Functions with many parameters are hard to call correctly and often do too much,
+    /// consider passing an object instead.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo(bar, baz, qux, qxx) {
+    ///     doSomething();
+    /// }
+    /// ```
+    MaxParams,
+    pedantic
+);
+
+impl Rule for MaxParams {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let max = config.and_then(|config| {
+            config
+                .as_u64()
+                .or_else(|| config.get("max")?.as_u64())
+                .or_else(|| config.get("maximum")?.as_u64())
+        });
+        max.and_then(|max| usize::try_from(max).ok()).map_or_else(Self::default, |max| Self { max })
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function, AstType::ArrowFunctionExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) => &func.params,
+            AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+            _ => return,
+        };
+        let count = params.items.len() + usize::from(params.rest.is_some());
+        if count > self.max {
+            ctx.diagnostic(MaxParamsDiagnostic(
+                get_function_name_with_kind(node, ctx),
+                count,
+                self.max,
+                get_function_head_span(node, ctx),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function a(x, y, z) {}", None),
+        ("var b = (w, x, y, z) => {};", Some(json!([4]))),
+        ("function c(...args) {}", Some(json!([{ "max": 1 }]))),
+        ("function d(this: Foo, x, y, z) {}", None),
+        ("class E { constructor(x, y) {} }", Some(json!([{ "maximum": 2 }]))),
+    ];
+
+    let fail = vec![
+        ("function a(w, x, y, z) {}", None),
+        ("var b = (x, y, ...z) => {};", Some(json!([2]))),
+        ("({ set c(x) {}, d(x, y = 1) {} })", Some(json!([{ "max": 1 }]))),
+    ];
+
+    Tester::new(MaxParams::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -50,6 +50,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAsyncPromiseExecutor {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NewExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(new_expression) = node.kind() else { return };
         if !new_expression.callee.is_specific_id("Promise") {
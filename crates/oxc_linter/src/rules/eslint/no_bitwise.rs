@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -75,6 +75,10 @@ impl Rule for NoBitwise {
         }))
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression, AstType::UnaryExpression, AstType::AssignmentExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::BinaryExpression(bin_expr) => {
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_ast::{ast::MemberExpression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -50,6 +50,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCaller {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::MemberExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(expr)) =
             node.kind()
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Declaration, Statement, VariableDeclarationKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -49,6 +49,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCaseDeclarations {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::SwitchCase])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::SwitchCase(switch_case) = node.kind() {
             let consequent = &switch_case.consequent;
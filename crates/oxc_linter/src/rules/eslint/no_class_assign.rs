@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -39,6 +40,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoClassAssign {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.semantic().symbols();
         if symbol_table.get_flag(symbol_id).is_class() {
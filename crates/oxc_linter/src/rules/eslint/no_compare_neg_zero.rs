@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -38,6 +38,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCompareNegZero {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(expr) = node.kind() else {
             return;
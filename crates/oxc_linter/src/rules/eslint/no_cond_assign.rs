@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{AssignmentExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -54,6 +54,17 @@ impl Rule for NoCondAssign {
         Self { config }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::IfStatement,
+            AstType::WhileStatement,
+            AstType::DoWhileStatement,
+            AstType::ForStatement,
+            AstType::ConditionalExpression,
+            AstType::AssignmentExpression,
+        ])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::IfStatement(stmt) => self.check_expression(ctx, &stmt.test),
@@ -0,0 +1,145 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "eslint(no-confusing-arrow): Arrow function used ambiguously with a conditional expression."
+)]
+#[diagnostic(severity(warning))]
+struct NoConfusingArrowDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoConfusingArrow {
+    allow_parens: bool,
+    only_one_simple_param: bool,
+}
+
+impl Default for NoConfusingArrow {
+    fn default() -> Self {
+        Self { allow_parens: true, only_one_simple_param: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow arrow functions where they could be confused with comparisons
+    ///
+    /// ### Why is this bad?
+    /// `a => 1 ? 2 : 3` reads much like the comparison `a >= 1 ? 2 : 3`.
+    ///
+    /// ### Options
+    /// * `allowParens`: allow parenthesized conditional expressions as bodies, defaults to `true`.
+    /// * `onlyOneSimpleParam`: only report arrow functions with one parameter which is not
+    ///   destructured, defaults to `false`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// var x = a => 1 ? 2 : 3;
+    /// var x = (a) => 1 ? 2 : 3;
+    ///
+    /// // good
+    /// var x = a => (1 ? 2 : 3);
+    /// var x = (a) => { return 1 ? 2 : 3; };
+    /// ```
+    NoConfusingArrow,
+    style
+);
+
+impl Rule for NoConfusingArrow {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let option = |name: &str, default: bool| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default)
+        };
+        Self {
+            allow_parens: option("allowParens", true),
+            only_one_simple_param: option("onlyOneSimpleParam", false),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ArrowFunctionExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ArrowFunctionExpression(arrow) = node.kind() else { return };
+        let Some(body) = arrow.get_expression() else { return };
+        let is_parenthesized = matches!(body, Expression::ParenthesizedExpression(_));
+        if !matches!(body.without_parenthesized(), Expression::ConditionalExpression(_))
+            || (self.allow_parens && is_parenthesized)
+        {
+            return;
+        }
+        if self.only_one_simple_param
+            && !matches!(
+                arrow.params.items.as_slice(),
+                [param] if matches!(param.pattern.kind, BindingPatternKind::BindingIdentifier(_))
+            )
+        {
+            return;
+        }
+
+        let diagnostic = NoConfusingArrowDiagnostic(arrow.span);
+        if !self.allow_parens {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, || {
+            Fix::new(format!("({})", body.span().source_text(ctx.source_text())), body.span())
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a => { return 1 ? 2 : 3; }", None),
+        ("var x = a => { return 1 ? 2 : 3; }", None),
+        ("var x = (a) => { return 1 ? 2 : 3; }", None),
+        ("var x = a => (1 ? 2 : 3)", None),
+        ("var x = a => (1 ? 2 : 3)", Some(serde_json::json!([{ "allowParens": true }]))),
+        ("var x = a => 1 + 2", None),
+        ("var x = () => 1 ? 2 : 3", Some(serde_json::json!([{ "onlyOneSimpleParam": true }]))),
+        ("var x = (a, b) => 1 ? 2 : 3", Some(serde_json::json!([{ "onlyOneSimpleParam": true }]))),
+        ("var x = ({ a }) => 1 ? 2 : 3", Some(serde_json::json!([{ "onlyOneSimpleParam": true }]))),
+        ("var x = (a = 1) => 1 ? 2 : 3", Some(serde_json::json!([{ "onlyOneSimpleParam": true }]))),
+    ];
+
+    let fail = vec![
+        ("a => 1 ? 2 : 3", None),
+        ("var x = a => 1 ? 2 : 3", None),
+        ("var x = (a) => 1 ? 2 : 3", None),
+        ("var x = a => (1 ? 2 : 3)", Some(serde_json::json!([{ "allowParens": false }]))),
+        ("var x = a => 1 ? 2 : 3", Some(serde_json::json!([{ "onlyOneSimpleParam": true }]))),
+        ("var x = async a => 1 ? 2 : 3", None),
+    ];
+
+    let fix = vec![
+        ("a => 1 ? 2 : 3", "a => (1 ? 2 : 3)", None),
+        ("var x = (a) => 1 ? 2 : 3", "var x = (a) => (1 ? 2 : 3)", None),
+        ("var x = async a => 1 ? 2 : 3", "var x = async a => (1 ? 2 : 3)", None),
+        (
+            "var x = a => 1 ? 2 : 3",
+            "var x = a => 1 ? 2 : 3",
+            Some(serde_json::json!([{ "allowParens": false }])),
+        ),
+    ];
+
+    Tester::new(NoConfusingArrow::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -71,6 +71,10 @@ impl Rule for NoConsole {
         }))
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::CallExpression(call_expr) = node.kind() {
             if let Expression::MemberExpression(mem) = &call_expr.callee {
@@ -1,5 +1,5 @@
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, AstKind};
+use oxc_ast::{ast::*, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -79,6 +79,10 @@ struct ConstantAlwaysNew(#[label] Span);
 struct ConstantBothAlwaysNew(#[label] Span);
 
 impl Rule for NoConstantBinaryExpression {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::LogicalExpression, AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::LogicalExpression(expr) => match expr.operator {
@@ -143,6 +147,20 @@ impl Rule for NoConstantBinaryExpression {
 }
 
 impl NoConstantBinaryExpression {
+    /// `undefined` only counts when it is not shadowed, e.g. by `function foo(undefined) {}`
+    fn evaluate_to_undefined<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                ident.name == "undefined" && ctx.semantic().is_reference_to_global_variable(ident)
+            }
+            _ => expr.is_void(),
+        }
+    }
+
+    fn is_null_or_undefined<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        expr.is_null() || Self::evaluate_to_undefined(expr, ctx)
+    }
+
     ///  Test if an AST node has a statically knowable constant nullishness. Meaning,
     /// it will always resolve to a constant value of either: `null`, `undefined`
     /// or not `null` _or_ `undefined`. An expression that can vary between those
@@ -152,7 +170,7 @@ impl NoConstantBinaryExpression {
         non_nullish: bool,
         ctx: &LintContext<'a>,
     ) -> bool {
-        if non_nullish && (expr.is_null() || expr.evaluate_to_undefined()) {
+        if non_nullish && Self::is_null_or_undefined(expr, ctx) {
             return false;
         }
         match expr.get_inner_expression() {
@@ -191,7 +209,7 @@ impl NoConstantBinaryExpression {
                 .iter()
                 .last()
                 .map_or(false, |last| Self::has_constant_nullishness(last, non_nullish, ctx)),
-            Expression::Identifier(_) => expr.evaluate_to_undefined(),
+            Expression::Identifier(_) => Self::evaluate_to_undefined(expr, ctx),
             _ => false,
         }
     }
@@ -205,7 +223,8 @@ impl NoConstantBinaryExpression {
     ) -> Option<&'a Expression<'a>> {
         match operator {
             BinaryOperator::Equality | BinaryOperator::Inequality => {
-                if (a.is_null_or_undefined() && Self::has_constant_nullishness(b, false, ctx))
+                if (Self::is_null_or_undefined(a, ctx)
+                    && Self::has_constant_nullishness(b, false, ctx))
                     || (ast_util::is_static_boolean(a, ctx)
                         && Self::has_constant_loose_boolean_comparison(b, ctx))
                 {
@@ -213,7 +232,8 @@ impl NoConstantBinaryExpression {
                 }
             }
             BinaryOperator::StrictEquality | BinaryOperator::StrictInequality => {
-                if (a.is_null_or_undefined() && Self::has_constant_nullishness(b, false, ctx))
+                if (Self::is_null_or_undefined(a, ctx)
+                    && Self::has_constant_nullishness(b, false, ctx))
                     || (ast_util::is_static_boolean(a, ctx)
                         && Self::has_constant_strict_boolean_comparison(b, ctx))
                 {
@@ -267,7 +287,7 @@ impl NoConstantBinaryExpression {
                 Self::has_constant_loose_boolean_comparison(&paren_expr.expression, ctx)
             }
             expr if expr.is_literal() => true,
-            expr if expr.evaluate_to_undefined() => true,
+            expr if Self::evaluate_to_undefined(expr, ctx) => true,
             _ => false,
         }
     }
@@ -298,9 +318,8 @@ impl NoConstantBinaryExpression {
             },
             Expression::CallExpression(call_expr) => {
                 if let Expression::Identifier(ident) = &call_expr.callee {
-                    if ident.name == "String"
-                        || ident.name == "Number"
-                            && ctx.semantic().is_reference_to_global_variable(ident)
+                    if (ident.name == "String" || ident.name == "Number")
+                        && ctx.semantic().is_reference_to_global_variable(ident)
                     {
                         return true;
                     }
@@ -332,7 +351,7 @@ impl NoConstantBinaryExpression {
             Expression::ParenthesizedExpression(paren_expr) => {
                 Self::has_constant_strict_boolean_comparison(&paren_expr.expression, ctx)
             }
-            Expression::Identifier(_) => expr.evaluate_to_undefined(),
+            Expression::Identifier(_) => Self::evaluate_to_undefined(expr, ctx),
             _ => false,
         }
     }
@@ -402,17 +421,18 @@ fn test() {
         ("[n] == true", None),
         ("delete bar.baz === true", None),
         ("foo.Boolean(true) && foo", None),
-        // ("function Boolean(n) { return n; }; Boolean(x) ?? foo", None),
-        // ("function String(n) { return n; }; String(x) ?? foo", None),
-        // ("function Number(n) { return n; }; Number(x) ?? foo", None),
-        // ("function Boolean(n) { return Math.random(); }; Boolean(x) === 1", None),
-        // ("function Boolean(n) { return Math.random(); }; Boolean(1) == true", None),
+        ("function Boolean(n) { return n; }; Boolean(x) ?? foo", None),
+        ("function String(n) { return n; }; String(x) ?? foo", None),
+        ("function Number(n) { return n; }; Number(x) ?? foo", None),
+        ("function Boolean(n) { return Math.random(); }; Boolean(x) === 1", None),
+        ("function Boolean(n) { return Math.random(); }; Boolean(1) == true", None),
+        ("function String(n) { return Math.random(); }; String(x) === true", None),
         ("new Foo() === x", None),
         ("x === new someObj.Promise()", None),
         ("Boolean(foo) === true", None),
-        // ("function foo(undefined) { undefined ?? bar;}", None),
-        // ("function foo(undefined) { undefined == true;}", None),
-        // ("function foo(undefined) { undefined === true;}", None),
+        ("function foo(undefined) { undefined ?? bar;}", None),
+        ("function foo(undefined) { undefined == true;}", None),
+        ("function foo(undefined) { undefined === true;}", None),
         ("[...arr, 1] == true", None),
         ("[,,,] == true", None),
         // { code: "new Foo() === bar;", globals: { Foo: "writable" } },
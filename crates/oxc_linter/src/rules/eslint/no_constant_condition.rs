@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -50,6 +50,10 @@ impl Rule for NoConstantCondition {
         }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::IfStatement, AstType::ConditionalExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::IfStatement(if_stmt) => {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -35,9 +35,15 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDebugger {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::DebuggerStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::DebuggerStatement(stmt) = node.kind() {
-            ctx.diagnostic_with_fix(NoDebuggerDiagnostic(stmt.span), || Fix::delete(stmt.span));
+            ctx.diagnostic_with_suggestion(NoDebuggerDiagnostic(stmt.span), || {
+                Fix::delete(stmt.span)
+            });
         }
     }
 }
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -36,6 +36,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDeleteVar {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::UnaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::UnaryExpression(expr) = node.kind() else { return };
         if expr.operator == UnaryOperator::Delete && expr.argument.is_identifier_reference() {
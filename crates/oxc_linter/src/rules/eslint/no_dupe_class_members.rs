@@ -1,12 +1,13 @@
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{CompactString, Span};
+use oxc_span::{CompactString, GetSpan, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-dupe-class-members): Duplicate class member: {0:?}")]
@@ -42,13 +43,21 @@ declare_oxc_lint!(
     /// let a = new A();
     /// a.foo() // Uncaught TypeError: a.foo is not a function
     /// ```
+    ///
+    /// The suggested fix removes the previous declaration.
+    /// Its computed key is not evaluated anymore then, so the fix is only a suggestion.
     NoDupeClassMembers,
     correctness
 );
 
 impl Rule for NoDupeClassMembers {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
-        ctx.semantic().classes().iter_enumerated().for_each(|(class_id, _)| {
+        ctx.semantic().classes().iter_enumerated().for_each(|(class_id, node_id)| {
+            let AstKind::Class(class) = ctx.nodes().kind(*node_id) else { return };
             let mut defined_elements = FxHashMap::default();
             let elements = &ctx.semantic().classes().elements[class_id];
             for (element_id, element) in elements.iter_enumerated() {
@@ -60,11 +69,26 @@ impl Rule for NoDupeClassMembers {
                             && prev_element.kind.is_setter_or_getter())
                             || element.kind == prev_element.kind)
                     {
-                        ctx.diagnostic(NoDupeClassMembersDiagnostic(
+                        let diagnostic = NoDupeClassMembersDiagnostic(
                             element.name.clone(),
                             prev_element.span,
                             element.span,
-                        ));
+                        );
+                        // The span of the element is the span of its key
+                        let member = class.body.body.iter().find(|member| {
+                            member.property_key().is_some_and(|key| key.span() == prev_element.span)
+                        });
+                        match member {
+                            Some(member) => ctx.diagnostic_with_suggestion(diagnostic, || {
+                                let source_text = ctx.source_text();
+                                let span = member.span();
+                                let rest = &source_text[span.end as usize..];
+                                let whitespace = rest.len() - rest.trim_start().len();
+                                #[allow(clippy::cast_possible_truncation)]
+                                Fix::delete(Span::new(span.start, span.end + whitespace as u32))
+                            }),
+                            None => ctx.diagnostic(diagnostic),
+                        }
                     }
                 }
             }
@@ -168,5 +192,14 @@ fn test() {
         "class A { foo;  foo() {}}",
     ];
 
-    Tester::new(NoDupeClassMembers::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("class A { foo() {} foo() {} }", "class A { foo() {} }", None),
+        ("class A { foo() { a(); }\n  foo() { b(); } }", "class A { foo() { b(); } }", None),
+        ("class A { foo() {} foo() {} foo() {} }", "class A { foo() {} foo() {} }", None),
+        ("class A { foo; foo = 42; }", "class A { foo = 42; }", None),
+        ("class A { static ['foo']() {} static foo() {} }", "class A { static foo() {} }", None),
+        ("class A { foo() {} get foo() {} }", "class A { get foo() {} }", None),
+    ];
+
+    Tester::new(NoDupeClassMembers::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
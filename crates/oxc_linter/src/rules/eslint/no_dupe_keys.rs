@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ObjectPropertyKind, PropertyKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -41,6 +41,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDupeKeys {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ObjectExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ObjectExpression(obj_expr) = node.kind() else { return };
         let mut map = FxHashMap::default();
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -46,6 +46,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDuplicateCase {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::SwitchStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::SwitchStatement(ss) = node.kind() {
             let mut map = FxHashMap::default();
@@ -0,0 +1,273 @@
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, ImportOrExportKind},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-duplicate-imports): '{0}' {1}.")]
+#[diagnostic(severity(warning), help("Merge the statements of '{0}' into one"))]
+struct NoDuplicateImportsDiagnostic(CompactString, &'static str, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateImports {
+    include_exports: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow duplicate module imports
+    ///
+    /// ### Why is this bad?
+    /// Using a single import statement per module will make the code clearer
+    /// because you can see everything being imported from that module on one line.
+    ///
+    /// With the `includeExports` option, re-exports from a module that is also imported
+    /// or re-exported are reported as well.
+    ///
+    /// Duplicated named imports, and duplicated named re-exports, are merged into the first statement
+    /// by the fixer.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import { merge } from 'module';
+    /// import something from 'another-module';
+    /// import { find } from 'module';
+    /// ```
+    NoDuplicateImports,
+    style
+);
+
+impl Rule for NoDuplicateImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let include_exports = value
+            .get(0)
+            .and_then(|config| config.get("includeExports"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        Self { include_exports }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let module_record = ctx.module_record();
+
+        // The sources of `export * from` and `export { x } from`
+        let export_requests = module_record
+            .indirect_export_entries
+            .iter()
+            .chain(&module_record.star_export_entries)
+            .filter_map(|entry| entry.module_request.as_ref())
+            .map(|request| request.span())
+            .collect::<FxHashSet<_>>();
+
+        let declarations = ctx
+            .nodes()
+            .iter()
+            .filter_map(|node| MergeableDeclaration::from_kind(node.kind()))
+            .map(|declaration| (declaration.source, declaration))
+            .collect::<FxHashMap<_, _>>();
+
+        for (request, spans) in &module_record.requested_modules {
+            // The first import and the first export of the module
+            let (mut imported, mut exported) = (None, None);
+            for span in spans {
+                let (message, first) = if export_requests.contains(span) {
+                    if !self.include_exports {
+                        continue;
+                    }
+                    let message = if exported.is_some() {
+                        Some("export is duplicated")
+                    } else if imported.is_some() {
+                        Some("export is duplicated as import")
+                    } else {
+                        None
+                    };
+                    (message, exported.get_or_insert(*span))
+                } else {
+                    let message = if imported.is_some() {
+                        Some("import is duplicated")
+                    } else if exported.is_some() {
+                        Some("import is duplicated as export")
+                    } else {
+                        None
+                    };
+                    (message, imported.get_or_insert(*span))
+                };
+                let Some(message) = message else {
+                    continue;
+                };
+                let diagnostic = NoDuplicateImportsDiagnostic(request.clone(), message, *span);
+                match (declarations.get(first), declarations.get(span)) {
+                    (Some(first), Some(duplicate)) if first.can_merge(duplicate) => {
+                        ctx.diagnostic_with_fix(diagnostic, || first.merge(duplicate, ctx));
+                    }
+                    _ => ctx.diagnostic(diagnostic),
+                }
+            }
+        }
+    }
+}
+
+/// An import or a re-export with named specifiers, e.g. `import a, { b } from 'a'`
+/// or `export { b } from 'a'`.
+struct MergeableDeclaration {
+    span: Span,
+    /// The span of the module request
+    source: Span,
+    is_export: bool,
+    kind: ImportOrExportKind,
+    has_default: bool,
+    /// From the start of the first named specifier to the end of the last one
+    named_specifiers: Option<Span>,
+}
+
+impl MergeableDeclaration {
+    fn from_kind(kind: AstKind) -> Option<Self> {
+        match kind {
+            AstKind::ImportDeclaration(decl) if decl.with_clause.is_none() => {
+                let specifiers = decl.specifiers.as_ref()?;
+                let mut named = specifiers.iter().filter_map(|specifier| match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => Some(specifier.span),
+                    _ => None,
+                });
+                let (first, last) = (named.next(), named.last());
+                let is_namespace = specifiers.iter().any(|specifier| {
+                    matches!(specifier, ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
+                });
+                (!is_namespace).then(|| Self {
+                    span: decl.span,
+                    source: decl.source.span,
+                    is_export: false,
+                    kind: decl.import_kind,
+                    has_default: specifiers.iter().any(|specifier| {
+                        matches!(specifier, ImportDeclarationSpecifier::ImportDefaultSpecifier(_))
+                    }),
+                    named_specifiers: first
+                        .map(|first| Span::new(first.start, last.unwrap_or(first).end)),
+                })
+            }
+            AstKind::ExportNamedDeclaration(decl) => {
+                let source = decl.source.as_ref()?;
+                let (first, last) = (decl.specifiers.first(), decl.specifiers.last());
+                Some(Self {
+                    span: decl.span,
+                    source: source.span,
+                    is_export: true,
+                    kind: decl.export_kind,
+                    has_default: false,
+                    named_specifiers: first
+                        .zip(last)
+                        .map(|(first, last)| Span::new(first.span.start, last.span.end)),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn can_merge(&self, duplicate: &Self) -> bool {
+        self.is_export == duplicate.is_export
+            && self.kind == duplicate.kind
+            && self.named_specifiers.is_some()
+            && duplicate.named_specifiers.is_some()
+            && !duplicate.has_default
+    }
+
+    /// Append the named specifiers of `duplicate` to this declaration, and remove `duplicate`.
+    fn merge<'a>(&self, duplicate: &Self, ctx: &LintContext<'a>) -> Fix<'a> {
+        let source_text = ctx.source_text();
+        let (Some(named), Some(duplicate_named)) =
+            (self.named_specifiers, duplicate.named_specifiers)
+        else {
+            return Fix::default();
+        };
+        let mut content = String::new();
+        content.push_str(&source_text[self.span.start as usize..named.end as usize]);
+        content.push_str(", ");
+        content.push_str(duplicate_named.source_text(source_text));
+        content.push_str(&source_text[named.end as usize..self.span.end as usize]);
+        // The statements between the two declarations
+        content.push_str(
+            source_text[self.span.end as usize..duplicate.span.start as usize].trim_end(),
+        );
+        Fix::new(content, Span::new(self.span.start, duplicate.span.end))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let include_exports = || Some(serde_json::json!([{ "includeExports": true }]));
+
+    let pass = vec![
+        ("import os from 'os'; import fs from 'fs';", None),
+        ("import { merge } from 'lodash-es';", None),
+        ("import _, { merge } from 'lodash-es';", None),
+        ("import * as Foobar from 'async';", None),
+        ("import 'foo'", None),
+        ("import os from 'os'; export { something } from 'os';", None),
+        ("import * as bar from 'os'; import { baz } from 'fs';", None),
+        ("import { merge } from 'lodash-es'; export * from 'lodash-es';", None),
+        ("import os from 'os'; export { hello } from 'hello';", include_exports()),
+        ("import os from 'os'; export * from 'hello';", include_exports()),
+        ("import os from 'os'; export { hello as hi } from 'hello';", include_exports()),
+        ("import os from 'os'; export default function(){};", include_exports()),
+        ("import { merge } from 'lodash-es'; export { merge };", include_exports()),
+    ];
+
+    let fail = vec![
+        ("import 'fs'; import 'fs'", None),
+        ("import { merge } from 'lodash-es'; import { find } from 'lodash-es';", None),
+        (
+            "import os from 'os'; import { something } from 'os'; import * as foobar from 'os';",
+            None,
+        ),
+        ("export { os } from 'os'; export { something } from 'os';", include_exports()),
+        ("import os from 'os'; export { os as foobar } from 'os';", include_exports()),
+        ("export * from 'os'; import { a } from 'os';", include_exports()),
+        ("import { a } from 'os'; foo(); import { b } from 'os';", None),
+    ];
+
+    let fix = vec![
+        (
+            "import { a } from 'os';\nimport { b, c } from 'os';",
+            "import { a, b, c } from 'os';",
+            None,
+        ),
+        (
+            "import x, { a } from 'os';\nfoo();\nimport { b } from 'os';\nbar();",
+            "import x, { a, b } from 'os';\nfoo();\nbar();",
+            None,
+        ),
+        (
+            "import { a } from 'os'; import b from 'os';",
+            "import { a } from 'os'; import b from 'os';",
+            None,
+        ),
+        (
+            "import * as a from 'os'; import { b } from 'os';",
+            "import * as a from 'os'; import { b } from 'os';",
+            None,
+        ),
+        (
+            "export { a } from 'os'; export { b as c } from 'os';",
+            "export { a, b as c } from 'os';",
+            include_exports(),
+        ),
+    ];
+
+    Tester::new(NoDuplicateImports::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,277 @@
+use oxc_ast::{
+    ast::{FunctionBody, MethodDefinitionKind, PropertyKind, TSAccessibility},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-empty-function): Unexpected empty {0}")]
+#[diagnostic(
+    severity(warning),
+    help("Add a comment inside the empty {0} to explain why it is empty")
+)]
+struct NoEmptyFunctionDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoEmptyFunction(Box<NoEmptyFunctionConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoEmptyFunctionConfig {
+    /// Kinds of functions which are allowed to be empty, e.g. `arrowFunctions`
+    allow: Vec<CompactString>,
+}
+
+impl std::ops::Deref for NoEmptyFunction {
+    type Target = NoEmptyFunctionConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows empty functions.
+    ///
+    /// ### Why is this bad?
+    /// An empty function is often left over from an unfinished refactoring.
+    /// A function that is empty on purpose should contain a comment saying so.
+    ///
+    /// ### Options
+    /// * `allow`: kinds of functions which may be empty. Any of `functions`, `arrowFunctions`,
+    ///   `generatorFunctions`, `methods`, `generatorMethods`, `getters`, `setters`,
+    ///   `constructors`, `asyncFunctions`, `asyncMethods`, `privateConstructors`,
+    ///   `protectedConstructors`, `decoratedFunctions` and `overrideMethods`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {}
+    /// const bar = () => {};
+    /// class Baz { qux() {} }
+    /// ```
+    NoEmptyFunction,
+    restriction
+);
+
+impl Rule for NoEmptyFunction {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoEmptyFunctionConfig {
+            allow: value
+                .get(0)
+                .and_then(|config| config.get("allow"))
+                .and_then(serde_json::Value::as_array)
+                .map(|allow| {
+                    allow
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactString::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function, AstType::ArrowFunctionExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (body, description) = match node.kind() {
+            AstKind::ArrowFunctionExpression(arrow) => {
+                if arrow.expression
+                    || !is_empty_body(&arrow.body, ctx)
+                    || self.is_allowed("arrowFunctions")
+                {
+                    return;
+                }
+                (&*arrow.body, "arrow function")
+            }
+            AstKind::Function(func) => {
+                // Declarations without a body, e.g. overloads and abstract methods
+                let Some(body) = &func.body else { return };
+                if !is_empty_body(body, ctx) {
+                    return;
+                }
+                let prefix = if func.generator {
+                    "generator"
+                } else if func.r#async {
+                    "async"
+                } else {
+                    ""
+                };
+                let description =
+                    match ctx.nodes().parent_kind(node.id()) {
+                        Some(AstKind::MethodDefinition(method)) => {
+                            if (!method.decorators.is_empty()
+                                && self.is_allowed("decoratedFunctions"))
+                                || (method.r#override && self.is_allowed("overrideMethods"))
+                            {
+                                return;
+                            }
+                            match method.kind {
+                                MethodDefinitionKind::Get => self.check("getters", "getter"),
+                                MethodDefinitionKind::Set => self.check("setters", "setter"),
+                                MethodDefinitionKind::Constructor => {
+                                    // `constructor(private foo) {}` is not empty as it declares a property
+                                    if func.params.items.iter().any(|param| {
+                                        param.accessibility.is_some() || param.readonly
+                                    }) {
+                                        return;
+                                    }
+                                    match method.accessibility {
+                                        Some(TSAccessibility::Private)
+                                            if self.is_allowed("privateConstructors") =>
+                                        {
+                                            None
+                                        }
+                                        Some(TSAccessibility::Protected)
+                                            if self.is_allowed("protectedConstructors") =>
+                                        {
+                                            None
+                                        }
+                                        _ => self.check("constructors", "constructor"),
+                                    }
+                                }
+                                MethodDefinitionKind::Method => self.check_method(prefix),
+                            }
+                        }
+                        Some(AstKind::ObjectProperty(prop)) => match prop.kind {
+                            PropertyKind::Get => self.check("getters", "getter"),
+                            PropertyKind::Set => self.check("setters", "setter"),
+                            PropertyKind::Init if prop.method => self.check_method(prefix),
+                            PropertyKind::Init => self.check_function(prefix),
+                        },
+                        _ => self.check_function(prefix),
+                    };
+                let Some(description) = description else { return };
+                (&**body, description)
+            }
+            _ => return,
+        };
+        ctx.diagnostic(NoEmptyFunctionDiagnostic(description, body.span));
+    }
+}
+
+impl NoEmptyFunction {
+    fn is_allowed(&self, kind: &str) -> bool {
+        self.allow.iter().any(|allowed| allowed.as_str() == kind)
+    }
+
+    /// The description of the function for the diagnostic, or `None` when its kind is allowed
+    fn check(&self, kind: &str, description: &'static str) -> Option<&'static str> {
+        (!self.is_allowed(kind)).then_some(description)
+    }
+
+    fn check_method(&self, prefix: &str) -> Option<&'static str> {
+        match prefix {
+            "generator" => self.check("generatorMethods", "generator method"),
+            "async" => self.check("asyncMethods", "async method"),
+            _ => self.check("methods", "method"),
+        }
+    }
+
+    fn check_function(&self, prefix: &str) -> Option<&'static str> {
+        match prefix {
+            "generator" => self.check("generatorFunctions", "generator function"),
+            "async" => self.check("asyncFunctions", "async function"),
+            _ => self.check("functions", "function"),
+        }
+    }
+}
+
+/// A body with a directive or a comment is not considered empty
+fn is_empty_body(body: &FunctionBody, ctx: &LintContext) -> bool {
+    body.is_empty() && !ctx.semantic().trivias().has_comments_between(body.span)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo() { bar(); }", None),
+        ("function foo() { // empty\n }", None),
+        ("function foo() { /* empty */ }", None),
+        ("function foo() { 'use strict'; }", None),
+        ("var foo = function() { bar(); };", None),
+        ("var foo = function() { /* empty */ };", None),
+        ("var foo = () => bar;", None),
+        ("var foo = () => {};", Some(json!([{ "allow": ["arrowFunctions"] }]))),
+        ("var foo = () => { /* empty */ };", None),
+        ("function foo() {}", Some(json!([{ "allow": ["functions"] }]))),
+        ("var foo = function() {};", Some(json!([{ "allow": ["functions"] }]))),
+        ("var obj = { foo: function() {} };", Some(json!([{ "allow": ["functions"] }]))),
+        ("function* foo() {}", Some(json!([{ "allow": ["generatorFunctions"] }]))),
+        ("async function foo() {}", Some(json!([{ "allow": ["asyncFunctions"] }]))),
+        ("var obj = { foo() {} };", Some(json!([{ "allow": ["methods"] }]))),
+        ("class A { foo() {} }", Some(json!([{ "allow": ["methods"] }]))),
+        ("class A { static foo() {} }", Some(json!([{ "allow": ["methods"] }]))),
+        ("var obj = { *foo() {} };", Some(json!([{ "allow": ["generatorMethods"] }]))),
+        ("class A { *foo() {} }", Some(json!([{ "allow": ["generatorMethods"] }]))),
+        ("var obj = { async foo() {} };", Some(json!([{ "allow": ["asyncMethods"] }]))),
+        ("class A { async foo() {} }", Some(json!([{ "allow": ["asyncMethods"] }]))),
+        ("var obj = { get foo() {} };", Some(json!([{ "allow": ["getters"] }]))),
+        ("class A { get foo() {} }", Some(json!([{ "allow": ["getters"] }]))),
+        ("var obj = { set foo(value) {} };", Some(json!([{ "allow": ["setters"] }]))),
+        ("class A { set foo(value) {} }", Some(json!([{ "allow": ["setters"] }]))),
+        ("class A { constructor() {} }", Some(json!([{ "allow": ["constructors"] }]))),
+        ("class A { constructor() { /* empty */ } }", None),
+        (
+            "class A { private constructor() {} }",
+            Some(json!([{ "allow": ["privateConstructors"] }])),
+        ),
+        (
+            "class A { protected constructor() {} }",
+            Some(json!([{ "allow": ["protectedConstructors"] }])),
+        ),
+        ("class A { constructor(private x: number) {} }", None),
+        ("class A { constructor(public x: number) {} }", None),
+        ("class A { constructor(readonly x: number) {} }", None),
+        ("class A { @decorator() foo() {} }", Some(json!([{ "allow": ["decoratedFunctions"] }]))),
+        (
+            "class B extends A { override foo() {} }",
+            Some(json!([{ "allow": ["overrideMethods"] }])),
+        ),
+        ("function foo(): void;", None),
+        ("abstract class A { abstract foo(): void; }", None),
+        ("declare function foo(): void;", None),
+    ];
+
+    let fail = vec![
+        ("function foo() {}", None),
+        ("var foo = function() {};", None),
+        ("var foo = () => {};", None),
+        ("function* foo() {}", None),
+        ("async function foo() {}", None),
+        ("var obj = { foo: function() {} };", None),
+        ("var obj = { foo() {} };", None),
+        ("class A { foo() {} }", None),
+        ("class A { static foo() {} }", None),
+        ("var obj = { *foo() {} };", None),
+        ("class A { async foo() {} }", None),
+        ("var obj = { get foo() {} };", None),
+        ("class A { set foo(value) {} }", None),
+        ("class A { constructor() {} }", None),
+        ("function foo() {}", Some(json!([{ "allow": ["arrowFunctions"] }]))),
+        ("var foo = () => {};", Some(json!([{ "allow": ["functions"] }]))),
+        ("function* foo() {}", Some(json!([{ "allow": ["functions"] }]))),
+        ("class A { foo() {} }", Some(json!([{ "allow": ["functions"] }]))),
+        ("class A { private constructor() {} }", None),
+        (
+            "class A { private constructor() {} }",
+            Some(json!([{ "allow": ["protectedConstructors"] }])),
+        ),
+        ("class A { @decorator() foo() {} }", None),
+        ("class B extends A { override foo() {} }", None),
+    ];
+
+    Tester::new(NoEmptyFunction::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -17,7 +17,9 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 struct NoEmptyPatternDiagnostic(&'static str, #[label("Empty {0} binding pattern")] pub Span);
 
 #[derive(Debug, Default, Clone)]
-pub struct NoEmptyPattern;
+pub struct NoEmptyPattern {
+    allow_object_patterns_as_parameters: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -73,21 +75,62 @@ declare_oxc_lint!(
     /// function foo({a = []}) {}
     /// ```
     ///
+    /// ### Options
+    /// With `{ "allowObjectPatternsAsParameters": true }`, empty object patterns are allowed as
+    /// parameters, also with an empty object as default value:
+    ///
+    /// ```JavaScript
+    /// function foo({}) {}
+    /// var foo = ({} = {}) => {};
+    /// ```
+    ///
     NoEmptyPattern,
     correctness,
 );
 
 impl Rule for NoEmptyPattern {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_object_patterns_as_parameters: value
+                .get(0)
+                .and_then(|config| config.get("allowObjectPatternsAsParameters"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let (pattern_type, span) = match node.kind() {
             AstKind::ArrayPattern(array) if array.is_empty() => ("array", array.span),
-            AstKind::ObjectPattern(object) if object.is_empty() => ("object", object.span),
+            AstKind::ObjectPattern(object) if object.is_empty() => {
+                if self.allow_object_patterns_as_parameters && is_parameter(node, ctx) {
+                    return;
+                }
+                ("object", object.span)
+            }
             _ => return,
         };
         ctx.diagnostic(NoEmptyPatternDiagnostic(pattern_type, span));
     }
 }
 
+/// Whether the pattern `node` is a parameter, like `{}` or `{} = {}`
+fn is_parameter(node: &AstNode, ctx: &LintContext) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else { return false };
+    match parent.kind() {
+        AstKind::FormalParameter(_) => true,
+        AstKind::AssignmentPattern(pattern) => {
+            let Expression::ObjectExpression(default) = &pattern.right else { return false };
+            default.properties.is_empty()
+                && ctx
+                    .nodes()
+                    .parent_kind(parent.id())
+                    .is_some_and(|kind| matches!(kind, AstKind::FormalParameter(_)))
+        }
+        _ => false,
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -101,6 +144,26 @@ fn test() {
         ("var [a] = foo", None),
         ("var {...x} = foo;", None),
         ("var [...x] = foo;", None),
+        (
+            "function foo({}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "var foo = function({}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "var foo = ({}) => {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo({} = {}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "var foo = ({} = {}) => {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -113,6 +176,31 @@ fn test() {
         ("function foo([]) {}", None),
         ("function foo({a: {}}) {}", None),
         ("function foo({a: []}) {}", None),
+        (
+            "function foo({}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": false }])),
+        ),
+        ("var {} = foo", Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }]))),
+        (
+            "function foo([]) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo({a: {}}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo({} = bar) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo({} = { a: 1 }) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "var foo = ([]) => {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
     ];
 
     Tester::new(NoEmptyPattern::NAME, pass, fail).test_and_snapshot();
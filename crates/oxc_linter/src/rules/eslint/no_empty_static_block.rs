@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -38,6 +38,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEmptyStaticBlock {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::StaticBlock])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StaticBlock(static_block) = node.kind() {
             if static_block.body.is_empty() {
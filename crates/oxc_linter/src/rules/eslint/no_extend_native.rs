@@ -0,0 +1,207 @@
+use oxc_ast::{
+    ast::{Argument, AssignmentTarget, ChainElement, Expression, SimpleAssignmentTarget},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+
+use crate::{
+    ast_util::is_method_call, context::LintContext, javascript_globals::GLOBALS, rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-extend-native): {0} prototype is read only, properties should not be added.")]
+#[diagnostic(severity(warning), help("Use a helper function or a subclass instead"))]
+struct NoExtendNativeDiagnostic(CompactString, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtendNative(Box<NoExtendNativeConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtendNativeConfig {
+    exceptions: Vec<CompactString>,
+}
+
+impl std::ops::Deref for NoExtendNative {
+    type Target = NoExtendNativeConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows extending the prototypes of builtin objects.
+    ///
+    /// ### Why is this bad?
+    /// Properties added to `Object.prototype` or `Array.prototype` show up in every object
+    /// or array, break `for-in` loops and may clash with future additions to the language.
+    ///
+    /// ### Options
+    /// * `exceptions`: builtins whose prototypes may be extended, e.g. `["Array"]`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// Object.prototype.a = "a";
+    /// Object.defineProperty(Array.prototype, "times", { value: 999 });
+    /// ```
+    NoExtendNative,
+    suspicious
+);
+
+impl Rule for NoExtendNative {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoExtendNativeConfig {
+            exceptions: value
+                .get(0)
+                .and_then(|config| config.get("exceptions"))
+                .and_then(serde_json::Value::as_array)
+                .map(|exceptions| {
+                    exceptions
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactString::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::AssignmentExpression, AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `Array.prototype.foo = bar`
+            AstKind::AssignmentExpression(expr) => {
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &expr.left
+                else {
+                    return;
+                };
+                if let Some(name) = self.native_prototype(member.object(), ctx) {
+                    ctx.diagnostic(NoExtendNativeDiagnostic(name.into(), expr.span));
+                }
+            }
+            // `Object.defineProperty(Array.prototype, "foo", { value: bar })`
+            AstKind::CallExpression(call) => {
+                if !is_method_call(
+                    call,
+                    Some(&["Object"]),
+                    Some(&["defineProperty", "defineProperties"]),
+                    Some(1),
+                    None,
+                ) {
+                    return;
+                }
+                let Expression::MemberExpression(callee) = call.callee.without_parenthesized()
+                else {
+                    return;
+                };
+                let Expression::Identifier(object) = callee.object().without_parenthesized() else {
+                    return;
+                };
+                if !ctx.semantic().is_reference_to_global_variable(object) {
+                    return;
+                }
+                let Argument::Expression(target) = &call.arguments[0] else { return };
+                if let Some(name) = self.native_prototype(target, ctx) {
+                    ctx.diagnostic(NoExtendNativeDiagnostic(name.into(), call.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoExtendNative {
+    /// The name of `Array` in `Array.prototype` when it is a builtin which is not an exception
+    fn native_prototype<'a>(&self, expr: &'a Expression, ctx: &LintContext) -> Option<&'a str> {
+        let member = match expr.without_parenthesized() {
+            Expression::MemberExpression(member) => member,
+            Expression::ChainExpression(chain) => {
+                let ChainElement::MemberExpression(member) = &chain.expression else {
+                    return None;
+                };
+                member
+            }
+            _ => return None,
+        };
+        if member.static_property_name() != Some("prototype") {
+            return None;
+        }
+        let Expression::Identifier(ident) = member.object().without_parenthesized() else {
+            return None;
+        };
+        let name = ident.name.as_str();
+        // Only constructors have a prototype, e.g. `Array` but not `globalThis`
+        let is_native = name.starts_with(|c: char| c.is_ascii_uppercase())
+            && GLOBALS["builtin"].contains_key(name);
+        (is_native
+            && !self.exceptions.iter().any(|exception| exception.as_str() == name)
+            && ctx.semantic().is_reference_to_global_variable(ident))
+        .then_some(name)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("x.prototype.p = 0", None),
+        ("x.prototype['p'] = 0", None),
+        ("Object.p = 0", None),
+        ("Object.toString.bind = 0", None),
+        ("Object['toString'].bind = 0", None),
+        ("Object.defineProperty(x, 'p', {value: 0})", None),
+        ("Object.defineProperties(x, {p: {value: 0}})", None),
+        ("global.Object.prototype.toString = 0", None),
+        ("this.Object.prototype.toString = 0", None),
+        ("with(Object) { prototype.p = 0; }", None),
+        ("o = Object; o.prototype.toString = 0", None),
+        ("eval('Object.prototype.toString = 0')", None),
+        ("parseFloat.prototype.x = 1", None),
+        ("Object.prototype.g = 0", Some(json!([{ "exceptions": ["Object"] }]))),
+        ("obj[Object.prototype] = 0", None),
+        ("Object.defineProperty()", None),
+        ("Object.defineProperties()", None),
+        ("function foo() { var Object = function() {}; Object.prototype.p = 0 }", None),
+        ("{ let Object = function() {}; Object.prototype.p = 0 }", None),
+        ("function foo(Object) { Object.defineProperty(Array.prototype, 'p', {value: 0}) }", None),
+    ];
+
+    let fail = vec![
+        ("Object.prototype.p = 0", None),
+        ("BigInt.prototype.p = 0", None),
+        ("WeakRef.prototype.p = 0", None),
+        ("FinalizationRegistry.prototype.p = 0", None),
+        ("AggregateError.prototype.p = 0", None),
+        ("Function.prototype['p'] = 0", None),
+        ("String['prototype'].p = 0", None),
+        ("Number['prototype']['p'] = 0", None),
+        ("Object.defineProperty(Array.prototype, 'p', {value: 0})", None),
+        ("Object.defineProperties(Array.prototype, {p: {value: 0}})", None),
+        ("Object.defineProperties(Array.prototype, {p: {value: 0}, q: {value: 0}})", None),
+        ("Number['prototype']['p'] = 0", Some(json!([{ "exceptions": ["Object"] }]))),
+        ("Object.prototype.p = 0; Object.prototype.q = 0", None),
+        ("function foo() { Object.prototype.p = 0 }", None),
+        ("(Object?.prototype).p = 0", None),
+        ("Object.defineProperty(Object?.prototype, 'p', { value: 0 })", None),
+        ("Object?.defineProperty(Object.prototype, 'p', { value: 0 })", None),
+        ("Array.prototype.p &&= 0", None),
+        ("Array.prototype.p ||= 0", None),
+        ("Array.prototype.p ??= 0", None),
+    ];
+
+    Tester::new(NoExtendNative::NAME, pass, fail).test_and_snapshot();
+}
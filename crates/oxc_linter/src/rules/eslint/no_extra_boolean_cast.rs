@@ -1,14 +1,20 @@
 use itertools::Itertools;
-use oxc_ast::{ast::Argument, AstKind};
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
-use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+use oxc_syntax::{
+    operator::{LogicalOperator, UnaryOperator},
+    precedence::{GetPrecedence, Precedence},
+};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::get_parenthesized_text, context::LintContext, rule::Rule, AstNode, Fix};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-extra-boolean-cast): Redundant double negation")]
@@ -65,19 +71,38 @@ impl Rule for NoExtraBooleanCast {
         }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression, AstType::UnaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(expr)
                 if expr.callee.is_specific_id("Boolean")
                     && is_flagged_ctx(node, ctx, self.enforce_for_logical_operands) =>
             {
-                ctx.diagnostic(NoExtraBooleanCastDiagnostic(expr.span));
+                let diagnostic = NoExtraBooleanCastDiagnostic(expr.span);
+                match expr.arguments.as_slice() {
+                    // `Boolean()` is always `false`
+                    [] => ctx.diagnostic_with_fix(diagnostic, || {
+                        Fix::new("false".to_string(), expr.span)
+                    }),
+                    [Argument::Expression(argument)] => {
+                        ctx.diagnostic_with_fix(diagnostic, || {
+                            Fix::new(print_in_place_of(argument, node, ctx), expr.span)
+                        });
+                    }
+                    _ => ctx.diagnostic(diagnostic),
+                }
             }
             AstKind::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
                 let Some(parent) = get_real_parent(node, ctx) else { return };
                 if matches!(parent.kind(), AstKind::UnaryExpression(p) if p.operator == UnaryOperator::LogicalNot && is_flagged_ctx(parent, ctx, self.enforce_for_logical_operands))
                 {
-                    ctx.diagnostic(NoExtraDoubleNegationCastDiagnostic(parent.kind().span()));
+                    let span = parent.kind().span();
+                    ctx.diagnostic_with_fix(NoExtraDoubleNegationCastDiagnostic(span), || {
+                        Fix::new(print_in_place_of(&unary.argument, parent, ctx), span)
+                    });
                 }
             }
             _ => {}
@@ -85,6 +110,21 @@ impl Rule for NoExtraBooleanCast {
     }
 }
 
+/// The source text of `expr` to replace `node` with, parenthesized if its parent requires it
+fn print_in_place_of(expr: &Expression, node: &AstNode, ctx: &LintContext) -> String {
+    let required = get_real_parent(node, ctx).map_or(Precedence::lowest(), |parent| {
+        match parent.kind() {
+            AstKind::UnaryExpression(_) => Precedence::Prefix,
+            AstKind::LogicalExpression(expr) => expr.precedence(),
+            // The test of a conditional expression is a short-circuit expression
+            AstKind::ConditionalExpression(_) => Precedence::Coalesce,
+            AstKind::CallExpression(_) | AstKind::NewExpression(_) => Precedence::Assign,
+            _ => Precedence::lowest(),
+        }
+    });
+    get_parenthesized_text(expr, required, ctx)
+}
+
 // Checks whether the node is a context that should report an error
 // Acts recursively if it is in a logical context
 fn is_flagged_ctx(node: &AstNode, ctx: &LintContext, enforce_for_logical_operands: bool) -> bool {
@@ -874,5 +914,30 @@ fn test() {
         ("if (!Boolean(a as any)) { }", None),
     ];
 
-    Tester::new(NoExtraBooleanCast::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("if (!!foo) {}", "if (foo) {}", None),
+        ("while (!!(a, b)) {}", "while ((a, b)) {}", None),
+        ("!!foo ? bar : baz", "foo ? bar : baz", None),
+        ("!!!foo", "!foo", None),
+        ("!!!(a || b)", "!(a || b)", None),
+        ("new Boolean(!!foo)", "new Boolean(foo)", None),
+        ("if (Boolean(foo)) {}", "if (foo) {}", None),
+        ("if (Boolean()) {}", "if (false) {}", None),
+        ("if (Boolean(a, b)) {}", "if (Boolean(a, b)) {}", None),
+        ("if (Boolean(...a)) {}", "if (Boolean(...a)) {}", None),
+        ("!Boolean(a && b)", "!(a && b)", None),
+        ("Boolean(a = b) ? c : d", "(a = b) ? c : d", None),
+        (
+            "if (Boolean(a) || !!b) {}",
+            "if (a || b) {}",
+            Some(serde_json::json!([{ "enforceForLogicalOperands": true }])),
+        ),
+        (
+            "if (Boolean(a ?? b) || c) {}",
+            "if ((a ?? b) || c) {}",
+            Some(serde_json::json!([{ "enforceForLogicalOperands": true }])),
+        ),
+    ];
+
+    Tester::new(NoExtraBooleanCast::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
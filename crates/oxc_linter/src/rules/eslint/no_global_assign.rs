@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -63,6 +64,10 @@ impl Rule for NoGlobalAssign {
         }))
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let symbol_table = ctx.symbols();
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
@@ -71,7 +76,7 @@ impl Rule for NoGlobalAssign {
                 if reference.is_write() && symbol_table.is_global_reference(reference_id) {
                     let name = reference.name();
 
-                    if !self.excludes.contains(name) && ctx.env_contains_var(name) {
+                    if !self.excludes.contains(name) && ctx.env_var_writable(name) == Some(false) {
                         ctx.diagnostic(NoGlobalAssignDiagnostic(name.clone(), reference.span()));
                     }
                 }
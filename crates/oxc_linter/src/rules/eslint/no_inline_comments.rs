@@ -0,0 +1,163 @@
+use oxc_ast::{ast::JSXExpression, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{
+    ast_util::{get_comment_span, get_line_text_around, is_directive_comment},
+    context::LintContext,
+    rule::Rule,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-inline-comments): Unexpected comment inline with code.")]
+#[diagnostic(severity(warning), help("Move the comment to its own line"))]
+struct NoInlineCommentsDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInlineComments(Box<NoInlineCommentsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInlineCommentsConfig {
+    ignore_pattern: Option<Regex>,
+}
+
+impl std::ops::Deref for NoInlineComments {
+    type Target = NoInlineCommentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow inline comments after code
+    ///
+    /// ### Why is this bad?
+    /// Comments on the same line as code can make the code harder to read.
+    ///
+    /// ### Options
+    /// * `ignorePattern`: a regular expression for the content of comments to allow.
+    ///
+    /// Comments inside an otherwise empty JSX expression container, e.g. `{/* comment */}`,
+    /// are allowed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var a = 1; // declaring a to 1
+    /// function getRandomNumber(){
+    ///     return 4; // chosen by fair dice roll.
+    /// }
+    /// /* A block comment before code */ var b = 2;
+    /// ```
+    NoInlineComments,
+    style
+);
+
+impl Rule for NoInlineComments {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore_pattern = value
+            .get(0)
+            .and_then(|config| config.get("ignorePattern"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(|pattern| Regex::new(pattern).ok());
+        Self(Box::new(NoInlineCommentsConfig { ignore_pattern }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        for (comment, span) in ctx.semantic().trivias().comments_spans() {
+            let comment_span = get_comment_span(span, comment);
+            let (before, after) = get_line_text_around(comment_span, source_text);
+            let (before, after) = (before.trim(), after.trim());
+            if before.is_empty() && after.is_empty() {
+                continue;
+            }
+
+            let content = span.source_text(source_text);
+            if self.ignore_pattern.as_ref().is_some_and(|pattern| pattern.is_match(content)) {
+                continue;
+            }
+
+            if matches!(before, "" | "{")
+                && matches!(after, "" | "}")
+                && is_in_empty_jsx_expression(comment_span, ctx)
+            {
+                continue;
+            }
+
+            if is_directive_comment(comment, content) {
+                continue;
+            }
+
+            ctx.diagnostic(NoInlineCommentsDiagnostic(comment_span));
+        }
+    }
+}
+
+/// Whether `span` is inside a JSX expression container without an expression, e.g. `{/* comment */}`.
+fn is_in_empty_jsx_expression(span: Span, ctx: &LintContext) -> bool {
+    ctx.nodes().iter().any(|node| {
+        let AstKind::JSXExpressionContainer(container) = node.kind() else { return false };
+        matches!(container.expression, JSXExpression::EmptyExpression(_))
+            && container.span.start < span.start
+            && span.end < container.span.end
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("// A valid comment before code\nvar a = 1;", None),
+        ("var a = 2;\n// A valid comment after code", None),
+        ("// A solitary comment", None),
+        ("var a = 1; // eslint-disable-line no-debugger", None),
+        ("var a = 1; /* eslint-disable-line no-debugger */", None),
+        ("foo(); // eslint-disable-line no-alert", None),
+        ("foo(); /* eslint-disable-line no-alert */", None),
+        ("foo(); /* eslint-disable-next-line no-alert */", None),
+        ("foo(); /* eslint-disable-next-line no-alert */\nbar();", None),
+        ("foo(); // oxlint-disable-line no-alert", None),
+        ("var a = (\n    <div>\n    {/*comment*/}\n    </div>\n)", None),
+        (
+            "var a = (\n    <div>\n    { /* comment */ }\n    <h1>Some heading</h1>\n    </div>\n)",
+            None,
+        ),
+        ("var a = (\n    <div>\n    {// comment\n    }\n    </div>\n)", None),
+        ("var a = (\n    <div>\n    { // comment\n    }\n    </div>\n)", None),
+        ("var a = (\n    <div>\n    {\n    /* comment */\n    }\n    </div>\n)", None),
+        ("foo(); // A comment", Some(serde_json::json!([{ "ignorePattern": "A comment" }]))),
+        (
+            "foo(); // Ignore this comment",
+            Some(serde_json::json!([{ "ignorePattern": "^\\s*Ignore" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var a = 1; /*A block comment inline after code*/", None),
+        ("/*A block comment inline before code*/ var a = 2;", None),
+        ("/* something */ var a = 2;", Some(serde_json::json!([{ "ignorePattern": "otherthing" }]))),
+        ("var a = 3; //A comment inline with code", None),
+        ("var a = 3; // someday use eslint-disable-line here", None),
+        ("var a = 3; // other line comment", Some(serde_json::json!([{ "ignorePattern": "something" }]))),
+        ("var a = (\n    <div>{/* comment */}text</div>\n)", None),
+        ("var a = (\n    <div>\n    {a /* comment */}\n    </div>\n)", None),
+        (
+            "var a = (\n    <div>\n    { /* comment */\n    <h1>Some heading</h1>\n    }\n    </div>\n)",
+            None,
+        ),
+    ];
+
+    Tester::new(NoInlineComments::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,221 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_js_regex::validator::{validate_pattern, PatternMode};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoInvalidRegexpDiagnostic {
+    #[error("eslint(no-invalid-regexp): Invalid regular expression: /{0}/{1}: {2}")]
+    #[diagnostic(severity(warning))]
+    Pattern(CompactString, &'static str, &'static str, #[label] Span),
+
+    #[error("eslint(no-invalid-regexp): Invalid flags supplied to RegExp constructor '{0}'")]
+    #[diagnostic(severity(warning))]
+    Flags(CompactString, #[label] Span),
+
+    #[error("eslint(no-invalid-regexp): Regex 'u' and 'v' flags cannot be used together")]
+    #[diagnostic(severity(warning))]
+    UnicodeFlags(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInvalidRegexp(Box<NoInvalidRegexpConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInvalidRegexpConfig {
+    /// Flags besides the standard ones which are allowed in `RegExp` constructors
+    allow_constructor_flags: Vec<char>,
+}
+
+impl std::ops::Deref for NoInvalidRegexp {
+    type Target = NoInvalidRegexpConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows invalid regular expression strings in `RegExp` constructors.
+    ///
+    /// ### Why is this bad?
+    /// An invalid pattern in a regular expression literal is a syntax error, but a string
+    /// passed to `RegExp` only throws a `SyntaxError` once the code runs.
+    ///
+    /// ### Options
+    /// * `allowConstructorFlags`: extra flags to allow, e.g. `["a"]`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// RegExp('[');
+    /// RegExp('.', 'z');
+    /// new RegExp('\\');
+    /// ```
+    NoInvalidRegexp,
+    correctness
+);
+
+const STANDARD_FLAGS: &str = "dgimsuyv";
+
+impl Rule for NoInvalidRegexp {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoInvalidRegexpConfig {
+            allow_constructor_flags: value
+                .get(0)
+                .and_then(|config| config.get("allowConstructorFlags"))
+                .and_then(serde_json::Value::as_array)
+                .map(|flags| {
+                    flags
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .flat_map(str::chars)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NewExpression, AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::NewExpression(expr) => (&expr.callee, &expr.arguments, expr.span),
+            AstKind::CallExpression(expr) => (&expr.callee, &expr.arguments, expr.span),
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee.without_parenthesized() else { return };
+        if ident.name != "RegExp" || !ctx.semantic().is_reference_to_global_variable(ident) {
+            return;
+        }
+
+        let flags = match arguments.get(1) {
+            Some(Argument::Expression(Expression::StringLiteral(flags))) => {
+                Some(flags.value.as_str())
+            }
+            Some(_) => None,
+            None => Some(""),
+        };
+        if let Some(flags) = flags {
+            let is_valid = flags.char_indices().all(|(i, flag)| {
+                (STANDARD_FLAGS.contains(flag) || self.allow_constructor_flags.contains(&flag))
+                    && !flags[..i].contains(flag)
+            });
+            if !is_valid {
+                ctx.diagnostic(NoInvalidRegexpDiagnostic::Flags(flags.into(), span));
+                return;
+            }
+            if flags.contains('u') && flags.contains('v') {
+                ctx.diagnostic(NoInvalidRegexpDiagnostic::UnicodeFlags(span));
+                return;
+            }
+        }
+
+        let Some(Argument::Expression(Expression::StringLiteral(pattern))) = arguments.first()
+        else {
+            return;
+        };
+        let result = match flags {
+            Some(flags) if flags.contains('v') => {
+                validate_pattern(&pattern.value, PatternMode::UnicodeSets).map_err(|e| (e, "v"))
+            }
+            Some(flags) if flags.contains('u') => {
+                validate_pattern(&pattern.value, PatternMode::Unicode).map_err(|e| (e, "u"))
+            }
+            Some(_) => validate_pattern(&pattern.value, PatternMode::Legacy).map_err(|e| (e, "")),
+            // The pattern is invalid regardless of the flags only when both modes reject it
+            None => validate_pattern(&pattern.value, PatternMode::Legacy)
+                .or_else(|e| validate_pattern(&pattern.value, PatternMode::Unicode).map_err(|_| e))
+                .map_err(|e| (e, "")),
+        };
+        if let Err((message, flags)) = result {
+            ctx.diagnostic(NoInvalidRegexpDiagnostic::Pattern(
+                pattern.value.to_compact_string(),
+                flags,
+                message,
+                span,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("RegExp('')", None),
+        ("RegExp()", None),
+        ("RegExp('.', 'g')", None),
+        ("new RegExp('.')", None),
+        ("new RegExp", None),
+        ("new RegExp('.', 'im')", None),
+        ("global.RegExp('\\\\')", None),
+        ("new RegExp('.', y)", None),
+        ("new RegExp('.', 'y')", None),
+        ("new RegExp('.', 'u')", None),
+        ("new RegExp('.', 'yu')", None),
+        ("new RegExp('/', 'yu')", None),
+        ("new RegExp('\\/', 'yu')", None),
+        ("new RegExp('\\\\u{65}', 'u')", None),
+        ("new RegExp('\\\\u{65}*', 'u')", None),
+        ("new RegExp('[\\\\u{0}-\\\\u{1F}]', 'u')", None),
+        ("new RegExp('.', 's')", None),
+        ("new RegExp('(?<=a)b')", None),
+        ("new RegExp('(?<!a)b')", None),
+        ("new RegExp('(?<a>b)\\\\k<a>')", None),
+        ("new RegExp('(?<a>b)\\\\k<a>', 'u')", None),
+        ("new RegExp('\\\\p{Letter}', 'u')", None),
+        ("var foo = new RegExp('(a)bc[de]', '');", None),
+        ("RegExp('a', 'd')", None),
+        ("new RegExp('[A--B]', 'v')", None),
+        ("new RegExp('[A&&B]', 'v')", None),
+        ("new RegExp('[[A--B]]', 'v')", None),
+        ("new RegExp('[\\\\q{abc}]', 'v')", None),
+        ("new RegExp('.', 'a')", Some(json!([{ "allowConstructorFlags": ["a"] }]))),
+        ("new RegExp('.', 'ga')", Some(json!([{ "allowConstructorFlags": ["a"] }]))),
+        ("new RegExp('.', 'az')", Some(json!([{ "allowConstructorFlags": ["a", "z"] }]))),
+        ("new RegExp('.', 'az')", Some(json!([{ "allowConstructorFlags": ["az"] }]))),
+        ("new RegExp('{', flags)", None),
+        ("new RegExp('\\\\u{0}*', flags)", None),
+        ("new RegExp(pattern, 'g')", None),
+        ("function foo(RegExp) { new RegExp('['); }", None),
+        ("new RegExp('a{')", None),
+        ("new RegExp('\\\\8')", None),
+    ];
+
+    let fail = vec![
+        ("RegExp('[');", None),
+        ("RegExp('.', 'z');", None),
+        ("RegExp('.', 'a');", Some(json!([{}]))),
+        ("new RegExp('.', 'a');", Some(json!([{ "allowConstructorFlags": ["z"] }]))),
+        ("RegExp('.', 'gg');", None),
+        ("new RegExp(pattern, 'gg');", None),
+        ("new RegExp('\\\\');", None),
+        ("new RegExp(')');", None),
+        ("new RegExp('a{', 'u');", None),
+        ("new RegExp('(?<a>.)(?<a>.)');", None),
+        ("new RegExp('\\\\k<a>', 'u');", None),
+        ("new RegExp('\\\\p{Letter', 'u');", None),
+        ("new RegExp('[z-a]');", None),
+        ("new RegExp('a**');", None),
+        ("new RegExp('(', flags);", None),
+        ("new RegExp('.', 'uv');", None),
+        ("new RegExp('[[A', 'v');", None),
+        ("new RegExp('\\\\a', 'u');", None),
+    ];
+
+    Tester::new(NoInvalidRegexp::NAME, pass, fail).test_and_snapshot();
+}
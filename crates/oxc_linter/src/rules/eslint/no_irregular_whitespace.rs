@@ -1,9 +1,11 @@
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use oxc_syntax::identifier::{is_irregular_line_terminator, is_irregular_whitespace};
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -12,13 +14,39 @@ use crate::{context::LintContext, rule::Rule};
 #[diagnostic(severity(warning), help("Try to remove the irregular whitespace"))]
 struct NoIrregularWhitespaceDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
-pub struct NoIrregularWhitespace;
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct NoIrregularWhitespace {
+    skip_strings: bool,
+    skip_comments: bool,
+    skip_regexps: bool,
+    skip_templates: bool,
+    skip_jsx_text: bool,
+}
+
+impl Default for NoIrregularWhitespace {
+    fn default() -> Self {
+        Self {
+            skip_strings: true,
+            skip_comments: false,
+            skip_regexps: false,
+            skip_templates: false,
+            skip_jsx_text: false,
+        }
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
     /// Disallows the use of irregular whitespaces in the code.
     ///
+    /// Options:
+    /// * `skipStrings`: allow irregular whitespaces in string literals, `true` by default
+    /// * `skipComments`: allow irregular whitespaces in comments
+    /// * `skipRegExps`: allow irregular whitespaces in regular expression literals
+    /// * `skipTemplates`: allow irregular whitespaces in template literals
+    /// * `skipJSXText`: allow irregular whitespaces in JSX text
+    ///
     /// ### Why is this bad
     /// The use of irregular whitespaces can hinder code readability and
     /// create inconsistencies, making maintenance and collaboration more challenging.
@@ -34,10 +62,70 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoIrregularWhitespace {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let flag = |name: &str, default: bool| {
+            value
+                .get(0)
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default)
+        };
+        Self {
+            skip_strings: flag("skipStrings", default.skip_strings),
+            skip_comments: flag("skipComments", default.skip_comments),
+            skip_regexps: flag("skipRegExps", default.skip_regexps),
+            skip_templates: flag("skipTemplates", default.skip_templates),
+            skip_jsx_text: flag("skipJSXText", default.skip_jsx_text),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
     fn run_once(&self, ctx: &LintContext) {
-        let irregular_whitespaces = ctx.semantic().trivias().irregular_whitespaces();
+        // The lexer only records the irregular whitespaces between tokens,
+        // the ones inside of comments and literals are found from the source text.
+        let mut irregular_whitespaces = ctx.semantic().trivias().irregular_whitespaces().clone();
+        let mut scan = |span: Span| {
+            let text = span.source_text(ctx.source_text());
+            irregular_whitespaces.extend(
+                text.char_indices()
+                    .filter(|(_, c)| {
+                        is_irregular_whitespace(*c) || is_irregular_line_terminator(*c)
+                    })
+                    .map(|(i, c)| {
+                        let start = span.start + i as u32;
+                        Span::new(start, start + c.len_utf8() as u32)
+                    }),
+            );
+        };
+
+        if !self.skip_comments {
+            for (_, span) in ctx.semantic().trivias().comments_spans() {
+                scan(span);
+            }
+        }
+        for node in ctx.nodes().iter() {
+            match node.kind() {
+                AstKind::StringLiteral(lit) if !self.skip_strings => scan(lit.span),
+                AstKind::RegExpLiteral(lit) if !self.skip_regexps => scan(lit.span),
+                AstKind::JSXText(text) if !self.skip_jsx_text => scan(text.span),
+                AstKind::TemplateLiteral(lit) if !self.skip_templates => {
+                    for quasi in &lit.quasis {
+                        scan(quasi.span);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        irregular_whitespaces.sort_unstable_by_key(|span| span.start);
+        irregular_whitespaces.dedup();
         for irregular_whitespace in irregular_whitespaces {
-            ctx.diagnostic(NoIrregularWhitespaceDiagnostic(*irregular_whitespace));
+            ctx.diagnostic(NoIrregularWhitespaceDiagnostic(irregular_whitespace));
         }
     }
 }
@@ -258,53 +346,53 @@ fn test() {
 			var third 　 = 'thing';",
             None,
         ),
-        // (r"// ", None),
-        // (r"// ", None),
+        (r"// ", None),
+        (r"// ", None),
         // (r"// ", None),
-        // (r"//  ", None),
+        (r"//  ", None),
         // (r"// ᠎", None),
-        // (r"// ﻿", None),
+        (r"// ﻿", None),
         // (r"//  ", None),
         // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
         // (r"// ​", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"// 　", None),
-        // (r"/*  */", None),
-        // (r"/*  */", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"// 　", None),
+        (r"/*  */", None),
+        (r"/*  */", None),
         // (r"/*  */", None),
-        // (r"/*   */", None),
+        (r"/*   */", None),
         // (r"/* ᠎ */", None),
-        // (r"/* ﻿ */", None),
+        (r"/* ﻿ */", None),
         // (r"/*   */", None),
         // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
         // (r"/* ​ */", None),
         // (r"/*   */", None),
         // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/* 　 */", None),
-        // (r"var any = /　/, other = //;", None),
-        // (r"var any = '　', other = '';", Some(serde_json::json!([{ "skipStrings": false }]))),
-        // (r"var any = `　`, other = ``;", Some(serde_json::json!([{ "skipTemplates": false }]))),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/* 　 */", None),
+        (r"var any = /　/, other = //;", None),
+        (r"var any = '　', other = '';", Some(serde_json::json!([{ "skipStrings": false }]))),
+        (r"var any = `　`, other = ``;", Some(serde_json::json!([{ "skipTemplates": false }]))),
         (
             r"`something ${　 10} another thing`",
             Some(serde_json::json!([{ "skipTemplates": true }])),
@@ -384,27 +472,27 @@ fn test() {
             None,
         ),
         // (r"foo ", None),
-        // (r"<div></div>;", None),
-        // (r"<div></div>;", None),
+        (r"<div></div>;", None),
+        (r"<div></div>;", None),
         // (r"<div></div>;", None),
-        // (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
         // (r"<div>᠎</div>;", None),
-        // (r"<div>﻿</div>;", None),
+        (r"<div>﻿</div>;", None),
         // (r"<div> </div>;", None),
         // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
         // (r"<div>​</div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div>　</div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div>　</div>;", None),
     ];
 
     Tester::new(NoIrregularWhitespace::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,141 @@
+use oxc_ast::{ast::Statement, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-lonely-if): Unexpected if as the only statement in an else block.")]
+#[diagnostic(severity(warning), help("Use `else if` instead"))]
+struct NoLonelyIfDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLonelyIf;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows `if` statements as the only statement in `else` blocks.
+    ///
+    /// ### Why is this bad?
+    /// `else { if (b) {} }` is the same as `else if (b) {}`, which has one less level
+    /// of indentation.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// if (condition) {
+    ///     // ...
+    /// } else {
+    ///     if (anotherCondition) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    NoLonelyIf,
+    style
+);
+
+impl Rule for NoLonelyIf {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::IfStatement])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::IfStatement(if_stmt) = node.kind() else { return };
+        let Some(AstKind::BlockStatement(block)) = ctx.nodes().parent_kind(node.id()) else {
+            return;
+        };
+        if block.body.len() != 1 {
+            return;
+        }
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else { return };
+        let Some(AstKind::IfStatement(parent_if)) = ctx.nodes().parent_kind(parent.id()) else {
+            return;
+        };
+        if !matches!(&parent_if.alternate, Some(Statement::BlockStatement(alternate)) if alternate.span == block.span)
+        {
+            return;
+        }
+
+        let diagnostic =
+            NoLonelyIfDiagnostic(Span::new(if_stmt.span.start, if_stmt.span.start + 2));
+        let source_text = ctx.source_text();
+        let if_text = if_stmt.span.source_text(source_text);
+        // Comments inside of the block but outside of the `if` would be lost
+        let has_comments = [
+            Span::new(block.span.start + 1, if_stmt.span.start),
+            Span::new(if_stmt.span.end, block.span.end - 1),
+        ]
+        .iter()
+        .any(|span| !span.source_text(source_text).trim().is_empty());
+        // `else { if (a) foo() }` can not become `else if (a) foo()` when it's followed by
+        // something like `(bar)`, which would continue the expression
+        let ends_statement = if_text.ends_with(['}', ';'])
+            || !source_text[block.span.end as usize..]
+                .trim_start()
+                .starts_with(['(', '[', '`', '+', '-', '/']);
+        if has_comments || !ends_statement {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, || Fix::new(if_text.to_string(), block.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (a) {;} else if (b) {}",
+        "if (a) {;} else { if (b) {} ; }",
+        "if (a) {;} else { foo(); if (b) {} }",
+        "if (a) { if (b) {} }",
+        "if (a) {;} else { if (b) {} else {} foo(); }",
+        "if (a) {;} else if (b) {} else { foo(); }",
+        "if (a) {} else { function foo() { if (b) {} } }",
+        "if (a) {} else { { if (b) {} } }",
+    ];
+
+    let fail = vec![
+        "if (a) {;} else { if (b) {} }",
+        "if (a) {\n  foo();\n} else {\n  if (b) {\n    bar();\n  }\n}",
+        "if (a) {} else { if (b) {} else {} }",
+        "if (a) {} else { if (b) {} else if (c) {} else {} }",
+        "if (a) {} else { // comment\n if (b) {} }",
+        "if (a) {} else { if (b) {} /* comment */ }",
+        "if (a) {} else { if (b) foo(); }",
+        "if (a) {} else { if (b) foo() }\n(bar)",
+        "function foo() { if (a) {} else { if (b) return; } }",
+    ];
+
+    let fix = vec![
+        ("if (a) {;} else { if (b) {} }", "if (a) {;} else if (b) {}", None),
+        (
+            "if (a) {\n  foo();\n} else {\n  if (b) {\n    bar();\n  }\n}",
+            "if (a) {\n  foo();\n} else if (b) {\n    bar();\n  }",
+            None,
+        ),
+        (
+            "if (a) {} else { if (b) {} else if (c) {} else {} }",
+            "if (a) {} else if (b) {} else if (c) {} else {}",
+            None,
+        ),
+        (
+            "if (a) {} else { // comment\n if (b) {} }",
+            "if (a) {} else { // comment\n if (b) {} }",
+            None,
+        ),
+        ("if (a) {} else { if (b) foo(); }", "if (a) {} else if (b) foo();", None),
+        ("if (a) {} else { if (b) foo() }\nbar()", "if (a) {} else if (b) foo()\nbar()", None),
+        ("if (a) {} else { if (b) foo() }\n(bar)", "if (a) {} else { if (b) foo() }\n(bar)", None),
+    ];
+
+    Tester::new(NoLonelyIf::NAME, pass, fail)
+        .with_plugin_name("eslint")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
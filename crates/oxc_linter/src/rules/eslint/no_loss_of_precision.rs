@@ -1,5 +1,5 @@
 use oxc_ast::ast::NumericLiteral;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -38,6 +38,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoLossOfPrecision {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NumericLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::NumericLiteral(node) if Self::lose_precision(node) => {
@@ -0,0 +1,319 @@
+use oxc_ast::{
+    ast::{Argument, MemberExpression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoMagicNumbersDiagnostic {
+    #[error("eslint(no-magic-numbers): No magic number: {0}.")]
+    #[diagnostic(severity(warning), help("Move the number into a named constant"))]
+    NoMagic(String, #[label] Span),
+    #[error("eslint(no-magic-numbers): Number constants declarations must use 'const'.")]
+    #[diagnostic(severity(warning))]
+    UseConst(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMagicNumbers(Box<NoMagicNumbersConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMagicNumbersConfig {
+    /// Numbers from the `ignore` option
+    ignore: Vec<f64>,
+    /// BigInts from the `ignore` option, e.g. `"-1n"`
+    ignore_bigints: Vec<String>,
+    ignore_array_indexes: bool,
+    ignore_default_values: bool,
+    ignore_class_field_initial_values: bool,
+    enforce_const: bool,
+    detect_objects: bool,
+    ignore_enums: bool,
+    ignore_numeric_literal_types: bool,
+    ignore_readonly_class_properties: bool,
+}
+
+impl std::ops::Deref for NoMagicNumbers {
+    type Target = NoMagicNumbersConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow magic numbers, numbers which are used without being declared as a constant first.
+    ///
+    /// Options:
+    /// * `ignore`: numbers to ignore, BigInts are written as strings like `"1n"`
+    /// * `ignoreArrayIndexes`: allow numbers used as array indexes, e.g. `data[100]`
+    /// * `ignoreDefaultValues`: allow numbers used as default values
+    /// * `ignoreClassFieldInitialValues`: allow numbers used as initial values of class fields
+    /// * `enforceConst`: require numbers to be declared with `const`
+    /// * `detectObjects`: also check numbers assigned to object properties
+    /// * `ignoreEnums`, `ignoreNumericLiteralTypes`, `ignoreReadonlyClassProperties`:
+    ///   allow numbers in TypeScript enums, literal types and `readonly` class properties
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The meaning of a bare number is often unclear to the reader,
+    /// and changing it requires updating every place it is repeated in.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var dutyFreePrice = 100;
+    /// var finalPrice = dutyFreePrice + (dutyFreePrice * 0.25);
+    /// ```
+    NoMagicNumbers,
+    style
+);
+
+impl Rule for NoMagicNumbers {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let ignore = config.get("ignore").and_then(serde_json::Value::as_array);
+        let flag =
+            |name: &str| config.get(name).and_then(serde_json::Value::as_bool).unwrap_or_default();
+        Self(Box::new(NoMagicNumbersConfig {
+            ignore: ignore
+                .map(|ignore| ignore.iter().filter_map(serde_json::Value::as_f64).collect())
+                .unwrap_or_default(),
+            ignore_bigints: ignore
+                .map(|ignore| {
+                    ignore
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .filter(|bigint| bigint.ends_with('n'))
+                        .map(normalize_bigint)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            ignore_array_indexes: flag("ignoreArrayIndexes"),
+            ignore_default_values: flag("ignoreDefaultValues"),
+            ignore_class_field_initial_values: flag("ignoreClassFieldInitialValues"),
+            enforce_const: flag("enforceConst"),
+            detect_objects: flag("detectObjects"),
+            ignore_enums: flag("ignoreEnums"),
+            ignore_numeric_literal_types: flag("ignoreNumericLiteralTypes"),
+            ignore_readonly_class_properties: flag("ignoreReadonlyClassProperties"),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NumericLiteral, AstType::BigintLiteral])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (mut number, mut raw) = match node.kind() {
+            AstKind::NumericLiteral(lit) => (Number::Float(lit.value), lit.raw.to_string()),
+            AstKind::BigintLiteral(lit) => {
+                (Number::BigInt(normalize_bigint(&lit.raw)), lit.raw.to_string())
+            }
+            _ => return,
+        };
+
+        // `-1` is a single number
+        let mut full_number = node;
+        let mut parent = parent_skipping_wrappers(node, ctx);
+        if let Some(unary) = parent.filter(|parent| is_negation(parent)) {
+            number = number.negate();
+            raw = format!("-{raw}");
+            full_number = unary;
+            parent = parent_skipping_wrappers(unary, ctx);
+        }
+        let span = full_number.kind().span();
+
+        let is_ignored = match &number {
+            Number::Float(value) => self.ignore.contains(value),
+            Number::BigInt(value) => self.ignore_bigints.contains(value),
+        };
+        let Some(parent) = parent else {
+            return;
+        };
+        if is_ignored
+            || self.is_ignored_parent(parent, span, &number)
+            || is_parse_int_radix(parent, span)
+        {
+            return;
+        }
+
+        match parent.kind() {
+            AstKind::VariableDeclarator(_) => {
+                let is_const = matches!(
+                    ctx.nodes().parent_kind(parent.id()),
+                    Some(AstKind::VariableDeclaration(decl)) if decl.kind.is_const()
+                );
+                if self.enforce_const && !is_const {
+                    ctx.diagnostic(NoMagicNumbersDiagnostic::UseConst(span));
+                }
+            }
+            AstKind::ObjectExpression(_) | AstKind::ObjectProperty(_) if !self.detect_objects => {}
+            AstKind::AssignmentExpression(expr)
+                if !self.detect_objects && !expr.left.is_identifier() => {}
+            _ => ctx.diagnostic(NoMagicNumbersDiagnostic::NoMagic(raw, span)),
+        }
+    }
+}
+
+impl NoMagicNumbers {
+    fn is_ignored_parent(&self, parent: &AstNode, span: Span, number: &Number) -> bool {
+        match parent.kind() {
+            AstKind::AssignmentPattern(pattern) => {
+                self.ignore_default_values && pattern.right.span() == span
+            }
+            AstKind::PropertyDefinition(prop) => {
+                (self.ignore_class_field_initial_values
+                    || (self.ignore_readonly_class_properties && prop.readonly))
+                    && prop.value.as_ref().is_some_and(|value| value.span() == span)
+            }
+            AstKind::MemberExpression(MemberExpression::ComputedMemberExpression(expr)) => {
+                self.ignore_array_indexes
+                    && expr.expression.span() == span
+                    && number.is_array_index()
+            }
+            AstKind::TSEnumMember(_) => self.ignore_enums,
+            AstKind::TSLiteralType(_) => self.ignore_numeric_literal_types,
+            AstKind::JSXExpressionContainer(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The number of a literal, negated when preceded by `-`
+enum Number {
+    Float(f64),
+    /// Decimal digits followed by `n`, see `normalize_bigint`
+    BigInt(String),
+}
+
+impl Number {
+    fn negate(self) -> Self {
+        match self {
+            Self::Float(value) => Self::Float(-value),
+            Self::BigInt(value) => match value.strip_prefix('-') {
+                Some(value) => Self::BigInt(value.to_string()),
+                None => Self::BigInt(format!("-{value}")),
+            },
+        }
+    }
+
+    /// Whether the number can index an array, i.e. is an integer in `0..2^32 - 1`
+    fn is_array_index(&self) -> bool {
+        const MAX_ARRAY_LENGTH: f64 = 4_294_967_295.0;
+        match self {
+            Self::Float(value) => value.fract() == 0.0 && (0.0..MAX_ARRAY_LENGTH).contains(value),
+            Self::BigInt(value) => !value.starts_with('-'),
+        }
+    }
+}
+
+/// Remove the numeric separators and leading zeros of a decimal BigInt, e.g. `1_000n` is `1000n`.
+/// Hexadecimal, octal and binary BigInts are kept as written.
+fn normalize_bigint(raw: &str) -> String {
+    let raw = raw.replace('_', "").to_ascii_lowercase();
+    let (sign, digits) = raw.strip_prefix('-').map_or(("", raw.as_str()), |digits| ("-", digits));
+    if digits.starts_with("0x") || digits.starts_with("0o") || digits.starts_with("0b") {
+        return raw;
+    }
+    let digits = digits.trim_start_matches('0');
+    if digits == "n" {
+        return String::from("0n");
+    }
+    format!("{sign}{digits}")
+}
+
+/// The parent of `node` ignoring parentheses and the nodes which do not exist in ESTree.
+fn parent_skipping_wrappers<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let mut parent = ctx.nodes().parent_node(node.id())?;
+    while matches!(
+        parent.kind(),
+        AstKind::ParenthesizedExpression(_)
+            | AstKind::Argument(_)
+            | AstKind::ArrayExpressionElement(_)
+            | AstKind::ExpressionArrayElement(_)
+            | AstKind::PropertyKey(_)
+    ) {
+        parent = ctx.nodes().parent_node(parent.id())?;
+    }
+    Some(parent)
+}
+
+fn is_negation(node: &AstNode) -> bool {
+    matches!(node.kind(), AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::UnaryNegation)
+}
+
+/// Whether the number is the radix of `parseInt(value, 10)` or `Number.parseInt(value, 10)`.
+fn is_parse_int_radix(parent: &AstNode, span: Span) -> bool {
+    let AstKind::CallExpression(call) = parent.kind() else {
+        return false;
+    };
+    matches!(call.arguments.get(1), Some(Argument::Expression(radix)) if radix.span() == span)
+        && (call.callee.is_specific_id("parseInt")
+            || call.callee.is_specific_member_access("Number", "parseInt"))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var x = parseInt(y, 10);", None),
+        ("var x = Number.parseInt(y, 10);", None),
+        ("var foo = 42;", None),
+        ("const foo = 42;", Some(json!([{ "enforceConst": true }]))),
+        ("var foo = -42;", None),
+        ("var foo = 0 + 1 - 2 + -2;", Some(json!([{ "ignore": [0, 1, 2, -2] }]))),
+        ("var foo = 10n + -2n;", Some(json!([{ "ignore": ["10n", "-2n"] }]))),
+        ("var foo = 1_000n;", Some(json!([{ "ignore": ["1000n"] }]))),
+        ("var a = <input maxLength={10} />;", None),
+        ("var obj = { prop: 1 }; obj.prop = 2;", None),
+        ("var obj = { 1: 'a' };", None),
+        (
+            "foo[0]; foo[-0]; foo[4294967294]; foo[0n];",
+            Some(json!([{ "ignoreArrayIndexes": true }])),
+        ),
+        ("foo[1] = bar;", Some(json!([{ "ignoreArrayIndexes": true }]))),
+        ("function foo(x = 10) {}", Some(json!([{ "ignoreDefaultValues": true }]))),
+        ("const { x = 2 } = bar;", Some(json!([{ "ignoreDefaultValues": true }]))),
+        (
+            "class C { x = 2; static y = -3; }",
+            Some(json!([{ "ignoreClassFieldInitialValues": true }])),
+        ),
+        ("enum E { A = 1, B = 2 }", Some(json!([{ "ignoreEnums": true }]))),
+        ("type Digit = 1 | 2 | 3;", Some(json!([{ "ignoreNumericLiteralTypes": true }]))),
+        ("class C { readonly x = 2; }", Some(json!([{ "ignoreReadonlyClassProperties": true }]))),
+    ];
+
+    let fail = vec![
+        ("var foo = 42 * 2;", None),
+        ("var foo = 42;", Some(json!([{ "enforceConst": true }]))),
+        ("var foo = -1 + 10n;", Some(json!([{ "ignore": [1] }]))),
+        ("console.log(0x1A + 0x02);", None),
+        ("var stats = { avg: 42 };", Some(json!([{ "detectObjects": true }]))),
+        ("var x; x = 5;", None),
+        ("foo[-1]; foo[1.5]; foo[4294967295];", Some(json!([{ "ignoreArrayIndexes": true }]))),
+        ("foo[0];", None),
+        ("var x = parseInt(y, 10, 8); var z = foo(y, 10);", None),
+        ("function foo(x = 10) {}", None),
+        ("class C { x = 2; }", None),
+        ("function f() { return [1, 2]; }", None),
+    ];
+
+    Tester::new(NoMagicNumbers::NAME, pass, fail).test_and_snapshot();
+}
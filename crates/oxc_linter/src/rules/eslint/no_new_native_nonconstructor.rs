@@ -0,0 +1,84 @@
+use oxc_ast::{ast::Expression, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-new-native-nonconstructor): `{0}` cannot be called as a constructor.")]
+#[diagnostic(severity(warning), help("Call `{0}` as a function instead"))]
+struct NoNewNativeNonconstructorDiagnostic(CompactString, #[label] pub Span);
+
+const NON_CONSTRUCTOR_GLOBALS: [&str; 2] = ["Symbol", "BigInt"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNewNativeNonconstructor;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows `new` operators with global non-constructor functions.
+    ///
+    /// ### Why is this bad?
+    /// `Symbol` and `BigInt` are meant to be called as functions, using them with
+    /// `new` throws a `TypeError`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = new Symbol('foo');
+    /// var bar = new BigInt(9007199254740991);
+    /// ```
+    NoNewNativeNonconstructor,
+    correctness
+);
+
+impl Rule for NoNewNativeNonconstructor {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NewExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::NewExpression(expr) = node.kind() else { return };
+        let Expression::Identifier(ident) = expr.callee.without_parenthesized() else { return };
+        if NON_CONSTRUCTOR_GLOBALS.contains(&ident.name.as_str())
+            && ctx.semantic().is_reference_to_global_variable(ident)
+        {
+            ctx.diagnostic(NoNewNativeNonconstructorDiagnostic(
+                ident.name.to_compact_string(),
+                Span::new(expr.span.start, ident.span.end),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = Symbol('foo');",
+        "function bar(Symbol) { var baz = new Symbol('baz');}",
+        "function Symbol() {} new Symbol();",
+        "new foo(Symbol);",
+        "new foo(bar, Symbol);",
+        "var foo = BigInt(9007199254740991);",
+        "function bar(BigInt) { var baz = new BigInt(9007199254740991);}",
+        "function BigInt() {} new BigInt();",
+        "new foo(BigInt);",
+        "new foo(bar, BigInt);",
+        "{ const BigInt = class {}; new BigInt(); }",
+    ];
+
+    let fail = vec![
+        "var foo = new Symbol('foo');",
+        "function bar() { return function Symbol() {}; } var baz = new Symbol('baz');",
+        "var foo = new BigInt(9007199254740991);",
+        "function bar() { return function BigInt() {}; } var baz = new BigInt(9007199254740991);",
+        "new (BigInt)",
+    ];
+
+    Tester::new(NoNewNativeNonconstructor::NAME, pass, fail).test_and_snapshot();
+}
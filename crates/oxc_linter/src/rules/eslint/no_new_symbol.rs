@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -40,6 +40,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNewSymbol {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NewExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(expr) = node.kind() else { return };
         let Expression::Identifier(ident) = &expr.callee else { return };
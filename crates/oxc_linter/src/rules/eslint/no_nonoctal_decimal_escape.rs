@@ -1,5 +1,5 @@
 use lazy_static::lazy_static;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -51,6 +51,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNonoctalDecimalEscape {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::StringLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StringLiteral(literal) = node.kind() {
             check_string(ctx, literal.span.source_text(ctx.source_text()));
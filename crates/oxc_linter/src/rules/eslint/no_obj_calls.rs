@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, IdentifierReference, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -46,8 +46,8 @@ declare_oxc_lint! {
     /// let json = JSON();
     /// let newJson = new JSON();
     ///
-    /// let atomics = CompactStringics();
-    /// let newCompactStringics = new CompactStringics();
+    /// let atomics = Atomics();
+    /// let newAtomics = new Atomics();
     ///
     /// let intl = Intl();
     /// let newIntl = new Intl();
@@ -58,7 +58,7 @@ declare_oxc_lint! {
     /// // Good
     /// let area = r => 2 * Math.PI * r * r;
     /// let object = JSON.parse("{}");
-    /// let first = CompactStringics.load(sharedArray, 0);
+    /// let first = Atomics.load(sharedArray, 0);
     /// let segmenterFrom = Intl.Segmenter("fr", { granularity: "word" });
     /// ```
     NoObjCalls,
@@ -71,11 +71,16 @@ fn is_global_obj(s: &str) -> bool {
 
 fn global_this_member<'a>(
     expr: &'a oxc_allocator::Box<'_, MemberExpression<'_>>,
+    ctx: &LintContext,
 ) -> Option<&'a str> {
-    if expr.object().is_specific_id(GLOBAL_THIS) {
-        expr.static_property_name()
-    } else {
-        None
+    match expr.object() {
+        Expression::Identifier(ident)
+            if ident.name == GLOBAL_THIS
+                && ctx.semantic().is_reference_to_global_variable(ident) =>
+        {
+            expr.static_property_name()
+        }
+        _ => None,
     }
 }
 
@@ -113,7 +118,7 @@ fn resolve_global_binding<'a, 'b: 'a>(
                             }
                             // handles "let a = globalThis.JSON; let b = a; a();"
                             Some(Expression::MemberExpression(parent_expr)) => {
-                                global_this_member(parent_expr)
+                                global_this_member(parent_expr, ctx)
                             }
                             _ => None,
                         }
@@ -126,6 +131,10 @@ fn resolve_global_binding<'a, 'b: 'a>(
 }
 
 impl Rule for NoObjCalls {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NewExpression, AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         #[allow(clippy::needless_return)]
         let (callee, span) = match node.kind() {
@@ -148,7 +157,7 @@ impl Rule for NoObjCalls {
 
             Expression::MemberExpression(expr) => {
                 // handle new globalThis.Math(), globalThis.Math(), etc
-                if let Some(global_member) = global_this_member(expr) {
+                if let Some(global_member) = global_this_member(expr, ctx) {
                     if is_global_obj(global_member) {
                         ctx.diagnostic(NoObjCallsDiagnostic(global_member.into(), span));
                     }
@@ -184,6 +193,8 @@ fn test() {
         // https://github.com/oxc-project/oxc/pull/508#issuecomment-1618850742
         ("{const Math = () => {}; {let obj = new Math();}}", None),
         ("{const {parse} = JSON;parse('{}')}", None),
+        ("function foo(globalThis) { globalThis.Math(); }", None),
+        ("function foo(JSON) { JSON(); }", None),
     ];
 
     let fail = vec![
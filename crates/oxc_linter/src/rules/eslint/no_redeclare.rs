@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingIdentifier, BindingPatternKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -72,6 +72,10 @@ impl Rule for NoRedeclare {
         Self { built_in_globals }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let redeclare_variables = ctx.semantic().redeclare_variables();
         let symbol_table = ctx.semantic().symbols();
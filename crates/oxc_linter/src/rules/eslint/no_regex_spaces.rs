@@ -1,7 +1,7 @@
 use oxc_allocator::Vec;
 use oxc_ast::{
     ast::{Argument, CallExpression, Expression, NewExpression, RegExpLiteral},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -43,6 +43,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoRegexSpaces {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::RegExpLiteral, AstType::CallExpression, AstType::NewExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::RegExpLiteral(lit) => {
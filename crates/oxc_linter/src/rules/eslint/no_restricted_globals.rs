@@ -0,0 +1,122 @@
+use oxc_ast::AstType;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-restricted-globals): Unexpected use of '{0}'.")]
+#[diagnostic(severity(warning))]
+struct NoRestrictedGlobalsDiagnostic(CompactString, #[label] pub Span, #[help] Option<String>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedGlobals(Box<NoRestrictedGlobalsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedGlobalsConfig {
+    /// Names of the restricted globals, along with their custom message
+    globals: Vec<(CompactString, Option<String>)>,
+}
+
+impl std::ops::Deref for NoRestrictedGlobals {
+    type Target = NoRestrictedGlobalsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows the use of the configured global variables.
+    ///
+    /// ### Why is this bad?
+    /// Some globals are easy to use by mistake, such as the browser's `event` or `name`
+    /// when a local variable or parameter of the same name was meant.
+    ///
+    /// ### Options
+    /// A list of names, or of objects with a `name` and a custom `message`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // "no-restricted-globals": ["error", { "name": "event", "message": "Use the local parameter instead." }]
+    /// function onClick() {
+    ///     console.log(event);
+    /// }
+    /// ```
+    NoRestrictedGlobals,
+    restriction
+);
+
+impl Rule for NoRestrictedGlobals {
+    fn from_configuration(value: Value) -> Self {
+        let globals = value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|option| match option {
+                Value::String(name) => Some((CompactString::from(name.as_str()), None)),
+                Value::Object(object) => Some((
+                    CompactString::from(object.get("name")?.as_str()?),
+                    object.get("message").and_then(Value::as_str).map(ToString::to_string),
+                )),
+                _ => None,
+            })
+            .collect();
+        Self(Box::new(NoRestrictedGlobalsConfig { globals }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let unresolved_references = ctx.scopes().root_unresolved_references();
+        for (name, message) in &self.globals {
+            let Some(reference_ids) = unresolved_references.get(name) else { continue };
+            for &reference_id in reference_ids {
+                let reference = ctx.symbols().get_reference(reference_id);
+                ctx.diagnostic(NoRestrictedGlobalsDiagnostic(
+                    name.clone(),
+                    reference.span(),
+                    message.clone(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("foo", None),
+        ("foo", Some(json!(["bar"]))),
+        ("var foo = 1;", Some(json!(["foo"]))),
+        ("function fn() { var foo; foo; }", Some(json!(["foo"]))),
+        ("function fn(event) { event.preventDefault(); }", Some(json!(["event"]))),
+        ("foo.bar", Some(json!(["bar"]))),
+        ("const { event } = window; event;", Some(json!([{ "name": "event" }]))),
+    ];
+
+    let fail = vec![
+        ("foo", Some(json!(["foo"]))),
+        ("function fn() { foo; }", Some(json!(["foo"]))),
+        ("foo = 1; typeof foo", Some(json!(["foo"]))),
+        ("bar; foo", Some(json!(["foo", "bar"]))),
+        (
+            "function onClick() { console.log(event); }",
+            Some(json!([{ "name": "event", "message": "Use the local parameter instead." }])),
+        ),
+        ("window; event", Some(json!(["window", { "name": "event" }]))),
+    ];
+
+    Tester::new(NoRestrictedGlobals::NAME, pass, fail).test_and_snapshot();
+}
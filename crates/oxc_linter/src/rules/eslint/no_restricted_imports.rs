@@ -0,0 +1,365 @@
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, ModuleDeclaration},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoRestrictedImportsDiagnostic {
+    #[error("eslint(no-restricted-imports): '{0}' import is restricted from being used.")]
+    #[diagnostic(severity(warning))]
+    Path(CompactString, #[label] Span, #[help] Option<String>),
+
+    #[error("eslint(no-restricted-imports): '{1}' import from '{0}' is restricted.")]
+    #[diagnostic(severity(warning))]
+    ImportName(CompactString, CompactString, #[label] Span, #[help] Option<String>),
+
+    #[error("eslint(no-restricted-imports): * import is invalid because '{1}' from '{0}' is restricted.")]
+    #[diagnostic(severity(warning))]
+    EverythingImport(CompactString, CompactString, #[label] Span, #[help] Option<String>),
+
+    #[error(
+        "eslint(no-restricted-imports): '{0}' import is restricted from being used by a pattern."
+    )]
+    #[diagnostic(severity(warning))]
+    Pattern(CompactString, #[label] Span, #[help] Option<String>),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImports(Box<NoRestrictedImportsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImportsConfig {
+    paths: Vec<RestrictedPath>,
+    patterns: Vec<RestrictedPattern>,
+}
+
+impl std::ops::Deref for NoRestrictedImports {
+    type Target = NoRestrictedImportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPath {
+    name: CompactString,
+    message: Option<String>,
+    /// Only these names are restricted if given
+    import_names: Option<Vec<CompactString>>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPattern {
+    /// The group of gitignore-style patterns
+    group: Gitignore,
+    message: Option<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows importing the configured modules.
+    ///
+    /// ### Why is this bad?
+    /// A team may want to keep a deprecated or heavy module out of the code base,
+    /// or point to the preferred replacement of a module.
+    ///
+    /// ### Options
+    /// A list of module names, or of objects with a `name`, a custom `message`,
+    /// and `importNames` to only restrict some of the exports of the module.
+    ///
+    /// Alternatively an object with these `paths`, and `patterns` of gitignore-style globs,
+    /// given either as strings, or as objects with a `group` of globs and a custom `message`.
+    /// Patterns are case insensitive unless `caseSensitive` is set.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // "no-restricted-imports": ["error", { "paths": ["fs"], "patterns": ["lodash/*"] }]
+    /// import fs from 'fs';
+    /// import pick from 'lodash/pick';
+    /// ```
+    NoRestrictedImports,
+    restriction
+);
+
+impl Rule for NoRestrictedImports {
+    fn from_configuration(value: Value) -> Self {
+        let options = value.as_array().map_or(&[][..], Vec::as_slice);
+        let config = match options.first() {
+            Some(Value::Object(object))
+                if object.contains_key("paths") || object.contains_key("patterns") =>
+            {
+                let paths =
+                    object.get("paths").and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+                let patterns =
+                    object.get("patterns").and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+                NoRestrictedImportsConfig {
+                    paths: paths.iter().filter_map(RestrictedPath::from_configuration).collect(),
+                    patterns: RestrictedPattern::from_configuration(patterns),
+                }
+            }
+            _ => NoRestrictedImportsConfig {
+                paths: options.iter().filter_map(RestrictedPath::from_configuration).collect(),
+                patterns: vec![],
+            },
+        };
+        Self(Box::new(config))
+    }
+
+    /// The imports and the re-exports, `require` calls are not checked as by ESLint
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ModuleDeclaration])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ModuleDeclaration(declaration) = node.kind() else { return };
+        let (source, span) = match declaration {
+            ModuleDeclaration::ImportDeclaration(import) => (&import.source, import.span),
+            ModuleDeclaration::ExportNamedDeclaration(export) => {
+                let Some(source) = &export.source else { return };
+                (source, export.span)
+            }
+            ModuleDeclaration::ExportAllDeclaration(export) => (&export.source, export.span),
+            _ => return,
+        };
+        let source_name = source.value.as_str();
+
+        for path in self.paths.iter().filter(|path| path.name == source_name) {
+            let Some(import_names) = &path.import_names else {
+                ctx.diagnostic(NoRestrictedImportsDiagnostic::Path(
+                    path.name.clone(),
+                    span,
+                    path.message.clone(),
+                ));
+                continue;
+            };
+            for (name, span) in imported_names(declaration) {
+                if name == "*" {
+                    ctx.diagnostic(NoRestrictedImportsDiagnostic::EverythingImport(
+                        path.name.clone(),
+                        import_names.join(", ").into(),
+                        span,
+                        path.message.clone(),
+                    ));
+                } else if import_names.iter().any(|import_name| import_name.as_str() == name) {
+                    ctx.diagnostic(NoRestrictedImportsDiagnostic::ImportName(
+                        path.name.clone(),
+                        name.into(),
+                        span,
+                        path.message.clone(),
+                    ));
+                }
+            }
+        }
+
+        for pattern in &self.patterns {
+            if pattern.is_match(source_name) {
+                ctx.diagnostic(NoRestrictedImportsDiagnostic::Pattern(
+                    source_name.into(),
+                    span,
+                    pattern.message.clone(),
+                ));
+            }
+        }
+    }
+}
+
+/// The imported or re-exported names of `declaration`, `*` for namespace imports and `export * from`
+fn imported_names<'a>(declaration: &'a ModuleDeclaration<'a>) -> Vec<(&'a str, Span)> {
+    match declaration {
+        ModuleDeclaration::ImportDeclaration(import) => import
+            .specifiers
+            .iter()
+            .flatten()
+            .map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    (specifier.imported.name().as_str(), specifier.span)
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    ("default", specifier.span)
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    ("*", specifier.span)
+                }
+            })
+            .collect(),
+        ModuleDeclaration::ExportNamedDeclaration(export) => export
+            .specifiers
+            .iter()
+            .map(|specifier| (specifier.local.name().as_str(), specifier.span))
+            .collect(),
+        ModuleDeclaration::ExportAllDeclaration(export) => vec![("*", export.span)],
+        _ => vec![],
+    }
+}
+
+impl RestrictedPath {
+    fn from_configuration(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(name) => {
+                Some(Self { name: name.as_str().into(), message: None, import_names: None })
+            }
+            Value::Object(object) => Some(Self {
+                name: object.get("name")?.as_str()?.into(),
+                message: object.get("message").and_then(Value::as_str).map(ToString::to_string),
+                import_names: object.get("importNames").and_then(Value::as_array).map(|names| {
+                    names.iter().filter_map(Value::as_str).map(CompactString::from).collect()
+                }),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl RestrictedPattern {
+    /// Plain strings are combined into one group
+    fn from_configuration(values: &[Value]) -> Vec<Self> {
+        let strings = values.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+        let mut patterns = Self::new(&strings, None, false).into_iter().collect::<Vec<_>>();
+        patterns.extend(values.iter().filter_map(Value::as_object).filter_map(|object| {
+            let group = object.get("group")?.as_array()?;
+            let group = group.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+            let message = object.get("message").and_then(Value::as_str).map(ToString::to_string);
+            let case_sensitive =
+                object.get("caseSensitive").and_then(Value::as_bool).unwrap_or_default();
+            Self::new(&group, message, case_sensitive)
+        }));
+        patterns
+    }
+
+    /// `None` if `group` is empty or a glob is invalid
+    fn new(group: &[&str], message: Option<String>, case_sensitive: bool) -> Option<Self> {
+        if group.is_empty() {
+            return None;
+        }
+        let mut builder = GitignoreBuilder::new("");
+        builder.case_insensitive(!case_sensitive).ok()?;
+        for glob in group {
+            builder.add_line(None, glob).ok()?;
+        }
+        Some(Self { group: builder.build().ok()?, message })
+    }
+
+    fn is_match(&self, source: &str) -> bool {
+        let source = source.trim_start_matches('/');
+        !source.is_empty() && self.group.matched_path_or_any_parents(source, false).is_ignore()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import os from 'os';", None),
+        ("import os from 'os';", Some(json!(["osx"]))),
+        ("import fs from 'fs';", Some(json!(["crypto"]))),
+        ("import path from 'path';", Some(json!(["crypto", "stream", "os"]))),
+        ("import async from 'async';", Some(json!(["asyncx"]))),
+        ("import 'foo'", Some(json!(["crypto"]))),
+        ("import 'foo/bar';", Some(json!(["foo"]))),
+        ("import withPaths from 'foo/bar';", Some(json!([{ "paths": ["foo", "bar"] }]))),
+        ("import withPatterns from 'foo/bar';", Some(json!([{ "patterns": ["foo/c*"] }]))),
+        ("import foo from 'foo';", Some(json!(["../foo"]))),
+        (
+            "import withPatternsAndNegation from 'foo/bar';",
+            Some(json!([{ "patterns": ["foo/*", "!foo/bar"] }])),
+        ),
+        (
+            "import { AllowedObject } from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "export { AllowedObject } from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        ("export * from 'foo';", Some(json!(["bar"]))),
+        ("export const foo = 1;", Some(json!(["foo"]))),
+        (
+            "import Foo from 'foo';",
+            Some(json!([{ "patterns": [{ "group": ["Foo"], "caseSensitive": true }] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("import 'fs'", Some(json!(["fs"]))),
+        ("import os from 'os';", Some(json!(["fs", "crypto", "stream", "os"]))),
+        ("import 'foo/bar';", Some(json!(["foo/bar"]))),
+        ("import withPaths from 'foo/bar';", Some(json!([{ "paths": ["foo/bar"] }]))),
+        ("import withPatterns from 'foo/bar';", Some(json!([{ "patterns": ["foo"] }]))),
+        ("import withPatterns from 'foo/bar';", Some(json!([{ "patterns": ["bar"] }]))),
+        (
+            "import withPatterns from 'foo/baz';",
+            Some(json!([{ "patterns": ["foo/*", "!foo/bar"] }])),
+        ),
+        ("import Foo from 'foo';", Some(json!([{ "patterns": ["FOO"] }]))),
+        (
+            "import withPatterns from 'foo/bar';",
+            Some(json!([{
+                "patterns": [{ "group": ["foo/*", "!foo/baz"], "message": "foo is forbidden, use bar instead" }]
+            }])),
+        ),
+        (
+            "import withGitignores from 'foo/bar';",
+            Some(json!([{ "patterns": ["foo/*", "!foo/baz"] }])),
+        ),
+        ("export * from 'fs';", Some(json!(["fs"]))),
+        ("export { a } from 'fs';", Some(json!(["fs"]))),
+        (
+            "import withPaths from 'foo/bar';",
+            Some(
+                json!([{ "paths": [{ "name": "foo/bar", "message": "Please import from 'bar' instead." }] }]),
+            ),
+        ),
+        (
+            "import withPaths from 'foo/bar';",
+            Some(json!([{ "name": "foo/bar", "message": "Please import from 'bar' instead." }])),
+        ),
+        (
+            "import { DisallowedObject } from 'foo';",
+            Some(json!([{
+                "paths": [{
+                    "name": "foo",
+                    "importNames": ["DisallowedObject"],
+                    "message": "Please import 'DisallowedObject' from /bar/ instead."
+                }]
+            }])),
+        ),
+        (
+            "import { AllowedObject, DisallowedObject as Bar } from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import DisallowedObject from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["default"] }] }])),
+        ),
+        (
+            "import * as All from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "export * from 'foo';",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject", "DisallowedObjectTwo"] }] }]),
+            ),
+        ),
+        (
+            "export { DisallowedObject } from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        ("import absolute from '/foo/bar';", Some(json!([{ "patterns": ["foo"] }]))),
+    ];
+
+    Tester::new(NoRestrictedImports::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,152 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, selector::Selector, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-restricted-syntax): {0}")]
+#[diagnostic(severity(warning))]
+struct NoRestrictedSyntaxDiagnostic(CompactString, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restrictions: Vec<RestrictedSyntax>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RestrictedSyntax {
+    selector: Selector,
+    message: CompactString,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows the syntax matched by the configured selectors.
+    ///
+    /// ### Why is this bad?
+    /// Teams can ban patterns which are specific to their code base, such as focused tests,
+    /// without writing a rule of their own.
+    ///
+    /// ### Options
+    /// A list of selectors, or of objects with a `selector` and a custom `message`.
+    /// Selectors are a subset of [esquery](https://github.com/estools/esquery):
+    /// node types, `*`, attributes (`[attr]`, `[attr="value"]`, `[attr!=value]`, `[attr=/regex/]`),
+    /// `:not(...)`, `:matches(...)`, and the descendant and child (`>`) combinators.
+    /// Node types are the names of oxc's AST nodes, `Identifier` and `Literal` match any
+    /// kind of identifier and literal.
+    /// Selectors which cannot be parsed are errors of the configuration file.
+    ///
+    /// ### Example
+    /// ```json
+    /// "no-restricted-syntax": ["error", "WithStatement", {
+    ///     "selector": "CallExpression[callee.name=\"fdescribe\"]",
+    ///     "message": "Focused tests are not allowed."
+    /// }]
+    /// ```
+    NoRestrictedSyntax,
+    restriction
+);
+
+/// The selector and the custom message of an option
+fn restriction_option(option: &Value) -> Option<(&str, Option<&str>)> {
+    match option {
+        Value::String(source) => Some((source.as_str(), None)),
+        Value::Object(object) => {
+            Some((object.get("selector")?.as_str()?, object.get("message").and_then(Value::as_str)))
+        }
+        _ => None,
+    }
+}
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        let restrictions = value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|option| {
+                let (source, message) = restriction_option(option)?;
+                // Invalid selectors are reported by `validate_configuration`
+                let selector = Selector::parse(source).ok()?;
+                let message = message.map_or_else(
+                    || CompactString::from(format!("Using '{source}' is not allowed.")),
+                    CompactString::from,
+                );
+                Some(RestrictedSyntax { selector, message })
+            })
+            .collect();
+        Self(Box::new(NoRestrictedSyntaxConfig { restrictions }))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        let options = value.as_array().into_iter().flatten();
+        options.filter_map(restriction_option).try_for_each(|(source, _)| {
+            Selector::parse(source)
+                .map(|_| ())
+                .map_err(|error| format!("invalid selector {source:?}: {error}"))
+        })
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        for restriction in &self.0.restrictions {
+            if restriction.selector.matches(node, ctx.nodes()) {
+                ctx.diagnostic(NoRestrictedSyntaxDiagnostic(
+                    restriction.message.clone(),
+                    node.kind().span(),
+                ));
+            }
+        }
+    }
+}
+
+// Most of the cases use attribute selectors, which need the `estree` feature
+#[cfg(feature = "estree")]
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("with (foo) {}", None),
+        ("doSomething();", Some(json!(["WithStatement"]))),
+        ("fdescribe('foo', () => {});", Some(json!(["CallExpression[callee.name=\"describe\"]"]))),
+        ("foo.fdescribe();", Some(json!(["CallExpression[callee.name=\"fdescribe\"]"]))),
+        ("const foo = 'bar';", Some(json!(["VariableDeclaration[kind=\"let\"]"]))),
+        ("foo();", Some(json!(["CallExpression:not([callee.name=\"foo\"])"]))),
+        ("for (const x of y) {}", Some(json!([":matches(ForInStatement, WhileStatement)"]))),
+        ("function foo() { return 1 }", Some(json!(["Program > ReturnStatement"]))),
+        ("foo('bar');", Some(json!(["Literal[value=/^baz/]"]))),
+    ];
+
+    let fail = vec![
+        ("with (foo) {}", Some(json!(["WithStatement"]))),
+        ("var foo = 41; with (foo) {}", Some(json!(["VariableDeclaration", "WithStatement"]))),
+        ("fdescribe('foo', () => {});", Some(json!(["CallExpression[callee.name=\"fdescribe\"]"]))),
+        (
+            "fdescribe('foo', () => {});",
+            Some(json!([{
+                "selector": "CallExpression[callee.name='fdescribe']",
+                "message": "Focused tests are not allowed."
+            }])),
+        ),
+        ("let foo = 'bar';", Some(json!(["VariableDeclaration[kind=\"let\"]"]))),
+        ("bar();", Some(json!(["CallExpression:not([callee.name=\"foo\"])"]))),
+        ("for (const x in y) {}", Some(json!([":matches(ForInStatement, WhileStatement)"]))),
+        ("function foo() { if (a) { return 1 } }", Some(json!(["FunctionBody ReturnStatement"]))),
+        ("function foo() { return 1 }", Some(json!(["FunctionBody > ReturnStatement"]))),
+        ("foo('bar');", Some(json!(["Literal[value=/^BA/i]"]))),
+        ("foo(1);", Some(json!(["CallExpression > Literal[value=1]"]))),
+        ("foo = bar", Some(json!(["Identifier[name=bar]"]))),
+        ("async function foo() {}", Some(json!(["Function[async=true]"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, pass, fail).test_and_snapshot();
+}
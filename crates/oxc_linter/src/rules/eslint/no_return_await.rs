@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -35,13 +35,17 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoReturnAwait {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::AwaitExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::AwaitExpression(await_expr) = node.kind() {
             if is_in_tail_call_position(node, ctx) && !has_error_handler(node, ctx) {
                 let start = await_expr.span.start;
                 let end = start + 5;
                 let await_keyword_span = Span::new(start, end);
-                ctx.diagnostic_with_fix(NoReturnAwaitDiagnostic(await_keyword_span), || {
+                ctx.diagnostic_with_suggestion(NoReturnAwaitDiagnostic(await_keyword_span), || {
                     Fix::new("", await_keyword_span)
                 });
             }
@@ -5,7 +5,7 @@ use oxc_ast::{
         AssignmentTargetPattern, AssignmentTargetProperty, ChainElement, ChainExpression,
         Expression, MemberExpression, ObjectProperty, ObjectPropertyKind, SimpleAssignmentTarget,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -63,6 +63,10 @@ impl Rule for NoSelfAssign {
         }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::AssignmentExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::AssignmentExpression(assignment) = node.kind() else { return };
         if matches!(
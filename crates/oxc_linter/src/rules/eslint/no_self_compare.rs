@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -42,6 +42,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSelfCompare {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(binary_expr) = node.kind() else { return };
         if !binary_expr.operator.is_compare() && !binary_expr.operator.is_equality() {
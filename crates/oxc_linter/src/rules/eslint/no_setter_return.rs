@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -40,6 +40,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSetterReturn {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ReturnStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ReturnStatement(stmt) = node.kind() else { return };
         if stmt.argument.is_some() && ctx.scopes().get_flags(node.scope_id()).is_set_accessor() {
@@ -0,0 +1,269 @@
+use oxc_ast::{
+    ast::{ClassType, FunctionType},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{CompactString, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoShadowDiagnostic {
+    #[error("eslint(no-shadow): '{0}' is already declared in the upper scope.")]
+    #[diagnostic(severity(warning))]
+    Shadowed(
+        CompactString,
+        #[label("'{0}' is declared here")] Span,
+        #[label("'{0}' is shadowed here")] Span,
+    ),
+
+    #[error("eslint(no-shadow): '{0}' is already a global variable.")]
+    #[diagnostic(severity(warning))]
+    Global(CompactString, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Hoist {
+    /// Report shadowing before the outer functions are declared
+    #[default]
+    Functions,
+    /// Report shadowing before any outer variable is declared
+    All,
+    /// Never report shadowing before the outer variable is declared
+    Never,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadow(Box<NoShadowConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadowConfig {
+    /// Also report shadowing of built-in globals such as `Object`
+    builtin_globals: bool,
+    hoist: Hoist,
+    /// Names which are allowed to shadow
+    allow: Vec<CompactString>,
+}
+
+impl std::ops::Deref for NoShadow {
+    type Target = NoShadowConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows variable declarations from shadowing variables declared in an outer scope.
+    ///
+    /// ### Why is this bad?
+    /// Shadowing makes it impossible to access the outer variable, and it is easy to confuse
+    /// which of the two variables is used.
+    ///
+    /// ### Options
+    /// * `builtinGlobals`: also report shadowing of built-in globals, defaults to `false`.
+    /// * `hoist`: report shadowing of outer variables declared later, `"functions"` (default) only
+    ///   for function declarations, `"all"` for all variables, `"never"` for none.
+    /// * `allow`: names which are allowed to shadow.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var a = 3;
+    /// function b() {
+    ///     var a = 10;
+    /// }
+    /// ```
+    NoShadow,
+    pedantic
+);
+
+impl Rule for NoShadow {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let hoist = match config.and_then(|v| v.get("hoist")).and_then(Value::as_str) {
+            Some("all") => Hoist::All,
+            Some("never") => Hoist::Never,
+            _ => Hoist::Functions,
+        };
+        Self(Box::new(NoShadowConfig {
+            builtin_globals: config
+                .and_then(|v| v.get("builtinGlobals"))
+                .and_then(Value::as_bool)
+                .unwrap_or_default(),
+            hoist,
+            allow: config
+                .and_then(|v| v.get("allow"))
+                .and_then(Value::as_array)
+                .map(|names| {
+                    names.iter().filter_map(Value::as_str).map(CompactString::from).collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let scopes = ctx.scopes();
+        let name = symbols.get_name(symbol_id);
+        if self.allow.iter().any(|allowed| allowed.as_str() == name) {
+            return;
+        }
+        let span = symbols.get_span(symbol_id);
+        // `var` declarations are also bound in the enclosing function scope
+        let shadowed = scopes
+            .ancestors(symbols.get_scope_id(symbol_id))
+            .skip(1)
+            .find_map(|scope_id| scopes.get_binding(scope_id, name).filter(|id| *id != symbol_id));
+        if let Some(shadowed_id) = shadowed {
+            if !self.is_allowed_shadow(symbol_id, shadowed_id, ctx) {
+                ctx.diagnostic(NoShadowDiagnostic::Shadowed(
+                    name.into(),
+                    symbols.get_span(shadowed_id),
+                    span,
+                ));
+            }
+        } else if self.builtin_globals && ctx.env_contains_var(name) {
+            // Top level bindings of modules are not in the global scope
+            let is_global_scope = symbols.get_scope_id(symbol_id) == scopes.root_scope_id()
+                && ctx.source_type().is_module();
+            if !is_global_scope {
+                ctx.diagnostic(NoShadowDiagnostic::Global(name.into(), span));
+            }
+        }
+    }
+}
+
+impl NoShadow {
+    fn is_allowed_shadow(
+        &self,
+        symbol_id: SymbolId,
+        shadowed_id: SymbolId,
+        ctx: &LintContext,
+    ) -> bool {
+        let symbols = ctx.symbols();
+        let span = symbols.get_span(symbol_id);
+        let shadowed_span = symbols.get_span(shadowed_id);
+        let declaration_id = symbols.get_declaration(symbol_id);
+        let declaration = ctx.nodes().kind(declaration_id);
+        let shadowed_declaration = ctx.nodes().kind(symbols.get_declaration(shadowed_id));
+
+        // Parameters of functions without a body, `declare function f(a: number): void` and overloads
+        if matches!(declaration, AstKind::FormalParameter(_)) {
+            let function =
+                ctx.nodes().iter_parents(declaration_id).find_map(|node| match node.kind() {
+                    AstKind::Function(function) => Some(function),
+                    _ => None,
+                });
+            if function.is_some_and(|function| function.body.is_none()) {
+                return true;
+            }
+        }
+
+        // `var a = function a() {}` and `var A = class A {}`
+        let is_expression_name = match declaration {
+            AstKind::Function(function) => {
+                function.r#type == FunctionType::FunctionExpression
+                    && function.id.as_ref().is_some_and(|id| id.span == span)
+            }
+            AstKind::Class(class) => {
+                class.r#type == ClassType::ClassExpression
+                    && class.id.as_ref().is_some_and(|id| id.span == span)
+            }
+            _ => false,
+        };
+        if is_expression_name {
+            if let AstKind::VariableDeclarator(declarator) = shadowed_declaration {
+                let init = declarator.init.as_ref().map(GetSpan::span);
+                if init.is_some_and(|init| init.start <= span.start && span.end <= init.end) {
+                    return true;
+                }
+            }
+        }
+
+        // The outer variable is declared after the shadowing one
+        if span.end < shadowed_span.start {
+            let is_function_declaration = matches!(
+                shadowed_declaration,
+                AstKind::Function(function) if function.r#type == FunctionType::FunctionDeclaration
+            );
+            return match self.hoist {
+                Hoist::All => false,
+                Hoist::Functions => !is_function_declaration,
+                Hoist::Never => true,
+            };
+        }
+        false
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "var a=3; function b(x) { a++; return x + a; }; setTimeout(function() { b(a); }, 0);",
+            None,
+        ),
+        ("(function() { var doSomething = function doSomething() {}; doSomething() }())", None),
+        ("var arguments;\nfunction bar() { }", None),
+        ("var a=3; var b = (x) => { a++; return x + a; }; setTimeout(() => { b(a); }, 0);", None),
+        ("class A {}", None),
+        ("class A { constructor() { var a; } }", None),
+        ("(function() { var A = class A {}; })()", None),
+        ("{ var a; } var a;", None),
+        ("{ let a; } let a;", None),
+        ("{ let a; } var a;", None),
+        ("function foo() { let a; } let a;", None),
+        ("function foo() { var a; } var a;", None),
+        ("function foo(a) { } var a;", None),
+        ("function foo() { var Object = 0; }", None),
+        ("function foo() { var top = 0; }", None),
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["cb"] }])),
+        ),
+        ("function foo() { var a; } var a;", Some(json!([{ "hoist": "never" }]))),
+        ("function foo() { var a; } function a() {}", Some(json!([{ "hoist": "never" }]))),
+        ("function foo() { var a; } var a;", Some(json!([{ "hoist": "functions" }]))),
+        ("const x = 1; type F = (x: number) => void;", None),
+        ("const x = 1; declare function foo(x: number): void;", None),
+        ("const a = 1; function foo(a: string): void; function foo(b: any) {}", None),
+    ];
+
+    let fail = vec![
+        ("function a(x) { var b = function c() { var x = 'foo'; }; }", None),
+        ("var a = (x) => { var b = () => { var x = 'foo'; }; }", None),
+        ("function a(x) { var b = function () { var x = 'foo'; }; }", None),
+        ("var x = 1; function a(x) { return ++x; }", None),
+        ("var a=3; function b() { var a=10; }", None),
+        ("var a=3; function b() { var a=10; }; setTimeout(function() { b(); }, 0);", None),
+        ("var a=3; function b() { var a=10; var b=0; }; setTimeout(function() { b(); }, 0);", None),
+        ("var x = 1; { let x = 2; }", None),
+        ("let x = 1; { const x = 2; }", None),
+        ("{ let a; } function a() {}", Some(json!([{ "hoist": "functions" }]))),
+        ("function foo() { var a; } var a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { var a; } function a() {}", None),
+        ("let x = 1; { const x = 2; }", Some(json!([{ "allow": ["y"] }]))),
+        ("function foo() { var Object = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+        ("try {} catch (e) { function f() { try {} catch (e) {} } }", None),
+        ("var a = function() { var a; }", None),
+        ("import { a } from 'a'; function foo() { var a; }", None),
+        ("type T = string; function foo<T>() {}", None),
+    ];
+
+    Tester::new(NoShadow::NAME, pass, fail).test_and_snapshot();
+}
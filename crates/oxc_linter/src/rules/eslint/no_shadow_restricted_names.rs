@@ -1,13 +1,11 @@
-use oxc_ast::{
-    ast::{AssignmentTarget, BindingPattern, Expression, SimpleAssignmentTarget},
-    AstKind,
-};
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{Atom, CompactString, Span};
+use oxc_semantic::{Reference, SymbolId};
+use oxc_span::{CompactString, GetSpan, Span};
 
 use crate::{context::LintContext, globals::PRE_DEFINE_VAR, rule::Rule};
 
@@ -41,117 +39,54 @@ declare_oxc_lint!(
     correctness
 );
 
-fn binding_pattern_is_global_obj<'a>(pat: &BindingPattern<'a>) -> Option<(Atom<'a>, Span)> {
-    match &pat.kind {
-        oxc_ast::ast::BindingPatternKind::BindingIdentifier(boxed_bind_identifier) => {
-            if PRE_DEFINE_VAR.contains_key(boxed_bind_identifier.name.as_str()) {
-                return Some((boxed_bind_identifier.name.clone(), boxed_bind_identifier.span));
-            }
-            None
-        }
-        oxc_ast::ast::BindingPatternKind::ObjectPattern(boxed_obj_pat) => {
-            let properties = &boxed_obj_pat.properties;
-            for property in properties {
-                if let Some(value) = binding_pattern_is_global_obj(&property.value) {
-                    return Some(value);
-                }
-            }
-            boxed_obj_pat
-                .rest
-                .as_ref()
-                .and_then(|boxed_rest| binding_pattern_is_global_obj(&boxed_rest.argument))
-        }
-        oxc_ast::ast::BindingPatternKind::ArrayPattern(boxed_arr_pat) => {
-            for element in &boxed_arr_pat.elements {
-                if let Some(value) = element.as_ref().and_then(binding_pattern_is_global_obj) {
-                    return Some(value);
-                }
-            }
-            boxed_arr_pat
-                .rest
-                .as_ref()
-                .and_then(|boxed_rest| binding_pattern_is_global_obj(&boxed_rest.argument))
-        }
-        oxc_ast::ast::BindingPatternKind::AssignmentPattern(boxed_assign_pat) => {
-            binding_pattern_is_global_obj(&boxed_assign_pat.left)
-        }
+impl Rule for NoShadowRestrictedNames {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
     }
-}
 
-#[inline]
-fn check_and_diagnostic(s: &str, span: Span, ctx: &LintContext) {
-    if PRE_DEFINE_VAR.contains_key(s) {
-        ctx.diagnostic(NoShadowRestrictedNamesDiagnostic(s.into(), span));
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let declarations =
+            symbols.iter().map(|symbol_id| (symbol_id, symbols.get_span(symbol_id))).chain(
+                ctx.semantic()
+                    .redeclare_variables()
+                    .iter()
+                    .map(|variable| (variable.symbol_id, variable.span)),
+            );
+        let mut restricted = declarations
+            .filter(|(symbol_id, span)| {
+                PRE_DEFINE_VAR.contains_key(symbols.get_name(*symbol_id))
+                    && !is_safe_undefined(*symbol_id, *span, ctx)
+            })
+            .collect::<Vec<_>>();
+        // Report in source order
+        restricted.sort_unstable_by_key(|(_, span)| span.start);
+        for (symbol_id, span) in restricted {
+            ctx.diagnostic(NoShadowRestrictedNamesDiagnostic(
+                symbols.get_name(symbol_id).into(),
+                span,
+            ));
+        }
     }
 }
 
-impl Rule for NoShadowRestrictedNames {
-    fn run_once(&self, ctx: &LintContext<'_>) {
-        let mut nearest_span: Option<Span> = None;
-        for node in ctx.nodes().iter() {
-            let kind = node.kind();
-            match kind {
-                AstKind::VariableDeclarator(decl) => {
-                    if let Some((atom, span)) = binding_pattern_is_global_obj(&decl.id) {
-                        if atom.as_str() != "undefined" || decl.init.is_some() {
-                            ctx.diagnostic(NoShadowRestrictedNamesDiagnostic(
-                                atom.to_compact_string(),
-                                span,
-                            ));
-                        } else {
-                            nearest_span = Some(span);
-                        }
-                    }
-                }
-                AstKind::ExpressionStatement(expr_stat) => {
-                    if let Expression::AssignmentExpression(assign_expr) = &expr_stat.expression {
-                        let left = &assign_expr.left;
-                        match left {
-                            AssignmentTarget::SimpleAssignmentTarget(
-                                SimpleAssignmentTarget::AssignmentTargetIdentifier(ati),
-                            ) if ati.name == "undefined" => {
-                                if let Some(span) = nearest_span {
-                                    ctx.diagnostic(NoShadowRestrictedNamesDiagnostic(
-                                        ati.name.to_compact_string(),
-                                        span,
-                                    ));
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-                AstKind::Function(function) => {
-                    if let Some(bind_ident) = function.id.as_ref() {
-                        check_and_diagnostic(bind_ident.name.as_str(), bind_ident.span, ctx);
-                    }
-                }
-                AstKind::FormalParameter(param) => {
-                    if let Some(value) = binding_pattern_is_global_obj(&param.pattern) {
-                        ctx.diagnostic(NoShadowRestrictedNamesDiagnostic(
-                            value.0.to_compact_string(),
-                            value.1,
-                        ));
-                    }
-                }
-                AstKind::Class(class_decl) => {
-                    if let Some(bind_ident) = class_decl.id.as_ref() {
-                        check_and_diagnostic(bind_ident.name.as_str(), bind_ident.span, ctx);
-                    }
-                }
-                AstKind::CatchClause(catch_clause) => {
-                    if let Some(param) = catch_clause.param.as_ref() {
-                        if let Some(value) = binding_pattern_is_global_obj(param) {
-                            ctx.diagnostic(NoShadowRestrictedNamesDiagnostic(
-                                value.0.to_compact_string(),
-                                value.1,
-                            ));
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+/// `var undefined;` keeps the value `undefined` unless it is initialized or assigned
+fn is_safe_undefined(symbol_id: SymbolId, span: Span, ctx: &LintContext<'_>) -> bool {
+    let symbols = ctx.symbols();
+    if symbols.get_name(symbol_id) != "undefined"
+        || symbols.get_resolved_references(symbol_id).any(Reference::is_write)
+    {
+        return false;
+    }
+    let is_uninitialized = |kind| {
+        matches!(kind, AstKind::VariableDeclarator(declarator)
+            if declarator.id.span() == span && declarator.init.is_none())
+    };
+    if span == symbols.get_span(symbol_id) {
+        is_uninitialized(ctx.nodes().kind(symbols.get_declaration(symbol_id)))
+    } else {
+        // Redeclarations have no declaration node of their own
+        ctx.nodes().iter().any(|node| is_uninitialized(node.kind()))
     }
 }
 
@@ -227,6 +162,9 @@ fn test() {
         ("class undefined { }", None),
         ("class foo { undefined(undefined) { } }", None),
         ("class foo { #undefined(undefined) { } }", None),
+        ("import { undefined } from 'foo';", None),
+        ("var undefined; var undefined = 1;", None),
+        ("var undefined; for (undefined of [1]) {}", None),
     ];
 
     Tester::new(NoShadowRestrictedNames::NAME, pass, fail).test_and_snapshot();
@@ -1,5 +1,5 @@
 use miette::{miette, LabeledSpan};
-use oxc_ast::{ast::ArrayExpressionElement, AstKind};
+use oxc_ast::{ast::ArrayExpressionElement, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic, Severity},
     thiserror::Error,
@@ -36,6 +36,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSparseArrays {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ArrayExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ArrayExpression(array_expr) = node.kind() {
             let violations = array_expr
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use oxc_ast::{
     ast::{Expression, MethodDefinitionKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -54,6 +54,10 @@ enum DefinitelyCallsThisBeforeSuper {
 }
 
 impl Rule for NoThisBeforeSuper {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let semantic = ctx.semantic();
         let cfg = semantic.cfg();
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -47,6 +47,10 @@ impl Rule for NoUndef {
             .unwrap_or_default();
         Self { type_of }
     }
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let symbol_table = ctx.symbols();
 
@@ -54,12 +58,12 @@ impl Rule for NoUndef {
             for &reference_id in reference_id_list {
                 let reference = symbol_table.get_reference(reference_id);
                 if ctx.env_contains_var(reference.name().as_str()) {
-                    return;
+                    continue;
                 }
 
                 let node = ctx.nodes().get_node(reference.node_id());
                 if !self.type_of && has_typeof_operator(node, ctx) {
-                    return;
+                    continue;
                 }
 
                 ctx.diagnostic(NoUndefDiagnostic(reference.name().clone(), reference.span()));
@@ -78,6 +82,8 @@ fn has_typeof_operator(node: &AstNode<'_>, ctx: &LintContext<'_>) -> bool {
 
 #[test]
 fn test() {
+    use std::path::PathBuf;
+
     use crate::tester::Tester;
 
     let pass = vec![
@@ -186,4 +192,28 @@ fn test() {
     ];
 
     Tester::new(NoUndef::NAME, pass, fail).test_and_snapshot();
+
+    let pass = vec![
+        (
+            "<script setup>const props = defineProps({ a: String });</script>",
+            None,
+            None,
+            Some(PathBuf::from("component.vue")),
+        ),
+        (
+            "<script lang=\"ts\">let { a } = $props(); let b = $state(a);</script>",
+            None,
+            None,
+            Some(PathBuf::from("component.svelte")),
+        ),
+    ];
+
+    let fail = vec![(
+        "<script setup>const props = defineFoo({ a: String });</script>",
+        None,
+        None,
+        Some(PathBuf::from("component.vue")),
+    )];
+
+    Tester::new(NoUndef::NAME, pass, fail).test();
 }
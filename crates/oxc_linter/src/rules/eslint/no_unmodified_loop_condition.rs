@@ -0,0 +1,268 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, Reference};
+use oxc_span::{CompactString, GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unmodified-loop-condition): '{0}' is not modified in this loop.")]
+#[diagnostic(
+    severity(warning),
+    help("Modify '{0}' in the loop, or the loop runs either never or forever")
+)]
+struct NoUnmodifiedLoopConditionDiagnostic(CompactString, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnmodifiedLoopCondition;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow unmodified loop conditions
+    ///
+    /// ### Why is this bad?
+    /// Variables in a loop condition usually get modified in the loop.
+    /// If not, it is probably a mistake, and the loop runs either never or forever.
+    ///
+    /// A variable counts as modified when it is assigned in the loop, or in a function declaration
+    /// that is called in the loop.
+    /// The variables of a comparison like `i < max` are only reported when none of them are modified,
+    /// and comparisons with calls or member accesses are not checked at all, as their value may change.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// let node = something;
+    /// while (node) {
+    ///   doSomething(node);
+    /// }
+    /// node = other;
+    /// ```
+    NoUnmodifiedLoopCondition,
+    correctness
+);
+
+/// A reference to a variable in the test of a loop.
+struct LoopCondition<'r> {
+    reference: &'r Reference,
+    loop_id: AstNodeId,
+    /// The outermost binary or conditional expression of the test containing the reference
+    group: Option<Span>,
+    modified: bool,
+}
+
+impl Rule for NoUnmodifiedLoopCondition {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let dynamic_groups = dynamic_groups(ctx);
+        let symbols = ctx.symbols();
+
+        let mut conditions = vec![];
+        for symbol_id in symbols.iter() {
+            let references = symbols.get_resolved_references(symbol_id).collect::<Vec<_>>();
+            let mut symbol_conditions = references
+                .iter()
+                .copied()
+                .filter_map(|reference| loop_condition(reference, &dynamic_groups, ctx))
+                .collect::<Vec<_>>();
+            if symbol_conditions.is_empty() {
+                continue;
+            }
+            for modifier in references.iter().filter(|reference| reference.is_write()) {
+                for condition in symbol_conditions.iter_mut().filter(|c| !c.modified) {
+                    condition.modified = is_modified_in_loop(modifier, condition.loop_id, ctx);
+                }
+            }
+            conditions.extend(symbol_conditions);
+        }
+
+        // The variables of a group are reported when none of them are modified
+        let mut modified_groups = FxHashMap::default();
+        for condition in &conditions {
+            if let Some(group) = condition.group {
+                *modified_groups.entry(group).or_insert(false) |= condition.modified;
+            }
+        }
+        conditions.sort_unstable_by_key(|condition| condition.reference.span().start);
+        for condition in conditions {
+            let is_reported = match condition.group {
+                Some(group) => !modified_groups[&group],
+                None => !condition.modified,
+            };
+            if is_reported {
+                ctx.diagnostic(NoUnmodifiedLoopConditionDiagnostic(
+                    condition.reference.name().clone(),
+                    condition.reference.span(),
+                ));
+            }
+        }
+    }
+}
+
+/// The loop `reference` is in the test of, if the test has no calls or member accesses around it.
+fn loop_condition<'r>(
+    reference: &'r Reference,
+    dynamic_groups: &FxHashSet<Span>,
+    ctx: &LintContext,
+) -> Option<LoopCondition<'r>> {
+    let mut group = None;
+    let mut child = reference.span();
+    for id in ctx.nodes().ancestors(reference.node_id()).skip(1) {
+        let kind = ctx.nodes().kind(id);
+        let test = match kind {
+            AstKind::WhileStatement(stmt) => Some(&stmt.test),
+            AstKind::DoWhileStatement(stmt) => Some(&stmt.test),
+            AstKind::ForStatement(stmt) => stmt.test.as_ref(),
+            _ => None,
+        };
+        if let Some(test) = test {
+            if test.span() == child {
+                return Some(LoopCondition { reference, loop_id: id, group, modified: false });
+            }
+        }
+        if kind.is_statement()
+            || kind.is_declaration()
+            || matches!(
+                kind,
+                AstKind::CallExpression(_)
+                    | AstKind::NewExpression(_)
+                    | AstKind::MemberExpression(_)
+                    | AstKind::YieldExpression(_)
+                    | AstKind::Function(_)
+                    | AstKind::ArrowFunctionExpression(_)
+                    | AstKind::Class(_)
+            )
+        {
+            return None;
+        }
+        if matches!(kind, AstKind::BinaryExpression(_) | AstKind::ConditionalExpression(_)) {
+            if dynamic_groups.contains(&kind.span()) {
+                return None;
+            }
+            group = Some(kind.span());
+        }
+        child = kind.span();
+    }
+    None
+}
+
+/// The spans of the binary and conditional expressions containing a call, a member access,
+/// a `new`, a tagged template or a `yield`, whose value may change without assignments.
+fn dynamic_groups(ctx: &LintContext) -> FxHashSet<Span> {
+    let mut groups = FxHashSet::default();
+    for node in ctx.nodes().iter() {
+        if !matches!(
+            node.kind(),
+            AstKind::CallExpression(_)
+                | AstKind::NewExpression(_)
+                | AstKind::MemberExpression(_)
+                | AstKind::TaggedTemplateExpression(_)
+                | AstKind::YieldExpression(_)
+        ) {
+            continue;
+        }
+        for id in ctx.nodes().ancestors(node.id()).skip(1) {
+            match ctx.nodes().kind(id) {
+                kind @ (AstKind::BinaryExpression(_) | AstKind::ConditionalExpression(_)) => {
+                    groups.insert(kind.span());
+                }
+                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) | AstKind::Class(_) => {
+                    break;
+                }
+                kind if kind.is_statement() => break,
+                _ => {}
+            }
+        }
+    }
+    groups
+}
+
+/// Whether `modifier` is in the loop, outside of its initializer, or in a function declaration
+/// called in the loop.
+fn is_modified_in_loop(modifier: &Reference, loop_id: AstNodeId, ctx: &LintContext) -> bool {
+    let in_loop = |span: Span| {
+        let (loop_span, init_span) = match ctx.nodes().kind(loop_id) {
+            AstKind::ForStatement(stmt) => (stmt.span, stmt.init.as_ref().map(GetSpan::span)),
+            kind => (kind.span(), None),
+        };
+        let contains = |outer: Span| outer.start <= span.start && span.end <= outer.end;
+        contains(loop_span) && !init_span.is_some_and(contains)
+    };
+    if in_loop(modifier.span()) {
+        return true;
+    }
+
+    let function =
+        ctx.nodes().ancestors(modifier.node_id()).find_map(|id| match ctx.nodes().kind(id) {
+            AstKind::Function(func) if func.is_declaration() => Some(func),
+            _ => None,
+        });
+    function.and_then(|func| func.id.as_ref()).and_then(|id| id.symbol_id.get()).is_some_and(
+        |symbol_id| {
+            ctx.symbols()
+                .get_resolved_references(symbol_id)
+                .any(|reference| in_loop(reference.span()))
+        },
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = 0; while (foo) { ++foo; }",
+        "var foo = 0; while (foo) { foo += 1; }",
+        "var foo = 0; while (foo++) { }",
+        "var foo = 0; while (foo = next()) { }",
+        "var foo = 0; while (ok(foo)) { }",
+        "var foo = 0, bar = 0; while (++foo < bar) { }",
+        "var foo = 0, obj = {}; while (foo === obj.bar) { }",
+        "var foo = 0, f = {}, bar = {}; while (foo === f(bar)) { }",
+        "var foo = 0, f = {}; while (foo === f()) { }",
+        "var foo = 0, tag = 0; while (foo === tag`abc`) { }",
+        "function* foo() { var foo = 0; while (yield foo) { } }",
+        "function* foo() { var foo = 0; while (foo === (yield)) { } }",
+        "var foo = 0; while (foo.ok) { }",
+        "var foo = 0; while (foo) { update(); } function update() { ++foo; }",
+        "var foo = 0, bar = 9; while (foo < bar) { foo += 1; }",
+        "var foo = 0, bar = 1, baz = 2; while (foo ? bar : baz) { foo += 1; }",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; ++bar; }",
+        "var foo = 0, bar = 0; while (foo || bar) { ++foo; ++bar; }",
+        "var foo = 0; do { ++foo; } while (foo);",
+        "var foo = 0; do { } while (foo++);",
+        "for (var foo = 0; foo; ++foo) { }",
+        "for (var foo = 0; foo; foo += 1) { }",
+        "for (var foo = 0; foo; ) { foo += 1; }",
+        "for (var foo = 0; foo < 10; ) { foo++; }",
+        "for (var foo = 0; foo < 10; foo++) { }",
+        "for (var foo = 0; foo; update()) { } function update() { ++foo; }",
+        "var a = 0; while (a) { () => { a = 1; }; }",
+        "var foo = 0; while (foo) { function update() { ++foo; } }",
+        "while (undeclared) { }",
+    ];
+
+    let fail = vec![
+        "var foo = 0; while (foo) { } foo = 1;",
+        "var foo = 0; while (!foo) { } foo = 1;",
+        "var foo = 0; while (foo != null) { } foo = 1;",
+        "var foo = 0, bar = 9; while (foo < bar) { } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++bar; } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; } foo = 1;",
+        "var a, b, c; while (a < c && b < c) { ++a; }",
+        "var foo = 0; while (foo ? 1 : 0) { } foo = 1;",
+        "var foo = 0; while (foo) { update(); } function update(foo) { ++foo; }",
+        "var foo; do { } while (foo);",
+        "for (var foo = 0; foo < 10; ) { } foo = 1;",
+        "var foo = 0; while (foo) { } function update() { ++foo; } update();",
+    ];
+
+    Tester::new(NoUnmodifiedLoopCondition::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,240 @@
+use oxc_ast::{
+    ast::{ConditionalExpression, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{operator::UnaryOperator, precedence::Precedence};
+
+use crate::{ast_util::get_parenthesized_text, context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnneededTernaryDiagnostic {
+    #[error("eslint(no-unneeded-ternary): Unnecessary use of boolean literals in conditional expression.")]
+    #[diagnostic(
+        severity(warning),
+        help("Use the test itself, negated or cast to a boolean if needed")
+    )]
+    BooleanLiterals(#[label] Span),
+
+    #[error("eslint(no-unneeded-ternary): Unnecessary use of conditional expression for default assignment.")]
+    #[diagnostic(severity(warning), help("Use the `||` operator instead"))]
+    DefaultAssignment(#[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUnneededTernary {
+    default_assignment: bool,
+}
+
+impl Default for NoUnneededTernary {
+    fn default() -> Self {
+        Self { default_assignment: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows conditional expressions when simpler alternatives exist.
+    ///
+    /// ### Why is this bad?
+    /// A conditional expression which selects between two boolean literals can be written
+    /// as the test itself, or its negation. `x ? x : y` is the same as `x || y`.
+    ///
+    /// ### Options
+    /// * `defaultAssignment`: allow `x ? x : y`, defaults to `true`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var isYes = answer === 1 ? true : false;
+    /// var isNo = answer === 1 ? false : true;
+    ///
+    /// // with "defaultAssignment": false
+    /// var foo = bar ? bar : 1;
+    /// ```
+    NoUnneededTernary,
+    style
+);
+
+impl Rule for NoUnneededTernary {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            default_assignment: value
+                .get(0)
+                .and_then(|config| config.get("defaultAssignment"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ConditionalExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ConditionalExpression(expr) = node.kind() else { return };
+        if let (Expression::BooleanLiteral(consequent), Expression::BooleanLiteral(alternate)) =
+            (&expr.consequent, &expr.alternate)
+        {
+            let diagnostic = NoUnneededTernaryDiagnostic::BooleanLiterals(expr.span);
+            if consequent.value == alternate.value {
+                // `foo ? true : true` is `true`, but `foo() ? true : true` has side effects
+                if matches!(expr.test, Expression::Identifier(_)) {
+                    ctx.diagnostic_with_fix(diagnostic, || {
+                        Fix::new(consequent.value.to_string(), expr.span)
+                    });
+                } else {
+                    ctx.diagnostic(diagnostic);
+                }
+                return;
+            }
+            ctx.diagnostic_with_fix(diagnostic, || {
+                let modified_code = if alternate.value {
+                    invert(&expr.test, ctx)
+                } else if is_boolean_expression(&expr.test) {
+                    expr.test.span().source_text(ctx.source_text()).to_string()
+                } else {
+                    format!("!{}", invert(&expr.test, ctx))
+                };
+                Fix::new(modified_code, expr.span)
+            });
+        } else if !self.default_assignment && is_default_assignment(expr) {
+            ctx.diagnostic_with_fix(
+                NoUnneededTernaryDiagnostic::DefaultAssignment(expr.span),
+                || {
+                    let test = expr.test.span().source_text(ctx.source_text());
+                    let alternate =
+                        get_parenthesized_text(&expr.alternate, Precedence::LogicalOr, ctx);
+                    Fix::new(format!("{test} || {alternate}"), expr.span)
+                },
+            );
+        }
+    }
+}
+
+/// Comparisons and negations, which always evaluate to a boolean
+fn is_boolean_expression(expr: &Expression) -> bool {
+    match expr {
+        Expression::BinaryExpression(binary) => {
+            binary.operator.is_equality()
+                || binary.operator.is_compare()
+                || binary.operator.is_relational()
+        }
+        Expression::UnaryExpression(unary) => unary.operator == UnaryOperator::LogicalNot,
+        _ => false,
+    }
+}
+
+/// `a == b` becomes `a != b`, anything else is negated with `!`
+fn invert(expr: &Expression, ctx: &LintContext) -> String {
+    if let Expression::BinaryExpression(binary) = expr {
+        if let Some(operator) = binary.operator.equality_inverse_operator() {
+            let span = Span::new(binary.left.span().end, binary.right.span().start);
+            let operator_text = span.source_text(ctx.source_text());
+            return format!(
+                "{}{}{}",
+                binary.left.span().source_text(ctx.source_text()),
+                operator_text.replace(binary.operator.as_str(), operator.as_str()),
+                binary.right.span().source_text(ctx.source_text()),
+            );
+        }
+    }
+    format!("!{}", get_parenthesized_text(expr, Precedence::Prefix, ctx))
+}
+
+/// `x ? x : y`
+fn is_default_assignment(expr: &ConditionalExpression) -> bool {
+    matches!(
+        (&expr.test, &expr.consequent),
+        (Expression::Identifier(test), Expression::Identifier(consequent)) if test.name == consequent.name
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("config.newIsCap = config.newIsCap !== false", None),
+        ("var a = x === 2 ? 'Yes' : 'No';", None),
+        ("var a = x === 2 ? true : 'No';", None),
+        ("var a = x === 2 ? 'Yes' : false;", None),
+        ("var a = x === 2 ? 'true' : 'false';", None),
+        ("var a = foo ? foo : bar;", None),
+        ("var value = 'a';var canSet = true;var result = value || (canSet ? 'unset' : 'can not set')", None),
+        ("var a = foo ? bar : foo;", None),
+        ("foo ? bar : foo;", None),
+        ("var a = f(x ? x : 1)", None),
+        ("f(x ? x : 1);", None),
+        ("foo ? foo : bar;", None),
+        ("var a = foo ? 'Yes' : foo;", None),
+        ("var a = foo ? 'Yes' : foo;", Some(json!([{ "defaultAssignment": false }]))),
+        ("var a = foo ? bar : foo;", Some(json!([{ "defaultAssignment": false }]))),
+        ("foo ? bar : foo;", Some(json!([{ "defaultAssignment": false }]))),
+    ];
+
+    let fail = vec![
+        ("var a = x === 2 ? true : false;", None),
+        ("var a = x >= 2 ? true : false;", None),
+        ("var a = x ? true : false;", None),
+        ("var a = x === 1 ? false : true;", None),
+        ("var a = x != 1 ? false : true;", None),
+        ("var a = foo() ? false : true;", None),
+        ("var a = !foo() ? false : true;", None),
+        ("var a = foo + bar ? false : true;", None),
+        ("var a = x instanceof foo ? false : true;", None),
+        ("var a = foo ? false : false;", None),
+        ("var a = foo() ? false : false;", None),
+        ("var a = x instanceof foo ? true : false;", None),
+        ("var a = !foo ? true : false;", None),
+        (
+            "var value = 'a'\nvar canSet = true\nvar result = value ? value : canSet",
+            Some(json!([{ "defaultAssignment": false }])),
+        ),
+        ("var a = x ? x : 1;", Some(json!([{ "defaultAssignment": false }]))),
+        ("var a = f(x ? x : 1)", Some(json!([{ "defaultAssignment": false }]))),
+        ("x ? x : 1;", Some(json!([{ "defaultAssignment": false }]))),
+        ("var a = foo ? foo : 'No';", Some(json!([{ "defaultAssignment": false }]))),
+        ("var a = foo ? foo : a ? 1 : 2;", Some(json!([{ "defaultAssignment": false }]))),
+        ("var a = foo ? foo : a ?? b;", Some(json!([{ "defaultAssignment": false }]))),
+        ("var a = foo ? foo : (a, b);", Some(json!([{ "defaultAssignment": false }]))),
+    ];
+
+    let fix = vec![
+        ("var a = x === 2 ? true : false;", "var a = x === 2;", None),
+        ("var a = x >= 2 ? true : false;", "var a = x >= 2;", None),
+        ("var a = x ? true : false;", "var a = !!x;", None),
+        ("var a = x === 1 ? false : true;", "var a = x !== 1;", None),
+        ("var a = x != 1 ? false : true;", "var a = x == 1;", None),
+        ("var a = foo() ? false : true;", "var a = !foo();", None),
+        ("var a = !foo() ? false : true;", "var a = !!foo();", None),
+        ("var a = foo + bar ? false : true;", "var a = !(foo + bar);", None),
+        ("var a = x instanceof foo ? false : true;", "var a = !(x instanceof foo);", None),
+        ("var a = foo ? false : false;", "var a = false;", None),
+        ("var a = foo() ? false : false;", "var a = foo() ? false : false;", None),
+        ("var a = !foo ? true : false;", "var a = !foo;", None),
+        ("var a = x ? x : 1;", "var a = x || 1;", Some(json!([{ "defaultAssignment": false }]))),
+        (
+            "var a = foo ? foo : a ? 1 : 2;",
+            "var a = foo || (a ? 1 : 2);",
+            Some(json!([{ "defaultAssignment": false }])),
+        ),
+        (
+            "var a = foo ? foo : a ?? b;",
+            "var a = foo || (a ?? b);",
+            Some(json!([{ "defaultAssignment": false }])),
+        ),
+        (
+            "var a = foo ? foo : (a, b);",
+            "var a = foo || (a, b);",
+            Some(json!([{ "defaultAssignment": false }])),
+        ),
+    ];
+
+    Tester::new(NoUnneededTernary::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,186 @@
+use oxc_ast::{ast::VariableDeclarationKind, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{
+    petgraph::{graph::NodeIndex, visit::EdgeRef},
+    AstNodeId, BasicBlockElement, EdgeType,
+};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unreachable): Unreachable code.")]
+#[diagnostic(severity(warning))]
+struct NoUnreachableDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachable;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow unreachable code after `return`, `throw`, `continue`, and `break` statements
+    ///
+    /// ### Why is this bad?
+    /// Because the `return`, `throw`, `break`, and `continue` statements unconditionally exit a block of code,
+    /// any statements after them cannot be executed. Unreachable statements are usually a mistake.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {
+    ///     return true;
+    ///     console.log("done");
+    /// }
+    /// ```
+    NoUnreachable,
+    correctness
+);
+
+impl Rule for NoUnreachable {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let live = live_basic_blocks(ctx);
+        // A finally clause is visited twice by the builder, after the try block completes and
+        // after it throws or returns, so its statements are reachable when either copy is
+        let live_spans = ctx
+            .nodes()
+            .iter()
+            .filter(|node| live.contains(&node.cfg_ix()) && is_reportable(node.kind()))
+            .map(|node| node.kind().span())
+            .collect::<FxHashSet<_>>();
+
+        // The unreachable statements of the same list are merged into one range
+        let mut ranges: Vec<(Option<AstNodeId>, Span)> = vec![];
+        for node in ctx.nodes().iter() {
+            if live.contains(&node.cfg_ix()) || !is_reportable(node.kind()) {
+                continue;
+            }
+            let span = node.kind().span();
+            if live_spans.contains(&span) {
+                continue;
+            }
+            let parent_id = ctx.nodes().parent_id(node.id());
+            match ranges.last_mut() {
+                Some((_, range)) if range.start <= span.start && span.end <= range.end => {}
+                Some((last_parent_id, range)) if *last_parent_id == parent_id => {
+                    range.end = span.end;
+                }
+                _ => ranges.push((parent_id, span)),
+            }
+        }
+
+        for (_, span) in ranges {
+            ctx.diagnostic(NoUnreachableDiagnostic(span));
+        }
+    }
+}
+
+/// The basic blocks reachable from the start of the program or of a function.
+///
+/// The builder connects a `break` or `continue` to the unreachable block following it,
+/// these edges are not taken.
+fn live_basic_blocks(ctx: &LintContext) -> FxHashSet<NodeIndex> {
+    let cfg = ctx.cfg();
+    let mut stack = vec![];
+    let mut jumps = FxHashSet::default();
+    for node in ctx.nodes().iter() {
+        match node.kind() {
+            AstKind::Program(_) | AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                stack.push(node.cfg_ix());
+            }
+            AstKind::BreakStatement(_) | AstKind::ContinueStatement(_) => {
+                jumps.insert(node.cfg_ix());
+            }
+            _ => {}
+        }
+    }
+
+    let mut live = FxHashSet::default();
+    while let Some(ix) = stack.pop() {
+        if !live.insert(ix) {
+            continue;
+        }
+        for edge in cfg.graph.edges(ix) {
+            if matches!(edge.weight(), EdgeType::NewFunction) {
+                continue;
+            }
+            let target = edge.target();
+            let starts_unreachable = matches!(
+                cfg.basic_block_by_index(target).first(),
+                Some(BasicBlockElement::Unreachable)
+            );
+            if starts_unreachable && jumps.contains(&ix) {
+                continue;
+            }
+            stack.push(target);
+        }
+    }
+    live
+}
+
+/// Function declarations are hoisted, and `var` declarations without an initializer
+/// do nothing at runtime, so they are not reported.
+fn is_reportable(kind: AstKind) -> bool {
+    match kind {
+        AstKind::EmptyStatement(_) => false,
+        AstKind::VariableDeclaration(decl) => {
+            decl.kind != VariableDeclarationKind::Var
+                || decl.declarations.iter().any(|declarator| declarator.init.is_some())
+        }
+        AstKind::Class(class) => class.is_declaration(),
+        kind => kind.is_statement(),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function foo() { function bar() { return 1; } return bar(); }",
+        "function foo() { return bar(); function bar() { return 1; } }",
+        "function foo() { return x; var x; }",
+        "function foo() { var x = 1; var y = 2; }",
+        "function foo() { var x = 1; var y = 2; return; }",
+        "while (true) { switch (foo) { case 1: x = 1; x = 2;} }",
+        "while (a) { if (b) { break; } foo(); } bar();",
+        "while (a) { if (b) { continue; } foo(); } bar();",
+        "function foo() { if (a) { return 1; } else { return 2; } }",
+        "function foo() { if (a) { b(); } else { c(); } return d(); }",
+        "function foo() { try { return 1; } catch (e) { bar(); } }",
+        "function foo() { try { throw a; } catch (e) { bar(); } baz(); }",
+        "switch (foo) { case 1: bar(); break; case 2: baz(); break; }",
+        "function foo() { return; } foo();",
+        "const foo = () => { return; }; foo();",
+        "function foo() { throw new Error(); } function bar() { baz(); }",
+        "function foo() { return; ; }",
+        "function f(){ try { return 1 } finally { g() } }",
+        "function k(){ l: { break l; } z(); }",
+    ];
+
+    let fail = vec![
+        "function foo() { return x; var x = 1; }",
+        "function foo() { return x; var x, y = 1; }",
+        "function foo() { return; x = 1; }",
+        "function foo() { throw error; x = 1; }",
+        "while (true) { break; x = 1; }",
+        "while (true) { continue; x = 1; }",
+        "function foo() { switch (foo) { case 1: return; x = 1; } }",
+        "switch (foo) { case 1: throw e; x = 1; }",
+        "function foo() { return; let x = 1; class A {} }",
+        "function foo() { return; if (a) { b(); } c(); }",
+        "const foo = () => { throw e; bar(); };",
+        "function foo() { return; function bar() { return; baz(); } }",
+        "function f(){ try { return 1 } finally { g() } z(); }",
+        "function k(){ l: { break l; y(); } z(); }",
+    ];
+
+    Tester::new(NoUnreachable::NAME, pass, fail).test_and_snapshot();
+}
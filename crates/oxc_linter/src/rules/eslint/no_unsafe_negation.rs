@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BinaryExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -59,6 +59,10 @@ impl Rule for NoUnsafeNegation {
         Self { enforce_for_ordering_relations }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(expr) = node.kind() else {
             return;
@@ -102,7 +106,7 @@ impl NoUnsafeNegation {
             Fix::new(modified_code, expr.span)
         };
 
-        ctx.diagnostic_with_fix(diagnostic, fix_producer);
+        ctx.diagnostic_with_suggestion(diagnostic, fix_producer);
     }
 }
 
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, AssignmentTarget, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -67,6 +67,25 @@ impl Rule for NoUnsafeOptionalChaining {
         }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::CallExpression,
+            AstType::MemberExpression,
+            AstType::TaggedTemplateExpression,
+            AstType::NewExpression,
+            AstType::AssignmentExpression,
+            AstType::BinaryExpression,
+            AstType::UnaryExpression,
+            AstType::ForOfStatement,
+            AstType::WithStatement,
+            AstType::Class,
+            AstType::AssignmentPattern,
+            AstType::Argument,
+            AstType::VariableDeclarator,
+            AstType::AssignmentTargetWithDefault,
+        ])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(expr) if !expr.optional => {
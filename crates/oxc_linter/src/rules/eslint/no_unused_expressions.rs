@@ -0,0 +1,212 @@
+use oxc_ast::{
+    ast::{ChainElement, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unused-expressions): Disallow unused expressions")]
+#[diagnostic(
+    severity(warning),
+    help("Expected an assignment or function call and instead saw an expression.")
+)]
+struct NoUnusedExpressionsDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedExpressions {
+    /// Allow short circuit evaluations, e.g. `a && a()`
+    allow_short_circuit: bool,
+    /// Allow ternary operators, e.g. `a ? b() : c()`
+    allow_ternary: bool,
+    /// Allow tagged templates, e.g. ``tag`foo` ``
+    allow_tagged_templates: bool,
+    /// Report JSX elements, e.g. `<div />`
+    enforce_for_jsx: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows expression statements which have no effect on the state of the program.
+    ///
+    /// ### Why is this bad?
+    /// An unused expression such as `n + 1;` is not a syntax error, but it is most likely
+    /// a mistake, e.g. a missing assignment or function call.
+    ///
+    /// ### Options
+    /// * `allowShortCircuit`: allow `a && b()` and `a || b()`.
+    /// * `allowTernary`: allow `a ? b() : c()`.
+    /// * `allowTaggedTemplates`: allow ``tag`foo` ``.
+    /// * `enforceForJSX`: also report `<div />` and `<></>`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// 0;
+    /// a && b;
+    /// a ? b : c;
+    /// "use strict" && foo();
+    /// ```
+    NoUnusedExpressions,
+    restriction
+);
+
+impl Rule for NoUnusedExpressions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        let get_bool = |name: &str| {
+            obj.and_then(|v| v.get(name)).and_then(serde_json::Value::as_bool).unwrap_or_default()
+        };
+        Self {
+            allow_short_circuit: get_bool("allowShortCircuit"),
+            allow_ternary: get_bool("allowTernary"),
+            allow_tagged_templates: get_bool("allowTaggedTemplates"),
+            enforce_for_jsx: get_bool("enforceForJSX"),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ExpressionStatement])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let AstKind::ExpressionStatement(stmt) = node.kind() {
+            if self.is_disallowed(&stmt.expression) {
+                ctx.diagnostic(NoUnusedExpressionsDiagnostic(stmt.span));
+            }
+        }
+    }
+}
+
+impl NoUnusedExpressions {
+    /// Whether the value of `expr` is thrown away without any effect
+    fn is_disallowed(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::ParenthesizedExpression(expr) => self.is_disallowed(&expr.expression),
+            Expression::TSAsExpression(expr) => self.is_disallowed(&expr.expression),
+            Expression::TSSatisfiesExpression(expr) => self.is_disallowed(&expr.expression),
+            Expression::TSTypeAssertion(expr) => self.is_disallowed(&expr.expression),
+            Expression::TSNonNullExpression(expr) => self.is_disallowed(&expr.expression),
+            Expression::ChainExpression(chain) => {
+                matches!(chain.expression, ChainElement::MemberExpression(_))
+            }
+            Expression::ConditionalExpression(expr) => {
+                !self.allow_ternary
+                    || self.is_disallowed(&expr.consequent)
+                    || self.is_disallowed(&expr.alternate)
+            }
+            Expression::LogicalExpression(expr) => {
+                !self.allow_short_circuit || self.is_disallowed(&expr.right)
+            }
+            Expression::TaggedTemplateExpression(_) => !self.allow_tagged_templates,
+            Expression::UnaryExpression(expr) => {
+                !matches!(expr.operator, UnaryOperator::Void | UnaryOperator::Delete)
+            }
+            Expression::JSXElement(_) | Expression::JSXFragment(_) => self.enforce_for_jsx,
+            Expression::AssignmentExpression(_)
+            | Expression::AwaitExpression(_)
+            | Expression::CallExpression(_)
+            | Expression::ImportExpression(_)
+            | Expression::NewExpression(_)
+            | Expression::UpdateExpression(_)
+            | Expression::YieldExpression(_) => false,
+            _ => true,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function f(){}", None),
+        ("a = b", None),
+        ("new a", None),
+        ("{}", None),
+        ("f(); g()", None),
+        ("i++", None),
+        ("--i", None),
+        ("a()", None),
+        ("a?.()", None),
+        ("a?.b()", None),
+        ("delete foo.bar", None),
+        ("void new C", None),
+        ("\"use strict\";", None),
+        ("\"directive one\"; \"directive two\"; f();", None),
+        ("function foo() {\"use strict\"; return true; }", None),
+        ("var foo = () => {\"use strict\"; return true; }", None),
+        ("function foo() {\"directive one\"; \"directive two\"; f(); }", None),
+        ("a && a()", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a() || (b = c)", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a ? b() : c()", Some(json!([{ "allowTernary": true }]))),
+        (
+            "a ? b() || (c = d) : e()",
+            Some(json!([{ "allowShortCircuit": true, "allowTernary": true }])),
+        ),
+        ("a ?? b()", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a ||= b", None),
+        ("async function foo() { await foo; }", None),
+        ("function* foo() { yield 0; }", None),
+        ("import('foo')", None),
+        ("func`foo`", Some(json!([{ "allowTaggedTemplates": true }]))),
+        ("func`foo${bar}`", Some(json!([{ "allowTaggedTemplates": true }]))),
+        ("<div />", None),
+        ("<></>", None),
+        ("var partial = <div />", None),
+        ("var partial = <div />", Some(json!([{ "enforceForJSX": true }]))),
+        ("foo() as any", None),
+        ("foo()!", None),
+        ("(foo())", None),
+    ];
+
+    let fail = vec![
+        ("0", None),
+        ("a", None),
+        ("f(), 0", None),
+        ("{0}", None),
+        ("[]", None),
+        ("a && b();", None),
+        ("a() || false", None),
+        ("a || (b = c)", None),
+        ("a ? b() || (c = d) : e", None),
+        ("`untagged`", None),
+        ("tag`tagged`", None),
+        ("`untagged`", Some(json!([{ "allowTaggedTemplates": true }]))),
+        ("tag`tagged`", Some(json!([{ "allowTaggedTemplates": false }]))),
+        ("a && b()", Some(json!([{ "allowTernary": true }]))),
+        ("a ? b() : c()", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a || b", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a() && b", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a ? b : 0", Some(json!([{ "allowTernary": true }]))),
+        ("a ? b : c()", Some(json!([{ "allowTernary": true }]))),
+        ("foo.bar;", None),
+        ("!a", None),
+        ("+a", None),
+        ("typeof a", None),
+        ("\"directive one\"; f(); \"directive two\";", None),
+        ("function foo() { \"directive\"; f(); \"use strict\"; }", None),
+        ("if (0) { \"not a directive\"; f(); }", None),
+        ("class C { static { \"use strict\"; } }", None),
+        ("a?.b", None),
+        ("(a?.b).c", None),
+        ("a?.['b']", None),
+        ("obj?.foo!", None),
+        ("foo as any", None),
+        ("function f() { new.target }", None),
+        ("function f() { this }", None),
+        ("(function () {})", None),
+        ("(class {})", None),
+        ("<div />", Some(json!([{ "enforceForJSX": true }]))),
+        ("<></>", Some(json!([{ "enforceForJSX": true }]))),
+    ];
+
+    Tester::new(NoUnusedExpressions::NAME, pass, fail).test_and_snapshot();
+}
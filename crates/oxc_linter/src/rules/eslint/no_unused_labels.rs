@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -41,6 +41,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnusedLabels {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         if ctx.file_path().extension().is_some_and(|ext| ext == "svelte") {
             return;
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -91,6 +91,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnusedPrivateClassMembers {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         ctx.semantic().classes().iter_enumerated().for_each(|(class_id, _)| {
             for (element_id, element) in
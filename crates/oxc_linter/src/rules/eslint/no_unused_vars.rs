@@ -0,0 +1,374 @@
+use oxc_ast::{
+    ast::{BindingIdentifier, BindingPatternKind, ClassType, FormalParameters, FunctionType},
+    syntax_directed_operations::BoundNames,
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, SymbolFlags, SymbolId};
+use oxc_span::{CompactString, GetSpan, Span};
+use regex::Regex;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unused-vars): '{0}' is {1} but never used.")]
+#[diagnostic(severity(warning))]
+struct NoUnusedVarsDiagnostic(CompactString, &'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Vars {
+    /// Check all variables, including the global ones
+    #[default]
+    All,
+    /// Only check the variables which are not global
+    Local,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Args {
+    /// Only check the parameters after the last used one
+    #[default]
+    AfterUsed,
+    All,
+    None,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVars(Box<NoUnusedVarsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVarsConfig {
+    vars: Vars,
+    vars_ignore_pattern: Option<Regex>,
+    args: Args,
+    args_ignore_pattern: Option<Regex>,
+    /// Also check the parameters of `catch` clauses
+    caught_errors: bool,
+    caught_errors_ignore_pattern: Option<Regex>,
+    /// Allow the properties destructured next to a rest element, e.g. `const { a, ...rest } = b`
+    ignore_rest_siblings: bool,
+}
+
+impl std::ops::Deref for NoUnusedVars {
+    type Target = NoUnusedVarsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows variables, functions, classes, imports and parameters which are never read.
+    ///
+    /// ### Why is this bad?
+    /// An unused declaration is most likely left over from a refactoring, or a mistake
+    /// where another variable is used instead. It is also a distraction for the reader.
+    ///
+    /// ### Options
+    /// * `vars`: `"all"` (default) checks all variables, `"local"` does not check global variables.
+    /// * `args`: `"after-used"` (default) only checks the parameters after the last used one,
+    ///   `"all"` checks all of them, `"none"` none of them.
+    /// * `caughtErrors`: `"none"` (default) or `"all"` to also check `catch` parameters.
+    /// * `varsIgnorePattern`, `argsIgnorePattern`, `caughtErrorsIgnorePattern`:
+    ///   regular expressions of the names which are allowed to be unused.
+    /// * `ignoreRestSiblings`: allow the properties destructured next to a rest element.
+    ///
+    /// A variable which is only used to update itself, like `a++` or `a += 1`,
+    /// or a function which is only called by itself, is still unused.
+    /// The components of JSX elements are used, and `React` is used by JSX when
+    /// `react/jsx-uses-react` is enabled.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import { foo } from "foo";
+    /// let count = 0;
+    /// count += 1;
+    /// function add(a, b) {
+    ///     return a;
+    /// }
+    /// ```
+    NoUnusedVars,
+    nursery
+);
+
+impl Rule for NoUnusedVars {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        // `"all"` or `"local"` instead of an object configures `vars`
+        if let Some(vars) = config.and_then(Value::as_str) {
+            let vars = if vars == "local" { Vars::Local } else { Vars::All };
+            return Self(Box::new(NoUnusedVarsConfig { vars, ..NoUnusedVarsConfig::default() }));
+        }
+        let get_str = |name: &str| config.and_then(|v| v.get(name)).and_then(Value::as_str);
+        let get_pattern = |name: &str| get_str(name).and_then(|pattern| Regex::new(pattern).ok());
+        Self(Box::new(NoUnusedVarsConfig {
+            vars: if get_str("vars") == Some("local") { Vars::Local } else { Vars::All },
+            vars_ignore_pattern: get_pattern("varsIgnorePattern"),
+            args: match get_str("args") {
+                Some("all") => Args::All,
+                Some("none") => Args::None,
+                _ => Args::AfterUsed,
+            },
+            args_ignore_pattern: get_pattern("argsIgnorePattern"),
+            caught_errors: get_str("caughtErrors") == Some("all"),
+            caught_errors_ignore_pattern: get_pattern("caughtErrorsIgnorePattern"),
+            ignore_rest_siblings: config
+                .and_then(|v| v.get("ignoreRestSiblings"))
+                .and_then(Value::as_bool)
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        if symbols.get_flag(symbol_id).intersects(
+            SymbolFlags::Export
+                | SymbolFlags::Ambient
+                | SymbolFlags::EnumMember
+                | SymbolFlags::TypeParameter,
+        ) {
+            return;
+        }
+        if is_used(symbol_id, ctx) {
+            return;
+        }
+
+        let name = symbols.get_name(symbol_id);
+        let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        let ignore_pattern = match declaration.kind() {
+            // The name of a function or class expression is only visible inside of it
+            AstKind::Function(func) if func.r#type == FunctionType::FunctionExpression => return,
+            AstKind::Class(class) if class.r#type == ClassType::ClassExpression => return,
+            // Overloads and declarations without a body
+            AstKind::Function(func) if func.body.is_none() => return,
+            AstKind::FormalParameter(_) | AstKind::BindingRestElement(_) => {
+                if !self.is_reported_parameter(declaration, symbol_id, ctx) {
+                    return;
+                }
+                &self.args_ignore_pattern
+            }
+            AstKind::CatchClause(_) => {
+                if !self.caught_errors {
+                    return;
+                }
+                &self.caught_errors_ignore_pattern
+            }
+            AstKind::VariableDeclarator(decl)
+                if self.ignore_rest_siblings && is_rest_sibling(&decl.id.kind, symbol_id) =>
+            {
+                return;
+            }
+            _ => {
+                if self.vars == Vars::Local
+                    && symbols.get_scope_id(symbol_id) == ctx.scopes().root_scope_id()
+                    && ctx.source_type().is_script()
+                {
+                    return;
+                }
+                &self.vars_ignore_pattern
+            }
+        };
+        if ignore_pattern.as_ref().is_some_and(|pattern| pattern.is_match(name)) {
+            return;
+        }
+
+        let has_init =
+            matches!(declaration.kind(), AstKind::VariableDeclarator(decl) if decl.init.is_some());
+        let is_assigned = has_init
+            || symbols
+                .get_resolved_reference_ids(symbol_id)
+                .iter()
+                .any(|reference_id| symbols.get_reference(*reference_id).is_write());
+        let action = if is_assigned { "assigned a value" } else { "defined" };
+        ctx.diagnostic(NoUnusedVarsDiagnostic(name.into(), action, symbols.get_span(symbol_id)));
+    }
+}
+
+impl NoUnusedVars {
+    /// Whether the unused parameter is reported according to the `args` option.
+    fn is_reported_parameter(
+        &self,
+        declaration: &AstNode,
+        symbol_id: SymbolId,
+        ctx: &LintContext<'_>,
+    ) -> bool {
+        let nodes = ctx.nodes();
+        let Some(params_node) = nodes.parent_node(declaration.id()) else {
+            return false;
+        };
+        let AstKind::FormalParameters(params) = params_node.kind() else {
+            return false;
+        };
+        // Parameters of overloads, abstract methods and `declare` functions
+        if matches!(
+            nodes.parent_kind(params_node.id()),
+            Some(AstKind::Function(func)) if func.body.is_none()
+        ) {
+            return false;
+        }
+        // TypeScript parameter properties, e.g. `constructor(private a) {}`
+        if let AstKind::FormalParameter(param) = declaration.kind() {
+            if param.accessibility.is_some() || param.readonly {
+                return false;
+            }
+        }
+        match self.args {
+            Args::All => true,
+            Args::None => false,
+            Args::AfterUsed => {
+                let bindings = parameter_bindings(params);
+                let position = bindings.iter().position(|id| *id == symbol_id);
+                position.map_or(true, |position| {
+                    !bindings[position + 1..].iter().any(|id| is_used(*id, ctx))
+                })
+            }
+        }
+    }
+}
+
+/// Whether the symbol is destructured next to a rest element,
+/// e.g. `a` in `const { a, ...rest } = b`.
+fn is_rest_sibling(pattern: &BindingPatternKind, symbol_id: SymbolId) -> bool {
+    let BindingPatternKind::ObjectPattern(pattern) = pattern else {
+        return false;
+    };
+    let Some(rest) = &pattern.rest else {
+        return false;
+    };
+    let mut is_rest = false;
+    rest.bound_names(&mut |ident| is_rest |= ident.symbol_id.get() == Some(symbol_id));
+    !is_rest
+}
+
+/// The symbols bound by the parameters, in order.
+fn parameter_bindings(params: &FormalParameters) -> Vec<SymbolId> {
+    let mut bindings = vec![];
+    let mut push = |ident: &BindingIdentifier| bindings.extend(ident.symbol_id.get());
+    for param in &params.items {
+        param.pattern.bound_names(&mut push);
+    }
+    if let Some(rest) = &params.rest {
+        rest.bound_names(&mut push);
+    }
+    bindings
+}
+
+/// Whether the symbol is read or used as a type somewhere other than for updating itself,
+/// or from inside of its own declaration for functions and classes, or marked as used by another
+/// rule such as `react/jsx-uses-react`.
+fn is_used(symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    if ctx.is_marked_used(symbol_id) {
+        return true;
+    }
+    let symbols = ctx.symbols();
+    let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+    // `function f() { f(); }` and `const f = () => f();`
+    let own_span = match declaration.kind() {
+        AstKind::Function(func) => Some(func.span),
+        AstKind::Class(class) => Some(class.span),
+        AstKind::VariableDeclarator(decl) => decl.init.as_ref().and_then(|init| {
+            let init = init.get_inner_expression();
+            init.is_function().then(|| init.span())
+        }),
+        _ => None,
+    };
+    symbols.get_resolved_references(symbol_id).any(|reference| {
+        if !(reference.is_read() || reference.is_type()) {
+            return false;
+        }
+        if own_span.is_some_and(|span| {
+            span.start <= reference.span().start && reference.span().end <= span.end
+        }) {
+            return false;
+        }
+        !(reference.is_write() && is_self_update(reference.node_id(), ctx))
+    })
+}
+
+/// Whether the reference is updated by a statement like `a++;` or `a += 1;`,
+/// which reads it only to write it again.
+fn is_self_update(node_id: AstNodeId, ctx: &LintContext<'_>) -> bool {
+    let mut ancestors = ctx.nodes().iter_parents(node_id).skip(1).skip_while(|node| {
+        matches!(
+            node.kind(),
+            AstKind::SimpleAssignmentTarget(_)
+                | AstKind::AssignmentTarget(_)
+                | AstKind::ParenthesizedExpression(_)
+        )
+    });
+    let Some(update) = ancestors.next() else {
+        return false;
+    };
+    matches!(update.kind(), AstKind::UpdateExpression(_) | AstKind::AssignmentExpression(_))
+        && matches!(
+            ancestors.next().map(AstNode::kind),
+            Some(AstKind::ExpressionStatement(_) | AstKind::ForStatement(_))
+        )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var a = 1; console.log(a);", None),
+        ("function foo() {} foo();", None),
+        ("export function foo() {}", None),
+        ("const a = 1; export { a };", None),
+        ("export default class A {}", None),
+        ("import { a } from 'a'; a();", None),
+        ("var a = function b() {}; a();", None),
+        ("(class B {})", None),
+        ("function foo(a, b) { return b; } foo();", None),
+        ("function foo(a, b) {} foo();", Some(json!([{ "args": "none" }]))),
+        ("function foo(_a) {} foo();", Some(json!([{ "argsIgnorePattern": "^_" }]))),
+        ("var _a = 1;", Some(json!([{ "varsIgnorePattern": "^_" }]))),
+        ("try {} catch (e) {}", None),
+        (
+            "try {} catch (_e) {}",
+            Some(json!([{ "caughtErrors": "all", "caughtErrorsIgnorePattern": "^_" }])),
+        ),
+        (
+            "const { a, ...rest } = b; console.log(rest);",
+            Some(json!([{ "ignoreRestSiblings": true }])),
+        ),
+        ("let a = 0; a = a + 1; console.log(a);", None),
+        ("type A = string; let x: A; x;", None),
+        ("class A { constructor(private a: number) {} } new A(1);", None),
+        ("function f(a: number): void; function f(a) { return a; } f(1);", None),
+        ("declare function g(a: number): void; g(1);", None),
+        ("enum E { A } E.A;", None),
+    ];
+
+    let fail = vec![
+        ("var a = 1;", None),
+        ("function foo() {}", None),
+        ("import { a } from 'a';", None),
+        ("let a = 0; a++; a += 1;", None),
+        ("function foo() { foo(); }", None),
+        ("const f = () => f();", None),
+        ("function foo(a, b) {} foo();", None),
+        ("function foo(a, b) { return b; } foo();", Some(json!([{ "args": "all" }]))),
+        ("try {} catch (e) {}", Some(json!([{ "caughtErrors": "all" }]))),
+        ("const { a, ...rest } = b; console.log(rest);", None),
+        ("const { a, ...rest } = b;", Some(json!([{ "ignoreRestSiblings": true }]))),
+        // Top level variables of modules are not global
+        ("var a = 1;", Some(json!(["local"]))),
+        ("let a; a = 1;", None),
+        ("class A {}", None),
+        ("interface I {}", None),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail).test_and_snapshot();
+}
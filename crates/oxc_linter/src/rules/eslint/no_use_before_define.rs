@@ -0,0 +1,244 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, FunctionType},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, ScopeId, ScopeTree, SymbolFlags, SymbolId};
+use oxc_span::{CompactString, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-use-before-define): '{0}' was used before it was defined.")]
+#[diagnostic(severity(warning))]
+struct NoUseBeforeDefineDiagnostic(CompactString, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoUseBeforeDefine {
+    functions: bool,
+    classes: bool,
+    variables: bool,
+}
+
+impl Default for NoUseBeforeDefine {
+    fn default() -> Self {
+        Self { functions: true, classes: true, variables: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows the use of variables before they are defined.
+    ///
+    /// ### Why is this bad?
+    /// `let`, `const` and classes are in the temporal dead zone until their declaration,
+    /// reading them throws a `ReferenceError`. `var` declarations are hoisted, so reading them
+    /// before the declaration silently gives `undefined`.
+    ///
+    /// ### Options
+    /// Either `"nofunc"`, the same as `{ "functions": false }`, or an object with:
+    /// * `functions`: report function declarations, defaults to `true`.
+    /// * `classes`: report classes used in an inner function scope, defaults to `true`.
+    /// * `variables`: report variables used in an inner function scope, defaults to `true`.
+    ///
+    /// Type references are never reported.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// alert(a);
+    /// var a = 10;
+    ///
+    /// new B();
+    /// class B {}
+    /// ```
+    NoUseBeforeDefine,
+    restriction
+);
+
+impl Rule for NoUseBeforeDefine {
+    fn from_configuration(value: Value) -> Self {
+        let default = Self::default();
+        match value.get(0) {
+            Some(Value::String(option)) if option == "nofunc" => {
+                Self { functions: false, ..default }
+            }
+            Some(Value::Object(config)) => {
+                let option = |name: &str| config.get(name).and_then(Value::as_bool).unwrap_or(true);
+                Self {
+                    functions: option("functions"),
+                    classes: option("classes"),
+                    variables: option("variables"),
+                }
+            }
+            _ => default,
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let flag = symbols.get_flag(symbol_id);
+        if flag.contains(SymbolFlags::ImportBinding) {
+            return;
+        }
+        let span = symbols.get_span(symbol_id);
+        for reference in symbols.get_resolved_references(symbol_id) {
+            if reference.is_type() {
+                continue;
+            }
+            if span.end < reference.span().end && !is_in_initializer(symbol_id, reference, ctx) {
+                continue;
+            }
+            if self.is_forbidden(symbol_id, flag, reference, ctx) {
+                ctx.diagnostic(NoUseBeforeDefineDiagnostic(
+                    symbols.get_name(symbol_id).into(),
+                    reference.span(),
+                ));
+            }
+        }
+    }
+}
+
+impl NoUseBeforeDefine {
+    fn is_forbidden(
+        &self,
+        symbol_id: SymbolId,
+        flag: SymbolFlags,
+        reference: &Reference,
+        ctx: &LintContext<'_>,
+    ) -> bool {
+        let declaration = ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id));
+        if matches!(declaration, AstKind::Function(function) if function.r#type == FunctionType::FunctionDeclaration)
+        {
+            return self.functions;
+        }
+        if flag.intersects(SymbolFlags::Class) && is_outer(symbol_id, reference, ctx) {
+            return self.classes;
+        }
+        if flag.intersects(SymbolFlags::Variable)
+            && matches!(declaration, AstKind::VariableDeclarator(_))
+            && is_outer(symbol_id, reference, ctx)
+        {
+            return self.variables;
+        }
+        true
+    }
+}
+
+/// The closest scope which `var` declarations are hoisted to
+fn variable_scope(scopes: &ScopeTree, scope_id: ScopeId) -> ScopeId {
+    scopes
+        .ancestors(scope_id)
+        .find(|scope_id| scopes.get_flags(*scope_id).is_var())
+        .unwrap_or_else(|| scopes.root_scope_id())
+}
+
+/// Whether the reference is inside a function nested in the declaring function
+fn is_outer(symbol_id: SymbolId, reference: &Reference, ctx: &LintContext<'_>) -> bool {
+    let scopes = ctx.scopes();
+    let reference_scope = ctx.nodes().get_node(reference.node_id()).scope_id();
+    variable_scope(scopes, ctx.symbols().get_scope_id(symbol_id))
+        != variable_scope(scopes, reference_scope)
+}
+
+/// `var a = a`, `for (var a of a)` and `function foo(a = a) {}`
+fn is_in_initializer(symbol_id: SymbolId, reference: &Reference, ctx: &LintContext<'_>) -> bool {
+    if is_outer(symbol_id, reference, ctx) {
+        return false;
+    }
+    let declaration_id = ctx.symbols().get_declaration(symbol_id);
+    let initializer = match ctx.nodes().kind(declaration_id) {
+        AstKind::VariableDeclarator(declarator) => {
+            let right = ctx.nodes().iter_parents(declaration_id).nth(2).and_then(|node| match node
+                .kind()
+            {
+                AstKind::ForInStatement(statement) => Some(statement.right.span()),
+                AstKind::ForOfStatement(statement) => Some(statement.right.span()),
+                _ => None,
+            });
+            right.or_else(|| declarator.init.as_ref().map(GetSpan::span))
+        }
+        AstKind::FormalParameter(param) => match &param.pattern.kind {
+            BindingPatternKind::AssignmentPattern(pattern) => Some(pattern.right.span()),
+            _ => None,
+        },
+        _ => None,
+    };
+    let span = reference.span();
+    initializer
+        .is_some_and(|initializer| initializer.start <= span.start && span.end <= initializer.end)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var a=10; alert(a);", None),
+        ("function b(a) { alert(a); }", None),
+        ("Object.hasOwnProperty.call(a);", None),
+        ("function a() { alert(arguments);}", None),
+        ("a(); function a() { alert(arguments); }", Some(json!(["nofunc"]))),
+        ("(() => { var a = 42; alert(a); })();", None),
+        ("a(); try { throw new Error() } catch (a) {}", None),
+        ("class A {} new A();", None),
+        ("var a = 0, b = a;", None),
+        ("var {a = 0, b = a} = {};", None),
+        ("var [a = 0, b = a] = {};", None),
+        ("function foo() { foo(); }", None),
+        ("var foo = function() { foo(); };", None),
+        ("var a; for (a in a) {}", None),
+        ("var a; for (a of a) {}", None),
+        ("const a = () => a;", None),
+        ("function foo() { new A(); } class A {};", Some(json!([{ "classes": false }]))),
+        ("function foo() { bar; } var bar;", Some(json!([{ "variables": false }]))),
+        ("var foo = () => bar; const bar = 1;", Some(json!([{ "variables": false }]))),
+        ("let a: A; interface A {}", None),
+        ("type B = A; type A = string;", None),
+        ("import { a } from 'a'; a();", None),
+        ("foo(); import { foo } from 'foo';", None),
+        ("export { a }; const a = 1;", None),
+    ];
+
+    let fail = vec![
+        ("a++; var a=19;", None),
+        ("a(); var a=function() {};", None),
+        ("alert(a[1]); var a=[1,3];", None),
+        ("a(); function a() { alert(b); var b=10; a(); }", None),
+        ("a(); var a=function() {};", Some(json!(["nofunc"]))),
+        ("(() => { alert(a); var a = 42; })();", None),
+        ("(() => a())(); function a() { }", None),
+        ("a(); try { throw new Error() } catch (foo) {var a;}", None),
+        ("var f = () => a; var a;", None),
+        ("new A(); class A {};", None),
+        ("function foo() { new A(); } class A {};", None),
+        ("new A(); var A = class {};", Some(json!([{ "classes": false }]))),
+        ("function foo() { new A(); } var A = class {};", None),
+        ("a++; { var a; }", None),
+        ("\"use strict\"; { a(); function a() {} }", None),
+        ("{ a; let a = 1 }", None),
+        ("switch (foo) { case 1:  a();\n default: \n let a;}", None),
+        ("if (true) { function foo() { a; } let a;}", None),
+        ("var a = a;", None),
+        ("let a = a + b;", None),
+        ("const a = foo(a);", None),
+        ("function foo(a = a) {}", None),
+        ("for (var a in a) {}", None),
+        ("for (var a of a) {}", None),
+        ("function foo() { bar; } var bar;", None),
+        ("foo(); function foo() {}", Some(json!([{ "functions": true }]))),
+        ("new A(); class A {}", Some(json!([{ "classes": false }]))),
+        ("A; enum A { B }", None),
+    ];
+
+    Tester::new(NoUseBeforeDefine::NAME, pass, fail).test_and_snapshot();
+}
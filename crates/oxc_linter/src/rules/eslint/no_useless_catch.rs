@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingPatternKind, Expression, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -9,7 +9,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-useless-catch): Unnecessary try/catch wrapper")]
@@ -55,6 +55,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUselessCatch {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TryStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TryStatement(try_stmt) = node.kind() else { return };
         let Some(catch_clause) = &try_stmt.handler else { return };
@@ -67,15 +71,32 @@ impl Rule for NoUselessCatch {
             return;
         };
         let Expression::Identifier(throw_ident) = &throw_stmt.argument else { return };
-        if binding_ident.name == throw_ident.name {
-            if try_stmt.finalizer.is_some() {
-                ctx.diagnostic(NoUselessCatchFinalizerDiagnostic(
-                    binding_ident.span,
-                    throw_stmt.span,
-                ));
-            } else {
-                ctx.diagnostic(NoUselessCatchDiagnostic(binding_ident.span, throw_stmt.span));
+        if binding_ident.name != throw_ident.name {
+            return;
+        }
+        // Comments and unreachable statements after the `throw` would be lost by the fix
+        let is_fixable = catch_clause.body.body.len() == 1
+            && !ctx.semantic().trivias().has_comments_between(catch_clause.span);
+        if try_stmt.finalizer.is_some() {
+            let diagnostic = NoUselessCatchFinalizerDiagnostic(binding_ident.span, throw_stmt.span);
+            if !is_fixable {
+                ctx.diagnostic(diagnostic);
+                return;
             }
+            // `try {} catch (e) { throw e } finally {}` -> `try {} finally {}`
+            ctx.diagnostic_with_fix(diagnostic, || {
+                Fix::delete(Span::new(try_stmt.block.span.end, catch_clause.span.end))
+            });
+        } else {
+            let diagnostic = NoUselessCatchDiagnostic(binding_ident.span, throw_stmt.span);
+            if !is_fixable {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+            // `try { foo() } catch (e) { throw e }` -> `{ foo() }`, the block keeps the scope
+            ctx.diagnostic_with_fix(diagnostic, || {
+                Fix::new(try_stmt.block.span.source_text(ctx.source_text()), try_stmt.span)
+            });
         }
     }
 }
@@ -214,5 +235,24 @@ fn test() {
       ",
     ];
 
-    Tester::new(NoUselessCatch::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("try { foo(); } catch (err) { throw err; }", "{ foo(); }", None),
+        (
+            "try { foo(); } catch (err) { throw err; } finally { bar(); }",
+            "try { foo(); } finally { bar(); }",
+            None,
+        ),
+        (
+            "try { foo(); } catch (err) { /* some comment */ throw err; }",
+            "try { foo(); } catch (err) { /* some comment */ throw err; }",
+            None,
+        ),
+        (
+            "try { foo(); } catch (err) { throw err; bar(); }",
+            "try { foo(); } catch (err) { throw err; bar(); }",
+            None,
+        ),
+    ];
+
+    Tester::new(NoUselessCatch::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -0,0 +1,243 @@
+use oxc_ast::{
+    ast::{Expression, PropertyKey},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-useless-computed-key): Unnecessarily computed property {1} found.")]
+#[diagnostic(severity(warning), help("Replace the computed property with {0}"))]
+struct NoUselessComputedKeyDiagnostic(&'static str, String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessComputedKey {
+    enforce_for_class_members: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows computed property keys which are string or number literals.
+    ///
+    /// ### Why is this bad?
+    /// `{ ["a"]: 1 }` is the same as `{ "a": 1 }`, the brackets only make it harder to read.
+    ///
+    /// ### Options
+    /// * `enforceForClassMembers`: also check class members, defaults to `false`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var a = { ['0']: 0 };
+    /// var a = { ['x']() {} };
+    /// var { ['x']: x } = obj;
+    /// ```
+    NoUselessComputedKey,
+    style
+);
+
+impl Rule for NoUselessComputedKey {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            enforce_for_class_members: value
+                .get(0)
+                .and_then(|config| config.get("enforceForClassMembers"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::ObjectProperty,
+            AstType::ObjectPattern,
+            AstType::MethodDefinition,
+            AstType::PropertyDefinition,
+        ])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ObjectProperty(property) if property.computed => {
+                // `{ ["__proto__"]: a }` does not set the prototype
+                let allowed = (!property.method && !property.shorthand).then_some("__proto__");
+                check(property.span, &property.key, allowed, ctx);
+            }
+            AstKind::ObjectPattern(pattern) => {
+                for property in pattern.properties.iter().filter(|property| property.computed) {
+                    check(property.span, &property.key, None, ctx);
+                }
+            }
+            AstKind::MethodDefinition(method)
+                if method.computed && self.enforce_for_class_members =>
+            {
+                // `["constructor"]() {}` is not the constructor
+                let allowed = if method.r#static { "prototype" } else { "constructor" };
+                check(method.span, &method.key, Some(allowed), ctx);
+            }
+            AstKind::PropertyDefinition(property)
+                if property.computed && self.enforce_for_class_members =>
+            {
+                let allowed = if property.r#static { "prototype" } else { "constructor" };
+                check(property.span, &property.key, Some(allowed), ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check(span: Span, key: &PropertyKey, allowed: Option<&str>, ctx: &LintContext) {
+    let PropertyKey::Expression(key) = key else { return };
+    let key = key.without_parenthesized();
+    let is_number = match key {
+        Expression::StringLiteral(literal) => {
+            if allowed.is_some_and(|allowed| literal.value == allowed) {
+                return;
+            }
+            false
+        }
+        Expression::NumericLiteral(_) => true,
+        _ => return,
+    };
+    let source_text = ctx.source_text();
+    let key_span = key.span();
+    let Some(open) = source_text[span.start as usize..key_span.start as usize].rfind('[') else {
+        return;
+    };
+    let Some(close) = source_text[key_span.end as usize..span.end as usize].find(']') else {
+        return;
+    };
+    #[allow(clippy::cast_possible_truncation)]
+    let brackets = Span::new(span.start + open as u32, key_span.end + close as u32 + 1);
+    let key_text = key_span.source_text(source_text);
+    let diagnostic = NoUselessComputedKeyDiagnostic(
+        if is_number { "a number key" } else { "a string key" },
+        brackets.source_text(source_text).to_string(),
+        brackets,
+    );
+    // Keep the comments inside of the brackets
+    let has_comments =
+        [Span::new(brackets.start + 1, key_span.start), Span::new(key_span.end, brackets.end - 1)]
+            .iter()
+            .any(|span| {
+                span.source_text(source_text)
+                    .chars()
+                    .any(|c| !c.is_whitespace() && c != '(' && c != ')')
+            });
+    if has_comments {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, || {
+        // `get[1]() {}` must not become `get1() {}`
+        let needs_space = key_text.starts_with(|c: char| c.is_ascii_digit())
+            && source_text[..brackets.start as usize]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+        let modified_code = if needs_space { format!(" {key_text}") } else { key_text.to_string() };
+        Fix::new(modified_code, brackets)
+    });
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("({ 'a': 0, b(){} })", None),
+        ("({ [x]: 0 });", None),
+        ("({ a: 0, [b](){} })", None),
+        ("({ ['__proto__']: [] })", None),
+        ("var { 'a': foo, a: bar } = obj;", None),
+        ("var { [a]: b } = obj;", None),
+        ("var { a } = obj;", None),
+        ("({ [`a`]: 0 });", None),
+        ("class Foo { a() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+        ("class Foo { 'a'() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+        ("class Foo { [x]() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+        ("class Foo { ['constructor']() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+        (
+            "class Foo { static ['prototype']() {} }",
+            Some(json!([{ "enforceForClassMembers": true }])),
+        ),
+        ("(class { ['constructor']() {} })", Some(json!([{ "enforceForClassMembers": true }]))),
+        ("class Foo { ['constructor'] = 1 }", Some(json!([{ "enforceForClassMembers": true }]))),
+        (
+            "class Foo { static ['prototype'] = 1 }",
+            Some(json!([{ "enforceForClassMembers": true }])),
+        ),
+        (
+            "class Foo { get ['constructor']() {} }",
+            Some(json!([{ "enforceForClassMembers": true }])),
+        ),
+        ("class Foo { ['x']() {} }", None),
+        ("class Foo { ['x'] = 1 }", Some(json!([{ "enforceForClassMembers": false }]))),
+    ];
+
+    let fail = vec![
+        ("({ ['0']: 0 })", None),
+        ("var { ['0']: a } = obj", None),
+        ("({ ['0+1,234']: 0 })", None),
+        ("({ [0]: 0 })", None),
+        ("var { [0]: a } = obj", None),
+        ("({ ['x']: 0 })", None),
+        ("var { ['x']: a } = obj", None),
+        ("var { ['__proto__']: a } = obj", None),
+        ("({ ['x']() {} })", None),
+        ("({ [/* this comment prevents a fix */ 'x']: 0 })", None),
+        ("({ ['x' /* this comment also prevents a fix */]: 0 })", None),
+        ("({ [('x')]: 0 })", None),
+        ("var { [('x')]: a } = obj", None),
+        ("({ *['x']() {} })", None),
+        ("({ async ['x']() {} })", None),
+        ("({ get[.2]() {} })", None),
+        ("({ set[.2](value) {} })", None),
+        ("({ async[.2]() {} })", None),
+        ("({ [2]() {} })", None),
+        ("({ get [2]() {} })", None),
+        ("({ get[2]() {} })", None),
+        ("({ get['foo']() {} })", None),
+        ("({ ['__proto__']() {} })", None),
+        ("class Foo { ['0']() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+        ("class Foo { ['0+1,234']() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+        ("class Foo { ['x']() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+        (
+            "class Foo { static ['constructor']() {} }",
+            Some(json!([{ "enforceForClassMembers": true }])),
+        ),
+        ("class Foo { ['prototype']() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+        ("class Foo { ['x'] = 1 }", Some(json!([{ "enforceForClassMembers": true }]))),
+        (
+            "class Foo { static ['constructor'] = 1 }",
+            Some(json!([{ "enforceForClassMembers": true }])),
+        ),
+        ("(class { ['x']() {} })", Some(json!([{ "enforceForClassMembers": true }]))),
+    ];
+
+    let fix = vec![
+        ("({ ['0']: 0 })", "({ '0': 0 })", None),
+        ("var { ['0']: a } = obj", "var { '0': a } = obj", None),
+        ("({ [0]: 0 })", "({ 0: 0 })", None),
+        ("({ ['x']() {} })", "({ 'x'() {} })", None),
+        ("({ *['x']() {} })", "({ *'x'() {} })", None),
+        ("({ get[.2]() {} })", "({ get.2() {} })", None),
+        ("({ [('x')]: 0 })", "({ 'x': 0 })", None),
+        ("({ [/* comment */ 'x']: 0 })", "({ [/* comment */ 'x']: 0 })", None),
+        ("({ get[2]() {} })", "({ get 2() {} })", None),
+        ("({ get['foo']() {} })", "({ get'foo'() {} })", None),
+        (
+            "class Foo { ['x'] = 1 }",
+            "class Foo { 'x' = 1 }",
+            Some(json!([{ "enforceForClassMembers": true }])),
+        ),
+    ];
+
+    Tester::new(NoUselessComputedKey::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,109 @@
+use oxc_ast::{ast::Expression, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-useless-concat): Unexpected string concatenation of literals.")]
+#[diagnostic(severity(warning), help("Combine the literals into a single string"))]
+struct NoUselessConcatDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessConcat;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows concatenating two string or template literals on the same line.
+    ///
+    /// ### Why is this bad?
+    /// `"a" + "b"` is the same as `"ab"`. Literals are usually only split on purpose
+    /// when they are too long for a single line.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var a = `some` + `string`;
+    /// var a = '1' + '0';
+    /// ```
+    NoUselessConcat,
+    pedantic
+);
+
+impl Rule for NoUselessConcat {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(expr) = node.kind() else { return };
+        if expr.operator != BinaryOperator::Addition {
+            return;
+        }
+        // The literals next to the `+`, `"b"` and `"c"` in `"a" + "b" + "c"`
+        let mut left = expr.left.without_parenthesized();
+        while let Expression::BinaryExpression(binary) = left {
+            if binary.operator != BinaryOperator::Addition {
+                break;
+            }
+            left = binary.right.without_parenthesized();
+        }
+        let mut right = expr.right.without_parenthesized();
+        while let Expression::BinaryExpression(binary) = right {
+            if binary.operator != BinaryOperator::Addition {
+                break;
+            }
+            right = binary.left.without_parenthesized();
+        }
+        if !is_string_literal(left) || !is_string_literal(right) {
+            return;
+        }
+        let span = Span::new(left.span().start, right.span().end);
+        if !span.source_text(ctx.source_text()).contains(['\n', '\r']) {
+            ctx.diagnostic(NoUselessConcatDiagnostic(span));
+        }
+    }
+}
+
+fn is_string_literal(expr: &Expression) -> bool {
+    matches!(expr, Expression::StringLiteral(_) | Expression::TemplateLiteral(_))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var a = 1 + 1;",
+        "var a = 1 * '2';",
+        "var a = 1 - 2;",
+        "var a = foo + bar;",
+        "var a = 'foo' + bar;",
+        "var foo = 'foo' +\n 'bar';",
+        "var string = (number + 1) + 'px';",
+        "'a' + 1",
+        "1 + '1'",
+        "1 + `1`",
+        "`1` + 1",
+        "(1 + +2) + `b`",
+        "'a' + foo + 'b'",
+        "`a` + foo()\n + `b`",
+    ];
+
+    let fail = vec![
+        "'a' + 'b'",
+        "'a' +\n'b' + 'c'",
+        "foo + 'a' + 'b'",
+        "'a' + 'b' + 'c'",
+        "(foo + 'a') + ('b' + 'c')",
+        "`a` + 'b'",
+        "`a` + `b`",
+        "foo + `a` + `b`",
+    ];
+
+    Tester::new(NoUselessConcat::NAME, pass, fail).test_and_snapshot();
+}
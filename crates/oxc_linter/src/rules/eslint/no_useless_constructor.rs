@@ -0,0 +1,210 @@
+use oxc_ast::{
+    ast::{
+        Argument, BindingPatternKind, Expression, FormalParameters, FunctionBody,
+        MethodDefinitionKind, Statement, TSAccessibility,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-useless-constructor): Useless constructor.")]
+#[diagnostic(severity(warning), help("Remove the constructor"))]
+struct NoUselessConstructorDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessConstructor;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow unnecessary constructors
+    ///
+    /// ### Why is this bad?
+    /// A class without a constructor gets a default one, which is empty for a base class
+    /// and passes all its arguments to `super()` for a derived class.
+    /// An explicit constructor doing the same is redundant, and removed by the fixer.
+    ///
+    /// Constructors with TypeScript parameter properties, decorated parameters,
+    /// or a `private` or `protected` modifier are not reported, as they change the class.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// class A {
+    ///   constructor() {}
+    /// }
+    /// class B extends A {
+    ///   constructor(...args) {
+    ///     super(...args);
+    ///   }
+    /// }
+    /// ```
+    NoUselessConstructor,
+    suspicious
+);
+
+impl Rule for NoUselessConstructor {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::MethodDefinition])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::MethodDefinition(method) = node.kind() else {
+            return;
+        };
+        if method.kind != MethodDefinitionKind::Constructor
+            || matches!(
+                method.accessibility,
+                Some(TSAccessibility::Private | TSAccessibility::Protected)
+            )
+        {
+            return;
+        }
+        let Some(body) = &method.value.body else {
+            return;
+        };
+        let params = &method.value.params;
+        if params.items.iter().any(|param| {
+            param.accessibility.is_some() || param.readonly || !param.decorators.is_empty()
+        }) {
+            return;
+        }
+        let class =
+            ctx.nodes().ancestors(node.id()).skip(1).find_map(|id| match ctx.nodes().kind(id) {
+                AstKind::Class(class) => Some(class),
+                _ => None,
+            });
+        let Some(class) = class else {
+            return;
+        };
+
+        let is_useless = if class.super_class.is_some() {
+            is_redundant_super_call(body, params)
+        } else {
+            body.statements.is_empty() && body.directives.is_empty()
+        };
+        if is_useless {
+            ctx.diagnostic_with_fix(NoUselessConstructorDiagnostic(method.span), || {
+                Fix::delete(method.span)
+            });
+        }
+    }
+}
+
+/// Whether `body` only calls `super` with the parameters of the constructor in order,
+/// or with `...arguments`.
+fn is_redundant_super_call<'a>(body: &FunctionBody<'a>, params: &FormalParameters<'a>) -> bool {
+    let [Statement::ExpressionStatement(stmt)] = body.statements.as_slice() else {
+        return false;
+    };
+    let Expression::CallExpression(call) = &stmt.expression else {
+        return false;
+    };
+    if !matches!(call.callee, Expression::Super(_)) || !body.directives.is_empty() {
+        return false;
+    }
+
+    let binding_name = |kind: &BindingPatternKind<'a>| match kind {
+        BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.clone()),
+        _ => None,
+    };
+    let Some(names) = params
+        .items
+        .iter()
+        .map(|param| binding_name(&param.pattern.kind))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+    let rest = match &params.rest {
+        Some(rest) => match binding_name(&rest.argument.kind) {
+            Some(name) => Some(name),
+            None => return false,
+        },
+        None => None,
+    };
+
+    if let [Argument::SpreadElement(spread)] = call.arguments.as_slice() {
+        if matches!(&spread.argument, Expression::Identifier(ident) if ident.name == "arguments") {
+            return true;
+        }
+    }
+
+    let argument_count = names.len() + usize::from(rest.is_some());
+    if call.arguments.len() != argument_count {
+        return false;
+    }
+    let (arguments, rest_argument) = call.arguments.split_at(names.len());
+    let names_match = names.iter().zip(arguments).all(|(name, argument)| {
+        matches!(argument, Argument::Expression(Expression::Identifier(ident)) if ident.name == name)
+    });
+    let rest_matches = match (rest, rest_argument) {
+        (None, []) => true,
+        (Some(name), [Argument::SpreadElement(spread)]) => {
+            matches!(&spread.argument, Expression::Identifier(ident) if ident.name == name)
+        }
+        _ => false,
+    };
+    names_match && rest_matches
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class A { }",
+        "class A { constructor(){ doSomething(); } }",
+        "class A extends B { constructor(){} }",
+        "class A extends B { constructor(){ super('foo'); } }",
+        "class A extends B { constructor(foo, bar){ super(foo, bar, 1); } }",
+        "class A extends B { constructor(){ super(); doSomething(); } }",
+        "class A extends B { constructor(...args){ super(...args); doSomething(); } }",
+        "class A { dummyMethod(){ doSomething(); } }",
+        "class A extends B.C { constructor() { super(foo); } }",
+        "class A extends B.C { constructor([a, b, c]) { super(...arguments); } }",
+        "class A extends B.C { constructor(a = f()) { super(...arguments); } }",
+        "class A extends B { constructor(a, b, c) { super(a, b); } }",
+        "class A extends B { constructor(foo, bar){ super(foo); } }",
+        "class A extends B { constructor(test) { super(); } }",
+        "class A extends B { constructor() { foo; } }",
+        "class A extends B { constructor(foo, bar) { super(bar); } }",
+        "class A { constructor() { 'use strict'; } }",
+        "class A extends B { constructor(...args) { super(args); } }",
+        "class A { private constructor() {} }",
+        "class A { protected constructor() {} }",
+        "class A extends B { public constructor(private foo: string) { super(); } }",
+        "class A { constructor(@Inject() foo) {} }",
+        "class A { constructor(); constructor(foo?: string) { foo; } }",
+        "declare class A { constructor(); }",
+    ];
+
+    let fail = vec![
+        "class A { constructor(){} }",
+        "class A extends B { constructor() { super(); } }",
+        "class A extends B { constructor(foo){ super(foo); } }",
+        "class A extends B { constructor(foo, bar){ super(foo, bar); } }",
+        "class A extends B { constructor(...args){ super(...args); } }",
+        "class A extends B.C { constructor() { super(...arguments); } }",
+        "class A extends B { constructor(a, b, ...c) { super(...arguments); } }",
+        "class A extends B { constructor(a, b, ...c) { super(a, b, ...c); } }",
+        "class A { public constructor() {} }",
+    ];
+
+    let fix = vec![
+        ("class A { constructor(){} }", "class A {  }", None),
+        (
+            "class A extends B { constructor() { super(); } foo() {} }",
+            "class A extends B {  foo() {} }",
+            None,
+        ),
+    ];
+
+    Tester::new(NoUselessConstructor::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -1,6 +1,6 @@
 use memchr::memmem;
 
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -13,7 +13,10 @@ use crate::{context::LintContext, rule::Rule, AstNode, Fix};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-useless-escape): Unnecessary escape character {0:?}")]
-#[diagnostic(severity(warning))]
+#[diagnostic(
+    severity(warning),
+    help("Remove the `\\` to keep the character, or replace it with `\\\\` to include an actual backslash.")
+)]
 struct NoUselessEscapeDiagnostic(char, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -26,15 +29,27 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Escaping characters which have no special meaning in strings, template literals
+    /// or regular expressions has no effect, and makes the code harder to read.
+    /// The fix removes the backslash, which keeps the value of the literal. If the backslash was
+    /// meant to be part of the string, escape it as `\\` by hand: a diagnostic has a single fix,
+    /// so this is only given as help.
     ///
     /// ### Example
     /// ```javascript
+    /// "\'";
+    /// `\"${foo}\"`;
+    /// /[\-]/;
     /// ```
     NoUselessEscape,
     correctness
 );
 
 impl Rule for NoUselessEscape {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::RegExpLiteral, AstType::StringLiteral, AstType::TemplateLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::RegExpLiteral(literal)
@@ -0,0 +1,272 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, AssignmentTargetMaybeDefault, AssignmentTargetPattern,
+        AssignmentTargetProperty, BindingPatternKind, Expression, ModuleExportName, PropertyKey,
+        SimpleAssignmentTarget,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, CompactString, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUselessRenameDiagnostic {
+    #[error("eslint(no-useless-rename): Destructuring assignment {{{0}}} unnecessarily renamed.")]
+    #[diagnostic(severity(warning), help("Remove the rename"))]
+    Destructuring(CompactString, #[label] Span),
+
+    #[error("eslint(no-useless-rename): Import {{{0}}} unnecessarily renamed.")]
+    #[diagnostic(severity(warning), help("Remove the rename"))]
+    Import(CompactString, #[label] Span),
+
+    #[error("eslint(no-useless-rename): Export {{{0}}} unnecessarily renamed.")]
+    #[diagnostic(severity(warning), help("Remove the rename"))]
+    Export(CompactString, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_field_names)]
+pub struct NoUselessRename {
+    ignore_destructuring: bool,
+    ignore_import: bool,
+    ignore_export: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows renaming import, export, and destructured assignments to the same name.
+    ///
+    /// ### Why is this bad?
+    /// `import { foo as foo }`, `export { foo as foo }` and `const { foo: foo } = bar` are
+    /// the same as not renaming at all.
+    ///
+    /// ### Options
+    /// * `ignoreDestructuring`: do not check destructuring, defaults to `false`.
+    /// * `ignoreImport`: do not check imports, defaults to `false`.
+    /// * `ignoreExport`: do not check exports, defaults to `false`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import { foo as foo } from "bar";
+    /// export { foo as foo };
+    /// let { foo: foo } = bar;
+    /// ```
+    NoUselessRename,
+    style
+);
+
+impl Rule for NoUselessRename {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let option = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self {
+            ignore_destructuring: option("ignoreDestructuring"),
+            ignore_import: option("ignoreImport"),
+            ignore_export: option("ignoreExport"),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::ObjectPattern,
+            AstType::AssignmentTarget,
+            AstType::ImportSpecifier,
+            AstType::ExportNamedDeclaration,
+        ])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ObjectPattern(pattern) if !self.ignore_destructuring => {
+                for property in &pattern.properties {
+                    if property.shorthand || property.computed {
+                        continue;
+                    }
+                    let Some(key) = static_key_name(&property.key) else { continue };
+                    let binding = match &property.value.kind {
+                        BindingPatternKind::BindingIdentifier(ident) => ident,
+                        BindingPatternKind::AssignmentPattern(pattern) => {
+                            let BindingPatternKind::BindingIdentifier(ident) = &pattern.left.kind
+                            else {
+                                continue;
+                            };
+                            ident
+                        }
+                        _ => continue,
+                    };
+                    if binding.name == key {
+                        report_destructuring(key, property.span, property.value.span(), ctx);
+                    }
+                }
+            }
+            AstKind::AssignmentTarget(AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ObjectAssignmentTarget(target),
+            )) if !self.ignore_destructuring => {
+                for property in &target.properties {
+                    let AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) =
+                        property
+                    else {
+                        continue;
+                    };
+                    // `({ [a]: a } = b)`, there is no flag for computed keys
+                    if ctx.source_text()[property.span.start as usize..].starts_with('[') {
+                        continue;
+                    }
+                    let Some(key) = static_key_name(&property.name) else { continue };
+                    let (target, value_span) = match &property.binding {
+                        AssignmentTargetMaybeDefault::AssignmentTarget(target) => {
+                            (target, target.span())
+                        }
+                        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                            (&target.binding, target.span)
+                        }
+                    };
+                    if let AssignmentTarget::SimpleAssignmentTarget(
+                        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+                    ) = target
+                    {
+                        if ident.name == key {
+                            report_destructuring(key, property.span, value_span, ctx);
+                        }
+                    }
+                }
+            }
+            AstKind::ImportSpecifier(specifier) if !self.ignore_import => {
+                let ModuleExportName::Identifier(imported) = &specifier.imported else { return };
+                if imported.span != specifier.local.span && imported.name == specifier.local.name {
+                    let span = Span::new(imported.span.start, specifier.local.span.end);
+                    ctx.diagnostic_with_fix(
+                        NoUselessRenameDiagnostic::Import(imported.name.to_compact_string(), span),
+                        || Fix::new(imported.name.to_string(), span),
+                    );
+                }
+            }
+            AstKind::ExportNamedDeclaration(decl) if !self.ignore_export => {
+                for specifier in &decl.specifiers {
+                    if specifier.local.span() != specifier.exported.span()
+                        && specifier.local.name() == specifier.exported.name()
+                    {
+                        let span =
+                            Span::new(specifier.local.span().start, specifier.exported.span().end);
+                        ctx.diagnostic_with_fix(
+                            NoUselessRenameDiagnostic::Export(
+                                specifier.local.name().to_compact_string(),
+                                span,
+                            ),
+                            || {
+                                let local = specifier.local.span().source_text(ctx.source_text());
+                                Fix::new(local.to_string(), span)
+                            },
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The name of `foo` and `"foo"` keys
+fn static_key_name<'a, 'b>(key: &'b PropertyKey<'a>) -> Option<&'b Atom<'a>> {
+    match key {
+        PropertyKey::Identifier(ident) => Some(&ident.name),
+        PropertyKey::Expression(Expression::StringLiteral(literal)) => Some(&literal.value),
+        _ => None,
+    }
+}
+
+/// Replaces `foo: foo = 1` with `foo = 1`
+fn report_destructuring(name: &Atom, property: Span, value: Span, ctx: &LintContext) {
+    ctx.diagnostic_with_fix(
+        NoUselessRenameDiagnostic::Destructuring(name.to_compact_string(), property),
+        || Fix::new(value.source_text(ctx.source_text()).to_string(), property),
+    );
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("let {foo} = obj;", None),
+        ("let {foo: bar} = obj;", None),
+        ("let {foo: bar, baz: qux} = obj;", None),
+        ("let {foo: {bar: baz}} = obj;", None),
+        ("let {foo, bar: {baz: qux}} = obj;", None),
+        ("let {'foo': bar} = obj;", None),
+        ("let {'foo': {'bar': baz}} = obj;", None),
+        ("let {['foo']: foo} = obj;", None),
+        ("let {[foo]: foo} = obj;", None),
+        ("let {foo = 1} = obj;", None),
+        ("let {foo: bar = 1} = obj;", None),
+        ("function func({foo}) {}", None),
+        ("function func({foo: bar}) {}", None),
+        ("({foo, bar} = obj);", None),
+        ("({foo: bar} = obj);", None),
+        ("({[foo]: foo} = obj);", None),
+        ("({['foo']: foo} = obj);", None),
+        ("({foo: a.foo} = obj);", None),
+        ("import * as foo from 'foo';", None),
+        ("import foo from 'foo';", None),
+        ("import {foo} from 'foo';", None),
+        ("import {foo as bar} from 'foo';", None),
+        ("import {'foo' as foo} from 'foo';", None),
+        ("export {foo};", None),
+        ("export {foo as bar};", None),
+        ("export {foo as bar} from 'foo';", None),
+        ("export {'foo' as bar} from 'foo';", None),
+        ("export {foo as 'bar'} from 'foo';", None),
+        ("export * as foo from 'foo';", None),
+        ("let {foo: foo} = obj;", Some(json!([{ "ignoreDestructuring": true }]))),
+        ("({foo: foo} = obj);", Some(json!([{ "ignoreDestructuring": true }]))),
+        ("import {foo as foo} from 'foo';", Some(json!([{ "ignoreImport": true }]))),
+        ("export {foo as foo};", Some(json!([{ "ignoreExport": true }]))),
+    ];
+
+    let fail = vec![
+        ("let {foo: foo} = obj;", None),
+        ("let {a, foo: foo} = obj;", None),
+        ("let {foo: foo, bar: baz} = obj;", None),
+        ("let {foo: bar, baz: baz} = obj;", None),
+        ("let {foo: foo, bar: bar} = obj;", None),
+        ("let {foo: {bar: bar}} = obj;", None),
+        ("let {'foo': foo} = obj;", None),
+        ("let {foo: foo = 1, 'bar': bar = 1, baz: baz} = obj;", None),
+        ("function func({foo: foo}) {}", None),
+        ("({foo: foo} = obj);", None),
+        ("({foo: foo = 1} = obj);", None),
+        ("import {foo as foo} from 'foo';", None),
+        ("import {foo as foo, bar as baz} from 'foo';", None),
+        ("export {foo as foo};", None),
+        ("export {foo as foo, bar as baz};", None),
+        ("export {foo as foo} from 'foo';", None),
+        ("export {'foo' as 'foo'} from 'foo';", None),
+    ];
+
+    let fix = vec![
+        ("let {foo: foo} = obj;", "let {foo} = obj;", None),
+        ("let {'foo': foo} = obj;", "let {foo} = obj;", None),
+        ("let {foo: foo = 1} = obj;", "let {foo = 1} = obj;", None),
+        ("let {foo: {bar: bar}} = obj;", "let {foo: {bar}} = obj;", None),
+        ("function func({foo: foo}) {}", "function func({foo}) {}", None),
+        ("({foo: foo} = obj);", "({foo} = obj);", None),
+        ("({foo: foo = 1} = obj);", "({foo = 1} = obj);", None),
+        ("import {foo as foo} from 'foo';", "import {foo} from 'foo';", None),
+        ("export {foo as foo};", "export {foo};", None),
+        ("export {'foo' as 'foo'} from 'foo';", "export {'foo'} from 'foo';", None),
+    ];
+
+    Tester::new(NoUselessRename::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
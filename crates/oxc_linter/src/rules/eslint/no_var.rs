@@ -1,4 +1,4 @@
-use oxc_ast::{ast::VariableDeclarationKind, AstKind};
+use oxc_ast::{ast::VariableDeclarationKind, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -43,6 +43,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoVar {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::VariableDeclaration])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::VariableDeclaration(dec) = node.kind() {
             if dec.kind == VariableDeclarationKind::Var {
@@ -1,6 +1,6 @@
 use crate::{context::LintContext, rule::Rule, AstNode};
 
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -51,6 +51,10 @@ impl Rule for NoVoid {
         Self { allow_as_statement }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::UnaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::UnaryExpression(unary_expr) = node.kind() else {
             return;
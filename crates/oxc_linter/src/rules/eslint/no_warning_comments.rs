@@ -0,0 +1,247 @@
+use oxc_ast::AstType;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::{Regex, RegexBuilder};
+
+use crate::{
+    ast_util::{get_comment_span, is_directive_comment},
+    context::LintContext,
+    rule::Rule,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-warning-comments): Unexpected '{0}' comment: '{1}'.")]
+#[diagnostic(severity(warning), help("Resolve the comment, or track it in an issue"))]
+struct NoWarningCommentsDiagnostic(String, String, #[label] pub Span);
+
+/// The length the content of a comment is shortened to in the diagnostic
+const COMMENT_DISPLAY_LIMIT: usize = 40;
+
+#[derive(Debug, Clone)]
+pub struct NoWarningComments(Box<NoWarningCommentsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoWarningCommentsConfig {
+    /// Each term with its pattern
+    terms: Vec<(String, Regex)>,
+}
+
+impl std::ops::Deref for NoWarningComments {
+    type Target = NoWarningCommentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoWarningComments {
+    fn default() -> Self {
+        Self::new(&["todo", "fixme", "xxx"], Location::Start, "")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Start,
+    Anywhere,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow specified warning terms in comments
+    ///
+    /// ### Why is this bad?
+    /// Comments like `TODO` or `FIXME` mark unfinished code, which should be
+    /// completed or tracked elsewhere before the code ships.
+    ///
+    /// ### Options
+    /// * `terms`: the case-insensitive terms to report, defaults to `["todo", "fixme", "xxx"]`.
+    /// * `location`: `"start"` (default) to only match terms at the start of comments,
+    ///   or `"anywhere"`.
+    /// * `decoration`: characters ignored before a term at the start of comments, e.g. `["*"]`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function callback(err, results) {
+    ///   // TODO: handle the error
+    ///   console.log(results);
+    /// }
+    /// ```
+    NoWarningComments,
+    pedantic
+);
+
+impl Rule for NoWarningComments {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let terms = config
+            .get("terms")
+            .and_then(serde_json::Value::as_array)
+            .map(|terms| terms.iter().filter_map(serde_json::Value::as_str).collect::<Vec<_>>());
+        let location = match config.get("location").and_then(serde_json::Value::as_str) {
+            Some("anywhere") => Location::Anywhere,
+            _ => Location::Start,
+        };
+        let decoration = config
+            .get("decoration")
+            .and_then(serde_json::Value::as_array)
+            .map(|decoration| {
+                decoration.iter().filter_map(serde_json::Value::as_str).collect::<String>()
+            })
+            .unwrap_or_default();
+
+        Self::new(terms.as_deref().unwrap_or(&["todo", "fixme", "xxx"]), location, &decoration)
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        for (comment, span) in ctx.semantic().trivias().comments_spans() {
+            let content = span.source_text(source_text);
+            // Don't report the comment configuring this rule
+            if is_directive_comment(comment, content) && content.contains("no-warning-comments") {
+                continue;
+            }
+            for (term, pattern) in &self.terms {
+                if pattern.is_match(content) {
+                    ctx.diagnostic(NoWarningCommentsDiagnostic(
+                        term.clone(),
+                        shorten_comment(content),
+                        get_comment_span(span, comment),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl NoWarningComments {
+    fn new(terms: &[&str], location: Location, decoration: &str) -> Self {
+        let terms = terms
+            .iter()
+            .filter_map(|term| {
+                let escaped = regex::escape(term);
+                let prefix = match location {
+                    Location::Start => format!(r"^[\s{}]*", regex::escape(decoration)),
+                    Location::Anywhere if starts_with_word_char(term) => r"\b".to_string(),
+                    Location::Anywhere => String::new(),
+                };
+                let suffix = if term.ends_with(is_word_char) { r"\b" } else { "" };
+                RegexBuilder::new(&format!("{prefix}{escaped}{suffix}"))
+                    .case_insensitive(true)
+                    .build()
+                    .ok()
+                    .map(|pattern| ((*term).to_string(), pattern))
+            })
+            .collect();
+        Self(Box::new(NoWarningCommentsConfig { terms }))
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn starts_with_word_char(term: &str) -> bool {
+    term.starts_with(is_word_char)
+}
+
+/// The words of `content` up to `COMMENT_DISPLAY_LIMIT` characters, followed by `...` if shortened.
+fn shorten_comment(content: &str) -> String {
+    let mut shortened = String::new();
+    for word in content.split_whitespace() {
+        let length =
+            if shortened.is_empty() { word.len() } else { shortened.len() + 1 + word.len() };
+        if length > COMMENT_DISPLAY_LIMIT {
+            shortened.push_str("...");
+            break;
+        }
+        if !shortened.is_empty() {
+            shortened.push(' ');
+        }
+        shortened.push_str(word);
+    }
+    shortened
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("// any comment", Some(serde_json::json!([{ "terms": ["fixme"] }]))),
+        ("// any comment", Some(serde_json::json!([{ "terms": ["fixme", "todo"] }]))),
+        ("// any comment", None),
+        ("// any comment", Some(serde_json::json!([{ "location": "anywhere" }]))),
+        (
+            "// any comment with TODO, FIXME or XXX",
+            Some(serde_json::json!([{ "location": "start" }])),
+        ),
+        ("// any comment with TODO, FIXME or XXX", None),
+        ("/* any block comment */", Some(serde_json::json!([{ "terms": ["fixme"] }]))),
+        ("/* any block comment */", None),
+        ("/* any block comment with TODO, FIXME or XXX */", None),
+        ("/** any block comment with TODO, FIXME or XXX */", None),
+        ("var x = 10; // fixme", Some(serde_json::json!([{ "terms": ["todo"] }]))),
+        ("var x = 10; /* fixme */", Some(serde_json::json!([{ "terms": ["todo"] }]))),
+        ("// foo", Some(serde_json::json!([{ "terms": ["foo-bar"] }]))),
+        ("/** multi-line block comment with lines starting with\nTODO\nFIXME or\nXXX\n*/", None),
+        ("//!TODO ", Some(serde_json::json!([{ "decoration": ["*"] }]))),
+        ("// eslint-disable-line todo", None),
+        (
+            "/* eslint no-warning-comments: [2, { terms: ['todo', 'fixme'] , location: 'anywhere' }] */",
+            Some(serde_json::json!([{ "location": "anywhere" }])),
+        ),
+        ("// todos are fine", None),
+    ];
+
+    let fail = vec![
+        ("// fixme", None),
+        ("// any fixme", Some(serde_json::json!([{ "location": "anywhere" }]))),
+        ("// any fixme", Some(serde_json::json!([{ "terms": ["fixme"], "location": "anywhere" }]))),
+        ("// any FIXME", Some(serde_json::json!([{ "terms": ["fixme"], "location": "anywhere" }]))),
+        ("// any fIxMe", Some(serde_json::json!([{ "terms": ["fixme"], "location": "anywhere" }]))),
+        (
+            "/* any fixme */",
+            Some(serde_json::json!([{ "terms": ["FIXME"], "location": "anywhere" }])),
+        ),
+        (
+            "/* any FIXME */",
+            Some(serde_json::json!([{ "terms": ["FIXME"], "location": "anywhere" }])),
+        ),
+        (
+            "// any fixme or todo",
+            Some(serde_json::json!([{ "terms": ["fixme", "todo"], "location": "anywhere" }])),
+        ),
+        (
+            "/* any fixme or todo */",
+            Some(serde_json::json!([{ "terms": ["fixme", "todo"], "location": "anywhere" }])),
+        ),
+        ("/* fixme and todo */", Some(serde_json::json!([{ "terms": ["fixme", "todo"] }]))),
+        ("/* any fixme */", Some(serde_json::json!([{ "location": "anywhere" }]))),
+        ("/* fixme! */", Some(serde_json::json!([{ "terms": ["fixme"] }]))),
+        (
+            "// regex [litera|$]",
+            Some(serde_json::json!([{ "terms": ["[litera|$]"], "location": "anywhere" }])),
+        ),
+        ("/* eslint one-var: 2 */", Some(serde_json::json!([{ "terms": ["eslint"] }]))),
+        (
+            "// foo bar baz qux garply waldo fred plugh xyzzy thud fixme",
+            Some(serde_json::json!([{ "location": "anywhere" }])),
+        ),
+        ("//**TODO ", Some(serde_json::json!([{ "decoration": ["*"] }]))),
+        ("// !TODO ", Some(serde_json::json!([{ "decoration": ["!"] }]))),
+        ("var x = 10; // TODO: fix it", None),
+    ];
+
+    Tester::new(NoWarningComments::NAME, pass, fail).test_and_snapshot();
+}
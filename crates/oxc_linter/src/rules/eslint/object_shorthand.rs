@@ -0,0 +1,336 @@
+use oxc_ast::{
+    ast::{Expression, Function, ObjectProperty, PropertyKey, PropertyKind},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ObjectShorthandDiagnostic {
+    #[error("eslint(object-shorthand): Expected method shorthand.")]
+    #[diagnostic(severity(warning))]
+    MethodShorthand(#[label] Span),
+
+    #[error("eslint(object-shorthand): Expected property shorthand.")]
+    #[diagnostic(severity(warning))]
+    PropertyShorthand(#[label] Span),
+
+    #[error("eslint(object-shorthand): Expected longform method syntax.")]
+    #[diagnostic(severity(warning))]
+    MethodLongform(#[label] Span),
+
+    #[error("eslint(object-shorthand): Expected longform property syntax.")]
+    #[diagnostic(severity(warning))]
+    PropertyLongform(#[label] Span),
+
+    #[error("eslint(object-shorthand): Expected longform method syntax for string literal keys.")]
+    #[diagnostic(severity(warning))]
+    LiteralMethodLongform(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    #[default]
+    Always,
+    Methods,
+    Properties,
+    Never,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ObjectShorthand {
+    mode: Mode,
+    avoid_quotes: bool,
+    ignore_constructors: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Requires or disallows the shorthand syntax for methods and properties of object literals.
+    ///
+    /// ### Why is this bad?
+    /// `{ a: a }` and `{ a: function() {} }` repeat what the shorthand `{ a }` and `{ a() {} }`
+    /// say more concisely. Mixing both styles in a code base is inconsistent.
+    ///
+    /// ### Options
+    /// The first option is one of:
+    /// * `"always"` (default): expect the shorthand whenever possible.
+    /// * `"methods"`: only expect the shorthand for methods.
+    /// * `"properties"`: only expect the shorthand for properties.
+    /// * `"never"`: expect the longform for both.
+    ///
+    /// The second option is an object with:
+    /// * `avoidQuotes`: prefer the longform when the key is a string literal, defaults to `false`.
+    /// * `ignoreConstructors`: do not expect the shorthand for methods whose name starts with
+    ///   an uppercase letter, defaults to `false`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = {
+    ///     w: function() {},
+    ///     x: function* () {},
+    ///     [y]: function() {},
+    ///     z: z,
+    /// };
+    /// ```
+    ObjectShorthand,
+    style
+);
+
+impl Rule for ObjectShorthand {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("methods") => Mode::Methods,
+            Some("properties") => Mode::Properties,
+            Some("never") => Mode::Never,
+            _ => Mode::Always,
+        };
+        let config = value.get(1);
+        let option = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self {
+            mode,
+            avoid_quotes: option("avoidQuotes"),
+            ignore_constructors: option("ignoreConstructors"),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ObjectProperty])
+    }
+
+    fn needs_tokens() -> bool {
+        true
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ObjectProperty(property) = node.kind() else { return };
+        if property.kind != PropertyKind::Init {
+            return;
+        }
+        let is_string_key =
+            matches!(property.key, PropertyKey::Expression(Expression::StringLiteral(_)))
+                && !property.computed;
+
+        if property.shorthand {
+            if self.mode == Mode::Never {
+                ctx.diagnostic_with_fix(
+                    ObjectShorthandDiagnostic::PropertyLongform(property.span),
+                    || {
+                        let name = property.span.source_text(ctx.source_text());
+                        Fix::new(format!("{name}: {name}"), property.span)
+                    },
+                );
+            }
+            return;
+        }
+
+        if property.method {
+            let Expression::FunctionExpression(function) = &property.value else { return };
+            let diagnostic = if self.mode == Mode::Never {
+                ObjectShorthandDiagnostic::MethodLongform(property.span)
+            } else if self.avoid_quotes && is_string_key {
+                ObjectShorthandDiagnostic::LiteralMethodLongform(property.span)
+            } else {
+                return;
+            };
+            ctx.diagnostic_with_fix(diagnostic, || {
+                let source_text = ctx.source_text();
+                let key = key_span(property, ctx).source_text(source_text);
+                let signature = Span::new(signature_start(function), function.span.end);
+                let modified_code = format!(
+                    "{key}: {}function{} {}",
+                    if function.r#async { "async " } else { "" },
+                    if function.generator { "*" } else { "" },
+                    signature.source_text(source_text)
+                );
+                Fix::new(modified_code, property.span)
+            });
+            return;
+        }
+
+        match &property.value {
+            Expression::FunctionExpression(function)
+                if function.id.is_none()
+                    && matches!(self.mode, Mode::Always | Mode::Methods)
+                    && !(self.avoid_quotes && is_string_key)
+                    && !(self.ignore_constructors && is_constructor_name(&property.key)) =>
+            {
+                ctx.diagnostic_with_fix(
+                    ObjectShorthandDiagnostic::MethodShorthand(property.span),
+                    || {
+                        let source_text = ctx.source_text();
+                        let key = key_span(property, ctx).source_text(source_text);
+                        let signature = Span::new(signature_start(function), function.span.end);
+                        let modified_code = format!(
+                            "{}{}{key}{}",
+                            if function.r#async { "async " } else { "" },
+                            if function.generator { "*" } else { "" },
+                            signature.source_text(source_text)
+                        );
+                        Fix::new(modified_code, property.span)
+                    },
+                );
+            }
+            Expression::Identifier(ident)
+                if !property.computed
+                    && matches!(self.mode, Mode::Always | Mode::Properties)
+                    && !(self.avoid_quotes && is_string_key)
+                    && property.key.static_name().is_some_and(|name| name == ident.name) =>
+            {
+                ctx.diagnostic_with_fix(
+                    ObjectShorthandDiagnostic::PropertyShorthand(property.span),
+                    || Fix::new(ident.name.to_string(), property.span),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The span of the key, including the brackets of computed keys
+fn key_span(property: &ObjectProperty, ctx: &LintContext) -> Span {
+    let key = property.key.span();
+    if !property.computed {
+        return key;
+    }
+    let start = ctx.get_token_before(key).map_or(key.start, |token| token.start);
+    let end = ctx.get_token_after(key).map_or(key.end, |token| token.end);
+    Span::new(start, end)
+}
+
+/// Where the type parameters or parameters of the function start
+fn signature_start(function: &Function) -> u32 {
+    function
+        .type_parameters
+        .as_ref()
+        .map_or(function.params.span.start, |type_parameters| type_parameters.span.start)
+}
+
+fn is_constructor_name(key: &PropertyKey) -> bool {
+    key.static_name().is_some_and(|name| name.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var x = {y() {}}", None),
+        ("var x = {y}", None),
+        ("var x = {a: b}", None),
+        ("var x = {a: 'a'}", None),
+        ("var x = {'a': 'a'}", None),
+        ("var x = {'a': b}", None),
+        ("var x = {y(x) {}}", None),
+        ("var {x,y,z} = x", None),
+        ("var {x: {y}} = z", None),
+        ("var x = {*x() {}}", None),
+        ("var x = {x: y}", None),
+        ("var x = {x: y, y: z}", None),
+        ("var x = {x: y, y: z, z: 'z'}", None),
+        ("var x = {x() {}, y: z, l(){}}", None),
+        ("var x = {x: y, y: z, a: b}", None),
+        ("var x = {x: y, y: z, 'a': b}", None),
+        ("var x = {x: y, y() {}, z: a}", None),
+        ("var x = {[x]: function() {}}", Some(json!(["properties"]))),
+        ("doSomething({x: y})", None),
+        ("doSomething({'x': y})", None),
+        ("doSomething({x: 'x'})", None),
+        ("doSomething({'x': 'x'})", None),
+        ("doSomething({y() {}})", None),
+        ("doSomething({x: y, y() {}})", None),
+        ("doSomething({y() {}, z: a})", None),
+        ("!{ a: function a(){} };", None),
+        ("var x = {y: (x)=>x}", None),
+        ("doSomething({y: (x)=>x})", None),
+        ("var x = {y: (x)=>x, y: a}", None),
+        ("doSomething({x, y: (x)=>x})", None),
+        ("({ foo: x => { return; }})", None),
+        ("({ foo: (x) => { return; }})", None),
+        ("({ foo: () => { return; }})", None),
+        ("var x = {get y() {}}", None),
+        ("var x = {set y(z) {}}", None),
+        ("var x = {get y() {}, set y(z) {}}", None),
+        ("doSomething({get y() {}})", None),
+        ("doSomething({set y(z) {}})", None),
+        ("var x = {y: y}", Some(json!(["methods"]))),
+        ("var x = {y: function() {}}", Some(json!(["properties"]))),
+        ("var x = {y: function() {}}", Some(json!(["never"]))),
+        ("var x = {y: y}", Some(json!(["never"]))),
+        ("var x = {'a'() {}}", None),
+        ("var x = {'a': function() {}}", Some(json!(["always", { "avoidQuotes": true }]))),
+        ("var x = {'a': a}", Some(json!(["always", { "avoidQuotes": true }]))),
+        (
+            "var x = {ConstructorFunction: function(){}, a: b}",
+            Some(json!(["always", { "ignoreConstructors": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var x = {x: x}", None),
+        ("var x = {'x': x}", None),
+        ("var x = {y: y, x: x}", None),
+        ("var x = {y: z, x: x, a: b}", None),
+        ("var x = {y: z,\n x: x,\n a: b\n // comment \n}", None),
+        ("var x = {x: function() {}}", None),
+        ("var x = {y: function() {}}", None),
+        ("var x = {x: y, y: function() {}}", None),
+        ("var x = {[y]: function() {}}", None),
+        ("var x = {'y': function() {}}", None),
+        ("var x = {x: async function() {}}", None),
+        ("var x = {x: function*() {}}", None),
+        ("var x = {x: async function* (a, b) {}}", None),
+        ("var x = {x: function<T>(a: T) {}}", None),
+        ("doSomething({x: x})", None),
+        ("doSomething({x: function() {}})", None),
+        ("var x = {y: y}", Some(json!(["properties"]))),
+        ("var x = {y: function() {}}", Some(json!(["methods"]))),
+        ("var x = {y() {}}", Some(json!(["never"]))),
+        ("var x = {*y() {}}", Some(json!(["never"]))),
+        ("var x = {async y(a) {}}", Some(json!(["never"]))),
+        ("var x = {[y]() {}}", Some(json!(["never"]))),
+        ("var x = {y}", Some(json!(["never"]))),
+        ("var x = {'a'() {}}", Some(json!(["always", { "avoidQuotes": true }]))),
+        ("var x = {ConstructorFunction: function(){}, a: b}", None),
+        (
+            "var x = {a: function(){}, ConstructorFunction: function(){}}",
+            Some(json!(["always", { "ignoreConstructors": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("var x = {x: x}", "var x = {x}", None),
+        ("var x = {'x': x}", "var x = {x}", None),
+        ("var x = {x: function() {}}", "var x = {x() {}}", None),
+        ("var x = {[y]: function(a) {}}", "var x = {[y](a) {}}", None),
+        ("var x = {'y': function() {}}", "var x = {'y'() {}}", None),
+        ("var x = {x: async function() {}}", "var x = {async x() {}}", None),
+        ("var x = {x: function*() {}}", "var x = {*x() {}}", None),
+        ("var x = {x: async function* (a, b) {}}", "var x = {async *x(a, b) {}}", None),
+        ("var x = {x: function<T>(a: T) {}}", "var x = {x<T>(a: T) {}}", None),
+        ("var x = {y() {}}", "var x = {y: function () {}}", Some(json!(["never"]))),
+        ("var x = {*y() {}}", "var x = {y: function* () {}}", Some(json!(["never"]))),
+        ("var x = {async y(a) {}}", "var x = {y: async function (a) {}}", Some(json!(["never"]))),
+        ("var x = {[y]() {}}", "var x = {[y]: function () {}}", Some(json!(["never"]))),
+        ("var x = {y}", "var x = {y: y}", Some(json!(["never"]))),
+        (
+            "var x = {'a'() {}}",
+            "var x = {'a': function () {}}",
+            Some(json!(["always", { "avoidQuotes": true }])),
+        ),
+    ];
+
+    Tester::new(ObjectShorthand::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
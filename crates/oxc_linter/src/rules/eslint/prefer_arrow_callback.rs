@@ -0,0 +1,310 @@
+use oxc_ast::{
+    ast::{Argument, BindingPatternKind, CallExpression, Expression, Function},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    ast_util::{get_function_context_usages, FunctionContextUsage},
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-arrow-callback): Unexpected function expression.")]
+#[diagnostic(severity(warning), help("Use an arrow function as callback"))]
+struct PreferArrowCallbackDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct PreferArrowCallback {
+    allow_named_functions: bool,
+    allow_unbound_this: bool,
+}
+
+impl Default for PreferArrowCallback {
+    fn default() -> Self {
+        Self { allow_named_functions: false, allow_unbound_this: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Require using arrow functions for callbacks
+    ///
+    /// ### Why is this bad?
+    /// Arrow functions are shorter than function expressions, and they bind `this` lexically,
+    /// which is usually what a callback needs.
+    ///
+    /// Function expressions using `arguments`, `super` or `new.target`, generators and
+    /// recursive named functions are not reported, since an arrow function cannot replace them.
+    /// Neither are functions using `this` unless they are bound with `.bind(this)`,
+    /// or `allowUnboundThis` is `false`. Named functions are allowed with `allowNamedFunctions`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// foo(function(a) { return a; });
+    /// foo(function() { return this.a; }.bind(this));
+    ///
+    /// // good
+    /// foo(a => a);
+    /// foo(function*() { yield; });
+    /// ```
+    PreferArrowCallback,
+    style
+);
+
+/// How a function expression is passed as a callback
+struct Callback<'a> {
+    /// The first `.bind()` call of the function, e.g. `function() {}.bind(this)`
+    bind_call: Option<(AstNodeId, &'a CallExpression<'a>)>,
+    /// Whether the function is bound to the `this` of its enclosing function
+    is_lexical_this: bool,
+}
+
+impl Rule for PreferArrowCallback {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let get_bool = |name: &str, default: bool| {
+            config.get(name).and_then(serde_json::Value::as_bool).unwrap_or(default)
+        };
+        Self {
+            allow_named_functions: get_bool("allowNamedFunctions", false),
+            allow_unbound_this: get_bool("allowUnboundThis", true),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let usages = get_function_context_usages(ctx);
+
+        for node in ctx.nodes().iter() {
+            let AstKind::Function(func) = node.kind() else { continue };
+            if !func.is_expression() || func.generator {
+                continue;
+            }
+            if let Some(id) = &func.id {
+                if self.allow_named_functions {
+                    continue;
+                }
+                // Recursive functions refer to themselves by their name
+                if id.symbol_id.get().is_some_and(|symbol_id| {
+                    ctx.semantic().symbol_references(symbol_id).next().is_some()
+                }) {
+                    continue;
+                }
+            }
+            let usage = usages.get(&node.id()).copied().unwrap_or_default();
+            if usage.uses_arguments || usage.uses_super || usage.uses_new_target {
+                continue;
+            }
+            let Some(callback) = get_callback(node.id(), ctx) else { continue };
+            if usage.uses_this && self.allow_unbound_this && !callback.is_lexical_this {
+                continue;
+            }
+
+            let diagnostic = PreferArrowCallbackDiagnostic(func.span);
+            match fix_callback(node.id(), func, &callback, usage, ctx) {
+                Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+/// The callback the function expression `id` is an argument of, through logical and conditional
+/// expressions and `.bind()` calls like ESLint
+fn get_callback<'a>(id: AstNodeId, ctx: &LintContext<'a>) -> Option<Callback<'a>> {
+    let mut callback = Callback { bind_call: None, is_lexical_this: false };
+    let mut current = ctx.nodes().get_node(id);
+    loop {
+        let parent = ctx.nodes().parent_node(current.id())?;
+        match parent.kind() {
+            AstKind::LogicalExpression(_)
+            | AstKind::ConditionalExpression(_)
+            | AstKind::ChainExpression(_)
+            | AstKind::ParenthesizedExpression(_) => current = parent,
+            AstKind::MemberExpression(member)
+                if !member.is_computed()
+                    && member.static_property_name() == Some("bind")
+                    && member.object().span() == current.kind().span() =>
+            {
+                let call_node = ctx.nodes().parent_node(parent.id())?;
+                let AstKind::CallExpression(call) = call_node.kind() else { return None };
+                if call.callee.span() != member.span() {
+                    return None;
+                }
+                if callback.bind_call.is_none() {
+                    callback.bind_call = Some((call_node.id(), call));
+                    callback.is_lexical_this = matches!(
+                        call.arguments.as_slice(),
+                        [Argument::Expression(Expression::ThisExpression(_))]
+                    );
+                }
+                current = call_node;
+            }
+            AstKind::Argument(_) => return Some(callback),
+            _ => return None,
+        }
+    }
+}
+
+/// Replace the function expression by an arrow function, and remove `.bind(this)`
+fn fix_callback<'a>(
+    id: AstNodeId,
+    func: &Function<'a>,
+    callback: &Callback<'a>,
+    usage: FunctionContextUsage,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    // Without `.bind(this)`, the `this` of the callback depends on its caller
+    if usage.uses_this && !callback.is_lexical_this {
+        return None;
+    }
+    // Duplicate parameters are allowed in sloppy mode functions, but not in arrow functions.
+    // Type parameters would be read as JSX.
+    if has_duplicate_params(func) || func.this_param.is_some() || func.type_parameters.is_some() {
+        return None;
+    }
+    let body = func.body.as_ref()?;
+    let trivias = ctx.semantic().trivias();
+    if trivias.has_comments_between(Span::new(func.span.start, func.params.span.start)) {
+        return None;
+    }
+
+    let source_text = ctx.source_text();
+    let head = Span::new(func.params.span.start, body.span.start).source_text(source_text);
+    let arrow = format!(
+        "{}{} => {}",
+        if func.r#async { "async " } else { "" },
+        head.trim_end(),
+        body.span.source_text(source_text)
+    );
+
+    let (replaced, arrow) = match callback.bind_call {
+        Some((call_id, call)) if callback.is_lexical_this => {
+            let Expression::MemberExpression(member) = &call.callee else { return None };
+            // E.g. `(foo || function() {}).bind(this)`
+            if member.object().without_parenthesized().span() != func.span
+                || trivias
+                    .has_comments_between(Span::new(member.object().span().end, call.span.end))
+            {
+                return None;
+            }
+            let arrow = format!(
+                "{}{arrow}{}",
+                Span::new(call.span.start, func.span.start).source_text(source_text),
+                Span::new(func.span.end, member.object().span().end).source_text(source_text),
+            );
+            (call_id, arrow)
+        }
+        _ => (id, arrow),
+    };
+
+    // `foo || () => {}` and `() => {}.bind(a)` are syntax errors
+    let span = ctx.nodes().kind(replaced).span();
+    let needs_parens =
+        ctx.nodes().parent_node(replaced).is_some_and(|parent| match parent.kind() {
+            AstKind::LogicalExpression(_) => true,
+            AstKind::ConditionalExpression(expr) => expr.test.span() == span,
+            AstKind::MemberExpression(expr) => expr.object().span() == span,
+            _ => false,
+        });
+    let arrow = if needs_parens { format!("({arrow})") } else { arrow };
+    Some(Fix::new(arrow, span))
+}
+
+fn has_duplicate_params(func: &Function) -> bool {
+    let mut names = FxHashSet::default();
+    func.params.items.iter().any(|param| match &param.pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => !names.insert(ident.name.as_str()),
+        _ => false,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("foo(a => a);", None),
+        ("foo(function*() {});", None),
+        ("foo(function() { this; });", None),
+        ("foo(function bar() {});", Some(serde_json::json!([{ "allowNamedFunctions": true }]))),
+        ("foo(function() { (() => this); });", None),
+        ("foo(function() { this; }.bind(obj));", None),
+        ("foo(function() { this; }.call(this));", None),
+        ("foo(function() { arguments; });", None),
+        ("foo(function() { (() => arguments); });", None),
+        ("foo(function() { super.a; });", None),
+        ("foo(function() { new.target; });", None),
+        ("foo(function bar() { bar(); });", None),
+        ("foo(function bar() { return () => bar; });", None),
+        ("foo(bar || function() { this; }.bind(obj));", None),
+        ("foo(function() {}.bind(this).call());", None),
+        ("foo(() => { function bar() {} });", None),
+        ("const foo = function() {};", None),
+        ("foo(function bar() {}.bar);", None),
+        ("foo(function() {}[bind](this));", None),
+        ("(function() {})();", None),
+        ("const foo = function() { return this; }.bind(this);", None),
+    ];
+
+    let fail = vec![
+        ("foo(function() {});", None),
+        ("foo(function bar() {});", None),
+        ("foo(bar, function() {});", None),
+        ("new Foo(function() {});", None),
+        ("foo(function() {}.bind(this));", None),
+        ("foo(function() { this; }.bind(this));", None),
+        ("foo(bar || function() {});", None),
+        ("foo(bar ? function() {} : baz);", None),
+        ("foo(function() { this; });", Some(serde_json::json!([{ "allowUnboundThis": false }]))),
+        ("foo(function() { (function() { this; }); });", None),
+        ("foo(function() { (function() { arguments; }); });", None),
+        ("foo(function() { var arguments; arguments; });", None),
+        ("foo(function() { class A { a = this; } });", None),
+        ("foo(async function() { await bar; });", None),
+        ("foo(function /* comment */ () {});", None),
+        ("foo(function() {}.bind(this, a));", None),
+        ("foo(function(a: string): void {});", None),
+        ("foo(function() {}.bind(this).bind(obj));", None),
+    ];
+
+    let fix = vec![
+        ("foo(function() {});", "foo(() => {});", None),
+        ("foo(function bar() {});", "foo(() => {});", None),
+        ("foo(function(a, b) { return a; });", "foo((a, b) => { return a; });", None),
+        ("foo(async function() { await bar; });", "foo(async () => { await bar; });", None),
+        ("new Foo(function() {});", "new Foo(() => {});", None),
+        ("foo(function() {}.bind(this));", "foo(() => {});", None),
+        ("foo(function() { this; }.bind(this));", "foo(() => { this; });", None),
+        ("foo((function() { this; }).bind(this));", "foo((() => { this; }));", None),
+        ("foo(function() {}.bind(this, a));", "foo((() => {}).bind(this, a));", None),
+        ("foo(function() {}.bind(this).bind(obj));", "foo((() => {}).bind(obj));", None),
+        ("foo(bar || function() {});", "foo(bar || (() => {}));", None),
+        ("foo(bar ? function() {} : baz);", "foo(bar ? () => {} : baz);", None),
+        ("foo(function(a: string): void {});", "foo((a: string): void => {});", None),
+        (
+            "foo(function() { this; });",
+            "foo(function() { this; });",
+            Some(serde_json::json!([{ "allowUnboundThis": false }])),
+        ),
+        ("foo(function /* comment */ () {});", "foo(function /* comment */ () {});", None),
+    ];
+
+    Tester::new(PreferArrowCallback::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,319 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingPatternKind, Expression, MemberExpression, SimpleAssignmentTarget,
+        VariableDeclarator,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{operator::AssignmentOperator, precedence::Precedence};
+
+use crate::{
+    ast_util::get_parenthesized_text, context::LintContext, fixer::Fix, rule::Rule, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-destructuring): Use {0} destructuring.")]
+#[diagnostic(severity(warning))]
+struct PreferDestructuringDiagnostic(&'static str, #[label] pub Span);
+
+/// The kinds of destructuring which are enforced
+#[derive(Debug, Clone, Copy)]
+struct DestructuringTypes {
+    array: bool,
+    object: bool,
+}
+
+impl DestructuringTypes {
+    fn from_configuration(value: &serde_json::Value) -> Self {
+        let option = |name: &str| value.get(name).and_then(serde_json::Value::as_bool);
+        Self {
+            array: option("array").unwrap_or_default(),
+            object: option("object").unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PreferDestructuring {
+    variable_declarator: DestructuringTypes,
+    assignment_expression: DestructuringTypes,
+    enforce_for_renamed_properties: bool,
+}
+
+impl Default for PreferDestructuring {
+    fn default() -> Self {
+        let all = DestructuringTypes { array: true, object: true };
+        Self {
+            variable_declarator: all,
+            assignment_expression: all,
+            enforce_for_renamed_properties: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Require destructuring from arrays and objects
+    ///
+    /// ### Why is this bad?
+    /// `const { foo } = object;` and `const [foo] = array;` do not repeat the name of the
+    /// variable or the index, unlike `const foo = object.foo;` and `const foo = array[0];`.
+    ///
+    /// ### Options
+    /// The first option enables the checks for `array` and `object` destructuring, either for both
+    /// declarations and assignments, like `{ "array": true, "object": true }` (the default), or for
+    /// each of them, like `{ "VariableDeclarator": { "array": false, "object": true },
+    /// "AssignmentExpression": { "array": true, "object": true } }`.
+    ///
+    /// With `{ "enforceForRenamedProperties": true }` as second option, properties are reported
+    /// even when they are assigned to variables of other names, like `const bar = object.foo;`.
+    ///
+    /// Accessing an index of an array is fixed to nothing, only `const foo = object.foo;`
+    /// is fixed to `const {foo} = object;`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// const foo = array[0];
+    /// bar = array[0];
+    /// const foo = object.foo;
+    /// const foo = object['foo'];
+    ///
+    /// // good
+    /// const [foo] = array;
+    /// const { foo } = object;
+    /// const foo = object.bar;
+    /// ```
+    PreferDestructuring,
+    style
+);
+
+impl Rule for PreferDestructuring {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut rule = Self::default();
+        if let Some(config) = value.get(0) {
+            if config.get("array").is_some() || config.get("object").is_some() {
+                let types = DestructuringTypes::from_configuration(config);
+                rule.variable_declarator = types;
+                rule.assignment_expression = types;
+            } else if config.get("VariableDeclarator").is_some()
+                || config.get("AssignmentExpression").is_some()
+            {
+                let types = |name: &str| {
+                    DestructuringTypes::from_configuration(
+                        config.get(name).unwrap_or(&serde_json::Value::Null),
+                    )
+                };
+                rule.variable_declarator = types("VariableDeclarator");
+                rule.assignment_expression = types("AssignmentExpression");
+            }
+        }
+        rule.enforce_for_renamed_properties = value
+            .get(1)
+            .and_then(|config| config.get("enforceForRenamedProperties"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        rule
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::VariableDeclarator, AstType::AssignmentExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                    return;
+                };
+                let Some(init) = &declarator.init else { return };
+                self.check(
+                    Some(ident.name.as_str()),
+                    init,
+                    self.variable_declarator,
+                    declarator.span,
+                    || fix_declarator(declarator, ctx),
+                    ctx,
+                );
+            }
+            AstKind::AssignmentExpression(assignment)
+                if assignment.operator == AssignmentOperator::Assign =>
+            {
+                let name = match &assignment.left {
+                    AssignmentTarget::SimpleAssignmentTarget(
+                        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+                    ) => Some(ident.name.as_str()),
+                    _ => None,
+                };
+                self.check(
+                    name,
+                    &assignment.right,
+                    self.assignment_expression,
+                    assignment.span,
+                    || None,
+                    ctx,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+impl PreferDestructuring {
+    /// Check the assignment of `right` to the variable `name`
+    fn check<'a>(
+        &self,
+        name: Option<&str>,
+        right: &Expression,
+        types: DestructuringTypes,
+        span: Span,
+        fix: impl FnOnce() -> Option<Fix<'a>>,
+        ctx: &LintContext<'a>,
+    ) {
+        let Expression::MemberExpression(member) = right.without_parenthesized() else { return };
+        if matches!(member.object(), Expression::Super(_)) {
+            return;
+        }
+        let property_name = match &**member {
+            MemberExpression::ComputedMemberExpression(computed) => match &computed.expression {
+                Expression::NumericLiteral(number) if number.value.fract() == 0.0 => {
+                    if types.array {
+                        ctx.diagnostic(PreferDestructuringDiagnostic("array", span));
+                    }
+                    return;
+                }
+                Expression::StringLiteral(string) => Some(string.value.as_str()),
+                _ => None,
+            },
+            MemberExpression::StaticMemberExpression(member) => Some(member.property.name.as_str()),
+            MemberExpression::PrivateFieldExpression(_) => return,
+        };
+        if !types.object {
+            return;
+        }
+        if self.enforce_for_renamed_properties
+            || name.is_some_and(|name| property_name == Some(name))
+        {
+            let diagnostic = PreferDestructuringDiagnostic("object", span);
+            match fix() {
+                Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+/// Fix `const foo = object.foo` to `const {foo} = object`
+fn fix_declarator<'a>(declarator: &VariableDeclarator, ctx: &LintContext<'a>) -> Option<Fix<'a>> {
+    let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return None };
+    let Expression::MemberExpression(member) = declarator.init.as_ref()?.without_parenthesized()
+    else {
+        return None;
+    };
+    let MemberExpression::StaticMemberExpression(member) = &**member else { return None };
+    if member.property.name != ident.name || declarator.id.type_annotation.is_some() {
+        return None;
+    }
+    // Only comments inside the object can be kept
+    let trivias = ctx.semantic().trivias();
+    if trivias.has_comments_between(Span::new(declarator.span.start, member.object.span().start))
+        || trivias.has_comments_between(Span::new(member.object.span().end, declarator.span.end))
+    {
+        return None;
+    }
+    let object = get_parenthesized_text(&member.object, Precedence::Assign, ctx);
+    Some(Fix::new(format!("{{{}}} = {object}", ident.name), declarator.span))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var [foo] = array;", None),
+        ("var { foo } = object;", None),
+        ("var foo;", None),
+        ("var foo = object.bar;", None),
+        ("var foo = object['bar'];", None),
+        ("var foo = object[bar];", None),
+        ("var foo = object.bar;", Some(serde_json::json!([{ "object": true }]))),
+        ("var foo = array[0];", Some(serde_json::json!([{ "array": false }]))),
+        ("var foo = object.foo;", Some(serde_json::json!([{ "array": true }]))),
+        (
+            "var foo = array[0];",
+            Some(serde_json::json!([{ "VariableDeclarator": { "array": false, "object": true } }])),
+        ),
+        (
+            "foo = array[0];",
+            Some(serde_json::json!([{ "VariableDeclarator": { "array": true, "object": true } }])),
+        ),
+        (
+            "var foo = object.foo;",
+            Some(serde_json::json!([{ "AssignmentExpression": { "object": true } }])),
+        ),
+        ("var { foo: { bar } } = object;", None),
+        ("var foo = array[100n];", None),
+        ("var foo = array[0.5];", None),
+        ("foo = object.bar;", None),
+        ("foo.bar = object.bar;", None),
+        ("foo += object.foo;", None),
+        ("foo = array?.[0];", None),
+        ("var foo = object?.foo;", None),
+        ("class Foo extends Bar { baz() { const foo = super.foo; } }", None),
+        ("class Foo { #foo; bar() { const foo = this.#foo; } }", None),
+        ("for (var foo of object.foo) {}", None),
+    ];
+
+    let fail = vec![
+        ("var foo = array[0];", None),
+        ("foo = array[0];", None),
+        ("var foo = object.foo;", None),
+        ("var foo = (a, b).foo;", None),
+        ("var foo = object['foo'];", None),
+        ("foo = object.foo;", None),
+        ("foo = object['foo'];", None),
+        (
+            "var foo = object.bar;",
+            Some(serde_json::json!([{}, { "enforceForRenamedProperties": true }])),
+        ),
+        (
+            "var foo = object[bar];",
+            Some(serde_json::json!([{}, { "enforceForRenamedProperties": true }])),
+        ),
+        (
+            "var foo = object.foo;",
+            Some(serde_json::json!([{ "VariableDeclarator": { "object": true } }])),
+        ),
+        ("var foo = object.foo.bar.foo;", None),
+        ("var foo: string = object.foo;", None),
+        ("var foo = /* comment */ object.foo;", None),
+        ("var foo = (object).foo;", None),
+    ];
+
+    let fix = vec![
+        ("var foo = object.foo;", "var {foo} = object;", None),
+        ("let foo = object.foo, bar = 1;", "let {foo} = object, bar = 1;", None),
+        ("var foo = (a, b).foo;", "var {foo} = (a, b);", None),
+        ("var foo = (object.foo);", "var {foo} = object;", None),
+        ("var foo = object.bar.foo;", "var {foo} = object.bar;", None),
+        ("var foo = object.foo.bar.foo;", "var {foo} = object.foo.bar;", None),
+        ("var foo = (object).foo;", "var {foo} = (object);", None),
+        ("var foo = object['foo'];", "var foo = object['foo'];", None),
+        ("foo = object.foo;", "foo = object.foo;", None),
+        ("var foo: string = object.foo;", "var foo: string = object.foo;", None),
+        ("var foo = /* comment */ object.foo;", "var foo = /* comment */ object.foo;", None),
+        (
+            "var foo = object.bar;",
+            "var foo = object.bar;",
+            Some(serde_json::json!([{}, { "enforceForRenamedProperties": true }])),
+        ),
+    ];
+
+    Tester::new(PreferDestructuring::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
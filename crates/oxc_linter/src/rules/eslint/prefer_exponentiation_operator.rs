@@ -0,0 +1,186 @@
+use oxc_ast::{
+    ast::{Argument, BinaryExpression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{operator::BinaryOperator, precedence::Precedence};
+
+use crate::{
+    ast_util::{get_parenthesized_text, is_global_method_call},
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-exponentiation-operator): Use the '**' operator instead of 'Math.pow'.")]
+#[diagnostic(severity(warning))]
+struct PreferExponentiationOperatorDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferExponentiationOperator;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow the use of `Math.pow` in favor of the `**` operator
+    ///
+    /// ### Why is this bad?
+    /// The `**` operator is shorter and easier to read than `Math.pow`, and it also works
+    /// with `BigInt`s.
+    ///
+    /// `Math` has to be the global object, calls of a variable named `Math` are not reported.
+    /// The fixer adds the parentheses which the operands and the result need.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// const foo = Math.pow(2, 8);
+    /// const bar = Math.pow(a + b, c + d);
+    ///
+    /// // good
+    /// const foo = 2 ** 8;
+    /// const bar = (a + b) ** (c + d);
+    /// ```
+    PreferExponentiationOperator,
+    style
+);
+
+impl Rule for PreferExponentiationOperator {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        if !is_global_method_call(call_expr, "Math", "pow", ctx) {
+            return;
+        }
+
+        let diagnostic = PreferExponentiationOperatorDiagnostic(call_expr.span);
+        let [Argument::Expression(base), Argument::Expression(exponent)] =
+            call_expr.arguments.as_slice()
+        else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        if ctx.semantic().trivias().has_comments_between(call_expr.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, || {
+            // `**` is right associative, and its left operand cannot be a unary expression
+            let base_text = get_parenthesized_text(base, Precedence::Postfix, ctx);
+            let exponent_text = get_parenthesized_text(exponent, Precedence::Exponential, ctx);
+            let power = format!("{base_text} ** {exponent_text}");
+            if needs_parentheses(node, ctx) {
+                Fix::new(format!("({power})"), call_expr.span)
+            } else {
+                Fix::new(power, call_expr.span)
+            }
+        });
+    }
+}
+
+/// Whether `a ** b` binds looser than its parent at the place of the call `node`
+fn needs_parentheses(node: &AstNode, ctx: &LintContext) -> bool {
+    let span = node.kind().span();
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else { return false };
+    match parent.kind() {
+        AstKind::MemberExpression(member) => member.object().span() == span,
+        AstKind::CallExpression(call) => call.callee.span() == span,
+        AstKind::NewExpression(new) => new.callee.span() == span,
+        AstKind::TaggedTemplateExpression(tagged) => tagged.tag.span() == span,
+        AstKind::UnaryExpression(_) | AstKind::AwaitExpression(_) => true,
+        AstKind::BinaryExpression(BinaryExpression {
+            operator: BinaryOperator::Exponential,
+            left,
+            ..
+        }) => left.span() == span,
+        AstKind::TSNonNullExpression(_) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Object.pow(a, b)",
+        "Math.max(a, b)",
+        "Math",
+        "Math(a, b)",
+        "pow",
+        "pow(a, b)",
+        "Math.pow",
+        "Math.Pow(a, b)",
+        "math.pow(a, b)",
+        "foo.Math.pow(a, b)",
+        "new Math.pow(a, b)",
+        "Math[pow](a, b)",
+        "globalThis.Object.pow(a, b)",
+        "let Math; Math.pow(a, b);",
+        "if (foo) { const Math = 1; Math.pow(a, b); }",
+        "var x = function Math() { Math.pow(a, b); }",
+        "function foo(Math) { Math.pow(a, b); }",
+        "function foo() { Math.pow(a, b); var Math; }",
+        "a ** b",
+    ];
+
+    let fail = vec![
+        "Math.pow(a, b)",
+        "(Math).pow(a, b)",
+        "Math['pow'](a, b)",
+        "Math.pow(a, b, c)",
+        "Math.pow(a)",
+        "Math.pow(...args)",
+        "Math.pow(/**/a, b)",
+        "Math.pow(a + b, c + d)",
+        "Math.pow(-a, b)",
+        "Math.pow(a, -b)",
+        "Math.pow(a ** b, c)",
+        "Math.pow(a, b ** c)",
+        "Math.pow(a, b).toString()",
+        "-Math.pow(a, b)",
+        "Math.pow(Math.pow(a, b), c)",
+        "async function f() { Math.pow(await a, b) }",
+    ];
+
+    let fix = vec![
+        ("Math.pow(a, b)", "a ** b", None),
+        ("(Math).pow(a, b)", "a ** b", None),
+        ("Math['pow'](a, b)", "a ** b", None),
+        ("Math.pow(a, b, c)", "Math.pow(a, b, c)", None),
+        ("Math.pow(...args)", "Math.pow(...args)", None),
+        ("Math.pow(/**/a, b)", "Math.pow(/**/a, b)", None),
+        ("Math.pow(2, 8)", "2 ** 8", None),
+        ("Math.pow(a + b, c + d)", "(a + b) ** (c + d)", None),
+        ("Math.pow((a + b), c)", "(a + b) ** c", None),
+        ("Math.pow(a, b) + c", "a ** b + c", None),
+        ("Math.pow(-a, b)", "(-a) ** b", None),
+        ("Math.pow(a, -b)", "a ** -b", None),
+        ("Math.pow(a++, b)", "a++ ** b", None),
+        ("Math.pow(a ** b, c)", "(a ** b) ** c", None),
+        ("Math.pow(a, b ** c)", "a ** b ** c", None),
+        ("Math.pow(a, b).toString()", "(a ** b).toString()", None),
+        ("-Math.pow(a, b)", "-(a ** b)", None),
+        ("Math.pow(a, b) ** c", "(a ** b) ** c", None),
+        ("c ** Math.pow(a, b)", "c ** a ** b", None),
+        ("a[Math.pow(b, c)]", "a[b ** c]", None),
+        ("Math.pow(a ? b : c, d)", "(a ? b : c) ** d", None),
+        (
+            "async function f() { Math.pow(await a, b) }",
+            "async function f() { (await a) ** b }",
+            None,
+        ),
+    ];
+
+    Tester::new(PreferExponentiationOperator::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
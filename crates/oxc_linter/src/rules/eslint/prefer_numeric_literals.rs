@@ -0,0 +1,150 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::get_global_parse_int_name, context::LintContext, fixer::Fix, rule::Rule, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-numeric-literals): Use {0} literals instead of {1}().")]
+#[diagnostic(severity(warning))]
+struct PreferNumericLiteralsDiagnostic(&'static str, &'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNumericLiterals;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow `parseInt()` and `Number.parseInt()` in favor of binary, octal, and hexadecimal literals
+    ///
+    /// ### Why is this bad?
+    /// Parsing a string constant with a radix of 2, 8 or 16 is a roundabout way to write
+    /// a binary, octal or hexadecimal literal.
+    ///
+    /// The fixer replaces the call with the literal when the string is a valid one for the radix.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// parseInt("111110111", 2) === 503;
+    /// parseInt(`767`, 8) === 503;
+    /// Number.parseInt("1F7", 16) === 503;
+    /// ```
+    PreferNumericLiterals,
+    style
+);
+
+impl Rule for PreferNumericLiterals {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let [Argument::Expression(string), Argument::Expression(Expression::NumericLiteral(radix))] =
+            call.arguments.as_slice()
+        else {
+            return;
+        };
+        let string = match string {
+            Expression::StringLiteral(lit) => lit.value.as_str(),
+            Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => match lit.quasi() {
+                Some(quasi) => quasi.as_str(),
+                None => return,
+            },
+            _ => return,
+        };
+        #[allow(clippy::float_cmp)]
+        let (radix_name, prefix, radix) = if radix.value == 2.0 {
+            ("binary", "0b", 2)
+        } else if radix.value == 8.0 {
+            ("octal", "0o", 8)
+        } else if radix.value == 16.0 {
+            ("hexadecimal", "0x", 16)
+        } else {
+            return;
+        };
+        let Some(function_name) = get_global_parse_int_name(call, ctx) else { return };
+
+        let diagnostic = PreferNumericLiteralsDiagnostic(radix_name, function_name, call.span);
+        // `parseInt` ignores the characters after the digits, a literal does not
+        let is_valid_literal = !string.is_empty() && string.chars().all(|c| c.is_digit(radix));
+        if !is_valid_literal || ctx.semantic().trivias().has_comments_between(call.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, || Fix::new(format!("{prefix}{string}"), call.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "parseInt(1);",
+        "parseInt(1, 3);",
+        "Number.parseInt(1);",
+        "Number.parseInt(1, 3);",
+        "0b111110111 === 503;",
+        "0o767 === 503;",
+        "0x1F7 === 503;",
+        "a[parseInt](1,2);",
+        "parseInt(foo);",
+        "parseInt(foo, 2);",
+        "Number.parseInt(foo);",
+        "Number.parseInt(foo, 2);",
+        "parseInt(11, 2);",
+        "Number.parseInt(1, 8);",
+        "parseInt(1e5, 16);",
+        "parseInt('11', '2');",
+        "Number.parseInt('11', '8');",
+        "parseInt(/foo/, 2);",
+        "parseInt(`11${foo}`, 2);",
+        "parseInt('11', 2, 3);",
+        "parseInt('11', 10);",
+        "var parseInt; parseInt('11', 2);",
+        "function f(Number) { Number.parseInt('11', 2); }",
+        "foo.parseInt('11', 2);",
+    ];
+
+    let fail = vec![
+        "parseInt(\"111110111\", 2) === 503;",
+        "parseInt(\"767\", 8) === 503;",
+        "parseInt(\"1F7\", 16) === 255;",
+        "Number.parseInt(\"111110111\", 2) === 503;",
+        "Number.parseInt(\"767\", 8) === 503;",
+        "Number.parseInt(\"1F7\", 16) === 255;",
+        "parseInt('7999', 8);",
+        "parseInt('1234', 2);",
+        "parseInt('1234.5', 8);",
+        "Number.parseInt('7999', 8);",
+        "parseInt(`111110111`, 2) === 503;",
+        "parseInt('', 8);",
+        "parseInt(/* comment */ '11', 2);",
+        "typeof parseInt('11', 2);",
+        "Number?.parseInt('11', 16);",
+    ];
+
+    let fix = vec![
+        ("parseInt(\"111110111\", 2) === 503;", "0b111110111 === 503;", None),
+        ("parseInt(\"767\", 8) === 503;", "0o767 === 503;", None),
+        ("parseInt(\"1F7\", 16) === 255;", "0x1F7 === 255;", None),
+        ("Number.parseInt(\"767\", 8) === 503;", "0o767 === 503;", None),
+        ("parseInt(`111110111`, 2) === 503;", "0b111110111 === 503;", None),
+        ("parseInt('7999', 8);", "parseInt('7999', 8);", None),
+        ("parseInt('', 8);", "parseInt('', 8);", None),
+        ("parseInt(/* comment */ '11', 2);", "parseInt(/* comment */ '11', 2);", None),
+        ("typeof parseInt('11', 2);", "typeof 0b11;", None),
+        ("a+parseInt('11', 2);", "a+0b11;", None),
+    ];
+
+    Tester::new(PreferNumericLiterals::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,217 @@
+use oxc_ast::{
+    ast::{Argument, Expression, ObjectExpression, ObjectPropertyKind, PropertyKind},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::precedence::Precedence;
+
+use crate::{
+    ast_util::{get_parenthesized_text, is_global_method_call},
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum PreferObjectSpreadDiagnostic {
+    #[error("eslint(prefer-object-spread): Use an object spread instead of `Object.assign` eg: `{{ ...foo }}`.")]
+    #[diagnostic(severity(warning))]
+    UseSpread(#[label] Span),
+    #[error("eslint(prefer-object-spread): Use an object literal instead of `Object.assign`. eg: `{{ foo: bar }}`.")]
+    #[diagnostic(severity(warning))]
+    UseLiteral(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferObjectSpread;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow using `Object.assign` with an object literal as the first argument and prefer
+    /// the use of object spread instead
+    ///
+    /// ### Why is this bad?
+    /// Object spread is a declarative alternative which may perform better than the more dynamic,
+    /// imperative `Object.assign`.
+    ///
+    /// `Object` has to be the global object, calls of a variable named `Object` are not reported.
+    /// Neither are calls with spread arguments, or with getters or setters in object literals,
+    /// which spreading does not call.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// Object.assign({}, foo);
+    /// Object.assign({}, { foo: 'bar' });
+    ///
+    /// // good
+    /// ({ ...foo });
+    /// Object.assign(foo, { bar: baz });
+    /// ```
+    PreferObjectSpread,
+    style
+);
+
+impl Rule for PreferObjectSpread {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        if !matches!(
+            call_expr.arguments.first(),
+            Some(Argument::Expression(Expression::ObjectExpression(_)))
+        ) || !is_global_method_call(call_expr, "Object", "assign", ctx)
+        {
+            return;
+        }
+
+        let mut args = vec![];
+        for arg in &call_expr.arguments {
+            let Argument::Expression(expr) = arg else { return };
+            if let Expression::ObjectExpression(object) = expr {
+                if has_accessor(object) {
+                    return;
+                }
+            }
+            args.push(expr);
+        }
+
+        let diagnostic = if args.len() == 1 {
+            PreferObjectSpreadDiagnostic::UseLiteral(call_expr.span)
+        } else {
+            PreferObjectSpreadDiagnostic::UseSpread(call_expr.span)
+        };
+        if ctx.semantic().trivias().has_comments_between(call_expr.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let source_text = ctx.source_text();
+            let members = args
+                .iter()
+                .filter_map(|expr| match expr {
+                    Expression::ObjectExpression(object) => {
+                        let first = object.properties.first()?;
+                        let last = object.properties.last()?;
+                        Some(
+                            Span::new(first.span().start, last.span().end)
+                                .source_text(source_text)
+                                .to_string(),
+                        )
+                    }
+                    _ => Some(format!(
+                        "...{}",
+                        get_parenthesized_text(expr, Precedence::Assign, ctx)
+                    )),
+                })
+                .collect::<Vec<_>>();
+            let object = if members.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{ {} }}", members.join(", "))
+            };
+            if needs_parentheses(node, ctx) {
+                Fix::new(format!("({object})"), call_expr.span)
+            } else {
+                Fix::new(object, call_expr.span)
+            }
+        });
+    }
+}
+
+fn has_accessor(object: &ObjectExpression) -> bool {
+    object.properties.iter().any(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property) => property.kind != PropertyKind::Init,
+        ObjectPropertyKind::SpreadProperty(_) => false,
+    })
+}
+
+/// Whether the object literal replacing the call `node` would be read as a block
+/// or the start of a statement. Like ESLint, parentheses are only left out where they
+/// are known not to be needed.
+fn needs_parentheses(node: &AstNode, ctx: &LintContext) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else { return true };
+    !matches!(
+        parent.kind(),
+        AstKind::VariableDeclarator(_)
+            | AstKind::ExpressionArrayElement(_)
+            | AstKind::ReturnStatement(_)
+            | AstKind::Argument(_)
+            | AstKind::ObjectProperty(_)
+            | AstKind::AssignmentExpression(_)
+            | AstKind::ParenthesizedExpression(_)
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Object.assign()",
+        "let a = Object.assign(a, b)",
+        "Object.assign(a, b)",
+        "let a = Object.assign(b, { c: 1 })",
+        "const bar = { ...foo }",
+        "Object.assign(...foo)",
+        "Object.assign(foo, { bar: baz })",
+        "Object.assign({}, ...objects)",
+        "foo({ foo: 'bar' })",
+        "const Object = {}; Object.assign({}, foo)",
+        "const Object = {}; Object.assign({ foo: 'bar' })",
+        "function foo(Object) { Object.assign({}, bar) }",
+        "class Object {}; Object.assign({}, foo)",
+        "foo.Object.assign({}, bar)",
+        "Object.assign({ get a() { return 1 } }, b)",
+        "Object.assign({}, { set a(value) {} })",
+        "globalThis.Object.assign({}, foo)",
+    ];
+
+    let fail = vec![
+        "Object.assign({}, foo)",
+        "Object.assign({}, { foo: 'bar' })",
+        "Object.assign({}, baz, { foo: 'bar' })",
+        "Object.assign({}, { foo: 'bar', baz: 'foo' })",
+        "Object.assign({ foo: 'bar' }, baz)",
+        "Object.assign({})",
+        "Object.assign({ foo: bar })",
+        "Object.assign({}, /* comment */ foo)",
+        "const a = Object.assign({}, b)",
+        "(Object).assign({}, foo)",
+        "Object['assign']({}, foo)",
+        "function foo(Obj) { Object.assign({}, bar) }",
+    ];
+
+    let fix = vec![
+        ("Object.assign({}, foo)", "({ ...foo })", None),
+        ("Object.assign({}, { foo: 'bar' })", "({ foo: 'bar' })", None),
+        ("Object.assign({}, baz, { foo: 'bar' })", "({ ...baz, foo: 'bar' })", None),
+        ("Object.assign({}, { foo: 'bar', baz: 'foo', })", "({ foo: 'bar', baz: 'foo' })", None),
+        ("Object.assign({ foo: 'bar' }, baz)", "({ foo: 'bar', ...baz })", None),
+        ("Object.assign({})", "({})", None),
+        ("Object.assign({}, {})", "({})", None),
+        ("Object.assign({}, foo, bar)", "({ ...foo, ...bar })", None),
+        ("Object.assign({}, (a, b))", "({ ...(a, b) })", None),
+        ("Object.assign({}, a || b)", "({ ...a || b })", None),
+        ("const a = Object.assign({}, b)", "const a = { ...b }", None),
+        ("a = Object.assign({}, b)", "a = { ...b }", None),
+        ("foo(Object.assign({}, b))", "foo({ ...b })", None),
+        ("const a = [Object.assign({}, b)]", "const a = [{ ...b }]", None),
+        ("function f() { return Object.assign({}, b) }", "function f() { return { ...b } }", None),
+        ("const f = () => Object.assign({}, b)", "const f = () => ({ ...b })", None),
+        ("(Object.assign({}, b))", "({ ...b })", None),
+        ("Object.assign({}, b).c", "({ ...b }).c", None),
+        ("Object.assign({}, /* comment */ foo)", "Object.assign({}, /* comment */ foo)", None),
+    ];
+
+    Tester::new(PreferObjectSpread::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,232 @@
+use oxc_ast::{ast::Expression, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{ast_util::outermost_paren_parent, context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-template): Unexpected string concatenation.")]
+#[diagnostic(severity(warning), help("Use a template literal instead"))]
+struct PreferTemplateDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferTemplate;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Requires template literals instead of string concatenation.
+    ///
+    /// ### Why is this bad?
+    /// Template literals are easier to read than long chains of `+` with quotes
+    /// opening and closing around every expression.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var str = "Hello, " + name + "!";
+    /// var str = "Time: " + (12 * 60 * 60 * 1000);
+    /// ```
+    PreferTemplate,
+    style
+);
+
+impl Rule for PreferTemplate {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(expr) = node.kind() else { return };
+        if expr.operator != BinaryOperator::Addition {
+            return;
+        }
+        // Only report the outermost concatenation
+        if outermost_paren_parent(node, ctx).is_some_and(|parent| {
+            matches!(parent.kind(), AstKind::BinaryExpression(parent) if parent.operator == BinaryOperator::Addition)
+        }) {
+            return;
+        }
+        let mut operands = vec![];
+        collect_operands(&expr.left, &mut operands);
+        collect_operands(&expr.right, &mut operands);
+        if !operands.iter().any(|operand| is_string_literal(operand))
+            || operands.iter().all(|operand| is_string_literal(operand))
+        {
+            return;
+        }
+        let diagnostic = PreferTemplateDiagnostic(expr.span);
+        match build_template(expr.span, &operands, ctx) {
+            Some(template) => ctx.diagnostic_with_fix(diagnostic, || Fix::new(template, expr.span)),
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+fn is_string_literal(expr: &Expression) -> bool {
+    matches!(expr, Expression::StringLiteral(_) | Expression::TemplateLiteral(_))
+}
+
+fn has_string_literal(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            has_string_literal(&binary.left) || has_string_literal(&binary.right)
+        }
+        expr => is_string_literal(expr),
+    }
+}
+
+/// Flattens `a + 'b' + c` into `[a, 'b', c]`. `a + b + 'c'` keeps `a + b` together since
+/// it is a numeric addition when both are numbers.
+fn collect_operands<'a, 'b>(expr: &'b Expression<'a>, operands: &mut Vec<&'b Expression<'a>>) {
+    match expr.without_parenthesized() {
+        Expression::BinaryExpression(binary)
+            if binary.operator == BinaryOperator::Addition && has_string_literal(expr) =>
+        {
+            collect_operands(&binary.left, operands);
+            collect_operands(&binary.right, operands);
+        }
+        expr => operands.push(expr),
+    }
+}
+
+/// Builds the template literal, or `None` when the concatenation cannot be rewritten safely
+fn build_template(span: Span, operands: &[&Expression], ctx: &LintContext) -> Option<String> {
+    let source_text = ctx.source_text();
+    // Comments between the operands would be lost
+    let is_blank = |start: u32, end: u32| {
+        source_text[start as usize..end as usize]
+            .chars()
+            .all(|c| c.is_whitespace() || matches!(c, '+' | '(' | ')'))
+    };
+    let mut end = span.start;
+    for operand in operands {
+        if !is_blank(end, operand.span().start) {
+            return None;
+        }
+        end = operand.span().end;
+    }
+    if !is_blank(end, span.end) {
+        return None;
+    }
+
+    let mut template = String::from("`");
+    // Whether `template` ends with a `$` that would start a substitution if followed by `{`
+    let mut trailing_dollar = false;
+    for operand in operands {
+        match operand {
+            Expression::StringLiteral(literal) => {
+                let raw = literal.span.source_text(source_text);
+                let quote = raw.chars().next()?;
+                let mut chars = raw[1..raw.len() - 1].chars().peekable();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            let escaped = chars.next()?;
+                            // Octal escapes are not allowed in templates
+                            if escaped.is_ascii_digit()
+                                && (escaped != '0'
+                                    || chars.peek().is_some_and(char::is_ascii_digit))
+                            {
+                                return None;
+                            }
+                            if escaped != quote {
+                                template.push('\\');
+                            }
+                            template.push(escaped);
+                            trailing_dollar = false;
+                            continue;
+                        }
+                        '`' => template.push_str("\\`"),
+                        '{' if trailing_dollar => {
+                            template.pop();
+                            template.push_str("\\${");
+                        }
+                        _ => template.push(c),
+                    }
+                    trailing_dollar = c == '$';
+                }
+            }
+            Expression::TemplateLiteral(literal) => {
+                let raw = literal.span.source_text(source_text);
+                let raw = &raw[1..raw.len() - 1];
+                template.push_str(raw);
+                trailing_dollar = raw.ends_with('$') && !raw.ends_with("\\$");
+            }
+            _ => {
+                template.push_str("${");
+                template.push_str(operand.span().source_text(source_text));
+                template.push('}');
+                trailing_dollar = false;
+            }
+        }
+    }
+    template.push('`');
+    Some(template)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "'use strict';",
+        "var foo = 'foo' + '\\0';",
+        "var foo = 'bar';",
+        "var foo = 'bar' + 'baz';",
+        "var foo = foo + +'100';",
+        "var foo = `bar`;",
+        "var foo = `hello, ${name}!`;",
+        "var foo = `foo` + `bar` + \"hoge\";",
+        "var foo = `foo` +\n    `bar` +\n    \"hoge\";",
+        "var foo = a + b;",
+        "var foo = 1 + 2;",
+        "var foo = a - 'b';",
+    ];
+
+    let fail = vec![
+        "var foo = 'hello, ' + name + '!';",
+        "var foo = bar + 'baz';",
+        "var foo = bar + `baz`;",
+        "var foo = +100 + 'yen';",
+        "var foo = 'bar' + baz;",
+        "var foo = '￥' + (n * 1000) + '-'",
+        "var foo = 'aaa' + aaa; var bar = 'bbb' + bbb;",
+        "var string = (number + 1) + 'px';",
+        "var foo = 'bar' + baz + 'qux';",
+        "var foo = '0 backslashes: ${bar}' + baz;",
+        "var foo = 'a' + b + '\\8';",
+        "var foo = 'a' + b + '\\01';",
+        "var foo = 'a' + /* comment */ b;",
+        "var foo = a + b + 'c';",
+        "var foo = 'a' + (b + c);",
+        "foo + 'bar' + (baz + 'qux')",
+    ];
+
+    let fix = vec![
+        ("var foo = 'hello, ' + name + '!';", "var foo = `hello, ${name}!`;", None),
+        ("var foo = bar + 'baz';", "var foo = `${bar}baz`;", None),
+        ("var foo = bar + `baz`;", "var foo = `${bar}baz`;", None),
+        ("var foo = +100 + 'yen';", "var foo = `${+100}yen`;", None),
+        ("var foo = '￥' + (n * 1000) + '-'", "var foo = `￥${n * 1000}-`", None),
+        ("var string = (number + 1) + 'px';", "var string = `${number + 1}px`;", None),
+        ("var foo = a + b + 'c';", "var foo = `${a + b}c`;", None),
+        ("var foo = 'a' + (b + c);", "var foo = `a${b + c}`;", None),
+        ("foo + 'bar' + (baz + 'qux')", "`${foo}bar${baz}qux`", None),
+        ("var foo = 'it\\'s ' + a;", "var foo = `it's ${a}`;", None),
+        ("var foo = \"\\\"q\\\" \" + a;", "var foo = `\"q\" ${a}`;", None),
+        ("var foo = 'a\\nb' + c;", "var foo = `a\\nb${c}`;", None),
+        ("var foo = '`' + a;", "var foo = `\\`${a}`;", None),
+        ("var foo = '${' + a;", "var foo = `\\${${a}`;", None),
+        ("var foo = '$' + '{' + a;", "var foo = `\\${${a}`;", None),
+        ("var foo = '\\0' + a;", "var foo = `\\0${a}`;", None),
+        ("var foo = `a${b}` + c;", "var foo = `a${b}${c}`;", None),
+        ("var foo = 'a' + b + '\\8';", "var foo = 'a' + b + '\\8';", None),
+        ("var foo = 'a' + /* comment */ b;", "var foo = 'a' + /* comment */ b;", None),
+    ];
+
+    Tester::new(PreferTemplate::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,173 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{ast_util::get_global_parse_int_name, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum RadixDiagnostic {
+    #[error("eslint(radix): Missing parameters.")]
+    #[diagnostic(severity(warning))]
+    MissingParameters(#[label] Span),
+
+    #[error("eslint(radix): Missing radix parameter.")]
+    #[diagnostic(severity(warning), help("Add radix parameter `10` for parsing decimal numbers"))]
+    MissingRadix(#[label] Span),
+
+    #[error("eslint(radix): Redundant radix parameter.")]
+    #[diagnostic(severity(warning), help("Remove the radix parameter `10`"))]
+    RedundantRadix(#[label] Span),
+
+    #[error("eslint(radix): Invalid radix parameter, must be an integer between 2 and 36.")]
+    #[diagnostic(severity(warning))]
+    InvalidRadix(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    #[default]
+    Always,
+    AsNeeded,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Radix {
+    mode: Mode,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce the consistent use of the radix argument when using `parseInt()`
+    ///
+    /// ### Why is this bad?
+    /// `parseInt` parses strings starting with `0x` as hexadecimal numbers when no radix is given,
+    /// which may not be intended for user input.
+    ///
+    /// ### Options
+    /// * `"always"` (default): require a radix for every call.
+    /// * `"as-needed"`: disallow the default radix `10`.
+    ///
+    /// Calls of `parseInt` and `Number.parseInt` are checked,
+    /// unless the name refers to a local variable.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const num = parseInt("071");
+    /// const bad = parseInt("071", 37);
+    /// ```
+    Radix,
+    pedantic
+);
+
+impl Rule for Radix {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("as-needed") => Mode::AsNeeded,
+            _ => Mode::Always,
+        };
+        Self { mode }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        if get_global_parse_int_name(call, ctx).is_none() {
+            return;
+        }
+
+        match call.arguments.as_slice() {
+            [] => ctx.diagnostic(RadixDiagnostic::MissingParameters(call.span)),
+            [_] => {
+                if self.mode == Mode::Always {
+                    ctx.diagnostic(RadixDiagnostic::MissingRadix(call.span));
+                }
+            }
+            [_, Argument::Expression(radix), ..] => {
+                if self.mode == Mode::AsNeeded && is_default_radix(radix) {
+                    ctx.diagnostic(RadixDiagnostic::RedundantRadix(call.span));
+                } else if !is_valid_radix(radix) {
+                    ctx.diagnostic(RadixDiagnostic::InvalidRadix(call.span));
+                }
+            }
+            [_, Argument::SpreadElement(_), ..] => {}
+        }
+    }
+}
+
+fn is_default_radix(radix: &Expression) -> bool {
+    matches!(radix.without_parenthesized(), Expression::NumericLiteral(lit) if lit.value == 10.0)
+}
+
+/// Whether `radix` may be an integer between 2 and 36, which is unknown for non-literals.
+fn is_valid_radix(radix: &Expression) -> bool {
+    match radix.without_parenthesized() {
+        Expression::NumericLiteral(lit) => {
+            lit.value.fract() == 0.0 && (2.0..=36.0).contains(&lit.value)
+        }
+        Expression::Identifier(ident) => ident.name != "undefined",
+        expr => !expr.is_literal(),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"parseInt("10", 10);"#, None),
+        (r#"parseInt("10", 2);"#, None),
+        (r#"parseInt("10", 36);"#, None),
+        (r#"parseInt("10", 0x10);"#, None),
+        (r#"parseInt("10", 1.6e1);"#, None),
+        (r#"parseInt("10", foo);"#, None),
+        (r#"parseInt("10", foo);"#, Some(serde_json::json!(["always"]))),
+        (r#"parseInt("10", 10);"#, Some(serde_json::json!(["always"]))),
+        (r#"Number.parseInt("10", 10);"#, None),
+        (r#"Number.parseInt("10", foo);"#, None),
+        (r#"parseInt("10");"#, Some(serde_json::json!(["as-needed"]))),
+        (r#"parseInt("10", 8);"#, Some(serde_json::json!(["as-needed"]))),
+        (r#"Number.parseInt("10");"#, Some(serde_json::json!(["as-needed"]))),
+        (r#"parseInt("10", ...args);"#, None),
+        ("parseInt", None),
+        ("Number.foo();", None),
+        ("Number[parseInt]();", None),
+        (r#"var parseInt; parseInt("10");"#, None),
+        (r#"function f(parseInt) { parseInt("10"); }"#, None),
+        (r#"var Number; Number.parseInt("10");"#, None),
+        (r#"function f(Number) { Number.parseInt("10"); }"#, None),
+    ];
+
+    let fail = vec![
+        ("parseInt();", Some(serde_json::json!(["as-needed"]))),
+        ("parseInt();", None),
+        (r#"parseInt("10");"#, None),
+        (r#"parseInt("10",);"#, None),
+        (r#"parseInt((0, "10"));"#, None),
+        (r#"parseInt("10", null);"#, None),
+        (r#"parseInt("10", undefined);"#, None),
+        (r#"parseInt("10", true);"#, None),
+        (r#"parseInt("10", "foo");"#, None),
+        (r#"parseInt("10", "123");"#, None),
+        (r#"parseInt("10", 1);"#, None),
+        (r#"parseInt("10", 37);"#, None),
+        (r#"parseInt("10", 10.5);"#, None),
+        ("Number.parseInt();", None),
+        (r#"Number.parseInt("10");"#, None),
+        (r#"Number.parseInt("10", 1);"#, None),
+        (r#"parseInt("10", 10);"#, Some(serde_json::json!(["as-needed"]))),
+        (r#"Number.parseInt("10", 10);"#, Some(serde_json::json!(["as-needed"]))),
+        (r#"Number?.parseInt("10");"#, None),
+    ];
+
+    Tester::new(Radix::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -36,6 +36,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for RequireYield {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::Function(func) = node.kind() {
             if !node.flags().has_yield()
@@ -0,0 +1,363 @@
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum SortImportsDiagnostic {
+    #[error("eslint(sort-imports): Imports should be sorted alphabetically.")]
+    #[diagnostic(severity(warning))]
+    Declaration(#[label] Span),
+    #[error("eslint(sort-imports): Expected '{0}' syntax before '{1}' syntax.")]
+    #[diagnostic(severity(warning))]
+    MemberSyntax(&'static str, &'static str, #[label] Span),
+    #[error(
+        "eslint(sort-imports): Member '{0}' of the import declaration should be sorted alphabetically."
+    )]
+    #[diagnostic(severity(warning))]
+    Member(CompactString, #[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct SortImports {
+    ignore_case: bool,
+    ignore_declaration_sort: bool,
+    ignore_member_sort: bool,
+    member_syntax_sort_order: [MemberSyntax; 4],
+    allow_separated_groups: bool,
+}
+
+impl Default for SortImports {
+    fn default() -> Self {
+        Self {
+            ignore_case: false,
+            ignore_declaration_sort: false,
+            ignore_member_sort: false,
+            member_syntax_sort_order: [
+                MemberSyntax::None,
+                MemberSyntax::All,
+                MemberSyntax::Multiple,
+                MemberSyntax::Single,
+            ],
+            allow_separated_groups: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce sorted import declarations within modules
+    ///
+    /// ### Why is this bad?
+    /// Sorted imports make it easier to find an import, and to see whether a module is already imported.
+    ///
+    /// Declarations are ordered by their member syntax (`memberSyntaxSortOrder`, defaulting to
+    /// `["none", "all", "multiple", "single"]`), then by the name of their first local member.
+    /// The members of a declaration are sorted alphabetically, which the fixer does.
+    ///
+    /// With `allowSeparatedGroups`, declarations separated by an empty line are sorted independently,
+    /// so that groups of imports (e.g. external modules, then local ones) are kept as they are.
+    /// `ignoreCase`, `ignoreDeclarationSort` and `ignoreMemberSort` are supported as well.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import b from 'foo.js';
+    /// import a from 'bar.js';
+    /// import { c, a } from 'baz.js';
+    /// ```
+    SortImports,
+    style
+);
+
+/// The kind of the members an import declaration has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MemberSyntax {
+    /// `import 'foo'`
+    None,
+    /// `import * as foo from 'foo'`
+    All,
+    /// `import { a, b } from 'foo'`
+    Multiple,
+    /// `import a from 'foo'`
+    Single,
+}
+
+impl MemberSyntax {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "none" => Some(Self::None),
+            "all" => Some(Self::All),
+            "multiple" => Some(Self::Multiple),
+            "single" => Some(Self::Single),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::All => "all",
+            Self::Multiple => "multiple",
+            Self::Single => "single",
+        }
+    }
+
+    fn of(decl: &ImportDeclaration) -> Self {
+        let Some(specifiers) = &decl.specifiers else {
+            return Self::None;
+        };
+        match specifiers.first() {
+            None => Self::None,
+            Some(ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)) => Self::All,
+            Some(_) if specifiers.len() == 1 => Self::Single,
+            Some(_) => Self::Multiple,
+        }
+    }
+}
+
+impl Rule for SortImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let flag = |key| config.get(key).and_then(serde_json::Value::as_bool).unwrap_or(false);
+        let member_syntax_sort_order = config
+            .get("memberSyntaxSortOrder")
+            .and_then(serde_json::Value::as_array)
+            .and_then(|order| {
+                let order = order
+                    .iter()
+                    .map(|syntax| syntax.as_str().and_then(MemberSyntax::from_str))
+                    .collect::<Option<Vec<_>>>()?;
+                <[MemberSyntax; 4]>::try_from(order).ok()
+            })
+            .unwrap_or(Self::default().member_syntax_sort_order);
+
+        Self {
+            ignore_case: flag("ignoreCase"),
+            ignore_declaration_sort: flag("ignoreDeclarationSort"),
+            ignore_member_sort: flag("ignoreMemberSort"),
+            member_syntax_sort_order,
+            allow_separated_groups: flag("allowSeparatedGroups"),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let mut previous: Option<&ImportDeclaration> = None;
+        for node in ctx.nodes().iter() {
+            let AstKind::ImportDeclaration(decl) = node.kind() else {
+                continue;
+            };
+            if !self.ignore_declaration_sort {
+                if let Some(previous_decl) = previous {
+                    let between = &ctx.source_text()
+                        [previous_decl.span.end as usize..decl.span.start as usize];
+                    if self.allow_separated_groups && between.matches('\n').count() > 1 {
+                        previous = None;
+                    }
+                }
+                if let Some(previous) = previous {
+                    self.check_declaration_order(previous, decl, ctx);
+                }
+                previous = Some(decl);
+            }
+            if !self.ignore_member_sort {
+                self.check_member_order(decl, ctx);
+            }
+        }
+    }
+}
+
+impl SortImports {
+    fn syntax_index(&self, decl: &ImportDeclaration) -> usize {
+        let syntax = MemberSyntax::of(decl);
+        self.member_syntax_sort_order.iter().position(|s| *s == syntax).unwrap_or_default()
+    }
+
+    fn sortable_name(&self, name: &str) -> String {
+        if self.ignore_case {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn check_declaration_order(
+        &self,
+        previous: &ImportDeclaration,
+        decl: &ImportDeclaration,
+        ctx: &LintContext,
+    ) {
+        let (index, previous_index) = (self.syntax_index(decl), self.syntax_index(previous));
+        if index != previous_index {
+            if index < previous_index {
+                ctx.diagnostic(SortImportsDiagnostic::MemberSyntax(
+                    self.member_syntax_sort_order[index].as_str(),
+                    self.member_syntax_sort_order[previous_index].as_str(),
+                    decl.span,
+                ));
+            }
+            return;
+        }
+        if let (Some(name), Some(previous_name)) =
+            (first_local_name(decl), first_local_name(previous))
+        {
+            if self.sortable_name(name) < self.sortable_name(previous_name) {
+                ctx.diagnostic(SortImportsDiagnostic::Declaration(decl.span));
+            }
+        }
+    }
+
+    fn check_member_order(&self, decl: &ImportDeclaration, ctx: &LintContext) {
+        let Some(specifiers) = &decl.specifiers else {
+            return;
+        };
+        let named = specifiers
+            .iter()
+            .filter_map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => Some(specifier),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let names = named.iter().map(|s| self.sortable_name(&s.local.name)).collect::<Vec<_>>();
+        let Some(unsorted) = (1..names.len()).find(|&i| names[i - 1] > names[i]) else {
+            return;
+        };
+
+        let diagnostic = SortImportsDiagnostic::Member(
+            named[unsorted].local.name.to_compact_string(),
+            named[unsorted].span,
+        );
+        let (first, last) = (named[0].span, named[named.len() - 1].span);
+        let source_text = ctx.source_text();
+        let range = Span::new(first.start, last.end);
+        // Comments would end up next to other members
+        if range.source_text(source_text).contains("//")
+            || range.source_text(source_text).contains("/*")
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let mut sorted = named.iter().zip(&names).collect::<Vec<_>>();
+            sorted.sort_by(|(_, a), (_, b)| a.cmp(b));
+            let mut content = String::new();
+            for (i, (specifier, _)) in sorted.iter().enumerate() {
+                content.push_str(specifier.span.source_text(source_text));
+                // Keep the separators of the original positions
+                if let Some(next) = named.get(i + 1) {
+                    content.push_str(
+                        &source_text[named[i].span.end as usize..next.span.start as usize],
+                    );
+                }
+            }
+            Fix::new(content, range)
+        });
+    }
+}
+
+fn first_local_name<'a>(decl: &'a ImportDeclaration) -> Option<&'a str> {
+    let specifier = decl.specifiers.as_ref()?.first()?;
+    let local = match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+    };
+    Some(local.name.as_str())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import a from 'foo.js';\nimport b from 'bar.js';\nimport c from 'baz.js';", None),
+        ("import * as B from 'foo.js';\nimport A from 'bar.js';", None),
+        ("import * as B from 'foo.js';\nimport {a, b} from 'bar.js';", None),
+        ("import {b, c} from 'bar.js';\nimport A from 'foo.js';", None),
+        (
+            "import A from 'bar.js';\nimport {b, c} from 'foo.js';",
+            Some(
+                serde_json::json!([{ "memberSyntaxSortOrder": ["single", "multiple", "none", "all"] }]),
+            ),
+        ),
+        ("import {a, b} from 'bar.js';\nimport {c, d} from 'foo.js';", None),
+        ("import A from 'foo.js';\nimport B from 'bar.js';", None),
+        ("import A from 'foo.js';\nimport a from 'bar.js';", None),
+        ("import a, * as b from 'foo.js';\nimport c from 'bar.js';", None),
+        ("import 'foo.js';\nimport a from 'bar.js';", None),
+        ("import B from 'foo.js';\nimport a from 'bar.js';", None),
+        (
+            "import a from 'foo.js';\nimport B from 'bar.js';",
+            Some(serde_json::json!([{ "ignoreCase": true }])),
+        ),
+        ("import {a, b, c, d} from 'foo.js';", None),
+        (
+            "import a from 'foo.js';\nimport B from 'bar.js';",
+            Some(serde_json::json!([{ "ignoreDeclarationSort": true }])),
+        ),
+        (
+            "import {b, A, C, d} from 'foo.js';",
+            Some(serde_json::json!([{ "ignoreMemberSort": true }])),
+        ),
+        ("import {a, B, c, D} from 'foo.js';", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        ("import a, * as b from 'foo.js';", None),
+        ("import * as a from 'foo';\n\nimport b from 'bar';", None),
+        (
+            "import b from 'b';\n\nimport a from 'a';",
+            Some(serde_json::json!([{ "allowSeparatedGroups": true }])),
+        ),
+        (
+            "import c from 'c';\nimport d from 'd';\n\nimport a from 'a';\nimport b from 'b';",
+            Some(serde_json::json!([{ "allowSeparatedGroups": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("import a from 'foo.js';\nimport A from 'bar.js';", None),
+        ("import b from 'foo.js';\nimport a from 'bar.js';", None),
+        ("import {b, c} from 'foo.js';\nimport {a, d} from 'bar.js';", None),
+        ("import * as foo from 'foo.js';\nimport * as bar from 'bar.js';", None),
+        ("import a from 'foo.js';\nimport {b, c} from 'bar.js';", None),
+        ("import a from 'foo.js';\nimport * as b from 'bar.js';", None),
+        ("import a from 'foo.js';\nimport 'bar.js';", None),
+        (
+            "import b from 'bar.js';\nimport * as a from 'foo.js';",
+            Some(
+                serde_json::json!([{ "memberSyntaxSortOrder": ["all", "single", "multiple", "none"] }]),
+            ),
+        ),
+        ("import {b, a, d, c} from 'foo.js';", None),
+        ("import {a, B, c, D} from 'foo.js';", None),
+        ("import b from 'b';\n\nimport a from 'a';", None),
+        (
+            "import b from 'b';\nimport a from 'a';\n\nimport d from 'd';",
+            Some(serde_json::json!([{ "allowSeparatedGroups": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("import {b, a, d, c} from 'foo.js';", "import {a, b, c, d} from 'foo.js';", None),
+        (
+            "import {zzzzz, /* comment */ aaaaa} from 'foo.js';",
+            "import {zzzzz, /* comment */ aaaaa} from 'foo.js';",
+            None,
+        ),
+        ("import {b as c, a} from 'foo.js';", "import {a, b as c} from 'foo.js';", None),
+        ("import {\n  b,\n  a,\n} from 'foo.js';", "import {\n  a,\n  b,\n} from 'foo.js';", None),
+    ];
+
+    Tester::new(SortImports::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
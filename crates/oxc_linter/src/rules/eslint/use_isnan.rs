@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, ChainElement, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -78,6 +78,15 @@ declare_oxc_lint!(
 );
 
 impl Rule for UseIsnan {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::BinaryExpression,
+            AstType::SwitchCase,
+            AstType::SwitchStatement,
+            AstType::CallExpression,
+        ])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::BinaryExpression(expr)
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -40,6 +41,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for Export {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.semantic().module_record();
         let named_export = &module_record.exported_bindings;
@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -30,6 +31,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for Named {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         let semantic = ctx.semantic();
 
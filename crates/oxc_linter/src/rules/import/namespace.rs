@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -38,6 +38,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for Namespace {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         ctx.semantic().module_record().import_entries.iter().for_each(|entry| {
             if !matches!(entry.import_name, ImportImportName::NamespaceObject) {
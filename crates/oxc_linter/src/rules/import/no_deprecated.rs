@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -25,6 +26,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDeprecated {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, _ctx: &LintContext<'_>) {}
 }
 
@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use oxc_ast::AstType;
 use oxc_diagnostics::miette::{miette, LabeledSpan, Severity};
 use oxc_macros::declare_oxc_lint;
 
@@ -17,6 +18,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDuplicates {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.semantic().module_record();
 
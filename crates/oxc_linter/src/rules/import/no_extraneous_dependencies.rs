@@ -0,0 +1,410 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_resolver::NODEJS_BUILTINS;
+use oxc_span::{CompactString, Span};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoExtraneousDependenciesDiagnostic {
+    #[error("eslint-plugin-import(no-extraneous-dependencies): '{0}' should be listed in the project's dependencies.")]
+    #[diagnostic(severity(warning), help("Run `npm i -S {0}` to add it"))]
+    Missing(CompactString, #[label] Span),
+    #[error("eslint-plugin-import(no-extraneous-dependencies): '{0}' should be listed in the project's dependencies, not {1}.")]
+    #[diagnostic(severity(warning))]
+    WrongKind(CompactString, &'static str, #[label] Span),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-extraneous-dependencies.md>
+#[derive(Debug, Clone)]
+pub struct NoExtraneousDependencies {
+    dev_dependencies: bool,
+    optional_dependencies: bool,
+    peer_dependencies: bool,
+    bundled_dependencies: bool,
+    include_types: bool,
+}
+
+impl Default for NoExtraneousDependencies {
+    fn default() -> Self {
+        Self {
+            dev_dependencies: true,
+            optional_dependencies: true,
+            peer_dependencies: true,
+            bundled_dependencies: true,
+            include_types: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbid the import of packages which are not declared in the `dependencies`,
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundledDependencies`
+    /// of the closest `package.json`.
+    ///
+    /// In a monorepo, the dependencies of the workspace root (the closest parent `package.json`
+    /// with a `workspaces` field) are allowed as well.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An undeclared package only works as long as another package happens to install it.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // package.json: { "dependencies": { "lodash": "*" } }
+    /// import _ from 'lodash'; // ok
+    /// import chalk from 'chalk'; // not declared
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` and `bundledDependencies`
+    /// (all `true` by default) allow imports of the dependencies of that kind.
+    /// Type imports are ignored unless `includeTypes` is `true`.
+    NoExtraneousDependencies,
+    nursery
+);
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let Some(config) = value.get(0) else { return default };
+        let option = |name: &str, default: bool| {
+            config.get(name).and_then(serde_json::Value::as_bool).unwrap_or(default)
+        };
+        Self {
+            dev_dependencies: option("devDependencies", default.dev_dependencies),
+            optional_dependencies: option("optionalDependencies", default.optional_dependencies),
+            peer_dependencies: option("peerDependencies", default.peer_dependencies),
+            bundled_dependencies: option("bundledDependencies", default.bundled_dependencies),
+            include_types: option("includeTypes", default.include_types),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::ImportDeclaration,
+            AstType::ExportNamedDeclaration,
+            AstType::ExportAllDeclaration,
+            AstType::ImportExpression,
+            AstType::CallExpression,
+        ])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (specifier, span) = match node.kind() {
+            AstKind::ImportDeclaration(decl) => {
+                if decl.import_kind.is_type() && !self.include_types {
+                    return;
+                }
+                (decl.source.value.as_str(), decl.source.span)
+            }
+            AstKind::ExportNamedDeclaration(decl) => {
+                let Some(source) = &decl.source else { return };
+                if decl.export_kind.is_type() && !self.include_types {
+                    return;
+                }
+                (source.value.as_str(), source.span)
+            }
+            AstKind::ExportAllDeclaration(decl) => {
+                if decl.export_kind.is_type() && !self.include_types {
+                    return;
+                }
+                (decl.source.value.as_str(), decl.source.span)
+            }
+            AstKind::ImportExpression(expr) => {
+                let Expression::StringLiteral(source) = &expr.source else { return };
+                (source.value.as_str(), source.span)
+            }
+            AstKind::CallExpression(call) if call.is_require_call() => {
+                let Some(Argument::Expression(Expression::StringLiteral(source))) =
+                    call.arguments.first()
+                else {
+                    return;
+                };
+                (source.value.as_str(), source.span)
+            }
+            _ => return,
+        };
+
+        let Some(package_name) = package_name(specifier) else { return };
+        if Self::resolves_into_project(specifier, ctx) {
+            return;
+        }
+
+        let Some((manifest_dir, manifest)) = closest_manifest(ctx.file_path()) else { return };
+        if manifest.name.as_deref() == Some(package_name) {
+            return;
+        }
+        let workspace_root = manifest_dir
+            .ancestors()
+            .skip(1)
+            .filter_map(manifest_in)
+            .find(|manifest| manifest.workspaces.is_some());
+
+        let Some(kinds) = std::iter::once(&manifest)
+            .chain(workspace_root.as_ref())
+            .map(|manifest| manifest.dependency_kinds(package_name))
+            .find(|kinds| !kinds.is_empty())
+        else {
+            ctx.diagnostic(NoExtraneousDependenciesDiagnostic::Missing(
+                CompactString::from(package_name),
+                span,
+            ));
+            return;
+        };
+        if kinds.iter().any(|kind| self.allows(*kind)) {
+            return;
+        }
+        let Some(kind) = kinds.first() else { return };
+        ctx.diagnostic(NoExtraneousDependenciesDiagnostic::WrongKind(
+            CompactString::from(package_name),
+            kind.field(),
+            span,
+        ));
+    }
+}
+
+impl NoExtraneousDependencies {
+    fn allows(&self, kind: DependencyKind) -> bool {
+        match kind {
+            DependencyKind::Production => true,
+            DependencyKind::Dev => self.dev_dependencies,
+            DependencyKind::Optional => self.optional_dependencies,
+            DependencyKind::Peer => self.peer_dependencies,
+            DependencyKind::Bundled => self.bundled_dependencies,
+        }
+    }
+
+    /// Path aliases, e.g. from a `tsconfig.json`, look like packages but resolve to project files.
+    /// Only known with the import plugin.
+    fn resolves_into_project(specifier: &str, ctx: &LintContext) -> bool {
+        let module_record = ctx.semantic().module_record();
+        module_record.loaded_modules.get(specifier).is_some_and(|remote| {
+            !remote
+                .resolved_absolute_path
+                .components()
+                .any(|component| component.as_os_str() == "node_modules")
+        })
+    }
+}
+
+/// The package name of a bare specifier, `None` for relative paths, subpath imports and Node.js builtins.
+fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with("node:")
+        || specifier.starts_with('#')
+        || !matches!(Path::new(specifier).components().next(), Some(Component::Normal(_)))
+    {
+        return None;
+    }
+    let mut end = specifier.find('/').unwrap_or(specifier.len());
+    if specifier.starts_with('@') {
+        // `@scope/name/subpath`
+        let rest = specifier.get(end + 1..).filter(|rest| !rest.is_empty())?;
+        end += 1 + rest.find('/').unwrap_or(rest.len());
+    }
+    let name = &specifier[..end];
+    if NODEJS_BUILTINS.binary_search(&name).is_ok() {
+        return None;
+    }
+    Some(name)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DependencyKind {
+    Production,
+    Dev,
+    Optional,
+    Peer,
+    Bundled,
+}
+
+impl DependencyKind {
+    /// The `package.json` field declaring this kind of dependency.
+    fn field(self) -> &'static str {
+        match self {
+            Self::Production => "dependencies",
+            Self::Dev => "devDependencies",
+            Self::Optional => "optionalDependencies",
+            Self::Peer => "peerDependencies",
+            Self::Bundled => "bundledDependencies",
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackageJson {
+    name: Option<String>,
+    #[serde(default)]
+    dependencies: FxHashMap<String, serde_json::Value>,
+    #[serde(default)]
+    dev_dependencies: FxHashMap<String, serde_json::Value>,
+    #[serde(default)]
+    optional_dependencies: FxHashMap<String, serde_json::Value>,
+    #[serde(default)]
+    peer_dependencies: FxHashMap<String, serde_json::Value>,
+    #[serde(default, alias = "bundleDependencies")]
+    bundled_dependencies: serde_json::Value,
+    workspaces: Option<serde_json::Value>,
+}
+
+impl PackageJson {
+    /// All the kinds of dependency `name` is declared as, production first.
+    fn dependency_kinds(&self, name: &str) -> Vec<DependencyKind> {
+        let bundled = self
+            .bundled_dependencies
+            .as_array()
+            .is_some_and(|names| names.iter().any(|bundled| bundled.as_str() == Some(name)));
+        [
+            (self.dependencies.contains_key(name), DependencyKind::Production),
+            (self.dev_dependencies.contains_key(name), DependencyKind::Dev),
+            (self.optional_dependencies.contains_key(name), DependencyKind::Optional),
+            (self.peer_dependencies.contains_key(name), DependencyKind::Peer),
+            (bundled, DependencyKind::Bundled),
+        ]
+        .into_iter()
+        .filter_map(|(declared, kind)| declared.then_some(kind))
+        .collect()
+    }
+}
+
+/// The modification time and the length of a file, `None` when there is none
+type FileStamp = Option<(SystemTime, u64)>;
+
+/// A `package.json` file, `None` when there is none or it is invalid
+type CachedManifest = (FileStamp, Option<Arc<PackageJson>>);
+
+/// `package.json` files keyed by their directory.
+/// They are read again when their `FileStamp` changes, e.g. in the language server after
+/// installing a dependency.
+static MANIFESTS: Lazy<DashMap<PathBuf, CachedManifest>> = Lazy::new(DashMap::new);
+
+fn manifest_in(dir: &Path) -> Option<Arc<PackageJson>> {
+    let path = dir.join("package.json");
+    let stamp =
+        fs::metadata(&path).and_then(|metadata| Ok((metadata.modified()?, metadata.len()))).ok();
+    if let Some(entry) = MANIFESTS.get(dir) {
+        let (cached_stamp, manifest) = entry.value();
+        if *cached_stamp == stamp {
+            return manifest.clone();
+        }
+    }
+    let manifest = stamp
+        .and_then(|_| fs::read_to_string(&path).ok())
+        .and_then(|source_text| serde_json::from_str::<PackageJson>(&source_text).ok())
+        .map(Arc::new);
+    MANIFESTS.insert(dir.to_path_buf(), (stamp, manifest.clone()));
+    manifest
+}
+
+fn closest_manifest(path: &Path) -> Option<(&Path, Arc<PackageJson>)> {
+    path.ancestors().skip(1).find_map(|dir| manifest_in(dir).map(|manifest| (dir, manifest)))
+}
+
+#[test]
+fn manifest_changes() {
+    let dir = std::env::temp_dir().join(format!("oxc_no_extraneous_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    assert!(manifest_in(&dir).is_none());
+    fs::write(dir.join("package.json"), r#"{ "dependencies": { "a": "1" } }"#).unwrap();
+    assert!(manifest_in(&dir).unwrap().dependencies.contains_key("a"));
+    fs::write(dir.join("package.json"), r#"{ "dependencies": { "a": "1", "bc": "2" } }"#).unwrap();
+    assert!(manifest_in(&dir).unwrap().dependencies.contains_key("bc"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let app = || Some(PathBuf::from("monorepo/packages/app/index.js"));
+    let typescript = || Some(PathBuf::from("with-typescript-dev-dependencies/index.ts"));
+
+    let pass = vec![
+        ("import 'lodash.cond'", None, None, None),
+        ("import 'find-up'", None, None, None),
+        ("import foo, { bar } from 'lodash.cond'", None, None, None),
+        ("import foo, { bar } from 'find-up'", None, None, None),
+        ("import 'eslint'", None, None, None),
+        ("import 'eslint/lib/api'", None, None, None),
+        ("import 'fs'", None, None, None),
+        ("import 'node:fs'", None, None, None),
+        ("import 'fs/promises'", None, None, None),
+        ("import './foo'", None, None, None),
+        ("import '../foo'", None, None, None),
+        ("import '#internal'", None, None, None),
+        ("import '@org/package'", None, None, None),
+        ("import '@org/package/subpath'", None, None, None),
+        ("import 'lodash.isarray'", None, None, None),
+        ("import '@generated/foo'", None, None, None),
+        ("import 'rxjs/operators'", None, None, None),
+        ("export { foo } from 'lodash.cond'", None, None, None),
+        ("export * from 'lodash.cond'", None, None, None),
+        ("const foo = require('lodash.cond')", None, None, None),
+        ("const foo = require(foo)", None, None, None),
+        ("import('lodash.cond')", None, None, None),
+        ("import type { Foo } from 'not-a-dependency'", None, None, None),
+        ("export type { Foo } from 'not-a-dependency'", None, None, None),
+        ("import 'glob'", Some(json!([{ "devDependencies": true }])), None, None),
+        ("import 'lodash.isarray'", Some(json!([{ "devDependencies": false }])), None, None),
+        // Self reference
+        ("import 'app/utils'", None, None, app()),
+        ("import 'lib'", None, None, app()),
+        // Declared in the workspace root
+        ("import 'shared-tool'", None, None, app()),
+        ("import 'a'", None, None, typescript()),
+    ];
+
+    let fail = vec![
+        ("import 'not-a-dependency'", None, None, None),
+        ("import '@org/not-a-dependency'", None, None, None),
+        ("import 'not-a-dependency/subpath'", None, None, None),
+        ("const foo = require('not-a-dependency')", None, None, None),
+        ("import('not-a-dependency')", None, None, None),
+        ("export { foo } from 'not-a-dependency'", None, None, None),
+        ("export * from 'not-a-dependency'", None, None, None),
+        ("import 'glob'", Some(json!([{ "devDependencies": false }])), None, None),
+        ("import 'lodash.isarray'", Some(json!([{ "optionalDependencies": false }])), None, None),
+        ("import '@generated/foo'", Some(json!([{ "bundledDependencies": false }])), None, None),
+        (
+            "import type { Foo } from 'not-a-dependency'",
+            Some(json!([{ "includeTypes": true }])),
+            None,
+            None,
+        ),
+        // The dependencies of the root of `fixtures/import` are not used in the workspace
+        ("import 'lodash.cond'", None, None, app()),
+        ("import 'shared-tool'", Some(json!([{ "devDependencies": false }])), None, app()),
+        ("import 'a'", Some(json!([{ "devDependencies": false }])), None, typescript()),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, pass, fail)
+        .change_rule_path("index.ts")
+        .test_and_snapshot();
+}
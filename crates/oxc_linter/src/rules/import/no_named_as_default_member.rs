@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use dashmap::mapref::one::Ref;
 use oxc_ast::{
     ast::{BindingPatternKind, Expression, IdentifierReference, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -55,6 +55,10 @@ fn get_symbol_id_from_ident(
 }
 
 impl Rule for NoNamedAsDefaultMember {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.semantic().module_record();
 
@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -32,6 +33,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSelfImport {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.semantic().module_record();
         let resolved_absolute_path = &module_record.resolved_absolute_path;
@@ -1,5 +1,6 @@
 use std::path::{Component, Path};
 
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -29,6 +30,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnresolved {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.semantic().module_record();
 
@@ -101,6 +106,8 @@ fn test() {
         r"require(foo)",
         // Unsupported extensions
         r#"import "./test.png""#,
+        // `paths` of the `tsconfig.json` in the working directory
+        r#"import bar from "@tsconfig-alias/bar""#,
     ];
 
     let fail = vec![
@@ -109,6 +116,7 @@ fn test() {
         r"import bar from './baz';",
         r"import bar from './empty-folder';",
         r"import { DEEP } from 'in-alternate-root';",
+        r#"import baz from "@tsconfig-alias/baz""#,
         // TODO: dynamic import
         // r#"import('in-alternate-root').then(function({DEEP}) {});"#,
         r#"export { foo } from "./does-not-exist""#,
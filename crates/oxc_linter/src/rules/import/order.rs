@@ -0,0 +1,348 @@
+use std::cmp::Ordering;
+
+use oxc_ast::{
+    ast::{ImportDeclaration, ModuleDeclaration, Statement},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactString, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::NODE_BUILTINS_MODULE, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(order): `{1}` import should occur before import of `{2}`")]
+#[diagnostic(severity(warning))]
+struct OrderDiagnostic(#[label] Span, CompactString, CompactString);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/order.md>
+#[derive(Debug, Default, Clone)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone)]
+pub struct OrderConfig {
+    /// Rank of each import type, import types missing from `groups` share the last rank.
+    ranks: [usize; ImportType::COUNT],
+    alphabetize: AlphabetizeOrder,
+    case_insensitive: bool,
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self::from_groups(&[
+            vec![ImportType::Builtin],
+            vec![ImportType::External],
+            vec![ImportType::Parent],
+            vec![ImportType::Sibling],
+            vec![ImportType::Index],
+        ])
+    }
+}
+
+impl OrderConfig {
+    fn from_groups(groups: &[Vec<ImportType>]) -> Self {
+        let mut ranks = [groups.len(); ImportType::COUNT];
+        for (rank, group) in groups.iter().enumerate() {
+            for import_type in group {
+                ranks[*import_type as usize] = rank;
+            }
+        }
+        Self { ranks, alphabetize: AlphabetizeOrder::Ignore, case_insensitive: false }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportType {
+    Builtin,
+    External,
+    Internal,
+    Parent,
+    Sibling,
+    Index,
+    Unknown,
+}
+
+impl ImportType {
+    const COUNT: usize = 7;
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "builtin" => Some(Self::Builtin),
+            "external" => Some(Self::External),
+            "internal" => Some(Self::Internal),
+            "parent" => Some(Self::Parent),
+            "sibling" => Some(Self::Sibling),
+            "index" => Some(Self::Index),
+            "unknown" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    /// Classify an import by its specifier, without resolving it.
+    fn from_specifier(specifier: &str) -> Self {
+        if specifier.starts_with("node:")
+            || NODE_BUILTINS_MODULE.contains(specifier)
+            || specifier.split('/').next().is_some_and(|name| NODE_BUILTINS_MODULE.contains(name))
+        {
+            return Self::Builtin;
+        }
+        if matches!(specifier, "." | "./")
+            || specifier
+                .strip_prefix("./index")
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        {
+            return Self::Index;
+        }
+        if specifier == ".." || specifier.starts_with("../") {
+            return Self::Parent;
+        }
+        if specifier.starts_with("./") {
+            return Self::Sibling;
+        }
+        // `#` for package.json subpath imports, `~/` and `@/` for common path aliases.
+        if specifier.starts_with('#') || specifier.starts_with("~/") || specifier.starts_with("@/")
+        {
+            return Self::Internal;
+        }
+        if specifier.starts_with('/') {
+            return Self::Unknown;
+        }
+        Self::External
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum AlphabetizeOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a convention in the order of import declarations,
+    /// grouped by the kind of module they import:
+    /// `builtin` (`fs`, `node:path`), `external` (`lodash`), `internal` (`#utils`, `~/utils`),
+    /// `parent` (`../a`), `sibling` (`./a`), `index` (`./`) and `unknown` (`/a`).
+    ///
+    /// Imports without bindings (`import './polyfill'`) are never moved, they split the imports into separately ordered blocks.
+    ///
+    /// ### Options
+    ///
+    /// * `groups`: the order of the groups, each item is a group name or an array of group names sharing the same rank.
+    ///   Groups missing from the list are placed last. Defaults to `["builtin", "external", "parent", "sibling", "index"]`.
+    /// * `alphabetize`: `{ "order": "ignore" | "asc" | "desc", "caseInsensitive": boolean }`
+    ///   sorts the imports within a group by their module specifier. Defaults to `{ "order": "ignore" }`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// import foo from './foo';
+    /// import fs from 'fs';
+    ///
+    /// // pass
+    /// import fs from 'fs';
+    /// import foo from './foo';
+    /// ```
+    Order,
+    style
+);
+
+impl Rule for Order {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        let mut order_config = config
+            .get("groups")
+            .and_then(serde_json::Value::as_array)
+            .map_or_else(OrderConfig::default, |groups| {
+                let groups = groups
+                    .iter()
+                    .map(|group| match group {
+                        serde_json::Value::String(name) => {
+                            ImportType::from_name(name).into_iter().collect()
+                        }
+                        serde_json::Value::Array(names) => names
+                            .iter()
+                            .filter_map(serde_json::Value::as_str)
+                            .filter_map(ImportType::from_name)
+                            .collect(),
+                        _ => vec![],
+                    })
+                    .collect::<Vec<_>>();
+                OrderConfig::from_groups(&groups)
+            });
+        if let Some(alphabetize) = config.get("alphabetize") {
+            order_config.alphabetize =
+                match alphabetize.get("order").and_then(serde_json::Value::as_str) {
+                    Some("asc") => AlphabetizeOrder::Asc,
+                    Some("desc") => AlphabetizeOrder::Desc,
+                    _ => AlphabetizeOrder::Ignore,
+                };
+            order_config.case_insensitive = alphabetize
+                .get("caseInsensitive")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default();
+        }
+        Self(Box::new(order_config))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Program])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Program(program) = node.kind() else { return };
+
+        let mut block = vec![];
+        for statement in &program.body {
+            if let Statement::ModuleDeclaration(decl) = statement {
+                if let ModuleDeclaration::ImportDeclaration(import) = &**decl {
+                    if import.specifiers.is_some() {
+                        block.push(&**import);
+                        continue;
+                    }
+                }
+            }
+            self.check_block(&block, ctx);
+            block.clear();
+        }
+        self.check_block(&block, ctx);
+    }
+}
+
+impl Order {
+    fn compare(&self, a: &ImportDeclaration, b: &ImportDeclaration) -> Ordering {
+        let rank = |import: &ImportDeclaration| {
+            self.ranks[ImportType::from_specifier(&import.source.value) as usize]
+        };
+        let ordering = rank(a).cmp(&rank(b));
+        if ordering != Ordering::Equal || self.alphabetize == AlphabetizeOrder::Ignore {
+            return ordering;
+        }
+        let (a, b) = (a.source.value.as_str(), b.source.value.as_str());
+        let ordering =
+            if self.case_insensitive { a.to_lowercase().cmp(&b.to_lowercase()) } else { a.cmp(b) };
+        if self.alphabetize == AlphabetizeOrder::Desc {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    fn check_block<'a>(&self, block: &[&ImportDeclaration<'a>], ctx: &LintContext<'a>) {
+        let mut fix = self.fix(block, ctx);
+        for (i, import) in block.iter().enumerate() {
+            let Some(previous) =
+                block[..i].iter().find(|previous| self.compare(previous, import).is_gt())
+            else {
+                continue;
+            };
+            let diagnostic = OrderDiagnostic(
+                import.span,
+                import.source.value.to_compact_string(),
+                previous.source.value.to_compact_string(),
+            );
+            // The fix sorts the whole block, so it is only attached to the first diagnostic.
+            match fix.take() {
+                Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+
+    /// Sort the imports of the block, keeping the text between them in place.
+    /// Not available when there are comments between the imports, as they could be attached to either import.
+    fn fix<'a>(&self, block: &[&ImportDeclaration<'a>], ctx: &LintContext<'a>) -> Option<Fix<'a>> {
+        let source_text = ctx.source_text();
+        let separators = block
+            .windows(2)
+            .map(|pair| &source_text[pair[0].span.end as usize..pair[1].span.start as usize])
+            .collect::<Vec<_>>();
+        if separators.iter().any(|separator| !separator.trim().is_empty()) {
+            return None;
+        }
+
+        let mut sorted = block.to_vec();
+        sorted.sort_by(|a, b| self.compare(a, b));
+
+        let mut content = String::new();
+        for (i, import) in sorted.iter().enumerate() {
+            content.push_str(import.span.source_text(source_text));
+            if let Some(separator) = separators.get(i) {
+                content.push_str(separator);
+            }
+        }
+        let span = Span::new(block.first()?.span.start, block.last()?.span.end);
+        Some(Fix::new(content, span))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let alphabetize = Some(json!([{ "alphabetize": { "order": "asc" } }]));
+
+    let pass = vec![
+        ("import fs from 'fs'; import _ from 'lodash'; import a from '../a'; import b from './b'; import i from './';", None),
+        ("import fs from 'node:fs'; import path from 'path'; import react from 'react';", None),
+        ("import a from './a'; import './polyfill'; import fs from 'fs';", None),
+        ("import b from './b'; import './polyfill'; import fs from 'fs'; import a from './a';", None),
+        ("import fs from 'fs'; const x = 1; import a from './a';", None),
+        ("import _ from 'lodash'; import fs from 'fs';", Some(json!([{ "groups": [["builtin", "external"], "sibling"] }]))),
+        ("import b from './b'; import a from './a';", None),
+        ("import a from './a'; import b from './b';", alphabetize.clone()),
+        ("import b from './b'; import a from './a';", Some(json!([{ "alphabetize": { "order": "desc" } }]))),
+        ("import A from './A'; import b from './b';", Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }]))),
+        ("import a from './a'; import u from '#utils';", None),
+        ("import u from '#utils'; import a from './a';", Some(json!([{ "groups": ["internal", "sibling"] }]))),
+    ];
+
+    let fail = vec![
+        ("import a from './a'; import fs from 'fs';", None),
+        ("import _ from 'lodash'; import fs from 'fs';", None),
+        ("import i from './'; import a from '../a';", None),
+        ("import b from './b'; import a from './a';", alphabetize.clone()),
+        ("import a from './a'; import A from './A';", alphabetize.clone()),
+        (
+            "import fs from 'fs'; import a from './a';",
+            Some(json!([{ "groups": ["sibling", "builtin"] }])),
+        ),
+        ("import a from './a'; import b from '../b'; import fs from 'fs';", None),
+        ("import a from './a';\n// comment\nimport fs from 'fs';", None),
+    ];
+
+    let fix = vec![
+        (
+            "import a from './a'; import fs from 'fs';",
+            "import fs from 'fs'; import a from './a';",
+            None,
+        ),
+        (
+            "import a from './a';\n\nimport b from '../b';\nimport fs from 'fs';",
+            "import fs from 'fs';\n\nimport b from '../b';\nimport a from './a';",
+            None,
+        ),
+        (
+            "import b from './b'; import a from './a';",
+            "import a from './a'; import b from './b';",
+            alphabetize,
+        ),
+    ];
+
+    Tester::new(Order::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -1,4 +1,5 @@
 use lazy_static::lazy_static;
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -44,6 +45,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCommentedOutTests {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         lazy_static! {
         //  /^\s*[xf]?(test|it|describe)(\.\w+|\[['"]\w+['"]\])?\s*\(/mu
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_ast::{ast::MemberExpression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -86,6 +86,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoConfusingSetTimeout {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let scopes = ctx.scopes();
         let symbol_table = ctx.symbols();
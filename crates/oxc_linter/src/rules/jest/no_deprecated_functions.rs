@@ -6,7 +6,6 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::{phf_map, Map};
-use std::borrow::Cow;
 
 use crate::{context::LintContext, fixer::Fix, rule::Rule};
 
@@ -111,26 +110,32 @@ impl Rule for NoDeprecatedFunctions {
         let AstKind::MemberExpression(mem_expr) = node.kind() else {
             return;
         };
-        let mut chain: Vec<Cow<'a, str>> = Vec::new();
-        if let Expression::Identifier(ident) = mem_expr.object() {
-            chain.push(Cow::Borrowed(ident.name.as_str()));
-        }
-
-        if let Some(name) = mem_expr.static_property_name() {
-            chain.push(Cow::Borrowed(name));
-        }
+        let Expression::Identifier(ident) = mem_expr.object() else {
+            return;
+        };
+        let Some(name) = mem_expr.static_property_name() else {
+            return;
+        };
 
-        let node_name = chain.join(".");
+        // Compare both parts with the keys instead of joining them, most member expressions do not match
+        let Some((node_name, (base_version, replacement))) = DEPRECATED_FUNCTIONS_MAP
+            .entries()
+            .find(|(key, _)| key.split_once('.') == Some((ident.name.as_str(), name)))
+        else {
+            return;
+        };
         // Todo: read from configuration
         let jest_version_num: usize = self.jest.version.parse().unwrap_or(29);
 
-        if let Some((base_version, replacement)) = DEPRECATED_FUNCTIONS_MAP.get(&node_name) {
-            if jest_version_num >= *base_version {
-                ctx.diagnostic_with_fix(
-                    DeprecatedFunction(node_name, (*replacement).to_string(), mem_expr.span()),
-                    || Fix::new(*replacement, mem_expr.span()),
-                );
-            }
+        if jest_version_num >= *base_version {
+            ctx.diagnostic_with_fix(
+                DeprecatedFunction(
+                    (*node_name).to_string(),
+                    (*replacement).to_string(),
+                    mem_expr.span(),
+                ),
+                || Fix::new(*replacement, mem_expr.span()),
+            );
         }
     }
 }
@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -38,6 +39,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoExport {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         // only used in jest files
         if !is_jest_file(ctx) {
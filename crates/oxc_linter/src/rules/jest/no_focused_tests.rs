@@ -76,7 +76,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
     }
 
     if name.starts_with('f') {
-        ctx.diagnostic_with_fix(NoFocusedTestsDiagnostic(call_expr.span), || {
+        ctx.diagnostic_with_suggestion(NoFocusedTestsDiagnostic(call_expr.span), || {
             let start = call_expr.span.start;
             Fix::delete(Span { start, end: start + 1 })
         });
@@ -86,7 +86,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
 
     let only_node = members.iter().find(|member| member.is_name_equal("only"));
     if let Some(only_node) = only_node {
-        ctx.diagnostic_with_fix(NoFocusedTestsDiagnostic(call_expr.span), || {
+        ctx.diagnostic_with_suggestion(NoFocusedTestsDiagnostic(call_expr.span), || {
             let span = only_node.span;
             let start = span.start - 1;
             let end = if matches!(only_node.element, MemberExpressionElement::IdentName(_)) {
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use oxc_ast::{
     ast::{Argument, CallExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -63,6 +63,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoIdenticalTitle {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let possible_jest_nodes = collect_possible_jest_call_node(ctx);
         let mut title_to_span_mapping = HashMap::new();
@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 use oxc_ast::{
     ast::{Argument, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -41,6 +41,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoMocksImport {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let module_records = ctx.semantic().module_record();
 
@@ -7,7 +7,7 @@ use crate::{
 use oxc_allocator::Box as OBox;
 use oxc_ast::{
     ast::{Argument, CallExpression, Expression, FunctionBody, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -44,6 +44,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoTestReturnStatement {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression, AstType::Function])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(call_expr) => {
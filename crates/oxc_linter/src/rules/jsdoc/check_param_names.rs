@@ -0,0 +1,152 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_function_jsdoc, get_param_names, get_param_tag_name},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum CheckParamNamesDiagnostic {
+    #[error(
+        "eslint-plugin-jsdoc(check-param-names): Expected @param names to be \"{1}\". Got \"{2}\"."
+    )]
+    #[diagnostic(severity(warning))]
+    Order(#[label] Span, String, String),
+    #[error("eslint-plugin-jsdoc(check-param-names): @param \"{1}\" does not match an existing function parameter.")]
+    #[diagnostic(severity(warning))]
+    NotFound(#[label] Span, String),
+    #[error("eslint-plugin-jsdoc(check-param-names): Duplicate @param \"{1}\".")]
+    #[diagnostic(severity(warning))]
+    Duplicate(#[label] Span, String),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckParamNames;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures that the names of the `@param` tags of a JSDoc block match the parameters of the
+    /// function, in the same order.
+    ///
+    /// Tags documenting the properties of a parameter, e.g. `@param options.name`, are not
+    /// checked. Any name is accepted for destructured parameters.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Tags left over after renaming or reordering parameters document parameters which do not
+    /// exist, and give them wrong types.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// /**
+    ///  * @param {string} bar
+    ///  * @param {number} foo
+    ///  */
+    /// function quux(foo, bar) {}
+    ///
+    /// // good
+    /// /**
+    ///  * @param {number} foo
+    ///  * @param {string} bar
+    ///  */
+    /// function quux(foo, bar) {}
+    /// ```
+    CheckParamNames,
+    correctness
+);
+
+impl Rule for CheckParamNames {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function, AstType::ArrowFunctionExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) => &func.params,
+            AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+            _ => return,
+        };
+        let Some(jsdoc) = get_function_jsdoc(node, ctx) else { return };
+        let names = jsdoc
+            .tags()
+            .iter()
+            .filter_map(get_param_tag_name)
+            .filter(|name| !name.contains(['.', '[']))
+            .collect::<Vec<_>>();
+        if names.is_empty() {
+            return;
+        }
+
+        let params = get_param_names(params);
+        let span = jsdoc.span();
+        let mut seen = vec![];
+        for (i, &name) in names.iter().enumerate() {
+            if seen.contains(&name) {
+                ctx.diagnostic(CheckParamNamesDiagnostic::Duplicate(span, name.to_string()));
+                continue;
+            }
+            seen.push(name);
+            match params.get(i) {
+                Some((Some(param), _)) if *param == name => {}
+                // Destructured parameters can be documented with any name
+                Some((None, _)) => {}
+                _ if params.iter().any(|(param, _)| *param == Some(name)) => {
+                    let expected = params.iter().filter_map(|(param, _)| *param);
+                    ctx.diagnostic(CheckParamNamesDiagnostic::Order(
+                        span,
+                        expected.collect::<Vec<_>>().join(", "),
+                        names.join(", "),
+                    ));
+                    return;
+                }
+                _ => ctx.diagnostic(CheckParamNamesDiagnostic::NotFound(span, name.to_string())),
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function quux(foo, bar) {}",
+        "/** @param foo */ function quux(foo) {}",
+        "/**
+          * @param {string} foo
+          * @param {number} [bar=1]
+          */
+         function quux(foo, bar = 1) {}",
+        "/** @param foo */ function quux(foo, bar) {}",
+        "/**
+          * @param {Object} options
+          * @param {string} options.name
+          */
+         function quux(options) {}",
+        "/** @param root0 @param bar */ function quux({ a }, bar) {}",
+        "/** @param {...string} args */ const quux = (...args) => {};",
+        "class A { /** @param foo */ quux(foo) {} }",
+        "/** @returns {string} */ function quux(foo) {}",
+    ];
+
+    let fail = vec![
+        "/** @param Foo */ function quux(foo) {}",
+        "/** @param {string} bar @param {number} foo */ function quux(foo, bar) {}",
+        "/** @param foo @param foo */ function quux(foo) {}",
+        "/** @param foo @param bar */ function quux(foo) {}",
+        "/** @param foo */ export const quux = () => {};",
+        "class A { /** @param bar */ quux(foo) {} }",
+    ];
+
+    Tester::new(CheckParamNames::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
+}
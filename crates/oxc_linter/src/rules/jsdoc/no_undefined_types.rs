@@ -0,0 +1,149 @@
+use oxc_ast::AstType;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::JSDocTagKind;
+use oxc_span::Span;
+use phf::phf_set;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_tag_type, get_type_names, split_tag_type},
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsdoc(no-undefined-types): The type '{0}' is undefined.")]
+#[diagnostic(
+    severity(warning),
+    help("Import or declare the type, or define it with a `@typedef` tag.")
+)]
+struct NoUndefinedTypesDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUndefinedTypes;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Checks that the types of JSDoc tags such as `@param`, `@returns` and `@type` are
+    /// defined.
+    ///
+    /// A type is defined when it is a primitive or utility type, a global of the enabled
+    /// environments, a variable, class, import or type declared in the file, or a type defined
+    /// by a `@typedef`, `@callback` or `@template` tag of the file.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A misspelled or missing type is silently treated as `any` by tools relying on JSDoc
+    /// types.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// /** @param {strnig} foo */
+    /// function quux(foo) {}
+    ///
+    /// // good
+    /// /** @typedef {{ name: string }} User */
+    ///
+    /// /** @param {User} user */
+    /// function quux(user) {}
+    /// ```
+    NoUndefinedTypes,
+    suspicious
+);
+
+/// Types which are not globals
+const BUILTIN_TYPES: phf::Set<&'static str> = phf_set! {
+    "any", "bigint", "boolean", "false", "never", "null", "number", "object", "string", "symbol",
+    "this", "true", "undefined", "unknown", "void",
+    "ArrayLike", "AsyncGenerator", "AsyncIterable", "AsyncIterableIterator", "AsyncIterator",
+    "Awaited", "Capitalize", "ConstructorParameters", "Exclude", "Extract", "Generator",
+    "InstanceType", "Iterable", "IterableIterator", "Iterator", "Lowercase", "NonNullable", "Omit",
+    "Parameters", "Partial", "Pick", "PromiseLike", "PropertyKey", "Readonly", "ReadonlyArray",
+    "Record", "Required", "ReturnType", "TemplateStringsArray", "ThisType", "Uncapitalize",
+    "Uppercase",
+};
+
+impl Rule for NoUndefinedTypes {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let jsdocs = ctx.jsdoc().iter_all().collect::<Vec<_>>();
+
+        // The types defined by the tags of any JSDoc block of the file
+        let mut defined = FxHashSet::default();
+        for tag in jsdocs.iter().flat_map(|jsdoc| jsdoc.tags()) {
+            let JSDocTagKind::Unknown(tag_name @ ("typedef" | "callback" | "template")) = tag.kind
+            else {
+                continue;
+            };
+            let (_, rest) = split_tag_type(&tag.comment);
+            if tag_name == "template" {
+                // `@template T, U`
+                defined.extend(rest.split(',').filter_map(|name| name.split_whitespace().next()));
+            } else if let Some(name) = rest.split_whitespace().next() {
+                defined.insert(name);
+            }
+        }
+
+        for jsdoc in jsdocs {
+            let mut reported = vec![];
+            for type_expression in jsdoc.tags().iter().filter_map(get_tag_type) {
+                for name in get_type_names(type_expression) {
+                    if BUILTIN_TYPES.contains(name)
+                        || defined.contains(name)
+                        || reported.contains(&name)
+                        || ctx.env_contains_var(name)
+                        || ctx.symbols().get_symbol_id_from_name(name).is_some()
+                    {
+                        continue;
+                    }
+                    reported.push(name);
+                    ctx.diagnostic(NoUndefinedTypesDiagnostic(name.to_string(), jsdoc.span()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/** @param {string} foo */ function quux(foo) {}",
+        "/** @param {string|number|boolean|null|undefined|*} foo */ function quux(foo) {}",
+        "/** @param {Array<Promise<Map<string, Set<number>>>>} foo */ function quux(foo) {}",
+        "class Foo {} /** @param {Foo} foo */ function quux(foo) {}",
+        "import { Foo } from './foo'; /** @param {Foo} foo */ function quux(foo) {}",
+        "/** @typedef {{ name: string }} User */ /** @param {User} user */ function quux(user) {}",
+        "/** @callback Handler @param {string} event */ /** @param {Handler} fn */ function quux(fn) {}",
+        "/** @template T, U @param {T} foo @returns {U} */ function quux(foo) {}",
+        "/** @param {{ name: string, age?: number }} foo */ function quux(foo) {}",
+        "/** @param {import('./foo').Foo} foo */ function quux(foo) {}",
+        "/** @param {'a' | \"b\" | 1} foo */ function quux(foo) {}",
+        "/** @type {Record<string, Partial<Foo>>} */ let foo; class Foo {}",
+        "/** @param {function(string): void} foo */ function quux(foo) {}",
+        "/** @param {typeof bar} foo */ function quux(foo) {}",
+        "/** @param {Foo.Bar} foo */ function quux(foo) {} const Foo = {};",
+        "/** @see {Unknown} */ function quux() {}",
+    ];
+
+    let fail = vec![
+        "/** @param {strnig} foo */ function quux(foo) {}",
+        "/** @param {Array<Foo>} foo @returns {Foo} */ function quux(foo) {}",
+        "/** @returns {Promise<Bar>} */ async function quux() {}",
+        "/** @type {Foo} */ let foo;",
+        "/** @typedef {Object} User @property {Nmae} name */",
+        "/** @param {{ name: Nmae }} foo */ function quux(foo) {}",
+    ];
+
+    Tester::new(NoUndefinedTypes::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
+}
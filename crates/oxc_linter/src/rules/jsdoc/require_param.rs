@@ -0,0 +1,125 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{JSDocTag, JSDocTagKind};
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_function_jsdoc, get_param_names, get_param_tag_name},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsdoc(require-param): Missing JSDoc @param \"{0}\" declaration.")]
+#[diagnostic(severity(warning), help("Add a `@param` tag for the parameter."))]
+struct RequireParamDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireParam;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires that all parameters of a function with a JSDoc block are documented with a
+    /// `@param` tag.
+    ///
+    /// Destructured parameters are not checked. Functions whose JSDoc block has an
+    /// `@inheritdoc` or a `@type` tag are not checked either.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In code bases relying on JSDoc for typing, an undocumented parameter has no type.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// /**
+    ///  * @param {string} a
+    ///  */
+    /// function quux(a, b) {}
+    ///
+    /// // good
+    /// /**
+    ///  * @param {string} a
+    ///  * @param {number} b
+    ///  */
+    /// function quux(a, b) {}
+    /// ```
+    RequireParam,
+    pedantic
+);
+
+impl Rule for RequireParam {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function, AstType::ArrowFunctionExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) => &func.params,
+            AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+            _ => return,
+        };
+        let Some(jsdoc) = get_function_jsdoc(node, ctx) else { return };
+        let tags = jsdoc.tags();
+        let is_exempted = |tag: &JSDocTag| match tag.kind {
+            JSDocTagKind::Unknown(name) => {
+                name.eq_ignore_ascii_case("inheritdoc") || name == "type"
+            }
+            _ => false,
+        };
+        if tags.iter().any(is_exempted) {
+            return;
+        }
+
+        let documented = tags.iter().filter_map(get_param_tag_name).collect::<Vec<_>>();
+        for (name, span) in get_param_names(params) {
+            let Some(name) = name else { continue };
+            if !documented.contains(&name) {
+                ctx.diagnostic(RequireParamDiagnostic(name.to_string(), span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function quux(foo) {}",
+        "/** @param foo */ function quux(foo) {}",
+        "/**
+          * @param {string} foo
+          * @param {number} [bar=1]
+          */
+         function quux(foo, bar = 1) {}",
+        "/** @param {...string} args */ function quux(...args) {}",
+        "/** @param root0 */ function quux({ a, b }) {}",
+        "/** @inheritdoc */ function quux(foo) {}",
+        "/** @type {(foo: string) => void} */ const quux = (foo) => {};",
+        "/** @param foo */ const quux = function (foo) {};",
+        "/** @param foo */ export function quux(foo) {}",
+        "class A { /** @param foo */ quux(foo) {} }",
+        "/** Not the JSDoc of the callback */ foo((bar) => {});",
+    ];
+
+    let fail = vec![
+        "/** Does something */ function quux(foo) {}",
+        "/** @param foo */ function quux(foo, bar) {}",
+        "/** @param foo */ const quux = (foo, bar = 1) => {};",
+        "/** @param foo */ export default function (foo, ...rest) {}",
+        "class A { /** @param foo */ quux(foo, bar) {} }",
+        "/**
+          * @param {string} foo
+          */
+         function quux(foo, bar) {}",
+    ];
+
+    Tester::new(RequireParam::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
+}
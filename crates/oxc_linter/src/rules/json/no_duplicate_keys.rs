@@ -0,0 +1,76 @@
+use oxc_ast::AstType;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+    Error as DiagnosticError,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    json::{JsonValue, JsonValueKind},
+    rule::Rule,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("json(no-duplicate-keys): Duplicate key `{0}`")]
+#[diagnostic(severity(warning), help("Only the last value of the key is used, remove the others."))]
+struct DuplicateKeyDiagnostic(
+    String,
+    #[label("`{0}` is first defined here")] Span,
+    #[label("and redefined here")] Span,
+);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateKeys;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows duplicate keys in the objects of JSON files, linted with `--json`.
+    ///
+    /// ### Why is this bad?
+    /// Only the last value of a duplicated key is used, the other ones are usually a mistake
+    /// of a merge or of a copy.
+    ///
+    /// ### Example
+    /// ```json
+    /// { "name": "a", "version": "1.0.0", "name": "b" }
+    /// ```
+    NoDuplicateKeys,
+    correctness
+);
+
+impl Rule for NoDuplicateKeys {
+    /// JSON files are not parsed as JavaScript, see `NoDuplicateKeys::check`
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+}
+
+impl NoDuplicateKeys {
+    /// Reports the keys defined more than once in the objects of `value`
+    pub(crate) fn check(value: &JsonValue, errors: &mut Vec<DiagnosticError>) {
+        match &value.kind {
+            JsonValueKind::Object(members) => {
+                let mut keys = FxHashMap::default();
+                for member in members {
+                    if let Some(first) = keys.insert(member.key.as_str(), member.key_span) {
+                        errors.push(DiagnosticError::new(DuplicateKeyDiagnostic(
+                            member.key.clone(),
+                            first,
+                            member.key_span,
+                        )));
+                    }
+                    Self::check(&member.value, errors);
+                }
+            }
+            JsonValueKind::Array(values) => {
+                for value in values {
+                    Self::check(value, errors);
+                }
+            }
+            _ => {}
+        }
+    }
+}
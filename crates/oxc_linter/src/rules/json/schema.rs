@@ -0,0 +1,261 @@
+use std::path::Path;
+
+use oxc_ast::AstType;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+    Error as DiagnosticError,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    json::{JsonValue, JsonValueKind},
+    rule::Rule,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("json(schema): `{0}` should be {1}, but is {2}")]
+#[diagnostic(severity(warning))]
+struct SchemaDiagnostic(String, String, &'static str, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct Schema;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Checks the types of the values of `package.json`, `tsconfig.json`, `jsconfig.json`
+    /// and lint configuration files, linted with `--json`.
+    ///
+    /// ### Why is this bad?
+    /// Their tools ignore or reject the values of the wrong type, often without telling where.
+    /// Only the common properties are checked, other properties can have any value.
+    ///
+    /// ### Example
+    /// ```json
+    /// { "name": "acme", "private": "true" }
+    /// ```
+    Schema,
+    correctness
+);
+
+impl Rule for Schema {
+    /// JSON files are not parsed as JavaScript, see `Schema::check`
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+}
+
+impl Schema {
+    /// Reports the values of `value` of the wrong type, when the file at `path` has a schema
+    pub(crate) fn check(path: &Path, value: &JsonValue, errors: &mut Vec<DiagnosticError>) {
+        let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        if let Some(schema) = JsonSchema::of_file(file_name) {
+            schema.validate(value, "", errors);
+        }
+    }
+}
+
+/// The expected values of a JSON file, only as detailed as needed to catch common mistakes
+enum JsonSchema {
+    Any,
+    String,
+    Bool,
+    /// One of these strings
+    Enum(&'static [&'static str]),
+    Array(&'static JsonSchema),
+    /// An object with these known properties, other properties are not checked
+    Object(&'static [(&'static str, JsonSchema)]),
+    /// An object whose values all match the schema
+    Map(&'static JsonSchema),
+    OneOf(&'static [JsonSchema]),
+}
+
+const STRINGS: JsonSchema = JsonSchema::Array(&JsonSchema::String);
+
+const STRING_MAP: JsonSchema = JsonSchema::Map(&JsonSchema::String);
+
+/// <https://docs.npmjs.com/cli/configuring-npm/package-json>
+const PACKAGE_JSON: JsonSchema = JsonSchema::Object(&[
+    ("name", JsonSchema::String),
+    ("version", JsonSchema::String),
+    ("description", JsonSchema::String),
+    ("keywords", STRINGS),
+    ("license", JsonSchema::String),
+    ("private", JsonSchema::Bool),
+    ("type", JsonSchema::Enum(&["module", "commonjs"])),
+    ("main", JsonSchema::String),
+    ("module", JsonSchema::String),
+    ("types", JsonSchema::String),
+    ("files", STRINGS),
+    ("bin", JsonSchema::OneOf(&[JsonSchema::String, STRING_MAP])),
+    ("scripts", STRING_MAP),
+    ("engines", STRING_MAP),
+    ("dependencies", STRING_MAP),
+    ("devDependencies", STRING_MAP),
+    ("peerDependencies", STRING_MAP),
+    ("optionalDependencies", STRING_MAP),
+    ("sideEffects", JsonSchema::OneOf(&[JsonSchema::Bool, STRINGS])),
+    ("workspaces", JsonSchema::OneOf(&[STRINGS, JsonSchema::Object(&[("packages", STRINGS)])])),
+]);
+
+/// <https://www.typescriptlang.org/tsconfig>, for `tsconfig.json` and `jsconfig.json`
+const TSCONFIG: JsonSchema = JsonSchema::Object(&[
+    ("extends", JsonSchema::OneOf(&[JsonSchema::String, STRINGS])),
+    (
+        "compilerOptions",
+        JsonSchema::Object(&[
+            ("allowJs", JsonSchema::Bool),
+            ("baseUrl", JsonSchema::String),
+            ("checkJs", JsonSchema::Bool),
+            ("composite", JsonSchema::Bool),
+            ("declaration", JsonSchema::Bool),
+            ("esModuleInterop", JsonSchema::Bool),
+            ("incremental", JsonSchema::Bool),
+            ("isolatedModules", JsonSchema::Bool),
+            ("jsx", JsonSchema::String),
+            ("lib", STRINGS),
+            ("module", JsonSchema::String),
+            ("moduleResolution", JsonSchema::String),
+            ("noEmit", JsonSchema::Bool),
+            ("outDir", JsonSchema::String),
+            ("paths", JsonSchema::Map(&STRINGS)),
+            ("resolveJsonModule", JsonSchema::Bool),
+            ("rootDir", JsonSchema::String),
+            ("skipLibCheck", JsonSchema::Bool),
+            ("sourceMap", JsonSchema::Bool),
+            ("strict", JsonSchema::Bool),
+            ("target", JsonSchema::String),
+            ("types", STRINGS),
+        ]),
+    ),
+    ("files", STRINGS),
+    ("include", STRINGS),
+    ("exclude", STRINGS),
+    ("references", JsonSchema::Array(&JsonSchema::Object(&[("path", JsonSchema::String)]))),
+]);
+
+/// A severity, or a severity followed by the options of the rule
+const RULE: JsonSchema = JsonSchema::OneOf(&[
+    JsonSchema::Enum(&["off", "allow", "warn", "error", "deny", "0", "1", "2"]),
+    JsonSchema::Array(&JsonSchema::Any),
+]);
+
+/// `.oxlintrc.json` and `.eslintrc.json`, see `ESLintConfig`
+const LINT_CONFIG: JsonSchema = JsonSchema::Object(&[
+    ("extends", JsonSchema::OneOf(&[JsonSchema::String, STRINGS])),
+    ("plugins", STRINGS),
+    ("rules", JsonSchema::Map(&RULE)),
+    ("env", JsonSchema::Map(&JsonSchema::Bool)),
+    ("globals", JsonSchema::Map(&JsonSchema::OneOf(&[JsonSchema::Bool, JsonSchema::String]))),
+    ("extensions", STRING_MAP),
+    ("ignorePatterns", STRINGS),
+    ("root", JsonSchema::Bool),
+    (
+        "overrides",
+        JsonSchema::Array(&JsonSchema::Object(&[
+            ("files", JsonSchema::OneOf(&[JsonSchema::String, STRINGS])),
+            ("excludedFiles", JsonSchema::OneOf(&[JsonSchema::String, STRINGS])),
+            ("rules", JsonSchema::Map(&RULE)),
+        ])),
+    ),
+]);
+
+impl JsonSchema {
+    /// The schema of the file named `file_name`, `None` for files without one
+    fn of_file(file_name: &str) -> Option<&'static Self> {
+        match file_name {
+            "package.json" => Some(&PACKAGE_JSON),
+            ".oxlintrc.json" | ".eslintrc.json" => Some(&LINT_CONFIG),
+            _ if file_name.ends_with(".json")
+                && (file_name.starts_with("tsconfig") || file_name.starts_with("jsconfig")) =>
+            {
+                Some(&TSCONFIG)
+            }
+            _ => None,
+        }
+    }
+
+    fn matches(&self, value: &JsonValue) -> bool {
+        match (self, &value.kind) {
+            (Self::Any, _)
+            | (Self::String, JsonValueKind::String(_))
+            | (Self::Bool, JsonValueKind::Bool(_))
+            | (Self::Array(_), JsonValueKind::Array(_))
+            | (Self::Object(_) | Self::Map(_), JsonValueKind::Object(_)) => true,
+            (Self::Enum(names), JsonValueKind::String(name)) => names.contains(&name.as_str()),
+            // Severities are also numbers
+            (Self::Enum(names), JsonValueKind::Number(number)) => {
+                names.contains(&number.to_string().as_str())
+            }
+            (Self::OneOf(schemas), _) => schemas.iter().any(|schema| schema.matches(value)),
+            _ => false,
+        }
+    }
+
+    fn description(&self) -> String {
+        match self {
+            Self::Any => "anything".to_string(),
+            Self::String => "a string".to_string(),
+            Self::Bool => "a boolean".to_string(),
+            Self::Enum(names) => {
+                let names = names.iter().map(|name| format!("{name:?}")).collect::<Vec<_>>();
+                format!("one of {}", names.join(", "))
+            }
+            Self::Array(_) => "an array".to_string(),
+            Self::Object(_) | Self::Map(_) => "an object".to_string(),
+            Self::OneOf(schemas) => {
+                schemas.iter().map(Self::description).collect::<Vec<_>>().join(" or ")
+            }
+        }
+    }
+
+    /// Reports the values of `value` which do not match, `path` is the property path of `value`
+    fn validate(&self, value: &JsonValue, path: &str, errors: &mut Vec<DiagnosticError>) {
+        if !self.matches(value) {
+            let path = if path.is_empty() { "(root)" } else { path };
+            errors.push(DiagnosticError::new(SchemaDiagnostic(
+                path.to_string(),
+                self.description(),
+                value.type_name(),
+                value.span,
+            )));
+            return;
+        }
+        match (self, &value.kind) {
+            (Self::Array(schema), JsonValueKind::Array(values)) => {
+                for (i, value) in values.iter().enumerate() {
+                    schema.validate(value, &format!("{path}[{i}]"), errors);
+                }
+            }
+            (Self::Object(properties), JsonValueKind::Object(members)) => {
+                for member in members {
+                    if let Some((_, schema)) = properties.iter().find(|(key, _)| *key == member.key)
+                    {
+                        schema.validate(&member.value, &join_path(path, &member.key), errors);
+                    }
+                }
+            }
+            (Self::Map(schema), JsonValueKind::Object(members)) => {
+                for member in members {
+                    schema.validate(&member.value, &join_path(path, &member.key), errors);
+                }
+            }
+            // The first schema which matches, e.g. the object of `bin`
+            (Self::OneOf(schemas), _) => {
+                if let Some(schema) = schemas.iter().find(|schema| schema.matches(value)) {
+                    schema.validate(value, path, errors);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
@@ -3,7 +3,7 @@ use oxc_ast::{
         JSXAttributeItem, JSXAttributeValue, JSXElement, JSXExpression, JSXExpressionContainer,
         JSXOpeningElement,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -164,6 +164,10 @@ impl Rule for AltText {
         Self(Box::new(alt_text))
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
         let Some(name) = &get_element_type(ctx, jsx_el) else { return };
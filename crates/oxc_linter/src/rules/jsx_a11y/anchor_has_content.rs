@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -68,6 +68,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for AnchorHasContent {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_el) = node.kind() {
             let Some(name) = &get_element_type(ctx, &jsx_el.opening_element) else { return };
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -126,6 +126,10 @@ impl Rule for AnchorIsValid {
         Self(Box::new(AnchorIsValidConfig { valid_hrefs }))
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_el) = node.kind() {
             let JSXElementName::Identifier(ident) = &jsx_el.opening_element.name else { return };
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttribute, JSXAttributeItem, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -60,6 +60,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for AriaActivedescendantHasTabindex {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
             return;
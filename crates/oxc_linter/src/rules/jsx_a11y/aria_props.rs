@@ -1,4 +1,4 @@
-use oxc_ast::{ast::JSXAttributeItem, AstKind};
+use oxc_ast::{ast::JSXAttributeItem, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -40,6 +40,10 @@ declare_oxc_lint!(
     correctness
 );
 impl Rule for AriaProps {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXAttributeItem])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) = node.kind() {
             let name = get_jsx_attribute_name(&attr.name).to_lowercase();
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeValue, JSXExpression, JSXExpressionContainer},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -118,6 +118,10 @@ impl Rule for AriaRole {
         Self(Box::new(AriaRoleConfig { ignore_non_dom, allowed_invalid_roles }))
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_el) = node.kind() {
             if let Option::Some(aria_role) = has_jsx_prop(&jsx_el.opening_element, "role") {
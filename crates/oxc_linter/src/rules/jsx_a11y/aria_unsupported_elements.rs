@@ -1,4 +1,4 @@
-use oxc_ast::{ast::JSXAttributeItem, AstKind};
+use oxc_ast::{ast::JSXAttributeItem, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -42,6 +42,10 @@ pub struct AriaUnsupportedElements;
 struct AriaUnsupportedElementsDiagnostic(#[label] pub Span, String);
 
 impl Rule for AriaUnsupportedElements {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             let Some(el_type) = get_element_type(ctx, jsx_el) else {
@@ -6,7 +6,7 @@ use crate::{
 };
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeValue},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -180,6 +180,10 @@ impl Rule for AutocompleteValid {
         Self(Box::new(AutocompleteValidConfig { input_components }))
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             let Some(name) = &get_element_type(ctx, jsx_el) else { return };
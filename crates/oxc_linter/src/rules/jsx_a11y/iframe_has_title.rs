@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, JSXAttributeValue, JSXElementName, JSXExpression, JSXExpressionContainer},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -62,6 +62,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for IframeHasTitle {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
             return;
@@ -5,7 +5,7 @@ use oxc_ast::{
         Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXExpression,
         JSXExpressionContainer,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -107,6 +107,10 @@ impl Rule for ImgRedundantAlt {
 
         Self(Box::new(img_redundant_alt))
     }
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
         let Some(element_type) = get_element_type(ctx, jsx_el) else {
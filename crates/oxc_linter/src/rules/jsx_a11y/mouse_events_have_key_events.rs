@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeValue, JSXExpression, JSXExpressionContainer},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -99,6 +99,10 @@ impl Rule for MouseEventsHaveKeyEvents {
         Self(Box::new(config))
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeValue, JSXOpeningElement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -44,6 +44,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAriaHiddenOnFocusable {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
         if let Some(aria_hidden_prop) = has_jsx_prop_lowercase(jsx_el, "aria-hidden") {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -89,6 +89,10 @@ impl Rule for NoAutofocus {
         no_focus
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_el) = node.kind() {
             if let Option::Some(autofocus) = has_jsx_prop(&jsx_el.opening_element, "autoFocus") {
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::JSXElementName, AstKind};
+use oxc_ast::{ast::JSXElementName, AstKind, AstType};
 use oxc_diagnostics::thiserror::Error;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
@@ -52,6 +52,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDistractingElements {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
         let JSXElementName::Identifier(iden) = &jsx_el.name else { return };
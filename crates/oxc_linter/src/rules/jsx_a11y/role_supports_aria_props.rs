@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXOpeningElement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -62,6 +62,10 @@ enum RoleSupportsAriaPropsDiagnostic {
 }
 
 impl Rule for RoleSupportsAriaProps {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             if let Some(el_type) = get_element_type(ctx, jsx_el) {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, JSXAttributeItem, JSXAttributeName, ObjectPropertyKind, PropertyKey},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -35,6 +35,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for InlineScriptId {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ImportDefaultSpecifier])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ImportDefaultSpecifier(specifier) = node.kind() else {
             return;
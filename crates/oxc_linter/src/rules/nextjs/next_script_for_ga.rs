@@ -3,7 +3,7 @@ use oxc_ast::{
         Expression, JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXExpression,
         JSXOpeningElement, ObjectProperty, ObjectPropertyKind, PropertyKey,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -42,6 +42,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NextScriptForGa {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else { return };
 
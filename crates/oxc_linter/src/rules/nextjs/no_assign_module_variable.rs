@@ -1,4 +1,4 @@
-use oxc_ast::{ast::BindingPatternKind, AstKind};
+use oxc_ast::{ast::BindingPatternKind, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -34,6 +34,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAssignModuleVariable {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::VariableDeclaration])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::VariableDeclaration(variable_decl) = node.kind() else { return };
 
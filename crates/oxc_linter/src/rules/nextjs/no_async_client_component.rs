@@ -2,7 +2,7 @@ use oxc_ast::{
     ast::{
         BindingPatternKind, ExportDefaultDeclarationKind, Expression, ModuleDeclaration, Statement,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -39,6 +39,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAsyncClientComponent {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let Some(root) = ctx.nodes().iter().next() else { return };
         let AstKind::Program(program) = root.kind() else { return };
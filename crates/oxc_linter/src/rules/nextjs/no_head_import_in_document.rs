@@ -1,4 +1,4 @@
-use oxc_ast::{ast::ModuleDeclaration, AstKind};
+use oxc_ast::{ast::ModuleDeclaration, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -34,6 +34,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoHeadImportInDocument {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ModuleDeclaration])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
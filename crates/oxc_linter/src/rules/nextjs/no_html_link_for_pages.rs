@@ -0,0 +1,306 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use oxc_ast::{
+    ast::{
+        JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElementName, JSXOpeningElement,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-next(no-html-link-for-pages): Do not use an `<a>` element to navigate to `{0}`. Use `<Link />` from `next/link` instead.")]
+#[diagnostic(
+    severity(warning),
+    help("See https://nextjs.org/docs/messages/no-html-link-for-pages")
+)]
+struct NoHtmlLinkForPagesDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoHtmlLinkForPages(Box<NoHtmlLinkForPagesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoHtmlLinkForPagesConfig {
+    /// Custom `pages` directories, instead of `pages` and `src/pages` of the root directories
+    pages_dirs: Vec<PathBuf>,
+}
+
+impl std::ops::Deref for NoHtmlLinkForPages {
+    type Target = NoHtmlLinkForPagesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Prevent usage of `<a>` elements to navigate to internal Next.js pages.
+    ///
+    /// ### Why is this bad?
+    /// An `<a>` element reloads the whole page, while `<Link />` from `next/link` performs
+    /// a client-side transition.
+    ///
+    /// The pages are found in the `pages` and `app` directories of the
+    /// `settings.next.rootDir` directories, or in the directories given as the option.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// function Home() {
+    ///   return <a href="/about/">About Us</a>;
+    /// }
+    /// ```
+    NoHtmlLinkForPages,
+    correctness
+);
+
+impl Rule for NoHtmlLinkForPages {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let pages_dirs = match value.get(0) {
+            Some(serde_json::Value::String(dir)) => vec![PathBuf::from(dir)],
+            Some(serde_json::Value::Array(dirs)) => {
+                dirs.iter().filter_map(serde_json::Value::as_str).map(PathBuf::from).collect()
+            }
+            _ => vec![],
+        };
+        Self(Box::new(NoHtmlLinkForPagesConfig { pages_dirs }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        let JSXElementName::Identifier(ident) = &element.name else { return };
+        if ident.name != "a" {
+            return;
+        }
+        if string_attribute(element, "target").is_some_and(|target| target == "_blank")
+            || has_attribute(element, "download")
+        {
+            return;
+        }
+        let Some(href) = string_attribute(element, "href") else { return };
+        let href = normalize_url(href);
+        // Outgoing links are ignored
+        if href.starts_with("http://") || href.starts_with("https://") || href.starts_with("//") {
+            return;
+        }
+
+        let root_dirs = ctx.settings().next.get_root_dirs();
+        let root_dirs = if root_dirs.is_empty() {
+            vec![PathBuf::from(".")]
+        } else {
+            root_dirs.into_iter().map(PathBuf::from).collect()
+        };
+        let pages_dirs = if self.pages_dirs.is_empty() {
+            root_dirs.iter().flat_map(|dir| [dir.join("pages"), dir.join("src/pages")]).collect()
+        } else {
+            self.pages_dirs.clone()
+        };
+        let app_dirs = root_dirs.iter().flat_map(|dir| [dir.join("app"), dir.join("src/app")]);
+
+        let is_page = pages_dirs
+            .iter()
+            .map(|dir| page_urls(dir, PagesKind::Pages))
+            .chain(app_dirs.map(|dir| page_urls(&dir, PagesKind::App)))
+            .any(|urls| urls.iter().any(|url| url.is_match(&href)));
+        if is_page {
+            ctx.diagnostic(NoHtmlLinkForPagesDiagnostic(href, element.span));
+        }
+    }
+}
+
+fn has_attribute(element: &JSXOpeningElement, name: &str) -> bool {
+    element.attributes.iter().any(|attribute| {
+        matches!(
+            attribute,
+            JSXAttributeItem::Attribute(attribute)
+                if matches!(&attribute.name, JSXAttributeName::Identifier(ident) if ident.name == name)
+        )
+    })
+}
+
+/// The value of the `name` attribute, when it is a string literal
+fn string_attribute<'a>(element: &'a JSXOpeningElement<'a>, name: &str) -> Option<&'a str> {
+    element.attributes.iter().find_map(|attribute| match attribute {
+        JSXAttributeItem::Attribute(attribute) => match (&attribute.name, &attribute.value) {
+            (
+                JSXAttributeName::Identifier(ident),
+                Some(JSXAttributeValue::StringLiteral(value)),
+            ) if ident.name == name => Some(value.value.as_str()),
+            _ => None,
+        },
+        JSXAttributeItem::SpreadAttribute(_) => None,
+    })
+}
+
+/// Drops the query and the hash and adds a trailing `/`, e.g. `/about?a=b` -> `/about/`
+fn normalize_url(url: &str) -> String {
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    let url = url.strip_suffix("index.html").filter(|url| url.ends_with('/')).unwrap_or(url);
+    if url.is_empty() || url.ends_with('/') {
+        url.to_string()
+    } else {
+        format!("{url}/")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum PagesKind {
+    /// `pages/about.tsx` is the page `/about/`
+    Pages,
+    /// `app/about/page.tsx` is the page `/about/`
+    App,
+}
+
+type PageUrls = Arc<[Regex]>;
+
+/// Patterns of the page urls keyed by their directory, empty when it does not exist.
+static PAGE_URLS: Lazy<DashMap<(PathBuf, PagesKind), PageUrls>> = Lazy::new(DashMap::new);
+
+fn page_urls(dir: &Path, kind: PagesKind) -> PageUrls {
+    let key = (dir.to_path_buf(), kind);
+    if let Some(urls) = PAGE_URLS.get(&key) {
+        return Arc::clone(&urls);
+    }
+    let mut urls = vec![];
+    match kind {
+        PagesKind::Pages => collect_pages_urls("/", dir, &mut urls),
+        PagesKind::App => collect_app_urls("/", dir, &mut urls),
+    }
+    let mut patterns = urls
+        .iter()
+        .map(|url| match kind {
+            PagesKind::Pages => normalize_url(url),
+            PagesKind::App => normalize_url(&normalize_app_path(url)),
+        })
+        .collect::<Vec<_>>();
+    patterns.sort_unstable();
+    patterns.dedup();
+    let patterns = patterns.iter().filter_map(|url| url_pattern(url)).collect::<PageUrls>();
+    PAGE_URLS.insert(key, Arc::clone(&patterns));
+    patterns
+}
+
+/// Turns the dynamic part of `url`, e.g. `[id]`, into a wildcard which does not match a file name
+fn url_pattern(url: &str) -> Option<Regex> {
+    let pattern = match (url.find('['), url.rfind(']')) {
+        (Some(start), Some(end)) if start < end => {
+            format!("^{}[^.]*?{}$", regex::escape(&url[..start]), regex::escape(&url[end + 1..]))
+        }
+        _ => format!("^{}$", regex::escape(url)),
+    };
+    Regex::new(&pattern).ok()
+}
+
+fn is_script(file_name: &str) -> bool {
+    [".js", ".jsx", ".ts", ".tsx"].iter().any(|ext| file_name.ends_with(ext))
+}
+
+fn file_stem(file_name: &str) -> &str {
+    file_name.rsplit_once('.').map_or(file_name, |(stem, _)| stem)
+}
+
+fn collect_pages_urls(prefix: &str, dir: &Path, urls: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if is_script(&file_name) {
+            let stem = file_stem(&file_name);
+            if stem == "index" {
+                urls.push(prefix.to_string());
+            }
+            urls.push(format!("{prefix}{stem}"));
+        } else if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            collect_pages_urls(&format!("{prefix}{file_name}/"), &entry.path(), urls);
+        }
+    }
+}
+
+fn collect_app_urls(prefix: &str, dir: &Path, urls: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if is_script(&file_name) {
+            if file_stem(&file_name) == "page" {
+                urls.push(prefix.to_string());
+            }
+        } else if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            collect_app_urls(&format!("{prefix}{file_name}/"), &entry.path(), urls);
+        }
+    }
+}
+
+/// Drops the route groups, e.g. `(marketing)`, and the parallel routes, e.g. `@modal`
+fn normalize_app_path(route: &str) -> String {
+    let path = route
+        .split('/')
+        .filter(|segment| {
+            let is_group = segment.starts_with('(') && segment.ends_with(')');
+            !(segment.is_empty() || is_group || segment.starts_with('@'))
+        })
+        .fold(String::new(), |path, segment| path + "/" + segment);
+    if path.is_empty() {
+        "/".to_string()
+    } else {
+        path
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pages = || Some(json!({ "next": { "rootDir": "fixtures/nextjs/with-pages" } }));
+    let app = || Some(json!({ "next": { "rootDir": "fixtures/nextjs/with-app" } }));
+    let custom_pages = || Some(json!(["fixtures/nextjs/custom-pages-dir/custom-pages"]));
+
+    let pass = vec![
+        ("export const Page = () => <a href='https://example.com/'>Homepage</a>;", None, pages()),
+        ("export const Page = () => <a href='//example.com/about/'>About</a>;", None, pages()),
+        ("export const Page = () => <a href='/contact/'>Contact</a>;", None, pages()),
+        ("export const Page = () => <a href='/list/foo/bar/baz.txt'>File</a>;", None, pages()),
+        ("export const Page = () => <a href='/about/' target='_blank'>About</a>;", None, pages()),
+        ("export const Page = () => <a href='/about/' download>About</a>;", None, pages()),
+        ("export const Page = () => <a href={about}>About</a>;", None, pages()),
+        ("export const Page = () => <a>About</a>;", None, pages()),
+        ("export const Page = () => <Link href='/about/'>About</Link>;", None, pages()),
+        ("export const Page = () => <a href='/about/'>About</a>;", None, None),
+        ("export const Page = () => <a href='/dashboard/'>Dashboard</a>;", None, app()),
+        ("export const Page = () => <a href='/about/'>About</a>;", custom_pages(), pages()),
+    ];
+
+    let fail = vec![
+        ("export const Page = () => <a href='/'>Homepage</a>;", None, pages()),
+        ("export const Page = () => <a href='/about'>About</a>;", None, pages()),
+        ("export const Page = () => <a href='/about/?a=b#c'>About</a>;", None, pages()),
+        ("export const Page = () => <a href='/list/'>List</a>;", None, pages()),
+        ("export const Page = () => <a href='/list/foo/'>Item</a>;", None, pages()),
+        ("export const Page = () => <a href='/list/index.html'>List</a>;", None, pages()),
+        ("export const Page = () => <a href='/'>Homepage</a>;", None, app()),
+        ("export const Page = () => <a href='/blog'>Blog</a>;", None, app()),
+        ("export const Page = () => <a href='/login/'>Login</a>;", None, app()),
+        ("export const Page = () => <a href='/settings/'>Settings</a>;", custom_pages(), None),
+    ];
+
+    Tester::new(NoHtmlLinkForPages::NAME, pass, fail).with_nextjs_plugin(true).test_and_snapshot();
+}
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ImportDeclarationSpecifier, JSXChild, JSXElementName, ModuleDeclaration},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -37,6 +37,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoTitleInDocumentHead {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ModuleDeclaration])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
@@ -1,6 +1,6 @@
 // Based on https://github.com/rust-lang/rust-clippy//blob/c9a43b18f11219fa70fe632b29518581fcd589c8/clippy_lints/src/approx_const.rs
 // https://rust-lang.github.io/rust-clippy/master/#approx_constant
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -36,14 +36,25 @@ declare_oxc_lint!(
 );
 
 impl Rule for ApproxConstant {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NumericLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NumericLiteral(number_literal) = node.kind() else {
             return;
         };
 
-        let number_lit_str = number_literal.value.to_string();
+        let value = number_literal.value;
+        let mut number_lit_str = None;
         for (constant, name, min_digits) in &KNOWN_CONSTS {
-            if is_approx_const(*constant, &number_lit_str, *min_digits) {
+            // An approximation has more than `min_digits` characters, so at least 3 decimals,
+            // skip formatting numbers which cannot be one
+            if (constant - value).abs() >= 0.01 {
+                continue;
+            }
+            let number_lit_str = number_lit_str.get_or_insert_with(|| value.to_string());
+            if is_approx_const(*constant, number_lit_str, *min_digits) {
                 ctx.diagnostic(ApproxConstantDiagnostic(number_literal.span, name));
             }
         }
@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 
 use oxc_ast::{
     ast::{Expression, NumericLiteral},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -75,6 +75,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for ConstComparisons {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::LogicalExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::LogicalExpression(logical_expr) = node.kind() else {
             return;
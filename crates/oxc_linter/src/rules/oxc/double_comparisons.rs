@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -46,6 +46,10 @@ declare_oxc_lint!(
 
 #[allow(clippy::similar_names)]
 impl Rule for DoubleComparisons {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::LogicalExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::LogicalExpression(logical_expr) = node.kind() else {
             return;
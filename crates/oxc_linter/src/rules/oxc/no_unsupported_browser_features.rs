@@ -0,0 +1,335 @@
+use oxc_ast::{
+    ast::{ObjectPropertyKind, PropertyKey, RegExpFlags, VariableDeclarationKind},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    syntax_feature::{EcmaVersion, SyntaxFeature},
+};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("oxc(no-unsupported-browser-features): Unsupported {1} syntax ({0}), the target is {2}")]
+#[diagnostic(severity(warning), help("Transpile the code for the target, or raise the target."))]
+struct NoUnsupportedBrowserFeaturesDiagnostic(
+    SyntaxFeature,
+    EcmaVersion,
+    EcmaVersion,
+    #[label] pub Span,
+);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedBrowserFeatures {
+    target: EcmaVersion,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow syntax which is newer than the configured ECMAScript `target`,
+    /// e.g. `??=` when targeting ES2019. Only syntax is checked, not built-in APIs such as `Array.prototype.at`.
+    ///
+    /// The `target` is one of `es5`, `es2015` (or `es6`) to `es2024` and `esnext`, which is the
+    /// default and reports nothing. Browserslist queries are not supported, use the ECMAScript
+    /// version supported by the browsers instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Browsers which do not support the syntax fail to parse the whole script,
+    /// when the code is shipped without being transpiled.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // { "target": "es2019" }
+    /// a ??= b;
+    /// const c = d?.e;
+    /// ```
+    NoUnsupportedBrowserFeatures,
+    restriction
+);
+
+impl Rule for NoUnsupportedBrowserFeatures {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let target = value
+            .get(0)
+            .and_then(|config| config.get("target"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(EcmaVersion::from_name)
+            .unwrap_or_default();
+        Self { target }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::VariableDeclaration,
+            AstType::ArrowFunctionExpression,
+            AstType::Function,
+            AstType::Class,
+            AstType::TemplateLiteral,
+            AstType::ForOfStatement,
+            AstType::ArrayPattern,
+            AstType::ObjectPattern,
+            AstType::AssignmentPattern,
+            AstType::BindingRestElement,
+            AstType::ObjectExpression,
+            AstType::SpreadElement,
+            AstType::MetaProperty,
+            AstType::RegExpLiteral,
+            AstType::BinaryExpression,
+            AstType::AssignmentExpression,
+            AstType::AwaitExpression,
+            AstType::CatchClause,
+            AstType::LogicalExpression,
+            AstType::ChainExpression,
+            AstType::BigintLiteral,
+            AstType::ImportExpression,
+            AstType::ExportAllDeclaration,
+            AstType::NumericLiteral,
+            AstType::PropertyDefinition,
+            AstType::MethodDefinition,
+            AstType::PrivateInExpression,
+            AstType::StaticBlock,
+        ])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if self.target == EcmaVersion::Latest {
+            return;
+        }
+        let check = |feature: SyntaxFeature, span: Span| {
+            let Some(version) = feature.ecma_version() else { return };
+            if version > self.target {
+                ctx.diagnostic(NoUnsupportedBrowserFeaturesDiagnostic(
+                    feature,
+                    version,
+                    self.target,
+                    span,
+                ));
+            }
+        };
+
+        match node.kind() {
+            AstKind::VariableDeclaration(decl) => {
+                let len = match decl.kind {
+                    VariableDeclarationKind::Var => return,
+                    VariableDeclarationKind::Let => 3,
+                    VariableDeclarationKind::Const => 5,
+                };
+                check(
+                    SyntaxFeature::LexicalDeclarations,
+                    Span::new(decl.span.start, decl.span.start + len),
+                );
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                let span = Span::new(arrow.span.start, arrow.body.span.start);
+                check(SyntaxFeature::ArrowFunctions, span);
+                if arrow.r#async {
+                    check(SyntaxFeature::AsyncFunctions, span);
+                }
+            }
+            AstKind::Function(func) => {
+                let head = Span::new(func.span.start, func.params.span.start);
+                let span = if head.size() == 0 { func.span } else { head };
+                match (func.r#async, func.generator) {
+                    (true, true) => check(SyntaxFeature::AsyncGenerators, span),
+                    (true, false) => check(SyntaxFeature::AsyncFunctions, span),
+                    (false, true) => check(SyntaxFeature::Generators, span),
+                    (false, false) => {}
+                }
+            }
+            AstKind::Class(class) => {
+                check(SyntaxFeature::Classes, Span::new(class.span.start, class.body.span.start));
+            }
+            AstKind::TemplateLiteral(lit) => {
+                check(SyntaxFeature::TemplateLiterals, lit.span);
+            }
+            AstKind::ForOfStatement(stmt) => {
+                let span = Span::new(stmt.span.start, stmt.body.span().start);
+                if stmt.r#await {
+                    check(SyntaxFeature::ForAwaitOf, span);
+                } else {
+                    check(SyntaxFeature::ForOf, span);
+                }
+            }
+            AstKind::ArrayPattern(pattern) => {
+                check(SyntaxFeature::Destructuring, pattern.span);
+            }
+            AstKind::ObjectPattern(pattern) => {
+                check(SyntaxFeature::Destructuring, pattern.span);
+                if let Some(rest) = &pattern.rest {
+                    check(SyntaxFeature::ObjectRest, rest.span);
+                }
+            }
+            AstKind::AssignmentPattern(pattern) => {
+                check(SyntaxFeature::DefaultValues, pattern.span);
+            }
+            AstKind::BindingRestElement(rest) => {
+                if !matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::ObjectPattern(_))) {
+                    check(SyntaxFeature::SpreadAndRest, rest.span);
+                }
+            }
+            AstKind::ObjectExpression(object) => {
+                for property in &object.properties {
+                    match property {
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            check(SyntaxFeature::ObjectSpread, spread.span);
+                        }
+                        ObjectPropertyKind::ObjectProperty(property) => {
+                            if property.shorthand {
+                                check(SyntaxFeature::ShorthandProperties, property.span);
+                            } else if property.method {
+                                check(SyntaxFeature::ShorthandMethods, property.span);
+                            } else if property.computed {
+                                check(SyntaxFeature::ComputedPropertyNames, property.key.span());
+                            }
+                        }
+                    }
+                }
+            }
+            AstKind::SpreadElement(spread) => {
+                if !matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::ObjectExpression(_)))
+                {
+                    check(SyntaxFeature::SpreadAndRest, spread.span);
+                }
+            }
+            AstKind::MetaProperty(meta) => {
+                match (meta.meta.name.as_str(), meta.property.name.as_str()) {
+                    ("new", "target") => check(SyntaxFeature::NewTarget, meta.span),
+                    ("import", "meta") => check(SyntaxFeature::ImportMeta, meta.span),
+                    _ => {}
+                }
+            }
+            AstKind::RegExpLiteral(lit) => {
+                let flags = lit.regex.flags;
+                for (flag, feature) in [
+                    (RegExpFlags::U, SyntaxFeature::RegExpUnicodeFlag),
+                    (RegExpFlags::Y, SyntaxFeature::RegExpStickyFlag),
+                    (RegExpFlags::S, SyntaxFeature::RegExpDotAllFlag),
+                    (RegExpFlags::D, SyntaxFeature::RegExpIndicesFlag),
+                    (RegExpFlags::V, SyntaxFeature::RegExpUnicodeSetsFlag),
+                ] {
+                    if flags.contains(flag) {
+                        check(feature, lit.span);
+                    }
+                }
+            }
+            AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::Exponential => {
+                check(SyntaxFeature::Exponentiation, expr.span);
+            }
+            AstKind::AssignmentExpression(expr) => {
+                if expr.operator == AssignmentOperator::Exponential {
+                    check(SyntaxFeature::Exponentiation, expr.span);
+                } else if expr.operator.is_logical() {
+                    check(SyntaxFeature::LogicalAssignment, expr.span);
+                }
+            }
+            AstKind::AwaitExpression(expr) => {
+                let in_function = ctx.nodes().iter_parents(node.id()).any(|parent| {
+                    matches!(
+                        parent.kind(),
+                        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                    )
+                });
+                if !in_function {
+                    check(SyntaxFeature::TopLevelAwait, expr.span);
+                }
+            }
+            AstKind::CatchClause(clause) if clause.param.is_none() => {
+                check(
+                    SyntaxFeature::OptionalCatchBinding,
+                    Span::new(clause.span.start, clause.body.span.start),
+                );
+            }
+            AstKind::LogicalExpression(expr) if expr.operator == LogicalOperator::Coalesce => {
+                check(SyntaxFeature::NullishCoalescing, expr.span);
+            }
+            AstKind::ChainExpression(expr) => {
+                check(SyntaxFeature::OptionalChaining, expr.span);
+            }
+            AstKind::BigintLiteral(lit) => check(SyntaxFeature::Bigint, lit.span),
+            AstKind::ImportExpression(expr) => {
+                check(SyntaxFeature::DynamicImport, expr.span);
+            }
+            AstKind::ExportAllDeclaration(decl) if decl.exported.is_some() => {
+                check(SyntaxFeature::ExportAllAs, decl.span);
+            }
+            AstKind::NumericLiteral(lit) if lit.raw.contains('_') => {
+                check(SyntaxFeature::NumericSeparators, lit.span);
+            }
+            AstKind::PropertyDefinition(def) => {
+                check(SyntaxFeature::ClassFields, def.key.span());
+            }
+            AstKind::MethodDefinition(def)
+                if matches!(def.key, PropertyKey::PrivateIdentifier(_)) =>
+            {
+                check(SyntaxFeature::PrivateMembers, def.key.span());
+            }
+            AstKind::PrivateInExpression(expr) => {
+                check(SyntaxFeature::PrivateIn, expr.span);
+            }
+            AstKind::StaticBlock(block) => {
+                check(
+                    SyntaxFeature::StaticBlocks,
+                    Span::new(block.span.start, block.span.start + 6),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let es5 = || Some(serde_json::json!([{ "target": "es5" }]));
+    let es2019 = || Some(serde_json::json!([{ "target": "es2019" }]));
+
+    let pass = vec![
+        ("a ??= b; a?.b; 1n; class A { #a = 1; static {} }", None),
+        ("var a = function () { return [].concat(b); };", es5()),
+        ("var a = { b: 1 }; for (var c in a) {}", es5()),
+        ("async function* f() { for await (const a of b) {} } const { a, ...b } = c;", es2019()),
+        ("try {} catch {} const a = { ...b };", es2019()),
+        ("a = b ** 2; a **= 2; /./s;", es2019()),
+        ("a ??= b;", Some(serde_json::json!([{ "target": "esnext" }]))),
+        ("a ??= b;", Some(serde_json::json!([{ "target": "es2021" }]))),
+        ("var a = /[a&&b]/v;", Some(serde_json::json!([{ "target": "es2024" }]))),
+    ];
+
+    let fail = vec![
+        ("let a = 1; const b = 2;", es5()),
+        ("var a = () => 1;", es5()),
+        ("class A {}", es5()),
+        ("function* g() {}", es5()),
+        ("var a = `b`;", es5()),
+        ("for (var a of b) {}", es5()),
+        ("var { a } = b; var [c] = d; function f(e = 1, ...g) {}", es5()),
+        ("var a = { b, c() {}, [d]: 1 }; f(...e);", es5()),
+        ("function F() { new.target; }", es5()),
+        ("var a = /./u;", es5()),
+        ("a ** b;", es5()),
+        ("async function f() {}", es5()),
+        ("a ??= b; a ||= c; a &&= d;", es2019()),
+        ("a ?? b;", es2019()),
+        ("a?.b;", es2019()),
+        ("var a = 1n;", es2019()),
+        ("import('a');", es2019()),
+        ("export * as ns from 'a';", es2019()),
+        ("var a = 1_000;", es2019()),
+        ("class A { a = 1; #b() {} static {} c() { return #b in this; } }", es2019()),
+        ("await a;", es2019()),
+        ("var a = import.meta;", es2019()),
+        ("var a = /./d;", es2019()),
+        ("var a = /[a&&b]/v;", Some(serde_json::json!([{ "target": "es2023" }]))),
+    ];
+
+    Tester::new(NoUnsupportedBrowserFeatures::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingIdentifier, BindingPatternKind, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -62,6 +62,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for OnlyUsedInRecursion {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Function])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::Function(function) = node.kind() else { return };
 
@@ -0,0 +1,237 @@
+use oxc_ast::{
+    ast::{CallExpression, Expression, MemberExpression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_method_name, is_promise},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-promise(catch-or-return): Expected {0}() or return")]
+#[diagnostic(severity(warning), help("Return the promise or end the chain with {0}()."))]
+struct CatchOrReturnDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct CatchOrReturn(Box<CatchOrReturnConfig>);
+
+#[derive(Debug, Clone)]
+pub struct CatchOrReturnConfig {
+    /// Allow `promise.then(onFulfilled, onRejected)` to end the chain
+    allow_then: bool,
+    /// Allow `promise.catch(fn).finally(fn)` to end the chain
+    allow_finally: bool,
+    /// The methods which end a chain, `catch` by default
+    termination_method: Vec<String>,
+}
+
+impl Default for CatchOrReturnConfig {
+    fn default() -> Self {
+        Self {
+            allow_then: false,
+            allow_finally: false,
+            termination_method: vec![String::from("catch")],
+        }
+    }
+}
+
+impl std::ops::Deref for CatchOrReturn {
+    type Target = CatchOrReturnConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Ensure that each time a `then()` is applied to a promise, a `catch()` is applied
+    /// as well, unless the promise is returned.
+    ///
+    /// ### Why is this bad?
+    /// A promise which is neither returned nor handled swallows its rejections,
+    /// which surface as unhandled rejections at best.
+    ///
+    /// ### Options
+    /// * `allowThen`: allow `then()` with two arguments to end the chain.
+    /// * `allowFinally`: allow `finally()` after a terminated chain.
+    /// * `terminationMethod`: a method name or a list of method names which end the
+    ///   chain, `catch` by default.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// myPromise.then(doSomething)
+    /// myPromise.then(doSomething, catchErrors) // `catch()` may be a little better
+    /// function doSomethingElse() {
+    ///   return myPromise.then(doSomething)
+    /// }
+    /// ```
+    CatchOrReturn,
+    restriction
+);
+
+impl Rule for CatchOrReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let get_bool = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        let termination_method = match config.and_then(|config| config.get("terminationMethod")) {
+            Some(serde_json::Value::String(method)) => vec![method.clone()],
+            Some(serde_json::Value::Array(methods)) => {
+                methods.iter().filter_map(serde_json::Value::as_str).map(String::from).collect()
+            }
+            _ => CatchOrReturnConfig::default().termination_method,
+        };
+        Self(Box::new(CatchOrReturnConfig {
+            allow_then: get_bool("allowThen"),
+            allow_finally: get_bool("allowFinally"),
+            termination_method,
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ExpressionStatement])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        let expr = stmt.expression.without_parenthesized();
+        if !is_promise(expr) {
+            return;
+        }
+        // The expression body of an arrow function is returned
+        let in_arrow_body = ctx.nodes().ancestors(node.id()).nth(2).is_some_and(|id| {
+            matches!(ctx.nodes().kind(id), AstKind::ArrowFunctionExpression(arrow) if arrow.expression)
+        });
+        if in_arrow_body {
+            return;
+        }
+        let Expression::CallExpression(call) = expr else { return };
+        if self.is_allowed_termination(call) {
+            return;
+        }
+        // `promise.catch(fn).finally(fn)`
+        if self.allow_finally && get_method_name(call) == Some("finally") {
+            let object = call.callee.get_member_expr().map(MemberExpression::object);
+            if let Some(object @ Expression::CallExpression(object_call)) = object {
+                if is_promise(object) && self.is_allowed_termination(object_call) {
+                    return;
+                }
+            }
+        }
+        ctx.diagnostic(CatchOrReturnDiagnostic(self.termination_method.join("() or "), stmt.span));
+    }
+}
+
+impl CatchOrReturn {
+    fn is_allowed_termination(&self, call: &CallExpression) -> bool {
+        let Some(name) = get_method_name(call) else { return false };
+        // `promise.then(onFulfilled, onRejected)`
+        if self.allow_then && name == "then" && call.arguments.len() == 2 {
+            return true;
+        }
+        // Cypress commands are not promises even though they have a `then()`
+        if is_member_call_with_object_name(call, "cy") {
+            return true;
+        }
+        self.termination_method.iter().any(|method| method == name)
+    }
+}
+
+/// Whether `call` is a chain of method calls on `name`, e.g. `cy.get('a').then()`
+fn is_member_call_with_object_name(call: &CallExpression, name: &str) -> bool {
+    let Some(member) = call.callee.get_member_expr() else { return false };
+    match member.object() {
+        Expression::Identifier(ident) => ident.name == name,
+        Expression::CallExpression(call) => is_member_call_with_object_name(call, name),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("frank().then(go).catch(doIt)", None),
+        ("frank().then(go).then().then().then().catch(doIt)", None),
+        ("frank().then(go).then().catch(function() { /* why bother */ })", None),
+        ("frank.then(go).then(to).catch(jail)", None),
+        ("Promise.resolve(frank).catch(jail)", None),
+        ("Promise.resolve(frank)[\"catch\"](jail)", None),
+        ("frank.then(to).finally(fn).catch(jail)", None),
+        (
+            "postJSON(\"/smajobber/api/reportJob.json\")\n\t.then(()=>this.setState())\n\t.catch(()=>this.setState())",
+            None,
+        ),
+        ("function a() { return frank().then(go) }", None),
+        ("function a() { return frank().then(go).then().then().then() }", None),
+        ("function a() { return frank().then(go).then().then().then().catch(function() { /* why bother */ }) }", None),
+        ("switch (a) { case 1: return frank().then(go) }", None),
+        ("const a = () => frank().then(go)", None),
+        ("nonPromiseExpressionStatement();", None),
+        ("frank().then(go)[\"catch\"]", None),
+        ("cy.get(\".myClass\").then(go)", None),
+        ("cy.get(\"button\").click().then()", None),
+        ("frank().then(a, b)", Some(json!([{ "allowThen": true }]))),
+        ("frank().then(a).then(b).then(null, c)", Some(json!([{ "allowThen": true }]))),
+        ("frank().then(a).then(b).then(c, d)", Some(json!([{ "allowThen": true }]))),
+        ("frank().then(a).then(b).then().then().then(null, doIt)", Some(json!([{ "allowThen": true }]))),
+        ("frank().then(go).catch(doIt).finally(fn)", Some(json!([{ "allowFinally": true }]))),
+        (
+            "frank().then(go).then().then().then().catch(doIt).finally(fn)",
+            Some(json!([{ "allowFinally": true }])),
+        ),
+        (
+            "frank().then(go).then().catch(function() { /* why bother */ }).finally(fn)",
+            Some(json!([{ "allowFinally": true }])),
+        ),
+        ("frank().then(go).done()", Some(json!([{ "terminationMethod": "done" }]))),
+        ("frank().then(go).catch()", Some(json!([{ "terminationMethod": ["catch", "done"] }]))),
+        ("frank().then(go).done()", Some(json!([{ "terminationMethod": ["catch", "done"] }]))),
+        ("frank().then(go).finally()", Some(json!([{ "terminationMethod": ["finally"] }]))),
+    ];
+
+    let fail = vec![
+        ("function callPromise(promise, cb) { promise.then(cb) }", None),
+        ("fetch(\"http://www.yahoo.com\").then(console.log.bind(console))", None),
+        ("a.then(function() { return \"x\"; }).then(function(y) { throw y; })", None),
+        ("Promise.resolve(frank)", None),
+        ("Promise.all([])", None),
+        ("Promise.allSettled([])", None),
+        ("Promise.any([])", None),
+        ("Promise.race([])", None),
+        ("frank().then(to).catch(fn).then(foo)", None),
+        ("frank().finally(fn)", None),
+        ("frank().then(to).finally(fn)", None),
+        ("frank().then(go).catch(doIt).finally(fn)", None),
+        ("(frank().then(go))", None),
+        ("function a() { frank().then(go) }", None),
+        ("function a() { frank().then(go).then().then().then() }", None),
+        ("function a() { frank().then(go).then().catch(function() { /* why bother */ }).then().finally(fn) }", None),
+        ("frank().catch(go).someOtherMethod()", None),
+        ("frank().then(a, b)", None),
+        ("frank().then(go).then(zam, doIt)", None),
+        ("frank().then(a).then(b).then(null, c)", None),
+        ("frank().then(to).finally(fn)", Some(json!([{ "allowFinally": true }]))),
+        ("frank().then(go)", Some(json!([{ "terminationMethod": "done" }]))),
+        ("frank().catch(go)", Some(json!([{ "terminationMethod": "done" }]))),
+        ("frank().catch(go)", Some(json!([{ "terminationMethod": ["done", "finally"] }]))),
+    ];
+
+    Tester::new(CatchOrReturn::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
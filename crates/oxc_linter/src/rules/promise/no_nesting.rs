@@ -0,0 +1,165 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::has_promise_callback, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-promise(no-nesting): Avoid nesting promises.")]
+#[diagnostic(severity(warning), help("Return the promise and chain the next `.then()` instead."))]
+struct NoNestingDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNesting;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow nested `then()` or `catch()` statements.
+    ///
+    /// ### Why is this bad?
+    /// Nesting promises brings back the callback pyramid promises are meant to avoid.
+    /// A nested chain is allowed when its callbacks use the parameters or variables
+    /// of the enclosing callback, as flattening it would lose them.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// doThing().then(function () {
+    ///   return a.then()
+    /// })
+    /// ```
+    NoNesting,
+    style
+);
+
+impl Rule for NoNesting {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        if !has_promise_callback(call) {
+            return;
+        }
+        let Some(callback) =
+            ctx.nodes().ancestors(node.id()).skip(1).find(|id| is_promise_callback(*id, ctx))
+        else {
+            return;
+        };
+        // Arguments which refer to the variables of the enclosing callback can not be
+        // moved out of it
+        let scope_id = ctx.nodes().get_node(callback).scope_id();
+        let refers_to_callback = ctx.scopes().get_bindings(scope_id).values().any(|symbol_id| {
+            ctx.semantic().symbol_references(*symbol_id).any(|reference| {
+                let span = reference.span();
+                call.arguments.iter().any(|arg| {
+                    let arg_span = arg.span();
+                    arg_span.start <= span.start && span.end <= arg_span.end
+                })
+            })
+        });
+        if refers_to_callback {
+            return;
+        }
+        let Some((span, _)) =
+            call.callee.get_member_expr().and_then(|member| member.static_property_info())
+        else {
+            return;
+        };
+        ctx.diagnostic(NoNestingDiagnostic(span));
+    }
+}
+
+/// Whether `id` is a function passed to a `.then()` or `.catch()` call
+fn is_promise_callback(id: AstNodeId, ctx: &LintContext) -> bool {
+    if !matches!(ctx.nodes().kind(id), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+        return false;
+    }
+    let Some(parent) = ctx.nodes().parent_node(id) else { return false };
+    if !matches!(parent.kind(), AstKind::Argument(_)) {
+        return false;
+    }
+    matches!(
+        ctx.nodes().parent_kind(parent.id()),
+        Some(AstKind::CallExpression(call)) if has_promise_callback(call)
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Promise.resolve(4).then(function(x) { return x })",
+        "Promise.reject(4).then(function(x) { return x })",
+        "Promise.resolve(4).then(function() {})",
+        "Promise.reject(4).then(function() {})",
+        "doThing().then(function() { return 4 })",
+        "doThing().then(function() { throw 4 })",
+        "doThing().then(null, function() { return 4 })",
+        "doThing().then(null, function() { throw 4 })",
+        "doThing().catch(null, function() { return 4 })",
+        "doThing().catch(null, function() { throw 4 })",
+        "doThing().then(() => 4)",
+        "doThing().then(() => { throw 4 })",
+        "doThing().then(()=>{}, () => 4)",
+        "doThing().then(()=>{}, () => { throw 4 })",
+        "doThing().catch(() => 4)",
+        "doThing().catch(() => { throw 4 })",
+        "var x = function() { return Promise.resolve(4) }",
+        "function y() { return Promise.resolve(4) }",
+        "function then() { return Promise.reject() }",
+        "doThing(function(x) { return Promise.reject(x) })",
+        "doThing().then(function() { return Promise.all([a,b,c]) })",
+        "doThing().then(function() { return Promise.resolve(4) })",
+        "doThing().then(() => Promise.resolve(4))",
+        "doThing().then(() => Promise.all([a]))",
+        "doThing(function() { a.then() })",
+        "
+            doThing()
+              .then(a => getB(a)
+                .then(b => getC(a, b))
+              )
+        ",
+        "
+            doThing()
+              .then(a => {
+                const c = a * 2;
+                return getB(c).then(b => getC(c, b))
+              })
+        ",
+    ];
+
+    let fail = vec![
+        "doThing().then(function() { a.then() })",
+        "doThing().then(function() { b.catch() })",
+        "doThing().then(function() { return a.then() })",
+        "doThing().then(function() { return b.catch() })",
+        "doThing().then(() => { a.then() })",
+        "doThing().then(() => { b.catch() })",
+        "doThing().then(() => a.then())",
+        "doThing().then(() => b.catch())",
+        "doThing().catch(() => { function f() { a.then() } })",
+        "
+            doThing()
+              .then(a => getB(a)
+                .then(b => getC(b))
+              )
+        ",
+        "
+            doThing()
+              .then(a => getB(a)
+                .then(b => getC(a, b)
+                  .then(c => getD(a, c))
+                )
+              )
+        ",
+    ];
+
+    Tester::new(NoNesting::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
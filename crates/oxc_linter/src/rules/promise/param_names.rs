@@ -0,0 +1,155 @@
+use oxc_ast::{
+    ast::{Argument, BindingPatternKind, Expression, FormalParameter},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "eslint-plugin-promise(param-names): Promise constructor parameters must be named to match \"{0}\""
+)]
+#[diagnostic(severity(warning))]
+struct ParamNamesDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct ParamNames(Box<ParamNamesConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ParamNamesConfig {
+    resolve_pattern: Regex,
+    reject_pattern: Regex,
+}
+
+impl Default for ParamNamesConfig {
+    fn default() -> Self {
+        Self {
+            resolve_pattern: Regex::new(r"^_?resolve$").unwrap(),
+            reject_pattern: Regex::new(r"^_?reject$").unwrap(),
+        }
+    }
+}
+
+impl std::ops::Deref for ParamNames {
+    type Target = ParamNamesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce standard parameter names for Promise constructors.
+    ///
+    /// ### Why is this bad?
+    /// Consistent names for the `resolve` and `reject` callbacks make it clear which one
+    /// is which, and mixing them up is a common source of bugs.
+    ///
+    /// ### Options
+    /// * `resolvePattern`: the pattern of the first parameter, `^_?resolve$` by default.
+    /// * `rejectPattern`: the pattern of the second parameter, `^_?reject$` by default.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// new Promise(function (reject, resolve) { /* ... */ }) // incorrect order
+    /// new Promise(function (ok, fail) { /* ... */ }) // non-standard parameter names
+    /// ```
+    ParamNames,
+    style
+);
+
+impl Rule for ParamNames {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let get_pattern = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_str)
+                .and_then(|pattern| Regex::new(pattern).ok())
+        };
+        let default = ParamNamesConfig::default();
+        Self(Box::new(ParamNamesConfig {
+            resolve_pattern: get_pattern("resolvePattern").unwrap_or(default.resolve_pattern),
+            reject_pattern: get_pattern("rejectPattern").unwrap_or(default.reject_pattern),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NewExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::NewExpression(new_expr) = node.kind() else { return };
+        if !new_expr.callee.is_specific_id("Promise") || new_expr.arguments.len() != 1 {
+            return;
+        }
+        let params = match &new_expr.arguments[0] {
+            Argument::Expression(Expression::FunctionExpression(func)) => &func.params,
+            Argument::Expression(Expression::ArrowFunctionExpression(func)) => &func.params,
+            _ => return,
+        };
+        let mut items = params.items.iter();
+        if let Some(param) = items.next() {
+            check_param(param, &self.resolve_pattern, ctx);
+        }
+        if let Some(param) = items.next() {
+            check_param(param, &self.reject_pattern, ctx);
+        }
+    }
+}
+
+fn check_param(param: &FormalParameter, pattern: &Regex, ctx: &LintContext) {
+    let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else { return };
+    if !pattern.is_match(&ident.name) {
+        ctx.diagnostic(ParamNamesDiagnostic(pattern.to_string(), ident.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("new Promise(function(resolve, reject) {})", None),
+        ("new Promise(function(resolve, _reject) {})", None),
+        ("new Promise(function(_resolve, reject) {})", None),
+        ("new Promise(function(_resolve, _reject) {})", None),
+        ("new Promise(function(resolve) {})", None),
+        ("new Promise(function(_resolve) {})", None),
+        ("new Promise(resolve => {})", None),
+        ("new Promise((resolve, reject) => {})", None),
+        ("new Promise(() => {})", None),
+        ("new Promise(({ resolve }, ...rest) => {})", None),
+        ("new NonPromise()", None),
+        (
+            "new Promise((yes, no) => {})",
+            Some(json!([{ "resolvePattern": "^yes$", "rejectPattern": "^no$" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("new Promise(function(reject, resolve) {})", None),
+        ("new Promise(function(resolve, rej) {})", None),
+        ("new Promise(yes => {})", None),
+        ("new Promise((yes, no) => {})", None),
+        (
+            "new Promise(function(resolve, reject) { config(); })",
+            Some(json!([{ "resolvePattern": "^yes$", "rejectPattern": "^no$" }])),
+        ),
+        (
+            "new Promise((resolve, reject) => {})",
+            Some(json!([{ "resolvePattern": "^_?yes$", "rejectPattern": "^_?no$" }])),
+        ),
+    ];
+
+    Tester::new(ParamNames::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
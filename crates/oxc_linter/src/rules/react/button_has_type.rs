@@ -3,7 +3,7 @@ use oxc_ast::{
         Argument, Expression, JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXExpression,
         JSXExpressionContainer, ObjectPropertyKind,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -67,6 +67,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for ButtonHasType {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement, AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXOpeningElement(jsx_el) => {
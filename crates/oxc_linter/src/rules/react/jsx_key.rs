@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, JSXAttributeItem, JSXAttributeName, JSXElement, JSXFragment, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -55,6 +55,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxKey {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement, AstType::JSXFragment])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
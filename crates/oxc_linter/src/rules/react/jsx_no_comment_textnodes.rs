@@ -1,5 +1,5 @@
 use lazy_static::lazy_static;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -54,6 +54,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxNoCommentTextnodes {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXText])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXText(jsx_text) = node.kind() else { return };
 
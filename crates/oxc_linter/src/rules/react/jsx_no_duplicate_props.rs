@@ -1,13 +1,13 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{Atom, CompactString, Span};
+use oxc_span::{CompactString, Span};
 use rustc_hash::FxHashMap;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
@@ -23,7 +23,10 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 struct JsxNoDuplicatePropsDiagnostic(CompactString, #[label] pub Span, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
-pub struct JsxNoDuplicateProps;
+pub struct JsxNoDuplicateProps {
+    /// Treat props which only differ in case as duplicates, e.g. `<App a A />`
+    ignore_case: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -35,6 +38,9 @@ declare_oxc_lint!(
     /// Having duplicate props in a JSX element is most likely a mistake.
     /// Creating JSX elements with duplicate props can cause unexpected behavior in your application.
     ///
+    /// ### Options
+    /// * `ignoreCase`: ignore case when comparing props, so `<App a A />` is reported.
+    ///
     /// ### Example
     /// ```javascript
     /// // Bad
@@ -51,17 +57,36 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxNoDuplicateProps {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            ignore_case: value
+                .get(0)
+                .and_then(|config| config.get("ignoreCase"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_elem) = node.kind() else { return };
 
-        let mut props: FxHashMap<Atom, Span> = FxHashMap::default();
+        let mut props: FxHashMap<CompactString, Span> = FxHashMap::default();
 
         for attr in &jsx_opening_elem.attributes {
             let JSXAttributeItem::Attribute(jsx_attr) = attr else { continue };
 
             let JSXAttributeName::Identifier(ident) = &jsx_attr.name else { continue };
 
-            if let Some(old_span) = props.insert(ident.name.clone(), ident.span) {
+            let name = if self.ignore_case {
+                CompactString::from(ident.name.to_lowercase())
+            } else {
+                ident.name.to_compact_string()
+            };
+            if let Some(old_span) = props.insert(name, ident.span) {
                 ctx.diagnostic(JsxNoDuplicatePropsDiagnostic(
                     ident.name.to_compact_string(),
                     old_span,
@@ -75,6 +100,7 @@ impl Rule for JsxNoDuplicateProps {
 #[test]
 fn test() {
     use crate::tester::Tester;
+    use serde_json::json;
 
     let pass = vec![
         ("<App />;", None),
@@ -89,6 +115,7 @@ fn test() {
         ("<App A a />;", None),
         ("<App A b a />;", None),
         (r#"<App A="a" b="b" B="B" />;"#, None),
+        ("<App a A />;", Some(json!([{ "ignoreCase": false }]))),
     ];
 
     let fail = vec![
@@ -111,6 +138,8 @@ fn test() {
         "#,
             None,
         ),
+        ("<App a A />;", Some(json!([{ "ignoreCase": true }]))),
+        ("<App onClick={a} onclick={b} />;", Some(json!([{ "ignoreCase": true }]))),
     ];
 
     Tester::new(JsxNoDuplicateProps::NAME, pass, fail).test_and_snapshot();
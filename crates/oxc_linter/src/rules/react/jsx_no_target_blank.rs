@@ -1,9 +1,9 @@
 use oxc_ast::{
     ast::{
         Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElementName,
-        JSXExpression, StringLiteral,
+        JSXExpression, JSXOpeningElement, StringLiteral,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -13,7 +13,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, GetSpan, Span};
 use std::ops::Deref;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 enum JsxNoTargetBlankDiagnostic {
@@ -58,11 +58,15 @@ impl Default for JsxNoTargetBlank {
 }
 
 impl JsxNoTargetBlank {
-    fn diagnostic(&self, span: Span, ctx: &LintContext) {
-        if self.allow_referrer {
-            ctx.diagnostic(JsxNoTargetBlankDiagnostic::TargetBlankWithoutNoopener(span));
+    fn diagnostic<'a>(&self, span: Span, fix: Option<Fix<'a>>, ctx: &LintContext<'a>) {
+        let diagnostic = if self.allow_referrer {
+            JsxNoTargetBlankDiagnostic::TargetBlankWithoutNoopener(span)
         } else {
-            ctx.diagnostic(JsxNoTargetBlankDiagnostic::TargetBlankWithoutNoreferrer(span));
+            JsxNoTargetBlankDiagnostic::TargetBlankWithoutNoreferrer(span)
+        };
+        match fix {
+            Some(fix) => ctx.diagnostic_with_suggestion(diagnostic, || fix),
+            None => ctx.diagnostic(diagnostic),
         }
     }
     fn check_is_link(&self, tag_name: &str, ctx: &LintContext) -> bool {
@@ -116,6 +120,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxNoTargetBlank {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXOpeningElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_ele) = node.kind() {
             if let JSXElementName::Identifier(tag_identifier) = &jsx_ele.name {
@@ -201,13 +209,13 @@ impl Rule for JsxNoTargetBlank {
                             if (target_blank_tuple.2 && !rel_valid_tuple.2)
                                 || (target_blank_tuple.3 && !rel_valid_tuple.3)
                             {
-                                self.diagnostic(span, ctx);
+                                self.diagnostic(span, fix_rel(jsx_ele), ctx);
                             }
                             return;
                         }
 
                         if target_blank_tuple.0 && !rel_valid_tuple.0 {
-                            self.diagnostic(span, ctx);
+                            self.diagnostic(span, fix_rel(jsx_ele), ctx);
                         }
                     }
                 }
@@ -246,6 +254,60 @@ impl Rule for JsxNoTargetBlank {
     }
 }
 
+/// Adds `noreferrer` to the `rel` attribute, when it is missing or a static string
+fn fix_rel<'a>(jsx_ele: &JSXOpeningElement<'a>) -> Option<Fix<'a>> {
+    let attribute_index = |name: &str| {
+        jsx_ele.attributes.iter().position(|attribute| {
+            matches!(
+                attribute,
+                JSXAttributeItem::Attribute(attribute)
+                    if matches!(&attribute.name, JSXAttributeName::Identifier(ident) if ident.name == name)
+            )
+        })
+    };
+    let last_spread_index = jsx_ele
+        .attributes
+        .iter()
+        .rposition(|attribute| matches!(attribute, JSXAttributeItem::SpreadAttribute(_)));
+    let rel_index = attribute_index("rel");
+    // A spread attribute may override the fixed attributes
+    if let Some(spread_index) = last_spread_index {
+        if rel_index.is_none() || attribute_index("target").is_some_and(|i| i < spread_index) {
+            return None;
+        }
+    }
+    let Some(rel_index) = rel_index else {
+        let last = jsx_ele.attributes.last()?.span();
+        return Some(Fix::new(" rel=\"noreferrer\"", Span::new(last.end, last.end)));
+    };
+    let JSXAttributeItem::Attribute(rel) = &jsx_ele.attributes[rel_index] else { return None };
+    let value = match &rel.value {
+        None => return Some(Fix::new("=\"noreferrer\"", Span::new(rel.span.end, rel.span.end))),
+        Some(JSXAttributeValue::StringLiteral(lit)) => lit,
+        Some(JSXAttributeValue::ExpressionContainer(container)) => match &container.expression {
+            JSXExpression::Expression(Expression::StringLiteral(lit)) => lit,
+            // `rel={0}`, `rel={null}`
+            JSXExpression::Expression(
+                Expression::BooleanLiteral(_)
+                | Expression::NullLiteral(_)
+                | Expression::NumericLiteral(_)
+                | Expression::BigintLiteral(_),
+            ) => return Some(Fix::new("\"noreferrer\"", container.span)),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    // `noopenernoreferrer` -> `noopener noreferrer`
+    let mut parts = value
+        .value
+        .split("noreferrer")
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>();
+    parts.push("noreferrer");
+    Some(Fix::new(format!("\"{}\"", parts.join(" ")), value.span))
+}
+
 fn check_is_external_link(link: &Atom) -> bool {
     link.as_str().contains("//")
 }
@@ -789,5 +851,63 @@ fn test() {
         ),
     ];
 
-    Tester::new(JsxNoTargetBlank::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (
+            r#"<a target="_blank" href="https://example.com/1"></a>"#,
+            r#"<a target="_blank" href="https://example.com/1" rel="noreferrer"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel="" href="https://example.com/2"></a>"#,
+            r#"<a target="_blank" rel="noreferrer" href="https://example.com/2"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel={0} href="https://example.com/3"></a>"#,
+            r#"<a target="_blank" rel="noreferrer" href="https://example.com/3"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel={null} href="https://example.com/5"></a>"#,
+            r#"<a target="_blank" rel="noreferrer" href="https://example.com/5"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel="noopenernoreferrer" href="https://example.com/6"></a>"#,
+            r#"<a target="_blank" rel="noopener noreferrer" href="https://example.com/6"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel="no referrer" href="https://example.com/7"></a>"#,
+            r#"<a target="_blank" rel="no referrer noreferrer" href="https://example.com/7"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" href="//example.com/14" rel={"noopenernoreferrer"}></a>"#,
+            r#"<a target="_blank" href="//example.com/14" rel={"noopener noreferrer"}></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" href="//example.com/17" rel></a>"#,
+            r#"<a target="_blank" href="//example.com/17" rel="noreferrer"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" href="//example.com/13" rel={getRel()}></a>"#,
+            r#"<a target="_blank" href="//example.com/13" rel={getRel()}></a>"#,
+            None,
+        ),
+        (
+            r#"<a href="https://example.com/20" target="_blank"></a>"#,
+            r#"<a href="https://example.com/20" target="_blank" rel="noreferrer"></a>"#,
+            Some(serde_json::json!([{ "allowReferrer": true }])),
+        ),
+        (
+            r#"<a target="_blank" {...spread} href="//example.com"></a>"#,
+            r#"<a target="_blank" {...spread} href="//example.com"></a>"#,
+            None,
+        ),
+    ];
+
+    Tester::new(JsxNoTargetBlank::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
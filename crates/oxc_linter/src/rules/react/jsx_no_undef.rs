@@ -20,7 +20,10 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 struct JsxNoUndefDiagnostic(CompactString, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
-pub struct JsxNoUndef;
+pub struct JsxNoUndef {
+    /// Allow globals of the enabled environments, e.g. `<Text />` in the browser
+    allow_globals: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -29,6 +32,9 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// It is most likely a potential ReferenceError caused by a misspelling of a variable or parameter name.
     ///
+    /// ### Options
+    /// * `allowGlobals`: allow components which are globals of the enabled environments.
+    ///
     /// ### Example
     /// ```jsx
     /// const A = () => <App />
@@ -57,6 +63,16 @@ fn get_resolvable_ident<'a>(node: &'a JSXElementName<'a>) -> Option<&'a JSXIdent
 }
 
 impl Rule for JsxNoUndef {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_globals: value
+                .get(0)
+                .and_then(|config| config.get("allowGlobals"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(JSXOpeningElement { name: el_name, .. }) = &node.kind() {
             if let Some(ident) = get_resolvable_ident(el_name) {
@@ -69,6 +85,9 @@ impl Rule for JsxNoUndef {
                         return;
                     }
                 }
+                if self.allow_globals && ctx.env_contains_var(&ident.name) {
+                    return;
+                }
                 ctx.diagnostic(JsxNoUndefDiagnostic(ident.name.to_compact_string(), ident.span));
             }
         }
@@ -78,6 +97,7 @@ impl Rule for JsxNoUndef {
 #[test]
 fn test() {
     use crate::tester::Tester;
+    use serde_json::json;
 
     let pass = vec![
         ("var React, App; React.render(<App />);", None),
@@ -111,6 +131,7 @@ fn test() {
         ),
         ("var App; var React; enum A { App };  React.render(<App />);", None),
         ("var React; enum A { App }; var App; React.render(<App />);", None),
+        ("var React; React.render(<Object />);", Some(json!([{ "allowGlobals": true }]))),
     ];
 
     let fail = vec![
@@ -122,6 +143,8 @@ fn test() {
         ("var React; Unknown; React.render(<Unknown />)", None),
         ("var React; { const App = null; }; React.render(<App />);", None),
         ("var React; enum A { App }; React.render(<App />);", None),
+        ("var React; React.render(<Object />);", None),
+        ("var React; React.render(<Object />);", Some(json!([{ "allowGlobals": false }]))),
     ];
 
     Tester::new(JsxNoUndef::NAME, pass, fail).test_and_snapshot();
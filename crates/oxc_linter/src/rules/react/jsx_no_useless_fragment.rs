@@ -3,7 +3,7 @@ use oxc_ast::{
         Expression, JSXAttributeItem, JSXAttributeName, JSXChild, JSXElement, JSXElementName,
         JSXExpression, JSXFragment, JSXMemberExpressionObject, JSXOpeningElement,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -65,6 +65,10 @@ impl Rule for JsxNoUselessFragment {
         }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement, AstType::JSXFragment])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
@@ -134,7 +138,7 @@ fn is_fragment_with_single_expression(children: &oxc_allocator::Vec<'_, JSXChild
 
 fn is_padding_spaces(v: &JSXChild<'_>) -> bool {
     if let JSXChild::Text(v) = v {
-        return !(v.value.trim().is_empty() && v.value.contains('\n'));
+        return !v.is_insignificant();
     }
 
     true
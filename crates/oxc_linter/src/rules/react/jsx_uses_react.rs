@@ -0,0 +1,87 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_macros::declare_oxc_lint;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxUsesReact;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Marks `React` as used when there is JSX in its scope, for `no-unused-vars`.
+    /// The name is the `pragma` of the react settings, `React` by default.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// With the classic JSX transform, `<a />` expands to `React.createElement("a")`, so an
+    /// import of `React` which is only used by JSX is not unused. This rule reports nothing.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // `React` is not reported by `no-unused-vars`
+    /// import React from "react";
+    /// var a = <a />;
+    /// ```
+    JsxUsesReact,
+    correctness
+);
+
+impl Rule for JsxUsesReact {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let scopes = ctx.scopes();
+        let pragma = ctx.settings().react.pragma();
+        for node in ctx.nodes().iter() {
+            if !matches!(node.kind(), AstKind::JSXOpeningElement(_) | AstKind::JSXFragment(_)) {
+                continue;
+            }
+            let symbol_id = scopes
+                .ancestors(node.scope_id())
+                .find_map(|scope_id| scopes.get_binding(scope_id, pragma));
+            if let Some(symbol_id) = symbol_id {
+                ctx.mark_used(symbol_id);
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::Path;
+
+    use crate::{Linter, RULES};
+
+    let rules = ["jsx-uses-react", "no-unused-vars"]
+        .map(|name| RULES.iter().find(|rule| rule.name() == name).unwrap().clone());
+    let run = |source_text: &str, settings: Option<serde_json::Value>| {
+        let mut builder = Linter::builder().with_rules(rules.to_vec());
+        if let Some(settings) = settings {
+            builder = builder.with_config(serde_json::json!({ "settings": settings }));
+        }
+        let linter = builder.build().unwrap();
+        linter
+            .lint_source(Path::new("test.jsx"), source_text)
+            .into_iter()
+            .map(|message| message.error.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert!(run("import React from 'react'; export const a = <a />;", None).is_empty());
+    assert!(run("import React from 'react'; export const a = <></>;", None).is_empty());
+    assert!(run("export function f(React) { return <a />; }", None).is_empty());
+    let settings = serde_json::json!({ "react": { "pragma": "h" } });
+    assert!(run("import { h } from 'preact'; export const a = <a />;", Some(settings)).is_empty());
+
+    assert_eq!(
+        run("import React from 'react'; export const a = 1;", None),
+        ["eslint(no-unused-vars): 'React' is defined but never used."]
+    );
+    assert_eq!(
+        run("export const a = <a />; function f(React) {} f();", None),
+        ["eslint(no-unused-vars): 'React' is defined but never used."]
+    );
+}
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression, JSXAttributeItem, JSXAttributeName, ObjectPropertyKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -60,6 +60,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoChildrenProp {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXAttributeItem, AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) => {
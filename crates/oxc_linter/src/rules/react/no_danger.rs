@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression, JSXAttributeItem, ObjectPropertyKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -42,6 +42,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDanger {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement, AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
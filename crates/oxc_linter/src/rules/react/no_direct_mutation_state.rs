@@ -3,7 +3,7 @@ use oxc_ast::{
         AssignmentTarget, Expression, MemberExpression, MethodDefinitionKind,
         SimpleAssignmentTarget, StaticMemberExpression,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -170,6 +170,10 @@ fn should_ignore_component<'a, 'b>(node: &'b AstNode<'a>, ctx: &'b LintContext<'
 }
 
 impl Rule for NoDirectMutationState {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::AssignmentExpression, AstType::UpdateExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::AssignmentExpression(assignment_expr) => {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{CallExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -47,6 +47,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoIsMounted {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(CallExpression {
             callee: Expression::MemberExpression(member_expr),
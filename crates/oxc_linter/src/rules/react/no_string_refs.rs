@@ -3,7 +3,7 @@ use oxc_ast::{
         Expression, JSXAttribute, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
         JSXExpression, JSXExpressionContainer,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -110,6 +110,10 @@ impl Rule for NoStringRefs {
 
         Self { no_template_literals }
     }
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXAttributeItem, AstType::MemberExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) => {
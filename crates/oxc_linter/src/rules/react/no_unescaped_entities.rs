@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -47,6 +47,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnescapedEntities {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXText])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXText(jsx_text) = node.kind() {
             let source = jsx_text.span.source_text(ctx.source_text());
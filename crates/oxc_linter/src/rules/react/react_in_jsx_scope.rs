@@ -1,7 +1,7 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
-    thiserror::Error,
+    thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, GetSpan, Span};
@@ -9,9 +9,9 @@ use oxc_span::{Atom, GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint-plugin-react(react-in-jsx-scope): 'React' must be in scope when using JSX")]
+#[error("eslint-plugin-react(react-in-jsx-scope): '{1}' must be in scope when using JSX")]
 #[diagnostic(severity(warning), help("When using JSX, `<a />` expands to `React.createElement(\"a\")`. Therefore the `React` variable must be in scope."))]
-struct ReactInJsxScopeDiagnostic(#[label] pub Span);
+struct ReactInJsxScopeDiagnostic(#[label] pub Span, String);
 
 #[derive(Debug, Default, Clone)]
 pub struct ReactInJsxScope;
@@ -47,7 +47,7 @@ impl Rule for ReactInJsxScope {
             _ => return,
         };
         let scope = ctx.scopes();
-        let react_name: &Atom = &Atom::from("React");
+        let react_name: &Atom = &Atom::from(ctx.settings().react.pragma());
         if scope.get_binding(scope.root_scope_id(), react_name).is_some() {
             return;
         }
@@ -56,7 +56,7 @@ impl Rule for ReactInJsxScope {
             .ancestors(node.scope_id())
             .any(|v| scope.get_bindings(v).iter().any(|(k, _)| k == react_name))
         {
-            ctx.diagnostic(ReactInJsxScopeDiagnostic(node_span));
+            ctx.diagnostic(ReactInJsxScopeDiagnostic(node_span, react_name.to_string()));
         }
     }
 }
@@ -65,14 +65,16 @@ impl Rule for ReactInJsxScope {
 fn test() {
     use crate::tester::Tester;
 
+    let settings = || serde_json::json!({ "react": { "pragma": "h" } });
+
     let pass = vec![
-        ("var React, App; <App />;", None),
-        ("var React; <img />;", None),
-        ("var React; <>fragment</>;", None),
-        ("var React; <x-gif />;", None),
-        ("var React, App, a=1; <App attr={a} />;", None),
-        ("var React, App, a=1; function elem() { return <App attr={a} />; }", None),
-        ("var React, App; <App />;", None),
+        ("var React, App; <App />;", None, None),
+        ("var React; <img />;", None, None),
+        ("var React; <>fragment</>;", None, None),
+        ("var React; <x-gif />;", None, None),
+        ("var React, App, a=1; <App attr={a} />;", None, None),
+        ("var React, App, a=1; function elem() { return <App attr={a} />; }", None, None),
+        ("var React, App; <App />;", None, None),
         (
             "
 			        import React from 'react/addons';
@@ -86,16 +88,19 @@ fn test() {
 			        export default Button;
 			      ",
             None,
+            None,
         ),
-        ("var React, a = <img />;", None),
+        ("var React, a = <img />;", None, None),
+        ("var h; <img />;", None, Some(settings())),
     ];
 
     let fail = vec![
-        ("var App, a = <App />;", None),
-        ("var a = <App />;", None),
-        ("var a = <img />;", None),
-        ("var a = <>fragment</>;", None),
-        ("var Foo, a = <img />;", None),
+        ("var App, a = <App />;", None, None),
+        ("var a = <App />;", None, None),
+        ("var a = <img />;", None, None),
+        ("var a = <>fragment</>;", None, None),
+        ("var Foo, a = <img />;", None, None),
+        ("var React; <img />;", None, Some(settings())),
     ];
 
     Tester::new(ReactInJsxScope::NAME, pass, fail).test_and_snapshot();
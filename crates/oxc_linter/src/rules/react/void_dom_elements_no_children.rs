@@ -3,7 +3,7 @@ use oxc_ast::{
         Argument, Expression, JSXAttributeItem, JSXAttributeName, JSXElementName,
         ObjectPropertyKind, PropertyKey,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -60,6 +60,10 @@ pub fn is_void_dom_element(element_name: &str) -> bool {
 }
 
 impl Rule for VoidDomElementsNoChildren {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement, AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_el) => {
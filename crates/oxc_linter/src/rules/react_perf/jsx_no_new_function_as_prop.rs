@@ -3,7 +3,7 @@ use oxc_ast::{
         Expression, JSXAttributeValue, JSXElement, JSXExpression, JSXExpressionContainer,
         MemberExpression,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -45,6 +45,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxNoNewFunctionAsProp {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_elem) = node.kind() {
             check_jsx_element(jsx_elem, ctx);
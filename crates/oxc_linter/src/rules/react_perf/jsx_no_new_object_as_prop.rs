@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, JSXAttributeValue, JSXElement, JSXExpression, JSXExpressionContainer},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -46,6 +46,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxNoNewObjectAsProp {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::JSXElement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_elem) = node.kind() {
             check_jsx_element(jsx_elem, ctx);
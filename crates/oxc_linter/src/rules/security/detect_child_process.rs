@@ -0,0 +1,194 @@
+use oxc_ast::{
+    ast::{Argument, BindingPatternKind, CallExpression, Expression, IdentifierReference},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{
+    ast_util::is_global_require_call, context::LintContext, rule::Rule,
+    utils::is_static_string_argument, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "eslint-plugin-security(detect-child-process): `child_process.{0}()` called with a non-literal command"
+)]
+#[diagnostic(
+    severity(warning),
+    help("Use `execFile()` or `spawn()` with an argument list so the input is not interpreted by a shell.")
+)]
+struct DetectChildProcessDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectChildProcess;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Detects `exec()` and `execSync()` of `child_process` called with a command which
+    /// is not a string literal.
+    ///
+    /// ### Why is this bad?
+    /// The command is run by a shell, so input concatenated into it can inject
+    /// arbitrary commands.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const { exec } = require('child_process');
+    /// exec('ls ' + userInput);
+    /// ```
+    DetectChildProcess,
+    restriction
+);
+
+const CHILD_PROCESS_MODULES: [&str; 2] = ["child_process", "node:child_process"];
+
+/// The methods which run their command in a shell
+const SHELL_METHODS: [&str; 2] = ["exec", "execSync"];
+
+/// How a binding refers to `child_process`
+enum Binding<'a> {
+    /// `import * as cp from 'child_process'` or `const cp = require('child_process')`
+    Module,
+    /// `import { exec } from 'child_process'` or `const { exec } = require('child_process')`
+    Method(Atom<'a>),
+}
+
+impl Rule for DetectChildProcess {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let method = match &call.callee {
+            Expression::Identifier(ident) => match child_process_binding(ident, ctx) {
+                Some(Binding::Method(method)) => shell_method(&method),
+                _ => None,
+            },
+            Expression::MemberExpression(member) => {
+                let is_module = match member.object() {
+                    Expression::Identifier(ident) => {
+                        matches!(child_process_binding(ident, ctx), Some(Binding::Module))
+                    }
+                    Expression::CallExpression(call) => is_child_process_require(call, ctx),
+                    _ => false,
+                };
+                member.static_property_name().filter(|_| is_module).and_then(shell_method)
+            }
+            _ => None,
+        };
+        let Some(method) = method else { return };
+        if call.arguments.first().is_some_and(|arg| !is_static_string_argument(arg)) {
+            ctx.diagnostic(DetectChildProcessDiagnostic(method, call.span));
+        }
+    }
+}
+
+fn shell_method(name: &str) -> Option<&'static str> {
+    SHELL_METHODS.into_iter().find(|method| *method == name)
+}
+
+fn is_child_process_require(call: &CallExpression, ctx: &LintContext) -> bool {
+    is_global_require_call(call, ctx)
+        && matches!(
+            call.arguments.first(),
+            Some(Argument::Expression(Expression::StringLiteral(lit)))
+                if CHILD_PROCESS_MODULES.contains(&lit.value.as_str())
+        )
+}
+
+fn child_process_binding<'a>(
+    ident: &IdentifierReference,
+    ctx: &LintContext<'a>,
+) -> Option<Binding<'a>> {
+    let symbols = ctx.symbols();
+    let symbol_id = symbols.get_reference(ident.reference_id.get()?).symbol_id()?;
+    let declaration = symbols.get_declaration(symbol_id);
+    let is_imported = || {
+        ctx.nodes().ancestors(declaration).any(|id| {
+            matches!(
+                ctx.nodes().kind(id),
+                AstKind::ImportDeclaration(decl)
+                    if CHILD_PROCESS_MODULES.contains(&decl.source.value.as_str())
+            )
+        })
+    };
+    match ctx.nodes().kind(declaration) {
+        AstKind::ImportDefaultSpecifier(_) | AstKind::ImportNamespaceSpecifier(_)
+            if is_imported() =>
+        {
+            Some(Binding::Module)
+        }
+        AstKind::ImportSpecifier(specifier) if is_imported() => {
+            Some(Binding::Method(specifier.imported.name().clone()))
+        }
+        AstKind::VariableDeclarator(decl) => {
+            let Some(Expression::CallExpression(init)) = &decl.init else { return None };
+            if !is_child_process_require(init, ctx) {
+                return None;
+            }
+            match &decl.id.kind {
+                BindingPatternKind::BindingIdentifier(_) => Some(Binding::Module),
+                BindingPatternKind::ObjectPattern(pattern) => {
+                    pattern.properties.iter().find_map(|property| {
+                        let BindingPatternKind::BindingIdentifier(local) = &property.value.kind
+                        else {
+                            return None;
+                        };
+                        (local.name == ident.name)
+                            .then(|| property.key.static_name())
+                            .flatten()
+                            .map(Binding::Method)
+                    })
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const { exec } = require('child_process'); exec('ls')",
+        "const { exec } = require('child_process'); exec(`ls`)",
+        "const cp = require('child_process'); cp.exec('ls')",
+        "const cp = require('child_process'); cp.execFile('ls', [dir])",
+        "const cp = require('child_process'); cp.spawn('ls', [dir])",
+        "require('child_process').exec('ls')",
+        "import { exec } from 'child_process'; exec('ls')",
+        "import { execFile } from 'child_process'; execFile(cmd)",
+        "import * as cp from 'node:child_process'; cp.execSync('ls')",
+        "import { exec } from 'shelljs'; exec(cmd)",
+        "const { exec } = require('./exec'); exec(cmd)",
+        "const cp = require('child_process'); function f(cp) { cp.exec(cmd) }",
+        "exec(cmd)",
+        "db.exec(query)",
+        "/a/.exec(str)",
+    ];
+
+    let fail = vec![
+        "const { exec } = require('child_process'); exec('ls ' + dir)",
+        "const { exec } = require('child_process'); exec(`ls ${dir}`)",
+        "const { execSync: run } = require('child_process'); run(cmd)",
+        "const cp = require('child_process'); cp.exec(cmd, () => {})",
+        "const cp = require('node:child_process'); cp.execSync(cmd)",
+        "require('child_process').exec(cmd)",
+        "import { exec } from 'child_process'; exec(cmd)",
+        "import { exec as run } from 'child_process'; run(cmd)",
+        "import * as cp from 'child_process'; cp.exec(cmd)",
+        "import cp from 'child_process'; cp['exec'](cmd)",
+    ];
+
+    Tester::new(DetectChildProcess::NAME, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,111 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::is_global_reference, context::LintContext, rule::Rule,
+    utils::is_static_string_argument, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "eslint-plugin-security(detect-eval-with-expression): `{0}` called with a non-literal argument"
+)]
+#[diagnostic(
+    severity(warning),
+    help("Code built from a non-literal string may run code controlled by an attacker.")
+)]
+struct DetectEvalWithExpressionDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectEvalWithExpression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Detects `eval()` and `Function()` calls whose code is not a string literal.
+    ///
+    /// ### Why is this bad?
+    /// Evaluating code built from variables lets anyone who controls them run arbitrary
+    /// code in the application.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// eval(userInput);
+    /// new Function('a', `return a + ${userInput}`);
+    /// ```
+    DetectEvalWithExpression,
+    restriction
+);
+
+impl Rule for DetectEvalWithExpression {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression, AstType::NewExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::CallExpression(call) => (&call.callee, &call.arguments, call.span),
+            AstKind::NewExpression(new_expr) => {
+                (&new_expr.callee, &new_expr.arguments, new_expr.span)
+            }
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee else { return };
+        let name = match ident.name.as_str() {
+            // `new eval()` throws
+            "eval" if matches!(node.kind(), AstKind::CallExpression(_)) => "eval",
+            "Function" => "Function",
+            _ => return,
+        };
+        if !is_global_reference(ident, ctx) {
+            return;
+        }
+        // `eval` only runs its first argument while every argument of `Function` is code
+        let is_dynamic = |arg: &Argument| !is_static_string_argument(arg);
+        let dynamic = if name == "eval" {
+            arguments.first().is_some_and(is_dynamic)
+        } else {
+            arguments.iter().any(is_dynamic)
+        };
+        if dynamic {
+            ctx.diagnostic(DetectEvalWithExpressionDiagnostic(name, span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "eval('alert()')",
+        "eval(`alert()`)",
+        "eval()",
+        "new Function('a', 'b', 'return a + b')",
+        "Function('return this')()",
+        "function eval(x) {} eval(x)",
+        "const Function = foo; new Function(bar)",
+        "obj.eval(x)",
+    ];
+
+    let fail = vec![
+        "eval(a);",
+        "eval('alert(' + a + ')');",
+        "eval(`alert(${a})`);",
+        "eval(a, 'b');",
+        "new Function(body)",
+        "new Function('a', body)",
+        "Function(`return ${a}`)()",
+    ];
+
+    Tester::new(DetectEvalWithExpression::NAME, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
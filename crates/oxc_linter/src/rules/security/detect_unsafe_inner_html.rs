@@ -0,0 +1,156 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, SimpleAssignmentTarget},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_static_string, is_static_string_argument},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum DetectUnsafeInnerHtmlDiagnostic {
+    #[error("eslint-plugin-security(detect-unsafe-inner-html): Unsafe assignment to `{0}`")]
+    #[diagnostic(
+        severity(warning),
+        help("Sanitize the HTML first, or assign `textContent` for plain text.")
+    )]
+    Assignment(&'static str, #[label] Span),
+
+    #[error("eslint-plugin-security(detect-unsafe-inner-html): Unsafe call to `{0}()`")]
+    #[diagnostic(severity(warning), help("Sanitize the HTML first."))]
+    Call(&'static str, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectUnsafeInnerHtml;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Detects HTML which is not a string literal being written to the DOM through
+    /// `innerHTML`, `outerHTML`, `insertAdjacentHTML()` or `document.write()`.
+    ///
+    /// ### Why is this bad?
+    /// The markup is parsed as HTML, so unsanitized input can inject scripts into
+    /// the page (cross-site scripting).
+    ///
+    /// ### Example
+    /// ```javascript
+    /// element.innerHTML = '<b>' + userInput + '</b>';
+    /// element.insertAdjacentHTML('beforeend', userInput);
+    /// ```
+    DetectUnsafeInnerHtml,
+    restriction
+);
+
+const HTML_PROPERTIES: [&str; 2] = ["innerHTML", "outerHTML"];
+
+impl Rule for DetectUnsafeInnerHtml {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::AssignmentExpression, AstType::CallExpression])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::AssignmentExpression(assignment) => {
+                // Other operators can not produce a string
+                if !matches!(
+                    assignment.operator,
+                    AssignmentOperator::Assign | AssignmentOperator::Addition
+                ) && !assignment.operator.is_logical()
+                {
+                    return;
+                }
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &assignment.left
+                else {
+                    return;
+                };
+                let Some(property) = member
+                    .static_property_name()
+                    .and_then(|name| HTML_PROPERTIES.into_iter().find(|p| *p == name))
+                else {
+                    return;
+                };
+                if !is_static_string(&assignment.right) {
+                    ctx.diagnostic(DetectUnsafeInnerHtmlDiagnostic::Assignment(
+                        property,
+                        assignment.span,
+                    ));
+                }
+            }
+            AstKind::CallExpression(call) => {
+                let Some(member) = call.callee.get_member_expr() else { return };
+                let is_document = || member.object().is_specific_id("document");
+                // Every argument of `document.write()` is written
+                let any_dynamic =
+                    || call.arguments.iter().any(|arg| !is_static_string_argument(arg));
+                let (method, is_unsafe) = match member.static_property_name() {
+                    // `element.insertAdjacentHTML(position, html)`
+                    Some("insertAdjacentHTML") => (
+                        "insertAdjacentHTML",
+                        call.arguments.get(1).is_some_and(|html| !is_static_string_argument(html)),
+                    ),
+                    Some("write") if is_document() => ("document.write", any_dynamic()),
+                    Some("writeln") if is_document() => ("document.writeln", any_dynamic()),
+                    _ => return,
+                };
+                if is_unsafe {
+                    ctx.diagnostic(DetectUnsafeInnerHtmlDiagnostic::Call(method, call.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "element.innerHTML = '';",
+        "element.innerHTML = '<b>static</b>';",
+        "element.innerHTML = `<b>static</b>`;",
+        "element.outerHTML = '<div></div>';",
+        "element.innerHTML += '<br>';",
+        "element.textContent = userInput;",
+        "element.innerText = userInput;",
+        "element.innerHTML -= 1;",
+        "const html = element.innerHTML;",
+        "element['innerHTML'] = '<br>';",
+        "element.insertAdjacentHTML('beforeend', '<br>');",
+        "element.insertAdjacentText('beforeend', userInput);",
+        "document.write('<p>static</p>');",
+        "document.writeln('<p>', '</p>');",
+        "stream.write(data);",
+    ];
+
+    let fail = vec![
+        "element.innerHTML = userInput;",
+        "element.innerHTML = '<b>' + userInput + '</b>';",
+        "element.innerHTML = `<b>${userInput}</b>`;",
+        "element.outerHTML = userInput;",
+        "element.innerHTML += userInput;",
+        "element.innerHTML ||= userInput;",
+        "element['innerHTML'] = userInput;",
+        "document.body.innerHTML = userInput;",
+        "element.insertAdjacentHTML('beforeend', userInput);",
+        "document.write(userInput);",
+        "document.writeln('<p>', userInput, '</p>');",
+    ];
+
+    Tester::new(DetectUnsafeInnerHtml::NAME, pass, fail)
+        .with_security_plugin(true)
+        .test_and_snapshot();
+}
@@ -3,7 +3,7 @@ use oxc_ast::{
         ClassElement, Declaration, ExportDefaultDeclarationKind, Expression, FunctionType,
         ModuleDeclaration, PropertyKey, Statement, TSSignature,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -281,6 +281,18 @@ fn check_and_report(methods: &Vec<Option<Method>>, ctx: &LintContext<'_>) {
 }
 
 impl Rule for AdjacentOverloadSignatures {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::Class,
+            AstType::TSTypeLiteral,
+            AstType::Program,
+            AstType::TSModuleBlock,
+            AstType::TSInterfaceDeclaration,
+            AstType::BlockStatement,
+            AstType::FunctionBody,
+        ])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::Class(class) => {
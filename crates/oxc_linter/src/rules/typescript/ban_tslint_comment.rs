@@ -1,4 +1,5 @@
 use lazy_static::lazy_static;
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -35,6 +36,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for BanTslintComment {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let comments = ctx.semantic().trivias().comments();
         let source_text_len = ctx.semantic().source_text().len();
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -52,6 +52,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for BanTypes {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TSTypeReference, AstType::TSTypeLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::TSTypeReference(typ) => {
@@ -0,0 +1,249 @@
+use oxc_ast::{
+    ast::{ExportDefaultDeclarationKind, TSInterfaceDeclaration, TSModuleDeclarationKind, TSType},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ConsistentTypeDefinitionsDiagnostic {
+    #[error(
+        "typescript-eslint(consistent-type-definitions): Use an `interface` instead of a `type`"
+    )]
+    #[diagnostic(severity(warning))]
+    InterfaceOverType(#[label] Span),
+
+    #[error(
+        "typescript-eslint(consistent-type-definitions): Use a `type` instead of an `interface`"
+    )]
+    #[diagnostic(severity(warning))]
+    TypeOverInterface(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentTypeDefinitions {
+    prefer: DefinitionOption,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum DefinitionOption {
+    #[default]
+    Interface,
+    Type,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce type definitions to consistently use either `interface` or `type`.
+    ///
+    /// ### Why is this bad?
+    /// An object type can be defined with either an `interface` or a `type` alias.
+    /// Mixing both styles in a codebase makes it harder to read.
+    ///
+    /// ### Options
+    /// `"interface"` (default) or `"type"`.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // with "interface"
+    /// type T = { x: number };
+    /// // with "type"
+    /// interface T { x: number }
+    /// ```
+    ConsistentTypeDefinitions,
+    style
+);
+
+impl Rule for ConsistentTypeDefinitions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let prefer = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("type") => DefinitionOption::Type,
+            _ => DefinitionOption::Interface,
+        };
+        Self { prefer }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::TSTypeAliasDeclaration,
+            AstType::TSInterfaceDeclaration,
+            AstType::ExportDefaultDeclaration,
+        ])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let source_text = ctx.source_text();
+        match node.kind() {
+            // `type T = { x: number }` -> `interface T { x: number }`
+            AstKind::TSTypeAliasDeclaration(decl) if self.prefer == DefinitionOption::Interface => {
+                let TSType::TSTypeLiteral(literal) = &decl.type_annotation else { return };
+                let keyword_start =
+                    keyword_start(source_text, decl.span.start, decl.id.span, "type");
+                let head_end =
+                    decl.type_parameters.as_ref().map_or(decl.id.span.end, |p| p.span.end);
+                ctx.diagnostic_with_fix(
+                    ConsistentTypeDefinitionsDiagnostic::InterfaceOverType(decl.id.span),
+                    || {
+                        let text = format!(
+                            "{}interface{} {}",
+                            &source_text[decl.span.start as usize..keyword_start as usize],
+                            &source_text
+                                [(keyword_start as usize + "type".len())..head_end as usize],
+                            literal.span.source_text(source_text),
+                        );
+                        // Drops the trailing `;` of the type alias
+                        Fix::new(text, decl.span)
+                    },
+                );
+            }
+            // `interface T extends U { x: number }` -> `type T = { x: number } & U`
+            AstKind::TSInterfaceDeclaration(decl) if self.prefer == DefinitionOption::Type => {
+                check_interface(decl, None, node, ctx);
+            }
+            // `export default interface` is not visited as a node of its own
+            AstKind::ExportDefaultDeclaration(export) if self.prefer == DefinitionOption::Type => {
+                if let ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) =
+                    &export.declaration
+                {
+                    check_interface(decl, Some(export.span), node, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reports `decl`, replacing `export_default` with the type alias and a default export when set
+fn check_interface<'a>(
+    decl: &TSInterfaceDeclaration<'a>,
+    export_default: Option<Span>,
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) {
+    let source_text = ctx.source_text();
+    let diagnostic = ConsistentTypeDefinitionsDiagnostic::TypeOverInterface(decl.id.span);
+    // Interfaces in `declare global {}` augment the global types, which a type alias
+    // can not do
+    let in_declare_global = ctx.nodes().ancestors(node.id()).any(|id| {
+        matches!(
+            ctx.nodes().kind(id),
+            AstKind::TSModuleDeclaration(module)
+                if module.kind == TSModuleDeclarationKind::Global
+        )
+    });
+    if in_declare_global {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    let keyword_start = keyword_start(source_text, decl.span.start, decl.id.span, "interface");
+    let head_end = decl.type_parameters.as_ref().map_or(decl.id.span.end, |p| p.span.end);
+    ctx.diagnostic_with_fix(diagnostic, || {
+        let mut text = format!(
+            "{}type{} = {}",
+            &source_text[decl.span.start as usize..keyword_start as usize],
+            &source_text[(keyword_start as usize + "interface".len())..head_end as usize],
+            decl.body.span.source_text(source_text),
+        );
+        for heritage in decl.extends.iter().flatten() {
+            text.push_str(" & ");
+            text.push_str(heritage.span.source_text(source_text));
+        }
+        // `export default type` is not valid
+        if let Some(span) = export_default {
+            text.push_str("\nexport default ");
+            text.push_str(&decl.id.name);
+            return Fix::new(text, span);
+        }
+        Fix::new(text, Span::new(decl.span.start, decl.body.span.end))
+    });
+}
+
+/// The start of `keyword`, which is the last one before the declaration's identifier
+/// as `declare` may come first
+fn keyword_start(source_text: &str, start: u32, id_span: Span, keyword: &str) -> u32 {
+    let head = &source_text[start as usize..id_span.start as usize];
+    #[allow(clippy::cast_possible_truncation)]
+    head.rfind(keyword).map_or(start, |offset| start + offset as u32)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var foo = {};", None),
+        ("interface A {}", None),
+        ("interface A extends B { x: number; }", None),
+        ("type U = string;", None),
+        ("type V = { x: number } | { y: string };", None),
+        ("type Record<T, U> = { [K in T]: U };", None),
+        ("type T = { x: number };", Some(json!(["type"]))),
+        ("type A = { x: number } & B & C;", Some(json!(["type"]))),
+        ("type A = { x: number } & B<T1> & C<T2>;", Some(json!(["type"]))),
+        ("export type W<T> = { x: T };", Some(json!(["type"]))),
+        ("interface T { x: number; }", Some(json!(["interface"]))),
+    ];
+
+    let fail = vec![
+        ("type T = { x: number; };", None),
+        ("type T={ x: number; };", None),
+        ("type T=                         { x: number; };", None),
+        ("export type W<T> = { x: T; };", None),
+        ("declare type T = { x: number; };", None),
+        ("type T = { x: number; };", Some(json!(["interface"]))),
+        ("interface T { x: number; }", Some(json!(["type"]))),
+        ("export interface W<T> { x: T; }", Some(json!(["type"]))),
+        ("namespace Foo { interface T { x: number; } }", Some(json!(["type"]))),
+        ("interface A extends B, C { x: number; };", Some(json!(["type"]))),
+        ("export default interface Test { bar(): string; foo(): number; }", Some(json!(["type"]))),
+        (
+            "declare global { interface Array<T> { foo(x: (x: number) => T): T[]; } }",
+            Some(json!(["type"])),
+        ),
+    ];
+
+    let fix = vec![
+        ("type T = { x: number; };", "interface T { x: number; }", None),
+        ("type T={ x: number; };", "interface T { x: number; }", None),
+        ("type T=                         { x: number; };", "interface T { x: number; }", None),
+        ("export type W<T> = { x: T; };", "export interface W<T> { x: T; }", None),
+        ("declare type T = { x: number; };", "declare interface T { x: number; }", None),
+        (
+            "namespace JSX { type IntrinsicElements = { div: any; }; }",
+            "namespace JSX { interface IntrinsicElements { div: any; } }",
+            None,
+        ),
+        ("interface T { x: number; }", "type T = { x: number; }", Some(json!(["type"]))),
+        ("interface T{ x: number; }", "type T = { x: number; }", Some(json!(["type"]))),
+        ("export interface W<T> { x: T; }", "export type W<T> = { x: T; }", Some(json!(["type"]))),
+        (
+            "interface A extends B, C { x: number; };",
+            "type A = { x: number; } & B & C;",
+            Some(json!(["type"])),
+        ),
+        (
+            "interface A extends B<T1>, C<T2> { x: number; };",
+            "type A = { x: number; } & B<T1> & C<T2>;",
+            Some(json!(["type"])),
+        ),
+        (
+            "export default interface Test { bar(): string; }",
+            "type Test = { bar(): string; }\nexport default Test",
+            Some(json!(["type"])),
+        ),
+        (
+            "declare global { interface Array<T> { foo(x: (x: number) => T): T[]; } }",
+            "declare global { interface Array<T> { foo(x: (x: number) => T): T[]; } }",
+            Some(json!(["type"])),
+        ),
+    ];
+
+    Tester::new(ConsistentTypeDefinitions::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,870 @@
+use oxc_ast::{
+    ast::{
+        BindingPattern, BindingPatternKind, Expression, ModifierKind, TSEnumMemberName,
+        VariableDeclarationKind,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{CompactString, GetSpan, Span};
+use oxc_syntax::{identifier::is_identifier_name, module_record::ExportLocalName};
+use regex::Regex;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(naming-convention): {0}")]
+#[diagnostic(severity(warning))]
+struct NamingConventionDiagnostic(String, #[label] pub Span);
+
+/// The selectors of typescript-eslint, the value of each is `1 << index`.
+/// The values determine the order in which the options are tried.
+const SELECTORS: [&str; 19] = [
+    "variable",
+    "function",
+    "parameter",
+    "parameterProperty",
+    "classicAccessor",
+    "enumMember",
+    "classMethod",
+    "objectLiteralMethod",
+    "typeMethod",
+    "classProperty",
+    "objectLiteralProperty",
+    "typeProperty",
+    "autoAccessor",
+    "class",
+    "interface",
+    "typeAlias",
+    "enum",
+    "typeParameter",
+    "import",
+];
+
+/// Selectors matching a group of selectors, tried after the individual selectors
+const META_SELECTORS: [(&str, &[&str]); 6] = [
+    ("variableLike", &["variable", "function", "parameter"]),
+    (
+        "memberLike",
+        &[
+            "parameterProperty",
+            "classicAccessor",
+            "enumMember",
+            "classMethod",
+            "objectLiteralMethod",
+            "typeMethod",
+            "classProperty",
+            "objectLiteralProperty",
+            "typeProperty",
+            "autoAccessor",
+        ],
+    ),
+    ("typeLike", &["class", "interface", "typeAlias", "enum", "typeParameter"]),
+    ("method", &["classMethod", "objectLiteralMethod", "typeMethod"]),
+    ("property", &["classProperty", "objectLiteralProperty", "typeProperty"]),
+    ("accessor", &["classicAccessor", "autoAccessor"]),
+];
+
+/// The modifiers of typescript-eslint, the value of each is `1 << index`.
+/// Options with more modifiers are tried first.
+const MODIFIERS: [&str; 17] = [
+    "const",
+    "readonly",
+    "static",
+    "public",
+    "protected",
+    "private",
+    "#private",
+    "abstract",
+    "destructured",
+    "global",
+    "exported",
+    "unused",
+    "requiresQuotes",
+    "override",
+    "async",
+    "default",
+    "namespace",
+];
+
+fn selector_value(name: &str) -> Option<i64> {
+    SELECTORS.iter().position(|selector| *selector == name).map(|index| 1 << index)
+}
+
+fn modifier_value(name: &str) -> i64 {
+    MODIFIERS.iter().position(|modifier| *modifier == name).map_or(0, |index| 1 << index)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    CamelCase,
+    StrictCamelCase,
+    PascalCase,
+    StrictPascalCase,
+    SnakeCase,
+    UpperCase,
+}
+
+impl Format {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "camelCase" => Some(Self::CamelCase),
+            "strictCamelCase" => Some(Self::StrictCamelCase),
+            "PascalCase" => Some(Self::PascalCase),
+            "StrictPascalCase" => Some(Self::StrictPascalCase),
+            "snake_case" => Some(Self::SnakeCase),
+            "UPPER_CASE" => Some(Self::UpperCase),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CamelCase => "camelCase",
+            Self::StrictCamelCase => "strictCamelCase",
+            Self::PascalCase => "PascalCase",
+            Self::StrictPascalCase => "StrictPascalCase",
+            Self::SnakeCase => "snake_case",
+            Self::UpperCase => "UPPER_CASE",
+        }
+    }
+
+    fn test(self, name: &str) -> bool {
+        let Some(first) = name.chars().next() else { return true };
+        match self {
+            Self::CamelCase => is_lower_or_uncased(first) && !name.contains('_'),
+            Self::StrictCamelCase => {
+                is_lower_or_uncased(first) && has_strict_camel_humps(name, false)
+            }
+            Self::PascalCase => is_upper_or_uncased(first) && !name.contains('_'),
+            Self::StrictPascalCase => {
+                is_upper_or_uncased(first) && has_strict_camel_humps(name, true)
+            }
+            Self::SnakeCase => name.chars().all(is_lower_or_uncased) && has_valid_underscores(name),
+            Self::UpperCase => name.chars().all(is_upper_or_uncased) && has_valid_underscores(name),
+        }
+    }
+}
+
+fn is_upper_or_uncased(c: char) -> bool {
+    c.to_uppercase().eq(std::iter::once(c))
+}
+
+fn is_lower_or_uncased(c: char) -> bool {
+    c.to_lowercase().eq(std::iter::once(c))
+}
+
+/// No underscores, and no two uppercase letters in a row
+fn has_strict_camel_humps(name: &str, mut is_upper: bool) -> bool {
+    if name.starts_with('_') {
+        return false;
+    }
+    for c in name.chars().skip(1) {
+        if c == '_' {
+            return false;
+        }
+        let is_uppercase = is_upper_or_uncased(c) && !is_lower_or_uncased(c);
+        if is_upper == is_uppercase {
+            if is_upper {
+                return false;
+            }
+        } else {
+            is_upper = !is_upper;
+        }
+    }
+    true
+}
+
+/// No leading, trailing or consecutive underscores
+fn has_valid_underscores(name: &str) -> bool {
+    !name.starts_with('_') && !name.ends_with('_') && !name.contains("__")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Underscore {
+    Forbid,
+    Require,
+    RequireDouble,
+    Allow,
+    AllowDouble,
+    AllowSingleOrDouble,
+}
+
+impl Underscore {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "forbid" => Some(Self::Forbid),
+            "require" => Some(Self::Require),
+            "requireDouble" => Some(Self::RequireDouble),
+            "allow" => Some(Self::Allow),
+            "allowDouble" => Some(Self::AllowDouble),
+            "allowSingleOrDouble" => Some(Self::AllowSingleOrDouble),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Leading,
+    Trailing,
+}
+
+impl Position {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Leading => "leading",
+            Self::Trailing => "trailing",
+        }
+    }
+
+    fn has(self, name: &str, affix: &str) -> bool {
+        match self {
+            Self::Leading => name.starts_with(affix),
+            Self::Trailing => name.ends_with(affix),
+        }
+    }
+
+    fn trim<'n>(self, name: &'n str, affix: &str) -> &'n str {
+        match self {
+            Self::Leading => &name[affix.len()..],
+            Self::Trailing => &name[..name.len() - affix.len()],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MatchRegex {
+    regex: Regex,
+    /// Whether the name has to match the regex, or must not match it
+    is_match: bool,
+}
+
+impl MatchRegex {
+    fn from_configuration(value: &Value) -> Option<Self> {
+        let regex = Regex::new(value.get("regex")?.as_str()?).ok()?;
+        let is_match = value.get("match").and_then(Value::as_bool).unwrap_or(true);
+        Some(Self { regex, is_match })
+    }
+
+    fn test(&self, name: &str) -> bool {
+        self.regex.is_match(name) == self.is_match
+    }
+}
+
+#[derive(Debug, Clone)]
+struct NamingOption {
+    selector: i64,
+    is_meta: bool,
+    /// All of these modifiers are required for the option to apply
+    modifiers: i64,
+    filter: Option<MatchRegex>,
+    leading_underscore: Option<Underscore>,
+    trailing_underscore: Option<Underscore>,
+    prefix: Vec<CompactString>,
+    suffix: Vec<CompactString>,
+    custom: Option<MatchRegex>,
+    formats: Vec<Format>,
+}
+
+impl NamingOption {
+    /// An option for each of the selectors of `value`
+    fn from_configuration(value: &Value) -> Vec<Self> {
+        // Types are not known without type information
+        if value.get("types").is_some() {
+            return vec![];
+        }
+        let modifiers = value.get("modifiers").and_then(Value::as_array).map_or(0, |modifiers| {
+            modifiers
+                .iter()
+                .filter_map(Value::as_str)
+                .fold(0, |acc, name| acc | modifier_value(name))
+        });
+        let filter = value.get("filter").and_then(|filter| match filter {
+            Value::String(regex) => {
+                Regex::new(regex).ok().map(|regex| MatchRegex { regex, is_match: true })
+            }
+            _ => MatchRegex::from_configuration(filter),
+        });
+        let underscore =
+            |key: &str| value.get(key).and_then(Value::as_str).and_then(Underscore::from_name);
+        let affixes = |key: &str| {
+            value
+                .get(key)
+                .and_then(Value::as_array)
+                .map(|affixes| {
+                    affixes.iter().filter_map(Value::as_str).map(CompactString::from).collect()
+                })
+                .unwrap_or_default()
+        };
+        let formats = value
+            .get("format")
+            .and_then(Value::as_array)
+            .map(|formats| {
+                formats.iter().filter_map(Value::as_str).filter_map(Format::from_name).collect()
+            })
+            .unwrap_or_default();
+        let option = Self {
+            selector: 0,
+            is_meta: false,
+            modifiers,
+            filter,
+            leading_underscore: underscore("leadingUnderscore"),
+            trailing_underscore: underscore("trailingUnderscore"),
+            prefix: affixes("prefix"),
+            suffix: affixes("suffix"),
+            custom: value.get("custom").and_then(MatchRegex::from_configuration),
+            formats,
+        };
+
+        let selectors = match value.get("selector") {
+            Some(Value::Array(selectors)) => selectors.iter().filter_map(Value::as_str).collect(),
+            Some(Value::String(selector)) => vec![selector.as_str()],
+            _ => vec![],
+        };
+        selectors
+            .into_iter()
+            .filter_map(|name| {
+                let (selector, is_meta) = if name == "default" {
+                    (-1, true)
+                } else if let Some((_, group)) =
+                    META_SELECTORS.iter().find(|(meta, _)| *meta == name)
+                {
+                    (group.iter().filter_map(|name| selector_value(name)).sum::<i64>(), true)
+                } else {
+                    (selector_value(name)?, false)
+                };
+                Some(Self { selector, is_meta, ..option.clone() })
+            })
+            .collect()
+    }
+
+    /// The unknown modifiers and the invalid regexes of `value`, which `from_configuration` ignores
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        let modifiers = value.get("modifiers").and_then(Value::as_array).into_iter().flatten();
+        if let Some(name) =
+            modifiers.filter_map(Value::as_str).find(|name| !MODIFIERS.contains(name))
+        {
+            let expected = MODIFIERS.join(", ");
+            return Err(format!("unknown modifier {name:?}, expected one of {expected}"));
+        }
+        let filter = value.get("filter").and_then(|filter| match filter {
+            Value::String(regex) => Some(regex.as_str()),
+            _ => filter.get("regex")?.as_str(),
+        });
+        let custom = value.get("custom").and_then(|custom| custom.get("regex")?.as_str());
+        for regex in filter.into_iter().chain(custom) {
+            Regex::new(regex).map_err(|error| format!("invalid regex {regex:?}: {error}"))?;
+        }
+        Ok(())
+    }
+
+    /// The reason `name` is invalid, following the name in the diagnostic
+    fn validate(&self, name: &str) -> Result<(), String> {
+        let mut trimmed = name;
+        for (underscore, position) in [
+            (self.leading_underscore, Position::Leading),
+            (self.trailing_underscore, Position::Trailing),
+        ] {
+            if let Some(underscore) = underscore {
+                trimmed = validate_underscore(trimmed, underscore, position)?;
+            }
+        }
+        for (affixes, position, description) in [
+            (&self.prefix, Position::Leading, "prefixes"),
+            (&self.suffix, Position::Trailing, "suffixes"),
+        ] {
+            if affixes.is_empty() {
+                continue;
+            }
+            let Some(affix) = affixes.iter().find(|affix| position.has(trimmed, affix)) else {
+                let affixes = affixes.iter().map(CompactString::as_str).collect::<Vec<_>>();
+                return Err(format!(
+                    "must have one of the following {description}: {}",
+                    affixes.join(", ")
+                ));
+            };
+            trimmed = position.trim(trimmed, affix);
+        }
+        if let Some(custom) = &self.custom {
+            if !custom.test(trimmed) {
+                let expected = if custom.is_match { "match" } else { "not match" };
+                return Err(format!("must {expected} the RegExp: /{}/u", custom.regex.as_str()));
+            }
+        }
+        if self.formats.is_empty() || self.formats.iter().any(|format| format.test(trimmed)) {
+            return Ok(());
+        }
+        let formats = self.formats.iter().map(|format| format.as_str()).collect::<Vec<_>>();
+        let formats = formats.join(", ");
+        if trimmed == name {
+            Err(format!("must match one of the following formats: {formats}"))
+        } else {
+            Err(format!(
+                "trimmed as `{trimmed}` must match one of the following formats: {formats}"
+            ))
+        }
+    }
+}
+
+/// The name without the allowed or required underscores
+fn validate_underscore(
+    name: &str,
+    underscore: Underscore,
+    position: Position,
+) -> Result<&str, String> {
+    let single = position.has(name, "_");
+    let double = position.has(name, "__");
+    match underscore {
+        Underscore::Allow if single => Ok(position.trim(name, "_")),
+        Underscore::AllowDouble if double => Ok(position.trim(name, "__")),
+        Underscore::AllowSingleOrDouble if double => Ok(position.trim(name, "__")),
+        Underscore::AllowSingleOrDouble if single => Ok(position.trim(name, "_")),
+        Underscore::Forbid if single => {
+            Err(format!("must not have a {} underscore.", position.as_str()))
+        }
+        Underscore::Require if !single => {
+            Err(format!("must have one {} underscore(s).", position.as_str()))
+        }
+        Underscore::Require => Ok(position.trim(name, "_")),
+        Underscore::RequireDouble if !double => {
+            Err(format!("must have two {} underscore(s).", position.as_str()))
+        }
+        Underscore::RequireDouble => Ok(position.trim(name, "__")),
+        _ => Ok(name),
+    }
+}
+
+/// The selector and modifiers of a symbol, and the span of its name
+fn classify_symbol(
+    symbol_id: SymbolId,
+    ctx: &LintContext<'_>,
+) -> Option<(&'static str, i64, Span)> {
+    let symbols = ctx.symbols();
+    let name = symbols.get_name(symbol_id);
+    let mut span = symbols.get_span(symbol_id);
+    let is_global = symbols.get_scope_id(symbol_id) == ctx.scopes().root_scope_id();
+    let is_exported = is_global
+        && ctx.module_record().local_export_entries.iter().any(|entry| match &entry.local_name {
+            ExportLocalName::Name(local) => local.name().as_str() == name,
+            _ => false,
+        });
+    let is_unused = !is_exported && symbols.get_resolved_reference_ids(symbol_id).is_empty();
+
+    let mut modifiers = Vec::new();
+    let selector = match ctx.nodes().kind(symbols.get_declaration(symbol_id)) {
+        AstKind::VariableDeclarator(decl) => {
+            if decl.kind == VariableDeclarationKind::Const {
+                modifiers.push("const");
+            }
+            if is_destructured(&decl.id, span) {
+                modifiers.push("destructured");
+            }
+            if is_global {
+                modifiers.push("global");
+            }
+            let is_async = match &decl.init {
+                Some(Expression::ArrowFunctionExpression(func)) => func.r#async,
+                Some(Expression::FunctionExpression(func)) => func.r#async,
+                _ => false,
+            };
+            if is_async {
+                modifiers.push("async");
+            }
+            "variable"
+        }
+        AstKind::Function(func) => {
+            if is_global {
+                modifiers.push("global");
+            }
+            if func.r#async {
+                modifiers.push("async");
+            }
+            "function"
+        }
+        AstKind::FormalParameter(param) => {
+            // Parameter properties are class members
+            if param.accessibility.is_some() || param.readonly {
+                return None;
+            }
+            if is_destructured(&param.pattern, span) {
+                modifiers.push("destructured");
+            }
+            "parameter"
+        }
+        AstKind::BindingRestElement(_) => "parameter",
+        AstKind::Class(class) => {
+            if class.modifiers.contains(ModifierKind::Abstract) {
+                modifiers.push("abstract");
+            }
+            "class"
+        }
+        AstKind::TSInterfaceDeclaration(_) => "interface",
+        AstKind::TSTypeAliasDeclaration(_) => "typeAlias",
+        AstKind::TSEnumDeclaration(_) => "enum",
+        AstKind::TSEnumMember(member) => {
+            span = match &member.id {
+                TSEnumMemberName::Identifier(ident) => ident.span,
+                TSEnumMemberName::StringLiteral(lit) => lit.span,
+                TSEnumMemberName::NumericLiteral(lit) => lit.span,
+                TSEnumMemberName::ComputedPropertyName(expr) => expr.span(),
+            };
+            if !is_identifier_name(name) {
+                modifiers.push("requiresQuotes");
+            }
+            "enumMember"
+        }
+        AstKind::TSTypeParameter(_) => "typeParameter",
+        AstKind::ImportDefaultSpecifier(_) => {
+            modifiers.push("default");
+            "import"
+        }
+        AstKind::ImportNamespaceSpecifier(_) => {
+            modifiers.push("namespace");
+            "import"
+        }
+        _ => return None,
+    };
+    if is_exported
+        && matches!(
+            selector,
+            "variable" | "function" | "class" | "interface" | "typeAlias" | "enum"
+        )
+    {
+        modifiers.push("exported");
+    }
+    if is_unused && !matches!(selector, "enumMember" | "import") {
+        modifiers.push("unused");
+    }
+    let modifiers = modifiers.into_iter().fold(0, |acc, name| acc | modifier_value(name));
+    Some((selector, modifiers, span))
+}
+
+/// Whether the binding at `span` is a shorthand property of an object pattern, e.g. `{ foo }`
+fn is_destructured(pattern: &BindingPattern, span: Span) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(object) => {
+            object.properties.iter().any(|prop| {
+                let value = match &prop.value.kind {
+                    BindingPatternKind::AssignmentPattern(assignment) => &assignment.left,
+                    _ => &prop.value,
+                };
+                match &value.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => {
+                        prop.shorthand && ident.span == span
+                    }
+                    _ => is_destructured(value, span),
+                }
+            }) || object.rest.as_ref().is_some_and(|rest| is_destructured(&rest.argument, span))
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().any(|element| is_destructured(element, span))
+                || array.rest.as_ref().is_some_and(|rest| is_destructured(&rest.argument, span))
+        }
+        BindingPatternKind::AssignmentPattern(assignment) => {
+            is_destructured(&assignment.left, span)
+        }
+    }
+}
+
+/// `"Type Alias"` for `"typeAlias"`
+fn selector_description(selector: &str) -> String {
+    let mut description = String::new();
+    for (i, c) in selector.chars().enumerate() {
+        if i == 0 {
+            description.extend(c.to_uppercase());
+        } else {
+            if c.is_uppercase() {
+                description.push(' ');
+            }
+            description.push(c);
+        }
+    }
+    description
+}
+
+#[derive(Debug, Clone)]
+pub struct NamingConvention(Box<NamingConventionConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NamingConventionConfig {
+    /// Sorted by the order in which they are tried
+    options: Vec<NamingOption>,
+}
+
+impl std::ops::Deref for NamingConvention {
+    type Target = NamingConventionConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NamingConvention {
+    fn default() -> Self {
+        Self::from_options(&serde_json::json!([
+            {
+                "selector": "default",
+                "format": ["camelCase"],
+                "leadingUnderscore": "allow",
+                "trailingUnderscore": "allow"
+            },
+            { "selector": "import", "format": ["camelCase", "PascalCase"] },
+            {
+                "selector": "variable",
+                "format": ["camelCase", "UPPER_CASE"],
+                "leadingUnderscore": "allow",
+                "trailingUnderscore": "allow"
+            },
+            { "selector": "typeLike", "format": ["PascalCase"] }
+        ]))
+    }
+}
+
+impl NamingConvention {
+    fn from_options(value: &Value) -> Self {
+        let mut options: Vec<NamingOption> = value
+            .as_array()
+            .map(|options| options.iter().flat_map(NamingOption::from_configuration).collect())
+            .unwrap_or_default();
+        // Individual selectors before meta selectors, and more specific options first
+        options.sort_by(|a, b| {
+            if a.selector == b.selector {
+                return b.modifiers.cmp(&a.modifiers);
+            }
+            a.is_meta.cmp(&b.is_meta).then(b.selector.cmp(&a.selector))
+        });
+        Self(Box::new(NamingConventionConfig { options }))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces naming conventions for declarations.
+    ///
+    /// ### Why is this bad?
+    /// Consistent names make it easier to tell what kind of declaration a name refers to.
+    ///
+    /// ### Options
+    /// A list of objects, each of which applies a convention to a `selector`:
+    /// * `selector`: `"variable"`, `"function"`, `"parameter"`, `"enumMember"`, `"class"`,
+    ///   `"interface"`, `"typeAlias"`, `"enum"`, `"typeParameter"`, `"import"`, a list of these,
+    ///   or a group of them: `"default"`, `"variableLike"`, `"memberLike"` or `"typeLike"`.
+    /// * `modifiers`: only apply to declarations with all of these modifiers, e.g. `"const"`,
+    ///   `"destructured"`, `"global"`, `"exported"`, `"unused"` or `"async"`.
+    /// * `filter`: only apply to names matching a regular expression, `{ "regex", "match" }`.
+    /// * `leadingUnderscore` and `trailingUnderscore`: `"forbid"`, `"require"`, `"requireDouble"`,
+    ///   `"allow"`, `"allowDouble"` or `"allowSingleOrDouble"`.
+    /// * `prefix` and `suffix`: the name must start or end with one of these.
+    /// * `custom`: a regular expression the name must match, or not match.
+    /// * `format`: the name must be in one of these formats: `"camelCase"`, `"strictCamelCase"`,
+    ///   `"PascalCase"`, `"StrictPascalCase"`, `"snake_case"` or `"UPPER_CASE"`.
+    ///
+    /// The first option matching a declaration applies, individual selectors before groups and
+    /// options with more modifiers first. Underscores, prefixes and suffixes are trimmed from the
+    /// name before checking its format.
+    ///
+    /// Class and object members are not checked, and options with `types` are ignored,
+    /// as they need type information.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// const my_variable = 1;
+    /// interface myInterface {}
+    /// ```
+    NamingConvention,
+    style
+);
+
+impl Rule for NamingConvention {
+    fn from_configuration(value: Value) -> Self {
+        if value.as_array().map_or(true, Vec::is_empty) {
+            return Self::default();
+        }
+        Self::from_options(&value)
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        value.as_array().into_iter().flatten().try_for_each(NamingOption::validate_configuration)
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let Some((selector, modifiers, span)) = classify_symbol(symbol_id, ctx) else { return };
+        let Some(selector_value) = selector_value(selector) else { return };
+        let name = ctx.symbols().get_name(symbol_id);
+        let Some(option) = self.options.iter().find(|option| {
+            option.selector & selector_value != 0
+                && option.modifiers & !modifiers == 0
+                && option.filter.as_ref().map_or(true, |filter| filter.test(name))
+        }) else {
+            return;
+        };
+        if let Err(reason) = option.validate(name) {
+            let description = selector_description(selector);
+            ctx.diagnostic(NamingConventionDiagnostic(
+                format!("{description} name `{name}` {reason}"),
+                span,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const fooBar = 1;", None),
+        ("const FOO_BAR = 1;", None),
+        ("const _fooBar = 1;", None),
+        ("function fooBar(baz) { return baz; }", None),
+        ("class FooBar {}", None),
+        ("interface FooBar {}", None),
+        ("type FooBar<T> = T[];", None),
+        ("enum Color { red, green }", None),
+        ("import React from 'react'; React;", None),
+        ("import * as path from 'path'; path;", None),
+        ("import { foo_bar } from 'mod'; foo_bar;", None),
+        ("const obj = { Foo_bar: 1 };", None),
+        ("class Foo { constructor(private Foo_bar: number) {} }", None),
+        (
+            "const isEnabled = true;",
+            Some(
+                serde_json::json!([{ "selector": "variable", "format": ["PascalCase"], "prefix": ["is", "should"] }]),
+            ),
+        ),
+        (
+            "let foo__ = 1;",
+            Some(
+                serde_json::json!([{ "selector": "variable", "format": null, "trailingUnderscore": "requireDouble" }]),
+            ),
+        ),
+        (
+            "interface IFoo {}",
+            Some(
+                serde_json::json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": true } }]),
+            ),
+        ),
+        (
+            "let foo_bar = 1;",
+            Some(serde_json::json!([{ "selector": "variable", "format": ["snake_case"] }])),
+        ),
+        (
+            "let FOO_BAR = 1;",
+            Some(serde_json::json!([{ "selector": "variableLike", "format": ["UPPER_CASE"] }])),
+        ),
+        (
+            "function f(fooBar) { return fooBar; }",
+            Some(serde_json::json!([{ "selector": "parameter", "format": ["strictCamelCase"] }])),
+        ),
+        (
+            "const { foo_bar } = obj;",
+            Some(serde_json::json!([
+                { "selector": "variable", "modifiers": ["destructured"], "format": null },
+                { "selector": "variable", "format": ["camelCase"] }
+            ])),
+        ),
+        (
+            "const _Ignored = 1;",
+            Some(
+                serde_json::json!([{ "selector": "variable", "filter": { "regex": "^_", "match": false }, "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "enum E { 'foo-bar' = 1 }",
+            Some(serde_json::json!([
+                { "selector": "enumMember", "modifiers": ["requiresQuotes"], "format": null },
+                { "selector": "enumMember", "format": ["camelCase"] }
+            ])),
+        ),
+        (
+            "const enabled = true;",
+            Some(
+                serde_json::json!([{ "selector": "variable", "types": ["boolean"], "prefix": ["is"] }]),
+            ),
+        ),
+    ];
+
+    let fail = vec![
+        ("const my_var = 1;", None),
+        ("function Foo_bar() {}", None),
+        ("interface foo {}", None),
+        ("enum Color { red_one }", None),
+        ("const __foo_bar = 1;", None),
+        ("import foo_bar from 'mod'; foo_bar;", None),
+        ("type Foo<t> = t[];", None),
+        (
+            "let foo = 1;",
+            Some(
+                serde_json::json!([{ "selector": "variable", "format": ["PascalCase"], "prefix": ["is", "should"] }]),
+            ),
+        ),
+        (
+            "let _foo = 1;",
+            Some(
+                serde_json::json!([{ "selector": "default", "format": null, "leadingUnderscore": "forbid" }]),
+            ),
+        ),
+        (
+            "let foo = 1;",
+            Some(
+                serde_json::json!([{ "selector": "variable", "format": null, "trailingUnderscore": "requireDouble" }]),
+            ),
+        ),
+        (
+            "interface Foo {}",
+            Some(
+                serde_json::json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": true } }]),
+            ),
+        ),
+        (
+            "interface IFoo {}",
+            Some(
+                serde_json::json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": false } }]),
+            ),
+        ),
+        (
+            "function f(fooBAR) { return fooBAR; }",
+            Some(serde_json::json!([{ "selector": "parameter", "format": ["strictCamelCase"] }])),
+        ),
+        (
+            "const maxCount = 1; function f() { const MAX = 1; return MAX; }",
+            Some(serde_json::json!([
+                { "selector": "variable", "modifiers": ["const", "global"], "format": ["UPPER_CASE"] },
+                { "selector": "variable", "format": ["camelCase"] }
+            ])),
+        ),
+        (
+            "const _Ignored = 1, Bad = 2;",
+            Some(
+                serde_json::json!([{ "selector": "variable", "filter": { "regex": "^_", "match": false }, "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "const { foo_bar, baz: qux_quux } = obj;",
+            Some(serde_json::json!([
+                { "selector": "variable", "modifiers": ["destructured"], "format": null },
+                { "selector": "variable", "format": ["camelCase"] }
+            ])),
+        ),
+        (
+            "export const fooBar = 1;",
+            Some(
+                serde_json::json!([{ "selector": "variable", "modifiers": ["exported"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+    ];
+
+    Tester::new(NamingConvention::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,5 +1,5 @@
 use oxc_ast::ast::Expression;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -40,6 +40,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDuplicateEnumValues {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TSEnumDeclaration])
+    }
+
     #[allow(clippy::float_cmp)]
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSEnumDeclaration(enum_body) = node.kind() else { return };
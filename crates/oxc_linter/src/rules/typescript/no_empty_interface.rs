@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -6,7 +6,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-empty-interface): an empty interface is equivalent to `{{}}`")]
@@ -21,7 +21,10 @@ struct NoEmptyInterfaceDiagnostic(#[label] pub Span);
 struct NoEmptyInterfaceExtendDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
-pub struct NoEmptyInterface;
+pub struct NoEmptyInterface {
+    /// Allow an empty interface extending a single interface, e.g. `interface Foo extends Bar {}`
+    allow_single_extends: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -34,6 +37,9 @@ declare_oxc_lint!(
     /// Using an empty interface is often a sign of programmer error, such as misunderstanding the concept of {} or forgetting to fill in fields.
     /// This rule aims to ensure that only meaningful interfaces are declared in the code.
     ///
+    /// ### Options
+    /// * `allowSingleExtends`: allow an empty interface extending a single interface.
+    ///
     /// ### Example
     /// ```javascript
     /// interface Foo {}
@@ -44,6 +50,20 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEmptyInterface {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_single_extends: value
+                .get(0)
+                .and_then(|config| config.get("allowSingleExtends"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TSInterfaceDeclaration])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSInterfaceDeclaration(interface) = node.kind() {
             if interface.body.body.is_empty() {
@@ -51,8 +71,28 @@ impl Rule for NoEmptyInterface {
                     None => {
                         ctx.diagnostic(NoEmptyInterfaceDiagnostic(interface.span));
                     }
-                    Some(extends) if extends.len() == 1 => {
-                        ctx.diagnostic(NoEmptyInterfaceExtendDiagnostic(interface.span));
+                    Some(extends) if extends.len() == 1 && !self.allow_single_extends => {
+                        let diagnostic = NoEmptyInterfaceExtendDiagnostic(interface.span);
+                        if !can_fix(node, ctx) {
+                            ctx.diagnostic(diagnostic);
+                            return;
+                        }
+                        // `interface Foo<T> extends Bar<T> {}` -> `type Foo<T> = Bar<T>`
+                        ctx.diagnostic_with_fix(diagnostic, || {
+                            let source_text = ctx.source_text();
+                            let type_parameters = interface
+                                .type_parameters
+                                .as_ref()
+                                .map_or("", |params| params.span.source_text(source_text));
+                            Fix::new(
+                                format!(
+                                    "type {}{type_parameters} = {}",
+                                    interface.id.name,
+                                    extends[0].span.source_text(source_text)
+                                ),
+                                interface.span,
+                            )
+                        });
                     }
                     _ => {}
                 }
@@ -61,31 +101,98 @@ impl Rule for NoEmptyInterface {
     }
 }
 
+/// A type alias can not be merged with a class of the same name,
+/// nor augment a module in a declaration file
+fn can_fix(node: &AstNode, ctx: &LintContext) -> bool {
+    let AstKind::TSInterfaceDeclaration(interface) = node.kind() else { return false };
+    let merged_with_class = ctx.nodes().iter().any(|other| {
+        other.scope_id() == node.scope_id()
+            && matches!(
+                other.kind(),
+                AstKind::Class(class)
+                    if class.is_declaration()
+                        && class.id.as_ref().is_some_and(|id| id.name == interface.id.name)
+            )
+    });
+    let in_ambient_module = ctx.source_type().is_typescript_definition()
+        && ctx.nodes().ancestors(node.id()).any(|id| {
+            matches!(
+                ctx.nodes().kind(id),
+                AstKind::TSModuleDeclaration(module) if module.modifiers.is_contains_declare()
+            )
+        });
+    !merged_with_class && !in_ambient_module
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
+    use serde_json::json;
 
     let pass = vec![
-        "interface Foo { name: string; }",
-        "interface Foo { name: string; }
+        ("interface Foo { name: string; }", None),
+        (
+            "interface Foo { name: string; }
         interface Bar { age: number; }
         // valid because extending multiple interfaces can be used instead of a union type
         interface Baz extends Foo, Bar {}",
+            None,
+        ),
+        (
+            "interface Foo { name: string; } interface Bar extends Foo {}",
+            Some(json!([{ "allowSingleExtends": true }])),
+        ),
     ];
 
     let fail = vec![
-        "interface Foo {}",
-        "interface Foo { props: string; } interface Bar extends Foo {} class Baz {}",
-        "interface Foo { props: string; } interface Bar extends Foo {} class Bar {}",
-        "interface Foo { props: string; } interface Bar extends Foo {} const bar = class Bar {};",
-        "interface Foo { name: string; } interface Bar extends Foo {}",
-        "interface Foo extends Array<number> {}",
-        "interface Foo extends Array<number | {}> {}",
-        "interface Bar { bar: string; } interface Foo extends Array<Bar> {}",
-        "type R = Record<string, unknown>; interface Foo extends R {}",
-        "interface Foo<T> extends Bar<T> {}",
-        "declare module FooBar { type Baz = typeof baz; export interface Bar extends Baz {} }",
+        ("interface Foo {}", None),
+        ("interface Foo {}", Some(json!([{ "allowSingleExtends": true }]))),
+        ("interface Foo { props: string; } interface Bar extends Foo {} class Baz {}", None),
+        ("interface Foo { props: string; } interface Bar extends Foo {} class Bar {}", None),
+        (
+            "interface Foo { props: string; } interface Bar extends Foo {} const bar = class Bar {};",
+            None,
+        ),
+        ("interface Foo { name: string; } interface Bar extends Foo {}", None),
+        (
+            "interface Foo { name: string; } interface Bar extends Foo {}",
+            Some(json!([{ "allowSingleExtends": false }])),
+        ),
+        ("interface Foo extends Array<number> {}", None),
+        ("interface Foo extends Array<number | {}> {}", None),
+        ("interface Bar { bar: string; } interface Foo extends Array<Bar> {}", None),
+        ("type R = Record<string, unknown>; interface Foo extends R {}", None),
+        ("interface Foo<T> extends Bar<T> {}", None),
+        (
+            "declare module FooBar { type Baz = typeof baz; export interface Bar extends Baz {} }",
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "interface Foo { name: string; } interface Bar extends Foo {}",
+            "interface Foo { name: string; } type Bar = Foo",
+            None,
+        ),
+        (
+            "interface Foo { props: string; } interface Bar extends Foo {} class Bar {}",
+            "interface Foo { props: string; } interface Bar extends Foo {} class Bar {}",
+            None,
+        ),
+        (
+            "interface Foo { props: string; } interface Bar extends Foo {} const bar = class Bar {};",
+            "interface Foo { props: string; } type Bar = Foo const bar = class Bar {};",
+            None,
+        ),
+        ("interface Foo extends Array<number> {}", "type Foo = Array<number>", None),
+        ("interface Foo<T> extends Bar<T> {}", "type Foo<T> = Bar<T>", None),
+        (
+            "declare module FooBar { type Baz = typeof baz; export interface Bar extends Baz {} }",
+            "declare module FooBar { type Baz = typeof baz; export type Bar = Baz }",
+            None,
+        ),
     ];
 
-    Tester::new(NoEmptyInterface::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoEmptyInterface::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -1,5 +1,5 @@
 use crate::Fix;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -89,6 +89,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoExplicitAny {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TSAnyKeyword])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSAnyKeyword(any) = node.kind() else { return };
         if self.ignore_rest_args && Self::is_in_rest(node, ctx) {
@@ -96,7 +100,7 @@ impl Rule for NoExplicitAny {
         }
 
         if self.fix_to_unknown {
-            ctx.diagnostic_with_fix(NoExplicitAnyDiagnostic(any.span), || {
+            ctx.diagnostic_with_dangerous_fix(NoExplicitAnyDiagnostic(any.span), || {
                 Fix::new("unknown", any.span)
             });
         } else {
@@ -0,0 +1,207 @@
+use oxc_ast::{ast::Expression, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(no-floating-promises): Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler.")]
+#[diagnostic(
+    severity(warning),
+    help("Add `await`, handle the rejection, or mark the promise as ignored with `void`.")
+)]
+struct NoFloatingPromisesDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoFloatingPromises {
+    ignore_void: bool,
+    ignore_iife: bool,
+}
+
+impl Default for NoFloatingPromises {
+    fn default() -> Self {
+        Self { ignore_void: true, ignore_iife: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require promise-like statements to be handled appropriately.
+    ///
+    /// This rule is type-aware, it does nothing unless type information is provided,
+    /// e.g. with `--type-info`.
+    ///
+    /// Options:
+    /// * `ignoreVoid`: allow ignoring promises with the `void` operator, `true` by default
+    /// * `ignoreIIFE`: allow immediately invoked async functions, `false` by default
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise which is neither awaited nor given a rejection handler can fail silently,
+    /// and the code after it may run before it settles.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// const promise = new Promise((resolve, reject) => resolve('value'));
+    /// promise;
+    ///
+    /// async function returnsPromise() {
+    ///   return 'value';
+    /// }
+    /// returnsPromise().then(() => {});
+    /// ```
+    NoFloatingPromises,
+    nursery
+);
+
+impl Rule for NoFloatingPromises {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let flag = |name: &str| {
+            value.get(0).and_then(|config| config.get(name)).and_then(serde_json::Value::as_bool)
+        };
+        Self {
+            ignore_void: flag("ignoreVoid").unwrap_or(default.ignore_void),
+            ignore_iife: flag("ignoreIIFE").unwrap_or(default.ignore_iife),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ExpressionStatement])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !ctx.has_type_info() {
+            return;
+        }
+        let AstKind::ExpressionStatement(stmt) = node.kind() else {
+            return;
+        };
+        if self.ignore_iife && is_iife(&stmt.expression) {
+            return;
+        }
+        if self.is_unhandled(&stmt.expression, ctx) {
+            ctx.diagnostic(NoFloatingPromisesDiagnostic(stmt.span));
+        }
+    }
+}
+
+impl NoFloatingPromises {
+    /// Whether `expr` is, or may evaluate to, a thenable without a rejection handler.
+    fn is_unhandled(&self, expr: &Expression, ctx: &LintContext) -> bool {
+        let expr = expr.without_parenthesized();
+        match expr {
+            Expression::SequenceExpression(seq) => {
+                return seq.expressions.iter().any(|expr| self.is_unhandled(expr, ctx));
+            }
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => {
+                return !self.ignore_void && self.is_unhandled(&unary.argument, ctx);
+            }
+            Expression::ConditionalExpression(cond) => {
+                return self.is_unhandled(&cond.consequent, ctx)
+                    || self.is_unhandled(&cond.alternate, ctx);
+            }
+            Expression::LogicalExpression(logical) => {
+                return self.is_unhandled(&logical.left, ctx)
+                    || self.is_unhandled(&logical.right, ctx);
+            }
+            Expression::CallExpression(call) => {
+                if let Expression::MemberExpression(member) = call.callee.without_parenthesized() {
+                    match member.static_property_name() {
+                        Some("catch") if !call.arguments.is_empty() => return false,
+                        Some("then") if call.arguments.len() >= 2 => return false,
+                        Some("finally") => return self.is_unhandled(member.object(), ctx),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        ctx.expression_type(expr).is_some_and(|ty| ty.thenable)
+    }
+}
+
+/// Whether `expr` is a function called right where it is defined, e.g. `(async () => {})()`.
+fn is_iife(expr: &Expression) -> bool {
+    matches!(
+        expr.without_parenthesized(),
+        Expression::CallExpression(call) if matches!(
+            call.callee.without_parenthesized(),
+            Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+        )
+    )
+}
+
+#[test]
+fn test() {
+    use std::{path::Path, sync::Arc};
+
+    use serde_json::json;
+
+    use crate::{tester::Tester, ExpressionType, TypeInfo};
+
+    /// Types `fetch()`, `promise*` identifiers, async IIFEs, `new Promise()` and
+    /// the `then`, `catch` and `finally` calls on them as promises.
+    #[derive(Debug)]
+    struct PromiseTypes;
+
+    impl TypeInfo for PromiseTypes {
+        fn expression_type(&self, path: &Path, expr: &Expression<'_>) -> Option<ExpressionType> {
+            let thenable = match expr.without_parenthesized() {
+                Expression::Identifier(ident) => ident.name.starts_with("promise"),
+                Expression::NewExpression(new) => new.callee.is_specific_id("Promise"),
+                Expression::CallExpression(call) => match call.callee.without_parenthesized() {
+                    Expression::Identifier(ident) => ident.name == "fetch",
+                    Expression::ArrowFunctionExpression(arrow) => arrow.r#async,
+                    Expression::FunctionExpression(func) => func.r#async,
+                    Expression::MemberExpression(member) => {
+                        matches!(member.static_property_name(), Some("then" | "catch" | "finally"))
+                            && self.expression_type(path, member.object()).is_some()
+                    }
+                    _ => false,
+                },
+                _ => false,
+            };
+            thenable.then(|| ExpressionType { text: String::from("Promise<void>"), thenable: true })
+        }
+    }
+
+    let pass = vec![
+        ("async function f() { await fetch(); }", None),
+        ("fetch().catch(() => {});", None),
+        ("fetch().then(() => {}, () => {});", None),
+        ("fetch().catch(() => {}).finally(() => {});", None),
+        ("void fetch();", None),
+        ("const p = fetch();", None),
+        ("foo();", None),
+        ("a ? fetch().catch(handle) : foo();", None),
+        ("(async () => {})();", Some(json!([{ "ignoreIIFE": true }]))),
+    ];
+
+    let fail = vec![
+        ("fetch();", None),
+        ("promise;", None),
+        ("new Promise((resolve) => resolve());", None),
+        ("fetch().then(() => {});", None),
+        ("fetch().catch();", None),
+        ("fetch().finally(() => {});", None),
+        ("void fetch();", Some(json!([{ "ignoreVoid": false }]))),
+        ("a ? fetch() : foo();", None),
+        ("a && promise;", None),
+        ("foo(), fetch();", None),
+        ("(async () => {})();", None),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, pass, fail)
+        .with_type_info(Arc::new(PromiseTypes))
+        .test_and_snapshot();
+
+    // Without type information nothing is reported
+    Tester::new(NoFloatingPromises::NAME, vec!["fetch();", "promise;"], vec![]).test();
+}
@@ -0,0 +1,287 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression, TSLiteral, TSType, TSTypeAnnotation, TSTypeName},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "typescript-eslint(no-inferrable-types): Type {0} trivially inferred from a {0} literal, remove type annotation."
+)]
+#[diagnostic(severity(warning))]
+struct NoInferrableTypesDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInferrableTypes {
+    /// Ignore parameters with a default value, e.g. `function f(a: number = 5) {}`
+    ignore_parameters: bool,
+    /// Ignore class properties with an initializer, e.g. `class A { a: number = 5 }`
+    ignore_properties: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows explicit type declarations for variables or parameters initialized to a
+    /// number, string, or boolean.
+    ///
+    /// ### Why is this bad?
+    /// TypeScript is able to infer the types of parameters, properties, and variables from
+    /// their default or initial values. Explicit types where they can be easily inferred
+    /// add needless verbosity.
+    ///
+    /// ### Options
+    /// * `ignoreParameters`: ignore function parameters.
+    /// * `ignoreProperties`: ignore class properties.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// const a: bigint = 10n;
+    /// const b: boolean = !0;
+    /// const c: number = Infinity;
+    /// const d: string = `str`;
+    /// function f(e: null = null) {}
+    /// class A { g: RegExp = /a/; }
+    /// ```
+    NoInferrableTypes,
+    style
+);
+
+impl Rule for NoInferrableTypes {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        let get_bool = |name: &str| {
+            obj.and_then(|v| v.get(name)).and_then(serde_json::Value::as_bool).unwrap_or_default()
+        };
+        Self {
+            ignore_parameters: get_bool("ignoreParameters"),
+            ignore_properties: get_bool("ignoreProperties"),
+        }
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::VariableDeclarator, AstType::FormalParameter, AstType::PropertyDefinition])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                let (Some(annotation), Some(init)) = (&decl.id.type_annotation, &decl.init) else {
+                    return;
+                };
+                report(annotation, init, annotation.span, ctx);
+            }
+            AstKind::FormalParameter(param) if !self.ignore_parameters => {
+                let BindingPatternKind::AssignmentPattern(pattern) = &param.pattern.kind else {
+                    return;
+                };
+                let Some(annotation) = &pattern.left.type_annotation else { return };
+                // `a?: number = 5` drops the `?` as well
+                let fix_span = Span::new(pattern.left.span().end, annotation.span.end);
+                report(annotation, &pattern.right, fix_span, ctx);
+            }
+            // A `readonly` property without a type has its value as the type
+            AstKind::PropertyDefinition(prop)
+                if !self.ignore_properties && !prop.readonly && !prop.optional =>
+            {
+                let (Some(annotation), Some(value)) = (&prop.type_annotation, &prop.value) else {
+                    return;
+                };
+                report(annotation, value, annotation.span, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reports `annotation` when it can be inferred from `init`, with a fix deleting `fix_span`
+fn report(annotation: &TSTypeAnnotation, init: &Expression, fix_span: Span, ctx: &LintContext) {
+    let Some(name) = inferrable_type(&annotation.type_annotation, init.without_parenthesized())
+    else {
+        return;
+    };
+    ctx.diagnostic_with_fix(NoInferrableTypesDiagnostic(name, annotation.span), || {
+        Fix::delete(fix_span)
+    });
+}
+
+/// The name of `ty` when `init` is trivially of that type
+fn inferrable_type(ty: &TSType, init: &Expression) -> Option<&'static str> {
+    let (inferrable, name) = match ty {
+        TSType::TSBigIntKeyword(_) => {
+            // `+` can not be applied to a bigint
+            let init = unary_argument(init, &[UnaryOperator::UnaryNegation]);
+            (
+                matches!(init, Expression::BigintLiteral(_)) || is_function_call(init, "BigInt"),
+                "bigint",
+            )
+        }
+        TSType::TSBooleanKeyword(_) => (
+            matches!(init, Expression::BooleanLiteral(_))
+                || is_function_call(init, "Boolean")
+                || matches!(init, Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot),
+            "boolean",
+        ),
+        TSType::TSNumberKeyword(_) => {
+            let init =
+                unary_argument(init, &[UnaryOperator::UnaryNegation, UnaryOperator::UnaryPlus]);
+            (
+                matches!(init, Expression::NumericLiteral(_))
+                    || is_function_call(init, "Number")
+                    || init.is_specific_id("Infinity")
+                    || init.is_specific_id("NaN"),
+                "number",
+            )
+        }
+        TSType::TSNullKeyword(_) => (init.is_null(), "null"),
+        // `null` in a type annotation is parsed as a literal type
+        TSType::TSLiteralType(literal) if matches!(literal.literal, TSLiteral::NullLiteral(_)) => {
+            (init.is_null(), "null")
+        }
+        TSType::TSStringKeyword(_) => (
+            matches!(init, Expression::StringLiteral(_) | Expression::TemplateLiteral(_))
+                || is_function_call(init, "String"),
+            "string",
+        ),
+        TSType::TSSymbolKeyword(_) => (is_function_call(init, "Symbol"), "symbol"),
+        TSType::TSUndefinedKeyword(_) => (init.is_undefined() || init.is_void(), "undefined"),
+        TSType::TSTypeReference(reference) => {
+            let TSTypeName::IdentifierReference(ident) = &reference.type_name else {
+                return None;
+            };
+            if ident.name != "RegExp" {
+                return None;
+            }
+            (
+                matches!(init, Expression::RegExpLiteral(_))
+                    || is_function_call(init, "RegExp")
+                    || matches!(init, Expression::NewExpression(expr) if expr.callee.is_specific_id("RegExp")),
+                "RegExp",
+            )
+        }
+        _ => return None,
+    };
+    inferrable.then_some(name)
+}
+
+/// The argument of `expr` when it is a unary expression with one of `operators`
+fn unary_argument<'a, 'b>(
+    expr: &'b Expression<'a>,
+    operators: &[UnaryOperator],
+) -> &'b Expression<'a> {
+    match expr {
+        Expression::UnaryExpression(unary) if operators.contains(&unary.operator) => {
+            &unary.argument
+        }
+        _ => expr,
+    }
+}
+
+fn is_function_call(expr: &Expression, name: &str) -> bool {
+    matches!(expr, Expression::CallExpression(call) if call.callee.is_specific_id(name))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("const a = 10n;", None),
+        ("const a = -10n;", None),
+        ("const a = BigInt(10);", None),
+        ("const a = false;", None),
+        ("const a = !0;", None),
+        ("const a = Boolean(null);", None),
+        ("const a = 10;", None),
+        ("const a = +10;", None),
+        ("const a = Infinity;", None),
+        ("const a = Number('1');", None),
+        ("const a = null;", None),
+        ("const a = /a/;", None),
+        ("const a = new RegExp('a');", None),
+        ("const a = 'str';", None),
+        ("const a = `str`;", None),
+        ("const a = Symbol('a');", None),
+        ("const a = undefined;", None),
+        ("const a = void someValue;", None),
+        ("const fn = (a = 5, b = true, c = 'foo') => {};", None),
+        ("const fn = function (a = 5, b = true, c = 'foo') {};", None),
+        ("function fn(a = 5, b = true, c = 'foo') {}", None),
+        ("function fn(a: number, b: boolean, c: string) {}", None),
+        ("class Foo { a = 5; b = true; c = 'foo'; }", None),
+        ("class Foo { readonly a: number = 5; }", None),
+        ("class Foo { a?: number = 5; }", None),
+        ("const a: any = 5;", None),
+        ("const fn = function (a: any = 5, b: any = true, c: any = 'foo') {};", None),
+        ("const a: string = 5;", None),
+        ("const a: number = 'str';", None),
+        ("const a: bigint = +10n;", None),
+        ("let a: number;", None),
+        ("const a: Foo.RegExp = /a/;", None),
+        (
+            "const fn = (a: number = 5, b: boolean = true) => {};",
+            Some(json!([{ "ignoreParameters": true }])),
+        ),
+        ("class Foo { a: number = 5; }", Some(json!([{ "ignoreProperties": true }]))),
+    ];
+
+    let fail = vec![
+        ("const a: bigint = 10n;", None),
+        ("const a: bigint = -10n;", None),
+        ("const a: bigint = BigInt(10);", None),
+        ("const a: boolean = false;", None),
+        ("const a: boolean = !0;", None),
+        ("const a: boolean = Boolean(null);", None),
+        ("const a: number = 10;", None),
+        ("const a: number = +10;", None),
+        ("const a: number = -Infinity;", None),
+        ("const a: number = NaN;", None),
+        ("const a: number = Number('1');", None),
+        ("const a: number = (10);", None),
+        ("const a: null = null;", None),
+        ("const a: RegExp = /a/;", None),
+        ("const a: RegExp = RegExp('a');", None),
+        ("const a: RegExp = new RegExp('a');", None),
+        ("const a: string = 'str';", None),
+        ("const a: string = `str${b}`;", None),
+        ("const a: string = String(1);", None),
+        ("const a: symbol = Symbol('a');", None),
+        ("const a: undefined = undefined;", None),
+        ("const a: undefined = void someValue;", None),
+        ("let a: number = 10;", None),
+        ("const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};", None),
+        ("function fn(a?: number = 5) {}", None),
+        ("class Foo { constructor(public a: number = 5) {} }", None),
+        ("class Foo { a: number = 5; }", None),
+        ("class Foo { static a: boolean = true; }", None),
+        ("class Foo { a: number = 5; }", Some(json!([{ "ignoreParameters": true }]))),
+        ("function fn(a: number = 5) {}", Some(json!([{ "ignoreProperties": true }]))),
+    ];
+
+    let fix = vec![
+        ("const a: number = 10;", "const a = 10;", None),
+        ("const a : string = 'str';", "const a  = 'str';", None),
+        (
+            "const fn = (a: number = 5, b: boolean = true) => {};",
+            "const fn = (a = 5, b = true) => {};",
+            None,
+        ),
+        ("function fn(a?: number = 5) {}", "function fn(a = 5) {}", None),
+        (
+            "class Foo { constructor(public a: number = 5) {} }",
+            "class Foo { constructor(public a = 5) {} }",
+            None,
+        ),
+        ("class Foo { a: RegExp = /a/; }", "class Foo { a = /a/; }", None),
+    ];
+
+    Tester::new(NoInferrableTypes::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
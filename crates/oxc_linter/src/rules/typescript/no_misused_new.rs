@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ClassElement, PropertyKey, TSSignature, TSType, TSTypeName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -43,20 +43,24 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```typescript
-    // declare class C {
-    //   new(): C;
-    // }
-
-    // interface I {
-    //   new (): I;
-    //   constructor(): void;
-    // }
+    /// declare class C {
+    ///   new(): C;
+    /// }
+    ///
+    /// interface I {
+    ///   new (): I;
+    ///   constructor(): void;
+    /// }
     /// ```
     NoMisusedNew,
     correctness
 );
 
 impl Rule for NoMisusedNew {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TSInterfaceDeclaration, AstType::TSMethodSignature, AstType::Class])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::TSInterfaceDeclaration(interface_decl) => {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ModifierKind, TSModuleDeclarationName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -61,6 +61,10 @@ impl Rule for NoNamespace {
         }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TSModuleDeclaration])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSModuleDeclaration(declaration) = node.kind() else { return };
         let TSModuleDeclarationName::Identifier(ident) = &declaration.id else { return };
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ChainElement, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -47,6 +47,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNonNullAssertedOptionalChain {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TSNonNullExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSNonNullExpression(non_null_expr) = node.kind() {
             let chain_span = match non_null_expr.expression.get_inner_expression() {
@@ -0,0 +1,206 @@
+use oxc_ast::{
+    ast::{Argument, Expression, TSModuleReference},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{ast_util::is_global_reference, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(no-require-imports): A `require()` style import is forbidden.")]
+#[diagnostic(severity(warning), help("Use ES6 style imports instead."))]
+struct NoRequireImportsDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRequireImports(Box<NoRequireImportsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRequireImportsConfig {
+    /// Patterns of import paths which may be required, e.g. `/package\.json$`
+    allow: Vec<Regex>,
+    /// Allow `import foo = require('foo')`
+    allow_as_import: bool,
+}
+
+impl std::ops::Deref for NoRequireImports {
+    type Target = NoRequireImportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows invocation of `require()`.
+    ///
+    /// ### Why is this bad?
+    /// `require()` is the CommonJS way of importing modules. With ES modules,
+    /// `import` statements are preferred as they can be statically analyzed.
+    ///
+    /// ### Options
+    /// * `allow`: patterns of import paths which may be required.
+    /// * `allowAsImport`: allow `import foo = require('foo')`.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// const lib1 = require('lib1');
+    /// const { lib2 } = require('lib2');
+    /// import lib3 = require('lib3');
+    /// ```
+    NoRequireImports,
+    restriction
+);
+
+impl Rule for NoRequireImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self(Box::new(NoRequireImportsConfig {
+            allow: config
+                .and_then(|config| config.get("allow"))
+                .and_then(serde_json::Value::as_array)
+                .map(|allow| {
+                    allow
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .filter_map(|pattern| Regex::new(pattern).ok())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            allow_as_import: config
+                .and_then(|config| config.get("allowAsImport"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression, AstType::TSImportEqualsDeclaration])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !ctx.source_type().is_typescript() {
+            return;
+        }
+        match node.kind() {
+            AstKind::CallExpression(call) => {
+                let Expression::Identifier(callee) = &call.callee else { return };
+                // A local `require`, e.g. one from `createRequire`, is not CommonJS
+                if callee.name != "require" || !is_global_reference(callee, ctx) {
+                    return;
+                }
+                let path = match call.arguments.first() {
+                    Some(Argument::Expression(Expression::StringLiteral(lit))) => {
+                        Some(lit.value.as_str())
+                    }
+                    Some(Argument::Expression(Expression::TemplateLiteral(lit)))
+                        if lit.is_no_substitution_template() =>
+                    {
+                        lit.quasi().map(oxc_span::Atom::as_str)
+                    }
+                    _ => None,
+                };
+                if path.is_some_and(|path| self.is_allowed(path)) {
+                    return;
+                }
+                ctx.diagnostic(NoRequireImportsDiagnostic(call.span));
+            }
+            AstKind::TSImportEqualsDeclaration(decl) => {
+                let TSModuleReference::ExternalModuleReference(reference) = &*decl.module_reference
+                else {
+                    return;
+                };
+                if self.allow_as_import || self.is_allowed(&reference.expression.value) {
+                    return;
+                }
+                ctx.diagnostic(NoRequireImportsDiagnostic(reference.span));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoRequireImports {
+    fn is_allowed(&self, path: &str) -> bool {
+        self.allow.iter().any(|pattern| pattern.is_match(path))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import { l } from 'lib';", None),
+        ("var lib3 = load('not_an_import');", None),
+        ("var lib4 = lib2.subImport;", None),
+        ("var lib7 = 700;", None),
+        ("import lib9 = lib2.anotherSubImport;", None),
+        ("import lib10 from 'lib10';", None),
+        ("var lib3 = load?.('not_an_import');", None),
+        (
+            "
+                import { createRequire } from 'module';
+                const require = createRequire();
+                require('remark-preset-prettier');
+            ",
+            None,
+        ),
+        (
+            "const pkg = require('./package.json');",
+            Some(json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        (
+            "const pkg = require('../package.json');",
+            Some(json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        (
+            "const pkg = require(`./package.json`);",
+            Some(json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        (
+            "import pkg = require('./package.json');",
+            Some(json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        ("import foo = require('foo');", Some(json!([{ "allowAsImport": true }]))),
+        (
+            "
+                let require = bazz;
+                import foo = bar;
+            ",
+            Some(json!([{ "allowAsImport": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var lib = require('lib');", None),
+        ("let lib2 = require('lib2');", None),
+        ("var lib5 = require('lib5'), lib6 = require('lib6');", None),
+        ("import lib8 = require('lib8');", None),
+        ("var lib = require?.('lib');", None),
+        ("let lib2 = require?.('lib2');", None),
+        ("require('side-effect');", None),
+        (
+            "const pkg = require('./package.jsonc');",
+            Some(json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        (
+            "const pkg = require(`./package.json${suffix}`);",
+            Some(json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        (
+            "import pkg = require('./package.jsonc');",
+            Some(json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        ("var foo = require('foo');", Some(json!([{ "allowAsImport": true }]))),
+    ];
+
+    Tester::new(NoRequireImports::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::TSType, AstKind};
+use oxc_ast::{ast::TSType, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -6,7 +6,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
@@ -50,6 +50,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnnecessaryTypeConstraint {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::TSTypeParameterDeclaration])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSTypeParameterDeclaration(decl) = node.kind() {
             for param in &decl.params {
@@ -59,12 +63,30 @@ impl Rule for NoUnnecessaryTypeConstraint {
                         TSType::TSUnknownKeyword(t) => ("unknown", t.span),
                         _ => continue,
                     };
-                    ctx.diagnostic(NoUnnecessaryTypeConstraintDiagnostic(
-                        param.name.name.to_compact_string(),
-                        value,
-                        param.name.span,
-                        ty_span,
-                    ));
+                    let constraint_span = Span::new(param.name.span.end, ty_span.end);
+                    // `<T>() => {}` is parsed as a JSX element in `.tsx` files, keep it as `<T,>`
+                    let needs_comma = decl.params.len() == 1
+                        && ctx.source_type().is_jsx()
+                        && matches!(
+                            ctx.nodes().parent_kind(node.id()),
+                            Some(AstKind::ArrowFunctionExpression(_))
+                        )
+                        && ctx.source_text()[ty_span.end as usize..].trim_start().starts_with('>');
+                    ctx.diagnostic_with_fix(
+                        NoUnnecessaryTypeConstraintDiagnostic(
+                            param.name.name.to_compact_string(),
+                            value,
+                            param.name.span,
+                            ty_span,
+                        ),
+                        || {
+                            if needs_comma {
+                                Fix::new(",", constraint_span)
+                            } else {
+                                Fix::delete(constraint_span)
+                            }
+                        },
+                    );
                 }
             }
         }
@@ -113,5 +135,26 @@ fn test() {
         "type Data<T extends unknown> = {};",
     ];
 
-    Tester::new(NoUnnecessaryTypeConstraint::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("function data<T extends any>() {}", "function data<T>() {}", None),
+        ("function data<T extends any, U>() {}", "function data<T, U>() {}", None),
+        ("function data<T, U extends unknown>() {}", "function data<T, U>() {}", None),
+        ("const data = <T extends any>() => {};", "const data = <T,>() => {};", None),
+        ("const data = <T extends any,>() => {};", "const data = <T,>() => {};", None),
+        (
+            "const data = <T extends any = unknown>() => {};",
+            "const data = <T = unknown>() => {};",
+            None,
+        ),
+        (
+            "const data = <T extends any, U extends any>() => {};",
+            "const data = <T, U>() => {};",
+            None,
+        ),
+        ("class Data<T extends unknown> {}", "class Data<T> {}", None),
+        ("interface Data<T extends unknown> {}", "interface Data<T> {}", None),
+        ("type Data<T extends unknown> = {};", "type Data<T> = {};", None),
+    ];
+
+    Tester::new(NoUnnecessaryTypeConstraint::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -1,5 +1,5 @@
 use oxc_ast::ast::{Expression, TSLiteral, TSType};
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -40,6 +40,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferAsConst {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::VariableDeclarator, AstType::PropertyDefinition, AstType::TSAsExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::VariableDeclarator(variable_declarator) => {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ExportDefaultDeclarationKind, Expression, TSInterfaceDeclaration, TSSignature, TSType},
-    AstKind, CommentKind,
+    AstKind, AstType, CommentKind,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -344,6 +344,15 @@ fn check_member(member: &TSSignature, node: &AstNode<'_>, ctx: &LintContext<'_>)
 }
 
 impl Rule for PreferFunctionType {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::TSInterfaceDeclaration,
+            AstType::ExportDefaultDeclaration,
+            AstType::TSTypeAnnotation,
+            AstType::TSTypeAliasDeclaration,
+        ])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::TSInterfaceDeclaration(decl) => {
@@ -1,4 +1,4 @@
-use oxc_ast::Comment;
+use oxc_ast::{AstType, Comment};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -50,6 +50,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferTsExpectError {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let comments = ctx.semantic().trivias().comments();
 
@@ -0,0 +1,388 @@
+use oxc_ast::{
+    ast::{BindingPattern, Expression, TSLiteral, TSType, TSTypeName, TemplateLiteral},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{
+    ast_util::{get_declaration_of_variable, is_global_reference},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(switch-exhaustiveness-check): Switch is not exhaustive. Cases not matched: {0}")]
+#[diagnostic(severity(warning), help("Add the missing cases, or a `default` case."))]
+struct SwitchExhaustivenessCheckDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct SwitchExhaustivenessCheck;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require switch statements over a union of literal types to handle every member of the
+    /// union.
+    ///
+    /// Unlike the rule of typescript-eslint, this rule does not use type information. It only
+    /// checks switches over a variable or parameter whose type annotation is a union of literal
+    /// types, `boolean`, `null` and `undefined`, written directly or as a type alias declared in
+    /// the same file. Switches with a `default` case, or with cases which are not literals,
+    /// are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When a member is added to a union, the switches which do not handle it silently do
+    /// nothing for it.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// type Day = 'Monday' | 'Tuesday' | 'Wednesday';
+    ///
+    /// function isWeekStart(day: Day) {
+    ///   switch (day) {
+    ///     case 'Monday':
+    ///       return true;
+    ///     case 'Tuesday':
+    ///       return false;
+    ///   }
+    /// }
+    /// ```
+    SwitchExhaustivenessCheck,
+    nursery
+);
+
+/// The value of a literal type, or of a case test
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LiteralValue<'a> {
+    String(&'a str),
+    Number(f64),
+    BigInt(&'a str),
+    Boolean(bool),
+    Null,
+    Undefined,
+}
+
+impl LiteralValue<'_> {
+    fn to_type_string(self) -> String {
+        match self {
+            Self::String(value) => format!("\"{value}\""),
+            Self::Number(value) => value.to_string(),
+            Self::BigInt(raw) => raw.to_string(),
+            Self::Boolean(value) => value.to_string(),
+            Self::Null => "null".to_string(),
+            Self::Undefined => "undefined".to_string(),
+        }
+    }
+}
+
+/// Type aliases referring to each other are followed up to this depth
+const MAX_ALIAS_DEPTH: usize = 8;
+
+impl Rule for SwitchExhaustivenessCheck {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::SwitchStatement])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SwitchStatement(switch) = node.kind() else { return };
+        let Expression::Identifier(ident) = switch.discriminant.without_parenthesized() else {
+            return;
+        };
+        let mut cases = vec![];
+        for case in &switch.cases {
+            // A `default` case handles the missing members
+            let Some(test) = &case.test else { return };
+            let Some(value) = expression_value(test, ctx) else { return };
+            cases.push(value);
+        }
+
+        let Some(declaration) = get_declaration_of_variable(ident, ctx) else { return };
+        let pattern = match declaration.kind() {
+            AstKind::VariableDeclarator(declarator) => &declarator.id,
+            AstKind::FormalParameter(param) => &param.pattern,
+            _ => return,
+        };
+        let Some(members) = pattern_members(pattern, ctx) else { return };
+
+        let mut missing: Vec<LiteralValue> = vec![];
+        for member in members {
+            if !cases.contains(&member) && !missing.contains(&member) {
+                missing.push(member);
+            }
+        }
+        if missing.is_empty() {
+            return;
+        }
+        let missing =
+            missing.into_iter().map(LiteralValue::to_type_string).collect::<Vec<_>>().join(" | ");
+        ctx.diagnostic(SwitchExhaustivenessCheckDiagnostic(missing, switch.discriminant.span()));
+    }
+}
+
+/// The members of the union type annotating a variable or parameter
+fn pattern_members<'a>(
+    pattern: &'a BindingPattern<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Vec<LiteralValue<'a>>> {
+    let annotation = pattern.type_annotation.as_ref()?;
+    let mut members = vec![];
+    collect_members(&annotation.type_annotation, 0, &mut members, ctx)?;
+    // Optional parameters can be `undefined`
+    if pattern.optional {
+        members.push(LiteralValue::Undefined);
+    }
+    Some(members)
+}
+
+/// Collect the values of a union of literal types, returning `None` for any other type
+fn collect_members<'a>(
+    ts_type: &'a TSType<'a>,
+    depth: usize,
+    members: &mut Vec<LiteralValue<'a>>,
+    ctx: &LintContext<'a>,
+) -> Option<()> {
+    match ts_type {
+        TSType::TSUnionType(union) => {
+            for ts_type in &union.types {
+                collect_members(ts_type, depth, members, ctx)?;
+            }
+        }
+        TSType::TSLiteralType(literal) => members.push(literal_type_value(&literal.literal)?),
+        TSType::TSBooleanKeyword(_) => {
+            members.extend([LiteralValue::Boolean(true), LiteralValue::Boolean(false)]);
+        }
+        TSType::TSNullKeyword(_) => members.push(LiteralValue::Null),
+        TSType::TSUndefinedKeyword(_) => members.push(LiteralValue::Undefined),
+        TSType::TSTypeReference(reference)
+            if reference.type_parameters.is_none() && depth < MAX_ALIAS_DEPTH =>
+        {
+            let TSTypeName::IdentifierReference(ident) = &reference.type_name else { return None };
+            let declaration = get_declaration_of_variable(ident, ctx)?;
+            let AstKind::TSTypeAliasDeclaration(alias) = declaration.kind() else { return None };
+            if alias.type_parameters.is_some() {
+                return None;
+            }
+            collect_members(&alias.type_annotation, depth + 1, members, ctx)?;
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+fn literal_type_value<'a>(literal: &'a TSLiteral<'a>) -> Option<LiteralValue<'a>> {
+    match literal {
+        TSLiteral::StringLiteral(string) => Some(LiteralValue::String(string.value.as_str())),
+        TSLiteral::NumericLiteral(number) => Some(LiteralValue::Number(number.value)),
+        TSLiteral::BigintLiteral(bigint) => Some(LiteralValue::BigInt(bigint.raw.as_str())),
+        TSLiteral::BooleanLiteral(boolean) => Some(LiteralValue::Boolean(boolean.value)),
+        TSLiteral::NullLiteral(_) => Some(LiteralValue::Null),
+        TSLiteral::TemplateLiteral(template) => template_value(template),
+        TSLiteral::UnaryExpression(unary) => negative_number(unary.operator, &unary.argument),
+        TSLiteral::RegExpLiteral(_) => None,
+    }
+}
+
+/// The value of a case test, for the literals which literal types can have
+fn expression_value<'a>(
+    expr: &'a Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<LiteralValue<'a>> {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(string) => Some(LiteralValue::String(string.value.as_str())),
+        Expression::NumericLiteral(number) => Some(LiteralValue::Number(number.value)),
+        Expression::BigintLiteral(bigint) => Some(LiteralValue::BigInt(bigint.raw.as_str())),
+        Expression::BooleanLiteral(boolean) => Some(LiteralValue::Boolean(boolean.value)),
+        Expression::NullLiteral(_) => Some(LiteralValue::Null),
+        Expression::TemplateLiteral(template) => template_value(template),
+        Expression::UnaryExpression(unary) => negative_number(unary.operator, &unary.argument),
+        Expression::Identifier(ident)
+            if ident.name == "undefined" && is_global_reference(ident, ctx) =>
+        {
+            Some(LiteralValue::Undefined)
+        }
+        _ => None,
+    }
+}
+
+fn template_value<'a>(template: &'a TemplateLiteral<'a>) -> Option<LiteralValue<'a>> {
+    if !template.is_no_substitution_template() {
+        return None;
+    }
+    template.quasi().map(|quasi| LiteralValue::String(quasi.as_str()))
+}
+
+fn negative_number(
+    operator: UnaryOperator,
+    argument: &Expression,
+) -> Option<LiteralValue<'static>> {
+    match (operator, argument) {
+        (UnaryOperator::UnaryNegation, Expression::NumericLiteral(number)) => {
+            Some(LiteralValue::Number(-number.value))
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "type Day = 'Monday' | 'Tuesday';
+        function f(day: Day) {
+            switch (day) {
+                case 'Monday': return 1;
+                case 'Tuesday': return 2;
+            }
+        }",
+        "function f(day: 'Monday' | 'Tuesday') {
+            switch (day) {
+                case 'Monday':
+                case 'Tuesday':
+                    return 1;
+            }
+        }",
+        "function f(day: 'Monday' | 'Tuesday') {
+            switch (day) {
+                case 'Monday': return 1;
+                default: return 2;
+            }
+        }",
+        "function f(value: string) {
+            switch (value) {
+                case 'a': return 1;
+            }
+        }",
+        "function f(value: 'a' | 'b' | string) {
+            switch (value) {
+                case 'a': return 1;
+            }
+        }",
+        "const A = 'a';
+        function f(value: 'a' | 'b') {
+            switch (value) {
+                case A: return 1;
+            }
+        }",
+        "function f(value) {
+            switch (value) {
+                case 'a': return 1;
+            }
+        }",
+        "function f(value: 'a' | 'b') {
+            switch (value.length) {
+                case 1: return 1;
+            }
+        }",
+        "type A = 'a' | B;
+        type B = 'b' | 'c';
+        declare const value: A;
+        switch (value) {
+            case 'a':
+            case 'b':
+            case 'c':
+                break;
+        }",
+        "function f(value: 1 | -1 | 2n | true | null) {
+            switch (value) {
+                case 1:
+                case -1:
+                case 2n:
+                case true:
+                case null:
+                    break;
+            }
+        }",
+        "function f(value: boolean | undefined) {
+            switch (value) {
+                case true:
+                case false:
+                case undefined:
+                    break;
+            }
+        }",
+        "function f(value: `a` | 'b') {
+            switch (value) {
+                case 'a':
+                case `b`:
+                    break;
+            }
+        }",
+        "type T<U> = 'a' | U;
+        function f(value: T<'b'>) {
+            switch (value) {
+                case 'a': break;
+            }
+        }",
+        "enum E { A, B }
+        function f(value: E) {
+            switch (value) {
+                case E.A: break;
+            }
+        }",
+        "function f(value: 'a' | undefined, undefined) {
+            switch (value) {
+                case 'a':
+                case undefined:
+                    break;
+            }
+        }",
+    ];
+
+    let fail = vec![
+        "type Day = 'Monday' | 'Tuesday' | 'Wednesday';
+        function f(day: Day) {
+            switch (day) {
+                case 'Monday': return 1;
+            }
+        }",
+        "function f(day: 'Monday' | 'Tuesday') {
+            switch (day) {
+                case 'Monday': return 1;
+            }
+        }",
+        "let value: 'a' | 'b' = 'a';
+        switch (value) {
+            case 'b': break;
+        }",
+        "function f(value: 'a' | 'b') {
+            switch (value) {}
+        }",
+        "type A = 'a' | B;
+        type B = 'b' | 'c';
+        declare const value: A;
+        switch (value) {
+            case 'a': break;
+        }",
+        "function f(value: 1 | -1 | 2n | true | null) {
+            switch (value) {
+                case 1: break;
+            }
+        }",
+        "function f(value: boolean) {
+            switch (value) {
+                case true: break;
+            }
+        }",
+        "function f(value?: 'a') {
+            switch (value) {
+                case 'a': break;
+            }
+        }",
+        "function f(value: 'a' | 'b' | 'a') {
+            switch ((value)) {
+                case 'c': break;
+            }
+        }",
+    ];
+
+    Tester::new(SwitchExhaustivenessCheck::NAME, pass, fail).test_and_snapshot();
+}
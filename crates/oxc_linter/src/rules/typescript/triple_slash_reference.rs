@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use oxc_ast::{
     ast::{Declaration, ModuleDeclaration, Statement, TSModuleReference},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -102,6 +102,10 @@ impl Rule for TripleSlashReference {
                 }),
         }))
     }
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let Some(root) = ctx.nodes().iter().next() else { return };
         let AstKind::Program(program) = root.kind() else { return };
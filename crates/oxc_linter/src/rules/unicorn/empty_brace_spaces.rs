@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -34,6 +34,17 @@ declare_oxc_lint!(
 );
 
 impl Rule for EmptyBraceSpaces {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::StaticBlock,
+            AstType::ObjectExpression,
+            AstType::FunctionBody,
+            AstType::Class,
+            AstType::BlockStatement,
+            AstType::CatchClause,
+        ])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::StaticBlock(static_block) => {
@@ -2,7 +2,7 @@ use std::str::Chars;
 
 use oxc_ast::{
     ast::{StringLiteral, TemplateLiteral},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -123,6 +123,10 @@ fn check_case(value: &str, is_regex: bool) -> Option<String> {
     }
 }
 impl Rule for EscapeCase {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::StringLiteral, AstType::TemplateLiteral, AstType::RegExpLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::StringLiteral(StringLiteral { span, .. }) => {
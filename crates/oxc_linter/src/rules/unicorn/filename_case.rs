@@ -1,4 +1,5 @@
 use convert_case::{Case, Casing};
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -95,6 +96,10 @@ impl Rule for FilenameCase {
         Self::default()
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once<'a>(&self, ctx: &LintContext<'_>) {
         let Some(filename) = ctx.file_path().file_stem().and_then(|s| s.to_str()) else { return };
 
@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -47,6 +48,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAbusiveEslintDisable {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for span in ctx.disable_directives().disable_all_comments() {
             ctx.diagnostic(NoAbusiveEslintDisableDiagnostic(*span));
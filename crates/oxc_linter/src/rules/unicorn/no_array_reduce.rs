@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, CallExpression, Expression, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -59,6 +59,10 @@ impl Rule for NoArrayReduce {
         Self { allow_simple_operations }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -39,6 +39,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAwaitExpressionMember {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::MemberExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MemberExpression(member_expr) = node.kind() else { return };
 
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{AssignmentTarget, Expression, SimpleAssignmentTarget},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -54,6 +54,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDocumentCookie {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::AssignmentExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::AssignmentExpression(assignment_expr) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{StringLiteral, TemplateLiteral},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -70,6 +70,10 @@ fn check_escape(value: &str) -> Option<String> {
 }
 
 impl Rule for NoHexEscape {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::StringLiteral, AstType::TemplateLiteral, AstType::RegExpLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::StringLiteral(StringLiteral { span, .. }) => {
@@ -1,5 +1,5 @@
 use oxc_ast::ast::Expression;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -35,6 +35,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoInstanceofArray {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(expr) = node.kind() else { return };
         if expr.operator != BinaryOperator::Instanceof {
@@ -43,7 +47,7 @@ impl Rule for NoInstanceofArray {
 
         match &expr.right.without_parenthesized() {
             Expression::Identifier(identifier) if identifier.name == "Array" => {
-                ctx.diagnostic_with_fix(NoInstanceofArrayDiagnostic(expr.span), || {
+                ctx.diagnostic_with_suggestion(NoInstanceofArrayDiagnostic(expr.span), || {
                     let modified_code = {
                         let mut codegen = String::new();
                         codegen.push_str("Array.isArray(");
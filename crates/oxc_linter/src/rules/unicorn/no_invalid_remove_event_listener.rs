@@ -1,7 +1,7 @@
 use oxc_ast::ast::Expression;
 use oxc_ast::{
     ast::{Argument, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -49,6 +49,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoInvalidRemoveEventListener {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
 
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -44,6 +44,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoLonelyIf {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::IfStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::IfStatement(if_stmt) = node.kind() else { return };
 
@@ -208,5 +212,5 @@ fn test() {
     ",
     ];
 
-    Tester::new(NoLonelyIf::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoLonelyIf::NAME, pass, fail).with_plugin_name("unicorn").test_and_snapshot();
 }
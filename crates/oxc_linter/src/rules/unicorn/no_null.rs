@@ -2,7 +2,7 @@ use oxc_ast::{
     ast::{
         Argument, BinaryExpression, CallExpression, Expression, NullLiteral, VariableDeclarator,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -89,7 +89,7 @@ fn diagnose_binary_expression(
     }
 
     // checkStrictEquality=true && `if (foo !== null) {}`
-    ctx.diagnostic_with_fix(ReplaceNullDiagnostic(null_literal.span), || {
+    ctx.diagnostic_with_suggestion(ReplaceNullDiagnostic(null_literal.span), || {
         Fix::new("undefined", null_literal.span)
     });
 }
@@ -104,7 +104,7 @@ fn diagnose_variable_declarator(
     if matches!(&variable_declarator.init, Some(Expression::NullLiteral(expr)) if expr.span == null_literal.span)
         && matches!(parent_kind, Some(AstKind::VariableDeclaration(var_declaration)) if !var_declaration.kind.is_const() )
     {
-        ctx.diagnostic_with_fix(RemoveNullDiagnostic(null_literal.span), || {
+        ctx.diagnostic_with_suggestion(RemoveNullDiagnostic(null_literal.span), || {
             Fix::delete(Span {
                 start: variable_declarator.id.span().end,
                 end: null_literal.span.end,
@@ -115,7 +115,7 @@ fn diagnose_variable_declarator(
     }
 
     // `const foo = null`
-    ctx.diagnostic_with_fix(ReplaceNullDiagnostic(null_literal.span), || {
+    ctx.diagnostic_with_suggestion(ReplaceNullDiagnostic(null_literal.span), || {
         Fix::new("undefined", null_literal.span)
     });
 }
@@ -170,6 +170,10 @@ impl Rule for NoNull {
         }
     }
 
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NullLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NullLiteral(null_literal) = node.kind() else {
             return;
@@ -204,7 +208,7 @@ impl Rule for NoNull {
 
             // `function foo() { return null; }`,
             if matches!(parent_node.kind(), AstKind::ReturnStatement(_)) {
-                ctx.diagnostic_with_fix(RemoveNullDiagnostic(null_literal.span), || {
+                ctx.diagnostic_with_suggestion(RemoveNullDiagnostic(null_literal.span), || {
                     Fix::delete(null_literal.span)
                 });
 
@@ -212,7 +216,7 @@ impl Rule for NoNull {
             }
         }
 
-        ctx.diagnostic_with_fix(ReplaceNullDiagnostic(null_literal.span), || {
+        ctx.diagnostic_with_suggestion(ReplaceNullDiagnostic(null_literal.span), || {
             Fix::new("undefined", null_literal.span)
         });
     }
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingPatternKind, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -46,6 +46,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoObjectAsDefaultParameter {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::AssignmentPattern])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::AssignmentPattern(assignment_pat) = node.kind() else { return };
 
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -42,6 +42,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoProcessExit {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::CallExpression(expr) = node.kind() {
             if is_method_call(expr, Some(&["process"]), Some(&["exit"]), None, None) {
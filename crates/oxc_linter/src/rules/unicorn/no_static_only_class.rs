@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -50,6 +50,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoStaticOnlyClass {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::Class])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::Class(class) = node.kind() else { return };
 
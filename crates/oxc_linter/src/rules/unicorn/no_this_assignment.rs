@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{AssignmentTarget, BindingPatternKind, Expression, SimpleAssignmentTarget},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -60,6 +60,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoThisAssignment {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::VariableDeclarator, AstType::AssignmentExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::VariableDeclarator(variable_decl) => {
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -32,6 +32,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnnecessaryAwait {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::AwaitExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::AwaitExpression(expr) = node.kind() {
             if !not_promise(&expr.argument) {
@@ -60,7 +64,7 @@ impl Rule for NoUnnecessaryAwait {
                     expr.span.start + 5,
                 )));
             } else {
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_suggestion(
                     NoUnnecessaryAwaitDiagnostic(Span::new(expr.span.start, expr.span.start + 5)),
                     || {
                         let mut codegen = String::new();
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -52,6 +52,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnreadableIife {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
 
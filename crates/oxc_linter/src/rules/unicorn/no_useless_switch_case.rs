@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -50,6 +50,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUselessSwitchCase {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::SwitchStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::SwitchStatement(switch_statement) = node.kind() else {
             return;
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -48,6 +48,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoZeroFractions {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NumericLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NumericLiteral(number_literal) = node.kind() else {
             return;
@@ -1,7 +1,7 @@
 use lazy_static::lazy_static;
 use oxc_ast::{
     ast::{BigintLiteral, NumericLiteral},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -88,6 +88,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NumericSeparatorsStyle {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::NumericLiteral, AstType::BigintLiteral])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::NumericLiteral(number) => {
@@ -7,7 +7,7 @@ use crate::{
 };
 use oxc_ast::{
     ast::{Argument, CallExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -52,6 +52,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferArraySome {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression, AstType::BinaryExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(call_expr) => {
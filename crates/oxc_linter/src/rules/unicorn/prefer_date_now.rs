@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -57,6 +57,15 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferDateNow {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::CallExpression,
+            AstType::UnaryExpression,
+            AstType::AssignmentExpression,
+            AstType::BinaryExpression,
+        ])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(call_expr) => {
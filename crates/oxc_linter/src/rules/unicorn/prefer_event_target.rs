@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -42,6 +42,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferEventTarget {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::IdentifierReference])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::IdentifierReference(ident) = node.kind() else { return };
 
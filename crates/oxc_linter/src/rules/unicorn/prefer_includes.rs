@@ -1,23 +1,32 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{
+    ast::{BinaryExpression, CallExpression, Expression},
+    AstKind, AstType,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
 use crate::{
     ast_util::{call_expr_method_callee_info, is_method_call},
     context::LintContext,
     rule::Rule,
-    AstNode,
+    utils::{fix_regex_test, get_regex_literal_pattern},
+    AstNode, Fix,
 };
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint-plugin-unicorn(prefer-includes): Prefer `includes()` over `indexOf()` when checking for existence or non-existence.")]
-#[diagnostic(severity(warning))]
-struct PreferIncludesDiagnostic(#[label] pub Span);
+enum PreferIncludesDiagnostic {
+    #[error("eslint-plugin-unicorn(prefer-includes): Prefer `includes()` over `indexOf()` when checking for existence or non-existence.")]
+    #[diagnostic(severity(warning))]
+    IndexOf(#[label] Span),
+    #[error("eslint-plugin-unicorn(prefer-includes): Prefer `String#includes()` over a regex matching a fixed string.")]
+    #[diagnostic(severity(warning))]
+    RegExpTest(#[label] Span),
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct PreferIncludes;
@@ -25,7 +34,8 @@ pub struct PreferIncludes;
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Prefer `includes()` over `indexOf()` when checking for existence or non-existence.
+    /// Prefer `includes()` over `indexOf()` when checking for existence or non-existence,
+    /// and over `RegExp#test()` with a regex which matches a fixed string.
     ///
     /// All built-ins have `.includes()` in addition to `.indexOf()`.
     ///
@@ -33,10 +43,14 @@ declare_oxc_lint!(
     ///
     /// The `.includes()` method is more readable and less error-prone than `.indexOf()`.
     ///
+    /// The fix of `indexOf()` is a suggestion, since `includes()` finds `NaN` in arrays and
+    /// `indexOf()` does not.
+    ///
     /// ### Example
     /// ```javascript
     /// // bad
     /// if (str.indexOf('foo') !== -1) { }
+    /// if (/foo/.test(str)) { }
     ///
     /// // good
     /// if (str.includes('foo')) { }
@@ -46,50 +60,85 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferIncludes {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::BinaryExpression(bin_expr) = node.kind() else {
-            return;
-        };
-
-        let Expression::CallExpression(left_call_expr) = &bin_expr.left.without_parenthesized()
-        else {
-            return;
-        };
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::BinaryExpression, AstType::CallExpression])
+    }
 
-        if !is_method_call(left_call_expr, None, Some(&["indexOf"]), None, Some(2)) {
-            return;
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::BinaryExpression(bin_expr) => check_index_of(bin_expr, ctx),
+            AstKind::CallExpression(call_expr) => check_regex_test(call_expr, ctx),
+            _ => {}
         }
+    }
+}
 
-        if matches!(
-            bin_expr.operator,
-            BinaryOperator::StrictInequality
-                | BinaryOperator::Inequality
-                | BinaryOperator::GreaterThan
-                | BinaryOperator::StrictEquality
-                | BinaryOperator::Equality
-        ) {
-            if !is_negative_one(bin_expr.right.without_parenthesized()) {
-                return;
-            }
+fn check_index_of<'a>(bin_expr: &'a BinaryExpression<'a>, ctx: &LintContext<'a>) {
+    let Expression::CallExpression(left_call_expr) = &bin_expr.left.without_parenthesized() else {
+        return;
+    };
 
-            ctx.diagnostic(PreferIncludesDiagnostic(
-                call_expr_method_callee_info(left_call_expr).unwrap().0,
-            ));
-        }
+    if !is_method_call(left_call_expr, None, Some(&["indexOf"]), None, Some(2)) {
+        return;
+    }
 
-        if matches!(bin_expr.operator, BinaryOperator::GreaterEqualThan | BinaryOperator::LessThan)
+    let negated = match bin_expr.operator {
+        BinaryOperator::StrictInequality
+        | BinaryOperator::Inequality
+        | BinaryOperator::GreaterThan
+            if is_negative_one(bin_expr.right.without_parenthesized()) =>
         {
+            false
+        }
+        BinaryOperator::StrictEquality | BinaryOperator::Equality
+            if is_negative_one(bin_expr.right.without_parenthesized()) =>
+        {
+            true
+        }
+        BinaryOperator::GreaterEqualThan | BinaryOperator::LessThan => {
             let Expression::NumericLiteral(num_lit) = bin_expr.right.without_parenthesized() else {
                 return;
             };
-
             if num_lit.raw != "0" {
                 return;
             }
-            ctx.diagnostic(PreferIncludesDiagnostic(
-                call_expr_method_callee_info(left_call_expr).unwrap().0,
-            ));
+            bin_expr.operator == BinaryOperator::LessThan
         }
+        _ => return,
+    };
+
+    let (property_span, _) = call_expr_method_callee_info(left_call_expr).unwrap();
+    ctx.diagnostic_with_suggestion(PreferIncludesDiagnostic::IndexOf(property_span), || {
+        let call_span = left_call_expr.span;
+        let source_text = ctx.source_text();
+        let includes = format!(
+            "{}{}includes{}",
+            if negated { "!" } else { "" },
+            Span::new(call_span.start, property_span.start).source_text(source_text),
+            Span::new(property_span.end, call_span.end).source_text(source_text),
+        );
+        Fix::new(includes, bin_expr.span)
+    });
+}
+
+fn check_regex_test<'a>(call_expr: &CallExpression<'a>, ctx: &LintContext<'a>) {
+    if call_expr.optional || !is_method_call(call_expr, None, Some(&["test"]), Some(1), Some(1)) {
+        return;
+    }
+    let Some(member_expr) = call_expr.callee.get_member_expr() else { return };
+    let Expression::RegExpLiteral(regex) = member_expr.object().without_parenthesized() else {
+        return;
+    };
+    let Some(pattern) = get_regex_literal_pattern(regex) else { return };
+    // Anchored patterns are left to `prefer-string-starts-ends-with`
+    if pattern.text.is_empty() || pattern.start_anchor || pattern.end_anchor {
+        return;
+    }
+
+    let diagnostic = PreferIncludesDiagnostic::RegExpTest(member_expr.span());
+    match fix_regex_test(call_expr, regex, "includes", &pattern.text, ctx) {
+        Some(fix) => ctx.diagnostic_with_fix(diagnostic, || Fix::new(fix, call_expr.span)),
+        None => ctx.diagnostic(diagnostic),
     }
 }
 
@@ -125,6 +174,14 @@ fn test() {
         r"null.indexOf('foo') !== 1",
         r"f(0) < 0",
         r"something.indexOf(foo, 0, another) !== -1",
+        r"/foo+/.test(str)",
+        r"/^foo/.test(str)",
+        r"/foo$/.test(str)",
+        r"/foo/i.test(str)",
+        r"/[foo]/.test(str)",
+        r"/foo/.test(a, b)",
+        r"/foo/.exec(str)",
+        r"foo.test(str)",
     ];
 
     let fail = vec![
@@ -139,7 +196,24 @@ fn test() {
         r"(a || b).indexOf('foo') === -1",
         r"foo.indexOf(bar, 0) !== -1",
         r"foo.indexOf(bar, 1) !== -1",
+        r"/foo/.test(str)",
+        r"/\.\//.test(path)",
+        r"/foo/g.test(str)",
+    ];
+
+    let fix = vec![
+        (r"'foobar'.indexOf('foo') !== -1", r"'foobar'.includes('foo')", None),
+        (r"str.indexOf('foo') > -1", r"str.includes('foo')", None),
+        (r"str.indexOf('foo') == -1", r"!str.includes('foo')", None),
+        (r"'foobar'.indexOf('foo') >= 0", r"'foobar'.includes('foo')", None),
+        (r"if (str.indexOf('foo') < 0) {}", r"if (!str.includes('foo')) {}", None),
+        (r"(a || b).indexOf('foo') === -1", r"!(a || b).includes('foo')", None),
+        (r"foo.indexOf(bar, 1) !== -1", r"foo.includes(bar, 1)", None),
+        (r"/foo/.test(str)", r#"String(str).includes("foo")"#, None),
+        (r"/\.\//.test(path)", r#"String(path).includes("./")"#, None),
+        (r"/foo/.test('a foo')", r#"'a foo'.includes("foo")"#, None),
+        (r"/foo/g.test(str)", r"/foo/g.test(str)", None),
     ];
 
-    Tester::new(PreferIncludes::NAME, pass, fail).test_and_snapshot();
+    Tester::new(PreferIncludes::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, BindingPatternKind, Expression, FormalParameters, FunctionBody, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -53,6 +53,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferNativeCoercionFunctions {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ArrowFunctionExpression, AstType::Function])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::ArrowFunctionExpression(arrow_expr) => {
@@ -56,17 +56,12 @@ impl Rule for PreferNodeProtocol {
         let Some((string_lit_value, span)) = string_lit_value_with_span else {
             return;
         };
-        let module_name = if let Some((prefix, postfix)) = string_lit_value.split_once('/') {
+        let module_name = match string_lit_value.split_once('/') {
             // `e.g. ignore "assert/"`
-            if postfix.is_empty() {
-                string_lit_value.to_string()
-            } else {
-                prefix.to_string()
-            }
-        } else {
-            string_lit_value.to_string()
+            Some((prefix, postfix)) if !postfix.is_empty() => prefix,
+            _ => string_lit_value.as_str(),
         };
-        if module_name.starts_with("node:") || !NODE_BUILTINS_MODULE.contains(&module_name) {
+        if module_name.starts_with("node:") || !NODE_BUILTINS_MODULE.contains(module_name) {
             return;
         }
 
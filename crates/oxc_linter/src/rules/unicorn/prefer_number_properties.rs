@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -50,6 +50,15 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferNumberProperties {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[
+            AstType::MemberExpression,
+            AstType::IdentifierReference,
+            AstType::IdentifierName,
+            AstType::CallExpression,
+        ])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::MemberExpression(member_expr) => {
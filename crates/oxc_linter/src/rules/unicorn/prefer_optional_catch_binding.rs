@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingPattern, BindingPatternKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -45,6 +45,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferOptionalCatchBinding {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CatchClause])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CatchClause(catch_clause) = node.kind() else { return };
 
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -58,6 +58,10 @@ fn is_static_property_name_equal(expr: &MemberExpression, value: &str) -> bool {
 }
 
 impl Rule for PreferReflectApply {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -48,6 +48,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferRegexpTest {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
 
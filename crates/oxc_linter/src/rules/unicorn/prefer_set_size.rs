@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ArrayExpressionElement, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -42,6 +42,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferSetSize {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::MemberExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MemberExpression(member_expr) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -43,6 +43,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferSpread {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
 
@@ -148,7 +152,7 @@ impl Rule for PreferSpread {
                     return;
                 }
 
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_suggestion(
                     PreferSpreadDiagnostic(call_expr.span, "string.split()"),
                     || {
                         let callee_obj = member_expr.object().without_parenthesized();
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_ast::{ast::MemberExpression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -33,6 +33,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferStringSlice {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
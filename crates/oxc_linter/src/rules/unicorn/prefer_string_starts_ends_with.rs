@@ -1,6 +1,6 @@
 use oxc_ast::{
-    ast::{Expression, MemberExpression, RegExpFlags, RegExpLiteral},
-    AstKind,
+    ast::{Expression, MemberExpression},
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -9,7 +9,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{fix_regex_test, get_regex_literal_pattern},
+    AstNode, Fix,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum PreferStringStartsEndsWithDiagnostic {
@@ -33,6 +38,9 @@ declare_oxc_lint!(
     ///
     /// Using `String#startsWith()` and `String#endsWith()` is more readable and performant as it does not need to parse a regex.
     ///
+    /// Only regexes matching a fixed string are reported, such as `/^\./` or `/\u{1F600}$/u`,
+    /// so that the fix does not change which strings match.
+    ///
     /// ### Example
     /// ```javascript
     /// // Bad
@@ -48,6 +56,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferStringStartsEndsWith {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::CallExpression])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -71,51 +83,28 @@ impl Rule for PreferStringStartsEndsWith {
             return;
         };
 
-        let Some(err_kind) = check_regex(regex) else { return };
+        let Some(pattern) = get_regex_literal_pattern(regex) else { return };
 
-        match err_kind {
-            ErrorKind::StartsWith => {
-                ctx.diagnostic(PreferStringStartsEndsWithDiagnostic::StartsWith(
-                    member_expr.span(),
-                ));
-            }
-            ErrorKind::EndsWith => {
-                ctx.diagnostic(PreferStringStartsEndsWithDiagnostic::EndsWith(member_expr.span()));
-            }
+        // `/^/` matches any string
+        if pattern.text.is_empty() {
+            return;
         }
-    }
-}
 
-enum ErrorKind {
-    StartsWith,
-    EndsWith,
-}
-
-fn check_regex(regexp_lit: &RegExpLiteral) -> Option<ErrorKind> {
-    if regexp_lit.regex.flags.intersects(RegExpFlags::I | RegExpFlags::M) {
-        return None;
-    }
-
-    if regexp_lit.regex.pattern.starts_with('^')
-        && is_simple_string(&regexp_lit.regex.pattern.as_str()[1..regexp_lit.regex.pattern.len()])
-    {
-        return Some(ErrorKind::StartsWith);
-    }
+        let (diagnostic, method) = match (pattern.start_anchor, pattern.end_anchor) {
+            (true, false) => {
+                (PreferStringStartsEndsWithDiagnostic::StartsWith(member_expr.span()), "startsWith")
+            }
+            (false, true) => {
+                (PreferStringStartsEndsWithDiagnostic::EndsWith(member_expr.span()), "endsWith")
+            }
+            _ => return,
+        };
 
-    if regexp_lit.regex.pattern.ends_with('$')
-        && is_simple_string(
-            &regexp_lit.regex.pattern.as_str()[0..regexp_lit.regex.pattern.len() - 1],
-        )
-    {
-        return Some(ErrorKind::EndsWith);
+        match fix_regex_test(call_expr, regex, method, &pattern.text, ctx) {
+            Some(fix) => ctx.diagnostic_with_fix(diagnostic, || Fix::new(fix, call_expr.span)),
+            None => ctx.diagnostic(diagnostic),
+        }
     }
-
-    None
-}
-
-fn is_simple_string(str: &str) -> bool {
-    str.chars()
-        .all(|c| !matches!(c, '^' | '$' | '+' | '[' | '{' | '(' | '\\' | '.' | '?' | '*' | '|'))
 }
 
 #[test]
@@ -152,6 +141,10 @@ fn test() {
         r"/A|B$/.test(bar)",
         // Additional tests
         r"/^http/i.test(uri)",
+        r"/^/.test(bar)",
+        r"/^\d+/.test(bar)",
+        r"/^a|b$/.test(bar)",
+        r"/^\uD83D/.test(bar)",
     ];
 
     let fail = vec![
@@ -196,7 +189,34 @@ fn test() {
         r"/a$/.test(String(unknown))",
         r"const a = /你$/.test('a');",
         r"const a = /^你/.test('a');",
+        r"/^\./.test(bar)",
+        r"/\.js$/.test(file)",
+        r"/^\u{1F600}/u.test(bar)",
+        r"/^a/g.test(bar)",
+    ];
+
+    let fix = vec![
+        (r"/^foo/.test(bar)", r#"String(bar).startsWith("foo")"#, None),
+        (r"/foo$/.test(bar)", r#"String(bar).endsWith("foo")"#, None),
+        (r#"/^a/.test("string")"#, r#""string".startsWith("a")"#, None),
+        (r"/^a/.test(`string`)", r#"`string`.startsWith("a")"#, None),
+        (r"/^a/.test(String(foo))", r#"String(foo).startsWith("a")"#, None),
+        (r"(/^b/).test((a))", r#"String((a)).startsWith("b")"#, None),
+        (
+            r"const fn = async () => /^b/.test(await foo)",
+            r#"const fn = async () => String(await foo).startsWith("b")"#,
+            None,
+        ),
+        (r"/^\./.test(bar)", r#"String(bar).startsWith(".")"#, None),
+        (r#"/^"\\/.test(bar)"#, r#"String(bar).startsWith("\"\\")"#, None),
+        (r"/\.js$/.test(file)", r#"String(file).endsWith(".js")"#, None),
+        (r"/^\t\x41/.test(bar)", r#"String(bar).startsWith("\tA")"#, None),
+        (r"/^\u{1F600}/u.test(bar)", r#"String(bar).startsWith("😀")"#, None),
+        (r"/^\uD83D\uDE00/.test(bar)", r#"String(bar).startsWith("😀")"#, None),
+        (r"const a = /你$/.test('a');", r#"const a = 'a'.endsWith("你");"#, None),
+        (r"/^a/g.test(bar)", r"/^a/g.test(bar)", None),
+        (r"/^a/.test(...bar)", r"/^a/.test(...bar)", None),
     ];
 
-    Tester::new(PreferStringStartsEndsWith::NAME, pass, fail).test_and_snapshot();
+    Tester::new(PreferStringStartsEndsWith::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
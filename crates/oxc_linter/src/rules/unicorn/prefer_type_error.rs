@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{CallExpression, Expression, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -46,6 +46,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferTypeError {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::ThrowStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ThrowStatement(throw_stmt) = node.kind() else { return };
 
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Statement, AstKind};
+use oxc_ast::{ast::Statement, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -42,6 +42,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for SwitchCaseBraces {
+    fn node_types() -> Option<&'static [AstType]> {
+        Some(&[AstType::SwitchStatement])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::SwitchStatement(switch) = node.kind() else { return };
 
@@ -82,13 +82,11 @@ fn get_replacement(node: &str) -> Option<&'static str> {
         return None;
     }
 
-    let node_lower = node.to_ascii_lowercase();
-
-    if node_lower == "utf-8" || node_lower == "utf8" {
+    if node.eq_ignore_ascii_case("utf-8") || node.eq_ignore_ascii_case("utf8") {
         return Some("utf8");
     }
 
-    if node_lower == "ascii" {
+    if node.eq_ignore_ascii_case("ascii") {
         return Some("ascii");
     }
 
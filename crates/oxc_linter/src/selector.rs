@@ -0,0 +1,615 @@
+//! A subset of [esquery](https://github.com/estools/esquery) selectors.
+//!
+//! Supported are node types and `*`, attributes (`[attr]`, `[attr="value"]`, `[attr!=value]`,
+//! `[attr=/regex/]` with dotted paths), `:not(...)`, `:matches(...)` and `:is(...)`,
+//! the descendant and child (`>`) combinators, and comma separated lists.
+//!
+//! Node types are the names of oxc's AST nodes, e.g. `IdentifierReference` or `StringLiteral`,
+//! `Identifier` and `Literal` match any kind of identifier and literal.
+//! Attributes are read from the serialized node, and need the `estree` feature.
+//! Nodes without an ESTree equivalent which only wrap another one, such as `Argument`,
+//! are skipped by the child combinator.
+//!
+//! Used by the `no-restricted-syntax` rule, and by the `ast` command of the CLI for exploring the AST.
+
+use std::cell::OnceCell;
+
+use oxc_ast::{AstKind, AstType};
+use oxc_semantic::{AstNode, AstNodes};
+use regex::Regex;
+use serde_json::Value;
+
+/// Comma separated list of selectors, matching if any of them matches
+#[derive(Debug, Clone)]
+pub struct Selector(Vec<Complex>);
+
+/// Compounds joined by combinators, `compounds[i]` and `compounds[i + 1]` are joined by `combinators[i]`
+#[derive(Debug, Clone)]
+struct Complex {
+    compounds: Vec<Vec<Condition>>,
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    /// `A B`
+    Descendant,
+    /// `A > B`
+    Child,
+}
+
+#[derive(Debug, Clone)]
+enum Condition {
+    /// Empty for unknown types, which never match
+    Type(Vec<AstType>),
+    Attribute {
+        path: Vec<String>,
+        operator: Option<(Operator, AttributeValue)>,
+    },
+    Not(Selector),
+    Matches(Selector),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Equal,
+    NotEqual,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeValue {
+    String(String),
+    Regex(Regex),
+}
+
+macro_rules! node_types {
+    ($($ty:ident),* $(,)?) => {
+        const NODE_TYPES: &[(&str, AstType)] = &[$((stringify!($ty), AstType::$ty)),*];
+
+        #[cfg(feature = "estree")]
+        fn serialize(kind: AstKind) -> Option<Value> {
+            match kind {
+                $(AstKind::$ty(node) => serde_json::to_value(node).ok(),)*
+            }
+        }
+    };
+}
+
+node_types!(
+    Program,
+    Directive,
+    Hashbang,
+    BlockStatement,
+    BreakStatement,
+    ContinueStatement,
+    DebuggerStatement,
+    DoWhileStatement,
+    EmptyStatement,
+    ExpressionStatement,
+    ForInStatement,
+    ForOfStatement,
+    ForStatement,
+    ForStatementInit,
+    IfStatement,
+    LabeledStatement,
+    ReturnStatement,
+    SwitchStatement,
+    ThrowStatement,
+    TryStatement,
+    WhileStatement,
+    WithStatement,
+    SwitchCase,
+    CatchClause,
+    FinallyClause,
+    VariableDeclaration,
+    VariableDeclarator,
+    UsingDeclaration,
+    IdentifierName,
+    IdentifierReference,
+    BindingIdentifier,
+    LabelIdentifier,
+    PrivateIdentifier,
+    NumericLiteral,
+    StringLiteral,
+    BooleanLiteral,
+    NullLiteral,
+    BigintLiteral,
+    RegExpLiteral,
+    TemplateLiteral,
+    MetaProperty,
+    Super,
+    ArrayExpression,
+    ArrowFunctionExpression,
+    AssignmentExpression,
+    AwaitExpression,
+    BinaryExpression,
+    CallExpression,
+    ChainExpression,
+    ConditionalExpression,
+    LogicalExpression,
+    MemberExpression,
+    NewExpression,
+    ObjectExpression,
+    ParenthesizedExpression,
+    SequenceExpression,
+    TaggedTemplateExpression,
+    ThisExpression,
+    UnaryExpression,
+    UpdateExpression,
+    YieldExpression,
+    ImportExpression,
+    PrivateInExpression,
+    ObjectProperty,
+    PropertyKey,
+    Argument,
+    AssignmentTarget,
+    SimpleAssignmentTarget,
+    AssignmentTargetWithDefault,
+    ArrayExpressionElement,
+    Elision,
+    ExpressionArrayElement,
+    SpreadElement,
+    BindingRestElement,
+    Function,
+    FunctionBody,
+    FormalParameters,
+    FormalParameter,
+    Class,
+    ClassBody,
+    ClassHeritage,
+    StaticBlock,
+    PropertyDefinition,
+    MethodDefinition,
+    ArrayPattern,
+    ObjectPattern,
+    AssignmentPattern,
+    Decorator,
+    ModuleDeclaration,
+    ImportDeclaration,
+    ImportSpecifier,
+    ImportDefaultSpecifier,
+    ImportNamespaceSpecifier,
+    ExportDefaultDeclaration,
+    ExportNamedDeclaration,
+    ExportAllDeclaration,
+    JSXElement,
+    JSXFragment,
+    JSXOpeningElement,
+    JSXClosingElement,
+    JSXElementName,
+    JSXExpressionContainer,
+    JSXAttributeItem,
+    JSXSpreadAttribute,
+    JSXText,
+    JSXIdentifier,
+    JSXMemberExpression,
+    JSXMemberExpressionObject,
+    JSXNamespacedName,
+    TSModuleBlock,
+    TSAnyKeyword,
+    TSIntersectionType,
+    TSLiteralType,
+    TSMethodSignature,
+    TSNullKeyword,
+    TSTypeLiteral,
+    TSTypeReference,
+    TSUnionType,
+    TSVoidKeyword,
+    TSIndexedAccessType,
+    TSAsExpression,
+    TSSatisfiesExpression,
+    TSNonNullExpression,
+    TSInstantiationExpression,
+    TSEnumDeclaration,
+    TSEnumMember,
+    TSImportEqualsDeclaration,
+    TSTypeName,
+    TSExternalModuleReference,
+    TSQualifiedName,
+    TSInterfaceDeclaration,
+    TSModuleDeclaration,
+    TSTypeAliasDeclaration,
+    TSTypeAnnotation,
+    TSTypeQuery,
+    TSTypeAssertion,
+    TSTypeParameter,
+    TSTypeParameterDeclaration,
+    TSTypeParameterInstantiation,
+    TSPropertySignature,
+);
+
+/// Never called, attributes cannot be parsed without the feature
+#[cfg(not(feature = "estree"))]
+fn serialize(_kind: AstKind) -> Option<Value> {
+    None
+}
+
+fn resolve_type(name: &str) -> Vec<AstType> {
+    match name {
+        "Identifier" => vec![
+            AstType::IdentifierName,
+            AstType::IdentifierReference,
+            AstType::BindingIdentifier,
+            AstType::LabelIdentifier,
+        ],
+        "Literal" => vec![
+            AstType::NumericLiteral,
+            AstType::StringLiteral,
+            AstType::BooleanLiteral,
+            AstType::NullLiteral,
+            AstType::BigintLiteral,
+            AstType::RegExpLiteral,
+        ],
+        _ => NODE_TYPES.iter().filter(|(ty, _)| *ty == name).map(|(_, ty)| *ty).collect(),
+    }
+}
+
+/// Nodes which only wrap another node, and have no equivalent in ESTree
+fn is_wrapper(ty: AstType) -> bool {
+    matches!(
+        ty,
+        AstType::Argument
+            | AstType::ArrayExpressionElement
+            | AstType::ExpressionArrayElement
+            | AstType::PropertyKey
+            | AstType::AssignmentTarget
+            | AstType::SimpleAssignmentTarget
+            | AstType::ForStatementInit
+            | AstType::ModuleDeclaration
+            | AstType::ClassHeritage
+            | AstType::JSXElementName
+            | AstType::JSXMemberExpressionObject
+            | AstType::JSXAttributeItem
+            | AstType::TSTypeName
+    )
+}
+
+impl Selector {
+    /// # Errors
+    ///
+    /// Returns a description of the problem if `source` is not a supported selector.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut parser = SelectorParser { source, pos: 0 };
+        let selector = parser.parse_selector()?;
+        parser.skip_whitespace();
+        if parser.pos < source.len() {
+            return Err(format!("Unexpected `{}` at {}", parser.rest(), parser.pos));
+        }
+        Ok(selector)
+    }
+
+    /// Whether `node` matches, `nodes` are the nodes of its semantic for finding its ancestors.
+    pub fn matches<'a>(&self, node: &AstNode<'a>, nodes: &AstNodes<'a>) -> bool {
+        self.matches_candidate(&Candidate::new(node), nodes)
+    }
+
+    fn matches_candidate<'a>(&self, candidate: &Candidate<'a, '_>, nodes: &AstNodes<'a>) -> bool {
+        self.0.iter().any(|complex| complex.matches(complex.compounds.len() - 1, candidate, nodes))
+    }
+}
+
+/// A node along with its serialized form, created when an attribute is first read
+struct Candidate<'a, 'n> {
+    node: &'n AstNode<'a>,
+    json: OnceCell<Option<Value>>,
+}
+
+impl<'a, 'n> Candidate<'a, 'n> {
+    fn new(node: &'n AstNode<'a>) -> Self {
+        Self { node, json: OnceCell::new() }
+    }
+
+    fn attribute(&self, path: &[String]) -> Option<&Value> {
+        let json = self.json.get_or_init(|| serialize(self.node.kind())).as_ref()?;
+        path.iter().try_fold(json, |value, key| value.get(key))
+    }
+}
+
+impl Complex {
+    fn matches<'a>(
+        &self,
+        index: usize,
+        candidate: &Candidate<'a, '_>,
+        nodes: &AstNodes<'a>,
+    ) -> bool {
+        if !self.compounds[index].iter().all(|condition| condition.matches(candidate, nodes)) {
+            return false;
+        }
+        if index == 0 {
+            return true;
+        }
+        let combinator = self.combinators[index - 1];
+        let mut parent = nodes.parent_node(candidate.node.id());
+        match combinator {
+            Combinator::Child => {
+                // Also try the parents of wrapper nodes, so `CallExpression > StringLiteral` matches
+                // an argument like in ESTree
+                while let Some(ancestor) = parent {
+                    if self.matches(index - 1, &Candidate::new(ancestor), nodes) {
+                        return true;
+                    }
+                    if !is_wrapper(ancestor.kind().ty()) {
+                        return false;
+                    }
+                    parent = nodes.parent_node(ancestor.id());
+                }
+                false
+            }
+            Combinator::Descendant => {
+                while let Some(ancestor) = parent {
+                    if self.matches(index - 1, &Candidate::new(ancestor), nodes) {
+                        return true;
+                    }
+                    parent = nodes.parent_node(ancestor.id());
+                }
+                false
+            }
+        }
+    }
+}
+
+impl Condition {
+    fn matches<'a>(&self, candidate: &Candidate<'a, '_>, nodes: &AstNodes<'a>) -> bool {
+        match self {
+            Self::Type(types) => types.contains(&candidate.node.kind().ty()),
+            Self::Attribute { path, operator } => {
+                let value = candidate.attribute(path);
+                let Some((operator, expected)) = operator else {
+                    return value.is_some_and(|value| !value.is_null());
+                };
+                let matched = value.is_some_and(|value| {
+                    let value = match value {
+                        Value::String(string) => string.clone(),
+                        Value::Number(number) => {
+                            number.as_f64().map_or_else(|| number.to_string(), |n| n.to_string())
+                        }
+                        value => value.to_string(),
+                    };
+                    match expected {
+                        AttributeValue::String(expected) => value == *expected,
+                        AttributeValue::Regex(regex) => regex.is_match(&value),
+                    }
+                });
+                matched == (*operator == Operator::Equal)
+            }
+            Self::Not(selector) => !selector.matches_candidate(candidate, nodes),
+            Self::Matches(selector) => selector.matches_candidate(candidate, nodes),
+        }
+    }
+}
+
+struct SelectorParser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> SelectorParser<'s> {
+    fn rest(&self) -> &'s str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(format!("Expected `{c}` at {}", self.pos))
+        }
+    }
+
+    /// Returns whether any whitespace was skipped
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        self.pos += self.rest().len() - self.rest().trim_start().len();
+        self.pos > start
+    }
+
+    fn parse_name(&mut self) -> Result<&'s str, String> {
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$' || c == '-'))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(format!("Expected a name at {}", self.pos));
+        }
+        let name = &self.rest()[..len];
+        self.pos += len;
+        Ok(name)
+    }
+
+    fn parse_selector(&mut self) -> Result<Selector, String> {
+        let mut list = vec![self.parse_complex()?];
+        while self.eat(',') {
+            list.push(self.parse_complex()?);
+        }
+        Ok(Selector(list))
+    }
+
+    fn parse_complex(&mut self) -> Result<Complex, String> {
+        self.skip_whitespace();
+        let mut complex = Complex { compounds: vec![self.parse_compound()?], combinators: vec![] };
+        loop {
+            let whitespace = self.skip_whitespace();
+            let combinator = if self.eat('>') {
+                self.skip_whitespace();
+                Combinator::Child
+            } else if whitespace && !matches!(self.peek(), None | Some(',' | ')')) {
+                Combinator::Descendant
+            } else {
+                return Ok(complex);
+            };
+            complex.combinators.push(combinator);
+            complex.compounds.push(self.parse_compound()?);
+        }
+    }
+
+    fn parse_compound(&mut self) -> Result<Vec<Condition>, String> {
+        let mut conditions = vec![];
+        let universal = self.eat('*');
+        if !universal && self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            conditions.push(Condition::Type(resolve_type(self.parse_name()?)));
+        }
+        loop {
+            if self.eat('[') {
+                conditions.push(self.parse_attribute()?);
+            } else if self.eat(':') {
+                let name = self.parse_name()?;
+                self.expect('(')?;
+                let selector = self.parse_selector()?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                conditions.push(match name {
+                    "not" => Condition::Not(selector),
+                    "matches" | "is" => Condition::Matches(selector),
+                    _ => return Err(format!("Unsupported pseudo-class `:{name}`")),
+                });
+            } else {
+                break;
+            }
+        }
+        if conditions.is_empty() && !universal {
+            return Err(format!("Expected a selector at {}", self.pos));
+        }
+        Ok(conditions)
+    }
+
+    /// Parse the rest of an attribute after `[`
+    fn parse_attribute(&mut self) -> Result<Condition, String> {
+        if cfg!(not(feature = "estree")) {
+            return Err("Attribute selectors need the `estree` feature".to_string());
+        }
+        self.skip_whitespace();
+        let mut path = vec![self.parse_name()?.to_string()];
+        while self.eat('.') {
+            path.push(self.parse_name()?.to_string());
+        }
+        self.skip_whitespace();
+        let operator = if self.eat('=') {
+            Some(Operator::Equal)
+        } else if self.rest().starts_with("!=") {
+            self.pos += 2;
+            Some(Operator::NotEqual)
+        } else {
+            None
+        };
+        let operator = match operator {
+            Some(operator) => {
+                self.skip_whitespace();
+                Some((operator, self.parse_attribute_value()?))
+            }
+            None => None,
+        };
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Condition::Attribute { path, operator })
+    }
+
+    fn parse_attribute_value(&mut self) -> Result<AttributeValue, String> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                let mut value = String::new();
+                let mut chars = self.rest().char_indices();
+                loop {
+                    match chars.next() {
+                        Some((i, c)) if c == quote => {
+                            self.pos += i + 1;
+                            return Ok(AttributeValue::String(value));
+                        }
+                        Some((_, '\\')) => value.extend(chars.next().map(|(_, c)| c)),
+                        Some((_, c)) => value.push(c),
+                        None => return Err("Unterminated string".to_string()),
+                    }
+                }
+            }
+            Some('/') => {
+                self.pos += 1;
+                let Some(end) = self.rest().find('/') else {
+                    return Err("Unterminated regular expression".to_string());
+                };
+                let pattern = &self.rest()[..end];
+                self.pos += end + 1;
+                let len = self
+                    .rest()
+                    .find(|c: char| !matches!(c, 'i' | 'm' | 's' | 'u'))
+                    .unwrap_or(self.rest().len());
+                // Unicode is always enabled in `regex`
+                let flags = self.rest()[..len].replace('u', "");
+                self.pos += len;
+                let pattern = if flags.is_empty() {
+                    pattern.to_string()
+                } else {
+                    format!("(?{flags}){pattern}")
+                };
+                Regex::new(&pattern).map(AttributeValue::Regex).map_err(|err| err.to_string())
+            }
+            _ => {
+                let len = self.rest().find([']', ' ']).unwrap_or(self.rest().len());
+                let value = &self.rest()[..len];
+                if value.is_empty() {
+                    return Err(format!("Expected a value at {}", self.pos));
+                }
+                self.pos += len;
+                Ok(AttributeValue::String(value.to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Selector;
+
+    #[test]
+    fn parse() {
+        let valid = [
+            "WithStatement",
+            "*",
+            "Identifier, Literal",
+            "FunctionBody > ReturnStatement",
+            ":matches(ForInStatement, ForOfStatement)",
+        ];
+        for source in valid {
+            assert!(Selector::parse(source).is_ok(), "{source}");
+        }
+
+        // Attributes are matched on the serialized AST
+        let attributes = [
+            "CallExpression[callee.name=\"fdescribe\"]",
+            "CallExpression[callee.name='fdescribe']",
+            "CallExpression[arguments]",
+            "CallExpression[callee.name!=foo]",
+            "StringLiteral[value=/^foo/i]",
+            "Class  MethodDefinition[kind=get]",
+            "CallExpression:not([callee.name=\"foo\"])",
+        ];
+        for source in attributes {
+            assert_eq!(Selector::parse(source).is_ok(), cfg!(feature = "estree"), "{source}");
+        }
+
+        let invalid = [
+            "",
+            "CallExpression[",
+            "CallExpression[callee.name=\"foo]",
+            "StringLiteral[value=/foo]",
+            "Identifier:first-child",
+            "Identifier ~ Identifier",
+            "Identifier >",
+        ];
+        for source in invalid {
+            assert!(Selector::parse(source).is_err(), "{source}");
+        }
+    }
+}
@@ -1,28 +1,87 @@
 use std::{
+    any::Any,
+    cell::RefCell,
     collections::HashMap,
     ffi::OsStr,
     fs,
+    hash::{Hash, Hasher},
+    io::{self, Write},
+    ops::Deref,
+    panic::{self, AssertUnwindSafe},
     path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, Condvar, Mutex},
+    sync::{Arc, Condvar, Mutex, PoisonError},
+    time::Instant,
 };
 
-use dashmap::DashMap;
-use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
-use rustc_hash::FxHashSet;
+use dashmap::{DashMap, DashSet};
+use rayon::{
+    iter::ParallelBridge,
+    prelude::{IntoParallelRefIterator, ParallelIterator},
+};
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use similar::TextDiff;
+use tracing::{debug, debug_span, trace};
 
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError};
+use oxc_diagnostics::{
+    CancellationToken, DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError,
+    InputLimitExceededError, InternalError, NestingTooDeepError,
+};
 use oxc_parser::Parser;
-use oxc_resolver::Resolver;
-use oxc_semantic::{ModuleRecord, SemanticBuilder};
+use oxc_resolver::{ResolveOptions, Resolver, TsconfigOptions, TsconfigReferences};
+use oxc_semantic::{AstNodes, ModuleRecord, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 
 use crate::{
-    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
-    Fixer, LintContext, Linter, Message,
+    config::ESLintConfig,
+    encoding::{Encoding, FileEncoding},
+    fixer::FixResult,
+    json::JSON_EXT,
+    partial_loader::{JavaScriptSource, PartialLoader, Processor, LINT_PARTIAL_LOADER_EXT},
+    paths, FixOutput, Fixer, LintContext, Linter, MemoryUsage, Message, Workspace,
 };
 
+/// Passes of fixing and linting the fixed code again, until no more fixes apply.
+const MAX_FIX_PASSES: usize = 10;
+
+/// Conditions of package.json `exports` and `imports` matched when resolving imports.
+/// `default` always matches.
+pub const DEFAULT_CONDITION_NAMES: &[&str] = &["import", "require", "module", "node", "types"];
+
+/// The resolution of the import plugin, for other tools to resolve the same files.
+///
+/// `conditions` are matched in addition to `DEFAULT_CONDITION_NAMES`.
+/// With the `browser` condition, the package.json `browser` field is used as well.
+///
+/// Symlinks are resolved to their real path unless `preserve_symlinks`,
+/// so packages of a pnpm virtual store find their own dependencies.
+pub fn resolve_options(
+    tsconfig: Option<TsconfigOptions>,
+    conditions: &[String],
+    preserve_symlinks: bool,
+) -> ResolveOptions {
+    let browser = conditions.iter().any(|condition| condition == "browser");
+    let (main_fields, alias_fields) = if browser {
+        (vec!["browser".into(), "main".into()], vec![vec!["browser".into()]])
+    } else {
+        (vec!["main".into()], vec![])
+    };
+    ResolveOptions {
+        extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+        condition_names: DEFAULT_CONDITION_NAMES
+            .iter()
+            .map(ToString::to_string)
+            .chain(conditions.iter().cloned())
+            .collect(),
+        main_fields,
+        alias_fields,
+        symlinks: !preserve_symlinks,
+        tsconfig,
+        ..ResolveOptions::default()
+    }
+}
+
 pub struct LintServiceOptions {
     /// Current working directory
     pub cwd: Box<Path>,
@@ -30,8 +89,125 @@ pub struct LintServiceOptions {
     /// All paths to lint
     pub paths: Vec<Box<Path>>,
 
-    /// TypeScript `tsconfig.json` path for reading path alias and project references
+    /// TypeScript `tsconfig.json` path for reading path alias and project references.
+    /// Defaults to the `tsconfig.json` of `cwd` when there is one.
     pub tsconfig: Option<PathBuf>,
+
+    /// Conditions of package.json `exports` and `imports` to resolve, in addition to `DEFAULT_CONDITION_NAMES`
+    pub conditions: Vec<String>,
+
+    /// Resolve imports to the symlinked location instead of the real path
+    pub preserve_symlinks: bool,
+
+    /// Memory map the files instead of reading them into a `String`.
+    /// Files fixed in place and files which are not valid UTF-8 are still read.
+    ///
+    /// Off by default: the mapped files must not be changed by other processes while they are
+    /// linted. Truncating one raises `SIGBUS`, and modifying one is undefined behavior, as the
+    /// text of the file is borrowed as a `&str` which must not change.
+    pub mmap: bool,
+
+    /// Record the arena memory used by each file, see `LintService::memory_usage`.
+    pub memory_usage: bool,
+
+    /// Files larger than this many bytes are linted one at a time, and the memory of their arena
+    /// is freed right after instead of being reused, to bound the memory used by large files.
+    /// Not used with the import plugin, which lints the dependencies of a file while it waits for them.
+    pub low_memory_threshold: Option<usize>,
+
+    /// Limits on the size of the linted files, see `InputLimits`.
+    pub limits: InputLimits,
+
+    /// Processors keyed by the file extension they handle, without the leading dot.
+    /// They take precedence over the built-in partial loaders.
+    pub processors: FxHashMap<String, Arc<dyn Processor>>,
+
+    /// The workspace of a monorepo, when linting its packages together.
+    /// The imports of each package are resolved with its own `tsconfig.json` unless `tsconfig` is set,
+    /// and the files are grouped by package.
+    pub workspace: Option<Workspace>,
+}
+
+impl LintServiceOptions {
+    /// Lint `paths` from `cwd`, with the default of the other options.
+    pub fn new(cwd: Box<Path>, paths: Vec<Box<Path>>) -> Self {
+        Self {
+            cwd,
+            paths,
+            tsconfig: None,
+            conditions: vec![],
+            preserve_symlinks: false,
+            mmap: false,
+            memory_usage: false,
+            low_memory_threshold: None,
+            limits: InputLimits::default(),
+            processors: FxHashMap::default(),
+            workspace: None,
+        }
+    }
+}
+
+/// Limits on the size of a file, for generated files such as bundles which take minutes to lint.
+/// The files over a limit are not linted and get an informational diagnostic instead.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct InputLimits {
+    /// Files larger than this many bytes are not parsed.
+    pub max_file_size: Option<usize>,
+    /// Files with more AST nodes are parsed but not linted.
+    pub max_node_count: Option<usize>,
+    /// Files with AST nodes nested deeper are parsed but not linted.
+    /// The `Program` is at depth 0.
+    /// The parser stops at syntax nested deeper, see `Parser::max_nesting_depth`.
+    pub max_nesting_depth: Option<usize>,
+}
+
+impl InputLimits {
+    fn check_file_size(&self, path: &Path, size: usize) -> Result<(), Error> {
+        match self.max_file_size {
+            Some(max) if size > max => Err(Self::exceeded(
+                path,
+                format!("it has {size} bytes, more than the limit of {max} bytes"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_nodes(&self, path: &Path, nodes: &AstNodes) -> Result<(), Error> {
+        if let Some(max) = self.max_node_count {
+            let count = nodes.len();
+            if count > max {
+                return Err(Self::exceeded(
+                    path,
+                    format!("it has {count} AST nodes, more than the limit of {max}"),
+                ));
+            }
+        }
+        if let Some(max) = self.max_nesting_depth {
+            let depth = Self::nesting_depth(nodes);
+            if depth > max {
+                return Err(Self::exceeded(
+                    path,
+                    format!("its AST is {depth} levels deep, more than the limit of {max}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The depth of the deepest node. Parents are added before their children.
+    fn nesting_depth(nodes: &AstNodes) -> usize {
+        let mut depths: Vec<usize> = Vec::with_capacity(nodes.len());
+        for node in nodes.iter() {
+            let depth =
+                nodes.parent_id(node.id()).map_or(0, |parent_id| depths[parent_id.index()] + 1);
+            depths.push(depth);
+        }
+        depths.into_iter().max().unwrap_or(0)
+    }
+
+    fn exceeded(path: &Path, reason: String) -> Error {
+        Error::new(InputLimitExceededError(path.to_path_buf(), reason))
+    }
 }
 
 #[derive(Clone)]
@@ -55,20 +231,75 @@ impl LintService {
         &self.runtime.linter
     }
 
+    pub fn memory_usage(&self) -> Option<&MemoryUsage> {
+        self.runtime.memory_usage.as_ref()
+    }
+
     pub fn number_of_dependencies(&self) -> usize {
         self.runtime.module_map.len() - self.runtime.paths.len()
     }
 
+    /// Forget the cached module records of `changed` files and of the modules depending on them,
+    /// along with the cached resolutions, so the next `run` reads them again.
+    /// For long running processes such as watch mode and the language server.
+    ///
+    /// Must not be called while `run` is in progress.
+    pub fn invalidate<P: AsRef<Path>>(&self, changed: &[P]) {
+        self.runtime.invalidate(changed);
+    }
+
+    /// Like `invalidate`, with the files whose content changed since they were read by `run`,
+    /// or which no longer exist. Returns those files.
+    ///
+    /// Only the files of the import plugin's module graph are cached, and newly created files are not found,
+    /// pass them to `invalidate` to update the resolution of imports.
+    pub fn invalidate_changed(&self) -> Vec<PathBuf> {
+        self.runtime.invalidate_changed()
+    }
+
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
-        self.runtime
-            .paths
-            .iter()
-            .par_bridge()
-            .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        // Files are split across the thread pool, idle threads steal from the busy ones.
+        // The files of a workspace package are grouped to share its linter and resolver caches.
+        self.runtime.partitions.par_iter().flat_map(|paths| paths.par_iter()).for_each(|path| {
+            self.runtime.process_path(path, None, tx_error);
+            // Marks the file as complete, see `DiagnosticService::with_expected_files`
+            let path = paths::display(path, &self.runtime.cwd);
+            tx_error.send(Some((path, vec![]))).unwrap();
+        });
         tx_error.send(None).unwrap();
     }
 
+    /// The paths of the files linted by `run` as they are reported,
+    /// see `DiagnosticService::with_expected_files`.
+    pub fn report_paths(&self) -> Vec<PathBuf> {
+        self.runtime.paths.iter().map(|path| paths::display(path, &self.runtime.cwd)).collect()
+    }
+
+    /// Lints `source_text` as the content of `path` instead of reading the file, e.g. the unsaved
+    /// content of an editor, the same way as `run`: with the processors, the nested configuration
+    /// files and the limits. The fixes are not applied. The diagnostics are sent to `tx_error`,
+    /// without the `None` sent at the end of `run`, and nothing is sent once `cancellation_token`
+    /// is cancelled.
+    ///
+    /// With the import plugin, the cached module records of `path` and of the changed files are
+    /// invalidated first, so like `invalidate` it must not be called while `run` or another call
+    /// is in progress.
+    pub fn run_source_text(
+        &self,
+        path: &Path,
+        source_text: String,
+        cancellation_token: &CancellationToken,
+        tx_error: &DiagnosticSender,
+    ) {
+        if self.runtime.linter.options().import_plugin {
+            self.runtime.invalidate_changed();
+            self.runtime.invalidate(&[path]);
+        }
+        let request = SourceTextRequest { source_text, cancellation_token };
+        self.runtime.process_path(path, Some(request), tx_error);
+    }
+
     /// For tests
     #[cfg(test)]
     pub(crate) fn run_source<'a>(
@@ -82,15 +313,25 @@ impl LintService {
             .paths
             .iter()
             .flat_map(|path| {
-                let source_type = SourceType::from_path(path).unwrap();
+                let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+                let sources = PartialLoader::parse(ext, source_text).unwrap_or_else(|| {
+                    let source_type = SourceType::from_path(path).unwrap();
+                    vec![JavaScriptSource::new(source_text, source_type, 0)]
+                });
                 self.runtime.init_cache_state(path);
-                self.runtime.process_source(
-                    path,
-                    allocator,
-                    source_text,
-                    source_type,
-                    check_syntax_errors,
-                    tx_error,
+                let mode = FileMode { lint: true, fix: false, cancellation_token: None };
+                sources.into_iter().flat_map(
+                    move |JavaScriptSource { source_text, source_type, .. }| {
+                        self.runtime.process_source(
+                            path,
+                            allocator,
+                            source_text,
+                            source_type,
+                            check_syntax_errors,
+                            mode,
+                            tx_error,
+                        )
+                    },
                 )
             })
             .collect::<Vec<_>>()
@@ -117,6 +358,58 @@ enum CacheStateEntry {
 /// Keyed by canonicalized path
 type ModuleMap = DashMap<Box<Path>, ModuleState>;
 
+/// Hash of the source text of the files in `ModuleMap`, to find the changed files.
+type ContentHashes = DashMap<Box<Path>, u64>;
+
+thread_local! {
+    static ALLOCATORS: RefCell<Vec<Allocator>> = RefCell::new(vec![]);
+}
+
+/// An allocator of the pool of the current thread, reset and returned to the pool when dropped,
+/// so the memory of the arena is reused from file to file instead of being allocated again.
+/// Dependencies processed on the same thread while a file waits for them take another allocator.
+struct PooledAllocator {
+    allocator: Allocator,
+    /// Dropped instead of being returned to the pool, see `LintServiceOptions::low_memory_threshold`
+    pooled: bool,
+}
+
+impl PooledAllocator {
+    fn take() -> Self {
+        let allocator =
+            ALLOCATORS.with(|allocators| allocators.borrow_mut().pop()).unwrap_or_default();
+        Self { allocator, pooled: true }
+    }
+
+    fn unpooled() -> Self {
+        Self { allocator: Allocator::default(), pooled: false }
+    }
+}
+
+impl Deref for PooledAllocator {
+    type Target = Allocator;
+
+    fn deref(&self) -> &Allocator {
+        &self.allocator
+    }
+}
+
+impl Drop for PooledAllocator {
+    fn drop(&mut self) {
+        if self.pooled {
+            let mut allocator = std::mem::take(&mut self.allocator);
+            allocator.reset();
+            ALLOCATORS.with(|allocators| allocators.borrow_mut().push(allocator));
+        }
+    }
+}
+
+/// Nested configuration files found for each directory, ordered from the outermost to the innermost.
+type NestedConfigPaths = DashMap<Box<Path>, Arc<[PathBuf]>>;
+
+/// Linters created for a list of nested configuration files.
+type NestedLinters = DashMap<Arc<[PathBuf]>, Arc<Linter>>;
+
 #[derive(Clone)]
 enum ModuleState {
     Resolved(Arc<ModuleRecord>),
@@ -127,28 +420,148 @@ pub struct Runtime {
     cwd: Box<Path>,
     /// All paths to lint
     paths: FxHashSet<Box<Path>>,
+    /// `paths` grouped by workspace package, see `Workspace::partition`
+    partitions: Vec<Vec<Box<Path>>>,
     linter: Linter,
     resolver: Option<Resolver>,
+    workspace: Option<Workspace>,
+    /// Resolvers of the workspace packages with their own `tsconfig.json`, keyed by package directory.
+    /// They share the cache of `resolver`.
+    package_resolvers: FxHashMap<PathBuf, Resolver>,
     module_map: ModuleMap,
+    content_hashes: ContentHashes,
     cache_state: CacheState,
+    nested_config_paths: NestedConfigPaths,
+    nested_linters: NestedLinters,
+    /// The nested configuration files whose warnings are reported, see `nested_linter`
+    reported_config_paths: DashSet<PathBuf>,
+    processors: FxHashMap<String, Arc<dyn Processor>>,
+    mmap: bool,
+    memory_usage: Option<MemoryUsage>,
+    low_memory_threshold: Option<usize>,
+    /// Held while linting a file larger than `low_memory_threshold`
+    low_memory_lock: Mutex<()>,
+    limits: InputLimits,
+}
+
+/// The content of a file given to `LintService::run_source_text`
+struct SourceTextRequest<'t> {
+    source_text: String,
+    cancellation_token: &'t CancellationToken,
+}
+
+/// How a file is processed
+#[derive(Clone, Copy)]
+struct FileMode<'t> {
+    /// Linted, instead of only adding its module record to the module graph of the import plugin
+    lint: bool,
+    fix: bool,
+    cancellation_token: Option<&'t CancellationToken>,
+}
+
+impl FileMode<'_> {
+    fn is_cancelled(&self) -> bool {
+        self.cancellation_token.is_some_and(CancellationToken::is_cancelled)
+    }
+}
+
+/// The text of a file, see `LintServiceOptions::mmap`.
+/// The text excludes the byte order mark of the file.
+enum SourceText {
+    Read(String, FileEncoding),
+    /// Checked to be valid UTF-8 after the byte order mark
+    Mapped(memmap2::Mmap, FileEncoding),
+}
+
+impl SourceText {
+    /// The encoding in which fixed code is written back
+    fn encoding(&self) -> FileEncoding {
+        match self {
+            Self::Read(_, encoding) | Self::Mapped(_, encoding) => *encoding,
+        }
+    }
+}
+
+impl Deref for SourceText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            Self::Read(source_text, _) => source_text,
+            // Checked again, as the file may be modified by another process after `Runtime::map_file`.
+            // The panic is reported as a crash of the file, see `Runtime::process_path`.
+            Self::Mapped(mmap, encoding) => std::str::from_utf8(&mmap[encoding.bom_len()..])
+                .expect("The file is modified while it is linted"),
+        }
+    }
 }
 
 impl Runtime {
     fn new(linter: Linter, options: LintServiceOptions) -> Self {
-        let resolver = linter.options().import_plugin.then(|| Self::get_resolver(options.tsconfig));
+        let explicit_tsconfig = options.tsconfig.is_some();
+        let tsconfig = options.tsconfig.or_else(|| {
+            let path = options.cwd.join("tsconfig.json");
+            path.is_file().then_some(path)
+        });
+        let resolver = linter.options().import_plugin.then(|| {
+            let resolve_options =
+                Self::get_resolve_options(tsconfig, &options.conditions, options.preserve_symlinks);
+            Resolver::new(linter.settings().import_resolver.apply(resolve_options))
+        });
+        let package_resolvers = match (&resolver, &options.workspace) {
+            (Some(resolver), Some(workspace)) if !explicit_tsconfig => workspace
+                .packages()
+                .iter()
+                .filter_map(|package| {
+                    let resolve_options = Self::get_resolve_options(
+                        Some(package.tsconfig.clone()?),
+                        &options.conditions,
+                        options.preserve_symlinks,
+                    );
+                    let resolve_options = linter.settings().import_resolver.apply(resolve_options);
+                    Some((package.dir.clone(), resolver.clone_with_options(resolve_options)))
+                })
+                .collect(),
+            _ => FxHashMap::default(),
+        };
+        let paths = options.paths.iter().cloned().collect::<FxHashSet<_>>();
+        let partitions = match &options.workspace {
+            Some(workspace) => workspace.partition(&options.cwd, paths.iter().map(|path| &**path)),
+            None => vec![paths.iter().cloned().collect()],
+        };
+        // Writing the fixed code would change mapped files while they are in use.
+        let mmap = options.mmap && !linter.options().fix;
+        let low_memory_threshold =
+            options.low_memory_threshold.filter(|_| !linter.options().import_plugin);
         Self {
-            cwd: options.cwd,
-            paths: options.paths.iter().cloned().collect(),
+            cwd: paths::normalize(&options.cwd).into_boxed_path(),
+            paths,
+            partitions,
             linter,
             resolver,
+            workspace: options.workspace,
+            package_resolvers,
             module_map: ModuleMap::default(),
+            content_hashes: ContentHashes::default(),
             cache_state: CacheState::default(),
+            nested_config_paths: NestedConfigPaths::default(),
+            nested_linters: NestedLinters::default(),
+            reported_config_paths: DashSet::default(),
+            processors: options.processors,
+            mmap,
+            memory_usage: options.memory_usage.then(MemoryUsage::default),
+            low_memory_threshold,
+            low_memory_lock: Mutex::default(),
+            limits: options.limits,
         }
     }
 
-    fn get_resolver(tsconfig: Option<PathBuf>) -> Resolver {
-        use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
-        let tsconfig = if let Some(path) = tsconfig {
+    fn get_resolve_options(
+        tsconfig: Option<PathBuf>,
+        conditions: &[String],
+        preserve_symlinks: bool,
+    ) -> ResolveOptions {
+        let tsconfig = tsconfig.and_then(|path| {
             if path.is_file() {
                 Some(TsconfigOptions { config_file: path, references: TsconfigReferences::Auto })
             } else {
@@ -156,29 +569,26 @@ impl Runtime {
                 eprintln!("Tsconfig {path:?} is not a file");
                 None
             }
-        } else {
-            None
-        };
-        Resolver::new(ResolveOptions {
-            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
-            condition_names: vec!["module".into(), "require".into()],
-            tsconfig,
-            ..ResolveOptions::default()
-        })
+        });
+        resolve_options(tsconfig, conditions, preserve_symlinks)
     }
 
     fn get_source_type_and_text(
+        &self,
         path: &Path,
         ext: &str,
-    ) -> Option<Result<(SourceType, String), Error>> {
-        let source_type = SourceType::from_path(path);
-        let not_supported_yet =
-            source_type.as_ref().is_err_and(|_| !LINT_PARTIAL_LOADER_EXT.contains(&ext));
+        request: Option<SourceTextRequest>,
+    ) -> Option<Result<(SourceType, SourceText), Error>> {
+        let source_type = self.linter.source_type(path);
+        let not_supported_yet = source_type.as_ref().is_err_and(|_| {
+            !LINT_PARTIAL_LOADER_EXT.contains(&ext) && !self.processors.contains_key(ext)
+        });
         if not_supported_yet {
             return None;
         }
         let source_type = source_type.unwrap_or_default();
-        let file_result = fs::read_to_string(path)
+        let file_result = self
+            .read_request_or_file(path, request)
             .map_err(|e| Error::new(FailedToOpenFileError(path.to_path_buf(), e)));
         Some(match file_result {
             Ok(source_text) => Ok((source_type, source_text)),
@@ -186,17 +596,119 @@ impl Runtime {
         })
     }
 
-    fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
+    fn read_source_text(&self, path: &Path) -> io::Result<SourceText> {
+        if self.mmap {
+            if let Some(source_text) = Self::map_file(path) {
+                return Ok(source_text);
+            }
+        }
+        let bytes = fs::read(path)?;
+        let encoding = FileEncoding::detect(&bytes);
+        encoding.decode(bytes).map(|source_text| SourceText::Read(source_text, encoding))
+    }
+
+    fn read_request_or_file(
+        &self,
+        path: &Path,
+        request: Option<SourceTextRequest>,
+    ) -> io::Result<SourceText> {
+        match request {
+            Some(request) => Ok(SourceText::Read(request.source_text, FileEncoding::default())),
+            None => self.read_source_text(path),
+        }
+    }
+
+    fn hash_source_text(source_text: &str) -> u64 {
+        let mut hasher = FxHasher::default();
+        source_text.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// `None` if the file cannot be mapped or is not valid UTF-8, to read it instead,
+    /// which reports the same errors as without `mmap` and transcodes UTF-16 files.
+    fn map_file(path: &Path) -> Option<SourceText> {
+        let file = fs::File::open(path).ok()?;
+        // SAFETY: Files are not modified by the linter while they are mapped, see `Runtime::new`.
+        // This is not sound if another process changes the file while it is mapped: truncating it
+        // raises `SIGBUS` on access, and modifying it changes the `&str` borrowed from the map.
+        // Which is why `mmap` is opt-in, and documented to require files which do not change.
+        let mmap = unsafe { memmap2::Mmap::map(&file) }.ok()?;
+        let encoding = FileEncoding::detect(&mmap);
+        (encoding.encoding == Encoding::Utf8
+            && std::str::from_utf8(&mmap[encoding.bom_len()..]).is_ok())
+        .then_some(SourceText::Mapped(mmap, encoding))
+    }
+
+    /// A crash is reported as a diagnostic of the file, and the other files are still linted.
+    /// The dependencies loaded by the import plugin are processed with this as well, so that
+    /// a crash never leaves the cache state of a file pending, see `recover_from_panic`.
+    fn process_path(
+        &self,
+        path: &Path,
+        request: Option<SourceTextRequest>,
+        tx_error: &DiagnosticSender,
+    ) {
+        let result =
+            panic::catch_unwind(AssertUnwindSafe(|| self.process_file(path, request, tx_error)));
+        if let Err(payload) = result {
+            self.recover_from_panic(path, payload.as_ref(), tx_error);
+        }
+    }
+
+    fn process_file(
+        &self,
+        path: &Path,
+        request: Option<SourceTextRequest>,
+        tx_error: &DiagnosticSender,
+    ) {
+        let _span = debug_span!("file", path = %path.display()).entered();
         if self.init_cache_state(path) {
+            trace!("skipped: processed already");
             return;
         }
 
+        let mode = match &request {
+            Some(request) => FileMode {
+                lint: true,
+                fix: false,
+                cancellation_token: Some(request.cancellation_token),
+            },
+            None => {
+                let lint = self.paths.contains(path);
+                // Dependencies loaded by the import plugin are not fixed.
+                FileMode { lint, fix: self.linter.options().fix && lint, cancellation_token: None }
+            }
+        };
+
         let Some(ext) = path.extension().and_then(OsStr::to_str) else {
+            debug!("skipped: no file extension");
             self.ignore_path(path);
             return;
         };
 
-        let Some(source_type_and_text) = Self::get_source_type_and_text(path, ext) else {
+        // Checked before reading generated files of gigabytes, except when they are read anyway
+        // to be printed unchanged, see `send_limit_exceeded`.
+        let fix_to_stdout = mode.fix && self.linter.options().fix_output == FixOutput::Stdout;
+        if request.is_none() && !fix_to_stdout {
+            let size = fs::metadata(path).map_or(0, |metadata| metadata.len());
+            let size = usize::try_from(size).unwrap_or(usize::MAX);
+            if let Err(error) = self.limits.check_file_size(path, size) {
+                debug!("skipped: {error}");
+                self.ignore_path(path);
+                if mode.lint {
+                    tx_error.send(Some((paths::display(path, &self.cwd), vec![error]))).unwrap();
+                }
+                return;
+            }
+        }
+
+        if JSON_EXT.contains(&ext) && !self.processors.contains_key(ext) {
+            self.process_json(path, request, mode, tx_error);
+            return;
+        }
+
+        let Some(source_type_and_text) = self.get_source_type_and_text(path, ext, request) else {
+            debug!("skipped: unsupported file extension `{ext}`");
             self.ignore_path(path);
             return;
         };
@@ -204,43 +716,229 @@ impl Runtime {
         let (source_type, source_text) = match source_type_and_text {
             Ok(source_text) => source_text,
             Err(e) => {
+                debug!("skipped: {e}");
                 self.ignore_path(path);
-                tx_error.send(Some((path.to_path_buf(), vec![e]))).unwrap();
+                tx_error.send(Some((paths::display(path, &self.cwd), vec![e]))).unwrap();
                 return;
             }
         };
 
-        let sources = PartialLoader::parse(ext, &source_text);
+        if let Err(error) = self.limits.check_file_size(path, source_text.len()) {
+            debug!("skipped: {error}");
+            self.ignore_path(path);
+            self.send_limit_exceeded(path, &source_text, error, mode, tx_error);
+            return;
+        }
+
+        if self.linter.options().import_plugin {
+            self.content_hashes.insert(path.into(), Self::hash_source_text(&source_text));
+        }
+
+        let processor = match self.processors.get(ext) {
+            Some(processor) => Some(&**processor),
+            None => PartialLoader::processor(ext),
+        };
+        let sources = processor.map(|processor| processor.preprocess(&source_text));
         let is_processed_by_partial_loader = sources.is_some();
         let sources =
             sources.unwrap_or_else(|| vec![JavaScriptSource::new(&source_text, source_type, 0)]);
 
         if sources.is_empty() {
+            debug!("skipped: no script in the file");
             self.ignore_path(path);
             return;
         }
 
-        for JavaScriptSource { source_text, source_type, .. } in sources {
-            let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+        let fix_output = self.linter.options().fix_output;
+        let fix = mode.fix;
 
-            // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+        // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
+        if fix && is_processed_by_partial_loader && fix_output == FixOutput::Stdout {
+            Self::write_stdout(&source_text);
+        }
+
+        let low_memory = self.low_memory_threshold.is_some_and(|max| source_text.len() > max);
+        // The lock is still usable after a crash while it was held, see `recover_from_panic`
+        let _low_memory_guard =
+            low_memory.then(|| self.low_memory_lock.lock().unwrap_or_else(PoisonError::into_inner));
+
+        let file_encoding = source_text.encoding();
+        let file_text: &str = &source_text;
+        for source in sources {
+            let JavaScriptSource { source_text, source_type, start } = source;
+            let allocator =
+                if low_memory { PooledAllocator::unpooled() } else { PooledAllocator::take() };
+            let messages = self.process_source(
+                path,
+                &allocator,
+                source_text,
+                source_type,
+                true,
+                mode,
+                tx_error,
+            );
+            if let Some(memory_usage) = &self.memory_usage {
+                memory_usage.record_file(path, &allocator);
             }
 
-            if !messages.is_empty() {
-                let errors = messages.into_iter().map(|m| m.error).collect();
-                let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+            let (fixed_code, mut errors) = if fix && !is_processed_by_partial_loader {
+                let (fixed_code, errors) =
+                    self.fix_until_stable(path, source_text, source_type, messages, mode, tx_error);
+                let output = fixed_code.as_deref().unwrap_or(source_text);
+                match fix_output {
+                    FixOutput::Write => fs::write(path, file_encoding.encode(output)).unwrap(),
+                    FixOutput::Diff if fixed_code.is_some() => {
+                        let path = paths::display(path, &self.cwd);
+                        Self::write_stdout(&Self::diff(&path, source_text, output));
+                    }
+                    FixOutput::Diff => {}
+                    FixOutput::Stdout => Self::write_stdout(output),
+                }
+                (fixed_code, errors)
+            } else {
+                (None, messages.into_iter().map(|m| m.error).collect())
+            };
+
+            // Only the fixed code is printed to stdout.
+            if fix && fix_output == FixOutput::Stdout {
+                continue;
+            }
+
+            if let Some(processor) = processor {
+                errors = processor.postprocess(&source, errors);
+            }
+            if mode.is_cancelled() {
+                return;
+            }
+            if !errors.is_empty() {
+                let path = paths::display(path, &self.cwd);
+                // The remaining diagnostics are of the fixed code
+                let report_text = fixed_code.as_deref().unwrap_or(file_text);
+                let diagnostics =
+                    DiagnosticService::wrap_diagnostics_at(&path, report_text, start, errors);
                 tx_error.send(Some(diagnostics)).unwrap();
             }
         }
     }
 
+    /// Lints a JSON file, see `Linter::lint_json`. JSON files are not fixed.
+    fn process_json(
+        &self,
+        path: &Path,
+        request: Option<SourceTextRequest>,
+        mode: FileMode,
+        tx_error: &DiagnosticSender,
+    ) {
+        // JSON files are not modules
+        self.ignore_path(path);
+        let source_text = match self.read_request_or_file(path, request) {
+            Ok(source_text) => source_text,
+            Err(e) => {
+                let error = Error::new(FailedToOpenFileError(path.to_path_buf(), e));
+                tx_error.send(Some((paths::display(path, &self.cwd), vec![error]))).unwrap();
+                return;
+            }
+        };
+        if mode.fix && self.linter.options().fix_output == FixOutput::Stdout {
+            Self::write_stdout(&source_text);
+            return;
+        }
+        if let Err(error) = self.limits.check_file_size(path, source_text.len()) {
+            debug!("skipped: {error}");
+            self.send_limit_exceeded(path, &source_text, error, mode, tx_error);
+            return;
+        }
+        let errors = self.lint_json(path, &source_text, tx_error);
+        if !errors.is_empty() {
+            let path = paths::display(path, &self.cwd);
+            let diagnostics =
+                DiagnosticService::wrap_diagnostics_at(&path, &source_text, 0, errors);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+    }
+
+    /// Reports a file which is not linted because of `InputLimits`.
+    /// Dependencies loaded by the import plugin are not reported, and files fixed to stdout
+    /// are printed unchanged.
+    fn send_limit_exceeded(
+        &self,
+        path: &Path,
+        source_text: &str,
+        error: Error,
+        mode: FileMode,
+        tx_error: &DiagnosticSender,
+    ) {
+        if !mode.lint {
+            return;
+        }
+        if mode.fix && self.linter.options().fix_output == FixOutput::Stdout {
+            Self::write_stdout(source_text);
+            return;
+        }
+        tx_error.send(Some((paths::display(path, &self.cwd), vec![error]))).unwrap();
+    }
+
+    /// Applies the fixes, then lints the fixed code again for the fixes which overlapped others
+    /// or became possible, at most `MAX_FIX_PASSES` times like ESLint.
+    /// Returns the fixed code, `None` if nothing was fixed, and its remaining diagnostics.
+    fn fix_until_stable<'a>(
+        &self,
+        path: &Path,
+        source_text: &'a str,
+        source_type: SourceType,
+        messages: Vec<Message<'a>>,
+        mode: FileMode,
+        tx_error: &DiagnosticSender,
+    ) -> (Option<String>, Vec<Error>) {
+        let FixResult { fixed, fixed_code, messages } = Fixer::new(source_text, messages).fix();
+        if !fixed {
+            return (None, messages.into_iter().map(|m| m.error).collect());
+        }
+        let mut fixed_code = fixed_code.into_owned();
+
+        for _ in 1..MAX_FIX_PASSES {
+            let allocator = PooledAllocator::take();
+            let messages = self.process_source(
+                path,
+                &allocator,
+                &fixed_code,
+                source_type,
+                true,
+                mode,
+                tx_error,
+            );
+            let FixResult { fixed, fixed_code: next_code, messages } =
+                Fixer::new(&fixed_code, messages).fix();
+            if !fixed {
+                let errors = messages.into_iter().map(|m| m.error).collect();
+                drop(next_code);
+                return (Some(fixed_code), errors);
+            }
+            fixed_code = next_code.into_owned();
+        }
+
+        // The diagnostics of the last fixed code, which is not fixed again
+        let allocator = PooledAllocator::take();
+        let messages =
+            self.process_source(path, &allocator, &fixed_code, source_type, true, mode, tx_error);
+        let errors = messages.into_iter().map(|m| m.error).collect();
+        (Some(fixed_code), errors)
+    }
+
+    /// Unified diff between the original and the fixed code of a file.
+    fn diff(path: &Path, source_text: &str, fixed_code: &str) -> String {
+        let path = path.to_string_lossy();
+        TextDiff::from_lines(source_text, fixed_code)
+            .unified_diff()
+            .header(&format!("a/{path}"), &format!("b/{path}"))
+            .to_string()
+    }
+
+    /// Written at once so the output of files linted in parallel does not interleave.
+    fn write_stdout(output: &str) {
+        std::io::stdout().lock().write_all(output.as_bytes()).unwrap();
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn process_source<'a>(
         &self,
@@ -249,13 +947,41 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
+        mode: FileMode,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .allow_return_outside_function(true)
-            .parse();
+        let timings = self.linter.timings();
+        let start = timings.map(|_| Instant::now());
+        let ret = debug_span!("parse").in_scope(|| {
+            // Dependencies loaded by the import plugin are not linted
+            let mut parser = Parser::new(allocator, source_text, source_type)
+                .allow_return_outside_function(true)
+                .collect_tokens(mode.lint && self.needs_tokens(path));
+            if let Some(token) = mode.cancellation_token {
+                parser = parser.with_cancellation_token(token.clone());
+            }
+            // Stops before the stack overflows on the files which `check_nodes` would skip
+            if let Some(max) = self.limits.max_nesting_depth {
+                parser = parser.max_nesting_depth(max);
+            }
+            parser.parse()
+        });
+        if let (Some(timings), Some(start)) = (timings, start) {
+            timings.record_phase("parse", start.elapsed());
+        }
 
+        if let Some(max) =
+            ret.errors.iter().find_map(|error| error.downcast_ref::<NestingTooDeepError>())
+        {
+            let error = InputLimits::exceeded(
+                path,
+                format!("its syntax is nested deeper than the limit of {}", max.0),
+            );
+            debug!("not linted: {error}");
+            return vec![Message::new(error, None)];
+        }
         if !ret.errors.is_empty() {
+            debug!("not linted: {} syntax errors", ret.errors.len());
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
@@ -263,11 +989,18 @@ impl Runtime {
 
         // Build the module record to unblock other threads from waiting for too long.
         // The semantic model is not built at this stage.
-        let semantic_builder = SemanticBuilder::new(source_text, source_type)
-            .with_trivias(ret.trivias)
-            .with_check_syntax_error(check_syntax_errors)
-            .build_module_record(path.to_path_buf(), program);
+        let start = timings.map(|_| Instant::now());
+        let semantic_builder = debug_span!("module_record").in_scope(|| {
+            let mut builder = SemanticBuilder::new(source_text, source_type)
+                .with_trivias(ret.trivias)
+                .with_check_syntax_error(check_syntax_errors);
+            if let Some(token) = mode.cancellation_token {
+                builder = builder.with_cancellation_token(token.clone());
+            }
+            builder.build_module_record(path.to_path_buf(), program)
+        });
         let module_record = semantic_builder.module_record();
+        let semantic_duration = start.map(|start| start.elapsed());
 
         if self.linter.options().import_plugin {
             self.module_map.insert(
@@ -282,14 +1015,15 @@ impl Runtime {
                 .requested_modules
                 .keys()
                 .par_bridge()
-                .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
+                .map_with(self.resolver(path), |resolver, specifier| {
                     resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
                 })
                 .flatten()
                 .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
-                    let path = resolution.path();
-                    self.process_path(path, tx_error);
-                    if let Some(target_module_record_ref) = self.module_map.get(path) {
+                    // Resolved to canonicalized paths, with a verbatim prefix on Windows
+                    let path = &paths::normalize(resolution.path());
+                    self.process_path(path, None, tx_error);
+                    if let Some(target_module_record_ref) = self.module_map.get(path.as_path()) {
                         if let ModuleState::Resolved(target_module_record) =
                             target_module_record_ref.value()
                         {
@@ -301,22 +1035,145 @@ impl Runtime {
                 });
 
             // Stop if the current module is not marked for lint.
-            if !self.paths.contains(path) {
+            if !mode.lint {
+                trace!("not linted: dependency of a linted file");
                 return vec![];
             }
         }
 
-        let semantic_ret = semantic_builder.build(program);
+        let start = timings.map(|_| Instant::now());
+        let semantic_ret = debug_span!("semantic").in_scope(|| semantic_builder.build(program));
+        if let (Some(timings), Some(start), Some(duration)) = (timings, start, semantic_duration) {
+            timings.record_phase("semantic", duration + start.elapsed());
+        }
 
         if !semantic_ret.errors.is_empty() {
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
-        let lint_ctx =
-            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+        if let Err(error) = self.limits.check_nodes(path, semantic_ret.semantic.nodes()) {
+            debug!("not linted: {error}");
+            return vec![Message::new(error, None)];
+        }
+
+        let mut lint_ctx =
+            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic))
+                .with_tokens(ret.tokens);
+        if let Some(token) = mode.cancellation_token {
+            lint_ctx = lint_ctx.with_cancellation_token(token.clone());
+        }
+
+        let start = timings.map(|_| Instant::now());
+        let messages = debug_span!("lint").in_scope(|| self.run_linter(path, lint_ctx, tx_error));
+        if let (Some(timings), Some(start)) = (timings, start) {
+            timings.record_phase("lint", start.elapsed());
+        }
+        messages
+    }
+
+    /// The resolver for the imports of `path`, with the `tsconfig.json` of its workspace package.
+    fn resolver(&self, path: &Path) -> &Resolver {
+        let resolver = self.resolver.as_ref().unwrap();
+        let Some(workspace) = &self.workspace else { return resolver };
+        workspace
+            .package_of(&paths::absolute(path, &self.cwd))
+            .and_then(|package| self.package_resolvers.get(&package.dir))
+            .unwrap_or(resolver)
+    }
+
+    fn run_linter<'a>(
+        &self,
+        path: &Path,
+        lint_ctx: LintContext<'a>,
+        tx_error: &DiagnosticSender,
+    ) -> Vec<Message<'a>> {
+        if self.linter.options().nested_config {
+            match self.nested_linter(path, tx_error) {
+                Ok(Some(linter)) => return linter.run(lint_ctx),
+                Ok(None) => {}
+                Err(error) => return vec![Message::new(error, None)],
+            }
+        }
         self.linter.run(lint_ctx)
     }
 
+    /// Lint the JSON file `path` with the linter of its nested configuration files, like `run_linter`.
+    fn lint_json(&self, path: &Path, source_text: &str, tx_error: &DiagnosticSender) -> Vec<Error> {
+        if self.linter.options().nested_config {
+            match self.nested_linter(path, tx_error) {
+                Ok(Some(linter)) => return linter.lint_json(path, source_text),
+                Ok(None) => {}
+                Err(error) => return vec![error],
+            }
+        }
+        self.linter.lint_json(path, source_text)
+    }
+
+    /// Whether the rules linting `path` use the tokens, see `Linter::needs_tokens`.
+    fn needs_tokens(&self, path: &Path) -> bool {
+        if self.linter.options().nested_config {
+            let config_paths = self.nested_config_paths(path);
+            if !config_paths.is_empty() {
+                // The linter of the configuration files is created when the first file is linted,
+                // so the tokens are collected for it.
+                return self
+                    .nested_linters
+                    .get(&config_paths)
+                    .map_or(true, |linter| linter.needs_tokens(path));
+            }
+        }
+        self.linter.needs_tokens(path)
+    }
+
+    /// The nested configuration files applying to `path`, cached by directory.
+    fn nested_config_paths(&self, path: &Path) -> Arc<[PathBuf]> {
+        let path = paths::absolute(path, &self.cwd);
+        let Some(dir) = path.parent() else { return Arc::new([]) };
+
+        let config_paths = self.nested_config_paths.get(dir).map(|r| Arc::clone(r.value()));
+        config_paths.unwrap_or_else(|| {
+            let config_paths: Arc<[PathBuf]> =
+                self.linter.options().nested_config_paths(&path).into();
+            self.nested_config_paths.insert(dir.into(), Arc::clone(&config_paths));
+            config_paths
+        })
+    }
+
+    /// The linter for the nested configuration files applying to `path`,
+    /// `None` if there are no configuration files.
+    /// When the linter is created, the warnings of each configuration file are sent to `tx_error`
+    /// as the diagnostics of the file, the first time it is used.
+    fn nested_linter(
+        &self,
+        path: &Path,
+        tx_error: &DiagnosticSender,
+    ) -> Result<Option<Arc<Linter>>, Error> {
+        let config_paths = self.nested_config_paths(path);
+        if config_paths.is_empty() {
+            return Ok(None);
+        }
+
+        if let Some(linter) = self.nested_linters.get(&config_paths) {
+            return Ok(Some(Arc::clone(linter.value())));
+        }
+        let linter = Arc::new(self.linter.with_config_files(&config_paths)?);
+        self.nested_linters.insert(Arc::clone(&config_paths), Arc::clone(&linter));
+        for config_path in config_paths.iter() {
+            if !self.reported_config_paths.insert(config_path.clone()) {
+                continue;
+            }
+            // The warnings of the merged configuration do not tell which file they are from
+            let warnings = ESLintConfig::from_file(config_path)
+                .map(|mut config| config.take_warnings())
+                .unwrap_or_default();
+            if !warnings.is_empty() {
+                let path = paths::display(config_path, &self.cwd);
+                tx_error.send(Some((path, warnings))).unwrap();
+            }
+        }
+        Ok(Some(linter))
+    }
+
     fn init_cache_state(&self, path: &Path) -> bool {
         if !self.linter.options().import_plugin {
             return false;
@@ -372,6 +1229,98 @@ impl Runtime {
         }
     }
 
+    fn invalidate<P: AsRef<Path>>(&self, changed: &[P]) {
+        let mut stale =
+            changed.iter().map(|path| path.as_ref().to_path_buf()).collect::<FxHashSet<_>>();
+        // A module record holds the records of its dependencies, so the dependents are stale as well.
+        loop {
+            let dependents = self
+                .module_map
+                .iter()
+                .filter(|entry| !stale.contains(entry.key().as_ref()))
+                .filter(|entry| match entry.value() {
+                    ModuleState::Resolved(module_record) => module_record
+                        .loaded_modules
+                        .iter()
+                        .any(|loaded| stale.contains(&loaded.value().resolved_absolute_path)),
+                    ModuleState::Ignored => false,
+                })
+                .map(|entry| entry.key().to_path_buf())
+                .collect::<Vec<_>>();
+            if dependents.is_empty() {
+                break;
+            }
+            stale.extend(dependents);
+        }
+
+        let mut cache_state = self.cache_state.lock().unwrap();
+        for path in &stale {
+            self.module_map.remove(path.as_path());
+            self.content_hashes.remove(path.as_path());
+            cache_state.remove(path.as_path());
+        }
+        // A created, deleted or renamed file can change the resolution of any specifier.
+        if let Some(resolver) = &self.resolver {
+            resolver.clear_cache();
+        }
+    }
+
+    fn invalidate_changed(&self) -> Vec<PathBuf> {
+        let changed = self
+            .content_hashes
+            .iter()
+            .filter(|entry| {
+                self.read_source_text(entry.key()).map_or(true, |source_text| {
+                    Self::hash_source_text(&source_text) != *entry.value()
+                })
+            })
+            .map(|entry| entry.key().to_path_buf())
+            .collect::<Vec<_>>();
+        if !changed.is_empty() {
+            self.invalidate(&changed);
+        }
+        changed
+    }
+
+    fn recover_from_panic(&self, path: &Path, payload: &dyn Any, tx_error: &DiagnosticSender) {
+        // Do not leave other threads waiting on a module which will never be stored.
+        if self.linter.options().import_plugin && !self.module_map.contains_key(path) {
+            self.ignore_path(path);
+        }
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        let reproduction = self.reproduction_hint(path);
+        let path = paths::display(path, &self.cwd);
+        let error = Error::new(InternalError(path.clone(), message, reproduction));
+        tx_error.send(Some((path, vec![error]))).unwrap();
+    }
+
+    /// How to reproduce a crash on `path` with as little as possible: the file alone, with only
+    /// the rule which crashed, from the code it was visiting.
+    fn reproduction_hint(&self, path: &Path) -> String {
+        let display_path = paths::display(path, &self.cwd);
+        let Some((rule_name, span)) = crate::take_panicked_rule() else {
+            return format!(
+                "Run the same command on {display_path:?} alone, and remove code from it while the error persists."
+            );
+        };
+        // The spans of the scripts of other files are not of the file
+        let location = span.filter(|_| self.linter.source_type(path).is_ok()).and_then(|span| {
+            let source_text = self.read_source_text(path).ok()?;
+            let before = source_text.get(..span.start as usize)?;
+            let line = before.matches('\n').count() + 1;
+            let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+            Some(format!(" while visiting the code at {}:{line}:{column}", display_path.display()))
+        });
+        format!(
+            "The rule `{rule_name}` crashed{}. Run the same command on {display_path:?} alone with `-A all -D {rule_name}`, and remove code from it while the error persists.",
+            location.unwrap_or_default()
+        )
+    }
+
     fn ignore_path(&self, path: &Path) {
         if self.linter.options().import_plugin {
             self.module_map.insert(path.to_path_buf().into_boxed_path(), ModuleState::Ignored);
@@ -379,3 +1328,355 @@ impl Runtime {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env, fs,
+        ops::Deref,
+        path::{Path, PathBuf},
+        sync::mpsc,
+        sync::Arc,
+    };
+
+    use oxc_diagnostics::{DiagnosticService, Error};
+    use oxc_span::SourceType;
+    use rustc_hash::FxHashMap;
+
+    use super::{LintService, LintServiceOptions};
+    use crate::{
+        partial_loader::{JavaScriptSource, Processor},
+        AllowWarnDeny, FixOutput, LintOptions, Linter, Workspace,
+    };
+
+    /// A directory of the temporary directory, removed with its files when dropped,
+    /// even when the test fails
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        /// `name` must be unique among the tests, which run in parallel
+        fn new(name: &str) -> Self {
+            let dir = env::temp_dir().join(format!("oxc_linter_{name}_{}", std::process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir.canonicalize().unwrap())
+        }
+    }
+
+    impl Deref for TempDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn invalidate() {
+        let dir = TempDir::new("invalidate");
+        fs::write(dir.join("a.js"), "import './b.js';").unwrap();
+        fs::write(dir.join("b.js"), "export {};").unwrap();
+
+        let linter = Linter::from_options(LintOptions::default().with_import_plugin(true)).unwrap();
+        let options = LintServiceOptions::new(
+            dir.to_path_buf().into_boxed_path(),
+            vec![dir.join("a.js").into_boxed_path()],
+        );
+        let service = LintService::new(linter, options);
+        let diagnostic_service = DiagnosticService::default();
+        service.run(diagnostic_service.sender());
+        assert_eq!(service.number_of_dependencies(), 1);
+
+        fs::write(dir.join("b.js"), "import './c.js';").unwrap();
+        fs::write(dir.join("c.js"), "export {};").unwrap();
+        // Cached until invalidated
+        service.run(diagnostic_service.sender());
+        assert_eq!(service.number_of_dependencies(), 1);
+
+        service.invalidate(&[dir.join("b.js")]);
+        // `a.js` depends on `b.js`
+        assert_eq!(service.runtime.module_map.len(), 0);
+        service.run(diagnostic_service.sender());
+        assert_eq!(service.number_of_dependencies(), 2);
+    }
+
+    #[test]
+    fn crash() {
+        use oxc_ast::AstKind;
+        use oxc_semantic::AstNode;
+
+        use crate::{rule::RuleCategory, PluginContext, PluginRule, RulePlugin};
+
+        #[derive(Debug)]
+        struct AcmePlugin;
+
+        #[derive(Debug)]
+        struct Crash;
+
+        impl RulePlugin for AcmePlugin {
+            fn name(&self) -> &'static str {
+                "acme"
+            }
+
+            fn rules(&self) -> Vec<Arc<dyn PluginRule>> {
+                vec![Arc::new(Crash)]
+            }
+        }
+
+        impl PluginRule for Crash {
+            fn name(&self) -> &'static str {
+                "crash"
+            }
+
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Restriction
+            }
+
+            fn run<'a>(&self, node: &AstNode<'a>, _ctx: &PluginContext<'_, 'a>) {
+                if matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "crash")
+                {
+                    panic!("crashed");
+                }
+            }
+        }
+
+        let dir = TempDir::new("crash");
+        fs::write(dir.join("a.js"), "import './b.js';\ncrash;").unwrap();
+        fs::write(dir.join("b.js"), "export {};").unwrap();
+        fs::write(dir.join("c.js"), "import './b.js';\ndebugger;").unwrap();
+
+        let linter = Linter::from_options(LintOptions::default().with_import_plugin(true))
+            .unwrap()
+            .with_plugin(Arc::new(AcmePlugin))
+            .with_config_json(serde_json::json!({ "rules": { "acme/crash": "error" } }))
+            .unwrap();
+        let options = LintServiceOptions::new(
+            dir.to_path_buf().into_boxed_path(),
+            vec![dir.join("a.js").into_boxed_path(), dir.join("c.js").into_boxed_path()],
+        );
+        let service = LintService::new(linter, options);
+        let (tx_error, rx_error) = mpsc::sync_channel(16);
+        service.run(&tx_error);
+
+        let mut diagnostics: Vec<_> = rx_error
+            .iter()
+            .map_while(|diagnostics| diagnostics)
+            .filter(|(_, errors)| !errors.is_empty())
+            .collect();
+        diagnostics.sort_by(|a, b| a.0.cmp(&b.0));
+        // The other files are still linted
+        assert_eq!(diagnostics.len(), 2);
+        let (path, errors) = &diagnostics[0];
+        assert_eq!(path, Path::new("a.js"));
+        assert_eq!(errors[0].to_string(), "Internal error while processing \"a.js\": crashed");
+        let help = errors[0].help().unwrap().to_string();
+        assert!(help.contains("The rule `crash` crashed while visiting the code at a.js:2:1."));
+        assert!(help.contains("-A all -D crash"));
+        assert_eq!(diagnostics[1].0, Path::new("c.js"));
+    }
+
+    #[test]
+    fn invalidate_changed() {
+        let dir = TempDir::new("invalidate_changed");
+        fs::write(dir.join("a.js"), "import './b.js';").unwrap();
+        fs::write(dir.join("b.js"), "import './c.js';").unwrap();
+        fs::write(dir.join("c.js"), "export {};").unwrap();
+        fs::write(dir.join("d.js"), "export {};").unwrap();
+
+        let linter = Linter::from_options(LintOptions::default().with_import_plugin(true)).unwrap();
+        let options = LintServiceOptions::new(
+            dir.to_path_buf().into_boxed_path(),
+            vec![dir.join("a.js").into_boxed_path(), dir.join("d.js").into_boxed_path()],
+        );
+        let service = LintService::new(linter, options);
+        let diagnostic_service = DiagnosticService::default();
+        service.run(diagnostic_service.sender());
+        assert_eq!(service.runtime.module_map.len(), 4);
+        assert!(service.invalidate_changed().is_empty());
+        assert_eq!(service.runtime.module_map.len(), 4);
+
+        // Only `c.js` and the modules importing it directly or indirectly are dirty
+        fs::write(dir.join("c.js"), "export const c = 1;").unwrap();
+        assert_eq!(service.invalidate_changed(), [dir.join("c.js")]);
+        assert_eq!(service.runtime.module_map.len(), 1);
+        assert!(service.runtime.module_map.contains_key(dir.join("d.js").as_path()));
+
+        service.run(diagnostic_service.sender());
+        assert_eq!(service.runtime.module_map.len(), 4);
+        assert!(service.invalidate_changed().is_empty());
+    }
+
+    /// Lints the sections separated by `---`, and reports the diagnostics of the sections after the first.
+    struct SectionProcessor;
+
+    impl Processor for SectionProcessor {
+        fn preprocess<'a>(&self, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+            let mut start = 0;
+            source_text
+                .split("---")
+                .map(|section| {
+                    let source = JavaScriptSource::new(section, SourceType::default(), start);
+                    start += section.len() + 3;
+                    source
+                })
+                .collect()
+        }
+
+        fn postprocess(&self, source: &JavaScriptSource, diagnostics: Vec<Error>) -> Vec<Error> {
+            if source.start == 0 {
+                vec![]
+            } else {
+                diagnostics
+            }
+        }
+    }
+
+    #[test]
+    fn processor() {
+        let dir = TempDir::new("processor");
+        fs::write(dir.join("a.txt"), "debugger;\n---\ndebugger;\n").unwrap();
+
+        let linter = Linter::from_options(LintOptions::default()).unwrap();
+        let mut processors: FxHashMap<String, Arc<dyn Processor>> = FxHashMap::default();
+        processors.insert("txt".into(), Arc::new(SectionProcessor));
+        let options = LintServiceOptions {
+            processors,
+            ..LintServiceOptions::new(
+                dir.to_path_buf().into_boxed_path(),
+                vec![dir.join("a.txt").into_boxed_path()],
+            )
+        };
+        let service = LintService::new(linter, options);
+        let (tx_error, rx_error) = mpsc::sync_channel(16);
+        service.run(&tx_error);
+
+        let diagnostics: Vec<_> = rx_error
+            .iter()
+            .map_while(|diagnostics| diagnostics)
+            .filter(|(_, errors)| !errors.is_empty())
+            .collect();
+        assert_eq!(diagnostics.len(), 1);
+        let (_, errors) = &diagnostics[0];
+        assert_eq!(errors.len(), 1);
+        let label = errors[0].labels().unwrap().next().unwrap();
+        // In the file, after `debugger;\n---\n`
+        assert_eq!(label.offset(), 14);
+    }
+
+    #[test]
+    fn fix_until_stable() {
+        let dir = TempDir::new("fix");
+        let path = dir.join("a.js");
+        // The fix of the outer `if` overlaps the fix of the inner one
+        fs::write(&path, "if (a) {} else { if (b) {} else { if (c) {} } }").unwrap();
+
+        let options = LintOptions::default()
+            .with_filter(vec![
+                (AllowWarnDeny::Allow, "all".into()),
+                (AllowWarnDeny::Deny, "no-lonely-if".into()),
+            ])
+            .with_fix(true)
+            .with_fix_output(FixOutput::Write);
+        let linter = Linter::from_options(options).unwrap();
+        let options = LintServiceOptions::new(
+            dir.to_path_buf().into_boxed_path(),
+            vec![path.clone().into_boxed_path()],
+        );
+        let service = LintService::new(linter, options);
+        let (tx_error, rx_error) = mpsc::sync_channel(16);
+        service.run(&tx_error);
+
+        assert_eq!(
+            rx_error
+                .iter()
+                .map_while(|diagnostics| diagnostics)
+                .filter(|(_, errors)| !errors.is_empty())
+                .count(),
+            0
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "if (a) {} else if (b) {} else if (c) {}");
+    }
+
+    #[test]
+    fn fix_keeps_encoding() {
+        let dir = TempDir::new("encoding");
+        let utf8_bom = dir.join("utf8_bom.js");
+        fs::write(&utf8_bom, "\u{feff}if (a) {} else { if (b) {} }\n").unwrap();
+        let utf16 = dir.join("utf16.js");
+        fn utf16_bytes(text: &str) -> Vec<u8> {
+            b"\xFF\xFE"
+                .iter()
+                .copied()
+                .chain(text.encode_utf16().flat_map(u16::to_le_bytes))
+                .collect()
+        }
+        fs::write(&utf16, utf16_bytes("if (a) {} else { if (b) {} }\n")).unwrap();
+
+        let options = LintOptions::default()
+            .with_filter(vec![
+                (AllowWarnDeny::Allow, "all".into()),
+                (AllowWarnDeny::Deny, "no-lonely-if".into()),
+            ])
+            .with_fix(true)
+            .with_fix_output(FixOutput::Write);
+        let linter = Linter::from_options(options).unwrap();
+        let options = LintServiceOptions {
+            mmap: true,
+            ..LintServiceOptions::new(
+                dir.to_path_buf().into_boxed_path(),
+                vec![utf8_bom.clone().into_boxed_path(), utf16.clone().into_boxed_path()],
+            )
+        };
+        let service = LintService::new(linter, options);
+        let (tx_error, rx_error) = mpsc::sync_channel(16);
+        service.run(&tx_error);
+
+        // Neither the byte order marks nor the zero bytes of UTF-16 are reported as syntax errors
+        assert_eq!(
+            rx_error
+                .iter()
+                .map_while(|diagnostics| diagnostics)
+                .filter(|(_, errors)| !errors.is_empty())
+                .count(),
+            0
+        );
+        assert_eq!(fs::read_to_string(&utf8_bom).unwrap(), "\u{feff}if (a) {} else if (b) {}\n");
+        assert_eq!(fs::read(&utf16).unwrap(), utf16_bytes("if (a) {} else if (b) {}\n"));
+    }
+
+    #[test]
+    fn workspace_tsconfig() {
+        let root = env::current_dir().unwrap().join("fixtures/workspace");
+        // `@/b` is a path alias of the `tsconfig.json` of `packages/a`
+        let lint = |workspace: Option<Workspace>| {
+            let options = LintOptions::default().with_import_plugin(true).with_filter(vec![
+                (AllowWarnDeny::Allow, "all".into()),
+                (AllowWarnDeny::Deny, "no-unresolved".into()),
+            ]);
+            let linter = Linter::from_options(options).unwrap();
+            let options = LintServiceOptions {
+                workspace,
+                ..LintServiceOptions::new(
+                    root.clone().into_boxed_path(),
+                    vec![root.join("packages/a/src/index.ts").into_boxed_path()],
+                )
+            };
+            let service = LintService::new(linter, options);
+            let (tx_error, rx_error) = mpsc::sync_channel(16);
+            service.run(&tx_error);
+            rx_error
+                .iter()
+                .map_while(|diagnostics| diagnostics)
+                .filter(|(_, errors)| !errors.is_empty())
+                .count()
+        };
+
+        assert_eq!(lint(None), 1);
+        assert_eq!(lint(Workspace::discover(&root)), 0);
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
     env,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use oxc_allocator::Allocator;
@@ -10,8 +11,8 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{
-    rules::RULES, ESLintSettings, Fixer, LintOptions, LintService, LintServiceOptions, Linter,
-    RuleEnum,
+    rules::RULES, ESLintSettings, FixKind, Fixer, LintOptions, LintService, LintServiceOptions,
+    Linter, RuleEnum, TypeInfo,
 };
 
 #[derive(Eq, PartialEq)]
@@ -57,6 +58,8 @@ impl From<(&str, Option<Value>, Option<Value>, Option<PathBuf>)> for TestCase {
 
 pub struct Tester {
     rule_name: &'static str,
+    /// Set when more than one plugin has a rule named `rule_name`
+    plugin_name: Option<&'static str>,
     rule_path: PathBuf,
     expect_pass: Vec<TestCase>,
     expect_fail: Vec<TestCase>,
@@ -67,7 +70,11 @@ pub struct Tester {
     jest_plugin: bool,
     jsx_a11y_plugin: bool,
     nextjs_plugin: bool,
+    promise_plugin: bool,
     react_perf_plugin: bool,
+    security_plugin: bool,
+    jsdoc_plugin: bool,
+    type_info: Option<Arc<dyn TypeInfo>>,
 }
 
 impl Tester {
@@ -83,6 +90,7 @@ impl Tester {
             env::current_dir().unwrap().join("fixtures/import").into_boxed_path();
         Self {
             rule_name,
+            plugin_name: None,
             rule_path,
             expect_pass,
             expect_fail,
@@ -93,7 +101,11 @@ impl Tester {
             jest_plugin: false,
             jsx_a11y_plugin: false,
             nextjs_plugin: false,
+            promise_plugin: false,
             react_perf_plugin: false,
+            security_plugin: false,
+            jsdoc_plugin: false,
+            type_info: None,
         }
     }
 
@@ -103,6 +115,12 @@ impl Tester {
         self
     }
 
+    /// Pick the rule of `plugin_name` when several plugins have a rule with the same name
+    pub fn with_plugin_name(mut self, plugin_name: &'static str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
     pub fn with_import_plugin(mut self, yes: bool) -> Self {
         self.import_plugin = yes;
         self
@@ -123,11 +141,32 @@ impl Tester {
         self
     }
 
+    pub fn with_promise_plugin(mut self, yes: bool) -> Self {
+        self.promise_plugin = yes;
+        self
+    }
+
     pub fn with_react_perf_plugin(mut self, yes: bool) -> Self {
         self.react_perf_plugin = yes;
         self
     }
 
+    pub fn with_security_plugin(mut self, yes: bool) -> Self {
+        self.security_plugin = yes;
+        self
+    }
+
+    pub fn with_jsdoc_plugin(mut self, yes: bool) -> Self {
+        self.jsdoc_plugin = yes;
+        self
+    }
+
+    /// Provide the types of expressions to type-aware rules
+    pub fn with_type_info(mut self, type_info: Arc<dyn TypeInfo>) -> Self {
+        self.type_info = Some(type_info);
+        self
+    }
+
     pub fn expect_fix<S: Into<String>>(mut self, expect_fix: Vec<(S, S, Option<Value>)>) -> Self {
         self.expect_fix =
             expect_fix.into_iter().map(|(s1, s2, r)| (s1.into(), s2.into(), r)).collect::<Vec<_>>();
@@ -146,7 +185,10 @@ impl Tester {
     }
 
     pub fn snapshot(&self) {
-        let name = self.rule_name.replace('-', "_");
+        let name = match self.plugin_name {
+            Some(plugin_name) => format!("{plugin_name}_{}", self.rule_name.replace('-', "_")),
+            None => self.rule_name.replace('-', "_"),
+        };
         insta::with_settings!({ prepend_module_to_snapshot => false, }, {
             insta::assert_snapshot!(name.clone(), self.snapshot, &name);
         });
@@ -171,11 +213,10 @@ impl Tester {
     fn test_fix(&mut self) {
         for (test, expected, config) in self.expect_fix.clone() {
             let result = self.run(&test, config, true, &None, &None);
-            if let TestResult::Fixed(fixed_str) = result {
-                assert_eq!(expected, fixed_str);
-            } else {
-                unreachable!()
-            }
+            let TestResult::Fixed(fixed_str) = result else {
+                panic!("expect test to report a diagnostic: {test}");
+            };
+            assert_eq!(expected, fixed_str, "unexpected fix for: {test}");
         }
     }
 
@@ -194,15 +235,22 @@ impl Tester {
             .map_or_else(ESLintSettings::default, |v| ESLintSettings::deserialize(v).unwrap());
         let options = LintOptions::default()
             .with_fix(is_fix)
+            .with_fix_kind(FixKind::Dangerous)
             .with_import_plugin(self.import_plugin)
             .with_jest_plugin(self.jest_plugin)
             .with_jsx_a11y_plugin(self.jsx_a11y_plugin)
             .with_nextjs_plugin(self.nextjs_plugin)
-            .with_react_perf_plugin(self.react_perf_plugin);
-        let linter = Linter::from_options(options)
+            .with_promise_plugin(self.promise_plugin)
+            .with_react_perf_plugin(self.react_perf_plugin)
+            .with_security_plugin(self.security_plugin)
+            .with_jsdoc_plugin(self.jsdoc_plugin);
+        let mut linter = Linter::from_options(options)
             .unwrap()
             .with_rules(vec![rule])
             .with_settings(lint_settings);
+        if let Some(type_info) = &self.type_info {
+            linter = linter.with_type_info(Arc::clone(type_info));
+        }
         let path_to_lint = if self.import_plugin {
             assert!(path.is_none(), "import plugin does not support path");
             self.current_working_directory.join(&self.rule_path)
@@ -214,7 +262,7 @@ impl Tester {
 
         let cwd = self.current_working_directory.clone();
         let paths = vec![path_to_lint.into_boxed_path()];
-        let options = LintServiceOptions { cwd, paths, tsconfig: None };
+        let options = LintServiceOptions::new(cwd, paths);
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
@@ -229,12 +277,11 @@ impl Tester {
             return TestResult::Fixed(fix_result.fixed_code.to_string());
         }
 
-        let diagnostic_path = if self.import_plugin {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
-        } else {
-            &self.rule_path
-        }
-        .to_string_lossy();
+        let diagnostic_path = self
+            .rule_path
+            .strip_prefix(&self.current_working_directory)
+            .unwrap_or(&self.rule_path)
+            .to_string_lossy();
 
         let handler = GraphicalReportHandler::new().with_theme(GraphicalTheme::unicode_nocolor());
         for diagnostic in result {
@@ -251,7 +298,12 @@ impl Tester {
     fn find_rule(&self) -> &RuleEnum {
         RULES
             .iter()
-            .find(|rule| rule.name() == self.rule_name)
+            .find(|rule| {
+                rule.name() == self.rule_name
+                    && self
+                        .plugin_name
+                        .map_or(true, |plugin_name| rule.plugin_name() == plugin_name)
+            })
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name))
     }
 }
@@ -0,0 +1,161 @@
+use std::{io::Write, sync::Mutex, time::Duration};
+
+use rustc_hash::FxHashMap;
+
+/// Number of rules printed by `Timings::print`, same as ESLint's `TIMING=1`.
+const NUMBER_OF_RULES_TO_PRINT: usize = 10;
+
+/// Time spent in each phase of the pipeline and in each rule, summed over all linted files.
+/// Collected when `LintOptions::timing` is enabled.
+#[derive(Debug, Default)]
+pub struct Timings {
+    phases: Mutex<Vec<(&'static str, Duration)>>,
+    rules: Mutex<FxHashMap<(&'static str, &'static str), RuleTiming>>,
+}
+
+/// Time spent in a rule and the number of diagnostics it reported,
+/// to keep an eye on the rules of the nursery category before promoting them.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct RuleTiming {
+    pub duration: Duration,
+    pub diagnostics: usize,
+}
+
+impl Timings {
+    pub(crate) fn record_phase(&self, phase: &'static str, duration: Duration) {
+        let mut phases = self.phases.lock().unwrap();
+        // Keep the phases in the order they first ran.
+        match phases.iter_mut().find(|(name, _)| *name == phase) {
+            Some((_, total)) => *total += duration,
+            None => phases.push((phase, duration)),
+        }
+    }
+
+    /// Record the timings of the rules of one file, keyed by `(plugin_name, rule_name)`.
+    pub(crate) fn record_rules<I>(&self, timings: I)
+    where
+        I: IntoIterator<Item = ((&'static str, &'static str), RuleTiming)>,
+    {
+        let mut rules = self.rules.lock().unwrap();
+        for (rule, timing) in timings {
+            let total = rules.entry(rule).or_default();
+            total.duration += timing.duration;
+            total.diagnostics += timing.diagnostics;
+        }
+    }
+
+    /// Print the phases followed by the slowest rules with the number of diagnostics they reported,
+    /// formatted as markdown tables.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if writing to `writer` fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while recording timings.
+    pub fn print<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let phases = self.phases.lock().unwrap();
+        let rows = phases.iter().map(|(name, duration)| ((*name).to_string(), *duration, None));
+        Self::print_table(writer, "Phase", rows.collect())?;
+        writeln!(writer)?;
+
+        let rules = self.rules.lock().unwrap();
+        let mut rows = rules
+            .iter()
+            .map(|((plugin_name, rule_name), timing)| {
+                (format!("{plugin_name}/{rule_name}"), timing.duration, Some(timing.diagnostics))
+            })
+            .collect::<Vec<_>>();
+        rows.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        // Relative to all rules, not only the printed ones.
+        let total = rows.iter().map(|(_, duration, _)| *duration).sum::<Duration>();
+        rows.truncate(NUMBER_OF_RULES_TO_PRINT);
+        Self::print_table_with_total(writer, "Rule", rows, total)
+    }
+
+    fn print_table<W: Write>(
+        writer: &mut W,
+        header: &str,
+        rows: Vec<(String, Duration, Option<usize>)>,
+    ) -> std::io::Result<()> {
+        let total = rows.iter().map(|(_, duration, _)| *duration).sum::<Duration>();
+        Self::print_table_with_total(writer, header, rows, total)
+    }
+
+    /// The `Diagnostics` column is printed when the rows have a number of diagnostics.
+    fn print_table_with_total<W: Write>(
+        writer: &mut W,
+        header: &str,
+        rows: Vec<(String, Duration, Option<usize>)>,
+        total: Duration,
+    ) -> std::io::Result<()> {
+        let width = rows.iter().map(|(name, _, _)| name.len()).max().unwrap_or(0).max(header.len());
+        let has_diagnostics = rows.iter().any(|(_, _, diagnostics)| diagnostics.is_some());
+        if has_diagnostics {
+            writeln!(writer, "{header:<width$} | Time (ms) | Relative | Diagnostics")?;
+            writeln!(writer, ":{}|----------:|--------:|-----------:", "-".repeat(width))?;
+        } else {
+            writeln!(writer, "{header:<width$} | Time (ms) | Relative")?;
+            writeln!(writer, ":{}|----------:|--------:", "-".repeat(width))?;
+        }
+        for (name, duration, diagnostics) in rows {
+            let relative = if total.is_zero() {
+                0.0
+            } else {
+                duration.as_secs_f64() / total.as_secs_f64() * 100.0
+            };
+            let millis = duration.as_secs_f64() * 1000.0;
+            write!(writer, "{name:<width$} | {millis:>9.3} | {relative:>7.1}%")?;
+            match diagnostics {
+                Some(diagnostics) => writeln!(writer, " | {diagnostics:>11}")?,
+                None => writeln!(writer)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{RuleTiming, Timings};
+
+    #[test]
+    fn print() {
+        let timings = Timings::default();
+        timings.record_phase("parse", Duration::from_millis(3));
+        timings.record_phase("semantic", Duration::from_millis(1));
+        timings.record_phase("parse", Duration::from_millis(1));
+        timings.record_rules([
+            (
+                ("eslint", "no-debugger"),
+                RuleTiming { duration: Duration::from_millis(1), diagnostics: 2 },
+            ),
+            (
+                ("eslint", "no-unused-vars"),
+                RuleTiming { duration: Duration::from_millis(3), diagnostics: 0 },
+            ),
+        ]);
+        timings.record_rules([(
+            ("eslint", "no-debugger"),
+            RuleTiming { duration: Duration::ZERO, diagnostics: 1 },
+        )]);
+        let mut writer = vec![];
+        timings.print(&mut writer).unwrap();
+        let output = String::from_utf8(writer).unwrap();
+        let expected = "\
+Phase    | Time (ms) | Relative
+:--------|----------:|--------:
+parse    |     4.000 |    80.0%
+semantic |     1.000 |    20.0%
+
+Rule                  | Time (ms) | Relative | Diagnostics
+:---------------------|----------:|--------:|-----------:
+eslint/no-unused-vars |     3.000 |    75.0% |           0
+eslint/no-debugger    |     1.000 |    25.0% |           3
+";
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,174 @@
+//! Type information for type-aware rules, see `LintContext::expression_type`.
+
+use std::{
+    fmt::Debug,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+    Report,
+};
+use oxc_span::GetSpan;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to read type information {0:?} with error {1:?}")]
+#[diagnostic()]
+pub struct FailedToReadTypeInfoError(pub PathBuf, pub String);
+
+/// The type of an expression, as reported by a type checker.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpressionType {
+    /// The type as printed by the type checker, e.g. `Promise<void>`.
+    pub text: String,
+    /// Whether the type has a callable `then` method, e.g. `Promise<void>`.
+    #[serde(default)]
+    pub thenable: bool,
+}
+
+/// Answers the type queries of type-aware rules.
+///
+/// `TypeInfoFile` provides the types computed ahead of time by the TypeScript compiler,
+/// a type checker running along the linter can implement it as well.
+pub trait TypeInfo: Debug + Send + Sync {
+    /// The type of `expr` in the file at `path`, `None` when it is unknown.
+    fn expression_type(&self, path: &Path, expr: &Expression<'_>) -> Option<ExpressionType>;
+}
+
+/// Expression types read from a JSON file, e.g. written by a script using the TypeScript
+/// compiler API on a `tsc --build` project:
+///
+/// ```json
+/// {
+///   "files": {
+///     "src/index.ts": [
+///       { "start": 0, "end": 7, "text": "Promise<void>", "thenable": true }
+///     ]
+///   }
+/// }
+/// ```
+///
+/// File paths are relative to the JSON file, `start` and `end` are UTF-8 byte offsets.
+#[derive(Debug, Default)]
+pub struct TypeInfoFile {
+    /// The types of each file, keyed by the start and end of the expressions.
+    files: FxHashMap<PathBuf, FxHashMap<(u32, u32), ExpressionType>>,
+    /// The canonicalized paths of the looked up files which are not keys of `files`.
+    canonical_paths: DashMap<PathBuf, PathBuf>,
+}
+
+#[derive(Deserialize)]
+struct TypeInfoJson {
+    files: FxHashMap<PathBuf, Vec<TypeEntryJson>>,
+}
+
+#[derive(Deserialize)]
+struct TypeEntryJson {
+    start: u32,
+    end: u32,
+    #[serde(flatten)]
+    ty: ExpressionType,
+}
+
+impl TypeInfoFile {
+    /// # Errors
+    ///
+    /// Returns `Err` if the file cannot be read or is not in the format described above.
+    pub fn from_file(path: &Path) -> Result<Self, Report> {
+        let error = |err: String| FailedToReadTypeInfoError(path.to_path_buf(), err);
+        let string = std::fs::read_to_string(path).map_err(|err| error(err.to_string()))?;
+        let json = serde_json::from_str(&string).map_err(|err| error(err.to_string()))?;
+        Self::from_value(json, path.parent().unwrap_or_else(|| Path::new("")))
+            .map_err(|err| error(err.to_string()).into())
+    }
+
+    /// Parse in-memory type information, file paths are resolved against `base_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the value is not in the format described in `TypeInfoFile`.
+    pub fn from_value(json: serde_json::Value, base_dir: &Path) -> serde_json::Result<Self> {
+        let json = TypeInfoJson::deserialize(json)?;
+        let files = json
+            .files
+            .into_iter()
+            .map(|(path, entries)| {
+                let types =
+                    entries.into_iter().map(|entry| ((entry.start, entry.end), entry.ty)).collect();
+                (canonicalize(&base_dir.join(path)), types)
+            })
+            .collect();
+        Ok(Self { files, canonical_paths: DashMap::default() })
+    }
+}
+
+impl TypeInfo for TypeInfoFile {
+    fn expression_type(&self, path: &Path, expr: &Expression<'_>) -> Option<ExpressionType> {
+        let types = self.files.get(path).or_else(|| {
+            let canonical_path = self
+                .canonical_paths
+                .entry(path.to_path_buf())
+                .or_insert_with(|| canonicalize(path));
+            self.files.get(canonical_path.value())
+        })?;
+        let span = expr.span();
+        types.get(&(span.start, span.end)).cloned()
+    }
+}
+
+fn canonicalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+    use serde_json::json;
+
+    use super::{TypeInfo, TypeInfoFile};
+
+    #[test]
+    fn from_value() {
+        let json = json!({
+            "files": {
+                "index.ts": [
+                    { "start": 0, "end": 7, "text": "Promise<void>", "thenable": true },
+                ]
+            }
+        });
+        let type_info = TypeInfoFile::from_value(json, Path::new("src")).unwrap();
+
+        let allocator = Allocator::default();
+        let source_text = "fetch();\nfoo;";
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let types = ret
+            .program
+            .body
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::ExpressionStatement(stmt) => {
+                    type_info.expression_type(Path::new("src/index.ts"), &stmt.expression)
+                }
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types[0].as_ref().map(|ty| (ty.text.as_str(), ty.thenable)),
+            Some(("Promise<void>", true))
+        );
+        assert_eq!(types[1], None);
+
+        assert!(TypeInfoFile::from_value(json!({ "files": [] }), Path::new("")).is_err());
+    }
+}
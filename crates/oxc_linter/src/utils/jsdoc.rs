@@ -0,0 +1,175 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, FormalParameters},
+    AstKind,
+};
+use oxc_semantic::{JSDoc, JSDocTag, JSDocTagKind};
+use oxc_span::Span;
+
+use crate::{context::LintContext, AstNode};
+
+/// The JSDoc of the function `node`: the nearest JSDoc block of the function declaration, of the
+/// method, or of the declaration of the variable the function is assigned to
+pub fn get_function_jsdoc<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<JSDoc<'a>> {
+    let mut current = node;
+    loop {
+        if let Some(jsdoc) = ctx.jsdoc().get_one_by_node(current) {
+            return Some(jsdoc);
+        }
+        let parent = ctx.nodes().parent_node(current.id())?;
+        if !matches!(
+            parent.kind(),
+            AstKind::MethodDefinition(_)
+                | AstKind::PropertyDefinition(_)
+                | AstKind::VariableDeclarator(_)
+                | AstKind::VariableDeclaration(_)
+                | AstKind::ExportNamedDeclaration(_)
+                | AstKind::ExportDefaultDeclaration(_)
+                | AstKind::ModuleDeclaration(_)
+        ) {
+            return None;
+        }
+        current = parent;
+    }
+}
+
+/// The name of each parameter, `None` for destructured parameters.
+/// The name of a rest parameter is written without `...`.
+pub fn get_param_names<'a>(params: &'a FormalParameters<'a>) -> Vec<(Option<&'a str>, Span)> {
+    let name = |kind: &'a BindingPatternKind<'a>| match kind {
+        BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.as_str()),
+        BindingPatternKind::AssignmentPattern(pattern) => match &pattern.left.kind {
+            BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.as_str()),
+            _ => None,
+        },
+        _ => None,
+    };
+    params
+        .items
+        .iter()
+        .map(|param| (name(&param.pattern.kind), param.span))
+        .chain(params.rest.iter().map(|rest| (name(&rest.argument.kind), rest.span)))
+        .collect()
+}
+
+/// The name documented by a `@param` tag, without the brackets of optional parameters and their
+/// default values, e.g. `name` of `[name=0]`
+pub fn get_param_tag_name<'a>(tag: &JSDocTag<'a>) -> Option<&'a str> {
+    let JSDocTagKind::Parameter(param) = tag.kind else { return None };
+    let name = param.name.trim_start_matches('[').trim_start_matches("...");
+    let name = name.split(['=', ']']).next().unwrap_or_default();
+    (!name.is_empty()).then_some(name)
+}
+
+/// The type expression of a tag, between the braces starting its comment, e.g. `string` of
+/// `@returns {string} name`, and the rest of the comment
+pub fn split_tag_type(comment: &str) -> (Option<&str>, &str) {
+    let Some(rest) = comment.strip_prefix('{') else { return (None, comment) };
+    let mut depth = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return (Some(&rest[..i]), rest[i + 1..].trim_start()),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    (None, comment)
+}
+
+/// The type expression of a tag, for the tags with a type
+pub fn get_tag_type<'a>(tag: &'a JSDocTag<'a>) -> Option<&'a str> {
+    match tag.kind {
+        JSDocTagKind::Parameter(param) => param.r#type.map(|r#type| r#type.value),
+        JSDocTagKind::Unknown(
+            "returns" | "return" | "type" | "typedef" | "throws" | "exception" | "yields"
+            | "property" | "prop" | "this" | "enum" | "implements" | "augments" | "extends"
+            | "satisfies" | "template",
+        ) => split_tag_type(&tag.comment).0,
+        _ => None,
+    }
+}
+
+/// The names of the types referred to by a type expression, e.g. `Foo` and `Bar` of
+/// `Array<Foo | Bar.Baz>`. Properties of namespaces, names of object type properties, `typeof`
+/// operands and the types of `import()` are not included.
+pub fn get_type_names(type_expression: &str) -> Vec<&str> {
+    const KEYWORDS: [&str; 13] = [
+        "typeof", "keyof", "readonly", "function", "new", "import", "extends", "infer", "is",
+        "asserts", "unique", "in", "as",
+    ];
+    let bytes = type_expression.as_bytes();
+    let is_identifier_start = |b: u8| b.is_ascii_alphabetic() || b == b'_' || b == b'$';
+    let next_non_space = |i: usize| bytes[i..].iter().copied().find(|b| !b.is_ascii_whitespace());
+    let mut names = vec![];
+    let mut skip_next = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'\'' || b == b'"' || b == b'`' {
+            // String literal types
+            i += bytes[i + 1..].iter().position(|c| *c == b).map_or(bytes.len(), |end| end + 2);
+            continue;
+        }
+        if b.is_ascii_digit() {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'.') {
+                i += 1;
+            }
+            continue;
+        }
+        if !is_identifier_start(b) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len()
+            && (bytes[i].is_ascii_alphanumeric() || matches!(bytes[i], b'_' | b'$'))
+        {
+            i += 1;
+        }
+        let name = &type_expression[start..i];
+        let is_member = type_expression[..start].trim_end().ends_with('.');
+        let is_key = match next_non_space(i) {
+            Some(b':') => true,
+            Some(b'?') => type_expression[i..].trim_start()[1..].trim_start().starts_with(':'),
+            _ => false,
+        };
+        if name == "import" && next_non_space(i) == Some(b'(') {
+            // The types of modules are checked by the import plugin
+            let close = type_expression[i..].find(')').map_or(bytes.len(), |end| i + end + 1);
+            i = close;
+            continue;
+        }
+        if KEYWORDS.contains(&name) {
+            skip_next = name == "typeof";
+            continue;
+        }
+        if !std::mem::take(&mut skip_next) && !is_member && !is_key {
+            names.push(name);
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_type_names, split_tag_type};
+
+    #[test]
+    fn type_names() {
+        assert_eq!(get_type_names("string"), ["string"]);
+        assert_eq!(get_type_names("Array<Foo | Bar.Baz>"), ["Array", "Foo", "Bar"]);
+        assert_eq!(get_type_names("{a: Foo, b?: Bar}"), ["Foo", "Bar"]);
+        assert_eq!(get_type_names("function(new:Foo, string): void"), ["Foo", "string", "void"]);
+        assert_eq!(get_type_names("'a' | \"b\" | 1.5 | Foo[]"), ["Foo"]);
+        assert_eq!(get_type_names("typeof foo | keyof Foo"), ["Foo"]);
+        assert_eq!(get_type_names("import('./foo').Foo | ?Bar="), ["Bar"]);
+    }
+
+    #[test]
+    fn tag_type() {
+        assert_eq!(split_tag_type("{Object} Foo"), (Some("Object"), "Foo"));
+        assert_eq!(split_tag_type("{{a: string}} Foo"), (Some("{a: string}"), "Foo"));
+        assert_eq!(split_tag_type("Foo"), (None, "Foo"));
+        assert_eq!(split_tag_type("{Foo"), (None, "{Foo"));
+    }
+}
@@ -1,8 +1,14 @@
 mod jest;
+mod jsdoc;
 mod nextjs;
 mod node;
+mod promise;
 mod react;
 mod react_perf;
+mod security;
 mod unicorn;
 
-pub use self::{jest::*, nextjs::*, node::*, react::*, react_perf::*, unicorn::*};
+pub use self::{
+    jest::*, jsdoc::*, nextjs::*, node::*, promise::*, react::*, react_perf::*, security::*,
+    unicorn::*,
+};
@@ -0,0 +1,30 @@
+use oxc_ast::ast::{CallExpression, Expression};
+
+pub const PROMISE_STATIC_METHODS: [&str; 6] =
+    ["all", "allSettled", "any", "race", "reject", "resolve"];
+
+/// The name of the method called by `call`, e.g. `then` in `promise.then(fn)`
+pub fn get_method_name<'a>(call: &'a CallExpression) -> Option<&'a str> {
+    call.callee.get_member_expr()?.static_property_name()
+}
+
+/// Whether `call` takes promise callbacks, e.g. `promise.then(fn)` or `promise.catch(fn)`
+pub fn has_promise_callback(call: &CallExpression) -> bool {
+    matches!(get_method_name(call), Some("then" | "catch"))
+}
+
+/// Whether `expr` is a promise chain, e.g. `a.then()`, `a.catch().foo()` or `Promise.all([])`
+pub fn is_promise(expr: &Expression) -> bool {
+    let Expression::CallExpression(call) = expr.without_parenthesized() else { return false };
+    let Some(member) = call.callee.get_member_expr() else { return false };
+    let name = member.static_property_name();
+    if matches!(name, Some("then" | "catch" | "finally")) {
+        return true;
+    }
+    // Any method called on a promise is assumed to return a promise as well
+    if is_promise(member.object()) {
+        return true;
+    }
+    member.object().is_specific_id("Promise")
+        && name.is_some_and(|name| PROMISE_STATIC_METHODS.contains(&name))
+}
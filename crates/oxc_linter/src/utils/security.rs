@@ -0,0 +1,15 @@
+use oxc_ast::ast::{Argument, Expression};
+
+/// Whether `expr` is a string known at compile time, e.g. `'a'` or `` `a` ``
+pub fn is_static_string(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(lit) => lit.is_no_substitution_template(),
+        _ => false,
+    }
+}
+
+/// Whether `arg` is a string known at compile time, see [is_static_string]
+pub fn is_static_string_argument(arg: &Argument) -> bool {
+    matches!(arg, Argument::Expression(expr) if is_static_string(expr))
+}
@@ -4,12 +4,17 @@ use crate::LintContext;
 pub use self::boolean::*;
 use oxc_ast::{
     ast::{
-        BindingPatternKind, ChainElement, Expression, FormalParameters, FunctionBody,
-        LogicalExpression, MemberExpression, Statement,
+        Argument, BindingPatternKind, CallExpression, ChainElement, Expression, FormalParameters,
+        FunctionBody, LogicalExpression, MemberExpression, RegExpFlags, RegExpLiteral, Statement,
     },
     AstKind,
 };
+use oxc_js_regex::{
+    literal::{literal_pattern, LiteralPattern},
+    validator::PatternMode,
+};
 use oxc_semantic::AstNode;
+use oxc_span::GetSpan;
 use oxc_syntax::operator::LogicalOperator;
 
 pub fn is_node_value_not_dom_node(expr: &Expression) -> bool {
@@ -243,3 +248,68 @@ pub fn is_same_member_expression(
 
     return is_same_reference(left.object(), right.object(), ctx);
 }
+
+/// The string matched by a regex literal without the `i` and `m` flags, see `literal_pattern`
+pub fn get_regex_literal_pattern(regex: &RegExpLiteral) -> Option<LiteralPattern> {
+    let flags = regex.regex.flags;
+    if flags.intersects(RegExpFlags::I | RegExpFlags::M) {
+        return None;
+    }
+    let mode = if flags.contains(RegExpFlags::V) {
+        PatternMode::UnicodeSets
+    } else if flags.contains(RegExpFlags::U) {
+        PatternMode::Unicode
+    } else {
+        PatternMode::Legacy
+    };
+    literal_pattern(&regex.regex.pattern, mode)
+}
+
+/// The replacement of `regex.test(value)` by `value.method(text)`.
+///
+/// `value` is converted by `String()` like `RegExp#test` does unless it is a string already.
+/// Returns `None` for the `g` and `y` flags, which make `RegExp#test` depend on `lastIndex`.
+pub fn fix_regex_test(
+    call_expr: &CallExpression,
+    regex: &RegExpLiteral,
+    method: &str,
+    text: &str,
+    ctx: &LintContext,
+) -> Option<String> {
+    if regex.regex.flags.intersects(RegExpFlags::G | RegExpFlags::Y) {
+        return None;
+    }
+    let [Argument::Expression(value)] = call_expr.arguments.as_slice() else { return None };
+    let value_text = value.span().source_text(ctx.source_text());
+    let is_string = match value.without_parenthesized() {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::CallExpression(call_expr) => call_expr.callee.is_specific_id("String"),
+        _ => false,
+    };
+    let value_text =
+        if is_string { value_text.to_string() } else { format!("String({value_text})") };
+    Some(format!("{value_text}.{method}({})", to_string_literal(text)))
+}
+
+/// `text` as a double quoted string literal
+fn to_string_literal(text: &str) -> String {
+    let mut literal = String::with_capacity(text.len() + 2);
+    literal.push('"');
+    for c in text.chars() {
+        match c {
+            '"' | '\\' => {
+                literal.push('\\');
+                literal.push(c);
+            }
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() || matches!(c, '\u{2028}' | '\u{2029}') => {
+                literal.push_str(&format!("\\u{:04x}", u32::from(c)));
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
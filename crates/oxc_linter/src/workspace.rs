@@ -0,0 +1,199 @@
+//! npm, yarn and pnpm workspaces, so linting the root of a monorepo handles each of its packages.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+/// A package of a `Workspace`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspacePackage {
+    /// The `name` of its package.json
+    pub name: Option<String>,
+    /// The directory of its package.json
+    pub dir: PathBuf,
+    /// Its own `tsconfig.json`, used to resolve the imports of its files
+    pub tsconfig: Option<PathBuf>,
+}
+
+/// The packages of a monorepo, declared by the `workspaces` field of the root package.json
+/// for npm and yarn, or by the `packages` field of `pnpm-workspace.yaml` for pnpm.
+///
+/// The configuration files of a package are found by the nested configuration lookup,
+/// `Workspace` gives each package its own `tsconfig.json` and groups the files by package.
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    root: PathBuf,
+    /// Sorted by directory, a nested package comes after the package containing it.
+    packages: Vec<WorkspacePackage>,
+}
+
+impl Workspace {
+    /// Find the workspace containing `dir`, from `dir` upward.
+    pub fn discover(dir: &Path) -> Option<Self> {
+        dir.ancestors().find_map(|dir| {
+            let patterns = Self::read_patterns(dir)?;
+            Some(Self::from_patterns(dir, &patterns))
+        })
+    }
+
+    /// The workspace of the packages matching the glob `patterns`, relative to `root`.
+    /// Patterns starting with `!` exclude packages, `node_modules` are never included.
+    pub fn from_patterns<S: AsRef<str>>(root: &Path, patterns: &[S]) -> Self {
+        let (excludes, includes): (Vec<&str>, Vec<&str>) =
+            patterns.iter().map(AsRef::as_ref).partition(|pattern| pattern.starts_with('!'));
+        let excludes = excludes
+            .iter()
+            .filter_map(|pattern| glob::Pattern::new(pattern[1..].trim_end_matches('/')).ok())
+            .collect::<Vec<_>>();
+
+        let mut dirs = includes
+            .iter()
+            .filter_map(|pattern| {
+                let pattern = root.join(pattern.trim_end_matches('/')).join("package.json");
+                glob::glob(pattern.to_str()?).ok()
+            })
+            .flatten()
+            .flatten()
+            .filter_map(|path| path.parent().map(Path::to_path_buf))
+            .filter(|dir| {
+                let relative = dir.strip_prefix(root).unwrap_or(dir);
+                !relative.components().any(|component| component.as_os_str() == "node_modules")
+                    && !excludes.iter().any(|pattern| pattern.matches_path(relative))
+            })
+            .collect::<Vec<_>>();
+        dirs.sort_unstable();
+        dirs.dedup();
+
+        let packages = dirs.into_iter().map(WorkspacePackage::new).collect();
+        Self { root: root.to_path_buf(), packages }
+    }
+
+    /// The directory of the root package.json or `pnpm-workspace.yaml`.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn packages(&self) -> &[WorkspacePackage] {
+        &self.packages
+    }
+
+    /// The innermost package containing the absolute `path`,
+    /// `None` for the files of the workspace root which are not in any package.
+    pub fn package_of(&self, path: &Path) -> Option<&WorkspacePackage> {
+        self.package_index(path).map(|index| &self.packages[index])
+    }
+
+    /// Group `paths` by the package containing them, relative paths are resolved against `cwd`.
+    /// The files which are not in any package come last, empty groups are left out.
+    pub fn partition<'p>(
+        &self,
+        cwd: &Path,
+        paths: impl IntoIterator<Item = &'p Path>,
+    ) -> Vec<Vec<Box<Path>>> {
+        let mut partitions = vec![vec![]; self.packages.len() + 1];
+        for path in paths {
+            let index = self.package_index(&cwd.join(path)).unwrap_or(self.packages.len());
+            partitions[index].push(path.into());
+        }
+        partitions.retain(|paths| !paths.is_empty());
+        partitions
+    }
+
+    fn package_index(&self, path: &Path) -> Option<usize> {
+        self.packages.iter().rposition(|package| path.starts_with(&package.dir))
+    }
+
+    /// The package patterns declared in `dir`, `None` if `dir` is not the root of a workspace.
+    fn read_patterns(dir: &Path) -> Option<Vec<String>> {
+        if let Ok(yaml) = fs::read_to_string(dir.join("pnpm-workspace.yaml")) {
+            return Some(parse_pnpm_workspace(&yaml));
+        }
+        let package_json = fs::read_to_string(dir.join("package.json")).ok()?;
+        let package_json: Value = serde_json::from_str(&package_json).ok()?;
+        let workspaces = package_json.get("workspaces")?;
+        // yarn also accepts `{ "workspaces": { "packages": [] } }`
+        let patterns = workspaces.get("packages").unwrap_or(workspaces).as_array()?;
+        Some(patterns.iter().filter_map(Value::as_str).map(ToString::to_string).collect())
+    }
+}
+
+impl WorkspacePackage {
+    fn new(dir: PathBuf) -> Self {
+        let name = fs::read_to_string(dir.join("package.json"))
+            .ok()
+            .and_then(|package_json| serde_json::from_str::<Value>(&package_json).ok())
+            .and_then(|package_json| package_json.get("name")?.as_str().map(ToString::to_string));
+        let tsconfig = Some(dir.join("tsconfig.json")).filter(|path| path.is_file());
+        Self { name, dir, tsconfig }
+    }
+}
+
+/// The items of the top level `packages` list of `pnpm-workspace.yaml`, the only field used.
+fn parse_pnpm_workspace(yaml: &str) -> Vec<String> {
+    let mut patterns = vec![];
+    let mut in_packages = false;
+    for line in yaml.lines() {
+        let line = line.split(" #").next().unwrap_or_default().trim_end();
+        if line.trim_start().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = line.starts_with("packages:");
+            continue;
+        }
+        if let Some(item) = line.trim_start().strip_prefix('-').filter(|_| in_packages) {
+            patterns.push(item.trim().trim_matches(['\'', '"']).to_string());
+        }
+    }
+    patterns
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use super::{parse_pnpm_workspace, Workspace};
+
+    #[test]
+    fn package_json_workspaces() {
+        let root = env::current_dir().unwrap().join("fixtures/workspace");
+        let workspace = Workspace::discover(&root.join("packages/a/src")).unwrap();
+        assert_eq!(workspace.root(), root);
+
+        let names =
+            workspace.packages().iter().map(|package| package.name.as_deref()).collect::<Vec<_>>();
+        // `packages/ignored` is excluded
+        assert_eq!(names, [Some("a"), Some("b")]);
+        assert_eq!(
+            workspace.packages()[0].tsconfig.as_deref(),
+            Some(root.join("packages/a/tsconfig.json").as_path())
+        );
+        assert_eq!(workspace.packages()[1].tsconfig, None);
+
+        let package = workspace.package_of(&root.join("packages/a/src/index.ts"));
+        assert_eq!(package.and_then(|package| package.name.as_deref()), Some("a"));
+        assert!(workspace.package_of(&root.join("index.js")).is_none());
+
+        let paths = ["index.js", "packages/b/index.js", "packages/a/src/index.ts"];
+        let partitions = workspace.partition(&root, paths.iter().map(Path::new));
+        assert_eq!(
+            partitions,
+            [
+                vec![Path::new("packages/a/src/index.ts").into()],
+                vec![Path::new("packages/b/index.js").into()],
+                vec![Path::new("index.js").into()],
+            ]
+        );
+    }
+
+    #[test]
+    fn pnpm_workspace() {
+        let yaml = "# comment\npackages:\n  - 'apps/*'\n  - \"libs/**\" # libraries\n  - '!apps/legacy'\ncatalog:\n  - react\n";
+        assert_eq!(parse_pnpm_workspace(yaml), ["apps/*", "libs/**", "!apps/legacy"]);
+
+        assert!(Workspace::discover(Path::new("/")).is_none());
+    }
+}
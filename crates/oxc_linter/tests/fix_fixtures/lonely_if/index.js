@@ -0,0 +1,2 @@
+// The fix of the outer `if` overlaps the fix of the inner one, a second pass fixes the inner one
+if (a) {} else { if (b) {} else { if (c) {} } }
@@ -0,0 +1,132 @@
+//! Runs `--fix-dangerously` over each project of `fix_fixtures`, with the `.oxlintrc.json` at its
+//! root, so every kind of fix is checked, and snapshots the fixed files followed by the remaining
+//! diagnostics.
+//!
+//! The fixed files must parse, and fixing them again must not change them.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::mpsc,
+};
+
+use oxc_diagnostics::StructuredDiagnostic;
+use oxc_linter::{
+    AllowWarnDeny, FixKind, FixOutput, LintOptions, LintService, LintServiceOptions, Linter,
+};
+use oxc_span::VALID_EXTENSIONS;
+
+#[test]
+fn test_fix_fixtures() {
+    let fixtures = env::current_dir().unwrap().join("tests/fix_fixtures");
+    let mut projects = fs::read_dir(&fixtures)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    projects.sort_unstable();
+
+    for project in projects {
+        let name = project.file_name().unwrap().to_string_lossy().to_string();
+        let dir = env::temp_dir().join(format!("oxc_linter_fixer_{name}_{}", std::process::id()));
+        copy_dir(&project, &dir);
+        let dir = dir.canonicalize().unwrap();
+        let files = source_files(&dir);
+
+        let diagnostics = fix(&dir, &files);
+        let fixed = files.iter().map(|path| fs::read_to_string(path).unwrap()).collect::<Vec<_>>();
+        if let Some(diagnostic) = diagnostics.iter().find(|diagnostic| diagnostic.rule.is_none()) {
+            panic!("{name}: the fixed code does not parse: {diagnostic:?}");
+        }
+
+        // The fixes are stable when the fixed code has nothing left to fix
+        let diagnostics_again = fix(&dir, &files);
+        for (path, code) in files.iter().zip(&fixed) {
+            let code_again = fs::read_to_string(path).unwrap();
+            assert_eq!(code, &code_again, "{name}: fixing {path:?} again changed it");
+        }
+        assert_eq!(diagnostics, diagnostics_again, "{name}: fixing again changed the diagnostics");
+
+        let mut output = String::new();
+        for (path, code) in files.iter().zip(&fixed) {
+            let path = path.strip_prefix(&dir).unwrap().to_string_lossy().replace('\\', "/");
+            output.push_str(&format!("--- {path}\n{code}"));
+        }
+        output.push_str("--- diagnostics\n");
+        for diagnostic in &diagnostics {
+            let path = diagnostic.filename.to_string_lossy().replace('\\', "/");
+            let (line, column) = diagnostic.span.map_or((0, 0), |span| (span.line, span.column));
+            let rule = diagnostic.rule.as_deref().unwrap_or_default();
+            output.push_str(&format!("{path}:{line}:{column} {rule}: {}\n", diagnostic.message));
+        }
+        insta::assert_snapshot!(name, output);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Lints and fixes `files` in place with all kinds of fixes, returning the remaining diagnostics sorted by position.
+fn fix(dir: &Path, files: &[PathBuf]) -> Vec<StructuredDiagnostic> {
+    let options = LintOptions::default()
+        .with_filter(vec![(AllowWarnDeny::Allow, "all".into())])
+        .with_config_path(Some(dir.join(".oxlintrc.json")))
+        .with_fix(true)
+        .with_fix_kind(FixKind::Dangerous)
+        .with_fix_output(FixOutput::Write);
+    let linter = Linter::from_options(options).unwrap();
+    let options = LintServiceOptions::new(
+        dir.to_path_buf().into_boxed_path(),
+        files.iter().map(|path| path.clone().into_boxed_path()).collect(),
+    );
+    let service = LintService::new(linter, options);
+    // The diagnostics and the end of each file, then the end of the run
+    let (tx_error, rx_error) = mpsc::sync_channel(2 * files.len() + 1);
+    service.run(&tx_error);
+
+    let mut diagnostics = rx_error
+        .iter()
+        .map_while(|diagnostics| diagnostics)
+        .flat_map(|(path, errors)| {
+            errors.into_iter().map(move |error| StructuredDiagnostic::new(&path, &error))
+        })
+        .collect::<Vec<_>>();
+    diagnostics.sort_by_key(|diagnostic| {
+        let offset = diagnostic.span.map(|span| span.offset);
+        (diagnostic.filename.clone(), offset)
+    });
+    diagnostics
+}
+
+/// The files to lint in `dir`, sorted by path.
+fn source_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path
+                .extension()
+                .is_some_and(|ext| VALID_EXTENSIONS.contains(&ext.to_string_lossy().as_ref()))
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort_unstable();
+    files
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
@@ -10,6 +10,10 @@ use syn::{
 use trie::RulePathTrieBuilder;
 
 pub struct LintRuleMeta {
+    /// Name of the rule structure
+    struct_name: syn::Ident,
+    /// Name the structure is imported as, which includes the plugin when another plugin has
+    /// a rule with the same name, e.g. `EslintNoLonelyIf` and `UnicornNoLonelyIf`
     name: syn::Ident,
     path: syn::Path,
 }
@@ -17,11 +21,11 @@ pub struct LintRuleMeta {
 impl Parse for LintRuleMeta {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let path = input.parse::<syn::Path>()?;
-        let name = syn::parse_str(
+        let struct_name: syn::Ident = syn::parse_str(
             &path.segments.iter().last().unwrap().ident.to_string().to_case(Case::Pascal),
         )
         .unwrap();
-        Ok(Self { name, path })
+        Ok(Self { name: struct_name.clone(), struct_name, path })
     }
 }
 
@@ -31,11 +35,29 @@ pub struct AllLintRulesMeta {
 
 impl Parse for AllLintRulesMeta {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
-        let rules = input
+        let mut rules: Vec<LintRuleMeta> = input
             .parse_terminated::<LintRuleMeta, syn::Token![,]>(LintRuleMeta::parse)?
             .into_iter()
             .collect();
 
+        let duplicates = rules
+            .iter()
+            .filter(|rule| rules.iter().filter(|r| r.struct_name == rule.struct_name).count() > 1)
+            .map(|rule| rule.struct_name.clone())
+            .collect::<Vec<_>>();
+        for rule in &mut rules {
+            if duplicates.contains(&rule.struct_name) {
+                let path = rule
+                    .path
+                    .segments
+                    .iter()
+                    .map(|s| s.ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("_");
+                rule.name = syn::parse_str(&path.to_case(Case::Pascal)).unwrap();
+            }
+        }
+
         Ok(Self { rules })
     }
 }
@@ -94,7 +116,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
-            pub fn plugin_name(&self) -> &str {
+            pub fn plugin_name(&self) -> &'static str {
                 match self {
                     #(Self::#struct_names(_) => #mod_names),*
                 }
@@ -108,6 +130,24 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn validate_json(&self, value: &serde_json::Value) -> Result<(), String> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::validate_configuration(value)),*
+                }
+            }
+
+            pub fn node_types(&self) -> Option<&'static [oxc_ast::AstType]> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::node_types()),*
+                }
+            }
+
+            pub fn needs_tokens(&self) -> bool {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::needs_tokens()),*
+                }
+            }
+
             pub fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
@@ -125,17 +165,24 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                     #(Self::#struct_names(rule) => rule.run_once(ctx)),*
                 }
             }
+
+            pub fn run_on_program_exit<'a>(&self, ctx: &LintContext<'a>) {
+                match self {
+                    #(Self::#struct_names(rule) => rule.run_on_program_exit(ctx)),*
+                }
+            }
         }
 
         impl std::hash::Hash for RuleEnum {
             fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
                 self.name().hash(state);
+                self.plugin_name().hash(state);
             }
         }
 
         impl PartialEq for RuleEnum {
             fn eq(&self, other: &Self) -> bool {
-                self.name() == other.name()
+                self.name() == other.name() && self.plugin_name() == other.plugin_name()
             }
         }
 
@@ -143,7 +190,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
 
         impl Ord for RuleEnum {
             fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-                self.name().cmp(&other.name())
+                (self.name(), self.plugin_name()).cmp(&(other.name(), other.plugin_name()))
             }
         }
 
@@ -10,15 +10,15 @@ pub struct RulePathTrieNode {
 }
 
 enum NodeKind {
-    /// This node is a leaf node, stores its rule structure name
-    LeafNode(Ident),
+    /// This node is a leaf node, stores its rule structure name and the name it is imported as
+    LeafNode(Ident, Ident),
     /// This node is internal node, stores its children
     InternalNode(Vec<RulePathTrieNode>),
 }
 
 impl RulePathTrieNode {
-    pub fn leaf_node(mod_name: Ident, struct_name: Ident) -> Self {
-        Self { name: mod_name, kind: NodeKind::LeafNode(struct_name) }
+    pub fn leaf_node(mod_name: Ident, struct_name: Ident, alias: Ident) -> Self {
+        Self { name: mod_name, kind: NodeKind::LeafNode(struct_name, alias) }
     }
 
     pub fn internal_node(name: Ident) -> Self {
@@ -33,9 +33,12 @@ impl RulePathTrieNode {
         let name = &self.name;
         let mut stmts = quote! { #name };
         stmts = match &self.kind {
-            NodeKind::LeafNode(struct_name) => {
+            NodeKind::LeafNode(struct_name, alias) if struct_name == alias => {
                 quote! { #stmts::#struct_name }
             }
+            NodeKind::LeafNode(struct_name, alias) => {
+                quote! { #stmts::#struct_name as #alias }
+            }
             NodeKind::InternalNode(children) => {
                 let child_uses = children.iter().map(|node| node.use_stmt(false));
                 quote! {
@@ -81,7 +84,11 @@ impl RulePathTrieBuilder {
             cur = child;
         }
         // The last path is a leaf node
-        *cur = RulePathTrieNode::leaf_node(cur.name.clone(), rule_meta.name.clone());
+        *cur = RulePathTrieNode::leaf_node(
+            cur.name.clone(),
+            rule_meta.struct_name.clone(),
+            rule_meta.name.clone(),
+        );
     }
 
     pub fn finish(self) -> Vec<RulePathTrieNode> {
@@ -1,6 +1,7 @@
 mod closure;
 mod esbuild;
 mod oxc;
+mod property;
 mod tdewolff;
 mod terser;
 
@@ -60,7 +60,7 @@ fn conditional() {
     test("a ? b : c", "a?b:c;");
     test("a ? (b, c) : (d, e)", "a?(b,c):(d,e);");
     test("a ? b : c ? b : c", "a?b:c?b:c;");
-    test("(a ? b : c) ? b : c", "a?b:c?b:c;");
+    test("(a ? b : c) ? b : c", "(a?b:c)?b:c;");
     test("a, b ? c : d", "a,b?c:d;");
     test("(a, b) ? c : d", "(a,b)?c:d;");
     test("a = b ? c : d", "a=b?c:d;");
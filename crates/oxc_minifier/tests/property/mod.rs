@@ -0,0 +1,229 @@
+//! Property tests of the minifier on generated constant expressions.
+//!
+//! `x = <expression>` is minified, and the expression is evaluated before and after, by a small
+//! evaluator of the numbers and booleans the generator uses. The values must be the same,
+//! compared with `Object.is`, and the minified code must be minified the same again.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Statement};
+use oxc_minifier::MinifierOptions;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+use proptest::prelude::*;
+
+use crate::minify;
+
+const BINARY_OPERATORS: [&str; 19] = [
+    "+", "-", "*", "/", "%", "==", "!=", "===", "!==", "<", "<=", ">", ">=", "<<", ">>", ">>>",
+    "&", "|", "^",
+];
+
+fn expression() -> impl Strategy<Value = String> {
+    let leaf = prop_oneof![
+        (0u32..100).prop_map(|value| value.to_string()),
+        any::<bool>().prop_map(|value| value.to_string()),
+    ];
+    leaf.prop_recursive(4, 32, 2, |inner| {
+        prop_oneof![
+            (prop::sample::select(&["!", "-", "+", "~", "void"][..]), inner.clone())
+                .prop_map(|(operator, argument)| format!("{operator} ({argument})")),
+            (
+                inner.clone(),
+                prop::sample::select([&BINARY_OPERATORS[..], &["&&", "||"][..]].concat()),
+                inner.clone()
+            )
+                .prop_map(|(left, operator, right)| format!("({left}) {operator} ({right})")),
+            (inner.clone(), inner.clone(), inner).prop_map(|(test, consequent, alternate)| {
+                format!("({test}) ? ({consequent}) : ({alternate})")
+            }),
+        ]
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    Number(f64),
+    Boolean(bool),
+    Undefined,
+}
+
+#[allow(clippy::float_cmp)]
+impl Value {
+    fn to_number(self) -> f64 {
+        match self {
+            Self::Number(value) => value,
+            Self::Boolean(value) => f64::from(u8::from(value)),
+            Self::Undefined => f64::NAN,
+        }
+    }
+
+    fn to_boolean(self) -> bool {
+        match self {
+            Self::Number(value) => value != 0.0 && !value.is_nan(),
+            Self::Boolean(value) => value,
+            Self::Undefined => false,
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+    fn to_int32(self) -> i32 {
+        let value = self.to_number();
+        if !value.is_finite() {
+            return 0;
+        }
+        // Wrap modulo 2^32 as `ToInt32` does
+        (value.trunc().rem_euclid(4_294_967_296.0) as u32) as i32
+    }
+
+    /// `Object.is`
+    fn same_value(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => {
+                (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits()
+            }
+            (Self::Boolean(a), Self::Boolean(b)) => a == b,
+            (Self::Undefined, Self::Undefined) => true,
+            _ => false,
+        }
+    }
+
+    /// `==` of the values the generator produces, which are all converted to numbers
+    fn loose_equals(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Undefined, Self::Undefined) => true,
+            (Self::Undefined, _) | (_, Self::Undefined) => false,
+            _ => self.to_number() == other.to_number(),
+        }
+    }
+
+    fn strict_equals(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a == b,
+            _ => self.same_value(other),
+        }
+    }
+}
+
+/// The value of `expression`, `None` when it is not supported
+#[allow(clippy::cast_sign_loss)]
+fn evaluate(expression: &Expression) -> Option<Value> {
+    let value = match expression {
+        Expression::NumericLiteral(literal) => Value::Number(literal.value),
+        Expression::BooleanLiteral(literal) => Value::Boolean(literal.value),
+        Expression::Identifier(ident) => match ident.name.as_str() {
+            "undefined" => Value::Undefined,
+            "Infinity" => Value::Number(f64::INFINITY),
+            "NaN" => Value::Number(f64::NAN),
+            _ => return None,
+        },
+        Expression::ParenthesizedExpression(expr) => evaluate(&expr.expression)?,
+        Expression::UnaryExpression(expr) => {
+            let argument = evaluate(&expr.argument)?;
+            match expr.operator {
+                UnaryOperator::LogicalNot => Value::Boolean(!argument.to_boolean()),
+                UnaryOperator::UnaryNegation => Value::Number(-argument.to_number()),
+                UnaryOperator::UnaryPlus => Value::Number(argument.to_number()),
+                UnaryOperator::BitwiseNot => Value::Number(f64::from(!argument.to_int32())),
+                UnaryOperator::Void => Value::Undefined,
+                _ => return None,
+            }
+        }
+        Expression::LogicalExpression(expr) => {
+            let left = evaluate(&expr.left)?;
+            match expr.operator {
+                LogicalOperator::And if !left.to_boolean() => left,
+                LogicalOperator::Or if left.to_boolean() => left,
+                LogicalOperator::And | LogicalOperator::Or => evaluate(&expr.right)?,
+                LogicalOperator::Coalesce => return None,
+            }
+        }
+        Expression::ConditionalExpression(expr) => {
+            if evaluate(&expr.test)?.to_boolean() {
+                evaluate(&expr.consequent)?
+            } else {
+                evaluate(&expr.alternate)?
+            }
+        }
+        Expression::SequenceExpression(expr) => {
+            let mut value = None;
+            for expression in &expr.expressions {
+                value = Some(evaluate(expression)?);
+            }
+            value?
+        }
+        Expression::BinaryExpression(expr) => {
+            let left = evaluate(&expr.left)?;
+            let right = evaluate(&expr.right)?;
+            let (a, b) = (left.to_number(), right.to_number());
+            let shift = || (right.to_int32() as u32) & 31;
+            match expr.operator {
+                BinaryOperator::Addition => Value::Number(a + b),
+                BinaryOperator::Subtraction => Value::Number(a - b),
+                BinaryOperator::Multiplication => Value::Number(a * b),
+                BinaryOperator::Division => Value::Number(a / b),
+                BinaryOperator::Remainder => Value::Number(a % b),
+                BinaryOperator::Equality => Value::Boolean(left.loose_equals(right)),
+                BinaryOperator::Inequality => Value::Boolean(!left.loose_equals(right)),
+                BinaryOperator::StrictEquality => Value::Boolean(left.strict_equals(right)),
+                BinaryOperator::StrictInequality => Value::Boolean(!left.strict_equals(right)),
+                BinaryOperator::LessThan => Value::Boolean(a < b),
+                BinaryOperator::LessEqualThan => Value::Boolean(a <= b),
+                BinaryOperator::GreaterThan => Value::Boolean(a > b),
+                BinaryOperator::GreaterEqualThan => Value::Boolean(a >= b),
+                BinaryOperator::ShiftLeft => {
+                    Value::Number(f64::from(left.to_int32().wrapping_shl(shift())))
+                }
+                BinaryOperator::ShiftRight => {
+                    Value::Number(f64::from(left.to_int32().wrapping_shr(shift())))
+                }
+                BinaryOperator::ShiftRightZeroFill => {
+                    Value::Number(f64::from((left.to_int32() as u32).wrapping_shr(shift())))
+                }
+                BinaryOperator::BitwiseAnd => {
+                    Value::Number(f64::from(left.to_int32() & right.to_int32()))
+                }
+                BinaryOperator::BitwiseOR => {
+                    Value::Number(f64::from(left.to_int32() | right.to_int32()))
+                }
+                BinaryOperator::BitwiseXOR => {
+                    Value::Number(f64::from(left.to_int32() ^ right.to_int32()))
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// The value assigned by the program `x = <expression>`
+fn evaluate_assignment(source_text: &str) -> Option<Value> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    assert!(ret.errors.is_empty(), "failed to parse {source_text:?}: {:?}", ret.errors);
+    let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+        return None;
+    };
+    let Expression::AssignmentExpression(assignment) = &stmt.expression else { return None };
+    evaluate(&assignment.right)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn constant_expressions(expression in expression()) {
+        let source_text = format!("x = {expression};");
+        let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
+        let minified = minify(&source_text, SourceType::default(), options);
+
+        let expected = evaluate_assignment(&source_text).unwrap();
+        let value = evaluate_assignment(&minified);
+        prop_assert!(
+            value.is_some_and(|value| value.same_value(expected)),
+            "{} is {:?}, minified to {} which is {:?}", source_text, expected, minified, value
+        );
+        prop_assert_eq!(&minified, &minify(&minified, SourceType::default(), options));
+    }
+}
@@ -8,15 +8,23 @@ use oxc_span::SourceType;
 // create a `test.js`,
 // run `cargo run -p oxc_parser --example parser`
 // or `cargo watch -x "run -p oxc_parser --example parser"`
+// or `cargo run -p oxc_parser --example parser -- test.js --ast` to print the ESTree AST only
 
 fn main() -> Result<(), String> {
-    let name = env::args().nth(1).unwrap_or_else(|| "test.js".to_string());
+    let ast = env::args().any(|arg| arg == "--ast");
+    let name =
+        env::args().skip(1).find(|arg| !arg.starts_with("--")).unwrap_or_else(|| "test.js".into());
     let path = Path::new(&name);
     let source_text = std::fs::read_to_string(path).map_err(|_| format!("Missing '{name}'"))?;
     let allocator = Allocator::default();
     let source_type = SourceType::from_path(path).unwrap();
     let ret = Parser::new(&allocator, &source_text, source_type).parse();
 
+    if ast {
+        println!("{}", ret.program.to_estree_json(&source_text));
+        return Ok(());
+    }
+
     println!("AST:");
     println!("{}", serde_json::to_string_pretty(&ret.program).unwrap());
 
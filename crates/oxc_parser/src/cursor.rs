@@ -16,6 +16,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_pos: usize,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -111,6 +112,7 @@ impl<'a> ParserImpl<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.collect_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_token();
     }
@@ -119,10 +121,22 @@ impl<'a> ParserImpl<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance_for_jsx_child(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.collect_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Keep the current token when collecting tokens, after it has been re-lexed if needed.
+    /// The empty token before the start of the file and the hashbang are not tokens of the program.
+    #[inline]
+    fn collect_token(&mut self) {
+        if let Some(tokens) = &mut self.tokens {
+            if !matches!(self.token.kind, Kind::Eof | Kind::HashbangComment) {
+                tokens.push(self.token);
+            }
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     pub(crate) fn eat(&mut self, kind: Kind) -> bool {
         if self.at(kind) {
@@ -248,17 +262,26 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_pos: self.tokens.as_ref().map_or(0, Vec::len),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos: errors_lens } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos: errors_lens,
+            tokens_pos,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_lens);
+        if let Some(tokens) = &mut self.tokens {
+            tokens.truncate(tokens_pos);
+        }
     }
 
     /// # Errors
@@ -4,6 +4,8 @@ use oxc_diagnostics::{
 };
 use oxc_span::Span;
 
+use oxc_syntax::syntax_feature::{EcmaVersion, SyntaxFeature};
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Source length exceeds 4 GiB limit")]
 #[diagnostic()]
@@ -331,3 +333,13 @@ pub struct StaticConstructor(#[label] pub Span);
 #[error("No line break is allowed before '=>'.")]
 #[diagnostic()]
 pub struct NoLineBreakIsAllowedBeforeArrow(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("{0} is required for {1}")]
+#[diagnostic(help("The parser is set to an older ECMAScript version"))]
+pub struct RequiresEcmaVersion(pub EcmaVersion, pub SyntaxFeature, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unexpected identifier `{0}`")]
+#[diagnostic(help("Did you mean `{1}`?"))]
+pub struct MisspelledKeyword(pub String, pub &'static str, #[label] pub Span);
@@ -0,0 +1,205 @@
+//! Rejects syntax newer than the targeted ECMAScript edition, see [`crate::Parser::ecma_version`].
+
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, ObjectPropertyKind, Program, RegExpFlags,
+        VariableDeclarationKind,
+    },
+    AstKind, Visit,
+};
+use oxc_diagnostics::Error;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    syntax_feature::{EcmaVersion, SyntaxFeature},
+};
+
+use crate::diagnostics;
+
+/// Reports the syntax of a parsed program that was introduced after `ecma_version`.
+pub(crate) struct EcmaVersionChecker {
+    ecma_version: EcmaVersion,
+    /// The number of functions the current node is in, for top-level `await`
+    function_depth: usize,
+    errors: Vec<Error>,
+}
+
+impl EcmaVersionChecker {
+    pub(crate) fn check_program(program: &Program, ecma_version: EcmaVersion) -> Vec<Error> {
+        let mut checker = Self { ecma_version, function_depth: 0, errors: vec![] };
+        if let Some(hashbang) = &program.hashbang {
+            checker.check(SyntaxFeature::Hashbang, hashbang.span);
+        }
+        checker.visit_program(program);
+        checker.errors
+    }
+
+    fn check(&mut self, feature: SyntaxFeature, span: Span) {
+        let Some(version) = feature.ecma_version() else { return };
+        if self.ecma_version < version {
+            self.errors.push(diagnostics::RequiresEcmaVersion(version, feature, span).into());
+        }
+    }
+
+    fn check_arguments(&mut self, arguments: &[Argument]) {
+        for argument in arguments {
+            if let Argument::SpreadElement(spread) = argument {
+                self.check(SyntaxFeature::SpreadAndRest, spread.span);
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for EcmaVersionChecker {
+    #[allow(clippy::too_many_lines)]
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::VariableDeclaration(decl) if decl.kind != VariableDeclarationKind::Var => {
+                self.check(SyntaxFeature::LexicalDeclarations, decl.span);
+            }
+            AstKind::ForOfStatement(stmt) => {
+                if stmt.r#await {
+                    self.check(SyntaxFeature::ForAwaitOf, stmt.span);
+                } else {
+                    self.check(SyntaxFeature::ForOf, stmt.span);
+                }
+            }
+            AstKind::CatchClause(clause) if clause.param.is_none() => {
+                self.check(SyntaxFeature::OptionalCatchBinding, clause.span);
+            }
+            AstKind::Function(func) => {
+                self.function_depth += 1;
+                match (func.r#async, func.generator) {
+                    (true, true) => self.check(SyntaxFeature::AsyncGenerators, func.span),
+                    (true, false) => self.check(SyntaxFeature::AsyncFunctions, func.span),
+                    (false, true) => self.check(SyntaxFeature::Generators, func.span),
+                    (false, false) => {}
+                }
+            }
+            AstKind::ArrowFunctionExpression(expr) => {
+                self.function_depth += 1;
+                self.check(SyntaxFeature::ArrowFunctions, expr.span);
+                if expr.r#async {
+                    self.check(SyntaxFeature::AsyncFunctions, expr.span);
+                }
+            }
+            AstKind::AwaitExpression(expr) if self.function_depth == 0 => {
+                self.check(SyntaxFeature::TopLevelAwait, expr.span);
+            }
+            AstKind::AssignmentPattern(pattern) => {
+                self.check(SyntaxFeature::DefaultValues, pattern.span);
+            }
+            AstKind::BindingRestElement(rest) => {
+                self.check(SyntaxFeature::SpreadAndRest, rest.span);
+            }
+            AstKind::ArrayPattern(pattern) => {
+                self.check(SyntaxFeature::Destructuring, pattern.span);
+            }
+            AstKind::ObjectPattern(pattern) => {
+                self.check(SyntaxFeature::Destructuring, pattern.span);
+                if let Some(rest) = &pattern.rest {
+                    self.check(SyntaxFeature::ObjectRest, rest.span);
+                }
+            }
+            AstKind::Class(class) => self.check(SyntaxFeature::Classes, class.span),
+            AstKind::PropertyDefinition(def) => self.check(SyntaxFeature::ClassFields, def.span),
+            AstKind::StaticBlock(block) => self.check(SyntaxFeature::StaticBlocks, block.span),
+            AstKind::PrivateIdentifier(ident) => {
+                self.check(SyntaxFeature::PrivateMembers, ident.span);
+            }
+            AstKind::TemplateLiteral(lit) => self.check(SyntaxFeature::TemplateLiterals, lit.span),
+            AstKind::BigintLiteral(lit) => {
+                self.check(SyntaxFeature::Bigint, lit.span);
+                if lit.raw.contains('_') {
+                    self.check(SyntaxFeature::NumericSeparators, lit.span);
+                }
+            }
+            AstKind::NumericLiteral(lit) if lit.raw.contains('_') => {
+                self.check(SyntaxFeature::NumericSeparators, lit.span);
+            }
+            AstKind::RegExpLiteral(lit) => {
+                let flags = lit.regex.flags;
+                for (flag, feature) in [
+                    (RegExpFlags::U, SyntaxFeature::RegExpUnicodeFlag),
+                    (RegExpFlags::Y, SyntaxFeature::RegExpStickyFlag),
+                    (RegExpFlags::S, SyntaxFeature::RegExpDotAllFlag),
+                    (RegExpFlags::D, SyntaxFeature::RegExpIndicesFlag),
+                    (RegExpFlags::V, SyntaxFeature::RegExpUnicodeSetsFlag),
+                ] {
+                    if flags.contains(flag) {
+                        self.check(feature, lit.span);
+                    }
+                }
+            }
+            AstKind::ObjectExpression(expr) => {
+                for property in &expr.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(prop) => {
+                            if prop.computed {
+                                self.check(SyntaxFeature::ComputedPropertyNames, prop.span);
+                            }
+                            if prop.shorthand {
+                                self.check(SyntaxFeature::ShorthandProperties, prop.span);
+                            }
+                            if prop.method {
+                                self.check(SyntaxFeature::ShorthandMethods, prop.span);
+                            }
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            self.check(SyntaxFeature::ObjectSpread, spread.span);
+                        }
+                    }
+                }
+            }
+            AstKind::ArrayExpression(expr) => {
+                for element in &expr.elements {
+                    if let ArrayExpressionElement::SpreadElement(spread) = element {
+                        self.check(SyntaxFeature::SpreadAndRest, spread.span);
+                    }
+                }
+            }
+            AstKind::CallExpression(expr) => self.check_arguments(&expr.arguments),
+            AstKind::NewExpression(expr) => self.check_arguments(&expr.arguments),
+            AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::Exponential => {
+                self.check(SyntaxFeature::Exponentiation, expr.span);
+            }
+            AstKind::AssignmentExpression(expr) => match expr.operator {
+                AssignmentOperator::Exponential => {
+                    self.check(SyntaxFeature::Exponentiation, expr.span);
+                }
+                operator if operator.is_logical() => {
+                    self.check(SyntaxFeature::LogicalAssignment, expr.span);
+                }
+                _ => {}
+            },
+            AstKind::LogicalExpression(expr) if expr.operator == LogicalOperator::Coalesce => {
+                self.check(SyntaxFeature::NullishCoalescing, expr.span);
+            }
+            AstKind::ChainExpression(expr) => {
+                self.check(SyntaxFeature::OptionalChaining, expr.span);
+            }
+            AstKind::PrivateInExpression(expr) => {
+                self.check(SyntaxFeature::PrivateIn, expr.span);
+            }
+            AstKind::ImportExpression(expr) => self.check(SyntaxFeature::DynamicImport, expr.span),
+            AstKind::MetaProperty(prop) => {
+                if prop.meta.name == "import" {
+                    self.check(SyntaxFeature::ImportMeta, prop.span);
+                } else {
+                    self.check(SyntaxFeature::NewTarget, prop.span);
+                }
+            }
+            AstKind::ModuleDeclaration(decl) => self.check(SyntaxFeature::EsModules, decl.span()),
+            AstKind::ExportAllDeclaration(decl) if decl.exported.is_some() => {
+                self.check(SyntaxFeature::ExportAllAs, decl.span);
+            }
+            _ => {}
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+            self.function_depth -= 1;
+        }
+    }
+}
@@ -70,7 +70,7 @@ impl<'a> ParserImpl<'a> {
     ///     `BindingPattern`[?Yield, ?Await] `Initializer`[+In, ?Yield, ?Await]opt
     pub(crate) fn parse_binding_pattern(&mut self) -> Result<BindingPattern<'a>> {
         let span = self.start_span();
-        let pattern = self.parse_binding()?.0;
+        let pattern = self.nested(Self::parse_binding)?.0;
         self.with_context(Context::In, |p| p.parse_initializer(span, pattern))
     }
 
@@ -828,7 +828,7 @@ impl<'a> ParserImpl<'a> {
         if self.cur_kind().is_update_operator() {
             let operator = map_update_operator(self.cur_kind());
             self.bump_any();
-            let argument = self.parse_unary_expression_base(lhs_span)?;
+            let argument = self.nested(|p| p.parse_unary_expression_base(lhs_span))?;
             let argument = SimpleAssignmentTarget::cover(argument, self)?;
             return Ok(self.ast.update_expression(
                 self.end_span(lhs_span),
@@ -843,14 +843,35 @@ impl<'a> ParserImpl<'a> {
             return self.parse_unary_expression();
         }
 
+        if self.at(Kind::Ident) {
+            return self.parse_update_expression_or_misspelled_keyword(lhs_span);
+        }
+
         self.parse_update_expression()
     }
 
+    /// `awiat foo()` is parsed as `await foo()`, with a did-you-mean error
+    fn parse_update_expression_or_misspelled_keyword(
+        &mut self,
+        lhs_span: Span,
+    ) -> Result<Expression<'a>> {
+        let checkpoint = self.checkpoint();
+        let expr = self.parse_update_expression()?;
+        if let Expression::Identifier(ident) = &expr {
+            if let Some(keyword) = self.misspelled_expression_keyword(&ident.name) {
+                self.rewind(checkpoint);
+                self.recover_misspelled_keyword(keyword);
+                return self.parse_unary_expression_base(lhs_span);
+            }
+        }
+        Ok(expr)
+    }
+
     fn parse_unary_expression(&mut self) -> Result<Expression<'a>> {
         let span = self.start_span();
         let operator = map_unary_operator(self.cur_kind());
         self.bump_any();
-        let argument = self.parse_unary_expression_base(span)?;
+        let argument = self.nested(|p| p.parse_unary_expression_base(span))?;
         Ok(self.ast.unary_expression(self.end_span(span), operator, argument))
     }
 
@@ -927,7 +948,8 @@ impl<'a> ParserImpl<'a> {
             }
 
             self.bump_any(); // bump operator
-            let rhs = self.parse_binary_or_logical_expression_base(left_precedence)?;
+            let rhs =
+                self.nested(|p| p.parse_binary_or_logical_expression_base(left_precedence))?;
 
             lhs = if kind.is_logical_operator() {
                 self.ast.logical_expression(
@@ -973,6 +995,10 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn parse_assignment_expression_base(&mut self) -> Result<Expression<'a>> {
+        self.nested(Self::parse_assignment_expression_base_impl)
+    }
+
+    fn parse_assignment_expression_base_impl(&mut self) -> Result<Expression<'a>> {
         match self.is_parenthesized_arrow_function() {
             IsParenthesizedArrowFunction::True => {
                 return self.parse_parenthesized_arrow_function();
@@ -0,0 +1,130 @@
+//! Recovery from misspelled keywords, e.g. `funtcion foo() {}` or `awiat foo()`.
+//!
+//! An identifier directly followed by another token on the same line is a syntax error,
+//! when the identifier is a few edits away from a keyword it is reported with a suggestion
+//! and parsed as the keyword.
+
+use crate::{diagnostics, lexer::Kind, ParserImpl};
+
+/// Keywords starting a statement or a declaration
+const STATEMENT_KEYWORDS: &[Kind] = &[
+    Kind::Async,
+    Kind::Class,
+    Kind::Const,
+    Kind::Export,
+    Kind::Function,
+    Kind::Import,
+    Kind::Let,
+    Kind::Return,
+    Kind::Throw,
+    Kind::Var,
+];
+
+/// Keywords starting a unary expression
+const EXPRESSION_KEYWORDS: &[Kind] =
+    &[Kind::Await, Kind::Delete, Kind::New, Kind::Typeof, Kind::Void];
+
+impl<'a> ParserImpl<'a> {
+    /// The statement keyword misspelled by the identifier statement `name`,
+    /// which is followed by the current token.
+    pub(crate) fn misspelled_statement_keyword(&self, name: &str) -> Option<Kind> {
+        let token = self.cur_token();
+        if token.is_on_new_line || matches!(token.kind, Kind::Semicolon | Kind::RCurly | Kind::Eof)
+        {
+            return None;
+        }
+        suggest_keyword(name, STATEMENT_KEYWORDS)
+    }
+
+    /// The unary keyword misspelled by the identifier reference `name`,
+    /// which is followed by the current token, e.g. `awiat foo`.
+    pub(crate) fn misspelled_expression_keyword(&self, name: &str) -> Option<Kind> {
+        let token = self.cur_token();
+        if token.is_on_new_line
+            || !(matches!(token.kind, Kind::Ident | Kind::This) || token.kind.is_literal())
+        {
+            return None;
+        }
+        suggest_keyword(name, EXPRESSION_KEYWORDS)
+    }
+
+    /// Report the identifier at the current token as a misspelling of `keyword`,
+    /// and continue as if it were `keyword`.
+    pub(crate) fn recover_misspelled_keyword(&mut self, keyword: Kind) {
+        let span = self.cur_token().span();
+        let name = self.cur_src().to_string();
+        self.error(diagnostics::MisspelledKeyword(name, keyword.to_str(), span));
+        self.token.kind = keyword;
+    }
+}
+
+/// The closest keyword of `keywords` to `name`, within one edit for short keywords and two
+/// for longer ones, where an edit is an insertion, a deletion, a substitution or a swap of
+/// adjacent characters.
+fn suggest_keyword(name: &str, keywords: &[Kind]) -> Option<Kind> {
+    keywords
+        .iter()
+        .filter_map(|&keyword| {
+            let keyword_str = keyword.to_str();
+            let max_distance = if keyword_str.len() >= 7 { 2 } else { 1 };
+            let distance = edit_distance(name, keyword_str);
+            (distance > 0 && distance <= max_distance).then_some((distance, keyword))
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, keyword)| keyword)
+}
+
+/// The optimal string alignment distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    if a.len().abs_diff(b.len()) > 2 {
+        return usize::MAX;
+    }
+    // distances[i][j] is the distance between a[..i] and b[..j]
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{edit_distance, suggest_keyword, EXPRESSION_KEYWORDS, STATEMENT_KEYWORDS};
+    use crate::lexer::Kind;
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("function", "function"), 0);
+        assert_eq!(edit_distance("funtcion", "function"), 1);
+        assert_eq!(edit_distance("fuction", "function"), 1);
+        assert_eq!(edit_distance("fnuctoin", "function"), 2);
+        assert_eq!(edit_distance("awiat", "await"), 1);
+    }
+
+    #[test]
+    fn suggestion() {
+        assert_eq!(suggest_keyword("funtcion", STATEMENT_KEYWORDS), Some(Kind::Function));
+        assert_eq!(suggest_keyword("cosnt", STATEMENT_KEYWORDS), Some(Kind::Const));
+        assert_eq!(suggest_keyword("retrun", STATEMENT_KEYWORDS), Some(Kind::Return));
+        assert_eq!(suggest_keyword("awiat", EXPRESSION_KEYWORDS), Some(Kind::Await));
+        assert_eq!(suggest_keyword("foo", STATEMENT_KEYWORDS), None);
+        assert_eq!(suggest_keyword("cnstt", STATEMENT_KEYWORDS), None);
+    }
+}
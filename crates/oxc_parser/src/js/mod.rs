@@ -10,6 +10,7 @@ mod class;
 pub mod declaration;
 mod expression;
 pub mod function;
+mod misspelled_keyword;
 mod module;
 mod object;
 mod operator;
@@ -39,6 +39,7 @@ impl<'a> ParserImpl<'a> {
 
         let mut expecting_directives = true;
         while !self.at(Kind::Eof) {
+            self.check_cancelled()?;
             match self.cur_kind() {
                 Kind::RCurly if !is_top_level => break,
                 Kind::Import if !matches!(self.peek_kind(), Kind::Dot | Kind::LParen) => {
@@ -96,6 +97,13 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_statement_list_item(
         &mut self,
         stmt_ctx: StatementContext,
+    ) -> Result<Statement<'a>> {
+        self.nested(|p| p.parse_statement_list_item_impl(stmt_ctx))
+    }
+
+    fn parse_statement_list_item_impl(
+        &mut self,
+        stmt_ctx: StatementContext,
     ) -> Result<Statement<'a>> {
         let start_span = self.start_span();
 
@@ -138,12 +146,16 @@ impl<'a> ParserImpl<'a> {
             _ if self.ts_enabled() && self.at_start_of_ts_declaration() => {
                 self.parse_ts_declaration_statement(start_span)
             }
-            _ => self.parse_expression_or_labeled_statement(),
+            _ => self.parse_expression_or_labeled_statement(stmt_ctx),
         }
     }
 
-    fn parse_expression_or_labeled_statement(&mut self) -> Result<Statement<'a>> {
+    fn parse_expression_or_labeled_statement(
+        &mut self,
+        stmt_ctx: StatementContext,
+    ) -> Result<Statement<'a>> {
         let span = self.start_span();
+        let checkpoint = self.at(Kind::Ident).then(|| self.checkpoint());
         let expr = self.parse_expression()?;
         if let Expression::Identifier(ident) = &expr {
             // Section 14.13 Labelled Statement
@@ -153,6 +165,14 @@ impl<'a> ParserImpl<'a> {
                 let body = self.parse_statement_list_item(StatementContext::Label)?;
                 return Ok(self.ast.labeled_statement(self.end_span(span), label, body));
             }
+            // `funtcion foo() {}`
+            if let Some(checkpoint) = checkpoint {
+                if let Some(keyword) = self.misspelled_statement_keyword(&ident.name) {
+                    self.rewind(checkpoint);
+                    self.recover_misspelled_keyword(keyword);
+                    return self.parse_statement_list_item(stmt_ctx);
+                }
+            }
         }
         self.parse_expression_statement(span, expr)
     }
@@ -3,6 +3,10 @@
 //! * `ByteMatchTable` and `SafeByteMatchTable` are lookup table types for byte values.
 //! * `byte_match_table!` and `safe_byte_match_table!` macros create those tables at compile time.
 //! * `byte_search!` macro searches source text for first byte matching a byte table.
+//!
+//! Tables also hold a `NibbleTable` when the pattern allows it, to search batches with SIMD.
+
+use super::simd::NibbleTable;
 
 /// Batch size for searching
 pub const SEARCH_BATCH_SIZE: usize = 32;
@@ -39,13 +43,13 @@ pub const SEARCH_BATCH_SIZE: usize = 32;
 /// ```
 // TODO: Delete this type + `byte_match_table!` macro if not used
 #[repr(C, align(64))]
-pub struct ByteMatchTable([bool; 256]);
+pub struct ByteMatchTable([bool; 256], Option<NibbleTable>);
 
 #[allow(dead_code)]
 impl ByteMatchTable {
     // Create new `ByteMatchTable`.
     pub const fn new(bytes: [bool; 256]) -> Self {
-        let mut table = Self([false; 256]);
+        let mut table = Self([false; 256], None);
         let mut i = 0;
         loop {
             table.0[i] = bytes[i];
@@ -54,6 +58,7 @@ impl ByteMatchTable {
                 break;
             }
         }
+        table.1 = NibbleTable::new(&bytes);
         table
     }
 
@@ -70,6 +75,20 @@ impl ByteMatchTable {
     pub const fn matches(&self, b: u8) -> bool {
         self.0[b as usize]
     }
+
+    /// Search `SEARCH_BATCH_SIZE` bytes from `ptr` with SIMD, see `NibbleTable::search_batch`.
+    /// Returns `None` if SIMD cannot be used for this table.
+    ///
+    /// # SAFETY
+    /// There must be at least `SEARCH_BATCH_SIZE` bytes readable from `ptr`.
+    #[inline]
+    pub unsafe fn search_batch(&self, ptr: *const u8) -> Option<usize> {
+        match &self.1 {
+            // SAFETY: Caller guarantees `ptr` can be read.
+            Some(nibbles) => unsafe { nibbles.search_batch(ptr) },
+            None => None,
+        }
+    }
 }
 
 /// Macro to create a `ByteMatchTable` at compile time.
@@ -159,12 +178,12 @@ pub(crate) use byte_match_table;
 /// }
 /// ```
 #[repr(C, align(64))]
-pub struct SafeByteMatchTable([bool; 256]);
+pub struct SafeByteMatchTable([bool; 256], Option<NibbleTable>);
 
 impl SafeByteMatchTable {
     // Create new `SafeByteMatchTable`.
     pub const fn new(bytes: [bool; 256]) -> Self {
-        let mut table = Self([false; 256]);
+        let mut table = Self([false; 256], None);
 
         // Check if contains either:
         // 1. `true` for all byte values 192..248
@@ -196,6 +215,7 @@ impl SafeByteMatchTable {
             "Cannot create a `SafeByteMatchTable` with an unsafe pattern"
         );
 
+        table.1 = NibbleTable::new(&bytes);
         table
     }
 
@@ -212,6 +232,20 @@ impl SafeByteMatchTable {
     pub const fn matches(&self, b: u8) -> bool {
         self.0[b as usize]
     }
+
+    /// Search `SEARCH_BATCH_SIZE` bytes from `ptr` with SIMD, see `NibbleTable::search_batch`.
+    /// Returns `None` if SIMD cannot be used for this table.
+    ///
+    /// # SAFETY
+    /// There must be at least `SEARCH_BATCH_SIZE` bytes readable from `ptr`.
+    #[inline]
+    pub unsafe fn search_batch(&self, ptr: *const u8) -> Option<usize> {
+        match &self.1 {
+            // SAFETY: Caller guarantees `ptr` can be read.
+            Some(nibbles) => unsafe { nibbles.search_batch(ptr) },
+            None => None,
+        }
+    }
 }
 
 /// Macro to create a `SafeByteMatchTable` at compile time.
@@ -447,6 +481,20 @@ macro_rules! byte_search {
                 // there are at least `SEARCH_BATCH_SIZE` bytes remaining in `lexer.source`.
                 // So calls to `$pos.read()` and `$pos.add(1)` in this loop cannot go out of bounds.
                 'inner: loop {
+                    // SAFETY: There are at least `SEARCH_BATCH_SIZE` bytes remaining (see above)
+                    if let Some(index) = unsafe { $table.search_batch($pos.ptr()) } {
+                        // SAFETY: `index <= SEARCH_BATCH_SIZE`, so `$pos` cannot go out of bounds.
+                        // Bytes before `index` do not match, same as advancing byte by byte below.
+                        $pos = unsafe { $pos.add(index) };
+                        if index < crate::lexer::search::SEARCH_BATCH_SIZE {
+                            // SAFETY: `$pos` is before end of batch, so not at end of source
+                            let byte = unsafe { $pos.read() };
+                            break 'inner byte;
+                        }
+                        continue 'outer;
+                    }
+
+                    // SIMD not available - search batch byte by byte
                     for _i in 0..crate::lexer::search::SEARCH_BATCH_SIZE {
                         // SAFETY: `$pos` cannot go out of bounds in this loop (see above)
                         let byte = unsafe { $pos.read() };
@@ -0,0 +1,215 @@
+//! SIMD search of a batch of bytes for `byte_search!`.
+//!
+//! A byte matches a `NibbleTable` if the entries for its low and high nibble have a bit in common,
+//! so 16 bytes can be tested at once with 2 table shuffles.
+//! Each bit stands for a distinct set of matching low nibbles ("row"), shared by all the high nibbles
+//! with that set, so any byte pattern with at most 8 distinct rows can be represented.
+//!
+//! SSSE3 is detected at runtime on x86_64, NEON is always available on aarch64.
+//! On other targets, or when a pattern cannot be represented, `byte_search!` searches byte by byte.
+
+use super::search::SEARCH_BATCH_SIZE;
+
+/// Width of a SIMD register in bytes.
+const LANES: usize = 16;
+
+#[derive(Debug, Clone, Copy)]
+pub struct NibbleTable {
+    lo: [u8; LANES],
+    hi: [u8; LANES],
+}
+
+impl NibbleTable {
+    /// Create a `NibbleTable` matching the same bytes as the lookup table `bytes`.
+    /// Returns `None` if the pattern has more than 8 distinct rows.
+    pub const fn new(bytes: &[bool; 256]) -> Option<Self> {
+        let mut lo = [0u8; LANES];
+        let mut hi = [0u8; LANES];
+        let mut rows = [0u16; 8];
+        let mut number_of_rows = 0;
+
+        let mut hi_index = 0;
+        while hi_index < LANES {
+            let mut row = 0u16;
+            let mut lo_index = 0;
+            while lo_index < LANES {
+                if bytes[hi_index * LANES + lo_index] {
+                    row |= 1 << lo_index;
+                }
+                lo_index += 1;
+            }
+
+            if row != 0 {
+                let mut bit = 0;
+                while bit < number_of_rows && rows[bit] != row {
+                    bit += 1;
+                }
+                if bit == number_of_rows {
+                    if number_of_rows == rows.len() {
+                        return None;
+                    }
+                    rows[bit] = row;
+                    number_of_rows += 1;
+                    let mut lo_index = 0;
+                    while lo_index < LANES {
+                        if row & (1 << lo_index) != 0 {
+                            lo[lo_index] |= 1 << bit;
+                        }
+                        lo_index += 1;
+                    }
+                }
+                hi[hi_index] = 1 << bit;
+            }
+            hi_index += 1;
+        }
+
+        Some(Self { lo, hi })
+    }
+
+    /// Search `SEARCH_BATCH_SIZE` bytes starting at `ptr` for the first matching byte.
+    ///
+    /// Returns the index of the first match, or `SEARCH_BATCH_SIZE` if no byte matches.
+    /// Returns `None` if SIMD is not available on this machine.
+    ///
+    /// # SAFETY
+    /// There must be at least `SEARCH_BATCH_SIZE` bytes readable from `ptr`.
+    #[inline]
+    pub unsafe fn search_batch(&self, ptr: *const u8) -> Option<usize> {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if std::is_x86_feature_detected!("ssse3") {
+                // SAFETY: SSSE3 is available, caller guarantees `ptr` can be read.
+                return Some(unsafe { x86_64::search_batch(self, ptr) });
+            }
+            None
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            // SAFETY: Caller guarantees `ptr` can be read.
+            Some(unsafe { aarch64::search_batch(self, ptr) })
+        }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            let _ = ptr;
+            None
+        }
+    }
+
+    /// Scalar equivalent of `search_batch`, for testing
+    #[cfg(test)]
+    fn matches(&self, b: u8) -> bool {
+        self.lo[(b & 0x0F) as usize] & self.hi[(b >> 4) as usize] != 0
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    #[allow(clippy::wildcard_imports)]
+    use std::arch::x86_64::*;
+
+    use super::{NibbleTable, LANES, SEARCH_BATCH_SIZE};
+
+    /// # SAFETY
+    /// SSSE3 must be available, and `SEARCH_BATCH_SIZE` bytes must be readable from `ptr`.
+    #[target_feature(enable = "ssse3")]
+    #[allow(clippy::cast_sign_loss, clippy::cast_ptr_alignment)]
+    pub unsafe fn search_batch(table: &NibbleTable, ptr: *const u8) -> usize {
+        let lo = _mm_loadu_si128(table.lo.as_ptr().cast());
+        let hi = _mm_loadu_si128(table.hi.as_ptr().cast());
+        let low_nibble = _mm_set1_epi8(0x0F);
+
+        let mut offset = 0;
+        while offset < SEARCH_BATCH_SIZE {
+            let bytes = _mm_loadu_si128(ptr.add(offset).cast());
+            let lo_bits = _mm_shuffle_epi8(lo, _mm_and_si128(bytes, low_nibble));
+            let hi_bits = _mm_shuffle_epi8(hi, _mm_and_si128(_mm_srli_epi16(bytes, 4), low_nibble));
+            let no_match = _mm_cmpeq_epi8(_mm_and_si128(lo_bits, hi_bits), _mm_setzero_si128());
+            let mask = !(_mm_movemask_epi8(no_match) as u32) & 0xFFFF;
+            if mask != 0 {
+                return offset + mask.trailing_zeros() as usize;
+            }
+            offset += LANES;
+        }
+        SEARCH_BATCH_SIZE
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    #[allow(clippy::wildcard_imports)]
+    use std::arch::aarch64::*;
+
+    use super::{NibbleTable, LANES, SEARCH_BATCH_SIZE};
+
+    /// # SAFETY
+    /// `SEARCH_BATCH_SIZE` bytes must be readable from `ptr`.
+    #[inline]
+    pub unsafe fn search_batch(table: &NibbleTable, ptr: *const u8) -> usize {
+        let lo = vld1q_u8(table.lo.as_ptr());
+        let hi = vld1q_u8(table.hi.as_ptr());
+        let low_nibble = vdupq_n_u8(0x0F);
+
+        let mut offset = 0;
+        while offset < SEARCH_BATCH_SIZE {
+            let bytes = vld1q_u8(ptr.add(offset));
+            let lo_bits = vqtbl1q_u8(lo, vandq_u8(bytes, low_nibble));
+            let hi_bits = vqtbl1q_u8(hi, vshrq_n_u8(bytes, 4));
+            let matched = vtstq_u8(lo_bits, hi_bits);
+            // Narrow each lane to 4 bits, to get a 64 bit mask with 4 bits per byte
+            let mask = vget_lane_u64(
+                vreinterpret_u64_u8(vshrn_n_u16(vreinterpretq_u16_u8(matched), 4)),
+                0,
+            );
+            if mask != 0 {
+                return offset + (mask.trailing_zeros() / 4) as usize;
+            }
+            offset += LANES;
+        }
+        SEARCH_BATCH_SIZE
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NibbleTable, SEARCH_BATCH_SIZE};
+
+    fn table(f: impl Fn(u8) -> bool) -> [bool; 256] {
+        std::array::from_fn(|i| f(u8::try_from(i).unwrap()))
+    }
+
+    #[test]
+    fn represents_patterns() {
+        let patterns = [
+            table(|b| matches!(b, b'"' | b'\r' | b'\n' | b'\\')),
+            table(|b| !matches!(b, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'$')),
+            table(|b| b != b' ' && b != b'\t'),
+            table(|b| !b.is_ascii()),
+        ];
+        for pattern in patterns {
+            let nibbles = NibbleTable::new(&pattern).unwrap();
+            for b in 0..=255u8 {
+                assert_eq!(nibbles.matches(b), pattern[b as usize], "byte {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn too_many_rows() {
+        // Every high nibble has a different row
+        assert!(NibbleTable::new(&table(|b| (b & 0x0F) == (b >> 4))).is_none());
+    }
+
+    #[test]
+    fn search_batch() {
+        let nibbles = NibbleTable::new(&table(|b| matches!(b, b'"' | b'\\'))).unwrap();
+        let mut bytes = [b'a'; SEARCH_BATCH_SIZE];
+        // SAFETY: `bytes` is `SEARCH_BATCH_SIZE` long
+        let Some(index) = (unsafe { nibbles.search_batch(bytes.as_ptr()) }) else { return };
+        assert_eq!(index, SEARCH_BATCH_SIZE);
+        for i in (0..SEARCH_BATCH_SIZE).rev() {
+            bytes[i] = if i % 2 == 0 { b'"' } else { b'\\' };
+            // SAFETY: `bytes` is `SEARCH_BATCH_SIZE` long
+            assert_eq!(unsafe { nibbles.search_batch(bytes.as_ptr()) }, Some(i));
+        }
+    }
+}
@@ -528,6 +528,12 @@ impl<'a> SourcePosition<'a> {
         self.ptr as usize
     }
 
+    /// Get the pointer of `SourcePosition`, e.g. for SIMD loads.
+    #[inline]
+    pub(super) fn ptr(self) -> *const u8 {
+        self.ptr
+    }
+
     /// Create new `SourcePosition` which is `n` bytes after this one.
     /// The provenance of the pointer `SourcePosition` contains is maintained.
     ///
@@ -4,6 +4,7 @@ use oxc_span::Span;
 
 use super::kind::Kind;
 
+/// A token of the source text, see [`crate::ParserReturn::tokens`]
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Token {
     /// Token Kind
@@ -19,11 +20,8 @@ pub struct Token {
     pub is_on_new_line: bool,
 
     /// True if the identifier / string / template kinds has escaped strings.
-    /// The escaped strings are saved in [Lexer::escaped_strings] and [Lexer::escaped_templates] by
+    /// The escaped strings are saved in `Lexer::escaped_strings` and `Lexer::escaped_templates` by
     /// [Token::start].
-    ///
-    /// [Lexer::escaped_strings]: [super::Lexer::escaped_strings]
-    /// [Lexer::escaped_templates]: [super::Lexer::escaped_templates]
     pub escaped: bool,
 
     // Padding to fill to 16 bytes.
@@ -63,6 +63,7 @@
 
 mod context;
 mod cursor;
+mod ecma_version;
 mod list;
 mod state;
 
@@ -80,15 +81,18 @@ mod lexer;
 pub mod lexer;
 
 pub use crate::lexer::Kind; // re-export for codegen
+pub use crate::lexer::Token; // re-export for `ParserReturn::tokens`
+pub use oxc_syntax::syntax_feature::EcmaVersion; // re-export for `Parser::ecma_version`
 
 use context::{Context, StatementContext};
 use oxc_allocator::Allocator;
 use oxc_ast::{ast::Program, AstBuilder, Trivias};
-use oxc_diagnostics::{Error, Result};
+use oxc_diagnostics::{CancellationToken, CancelledError, Error, NestingTooDeepError, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
 
 use crate::{
-    lexer::{Lexer, Token},
+    ecma_version::EcmaVersionChecker,
+    lexer::Lexer,
     state::ParserState,
 };
 
@@ -115,6 +119,9 @@ pub struct ParserReturn<'a> {
     pub program: Program<'a>,
     pub errors: Vec<Error>,
     pub trivias: Trivias,
+    /// The tokens of the program in source order, without comments.
+    /// Only collected with [`Parser::collect_tokens`].
+    pub tokens: Vec<Token>,
     pub panicked: bool,
 }
 
@@ -123,11 +130,20 @@ pub struct ParserReturn<'a> {
 struct ParserOptions {
     pub allow_return_outside_function: bool,
     pub preserve_parens: bool,
+    pub ecma_version: EcmaVersion,
+    pub collect_tokens: bool,
+    pub max_nesting_depth: Option<usize>,
 }
 
 impl Default for ParserOptions {
     fn default() -> Self {
-        Self { allow_return_outside_function: false, preserve_parens: true }
+        Self {
+            allow_return_outside_function: false,
+            preserve_parens: true,
+            ecma_version: EcmaVersion::Latest,
+            collect_tokens: false,
+            max_nesting_depth: None,
+        }
     }
 }
 
@@ -139,13 +155,14 @@ pub struct Parser<'a> {
     source_text: &'a str,
     source_type: SourceType,
     options: ParserOptions,
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl<'a> Parser<'a> {
     /// Create a new parser
     pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
         let options = ParserOptions::default();
-        Self { allocator, source_text, source_type, options }
+        Self { allocator, source_text, source_type, options, cancellation_token: None }
     }
 
     /// Allow return outside of function
@@ -167,6 +184,45 @@ impl<'a> Parser<'a> {
         self.options.preserve_parens = allow;
         self
     }
+
+    /// Reject syntax introduced after `version`.
+    ///
+    /// By default, all the syntax supported by the parser is accepted.
+    /// Set this to e.g. [`EcmaVersion::Es2019`] to report class fields with an
+    /// "ES2022 is required" error, for code that must run on older engines without being transpiled.
+    #[must_use]
+    pub fn ecma_version(mut self, version: EcmaVersion) -> Self {
+        self.options.ecma_version = version;
+        self
+    }
+
+    /// Collect the tokens of the program in [`ParserReturn::tokens`],
+    /// for tools working on the token level such as linters.
+    #[must_use]
+    pub fn collect_tokens(mut self, yes: bool) -> Self {
+        self.options.collect_tokens = yes;
+        self
+    }
+
+    /// Stop parsing at statements, expressions, types, JSX elements or binding patterns nested
+    /// more than `depth` times in each other, instead of overflowing the stack.
+    ///
+    /// The program is then empty, `panicked` is true and `errors` contains a `NestingTooDeepError`.
+    /// Each of them is at least one AST node, except for some TypeScript types.
+    #[must_use]
+    pub fn max_nesting_depth(mut self, depth: usize) -> Self {
+        self.options.max_nesting_depth = Some(depth);
+        self
+    }
+
+    /// Stop parsing when `token` is cancelled.
+    ///
+    /// The program is then empty, `panicked` is true and `errors` contains a `CancelledError`.
+    #[must_use]
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
 }
 
 mod parser_parse {
@@ -212,13 +268,14 @@ mod parser_parse {
         /// Recoverable errors are stored inside `errors`.
         pub fn parse(self) -> ParserReturn<'a> {
             let unique = UniquePromise::new();
-            let parser = ParserImpl::new(
+            let mut parser = ParserImpl::new(
                 self.allocator,
                 self.source_text,
                 self.source_type,
                 self.options,
                 unique,
             );
+            parser.cancellation_token = self.cancellation_token;
             parser.parse()
         }
     }
@@ -258,6 +315,20 @@ struct ParserImpl<'a> {
     /// Emit `ParenthesizedExpression` in AST.
     /// Default: `true`
     preserve_parens: bool,
+
+    /// Syntax introduced after this version is reported
+    ecma_version: EcmaVersion,
+
+    /// Checked before each statement
+    cancellation_token: Option<CancellationToken>,
+
+    /// The consumed tokens, `Some` when collecting tokens
+    tokens: Option<Vec<Token>>,
+
+    /// The current nesting, see `ParserImpl::nested`
+    depth: usize,
+
+    max_nesting_depth: Option<usize>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -284,6 +355,11 @@ impl<'a> ParserImpl<'a> {
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             preserve_parens: options.preserve_parens,
+            ecma_version: options.ecma_version,
+            cancellation_token: None,
+            tokens: options.collect_tokens.then(Vec::new),
+            depth: 0,
+            max_nesting_depth: options.max_nesting_depth,
         }
     }
 
@@ -307,11 +383,22 @@ impl<'a> ParserImpl<'a> {
     #[inline]
     pub fn parse(mut self) -> ParserReturn<'a> {
         let (program, panicked) = match self.parse_program() {
-            Ok(program) => (program, false),
+            Ok(program) => {
+                if self.ecma_version < EcmaVersion::Latest {
+                    self.errors
+                        .extend(EcmaVersionChecker::check_program(&program, self.ecma_version));
+                }
+                (program, false)
+            }
             Err(error) => {
-                self.error(
-                    self.flow_error().unwrap_or_else(|| self.overlong_error().unwrap_or(error)),
-                );
+                let error = if self.is_cancelled()
+                    || error.downcast_ref::<NestingTooDeepError>().is_some()
+                {
+                    error
+                } else {
+                    self.flow_error().unwrap_or_else(|| self.overlong_error().unwrap_or(error))
+                };
+                self.error(error);
                 let program = self.ast.program(
                     Span::default(),
                     self.source_type,
@@ -324,7 +411,8 @@ impl<'a> ParserImpl<'a> {
         };
         let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
         let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias, panicked }
+        let tokens = self.tokens.unwrap_or_default();
+        ParserReturn { program, errors, trivias, tokens, panicked }
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -373,6 +461,33 @@ impl<'a> ParserImpl<'a> {
         None
     }
 
+    fn is_cancelled(&self) -> bool {
+        self.cancellation_token.as_ref().is_some_and(CancellationToken::is_cancelled)
+    }
+
+    fn check_cancelled(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(CancelledError.into());
+        }
+        Ok(())
+    }
+
+    /// Parse with `parse` one level deeper, failing at `Parser::max_nesting_depth`.
+    fn nested<F, T>(&mut self, parse: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        if let Some(max) = self.max_nesting_depth {
+            if self.depth >= max {
+                return Err(NestingTooDeepError(max, self.cur_token().span().into()).into());
+            }
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
     /// Return error info at current token
     /// # Panics
     ///   * The lexer did not push a diagnostic when `Kind::Undetermined` is returned
@@ -400,6 +515,8 @@ impl<'a> ParserImpl<'a> {
 #[cfg(test)]
 mod test {
 
+    use oxc_span::{LineColumn, LineEnding};
+
     use super::*;
 
     #[test]
@@ -427,6 +544,115 @@ mod test {
         assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
     }
 
+    #[test]
+    fn cancelled() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "// @flow\nfoo;";
+        let token = CancellationToken::new();
+        let ret = Parser::new(&allocator, source, source_type)
+            .with_cancellation_token(token.clone())
+            .parse();
+        assert!(!ret.panicked);
+
+        token.cancel();
+        let ret =
+            Parser::new(&allocator, source, source_type).with_cancellation_token(token).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.is_empty());
+        assert_eq!(ret.errors.first().unwrap().to_string(), "Cancelled");
+    }
+
+    #[test]
+    fn max_nesting_depth() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true).with_jsx(true);
+        let depth = 2000;
+        let sources = [
+            format!("{}x{};", "(".repeat(depth), ")".repeat(depth)),
+            format!("{}x{};", "[".repeat(depth), "]".repeat(depth)),
+            format!("{}x;", "!".repeat(depth)),
+            format!("x{};", " ** x".repeat(depth)),
+            format!("{}{}", "{".repeat(depth), "}".repeat(depth)),
+            format!("type T = {}x{};", "[".repeat(depth), "]".repeat(depth)),
+            format!("let {}x{} = y;", "[".repeat(depth), "]".repeat(depth)),
+            format!("{}{};", "<a>".repeat(depth), "</a>".repeat(depth)),
+        ];
+        for source in &sources {
+            let ret = Parser::new(&allocator, source, source_type).max_nesting_depth(100).parse();
+            assert!(ret.panicked, "{source}");
+            assert_eq!(
+                ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                ["Nesting deeper than 100 is not supported"],
+                "{source}"
+            );
+        }
+
+        let source = "let a = [[b]]; if (c) { d(!e) }";
+        let ret = Parser::new(&allocator, source, source_type).max_nesting_depth(6).parse();
+        assert!(ret.errors.is_empty());
+        let ret = Parser::new(&allocator, source, source_type).max_nesting_depth(3).parse();
+        assert!(ret.panicked);
+    }
+
+    #[test]
+    fn line_endings() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let a;\r\n`b\r\nc`;\r\n/*\r*/ d;\u{2028}e;\r\n";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let line_endings = ret.trivias.line_endings(source);
+        assert_eq!(line_endings.style(), LineEnding::CrLf);
+        assert!(line_endings.is_mixed());
+        assert_eq!(line_endings.carriage_returns(), [6, 10, 15, 19, 30]);
+        assert_eq!(line_endings.line_count(), 7);
+        assert!(line_endings.has_final_newline(source));
+
+        let position = |offset| {
+            let position = line_endings.line_column(offset, source);
+            (position.line, position.column)
+        };
+        // `c`, `d`, `e`
+        assert_eq!(position(12), (2, 0));
+        assert_eq!(position(23), (4, 3));
+        assert_eq!(position(28), (5, 0));
+        // The `\r` and the `\n` of a line ending are at the same column
+        assert_eq!(position(6), (0, 6));
+        assert_eq!(position(7), (0, 6));
+        assert_eq!(line_endings.line_start(1), Some(8));
+        assert_eq!(line_endings.line_end(1, source), Some(10));
+        assert_eq!(line_endings.line_end(6, source), Some(32));
+        assert_eq!(line_endings.line_end(7, source), None);
+
+        let source = "let a;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let line_endings = ret.trivias.line_endings(source);
+        assert_eq!(line_endings.style(), LineEnding::Lf);
+        assert!(!line_endings.has_final_newline(source));
+        assert_eq!(line_endings.line_column(6, source), LineColumn { line: 0, column: 6 });
+    }
+
+    #[test]
+    fn collect_tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let source = "#!/usr/bin/env node\nlet a = /b/g; // c\n`d${e}f`; g<T>(h);";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.tokens.is_empty());
+
+        let ret = Parser::new(&allocator, source, source_type).collect_tokens(true).parse();
+        assert!(ret.errors.is_empty());
+        let tokens = ret.tokens.iter().map(|token| token.span().source_text(source));
+        assert_eq!(
+            tokens.collect::<Vec<_>>(),
+            [
+                "let", "a", "=", "/b/g", ";", "`d${", "e", "}f`", ";", "g", "<", "T", ">", "(",
+                "h", ")", ";"
+            ]
+        );
+    }
+
     #[test]
     fn directives() {
         let allocator = Allocator::default();
@@ -443,6 +669,137 @@ mod test {
         }
     }
 
+    #[test]
+    fn estree() {
+        let allocator = Allocator::default();
+        let source = "'use strict';\nfoo.bar(x => 'é', 1n);\nfunction f(a, ...b) {}";
+        let ret = Parser::new(&allocator, source, SourceType::default().with_module(true)).parse();
+        let json = ret.program.to_estree_json(source);
+        let ast: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(ast["sourceType"], "module");
+        let directive = &ast["body"][0];
+        assert_eq!(directive["type"], "ExpressionStatement");
+        assert_eq!(directive["directive"], "use strict");
+
+        let call = &ast["body"][1]["expression"];
+        assert_eq!(call["callee"]["type"], "MemberExpression");
+        assert_eq!(call["callee"]["computed"], false);
+        assert_eq!(call["callee"]["property"]["type"], "Identifier");
+        assert_eq!(call["arguments"][0]["params"][0]["name"], "x");
+        let string = &call["arguments"][0]["body"];
+        assert_eq!(string["type"], "Literal");
+        assert_eq!(string["raw"], "'é'");
+        assert_eq!(call["arguments"][1]["bigint"], "1");
+        // The offsets are in UTF-16 code units
+        let end = call["loc"]["end"].clone();
+        assert_eq!(end, serde_json::json!({ "line": 2, "column": 21 }));
+        assert_eq!(call["range"], serde_json::json!([14, 35]));
+
+        let function = &ast["body"][2];
+        assert_eq!(function["body"]["type"], "BlockStatement");
+        assert_eq!(function["params"][0]["type"], "Identifier");
+        assert_eq!(function["params"][1]["type"], "RestElement");
+
+        let binary = ret.program.to_estree_binary(source);
+        let decoded = oxc_ast::decode_estree_binary(&binary).unwrap();
+        assert_eq!(decoded, ret.program.to_estree_value(source));
+        assert!(binary.len() < json.len());
+    }
+
+    /// Fails when the binary encoding or the ESTree shape changes, which must bump
+    /// `ESTREE_BINARY_VERSION` and add the snapshot of the new version.
+    #[test]
+    fn estree_binary_version() {
+        use std::fmt::Write;
+
+        let allocator = Allocator::default();
+        let source = "import a from 'b';\nexport const c = [1.5, -2, `d${e}`, /f/g, null, !0];";
+        let ret = Parser::new(&allocator, source, SourceType::default().with_module(true)).parse();
+        assert!(ret.errors.is_empty());
+        let binary = ret.program.to_estree_binary(source);
+        let hex = binary
+            .chunks(32)
+            .map(|chunk| {
+                chunk.iter().fold(String::new(), |mut hex, byte| {
+                    write!(hex, "{byte:02x}").unwrap();
+                    hex
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let name = format!("estree_binary_v{}", oxc_ast::ESTREE_BINARY_VERSION);
+        insta::assert_snapshot!(name, hex);
+    }
+
+    #[test]
+    fn ecma_version() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let source = "class A { x = 1; static { a?.b; } }";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let ret =
+            Parser::new(&allocator, source, source_type).ecma_version(EcmaVersion::Es2020).parse();
+        let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            ["ES2022 is required for class fields", "ES2022 is required for class static blocks"]
+        );
+        let ret =
+            Parser::new(&allocator, source, source_type).ecma_version(EcmaVersion::Es2019).parse();
+        assert_eq!(ret.errors.len(), 3);
+        assert_eq!(ret.errors[2].to_string(), "ES2020 is required for optional chaining");
+
+        let sources = [
+            ("let x = () => {};", EcmaVersion::Es2015),
+            ("x **= 2;", EcmaVersion::Es2016),
+            ("async function f() {}", EcmaVersion::Es2017),
+            ("({ ...x });", EcmaVersion::Es2018),
+            ("try {} catch {}", EcmaVersion::Es2019),
+            ("x ?? 1n;", EcmaVersion::Es2020),
+            ("x ||= 1_000;", EcmaVersion::Es2021),
+            ("await x;", EcmaVersion::Es2022),
+        ];
+        for (source, version) in sources {
+            let ret = Parser::new(&allocator, source, source_type).ecma_version(version).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            let ret =
+                Parser::new(&allocator, source, source_type).ecma_version(EcmaVersion::Es5).parse();
+            assert!(
+                ret.errors
+                    .iter()
+                    .any(|error| error.to_string().starts_with(&format!("{version} is required"))),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn misspelled_keyword() {
+        use oxc_ast::ast::{Declaration, Statement};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "funtcion foo() { retrun 1; }";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(errors, ["Unexpected identifier `funtcion`", "Unexpected identifier `retrun`"]);
+        assert!(matches!(
+            ret.program.body[0],
+            Statement::Declaration(Declaration::FunctionDeclaration(_))
+        ));
+
+        let source = "async function foo() { const x = awiat bar(); }";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Unexpected identifier `awiat`");
+
+        let source = "awiat(x); let awiat = 1; foo(awiat)";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+    }
+
     #[test]
     fn memory_leak() {
         let allocator = Allocator::default();
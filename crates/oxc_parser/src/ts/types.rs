@@ -108,6 +108,10 @@ impl ModifierFlags {
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type(&mut self) -> Result<TSType<'a>> {
+        self.nested(Self::parse_ts_type_impl)
+    }
+
+    fn parse_ts_type_impl(&mut self) -> Result<TSType<'a>> {
         if self.is_at_constructor_type() {
             return self.parse_ts_constructor_type();
         }
@@ -18,7 +18,7 @@ use std::{iter::Peekable, vec};
 
 use oxc_allocator::Allocator;
 use oxc_ast::{ast::Program, AstKind, CommentKind, Trivias};
-use oxc_span::Span;
+use oxc_span::{LineEnding, Span};
 use oxc_syntax::identifier::is_line_terminator;
 
 use crate::{doc::Doc, doc::DocBuilder, format::Format, printer::Printer};
@@ -74,8 +74,16 @@ impl<'a> Prettier<'a> {
         allocator: &'a Allocator,
         source_text: &'a str,
         trivias: Trivias,
-        options: PrettierOptions,
+        mut options: PrettierOptions,
     ) -> Self {
+        // `auto` keeps the line ending of the first line
+        if matches!(options.end_of_line, EndOfLine::Auto) {
+            options.end_of_line = match trivias.line_endings(source_text).style() {
+                LineEnding::Lf => EndOfLine::Lf,
+                LineEnding::CrLf => EndOfLine::Crlf,
+                LineEnding::Cr => EndOfLine::Cr,
+            };
+        }
         Self {
             allocator,
             source_text,
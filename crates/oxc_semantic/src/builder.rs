@@ -4,7 +4,7 @@ use std::{cell::RefCell, path::PathBuf, rc::Rc, sync::Arc};
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstKind, Trivias, TriviasMap, Visit};
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{CancellationToken, CancelledError, Error};
 use oxc_span::{Atom, CompactString, SourceType, Span};
 use oxc_syntax::{
     module_record::{ExportLocalName, ModuleRecord},
@@ -84,6 +84,8 @@ pub struct SemanticBuilder<'a> {
     pub cfg: ControlFlowGraph,
 
     pub class_table_builder: ClassTableBuilder,
+
+    cancellation_token: Option<CancellationToken>,
 }
 
 pub struct SemanticBuilderReturn<'a> {
@@ -120,6 +122,7 @@ impl<'a> SemanticBuilder<'a> {
             redeclare_variables: RedeclareVariables { variables: vec![] },
             cfg: ControlFlowGraph::new(),
             class_table_builder: ClassTableBuilder::new(),
+            cancellation_token: None,
         }
     }
 
@@ -136,6 +139,17 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Stop visiting statements when `token` is cancelled, `errors` then contains a `CancelledError`.
+    #[must_use]
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancellation_token.as_ref().is_some_and(CancellationToken::is_cancelled)
+    }
+
     /// Get the built module record from `build_module_record`
     pub fn module_record(&self) -> Arc<ModuleRecord> {
         Arc::clone(&self.module_record)
@@ -160,6 +174,10 @@ impl<'a> SemanticBuilder<'a> {
         } else {
             self.visit_program(program);
 
+            if self.is_cancelled() {
+                self.error(CancelledError);
+            }
+
             // Checking syntax error on module record requires scope information from the previous AST pass
             if self.check_syntax_error {
                 EarlyErrorJavaScript::check_module_record(&self);
@@ -207,6 +225,9 @@ impl<'a> SemanticBuilder<'a> {
 
     fn create_ast_node(&mut self, kind: AstKind<'a>) {
         let mut flags = self.current_node_flags;
+        if self.strict_mode() {
+            flags |= NodeFlags::StrictMode;
+        }
         if self.jsdoc.retrieve_attached_jsdoc(&kind) {
             flags |= NodeFlags::JSDoc;
         }
@@ -232,6 +253,13 @@ impl<'a> SemanticBuilder<'a> {
             || self.current_node_flags.contains(NodeFlags::Class)
     }
 
+    /// Restore the flags of the enclosing context to what they were when the current node was created.
+    fn restore_node_flags(&mut self) {
+        let context =
+            NodeFlags::Class | NodeFlags::Function | NodeFlags::Iteration | NodeFlags::Switch;
+        self.current_node_flags = self.nodes.get_node(self.current_node_id).flags() & context;
+    }
+
     pub fn set_function_node_flag(&mut self, flag: NodeFlags) {
         if let Some(current_function) = self.function_stack.last() {
             *self.nodes.get_node_mut(*current_function).flags_mut() |= flag;
@@ -413,7 +441,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         if self.check_syntax_error {
             let node = self.nodes.get_node(self.current_node_id);
             EarlyErrorJavaScript::run(node, self);
-            EarlyErrorTypeScript::run(node, self);
+            if self.source_type.is_typescript() {
+                EarlyErrorTypeScript::run(node, self);
+            }
         }
         self.leave_kind(kind);
         self.pop_ast_node();
@@ -443,6 +473,12 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.leave_scope();
     }
 
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if !self.is_cancelled() {
+            self.visit_statement_match(stmt);
+        }
+    }
+
     fn visit_block_statement(&mut self, stmt: &BlockStatement<'a>) {
         let kind = AstKind::BlockStatement(self.alloc(stmt));
         self.enter_scope(ScopeFlags::empty());
@@ -1158,8 +1194,15 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             );
         }
 
-        if let Some(last) = switch_case_conditions.last() {
-            self.cfg.add_edge(*last, self.cfg.current_node_ix, EdgeType::Normal);
+        let end_of_last_switch_case = self.cfg.current_node_ix;
+        let after_switch_graph_ix = self.cfg.new_basic_block();
+        self.cfg.add_edge(end_of_last_switch_case, after_switch_graph_ix, EdgeType::Normal);
+
+        // without a `default` case, none of the switch cases may be evaluated
+        if !stmt.cases.iter().any(SwitchCase::is_default_case) {
+            let last_condition_graph_ix =
+                switch_case_conditions.last().copied().unwrap_or(discriminant_graph_ix);
+            self.cfg.add_edge(last_condition_graph_ix, after_switch_graph_ix, EdgeType::Normal);
         }
 
         self.cfg.after_statement(
@@ -1216,10 +1259,14 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         /* cfg */
 
         self.visit_expression(&stmt.argument);
-        // todo - put unreachable after throw statement
 
         /* cfg */
         self.cfg.put_throw(throw_expr);
+
+        /* cfg - put unreachable after throw */
+        let _ = self.cfg.new_basic_block();
+        self.cfg.put_unreachable();
+
         self.cfg.after_statement(
             &statement_state,
             self.current_node_id,
@@ -1418,6 +1465,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             after_try_statement_block_ix,
             EdgeType::Normal,
         );
+        // in a try-catch, the try block completing successfully skips the catch block
+        if stmt.handler.is_some() && stmt.finalizer.is_none() {
+            self.cfg.add_edge(end_of_try_block_ix, after_try_statement_block_ix, EdgeType::Normal);
+        }
 
         self.cfg.after_statement(
             &statement_state,
@@ -1672,8 +1723,13 @@ impl<'a> SemanticBuilder<'a> {
                 decl.bind(self);
                 self.make_all_namespaces_valuelike();
             }
-            AstKind::StaticBlock(_) => self.label_builder.enter_function_or_static_block(),
+            AstKind::StaticBlock(_) => {
+                self.current_node_flags -= NodeFlags::Iteration | NodeFlags::Switch;
+                self.label_builder.enter_function_or_static_block();
+            }
             AstKind::Function(func) => {
+                self.current_node_flags -= NodeFlags::Iteration | NodeFlags::Switch;
+                self.current_node_flags |= NodeFlags::Function;
                 self.function_stack.push(self.current_node_id);
                 func.bind(self);
                 self.label_builder.enter_function_or_static_block();
@@ -1681,6 +1737,8 @@ impl<'a> SemanticBuilder<'a> {
                 self.make_all_namespaces_valuelike();
             }
             AstKind::ArrowFunctionExpression(_) => {
+                self.current_node_flags -= NodeFlags::Iteration | NodeFlags::Switch;
+                self.current_node_flags |= NodeFlags::Function;
                 self.function_stack.push(self.current_node_id);
                 self.add_current_node_id_to_current_scope();
                 self.make_all_namespaces_valuelike();
@@ -1690,6 +1748,16 @@ impl<'a> SemanticBuilder<'a> {
                 class.bind(self);
                 self.make_all_namespaces_valuelike();
             }
+            AstKind::DoWhileStatement(_)
+            | AstKind::WhileStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_)
+            | AstKind::ForStatement(_) => {
+                self.current_node_flags |= NodeFlags::Iteration;
+            }
+            AstKind::SwitchStatement(_) => {
+                self.current_node_flags |= NodeFlags::Switch;
+            }
             AstKind::ClassBody(body) => {
                 self.class_table_builder.declare_class_body(
                     body,
@@ -1793,7 +1861,7 @@ impl<'a> SemanticBuilder<'a> {
                 self.add_export_flag_for_export_identifier();
             }
             AstKind::Class(_) => {
-                self.current_node_flags -= NodeFlags::Class;
+                self.restore_node_flags();
                 self.class_table_builder.pop_class();
             }
             AstKind::ExportDefaultDeclaration(_) | AstKind::ExportNamedDeclaration(_) => {
@@ -1801,15 +1869,24 @@ impl<'a> SemanticBuilder<'a> {
             }
             AstKind::LabeledStatement(_) => self.label_builder.leave(),
             AstKind::StaticBlock(_) => {
+                self.restore_node_flags();
                 self.label_builder.leave_function_or_static_block();
             }
             AstKind::Function(_) => {
+                self.restore_node_flags();
                 self.label_builder.leave_function_or_static_block();
                 self.function_stack.pop();
             }
             AstKind::ArrowFunctionExpression(_) => {
+                self.restore_node_flags();
                 self.function_stack.pop();
             }
+            AstKind::DoWhileStatement(_)
+            | AstKind::WhileStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_)
+            | AstKind::ForStatement(_)
+            | AstKind::SwitchStatement(_) => self.restore_node_flags(),
             AstKind::TSModuleBlock(_) => {
                 self.namespace_stack.pop();
             }
@@ -8,9 +8,11 @@ use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
 };
+use oxc_js_regex::validator::{validate_pattern, PatternMode};
 use oxc_span::{Atom, CompactString, GetSpan, ModuleKind, Span};
 use oxc_syntax::{
     module_record::ExportLocalName,
+    node::NodeFlags,
     operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
     NumberBase,
 };
@@ -26,10 +28,7 @@ impl EarlyErrorJavaScript {
         let kind = node.kind();
 
         match kind {
-            AstKind::Program(_) => {
-                check_labeled_statement(ctx);
-                check_duplicate_class_elements(ctx);
-            }
+            AstKind::Program(_) => check_duplicate_class_elements(ctx),
             AstKind::BindingIdentifier(ident) => {
                 check_identifier(&ident.name, ident.span, node, ctx);
                 check_binding_identifier(ident, node, ctx);
@@ -40,8 +39,8 @@ impl EarlyErrorJavaScript {
             }
             AstKind::LabelIdentifier(ident) => check_identifier(&ident.name, ident.span, node, ctx),
             AstKind::PrivateIdentifier(ident) => check_private_identifier_outside_class(ident, ctx),
-            AstKind::NumericLiteral(lit) => check_number_literal(lit, ctx),
-            AstKind::StringLiteral(lit) => check_string_literal(lit, ctx),
+            AstKind::NumericLiteral(lit) => check_number_literal(lit, node, ctx),
+            AstKind::StringLiteral(lit) => check_string_literal(lit, node, ctx),
             AstKind::RegExpLiteral(lit) => check_regexp_literal(lit, ctx),
 
             AstKind::Directive(dir) => check_directive(dir, ctx),
@@ -59,6 +58,7 @@ impl EarlyErrorJavaScript {
             AstKind::ContinueStatement(stmt) => check_continue_statement(stmt, node, ctx),
             AstKind::LabeledStatement(stmt) => {
                 check_function_declaration(&stmt.body, true, ctx);
+                check_labeled_statement(stmt, ctx);
             }
             AstKind::ForInStatement(stmt) => {
                 check_function_declaration(&stmt.body, false, ctx);
@@ -366,7 +366,7 @@ fn check_private_identifier(ctx: &SemanticBuilder<'_>) {
 #[diagnostic(help("for octal literals use the '0o' prefix instead"))]
 struct LegacyOctal(#[label] Span);
 
-fn check_number_literal(lit: &NumericLiteral, ctx: &SemanticBuilder<'_>) {
+fn check_number_literal(lit: &NumericLiteral, node: &AstNode, ctx: &SemanticBuilder<'_>) {
     // NumericLiteral :: LegacyOctalIntegerLiteral
     // DecimalIntegerLiteral :: NonOctalDecimalIntegerLiteral
     // * It is a Syntax Error if the source text matched by this production is strict mode code.
@@ -380,7 +380,7 @@ fn check_number_literal(lit: &NumericLiteral, ctx: &SemanticBuilder<'_>) {
         false
     }
 
-    if ctx.strict_mode() {
+    if node.flags().is_strict_mode() {
         match lit.base {
             NumberBase::Octal if leading_zero(lit.raw) => {
                 ctx.error(LegacyOctal(lit.span));
@@ -397,14 +397,14 @@ fn check_number_literal(lit: &NumericLiteral, ctx: &SemanticBuilder<'_>) {
     }
 }
 
-fn check_string_literal(lit: &StringLiteral, ctx: &SemanticBuilder<'_>) {
+fn check_string_literal(lit: &StringLiteral, node: &AstNode, ctx: &SemanticBuilder<'_>) {
     // 12.9.4.1 Static Semantics: Early Errors
     // EscapeSequence ::
     //   LegacyOctalEscapeSequence
     //   NonOctalDecimalEscapeSequence
     // It is a Syntax Error if the source text matched by this production is strict mode code.
     let raw = lit.span.source_text(ctx.source_text);
-    if ctx.strict_mode() && raw.len() != lit.value.len() {
+    if node.flags().is_strict_mode() && raw.len() != lit.value.len() {
         let mut chars = raw.chars().peekable();
         while let Some(c) = chars.next() {
             if c == '\\' {
@@ -594,9 +594,26 @@ fn check_regexp_literal(lit: &RegExpLiteral, ctx: &SemanticBuilder<'_>) {
     #[diagnostic()]
     struct RegExpFlagUAndV(#[label] Span);
 
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Invalid regular expression: {0}: {1}")]
+    #[diagnostic()]
+    struct InvalidRegExp(String, &'static str, #[label] Span);
+
     let flags = lit.regex.flags;
     if flags.contains(RegExpFlags::U | RegExpFlags::V) {
         ctx.error(RegExpFlagUAndV(lit.span));
+        return;
+    }
+
+    let mode = if flags.contains(RegExpFlags::V) {
+        PatternMode::UnicodeSets
+    } else if flags.contains(RegExpFlags::U) {
+        PatternMode::Unicode
+    } else {
+        PatternMode::Legacy
+    };
+    if let Err(reason) = validate_pattern(&lit.regex.pattern, mode) {
+        ctx.error(InvalidRegExp(lit.regex.to_string(), reason, lit.span));
     }
 }
 
@@ -683,19 +700,8 @@ fn check_break_statement<'a>(stmt: &BreakStatement, node: &AstNode<'a>, ctx: &Se
     }
 
     // It is a Syntax Error if this BreakStatement is not nested, directly or indirectly (but not crossing function or static initialization block boundaries), within an IterationStatement or a SwitchStatement.
-    for node_id in ctx.nodes.ancestors(node.id()).skip(1) {
-        match ctx.nodes.kind(node_id) {
-            AstKind::Program(_) | AstKind::Function(_) | AstKind::StaticBlock(_) => {
-                ctx.error(InvalidBreak(stmt.span));
-            }
-            kind if (kind.is_iteration_statement()
-                || matches!(kind, AstKind::SwitchStatement(_)))
-                && stmt.label.is_none() =>
-            {
-                break;
-            }
-            _ => {}
-        }
+    if !node.flags().intersects(NodeFlags::Iteration | NodeFlags::Switch) {
+        ctx.error(InvalidBreak(stmt.span));
     }
 }
 
@@ -716,29 +722,27 @@ fn check_continue_statement<'a>(
     }
 
     // It is a Syntax Error if this ContinueStatement is not nested, directly or indirectly (but not crossing function or static initialization block boundaries), within an IterationStatement.
-    for node_id in ctx.nodes.ancestors(node.id()).skip(1) {
-        match ctx.nodes.kind(node_id) {
-            AstKind::Program(_) | AstKind::Function(_) | AstKind::StaticBlock(_) => {
-                ctx.error(InvalidContinue(stmt.span));
-            }
-            kind if kind.is_iteration_statement() && stmt.label.is_none() => break,
-            _ => {}
-        }
+    if !node.flags().is_in_iteration() {
+        ctx.error(InvalidContinue(stmt.span));
     }
 }
 
-#[allow(clippy::option_if_let_else)]
-fn check_labeled_statement(ctx: &SemanticBuilder) {
-    ctx.label_builder.labels.iter().for_each(|labels| {
-        let mut defined = FxHashMap::default();
-        for labeled in labels {
-            if let Some(span) = defined.get(labeled.name) {
-                ctx.error(Redeclaration(labeled.name.into(), *span, labeled.span));
-            } else {
-                defined.insert(labeled.name, labeled.span);
-            }
-        }
-    });
+fn check_labeled_statement(stmt: &LabeledStatement, ctx: &SemanticBuilder) {
+    // It is a Syntax Error if any element of the LabelledStatement's label set is also
+    // the label of an enclosing LabelledStatement, sibling statements may share a label.
+    // The accessible labels are the enclosing ones, starting with the label of `stmt` itself.
+    let duplicate = ctx
+        .label_builder
+        .get_accessible_labels()
+        .skip(1)
+        .find(|labeled| labeled.name == stmt.label.name);
+    if let Some(labeled) = duplicate {
+        ctx.error(Redeclaration(
+            stmt.label.name.to_compact_string(),
+            labeled.span,
+            stmt.label.span,
+        ));
+    }
 }
 
 fn check_for_statement_left<'a>(
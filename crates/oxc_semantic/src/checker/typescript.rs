@@ -3,7 +3,7 @@ use oxc_ast::syntax_directed_operations::BoundNames;
 use oxc_ast::{ast::*, AstKind};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
-    thiserror::Error,
+    thiserror::{self, Error},
 };
 use oxc_span::{Atom, GetSpan, Span};
 use rustc_hash::FxHashMap;
@@ -17,13 +17,43 @@ impl EarlyErrorTypeScript {
         let kind = node.kind();
 
         match kind {
+            AstKind::Program(program) => check_export_assignment_with_other_exports(program, ctx),
             AstKind::VariableDeclarator(decl) => check_variable_declarator(decl, ctx),
             AstKind::SimpleAssignmentTarget(target) => check_simple_assignment_target(target, ctx),
             AstKind::FormalParameters(params) => check_formal_parameters(params, ctx),
+            AstKind::FormalParameter(param) if param.accessibility.is_some() => {
+                let end = param.pattern.span().start;
+                check_accessibility_modifiers(param.span, &param.decorators, end, ctx);
+            }
             AstKind::ArrayPattern(pattern) => check_array_pattern(pattern, ctx),
             AstKind::TSTypeParameterDeclaration(declaration) => {
                 check_ts_type_parameter_declaration(declaration, ctx);
             }
+            AstKind::VariableDeclaration(decl) => check_modifiers(&decl.modifiers, kind, ctx),
+            AstKind::Function(func) => {
+                check_modifiers(&func.modifiers, kind, ctx);
+                check_function_implementation(func, node, ctx);
+            }
+            AstKind::Class(class) => check_modifiers(&class.modifiers, kind, ctx),
+            AstKind::TSEnumDeclaration(decl) => check_modifiers(&decl.modifiers, kind, ctx),
+            AstKind::TSTypeAliasDeclaration(decl) => check_modifiers(&decl.modifiers, kind, ctx),
+            AstKind::TSInterfaceDeclaration(decl) => check_modifiers(&decl.modifiers, kind, ctx),
+            AstKind::TSModuleDeclaration(decl) => {
+                check_modifiers(&decl.modifiers, kind, ctx);
+                check_ts_module_declaration(decl, node, ctx);
+            }
+            AstKind::PropertyDefinition(prop) if prop.accessibility.is_some() => {
+                let end = prop.key.span().start;
+                check_accessibility_modifiers(prop.span, &prop.decorators, end, ctx);
+            }
+            AstKind::MethodDefinition(method) if method.accessibility.is_some() => {
+                let end = method.key.span().start;
+                check_accessibility_modifiers(method.span, &method.decorators, end, ctx);
+            }
+            AstKind::TSEnumMember(member) => check_ts_enum_member(member, ctx),
+            AstKind::ModuleDeclaration(ModuleDeclaration::TSExportAssignment(assignment)) => {
+                check_ts_export_assignment(assignment, node, ctx);
+            }
             _ => {}
         }
     }
@@ -65,6 +95,10 @@ fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<'_>)
     #[error("A parameter property is only allowed in a constructor implementation.")]
     #[diagnostic()]
     struct ParameterPropertyOutsideConstructor(#[label] Span);
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Parameter cannot have question mark and initializer.")]
+    #[diagnostic()]
+    struct OptionalParameterWithInitializer(#[label] Span);
 
     if !params.is_empty() && params.kind == FormalParameterKind::Signature {
         check_duplicate_bound_names(params, ctx);
@@ -82,6 +116,13 @@ fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<'_>)
             has_optional = true;
         }
 
+        // function a(optional?: number = 1) { }
+        if let BindingPatternKind::AssignmentPattern(pattern) = &item.pattern.kind {
+            if pattern.left.optional {
+                ctx.error(OptionalParameterWithInitializer(item.span));
+            }
+        }
+
         // function a(public x: number) { }
         if !is_inside_constructor && item.accessibility.is_some() {
             ctx.error(ParameterPropertyOutsideConstructor(item.span));
@@ -133,3 +174,250 @@ fn check_array_pattern<'a>(pattern: &ArrayPattern<'a>, ctx: &SemanticBuilder<'a>
         });
     }
 }
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Accessibility modifier already seen.")]
+#[diagnostic()]
+struct AccessibilityModifierAlreadySeen(#[label] Span);
+
+/// Modifiers of declarations, e.g. `abstract interface A {}` or `private const a = 1`.
+fn check_modifiers(modifiers: &Modifiers<'_>, kind: AstKind<'_>, ctx: &SemanticBuilder<'_>) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("'{0}' modifier already seen.")]
+    #[diagnostic()]
+    struct ModifierAlreadySeen(&'static str, #[label] Span);
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("'abstract' modifier can only appear on a class, method, or property declaration.")]
+    #[diagnostic()]
+    struct AbstractModifier(#[label] Span);
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("'{0}' modifier cannot appear on a module or namespace element.")]
+    #[diagnostic()]
+    struct ModuleElementModifier(&'static str, #[label] Span);
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("'readonly' modifier can only appear on a property declaration or index signature.")]
+    #[diagnostic()]
+    struct ReadonlyModifier(#[label] Span);
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("'{0}' modifier can only appear on a type parameter.")]
+    #[diagnostic()]
+    struct VarianceModifier(&'static str, #[label] Span);
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("'async' modifier cannot be used here.")]
+    #[diagnostic()]
+    struct AsyncModifier(#[label] Span);
+
+    let mut seen: Vec<ModifierKind> = vec![];
+    for modifier in modifiers.iter() {
+        let span = modifier.span;
+        if seen.contains(&modifier.kind) {
+            ctx.error(ModifierAlreadySeen(modifier.kind.as_str(), span));
+            continue;
+        }
+        if modifier.kind.is_accessibility() && seen.iter().any(|kind| kind.is_accessibility()) {
+            ctx.error(AccessibilityModifierAlreadySeen(span));
+            continue;
+        }
+        seen.push(modifier.kind);
+        match modifier.kind {
+            ModifierKind::Abstract if !matches!(kind, AstKind::Class(_)) => {
+                ctx.error(AbstractModifier(span));
+            }
+            ModifierKind::Public
+            | ModifierKind::Private
+            | ModifierKind::Protected
+            | ModifierKind::Static
+            | ModifierKind::Override
+            | ModifierKind::Accessor => {
+                ctx.error(ModuleElementModifier(modifier.kind.as_str(), span));
+            }
+            ModifierKind::Readonly => ctx.error(ReadonlyModifier(span)),
+            ModifierKind::In | ModifierKind::Out => {
+                ctx.error(VarianceModifier(modifier.kind.as_str(), span));
+            }
+            ModifierKind::Async if !matches!(kind, AstKind::Function(_)) => {
+                ctx.error(AsyncModifier(span));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Accessibility modifiers of class members and parameter properties, e.g. `public private a`.
+/// The parser only keeps one of them, so the source text before `end` is scanned.
+#[allow(clippy::cast_possible_truncation)]
+fn check_accessibility_modifiers(
+    span: Span,
+    decorators: &[Decorator<'_>],
+    end: u32,
+    ctx: &SemanticBuilder<'_>,
+) {
+    let start = decorators.last().map_or(span.start, |decorator| decorator.span.end);
+    if end <= start {
+        return;
+    }
+    let mut offset = start;
+    let mut seen = false;
+    for piece in
+        Span::new(start, end).source_text(ctx.source_text).split_inclusive(char::is_whitespace)
+    {
+        let word = piece.trim_end();
+        if matches!(word, "public" | "private" | "protected") {
+            if seen {
+                let word_end = offset + word.len() as u32;
+                ctx.error(AccessibilityModifierAlreadySeen(Span::new(offset, word_end)));
+            }
+            seen = true;
+        }
+        offset += piece.len() as u32;
+    }
+}
+
+/// `declare function a() {}`
+fn check_function_implementation<'a>(
+    func: &Function<'a>,
+    node: &AstNode<'a>,
+    ctx: &SemanticBuilder<'a>,
+) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("An implementation cannot be declared in ambient contexts.")]
+    #[diagnostic()]
+    struct ImplementationInAmbientContext(#[label] Span);
+
+    let Some(body) = &func.body else { return };
+    if !func.is_declaration() {
+        return;
+    }
+    let is_ambient = func.modifiers.is_contains_declare()
+        || ctx.source_type.is_typescript_definition()
+        || ctx.nodes.ancestors(node.id()).any(|node_id| {
+            matches!(
+                ctx.nodes.kind(node_id),
+                AstKind::TSModuleDeclaration(decl) if decl.modifiers.is_contains_declare()
+            )
+        });
+    if is_ambient {
+        ctx.error(ImplementationInAmbientContext(body.span));
+    }
+}
+
+fn check_ts_enum_member(member: &TSEnumMember<'_>, ctx: &SemanticBuilder<'_>) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Computed property names are not allowed in enums.")]
+    #[diagnostic()]
+    struct ComputedEnumMember(#[label] Span);
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("An enum member cannot have a numeric name.")]
+    #[diagnostic()]
+    struct NumericEnumMember(#[label] Span);
+
+    match &member.id {
+        // enum A { ['a'] }
+        TSEnumMemberName::ComputedPropertyName(
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_),
+        ) => {}
+        TSEnumMemberName::ComputedPropertyName(expr) => ctx.error(ComputedEnumMember(expr.span())),
+        TSEnumMemberName::NumericLiteral(lit) => ctx.error(NumericEnumMember(lit.span)),
+        _ => {}
+    }
+}
+
+/// `namespace` and `declare module 'a'` declarations are only allowed at the top level.
+fn check_ts_module_declaration<'a>(
+    decl: &TSModuleDeclaration<'a>,
+    node: &AstNode<'a>,
+    ctx: &SemanticBuilder<'a>,
+) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("A namespace declaration is only allowed at the top level of a namespace or module.")]
+    #[diagnostic()]
+    struct NamespaceNotAtTopLevel(#[label] Span);
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Ambient modules cannot be nested in other modules or namespaces.")]
+    #[diagnostic()]
+    struct NestedAmbientModule(#[label] Span);
+
+    let parent =
+        ctx.nodes.ancestors(node.id()).skip(1).map(|node_id| ctx.nodes.kind(node_id)).find(
+            |kind| {
+                !matches!(kind, AstKind::ModuleDeclaration(_) | AstKind::ExportNamedDeclaration(_))
+            },
+        );
+    match decl.id {
+        TSModuleDeclarationName::Identifier(_) => {
+            if !matches!(
+                parent,
+                Some(
+                    AstKind::Program(_)
+                        | AstKind::TSModuleBlock(_)
+                        | AstKind::TSModuleDeclaration(_)
+                )
+            ) {
+                ctx.error(NamespaceNotAtTopLevel(decl.span));
+            }
+        }
+        TSModuleDeclarationName::StringLiteral(_) => {
+            if !matches!(parent, Some(AstKind::Program(_))) {
+                ctx.error(NestedAmbientModule(decl.span));
+            }
+        }
+    }
+}
+
+/// `namespace A { export = a }`
+fn check_ts_export_assignment<'a>(
+    assignment: &TSExportAssignment<'a>,
+    node: &AstNode<'a>,
+    ctx: &SemanticBuilder<'a>,
+) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("An export assignment cannot be used in a namespace.")]
+    #[diagnostic()]
+    struct ExportAssignmentInNamespace(#[label] Span);
+
+    let in_namespace = ctx.nodes.ancestors(node.id()).skip(1).any(|node_id| {
+        matches!(
+            ctx.nodes.kind(node_id),
+            AstKind::TSModuleDeclaration(decl)
+                if matches!(decl.id, TSModuleDeclarationName::Identifier(_))
+        )
+    });
+    if in_namespace {
+        ctx.error(ExportAssignmentInNamespace(assignment.span));
+    }
+}
+
+/// `export = a` cannot be combined with `export const b = 1`.
+fn check_export_assignment_with_other_exports(program: &Program<'_>, ctx: &SemanticBuilder<'_>) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("An export assignment cannot be used in a module with other exported elements.")]
+    #[diagnostic()]
+    struct ExportAssignmentWithOtherExports(#[label] Span);
+
+    let mut export_assignment = None;
+    let mut has_other_exports = false;
+    for stmt in &program.body {
+        let Statement::ModuleDeclaration(decl) = stmt else { continue };
+        match &**decl {
+            ModuleDeclaration::TSExportAssignment(assignment) => {
+                export_assignment = Some(assignment.span);
+            }
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                has_other_exports |= decl.export_kind.is_value()
+                    && !decl.declaration.as_ref().is_some_and(|decl| {
+                        matches!(
+                            decl,
+                            Declaration::TSInterfaceDeclaration(_)
+                                | Declaration::TSTypeAliasDeclaration(_)
+                        )
+                    });
+            }
+            ModuleDeclaration::ExportDefaultDeclaration(_)
+            | ModuleDeclaration::ExportAllDeclaration(_) => has_other_exports = true,
+            _ => {}
+        }
+    }
+    if let (Some(span), true) = (export_assignment, has_other_exports) {
+        ctx.error(ExportAssignmentWithOtherExports(span));
+    }
+}
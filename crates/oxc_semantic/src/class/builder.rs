@@ -155,6 +155,6 @@ impl ClassTableBuilder {
     pub fn pop_class(&mut self) {
         self.current_class_id = self
             .current_class_id
-            .and_then(|current_class_id| self.classes.parent_ids.get(&current_class_id).copied());
+            .and_then(|current_class_id| self.classes.parent_ids[current_class_id]);
     }
 }
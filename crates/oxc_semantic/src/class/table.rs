@@ -1,7 +1,6 @@
 use oxc_index::IndexVec;
 use oxc_span::{Atom, CompactString, Span};
 use oxc_syntax::class::{ClassId, ElementId, ElementKind};
-use rustc_hash::FxHashMap;
 
 use crate::node::AstNodeId;
 
@@ -50,7 +49,7 @@ impl PrivateIdentifierReference {
 /// `SoA` (Struct of Arrays) for memory efficiency.
 #[derive(Debug, Default)]
 pub struct ClassTable {
-    pub parent_ids: FxHashMap<ClassId, ClassId>,
+    pub parent_ids: IndexVec<ClassId, Option<ClassId>>,
     pub declarations: IndexVec<ClassId, AstNodeId>,
     pub elements: IndexVec<ClassId, IndexVec<ElementId, Element>>,
     // PrivateIdentifier reference
@@ -59,7 +58,7 @@ pub struct ClassTable {
 
 impl ClassTable {
     pub fn ancestors(&self, class_id: ClassId) -> impl Iterator<Item = ClassId> + '_ {
-        std::iter::successors(Some(class_id), |class_id| self.parent_ids.get(class_id).copied())
+        std::iter::successors(Some(class_id), |class_id| self.parent_ids[*class_id])
     }
 
     pub fn iter_enumerated(&self) -> impl Iterator<Item = (ClassId, &AstNodeId)> + '_ {
@@ -104,9 +103,7 @@ impl ClassTable {
 
     pub fn declare_class(&mut self, parent_id: Option<ClassId>, ast_node_id: AstNodeId) -> ClassId {
         let class_id = self.declarations.push(ast_node_id);
-        if let Some(parent_id) = parent_id {
-            self.parent_ids.insert(class_id, parent_id);
-        };
+        self.parent_ids.push(parent_id);
         self.elements.push(IndexVec::default());
         self.private_identifiers.push(Vec::new());
         class_id
@@ -325,6 +325,19 @@ impl ControlFlowGraph {
             .pop()
             .expect("expected there to be a breaks array for this statement");
 
+        // The `break`s of a labeled statement jump past its end, which can be unreachable
+        // itself, e.g. after the `break` of `l: { break l; }`
+        let break_jump_position = if preserved_state.put_label
+            && !basic_blocks_with_breaks.is_empty()
+            && break_jump_position == self.current_node_ix
+        {
+            let after_statement_ix = self.new_basic_block();
+            self.add_edge(break_jump_position, after_statement_ix, EdgeType::Normal);
+            after_statement_ix
+        } else {
+            break_jump_position
+        };
+
         for break_ in basic_blocks_with_breaks {
             // can this always be self.current_node_ix?
             self.add_edge(break_, break_jump_position, EdgeType::Normal);
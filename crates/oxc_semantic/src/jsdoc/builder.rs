@@ -77,7 +77,7 @@ impl<'a> JSDocBuilder<'a> {
         // This may be diffed compare to TypeScript's `canHaveJSDoc()`, should adjust if needed
         if !(kind.is_statement()
             || kind.is_declaration()
-            || matches!(kind, AstKind::ParenthesizedExpression(_)))
+            || matches!(kind, AstKind::ParenthesizedExpression(_) | AstKind::MethodDefinition(_)))
         {
             return false;
         }
@@ -121,7 +121,9 @@ impl<'a> JSDocBuilder<'a> {
         }
 
         // Remove the very first `*`
-        Some(JSDoc::new(&comment_content[1..]))
+        // The span of the comment excludes `/*` and `*/`
+        let span = Span::new(span_start - 2, comment.end() + 2);
+        Some(JSDoc::new(&comment_content[1..], span))
     }
 }
 
@@ -249,13 +251,22 @@ mod test {
 
     #[test]
     fn found_ts() {
-        let source_texts = [(
-            "class Foo {
+        let source_texts = [
+            (
+                "class Foo {
             /** jsdoc */
             bar: string;
         }",
-            "bar: string;",
-        )];
+                "bar: string;",
+            ),
+            (
+                "class Foo {
+            /** jsdoc */
+            bar(): void {}
+        }",
+                "bar(): void {}",
+            ),
+        ];
 
         let source_type = SourceType::default().with_typescript(true);
         for (source_text, target) in source_texts {
@@ -4,3 +4,4 @@ mod parser;
 
 pub use builder::JSDocBuilder;
 pub use finder::JSDocFinder;
+pub use parser::{JSDoc, JSDocTag, JSDocTagKind, Param, ParamType, ParamTypeKind};
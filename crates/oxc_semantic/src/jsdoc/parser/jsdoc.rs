@@ -1,18 +1,25 @@
 use super::jsdoc_tag::JSDocTag;
 use super::parse::JSDocParser;
+use oxc_span::Span;
 use std::cell::OnceCell;
 
 #[derive(Debug, Clone)]
 pub struct JSDoc<'a> {
     raw: &'a str,
+    /// The whole comment, including `/**` and `*/`
+    span: Span,
     /// Cached+parsed JSDoc comment and tags
     cached: OnceCell<(String, Vec<JSDocTag<'a>>)>,
 }
 
 impl<'a> JSDoc<'a> {
     /// comment_content: Inside of /**HERE*/, not include `/**` and `*/`
-    pub fn new(comment_content: &'a str) -> JSDoc<'a> {
-        Self { raw: comment_content, cached: OnceCell::new() }
+    pub fn new(comment_content: &'a str, span: Span) -> JSDoc<'a> {
+        Self { raw: comment_content, span, cached: OnceCell::new() }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
     }
 
     pub fn comment(&self) -> &str {
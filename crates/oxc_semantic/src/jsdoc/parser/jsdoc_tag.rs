@@ -16,7 +16,6 @@ pub struct ParamType<'a> {
 }
 
 impl<'a> ParamType<'a> {
-    #[allow(unused)]
     pub fn kind(&self) -> Option<ParamTypeKind> {
         ParamTypeKind::from_str(self.value).map(Option::Some).unwrap_or_default()
     }
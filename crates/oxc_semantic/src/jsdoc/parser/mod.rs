@@ -4,3 +4,4 @@ mod parse;
 mod utils;
 
 pub use jsdoc::JSDoc;
+pub use jsdoc_tag::{JSDocTag, JSDocTagKind, Param, ParamType, ParamTypeKind};
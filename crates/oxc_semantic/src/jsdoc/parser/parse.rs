@@ -76,12 +76,9 @@ impl<'a> JSDocParser<'a> {
 
         let mut r#type = None;
         if self.at('{') {
-            // If we hit a space, then treat it as the end of the type annotation.
-            let type_annotation = self.take_until(|c| c == '}' || c == ' ' || c == '@');
+            let type_annotation = self.take_type_expression();
             r#type = Some(ParamType { value: type_annotation });
-            if self.at('}') {
-                self.skip_whitespace();
-            }
+            self.at('}');
             self.skip_whitespace();
         }
 
@@ -129,6 +126,25 @@ impl<'a> JSDocParser<'a> {
         }
     }
 
+    // Until the `}` closing the type, e.g. `{{ a: string | number }}`.
+    // If the type is not closed on its line, then treat a space as the end of the type annotation.
+    fn take_type_expression(&mut self) -> &'a str {
+        let start = self.current;
+        let mut depth = 0;
+        while let Some(c) = self.source_text.chars().nth(self.current) {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => return &self.source_text[start..self.current],
+                '}' => depth -= 1,
+                '\n' | '@' => break,
+                _ => {}
+            }
+            self.current += 1;
+        }
+        self.current = start;
+        self.take_until(|c| c == '}' || c == ' ' || c == '@')
+    }
+
     fn take_until(&mut self, predicate: fn(char) -> bool) -> &'a str {
         let start = self.current;
         while let Some(c) = self.source_text.chars().nth(self.current) {
@@ -381,6 +397,16 @@ asterisk
             parse_from_full_text("/** @param {str} name comment */"),
             parse_from_full_text("/** @param {str} name - comment */"),
         );
+        assert_eq!(
+            parse_from_full_text("/** @param {string | {a: number}} name comment */").1,
+            vec![JSDocTag {
+                kind: JSDocTagKind::Parameter(Param {
+                    name: "name",
+                    r#type: Some(ParamType { value: "string | {a: number}" })
+                }),
+                comment: "comment".to_string(),
+            },]
+        );
         assert_eq!(
             parse_from_full_text("/** @param {str} name comment */"),
             parse_from_full_text(
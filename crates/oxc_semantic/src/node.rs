@@ -65,6 +65,15 @@ impl<'a> AstNodes<'a> {
         self.nodes.iter()
     }
 
+    /// The number of AST nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
     /// Walk up the AST, iterating over each parent node.
     ///
     /// The first node produced by this iterator is the first parent of the node
@@ -23,9 +23,9 @@ pub struct ScopeTree {
     parent_ids: IndexVec<ScopeId, Option<ScopeId>>,
 
     /// Maps a scope to direct children scopes
-    child_ids: FxHashMap<ScopeId, Vec<ScopeId>>,
-    // Maps a scope to its node id
-    node_ids: FxHashMap<ScopeId, AstNodeId>,
+    child_ids: IndexVec<ScopeId, Vec<ScopeId>>,
+    // Maps a function scope to its node id
+    node_ids: IndexVec<ScopeId, Option<AstNodeId>>,
     flags: IndexVec<ScopeId, ScopeFlags>,
     bindings: IndexVec<ScopeId, Bindings>,
     unresolved_references: IndexVec<ScopeId, UnresolvedReferences>,
@@ -49,14 +49,12 @@ impl ScopeTree {
         // have recursive closures
         fn add_to_list(
             parent_id: ScopeId,
-            child_ids: &FxHashMap<ScopeId, Vec<ScopeId>>,
+            child_ids: &IndexVec<ScopeId, Vec<ScopeId>>,
             items: &mut Vec<ScopeId>,
         ) {
-            if let Some(children) = child_ids.get(&parent_id) {
-                for child_id in children {
-                    items.push(*child_id);
-                    add_to_list(*child_id, child_ids, items);
-                }
+            for child_id in &child_ids[parent_id] {
+                items.push(*child_id);
+                add_to_list(*child_id, child_ids, items);
             }
         }
 
@@ -67,8 +65,8 @@ impl ScopeTree {
         list.into_iter()
     }
 
-    pub fn get_child_ids(&self, scope_id: ScopeId) -> Option<&Vec<ScopeId>> {
-        self.child_ids.get(&scope_id)
+    pub fn get_child_ids(&self, scope_id: ScopeId) -> &[ScopeId] {
+        &self.child_ids[scope_id]
     }
 
     pub fn descendants_from_root(&self) -> impl Iterator<Item = ScopeId> + '_ {
@@ -116,8 +114,11 @@ impl ScopeTree {
         &self.bindings[scope_id]
     }
 
+    /// # Panics
+    ///
+    /// * The scope is not the scope of a function
     pub fn get_node_id(&self, scope_id: ScopeId) -> AstNodeId {
-        self.node_ids[&scope_id]
+        self.node_ids[scope_id].expect("scope has no node id")
     }
 
     pub fn iter_bindings(
@@ -137,16 +138,18 @@ impl ScopeTree {
         _ = self.flags.push(flags);
         _ = self.bindings.push(Bindings::default());
         _ = self.unresolved_references.push(UnresolvedReferences::default());
+        _ = self.child_ids.push(vec![]);
+        _ = self.node_ids.push(None);
 
         if let Some(parent_id) = parent_id {
-            self.child_ids.entry(parent_id).or_default().push(scope_id);
+            self.child_ids[parent_id].push(scope_id);
         }
 
         scope_id
     }
 
     pub(crate) fn add_node_id(&mut self, scope_id: ScopeId, node_id: AstNodeId) {
-        self.node_ids.insert(scope_id, node_id);
+        self.node_ids[scope_id] = Some(node_id);
     }
 
     pub fn add_binding(&mut self, scope_id: ScopeId, name: CompactString, symbol_id: SymbolId) {
@@ -3,12 +3,16 @@
 //! <https://doc.rust-lang.org/beta/nightly-rustc/rustc_span>
 
 mod atom;
+mod line_endings;
 mod source_type;
 mod span;
 
 pub use crate::{
     atom::Atom,
-    source_type::{Language, LanguageVariant, ModuleKind, SourceType, VALID_EXTENSIONS},
+    line_endings::{LineColumn, LineEnding, LineEndings},
+    source_type::{
+        Language, LanguageVariant, ModuleKind, SourceType, UnknownExtension, VALID_EXTENSIONS,
+    },
     span::{GetSpan, Span, SPAN},
 };
 pub use compact_str::CompactString;
@@ -0,0 +1,147 @@
+//! Line breaks of a source text, for computing lines and columns the same way on files with `\n`,
+//! `\r\n` or `\r` line endings.
+
+/// The characters ending a line
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+    Cr,
+}
+
+impl LineEnding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+            Self::Cr => "\r",
+        }
+    }
+
+    /// The line ending of the first line of `source_text`, `None` without line endings.
+    /// `\u{2028}` and `\u{2029}` also end lines, but are not line endings of files.
+    pub fn detect(source_text: &str) -> Option<Self> {
+        let i = source_text.find(['\n', '\r'])?;
+        Some(match &source_text.as_bytes()[i..] {
+            [b'\r', b'\n', ..] => Self::CrLf,
+            [b'\r', ..] => Self::Cr,
+            _ => Self::Lf,
+        })
+    }
+}
+
+/// The line and column of an offset, starting at 0
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LineColumn {
+    pub line: u32,
+    /// Counted in characters
+    pub column: u32,
+}
+
+/// The line breaks of a source text: `\n`, `\r\n`, `\r`, `\u{2028}` and `\u{2029}` as in
+/// ECMAScript.
+#[derive(Debug, Default, Clone)]
+pub struct LineEndings {
+    /// The offsets of the line breaks, and of the start of the next line
+    breaks: Vec<(u32, u32)>,
+    /// The offsets of the `\r` characters, of `\r\n` line endings or alone
+    carriage_returns: Vec<u32>,
+    /// The line ending of the first line
+    style: Option<LineEnding>,
+    /// Whether the line endings are not all the same as the first one
+    mixed: bool,
+}
+
+impl LineEndings {
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(source_text: &str) -> Self {
+        let mut line_endings = Self::default();
+        let bytes = source_text.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let (ending, len) = match bytes[i] {
+                b'\n' => (Some(LineEnding::Lf), 1),
+                b'\r' if bytes.get(i + 1) == Some(&b'\n') => (Some(LineEnding::CrLf), 2),
+                b'\r' => (Some(LineEnding::Cr), 1),
+                // `\u{2028}` and `\u{2029}` are encoded as `E2 80 A8` and `E2 80 A9`
+                0xE2 if matches!(bytes.get(i + 1..i + 3), Some([0x80, 0xA8 | 0xA9])) => (None, 3),
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+            if bytes[i] == b'\r' {
+                line_endings.carriage_returns.push(i as u32);
+            }
+            if let Some(ending) = ending {
+                match line_endings.style {
+                    None => line_endings.style = Some(ending),
+                    Some(style) => line_endings.mixed |= style != ending,
+                }
+            }
+            line_endings.breaks.push((i as u32, (i + len) as u32));
+            i += len;
+        }
+        line_endings
+    }
+
+    /// The line ending of the first line, `\n` without line endings
+    pub fn style(&self) -> LineEnding {
+        self.style.unwrap_or_default()
+    }
+
+    /// Whether the file has different line endings
+    pub fn is_mixed(&self) -> bool {
+        self.mixed
+    }
+
+    /// The offsets of the `\r` characters
+    pub fn carriage_returns(&self) -> &[u32] {
+        &self.carriage_returns
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.breaks.len() + 1
+    }
+
+    /// The offset of the start of `line`, starting at 0
+    pub fn line_start(&self, line: usize) -> Option<u32> {
+        match line {
+            0 => Some(0),
+            _ => self.breaks.get(line - 1).map(|(_, next)| *next),
+        }
+    }
+
+    /// The offset of the end of `line` before its line break, or of the end of the file for the
+    /// last line
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn line_end(&self, line: usize, source_text: &str) -> Option<u32> {
+        match self.breaks.get(line) {
+            Some((end, _)) => Some(*end),
+            None => (line == self.breaks.len()).then_some(source_text.len() as u32),
+        }
+    }
+
+    /// Whether the file ends with a line break
+    pub fn has_final_newline(&self, source_text: &str) -> bool {
+        self.breaks.last().is_some_and(|(_, next)| *next as usize == source_text.len())
+    }
+
+    /// The line of `offset`, starting at 0. The line break at the end of a line, such as the `\n`
+    /// of `\r\n`, is part of the line.
+    pub fn line(&self, offset: u32) -> usize {
+        self.breaks.partition_point(|(_, next)| *next <= offset)
+    }
+
+    /// The line and column of `offset`. The characters of a line break are at the
+    /// column of its first character.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn line_column(&self, offset: u32, source_text: &str) -> LineColumn {
+        let line = self.line(offset);
+        let start = self.line_start(line).unwrap_or_default() as usize;
+        let end = self.line_end(line, source_text).unwrap_or_default();
+        let column = source_text[start..offset.min(end) as usize].chars().count();
+        LineColumn { line: line as u32, column: column as u32 }
+    }
+}
@@ -11,6 +11,7 @@ pub mod precedence;
 pub mod reference;
 pub mod scope;
 pub mod symbol;
+pub mod syntax_feature;
 pub mod xml_entities;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
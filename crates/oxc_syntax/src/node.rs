@@ -15,7 +15,11 @@ export type AstNodeId = number;
 export type NodeFlags = {
     JSDoc: 1,
     Class: 2,
-    HasYield: 4
+    HasYield: 4,
+    StrictMode: 8,
+    Function: 16,
+    Iteration: 32,
+    Switch: 64
 };
 "#;
 
@@ -25,7 +29,10 @@ bitflags! {
         const JSDoc    = 1 << 0; // If the Node has a JSDoc comment attached
         const Class    = 1 << 1; // If Node is inside a class
         const HasYield = 1 << 2; // If function has yield statement
-
+        const StrictMode = 1 << 3; // If Node is in strict mode code
+        const Function = 1 << 4; // If Node is inside a function
+        const Iteration = 1 << 5; // If Node is inside a loop, without crossing a function or static block
+        const Switch = 1 << 6; // If Node is inside a switch, without crossing a function or static block
     }
 }
 
@@ -41,4 +48,12 @@ impl NodeFlags {
     pub fn has_yield(&self) -> bool {
         self.contains(Self::HasYield)
     }
+
+    pub fn is_strict_mode(&self) -> bool {
+        self.contains(Self::StrictMode)
+    }
+
+    pub fn is_in_iteration(&self) -> bool {
+        self.contains(Self::Iteration)
+    }
 }
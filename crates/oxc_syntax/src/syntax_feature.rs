@@ -0,0 +1,271 @@
+//! The syntax features of JavaScript and TypeScript, with the edition of ECMAScript which
+//! introduced them. The one table used by the parser to reject syntax newer than its
+//! `ecma_version`, by the linter to check a target, and by `oxlint --stats` to count them.
+
+use std::fmt;
+
+/// An edition of ECMAScript.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EcmaVersion {
+    Es5,
+    Es2015,
+    Es2016,
+    Es2017,
+    Es2018,
+    Es2019,
+    Es2020,
+    Es2021,
+    Es2022,
+    Es2023,
+    Es2024,
+    /// All the syntax supported by oxc
+    #[default]
+    Latest,
+}
+
+impl EcmaVersion {
+    /// The edition of `year`, or of its number as in `ecmaVersion: 6` of ESLint.
+    pub fn from_year(year: u16) -> Option<Self> {
+        let version = match year {
+            5 => Self::Es5,
+            6 | 2015 => Self::Es2015,
+            7 | 2016 => Self::Es2016,
+            8 | 2017 => Self::Es2017,
+            9 | 2018 => Self::Es2018,
+            10 | 2019 => Self::Es2019,
+            11 | 2020 => Self::Es2020,
+            12 | 2021 => Self::Es2021,
+            13 | 2022 => Self::Es2022,
+            14 | 2023 => Self::Es2023,
+            15 | 2024 => Self::Es2024,
+            _ => return None,
+        };
+        Some(version)
+    }
+
+    /// The edition of a name such as `es2020`, `es6` or `esnext`, ignoring the case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        if name == "esnext" || name == "latest" {
+            return Some(Self::Latest);
+        }
+        name.strip_prefix("es").and_then(|year| year.parse().ok()).and_then(Self::from_year)
+    }
+}
+
+impl fmt::Display for EcmaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Es5 => "ES5",
+            Self::Es2015 => "ES2015",
+            Self::Es2016 => "ES2016",
+            Self::Es2017 => "ES2017",
+            Self::Es2018 => "ES2018",
+            Self::Es2019 => "ES2019",
+            Self::Es2020 => "ES2020",
+            Self::Es2021 => "ES2021",
+            Self::Es2022 => "ES2022",
+            Self::Es2023 => "ES2023",
+            Self::Es2024 => "ES2024",
+            Self::Latest => "ESNext",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A syntax feature, found in the AST by each of its users.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SyntaxFeature {
+    // ES2015
+    LexicalDeclarations,
+    ArrowFunctions,
+    Classes,
+    Generators,
+    TemplateLiterals,
+    TaggedTemplates,
+    ForOf,
+    Destructuring,
+    DefaultValues,
+    SpreadAndRest,
+    ShorthandProperties,
+    ShorthandMethods,
+    ComputedPropertyNames,
+    NewTarget,
+    RegExpUnicodeFlag,
+    RegExpStickyFlag,
+    EsModules,
+    // ES2016
+    Exponentiation,
+    // ES2017
+    AsyncFunctions,
+    Await,
+    // ES2018
+    AsyncGenerators,
+    ForAwaitOf,
+    ObjectRest,
+    ObjectSpread,
+    RegExpDotAllFlag,
+    // ES2019
+    OptionalCatchBinding,
+    // ES2020
+    NullishCoalescing,
+    OptionalChaining,
+    Bigint,
+    DynamicImport,
+    ImportMeta,
+    ExportAllAs,
+    // ES2021
+    LogicalAssignment,
+    NumericSeparators,
+    // ES2022
+    TopLevelAwait,
+    ClassFields,
+    PrivateMembers,
+    PrivateIn,
+    StaticBlocks,
+    RegExpIndicesFlag,
+    // ES2023
+    Hashbang,
+    // ES2024
+    RegExpUnicodeSetsFlag,
+    // Not in ECMAScript
+    Decorators,
+    UsingDeclarations,
+    CommonjsRequire,
+    Jsx,
+    TsEnums,
+    TsNamespaces,
+    TsInterfaces,
+    TsTypeAliases,
+    TsGenerics,
+    TsTypeAssertions,
+    TsSatisfies,
+    TsNonNullAssertions,
+    TsAny,
+}
+
+impl SyntaxFeature {
+    /// The kebab-case name, e.g. `arrow-functions`
+    pub fn name(self) -> &'static str {
+        self.info().0
+    }
+
+    /// The description in diagnostics, e.g. "arrow functions"
+    pub fn description(self) -> &'static str {
+        self.info().1
+    }
+
+    /// The edition of ECMAScript which introduced the feature,
+    /// `None` for the syntax which is not part of ECMAScript such as JSX and TypeScript.
+    pub fn ecma_version(self) -> Option<EcmaVersion> {
+        self.info().2
+    }
+
+    fn info(self) -> (&'static str, &'static str, Option<EcmaVersion>) {
+        use EcmaVersion::{
+            Es2015, Es2016, Es2017, Es2018, Es2019, Es2020, Es2021, Es2022, Es2023, Es2024,
+        };
+        match self {
+            Self::LexicalDeclarations => {
+                ("lexical-declarations", "`let` and `const`", Some(Es2015))
+            }
+            Self::ArrowFunctions => ("arrow-functions", "arrow functions", Some(Es2015)),
+            Self::Classes => ("classes", "classes", Some(Es2015)),
+            Self::Generators => ("generators", "generators", Some(Es2015)),
+            Self::TemplateLiterals => ("template-literals", "template literals", Some(Es2015)),
+            Self::TaggedTemplates => ("tagged-templates", "tagged templates", Some(Es2015)),
+            Self::ForOf => ("for-of", "`for...of`", Some(Es2015)),
+            Self::Destructuring => ("destructuring", "destructuring", Some(Es2015)),
+            Self::DefaultValues => ("default-values", "default values", Some(Es2015)),
+            Self::SpreadAndRest => ("spread-and-rest", "spread and rest elements", Some(Es2015)),
+            Self::ShorthandProperties => {
+                ("shorthand-properties", "shorthand properties", Some(Es2015))
+            }
+            Self::ShorthandMethods => ("shorthand-methods", "shorthand methods", Some(Es2015)),
+            Self::ComputedPropertyNames => {
+                ("computed-property-names", "computed property names", Some(Es2015))
+            }
+            Self::NewTarget => ("new-target", "`new.target`", Some(Es2015)),
+            Self::RegExpUnicodeFlag => {
+                ("regexp-unicode-flag", "the `u` regular expression flag", Some(Es2015))
+            }
+            Self::RegExpStickyFlag => {
+                ("regexp-sticky-flag", "the `y` regular expression flag", Some(Es2015))
+            }
+            Self::EsModules => ("es-modules", "ES modules", Some(Es2015)),
+            Self::Exponentiation => {
+                ("exponentiation", "the `**` and `**=` operators", Some(Es2016))
+            }
+            Self::AsyncFunctions => ("async-functions", "async functions", Some(Es2017)),
+            Self::Await => ("await", "`await`", Some(Es2017)),
+            Self::AsyncGenerators => ("async-generators", "async generators", Some(Es2018)),
+            Self::ForAwaitOf => ("for-await-of", "`for await...of`", Some(Es2018)),
+            Self::ObjectRest => ("object-rest", "object rest properties", Some(Es2018)),
+            Self::ObjectSpread => ("object-spread", "object spread properties", Some(Es2018)),
+            Self::RegExpDotAllFlag => {
+                ("regexp-dot-all-flag", "the `s` regular expression flag", Some(Es2018))
+            }
+            Self::OptionalCatchBinding => {
+                ("optional-catch-binding", "optional catch binding", Some(Es2019))
+            }
+            Self::NullishCoalescing => ("nullish-coalescing", "the `??` operator", Some(Es2020)),
+            Self::OptionalChaining => ("optional-chaining", "optional chaining", Some(Es2020)),
+            Self::Bigint => ("bigint", "BigInt literals", Some(Es2020)),
+            Self::DynamicImport => ("dynamic-import", "dynamic `import()`", Some(Es2020)),
+            Self::ImportMeta => ("import-meta", "`import.meta`", Some(Es2020)),
+            Self::ExportAllAs => ("export-all-as", "`export * as ns`", Some(Es2020)),
+            Self::LogicalAssignment => {
+                ("logical-assignment", "logical assignment operators", Some(Es2021))
+            }
+            Self::NumericSeparators => ("numeric-separators", "numeric separators", Some(Es2021)),
+            Self::TopLevelAwait => ("top-level-await", "top-level `await`", Some(Es2022)),
+            Self::ClassFields => ("class-fields", "class fields", Some(Es2022)),
+            Self::PrivateMembers => ("private-members", "private class members", Some(Es2022)),
+            Self::PrivateIn => ("private-in", "`#field in object` checks", Some(Es2022)),
+            Self::StaticBlocks => ("static-blocks", "class static blocks", Some(Es2022)),
+            Self::RegExpIndicesFlag => {
+                ("regexp-indices-flag", "the `d` regular expression flag", Some(Es2022))
+            }
+            Self::Hashbang => ("hashbang", "hashbang comments", Some(Es2023)),
+            Self::RegExpUnicodeSetsFlag => {
+                ("regexp-unicode-sets-flag", "the `v` regular expression flag", Some(Es2024))
+            }
+            Self::Decorators => ("decorators", "decorators", None),
+            Self::UsingDeclarations => ("using-declarations", "`using` declarations", None),
+            Self::CommonjsRequire => ("commonjs-require", "CommonJS `require`", None),
+            Self::Jsx => ("jsx", "JSX", None),
+            Self::TsEnums => ("ts-enums", "TypeScript enums", None),
+            Self::TsNamespaces => ("ts-namespaces", "TypeScript namespaces", None),
+            Self::TsInterfaces => ("ts-interfaces", "TypeScript interfaces", None),
+            Self::TsTypeAliases => ("ts-type-aliases", "TypeScript type aliases", None),
+            Self::TsGenerics => ("ts-generics", "TypeScript generics", None),
+            Self::TsTypeAssertions => ("ts-type-assertions", "TypeScript type assertions", None),
+            Self::TsSatisfies => ("ts-satisfies", "the TypeScript `satisfies` operator", None),
+            Self::TsNonNullAssertions => {
+                ("ts-non-null-assertions", "TypeScript non-null assertions", None)
+            }
+            Self::TsAny => ("ts-any", "the TypeScript `any` type", None),
+        }
+    }
+}
+
+impl fmt::Display for SyntaxFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EcmaVersion;
+
+    #[test]
+    fn ecma_version_from_name() {
+        assert_eq!(EcmaVersion::from_name("es5"), Some(EcmaVersion::Es5));
+        assert_eq!(EcmaVersion::from_name("ES6"), Some(EcmaVersion::Es2015));
+        assert_eq!(EcmaVersion::from_name("es2021"), Some(EcmaVersion::Es2021));
+        assert_eq!(EcmaVersion::from_name("esnext"), Some(EcmaVersion::Latest));
+        assert_eq!(EcmaVersion::from_name("es2030"), None);
+        assert_eq!(EcmaVersion::from_name("2020"), None);
+    }
+}
@@ -9,10 +9,7 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_span::{Atom, CompactString, GetSpan, Span, SPAN};
-use oxc_syntax::{
-    identifier::{is_irregular_whitespace, is_line_terminator},
-    xml_entities::XML_ENTITIES,
-};
+use oxc_syntax::xml_entities::XML_ENTITIES;
 
 pub use self::options::{ReactJsxOptions, ReactJsxRuntime, ReactJsxRuntimeOption};
 use crate::{context::TransformerCtx, TransformOptions};
@@ -702,7 +699,7 @@ impl<'a> ReactJsx<'a> {
 
     fn transform_jsx_child(&mut self, child: &JSXChild<'a>) -> Option<Expression<'a>> {
         match child {
-            JSXChild::Text(text) => self.transform_jsx_text(text.value.as_str()),
+            JSXChild::Text(text) => self.transform_jsx_text(text),
             JSXChild::ExpressionContainer(e) => match &e.expression {
                 JSXExpression::Expression(e) => Some(self.ast.copy(e)),
                 JSXExpression::EmptyExpression(_) => None,
@@ -716,61 +713,22 @@ impl<'a> ReactJsx<'a> {
         }
     }
 
-    fn transform_jsx_text(&self, text: &str) -> Option<Expression<'a>> {
+    fn transform_jsx_text(&self, text: &JSXText<'a>) -> Option<Expression<'a>> {
         Self::fixup_whitespace_and_decode_entities(text).map(|s| {
             let s = StringLiteral::new(SPAN, s.into());
             self.ast.literal_string_expression(s)
         })
     }
 
-    /// JSX trims whitespace at the end and beginning of lines, except that the
-    /// start/end of a tag is considered a start/end of a line only if that line is
-    /// on the same line as the closing tag. See examples in
-    /// tests/cases/conformance/jsx/tsxReactEmitWhitespace.tsx
+    /// Trims the lines of `text` as JSX does, see [JSXText::trimmed_lines], then decodes
+    /// entities on each line individually and joins them with " ".
     /// See also https://www.w3.org/TR/html4/struct/text.html#h-9.1 and https://www.w3.org/TR/CSS2/text.html#white-space-model
-    ///
-    /// An equivalent algorithm would be:
-    /// - If there is only one line, return it.
-    /// - If there is only whitespace (but multiple lines), return `undefined`.
-    /// - Split the text into lines.
-    /// - 'trimRight' the first line, 'trimLeft' the last line, 'trim' middle lines.
-    /// - Decode entities on each line (individually).
-    /// - Remove empty lines and join the rest with " ".
-    ///
-    /// <https://github.com/microsoft/TypeScript/blob/f0374ce2a9c465e27a15b7fa4a347e2bd9079450/src/compiler/transformers/jsx.ts#L557-L608>
-    fn fixup_whitespace_and_decode_entities(text: &str) -> Option<String> {
-        let mut acc: Option<String> = None;
-        let mut first_non_whitespace: Option<usize> = Some(0);
-        let mut last_non_whitespace: Option<usize> = None;
-        let mut i: usize = 0;
-        for c in text.chars() {
-            if is_line_terminator(c) {
-                if let (Some(first), Some(last)) = (first_non_whitespace, last_non_whitespace) {
-                    acc = Some(Self::add_line_of_jsx_text(acc, &text[first..=last]));
-                }
-                first_non_whitespace = None;
-            } else if c != ' ' && !is_irregular_whitespace(c) {
-                last_non_whitespace = Some(i);
-                if first_non_whitespace.is_none() {
-                    first_non_whitespace.replace(i);
-                }
-            }
-            i += c.len_utf8();
-        }
-        if let Some(first) = first_non_whitespace {
-            Some(Self::add_line_of_jsx_text(acc, &text[first..]))
-        } else {
-            acc
-        }
-    }
-
-    fn add_line_of_jsx_text(acc: Option<String>, trimmed_line: &str) -> String {
-        let decoded = Self::decode_entities(trimmed_line);
-        if let Some(acc) = acc {
-            format!("{acc} {decoded}")
-        } else {
-            decoded
+    fn fixup_whitespace_and_decode_entities(text: &JSXText) -> Option<String> {
+        let lines = text.trimmed_lines();
+        if lines.is_empty() {
+            return None;
         }
+        Some(lines.into_iter().map(Self::decode_entities).collect::<std::vec::Vec<_>>().join(" "))
     }
 
     /// * Replace entities like "&nbsp;", "&#123;", and "&#xDEADBEEF;" with the characters they encode.
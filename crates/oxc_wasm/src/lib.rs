@@ -1,6 +1,8 @@
 mod options;
 
-use std::{cell::RefCell, path::PathBuf, rc::Rc};
+#[cfg(feature = "linter")]
+use std::rc::Rc;
+use std::{cell::RefCell, path::PathBuf};
 
 use oxc::{
     allocator::Allocator,
@@ -12,7 +14,9 @@ use oxc::{
     span::SourceType,
     transformer::{TransformOptions, TransformTarget, Transformer},
 };
+#[cfg(feature = "linter")]
 use oxc_linter::{LintContext, Linter};
+#[cfg(feature = "prettier")]
 use oxc_prettier::{Prettier, PrettierOptions};
 use serde::Serialize;
 use tsify::Tsify;
@@ -61,10 +65,37 @@ pub struct Oxc {
 pub struct OxcDiagnostic {
     pub start: usize,
     pub end: usize,
+    #[serde(rename = "startPosition")]
+    pub start_position: OxcPosition,
+    #[serde(rename = "endPosition")]
+    pub end_position: OxcPosition,
     pub severity: String,
     pub message: String,
 }
 
+/// Zero-based line and column, the column is counted in UTF-16 code units as in JavaScript strings.
+#[derive(Default, Clone, Copy, Serialize)]
+pub struct OxcPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl OxcPosition {
+    /// Convert a byte offset (as used by spans) into a position within `source_text`.
+    pub fn from_offset(source_text: &str, offset: usize) -> Self {
+        let mut offset = offset.min(source_text.len());
+        while !source_text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &source_text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count(),
+            column: before[line_start..].encode_utf16().count(),
+        }
+    }
+}
+
 #[wasm_bindgen]
 impl Oxc {
     #[wasm_bindgen(constructor)]
@@ -96,9 +127,12 @@ impl Oxc {
                 let Some(labels) = error.labels() else { return vec![] };
                 labels
                     .map(|label| {
+                        let (start, end) = (label.offset(), label.offset() + label.len());
                         OxcDiagnostic {
-                            start: label.offset(),
-                            end: label.offset() + label.len(),
+                            start,
+                            end,
+                            start_position: OxcPosition::from_offset(&self.source_text, start),
+                            end_position: OxcPosition::from_offset(&self.source_text, end),
                             severity: format!("{:?}", error.severity().unwrap_or_default()),
                             message: format!("{error}"),
                         }
@@ -110,6 +144,14 @@ impl Oxc {
             .collect::<Vec<_>>())
     }
 
+    /// Convert a byte offset from the AST or the diagnostics into a `{ line, column }` position.
+    /// # Errors
+    /// Serde serialization error
+    #[wasm_bindgen(js_name = getPosition)]
+    pub fn get_position(&self, offset: usize) -> Result<JsValue, serde_wasm_bindgen::Error> {
+        OxcPosition::from_offset(&self.source_text, offset).serialize(&self.serializer)
+    }
+
     /// # Errors
     /// Serde serialization error
     #[wasm_bindgen]
@@ -117,7 +159,7 @@ impl Oxc {
         &mut self,
         run_options: &OxcRunOptions,
         parser_options: &OxcParserOptions,
-        _linter_options: &OxcLinterOptions,
+        linter_options: &OxcLinterOptions,
         _codegen_options: &OxcCodegenOptions,
         minifier_options: &OxcMinifierOptions,
     ) -> Result<(), serde_wasm_bindgen::Error> {
@@ -132,6 +174,7 @@ impl Oxc {
 
         let ret = Parser::new(&allocator, source_text, source_type)
             .allow_return_outside_function(parser_options.allow_return_outside_function)
+            .collect_tokens(run_options.lint())
             .parse();
         self.save_diagnostics(ret.errors);
 
@@ -149,16 +192,26 @@ impl Oxc {
         }
 
         // Only lint if there are not syntax errors
+        #[cfg(feature = "linter")]
         if run_options.lint() && self.diagnostics.borrow().is_empty() {
-            let semantic = Rc::new(semantic_ret.semantic);
-            let lint_ctx = LintContext::new(path.into_boxed_path(), &semantic);
-            let linter_ret = Linter::default().run(lint_ctx);
-            let diagnostics = linter_ret.into_iter().map(|e| e.error).collect();
-            self.save_diagnostics(diagnostics);
+            match Self::linter(linter_options) {
+                Ok(linter) => {
+                    let semantic = Rc::new(semantic_ret.semantic);
+                    let lint_ctx =
+                        LintContext::new(path.into_boxed_path(), &semantic).with_tokens(ret.tokens);
+                    let linter_ret = linter.run(lint_ctx);
+                    let diagnostics = linter_ret.into_iter().map(|e| e.error).collect();
+                    self.save_diagnostics(diagnostics);
+                }
+                Err(error) => self.save_diagnostics(vec![error]),
+            }
         }
+        #[cfg(not(feature = "linter"))]
+        let _ = linter_options;
 
         self.ast = program.serialize(&self.serializer)?;
 
+        #[cfg(feature = "prettier")]
         if run_options.prettier_format() {
             let ret = Parser::new(&allocator, source_text, source_type)
                 .allow_return_outside_function(parser_options.allow_return_outside_function)
@@ -170,6 +223,7 @@ impl Oxc {
             self.prettier_formatted_text = printed;
         }
 
+        #[cfg(feature = "prettier")]
         if run_options.prettier_ir() {
             let ret = Parser::new(&allocator, source_text, source_type)
                 .allow_return_outside_function(parser_options.allow_return_outside_function)
@@ -237,12 +291,21 @@ impl Oxc {
         Ok(())
     }
 
+    #[cfg(feature = "linter")]
+    fn linter(options: &OxcLinterOptions) -> Result<Linter, Error> {
+        let Some(config) = &options.config else {
+            return Ok(Linter::default());
+        };
+        let json = serde_json::from_str(config).map_err(|err| Error::msg(err.to_string()))?;
+        Linter::default().with_config_json(json)
+    }
+
     fn get_scope_text(semantic: &Semantic) -> String {
         fn write_scope_text(
             semantic: &Semantic,
             scope_text: &mut String,
             depth: usize,
-            scope_ids: &Vec<ScopeId>,
+            scope_ids: &[ScopeId],
         ) {
             let space = " ".repeat(depth * 2);
 
@@ -264,15 +327,13 @@ impl Oxc {
                     scope_text.push_str(&format!("\n{binding_space}}}\n"));
                 }
 
-                if let Some(next_scope_ids) = next_scope_ids {
-                    write_scope_text(semantic, scope_text, depth + 1, next_scope_ids);
-                }
+                write_scope_text(semantic, scope_text, depth + 1, next_scope_ids);
                 scope_text.push_str(&format!("{space}}}\n"));
             }
         }
 
         let mut scope_text = String::default();
-        write_scope_text(semantic, &mut scope_text, 0, &vec![semantic.scopes().root_scope_id()]);
+        write_scope_text(semantic, &mut scope_text, 0, &[semantic.scopes().root_scope_id()]);
         scope_text
     }
 
@@ -130,15 +130,18 @@ impl OxcParserOptions {
     }
 }
 
-#[wasm_bindgen]
-#[derive(Default, Clone, Copy)]
-pub struct OxcLinterOptions;
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Default, Clone)]
+pub struct OxcLinterOptions {
+    /// Content of an `.eslintrc.json`, the default rules are used when omitted.
+    pub config: Option<String>,
+}
 
 #[wasm_bindgen]
 impl OxcLinterOptions {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 }
 
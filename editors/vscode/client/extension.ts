@@ -128,6 +128,7 @@ export async function activate(context: ExtensionContext) {
       "javascriptreact",
       "vue",
       "svelte",
+      "html",
     ].map((lang) => ({
       language: lang,
       scheme: "file",
@@ -0,0 +1,42 @@
+/* tslint:disable */
+/* eslint-disable */
+
+/* auto-generated by NAPI-RS */
+
+export interface LinterOptions {
+  sourceType?: 'script' | 'module' | 'unambiguous' | undefined
+  sourceFilename?: string
+  /** Path to an ESLint configuration file, the default rules are used when omitted. */
+  configPath?: string
+}
+export interface LintResult {
+  /** Syntax errors and lint diagnostics, sorted by position. */
+  diagnostics: Array<Diagnostic>
+}
+export interface Diagnostic {
+  message: string
+  severity: 'error' | 'warning' | 'advice'
+  help?: string
+  start: number
+  end: number
+  /** The diagnostic rendered with the source code. */
+  rendered: string
+}
+/**
+ * # Errors
+ *
+ * * File extension is invalid
+ * * Configuration file is invalid
+ */
+export function lintSync(sourceText: string, options?: LinterOptions | undefined | null): LintResult
+/**
+ * # Errors
+ *
+ * * File extension is invalid
+ * * Configuration file is invalid
+ *
+ * # Panics
+ *
+ * * Tokio crashes
+ */
+export function lintAsync(sourceText: string, options?: LinterOptions | undefined | null): Promise<LintResult>
@@ -0,0 +1,137 @@
+use std::{path::PathBuf, rc::Rc, sync::Arc};
+
+use napi_derive::napi;
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{
+    miette::{LabeledSpan, NamedSource, Severity},
+    Error,
+};
+use oxc_linter::{LintContext, LintOptions, Linter};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+#[napi(object)]
+#[derive(Default)]
+pub struct LinterOptions {
+    #[napi(ts_type = "'script' | 'module' | 'unambiguous' | undefined")]
+    pub source_type: Option<String>,
+    pub source_filename: Option<String>,
+    /// Path to an ESLint configuration file, the default rules are used when omitted.
+    pub config_path: Option<String>,
+}
+
+#[napi(object)]
+pub struct LintResult {
+    /// Syntax errors and lint diagnostics, sorted by position.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[napi(object)]
+pub struct Diagnostic {
+    pub message: String,
+    #[napi(ts_type = "'error' | 'warning' | 'advice'")]
+    pub severity: &'static str,
+    pub help: Option<String>,
+    pub start: u32,
+    pub end: u32,
+    /// The diagnostic rendered with the source code.
+    pub rendered: String,
+}
+
+fn lint(source_text: &str, options: &LinterOptions) -> napi::Result<Vec<Error>> {
+    let path = PathBuf::from(options.source_filename.as_deref().unwrap_or("anonymous.js"));
+    let source_type =
+        SourceType::from_path(&path).map_err(|err| napi::Error::from_reason(format!("{err:?}")))?;
+    let source_type = match options.source_type.as_deref() {
+        Some("script") => source_type.with_script(true),
+        Some("module") => source_type.with_module(true),
+        _ => source_type,
+    };
+
+    let lint_options =
+        LintOptions::default().with_config_path(options.config_path.clone().map(PathBuf::from));
+    let linter = Linter::from_options(lint_options)
+        .map_err(|err| napi::Error::from_reason(format!("{err:?}")))?;
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return Ok(ret.errors);
+    }
+
+    let program = allocator.alloc(ret.program);
+    let semantic_ret = SemanticBuilder::new(source_text, source_type)
+        .with_trivias(ret.trivias)
+        .with_check_syntax_error(true)
+        .build(program);
+    if !semantic_ret.errors.is_empty() {
+        return Ok(semantic_ret.errors);
+    }
+
+    let semantic = Rc::new(semantic_ret.semantic);
+    let lint_ctx = LintContext::new(path.into_boxed_path(), &semantic);
+    Ok(linter.run(lint_ctx).into_iter().map(|message| message.error).collect())
+}
+
+/// The range covered by all labels of the diagnostic.
+#[allow(clippy::cast_possible_truncation)]
+fn span_of(error: &Error) -> (u32, u32) {
+    let labels = error.labels().map_or(vec![], Iterator::collect);
+    let start = labels.iter().map(LabeledSpan::offset).min().unwrap_or(0);
+    let end = labels.iter().map(|label| label.offset() + label.len()).max().unwrap_or(0);
+    (start as u32, end as u32)
+}
+
+/// # Errors
+///
+/// * File extension is invalid
+/// * Configuration file is invalid
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn lint_sync(source_text: String, options: Option<LinterOptions>) -> napi::Result<LintResult> {
+    let options = options.unwrap_or_default();
+    let mut errors = lint(&source_text, &options)?;
+    errors.sort_by_cached_key(span_of);
+
+    let file_name = options.source_filename.unwrap_or_default();
+    let source = Arc::new(NamedSource::new(file_name, source_text));
+    let diagnostics = errors
+        .into_iter()
+        .map(|error| {
+            let (start, end) = span_of(&error);
+            Diagnostic {
+                message: error.to_string(),
+                severity: match error.severity() {
+                    Some(Severity::Warning) => "warning",
+                    Some(Severity::Advice) => "advice",
+                    Some(Severity::Error) | None => "error",
+                },
+                help: error.help().map(|help| help.to_string()),
+                start,
+                end,
+                rendered: format!("{:?}", error.with_source_code(Arc::clone(&source))),
+            }
+        })
+        .collect();
+
+    Ok(LintResult { diagnostics })
+}
+
+/// # Errors
+///
+/// * File extension is invalid
+/// * Configuration file is invalid
+///
+/// # Panics
+///
+/// * Tokio crashes
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub async fn lint_async(
+    source_text: String,
+    options: Option<LinterOptions>,
+) -> napi::Result<LintResult> {
+    tokio::spawn(async move { lint_sync(source_text, options) }).await.unwrap()
+}
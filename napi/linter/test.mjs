@@ -0,0 +1,21 @@
+import oxc from './index.js';
+import assert from 'assert';
+
+console.log(`Testing on ${process.platform}-${process.arch}`)
+
+function test(ret) {
+  console.log(ret);
+  assert(ret.diagnostics.length == 1);
+  assert(ret.diagnostics[0].message.includes('no-debugger'));
+  assert(ret.diagnostics[0].start == 14);
+}
+
+const sourceText = "/* comment */ debugger;";
+
+test(oxc.lintSync(sourceText));
+
+async function main() {
+  test(await oxc.lintAsync(sourceText));
+}
+
+main()
@@ -53,6 +53,10 @@ impl TestFiles {
             "https://cdn.jsdelivr.net/npm/pdfjs-dist@4.0.269/build/pdf.mjs",
             // ES5 (3.9M)
             "https://cdn.jsdelivr.net/npm/antd@5.12.5/dist/antd.js",
+            // Real world library, heavy with prototypes and math (1.2M)
+            "https://cdn.jsdelivr.net/npm/three@0.124.0/build/three.js",
+            // Minified bundle, long lines and short identifiers
+            "https://cdn.jsdelivr.net/npm/terser@5.17.4/dist/bundle.min.js",
         ]
         .into_iter()
         .map(TestFile::new)
@@ -0,0 +1,5 @@
+a: a: ;
+b: { c: { b: ; } }
+d: { continue d; }
+e: { f: ; f: ; }
+g: while (true) { h: { break g; } continue g; }
@@ -0,0 +1,3 @@
+/(?<dup>a)(?<dup>b)/;
+/[z-a]/u;
+/a{2,1}/;
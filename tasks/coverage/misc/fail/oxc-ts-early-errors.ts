@@ -0,0 +1,21 @@
+abstract interface A {}
+private const a = 1;
+readonly type T = string;
+declare declare const b: number;
+declare function f(): void {}
+function g(x?: number = 1) {}
+class C {
+  public private c = 1;
+}
+enum E {
+  [a] = 1,
+  1 = 2,
+}
+function h() {
+  namespace N {}
+}
+namespace M {
+  export = a;
+}
+export = a;
+export const d = 1;
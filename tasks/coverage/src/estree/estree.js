@@ -0,0 +1,51 @@
+// Parses the code posted by `cargo coverage estree` with acorn, the reference ESTree parser,
+// and answers with either `{ "ast": ... }` or `{ "error": { "pos": ..., "message": ... } }`.
+// The positions are offsets in UTF-16 code units.
+import process from "node:process";
+import { createServer } from "node:http";
+import { parse } from "acorn";
+
+function parseCode({ code = "", isModule = false }) {
+  try {
+    const ast = parse(code, {
+      ecmaVersion: "latest",
+      sourceType: isModule ? "module" : "script",
+      // oxc keeps the parentheses in the AST
+      preserveParens: true,
+      allowHashBang: true,
+    });
+    return { ast };
+  } catch (err) {
+    if (!(err instanceof SyntaxError)) throw err;
+    return { error: { pos: err.pos ?? 0, message: err.message } };
+  }
+}
+
+const server = createServer((req, res) => {
+  if (req.method === 'POST') {
+    let body = '';
+    req.on('data', chunk => {
+      body += chunk.toString();
+    });
+    req.on('end', () => {
+      let result;
+      try {
+        result = parseCode(JSON.parse(body));
+      } catch (err) {
+        res.statusCode = 500;
+        return res.end(err.toString());
+      }
+      res.setHeader('Content-Type', 'application/json');
+      res.end(JSON.stringify(result));
+    });
+  } else {
+    res.statusCode = 404;
+    res.end('Not Found');
+  }
+});
+
+process.on('uncaughtException', () => {
+  // Keep serving the other tests
+})
+
+server.listen(32056, () => {});
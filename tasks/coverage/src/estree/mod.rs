@@ -0,0 +1,233 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_tasks_common::agent;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    suite::{Case, TestResult},
+    test262::{Test262Case, TestFlag},
+};
+
+/// Node types of oxc without an ESTree counterpart
+const OXC_ONLY_TYPES: &[&str] = &["FormalParameters", "Hashbang"];
+
+/// The number of divergent nodes printed for each test
+const MAX_REPORTED_NODES: usize = 5;
+
+/// Compares the AST and the first syntax error of oxc with those of acorn,
+/// the reference parser run by `estree.js`.
+///
+/// The ASTs are compared by the spans of their nodes, as the node types of oxc do not
+/// all follow ESTree, so that a node missing or with a different span is reported.
+pub struct EstreeTest262Case {
+    base: Test262Case,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum ReferenceResult {
+    Ast(Value),
+    Error { pos: usize, message: String },
+}
+
+impl Case for EstreeTest262Case {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { base: Test262Case::new(path, code) }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn should_fail(&self) -> bool {
+        self.base.should_fail()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.skip_test_case()
+    }
+
+    fn run(&mut self) {}
+
+    async fn run_async(&mut self) {
+        let flags = &self.base.meta().flags;
+        let is_module = flags.contains(&TestFlag::Module);
+        // acorn has no option for strict mode, prepend the directive for both parsers
+        let source_text = if flags.contains(&TestFlag::OnlyStrict) {
+            format!("\"use strict\";\n{}", self.base.code())
+        } else {
+            self.base.code().to_string()
+        };
+        let source_type = SourceType::default().with_module(is_module);
+
+        let reference = request_parse(json!({ "code": source_text, "isModule": is_module })).await;
+        let result = match reference {
+            Ok(reference) => compare(&source_text, source_type, reference),
+            Err(error) => TestResult::RuntimeError(error),
+        };
+        self.base.set_result(result);
+    }
+}
+
+fn compare(source_text: &str, source_type: SourceType, reference: ReferenceResult) -> TestResult {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let oxc_ast = (ret.errors.is_empty() && !ret.panicked).then(|| ret.program.to_json());
+    let program = allocator.alloc(ret.program);
+    let semantic_ret = SemanticBuilder::new(source_text, source_type)
+        .with_trivias(ret.trivias)
+        .with_check_syntax_error(true)
+        .build(program);
+    let oxc_error = ret
+        .errors
+        .iter()
+        .chain(&semantic_ret.errors)
+        .filter_map(|error| error.labels().and_then(|mut labels| labels.next()))
+        .map(|label| label.offset())
+        .min();
+
+    let offsets = utf16_to_utf8_offsets(source_text);
+    let mut divergence = String::new();
+    match (oxc_error, oxc_ast, reference) {
+        (None, Some(oxc_ast), ReferenceResult::Ast(reference_ast)) => {
+            let oxc_ast = serde_json::from_str::<Value>(&oxc_ast).unwrap();
+            let mut oxc_nodes = vec![];
+            collect_nodes(&oxc_ast, None, &mut oxc_nodes);
+            oxc_nodes.retain(|(ty, _, _)| !OXC_ONLY_TYPES.contains(&ty.as_str()));
+            let mut reference_nodes = vec![];
+            collect_nodes(&reference_ast, Some(&offsets), &mut reference_nodes);
+
+            let (oxc_only, reference_only) = difference(oxc_nodes, reference_nodes);
+            for (name, nodes) in [("oxc", oxc_only), ("acorn", reference_only)] {
+                for (ty, start, end) in nodes.iter().take(MAX_REPORTED_NODES) {
+                    let text = source_text.get(*start..*end).unwrap_or_default();
+                    let text = text.lines().next().unwrap_or_default();
+                    writeln!(divergence, "Only in {name}: {ty} {start}..{end} `{text}`").unwrap();
+                }
+                if nodes.len() > MAX_REPORTED_NODES {
+                    let more = nodes.len() - MAX_REPORTED_NODES;
+                    writeln!(divergence, "Only in {name}: {more} more nodes").unwrap();
+                }
+            }
+        }
+        (Some(offset), _, ReferenceResult::Error { pos, message }) => {
+            let pos = offsets.get(pos).copied().unwrap_or(pos);
+            if offset != pos {
+                writeln!(divergence, "oxc reports an error at {offset}").unwrap();
+                writeln!(divergence, "acorn reports an error at {pos}: {message}").unwrap();
+            }
+        }
+        (Some(offset), _, ReferenceResult::Ast(_)) => {
+            writeln!(divergence, "Only oxc reports an error, at {offset}").unwrap();
+        }
+        (None, _, ReferenceResult::Error { pos, message }) => {
+            let pos = offsets.get(pos).copied().unwrap_or(pos);
+            writeln!(divergence, "Only acorn reports an error, at {pos}: {message}").unwrap();
+        }
+        (None, None, ReferenceResult::Ast(_)) => {
+            divergence.push_str("oxc panicked without an error\n");
+        }
+    }
+
+    if divergence.is_empty() {
+        TestResult::Passed
+    } else {
+        TestResult::Divergence(divergence)
+    }
+}
+
+/// The type and the span of all the nodes of `ast`, with the UTF-16 offsets of acorn
+/// converted to the UTF-8 offsets of oxc by `offsets`.
+fn collect_nodes(ast: &Value, offsets: Option<&[usize]>, nodes: &mut Vec<(String, usize, usize)>) {
+    match ast {
+        Value::Array(values) => {
+            for value in values {
+                collect_nodes(value, offsets, nodes);
+            }
+        }
+        Value::Object(object) => {
+            let node = (object.get("type"), object.get("start"), object.get("end"));
+            if let (Some(Value::String(ty)), Some(start), Some(end)) = node {
+                if let (Some(start), Some(end)) = (start.as_u64(), end.as_u64()) {
+                    let convert = |offset: u64| {
+                        let offset = usize::try_from(offset).unwrap();
+                        offsets.and_then(|offsets| offsets.get(offset).copied()).unwrap_or(offset)
+                    };
+                    nodes.push((ty.clone(), convert(start), convert(end)));
+                }
+            }
+            for value in object.values() {
+                collect_nodes(value, offsets, nodes);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The nodes whose span is only in `a`, and those whose span is only in `b`.
+#[allow(clippy::type_complexity)]
+fn difference(
+    mut a: Vec<(String, usize, usize)>,
+    mut b: Vec<(String, usize, usize)>,
+) -> (Vec<(String, usize, usize)>, Vec<(String, usize, usize)>) {
+    let key = |node: &(String, usize, usize)| (node.1, node.2);
+    a.sort_by_key(key);
+    b.sort_by_key(key);
+    let (mut a_only, mut b_only) = (vec![], vec![]);
+    let (mut a, mut b) = (a.into_iter().peekable(), b.into_iter().peekable());
+    loop {
+        match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) if key(x) == key(y) => {
+                a.next();
+                b.next();
+            }
+            (Some(x), Some(y)) if key(x) < key(y) => a_only.extend(a.next()),
+            (Some(_), Some(_)) | (None, Some(_)) => b_only.extend(b.next()),
+            (Some(_), None) => a_only.extend(a.next()),
+            (None, None) => return (a_only, b_only),
+        }
+    }
+}
+
+/// The UTF-8 offset of each UTF-16 offset of `source_text`.
+fn utf16_to_utf8_offsets(source_text: &str) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(source_text.len() + 1);
+    for (offset, c) in source_text.char_indices() {
+        for _ in 0..c.len_utf16() {
+            offsets.push(offset);
+        }
+    }
+    offsets.push(source_text.len());
+    offsets
+}
+
+async fn request_parse(json: Value) -> Result<ReferenceResult, String> {
+    tokio::spawn(async move {
+        agent()
+            .post("http://localhost:32056/parse")
+            .timeout(Duration::from_secs(10))
+            .send_json(json)
+            .map_err(|err| err.to_string())
+            .and_then(|res| res.into_string().map_err(|err| err.to_string()))
+            .and_then(|body| serde_json::from_str(&body).map_err(|err| err.to_string()))
+    })
+    .await
+    .map_err(|err| err.to_string())?
+}
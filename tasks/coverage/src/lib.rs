@@ -1,5 +1,6 @@
 mod babel;
 mod codegen;
+mod estree;
 mod minifier;
 mod misc;
 mod prettier;
@@ -17,6 +18,7 @@ use similar::DiffableStr;
 use crate::{
     babel::{BabelCase, BabelSuite},
     codegen::{CodegenBabelCase, CodegenMiscCase, CodegenTest262Case, CodegenTypeScriptCase},
+    estree::EstreeTest262Case,
     minifier::{MinifierBabelCase, MinifierTest262Case},
     misc::{MiscCase, MiscSuite},
     prettier::{PrettierBabelCase, PrettierMiscCase, PrettierTest262Case, PrettierTypeScriptCase},
@@ -91,6 +93,19 @@ impl AppArgs {
         let _ = runtime_process.kill();
     }
 
+    /// Compare the ASTs and the syntax errors with acorn, run by `estree.js`.
+    /// Run `npm install` in `tasks/coverage/src/estree` beforehand.
+    ///
+    /// # Panics
+    pub fn run_estree(&self) {
+        let mut estree_process = Command::new("node")
+            .arg(project_root().join("tasks/coverage/src/estree/estree.js"))
+            .spawn()
+            .expect("Run estree.js failed");
+        Test262Suite::<EstreeTest262Case>::new().run_async("estree_test262", self);
+        let _ = estree_process.kill();
+    }
+
     // Generate v8 test262 status file, which is used to skip failed tests
     // see https://chromium.googlesource.com/v8/v8/+/refs/heads/main/test/test262/test262.status
     #[allow(clippy::missing_panics_doc)]
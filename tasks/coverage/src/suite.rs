@@ -35,6 +35,8 @@ pub enum TestResult {
     CorrectError(String, /* panicked */ bool),
     RuntimeError(String),
     CodegenError(/* reason */ &'static str),
+    /// The AST or the syntax errors differ from those of the reference parser
+    Divergence(String),
 }
 
 pub struct CoverageReport<'a, T> {
@@ -386,6 +388,12 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
                     format!("{reason} failed: {:?}\n", normalize_path(self.path())).as_bytes(),
                 )?;
             }
+            TestResult::Divergence(divergence) => {
+                writer.write_all(
+                    format!("Diverges from acorn: {:?}\n", normalize_path(self.path())).as_bytes(),
+                )?;
+                writer.write_all(format!("{divergence}\n").as_bytes())?;
+            }
             TestResult::Passed | TestResult::ToBeRun | TestResult::CorrectError(..) => {}
         }
         Ok(())
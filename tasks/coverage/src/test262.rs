@@ -137,8 +137,10 @@ impl Test262Case {
         self.result = result;
     }
 
+    /// Early errors are reported by the parser and the semantic checker,
+    /// older versions of test262 mark them with the `early` phase instead of `parse`.
     fn compute_should_fail(meta: &MetaData) -> bool {
-        meta.negative.as_ref().filter(|n| n.phase == Phase::Parse).is_some()
+        meta.negative.as_ref().is_some_and(|n| matches!(n.phase, Phase::Parse | Phase::Early))
     }
 }
 
@@ -0,0 +1,145 @@
+//! Generate a documentation page for every lint rule.
+//!
+//! The description comes from the `declare_oxc_lint!` doc comment of the rule,
+//! and the examples from the `pass` and `fail` cases of its test, so the pages cannot drift from the rules.
+//!
+//! Usage: `just rule-docs [OUT_DIR]`, the pages are written to `target/rule_docs` by default.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use oxc_linter::{RuleEnum, RULES};
+use oxc_tasks_common::project_root;
+use syn::{punctuated::Punctuated, Expr, Item, Lit, Pat, Stmt, Token};
+
+/// Tests often have hundreds of cases, only the first ones are shown.
+const MAX_EXAMPLES: usize = 10;
+
+struct Example {
+    code: String,
+    /// The rule configuration the example was tested with
+    config: Option<serde_json::Value>,
+}
+
+#[derive(Default)]
+struct Examples {
+    pass: Vec<Example>,
+    fail: Vec<Example>,
+}
+
+fn main() {
+    let out_dir = std::env::args()
+        .nth(1)
+        .map_or_else(|| project_root().join("target/rule_docs"), PathBuf::from);
+    let rules_dir = project_root().join("crates/oxc_linter/src/rules");
+
+    let mut index = String::from("# Rules\n");
+    let mut plugin = "";
+    for rule in RULES.iter() {
+        if rule.plugin_name() != plugin {
+            plugin = rule.plugin_name();
+            writeln!(index, "\n## {plugin}\n").unwrap();
+        }
+        let examples = read_examples(&rule_path(&rules_dir, rule));
+        let page = render_page(rule, &examples);
+        let path = out_dir.join(plugin).join(format!("{}.md", rule.name()));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, page).unwrap();
+        writeln!(index, "* [{}]({plugin}/{}.md) ({})", rule.name(), rule.name(), rule.category())
+            .unwrap();
+    }
+    fs::write(out_dir.join("README.md"), index).unwrap();
+    println!("Generated documentation for {} rules in {}", RULES.len(), out_dir.display());
+}
+
+fn rule_path(rules_dir: &Path, rule: &RuleEnum) -> PathBuf {
+    rules_dir.join(rule.plugin_name()).join(format!("{}.rs", rule.name().replace('-', "_")))
+}
+
+fn render_page(rule: &RuleEnum, examples: &Examples) -> String {
+    let mut page = String::new();
+    writeln!(page, "# {}/{}\n", rule.plugin_name(), rule.name()).unwrap();
+    writeln!(page, "Category: {}\n", rule.category()).unwrap();
+    if let Some(documentation) = rule.documentation() {
+        writeln!(page, "{}", documentation.trim()).unwrap();
+    }
+    for (kind, examples) in [("correct", &examples.pass), ("incorrect", &examples.fail)] {
+        if examples.is_empty() {
+            continue;
+        }
+        writeln!(page, "\n### Examples of {kind} code\n").unwrap();
+        for Example { code, config } in examples.iter().take(MAX_EXAMPLES) {
+            if let Some(config) = config {
+                writeln!(page, "With the configuration `{config}`:\n").unwrap();
+            }
+            writeln!(page, "```tsx\n{}\n```\n", code.trim()).unwrap();
+        }
+    }
+    page
+}
+
+/// Read the cases of the `pass` and `fail` vectors of the `test` function in the rule file.
+/// Cases which are not string literals, e.g. built with `format!`, are left out.
+fn read_examples(path: &Path) -> Examples {
+    let mut examples = Examples::default();
+    let Ok(source_text) = fs::read_to_string(path) else { return examples };
+    let Ok(file) = syn::parse_file(&source_text) else { return examples };
+    let Some(test) = file.items.iter().find_map(|item| match item {
+        Item::Fn(item_fn) if item_fn.sig.ident == "test" => Some(item_fn),
+        _ => None,
+    }) else {
+        return examples;
+    };
+
+    for stmt in &test.block.stmts {
+        let Stmt::Local(local) = stmt else { continue };
+        let Pat::Ident(pat) = &local.pat else { continue };
+        let examples = match pat.ident.to_string().as_str() {
+            "pass" => &mut examples.pass,
+            "fail" => &mut examples.fail,
+            _ => continue,
+        };
+        let Some((_, init)) = &local.init else { continue };
+        let Expr::Macro(expr_macro) = &**init else { continue };
+        let Ok(cases) =
+            expr_macro.mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        else {
+            continue;
+        };
+        examples.extend(cases.iter().filter_map(read_example));
+    }
+    examples
+}
+
+/// A case is either `"code"` or `("code", config, ...)`.
+fn read_example(case: &Expr) -> Option<Example> {
+    match case {
+        Expr::Lit(lit) => Some(Example { code: string_literal(&lit.lit)?, config: None }),
+        Expr::Tuple(tuple) => {
+            let Expr::Lit(lit) = tuple.elems.first()? else { return None };
+            let config = tuple.elems.iter().nth(1).and_then(read_config);
+            Some(Example { code: string_literal(&lit.lit)?, config })
+        }
+        _ => None,
+    }
+}
+
+fn string_literal(lit: &Lit) -> Option<String> {
+    match lit {
+        Lit::Str(lit) => Some(lit.value()),
+        _ => None,
+    }
+}
+
+/// Read the JSON of `Some(json!(...))` or `Some(serde_json::json!(...))`.
+fn read_config(expr: &Expr) -> Option<serde_json::Value> {
+    let Expr::Call(call) = expr else { return None };
+    let Expr::Macro(expr_macro) = call.args.first()? else { return None };
+    if !expr_macro.mac.path.segments.last().is_some_and(|segment| segment.ident == "json") {
+        return None;
+    }
+    serde_json::from_str(&expr_macro.mac.tokens.to_string()).ok()
+}
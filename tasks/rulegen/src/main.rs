@@ -53,6 +53,12 @@ const REACT_PERF_TEST_PATH: &str =
 const NODE_TEST_PATH: &str =
     "https://raw.githubusercontent.com/eslint-community/eslint-plugin-n/master/tests/lib/rules";
 
+const PROMISE_TEST_PATH: &str =
+    "https://raw.githubusercontent.com/eslint-community/eslint-plugin-promise/main/__tests__";
+
+const SECURITY_TEST_PATH: &str =
+    "https://raw.githubusercontent.com/eslint-community/eslint-plugin-security/main/test/rules";
+
 struct TestCase<'a> {
     source_text: String,
     code: Option<String>,
@@ -453,6 +459,8 @@ pub enum RuleKind {
     NextJS,
     JSDoc,
     Node,
+    Promise,
+    Security,
 }
 
 impl RuleKind {
@@ -469,6 +477,8 @@ impl RuleKind {
             "nextjs" => Self::NextJS,
             "jsdoc" => Self::JSDoc,
             "n" => Self::Node,
+            "promise" => Self::Promise,
+            "security" => Self::Security,
             _ => Self::ESLint,
         }
     }
@@ -489,6 +499,8 @@ impl Display for RuleKind {
             Self::NextJS => write!(f, "eslint-plugin-next"),
             Self::JSDoc => write!(f, "eslint-plugin-jsdoc"),
             Self::Node => write!(f, "eslint-plugin-n"),
+            Self::Promise => write!(f, "eslint-plugin-promise"),
+            Self::Security => write!(f, "eslint-plugin-security"),
         }
     }
 }
@@ -514,6 +526,8 @@ fn main() {
         RuleKind::NextJS => format!("{NEXT_JS_TEST_PATH}/{kebab_rule_name}.test.ts"),
         RuleKind::JSDoc => format!("{JSDOC_TEST_PATH}/{camel_rule_name}.js"),
         RuleKind::Node => format!("{NODE_TEST_PATH}/{kebab_rule_name}.js"),
+        RuleKind::Promise => format!("{PROMISE_TEST_PATH}/{kebab_rule_name}.js"),
+        RuleKind::Security => format!("{SECURITY_TEST_PATH}/{kebab_rule_name}.js"),
         RuleKind::Oxc | RuleKind::DeepScan => String::new(),
     };
 
@@ -42,6 +42,8 @@ impl<'a> Template<'a> {
             RuleKind::NextJS => Path::new("crates/oxc_linter/src/rules/nextjs"),
             RuleKind::JSDoc => Path::new("crates/oxc_linter/src/rules/jsdoc"),
             RuleKind::Node => Path::new("crates/oxc_linter/src/rules/node"),
+            RuleKind::Promise => Path::new("crates/oxc_linter/src/rules/promise"),
+            RuleKind::Security => Path::new("crates/oxc_linter/src/rules/security"),
         };
 
         std::fs::create_dir_all(path)?;